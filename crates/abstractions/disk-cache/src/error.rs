@@ -6,8 +6,26 @@ pub enum DiskCacheError {
     Io(PathBuf, io::Error),
     #[error("cache file is locked by another process")]
     CacheFileLockedByAnotherProcess,
+    #[error("no response cached in {path} for request {request}")]
+    ReplayMiss { path: PathBuf, request: String },
+    #[error("cache save thread has stopped")]
+    SaveThreadStopped,
     #[error("signal handler error: {0}")]
     SignalHandler(String),
 }
 
 pub type DiskCacheResult<T> = Result<T, DiskCacheError>;
+
+/// A part of the cache file that couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskCacheCorruption {
+    /// byte offset into the cache file
+    pub offset: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for DiskCacheCorruption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "at byte offset {}: {}", self.offset, self.message)
+    }
+}
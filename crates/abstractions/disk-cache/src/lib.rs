@@ -1,6 +1,9 @@
 #![feature(async_closure)]
 mod entry;
 pub mod error;
+mod load;
+mod lock;
+pub mod mode;
 mod save;
 pub mod seed;
 #[cfg(test)]
@@ -9,17 +12,23 @@ pub mod traits;
 
 use self::{
     entry::LlmCacheEntry,
-    error::{DiskCacheError, DiskCacheResult},
+    error::{DiskCacheCorruption, DiskCacheError, DiskCacheResult},
     traits::{IsDiskCacheRequest, IsDiskCacheResponse},
 };
 use attach::Attach;
 use chrono::Duration;
 use dashmap::DashMap;
+use load::{load_entries, LoadedEntries};
+#[cfg(test)]
+use lock::lock_file_path;
+use lock::{acquire_lock_file, release_lock_file};
+use mode::DiskCacheMode;
 use save::LlmCacheSaveThread;
 use seed::IsDiskCacheSeed;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::{fs, path::PathBuf};
 use std::{io, sync::RwLock};
 #[cfg(test)]
@@ -36,7 +45,15 @@ where
     path: PathBuf,
     entries: RwLock<Vec<LlmCacheEntry<Seed, Request, Response>>>,
     indices: DashMap<(Seed, Request), usize>,
-    save_thread: LlmCacheSaveThread<Db, Seed, Request, Response>,
+    /// one lock per key whose response is being computed,
+    /// so that identical requests are only sent once while different ones run in parallel
+    in_flight: DashMap<(Seed, Request), Arc<Mutex<()>>>,
+    /// set when the file on disk has to be rewritten before appending
+    needs_rewrite: AtomicBool,
+    corruptions: Vec<DiskCacheCorruption>,
+    mode: DiskCacheMode,
+    /// `None` unless in read-write mode
+    save_thread: Option<LlmCacheSaveThread<Db, Seed, Request, Response>>,
 }
 
 impl<Db, Seed, Request, Response> DiskCache<Db, Seed, Request, Response>
@@ -71,86 +88,176 @@ where
         tokio_runtime: Arc<tokio::runtime::Runtime>,
         path: PathBuf,
     ) -> DiskCacheResult<Self> {
-        fn setup_lock_file(path: &Path) -> DiskCacheResult<()> {
-            use lazy_static::lazy_static;
-            use std::sync::Mutex;
-
-            lazy_static! {
-                static ref CLEANUP_HANDLER: Mutex<CleanupHandler> =
-                    Mutex::new(CleanupHandler::new());
-            }
-
-            struct CleanupHandler {
-                paths: Vec<PathBuf>,
-            }
-
-            impl CleanupHandler {
-                fn new() -> Self {
-                    if let Err(e) = ctrlc::set_handler(move || {
-                        for path in &CLEANUP_HANDLER.lock().unwrap().paths {
-                            let _ = fs::remove_file(lock_file_path(path));
-                        }
-                        std::process::exit(0);
-                    }) {
-                        eprintln!("Warning: Failed to set Ctrl+C handler: {}", e);
-                    }
-
-                    Self { paths: Vec::new() }
-                }
+        // Create directory if it doesn't exist
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| DiskCacheError::Io(path.clone(), e))?;
+        }
 
-                fn register(&mut self, path: PathBuf) {
-                    self.paths.push(path);
-                }
+        acquire_lock_file(&path)?;
+        let loaded_entries = match Self::load_and_back_up(db, &path) {
+            Ok(loaded_entries) => loaded_entries,
+            Err(e) => {
+                // nothing will release it later, as there's no cache to drop
+                release_lock_file(&path);
+                return Err(e);
             }
+        };
+        let save_thread = LlmCacheSaveThread::new(db, path.clone());
+        Ok(Self::new_aux(
+            db,
+            tokio_runtime,
+            path,
+            loaded_entries,
+            DiskCacheMode::ReadWrite,
+            Some(save_thread),
+        ))
+    }
 
-            // check lock file does not exist
-            if lock_file_path(path).exists() {
-                return Err(DiskCacheError::CacheFileLockedByAnotherProcess);
+    /// Loads the entries, backing up the file first if parts of it are corrupted.
+    fn load_and_back_up(
+        db: Db,
+        path: &Path,
+    ) -> DiskCacheResult<LoadedEntries<Seed, Request, Response>> {
+        let loaded_entries = Self::load(db, path)?;
+        if !loaded_entries.corruptions.is_empty() {
+            // keep the original file around, as the corrupted parts are dropped on the next save
+            let backup_path = path_with_suffix(
+                path,
+                &format!("corrupted-{}", chrono::Utc::now().format("%Y%m%d%H%M%S")),
+            );
+            fs::copy(path, &backup_path)
+                .map_err(|e| DiskCacheError::Io(backup_path.clone(), e))?;
+            for corruption in &loaded_entries.corruptions {
+                eprintln!(
+                    "Warning: cache file {} is corrupted {corruption}, backed up to {}",
+                    path.display(),
+                    backup_path.display()
+                );
             }
+        }
+        Ok(loaded_entries)
+    }
 
-            let mut cleanup_handler = CLEANUP_HANDLER.lock().unwrap();
+    pub fn new_with_mode(
+        db: Db,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        path: PathBuf,
+        mode: DiskCacheMode,
+    ) -> DiskCacheResult<Self> {
+        match mode {
+            DiskCacheMode::ReadWrite => Self::new(db, tokio_runtime, path),
+            DiskCacheMode::ReadOnly => Self::new_read_only(db, tokio_runtime, path),
+            DiskCacheMode::Replay => Self::new_replay(db, tokio_runtime, path),
+        }
+    }
 
-            // create lock file
-            fs::File::create(lock_file_path(path))
-                .map_err(|e| DiskCacheError::Io(path.to_path_buf(), e))?;
+    /// Opens the cache without locking it, so that any number of processes can share it.
+    ///
+    /// Nothing is ever written to disk; responses for missing requests are only kept in memory.
+    pub fn new_read_only(
+        db: Db,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        path: PathBuf,
+    ) -> DiskCacheResult<Self> {
+        Self::new_shared(db, tokio_runtime, path, DiskCacheMode::ReadOnly)
+    }
 
-            // Register this lock file with the cleanup handler
-            cleanup_handler.register(path.to_path_buf());
+    /// Opens the cache like [`Self::new_read_only`], except that a miss fails with
+    /// [`DiskCacheError::ReplayMiss`] instead of computing the response.
+    pub fn new_replay(
+        db: Db,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        path: PathBuf,
+    ) -> DiskCacheResult<Self> {
+        Self::new_shared(db, tokio_runtime, path, DiskCacheMode::Replay)
+    }
 
-            Ok(())
+    fn new_shared(
+        db: Db,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        path: PathBuf,
+        mode: DiskCacheMode,
+    ) -> DiskCacheResult<Self> {
+        let loaded_entries = Self::load(db, &path)?;
+        for corruption in &loaded_entries.corruptions {
+            eprintln!(
+                "Warning: cache file {} is corrupted {corruption}",
+                path.display()
+            );
         }
+        Ok(Self::new_aux(
+            db,
+            tokio_runtime,
+            path,
+            loaded_entries,
+            mode,
+            None,
+        ))
+    }
 
-        // Create directory if it doesn't exist
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent).map_err(|e| DiskCacheError::Io(path.clone(), e))?;
+    fn load(db: Db, path: &Path) -> DiskCacheResult<LoadedEntries<Seed, Request, Response>> {
+        if !path.exists() {
+            return Ok(Default::default());
         }
+        let contents =
+            fs::read_to_string(path).map_err(|e| DiskCacheError::Io(path.to_path_buf(), e))?;
+        Ok(db.attach(|| load_entries(&contents)))
+    }
 
-        setup_lock_file(&path)?;
-
-        // Try to load existing cache
-        let entries: Vec<LlmCacheEntry<Seed, Request, Response>> = if path.exists() {
-            let contents =
-                fs::read_to_string(&path).map_err(|e| DiskCacheError::Io(path.clone(), e))?;
-            db.attach(|| serde_json::from_str(&contents).unwrap_or_default())
-        } else {
-            Default::default()
-        };
-
-        let save_thread = LlmCacheSaveThread::new(db, path.clone(), entries.clone());
-
+    fn new_aux(
+        db: Db,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        path: PathBuf,
+        LoadedEntries {
+            entries,
+            corruptions,
+            needs_rewrite,
+        }: LoadedEntries<Seed, Request, Response>,
+        mode: DiskCacheMode,
+        save_thread: Option<LlmCacheSaveThread<Db, Seed, Request, Response>>,
+    ) -> Self {
         let indices = entries
             .iter()
             .enumerate()
             .map(|(i, e)| ((e.seed, e.request.clone()), i))
             .collect();
-        Ok(Self {
+        Self {
             db,
             tokio_runtime,
             path,
             entries: RwLock::new(entries),
             indices,
+            in_flight: Default::default(),
+            needs_rewrite: AtomicBool::new(needs_rewrite),
+            corruptions,
+            mode,
             save_thread,
-        })
+        }
+    }
+}
+
+/// # getters
+impl<Db, Seed, Request, Response> DiskCache<Db, Seed, Request, Response>
+where
+    Seed: IsDiskCacheSeed,
+    Request: IsDiskCacheRequest,
+    Response: IsDiskCacheResponse,
+{
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn mode(&self) -> DiskCacheMode {
+        self.mode
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.save_thread.is_none()
+    }
+
+    /// parts of the cache file that couldn't be parsed when it was opened
+    pub fn corruptions(&self) -> &[DiskCacheCorruption] {
+        &self.corruptions
     }
 }
 
@@ -170,8 +277,15 @@ where
     where
         E: From<DiskCacheError>,
     {
-        if let Some(index) = self.indices.get(&(seed, request.clone())) {
-            return Ok(self.entries.read().unwrap()[*index].response.clone());
+        if let Some(response) = self.get(seed, &request) {
+            return Ok(response);
+        }
+        if self.mode == DiskCacheMode::Replay {
+            return Err(DiskCacheError::ReplayMiss {
+                path: self.path.clone(),
+                request: serde_json::to_string(&request).unwrap(),
+            }
+            .into());
         }
         let response = self.get_or_call_aux(seed, request, f)?;
         Ok(response)
@@ -186,19 +300,72 @@ where
     where
         E: From<DiskCacheError>,
     {
-        let mut entries = self.entries.write().unwrap();
+        let key = (seed, request.clone());
+        let key_lock = self.in_flight.entry(key.clone()).or_default().clone();
+        let _in_flight_guard = InFlightGuard {
+            in_flight: &self.in_flight,
+            key: &key,
+            key_lock: &key_lock,
+        };
+        // a panicking call must not stop others from retrying the key
+        let _key_guard = key_lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
         // check again in case another thread has added the entry
-        if let Some(index) = self.indices.get(&(seed, request.clone())) {
-            return Ok(entries[*index].response.clone());
+        if let Some(response) = self.get(seed, &request) {
+            return Ok(response);
         }
+        // the remote call is made without holding `entries`,
+        // so that calls for different keys don't block each other
         let response = self.tokio_runtime.block_on(f(&request))?;
-        let new_entry = LlmCacheEntry::new(seed, request.clone(), response.clone());
-        entries.push(new_entry);
-        self.save_thread.save(&entries)?;
-        self.indices
-            .insert((seed, request.clone()), entries.len() - 1);
+        {
+            let mut entries = self.entries.write().unwrap();
+            let new_entry = LlmCacheEntry::new(seed, request, response.clone());
+            match self.save_thread {
+                Some(ref save_thread) if self.needs_rewrite.load(Ordering::SeqCst) => {
+                    entries.push(new_entry);
+                    save_thread.rewrite(&entries)?;
+                    self.needs_rewrite.store(false, Ordering::SeqCst);
+                }
+                Some(ref save_thread) => {
+                    save_thread.append(&new_entry)?;
+                    entries.push(new_entry);
+                }
+                None => entries.push(new_entry),
+            }
+            self.indices.insert(key.clone(), entries.len() - 1);
+        }
         Ok(response)
     }
+
+    fn get(&self, seed: Seed, request: &Request) -> Option<Response> {
+        // copy the index out first so that no `indices` shard is locked while reading `entries`
+        let index = *self.indices.get(&(seed, request.clone()))?;
+        Some(self.entries.read().unwrap()[index].response.clone())
+    }
+}
+
+/// Removes the key from `in_flight` on every exit of a call, including errors and panics,
+/// unless a later call has already replaced its lock.
+struct InFlightGuard<'a, Seed, Request>
+where
+    Seed: IsDiskCacheSeed,
+    Request: IsDiskCacheRequest,
+{
+    in_flight: &'a DashMap<(Seed, Request), Arc<Mutex<()>>>,
+    key: &'a (Seed, Request),
+    key_lock: &'a Arc<Mutex<()>>,
+}
+
+impl<'a, Seed, Request> Drop for InFlightGuard<'a, Seed, Request>
+where
+    Seed: IsDiskCacheSeed,
+    Request: IsDiskCacheRequest,
+{
+    fn drop(&mut self) {
+        self.in_flight
+            .remove_if(self.key, |_, key_lock| Arc::ptr_eq(key_lock, self.key_lock));
+    }
 }
 
 impl<Db, Seed, Request, Response> Drop for DiskCache<Db, Seed, Request, Response>
@@ -208,29 +375,22 @@ where
     Response: IsDiskCacheResponse,
 {
     fn drop(&mut self) {
-        fs::remove_file(lock_file_path(&self.path)).unwrap();
+        if let Some(save_thread) = self.save_thread.take() {
+            // flush pending saves before giving up the lock
+            drop(save_thread);
+            release_lock_file(&self.path);
+        }
     }
 }
 
-fn lock_file_path(path: &Path) -> PathBuf {
+/// Appends `suffix` to the extension of `path`, e.g., `cache.json` becomes `cache.json.lock`.
+pub(crate) fn path_with_suffix(path: &Path, suffix: &str) -> PathBuf {
     let ext = path.extension().unwrap_or_default().to_str().unwrap_or("");
     if ext.is_empty() {
-        // No extension: just add ".lock"
-        path.with_extension("lock")
+        // No extension: just add the suffix
+        path.with_extension(suffix)
     } else {
-        // Has extension: append ".lock" to the existing extension
-        path.with_extension(format!("{}.lock", ext))
+        // Has extension: append the suffix to the existing extension
+        path.with_extension(format!("{}.{}", ext, suffix))
     }
 }
-
-#[test]
-fn lock_file_path_works() {
-    assert_eq!(
-        lock_file_path(Path::new("path/to/cache")),
-        PathBuf::from("path/to/cache.lock")
-    );
-    assert_eq!(
-        lock_file_path(Path::new("path/to/cache.json")),
-        PathBuf::from("path/to/cache.json.lock")
-    );
-}
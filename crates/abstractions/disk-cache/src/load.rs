@@ -0,0 +1,115 @@
+use crate::{
+    entry::LlmCacheEntry,
+    error::DiskCacheCorruption,
+    seed::IsDiskCacheSeed,
+    traits::{IsDiskCacheRequest, IsDiskCacheResponse},
+};
+use std::collections::HashSet;
+
+pub(crate) struct LoadedEntries<Seed, Request, Response> {
+    pub(crate) entries: Vec<LlmCacheEntry<Seed, Request, Response>>,
+    pub(crate) corruptions: Vec<DiskCacheCorruption>,
+    /// whether the file on disk should be rewritten before anything is appended,
+    /// i.e., it is in the legacy JSON array format, or it has bad or duplicate lines
+    pub(crate) needs_rewrite: bool,
+}
+
+impl<Seed, Request, Response> Default for LoadedEntries<Seed, Request, Response> {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            corruptions: vec![],
+            needs_rewrite: false,
+        }
+    }
+}
+
+/// Parses the cache file.
+///
+/// The current format is JSON lines, one entry per line.
+/// Files written as a single JSON array by earlier versions are still accepted.
+pub(crate) fn load_entries<Seed, Request, Response>(
+    contents: &str,
+) -> LoadedEntries<Seed, Request, Response>
+where
+    Seed: IsDiskCacheSeed,
+    Request: IsDiskCacheRequest,
+    Response: IsDiskCacheResponse,
+{
+    if contents.trim_start().starts_with('[') {
+        return match serde_json::from_str(contents) {
+            Ok(entries) => LoadedEntries {
+                entries: dedup(entries).0,
+                corruptions: vec![],
+                needs_rewrite: true,
+            },
+            Err(e) => LoadedEntries {
+                entries: vec![],
+                corruptions: vec![DiskCacheCorruption {
+                    offset: line_column_offset(contents, e.line(), e.column()),
+                    message: e.to_string(),
+                }],
+                needs_rewrite: true,
+            },
+        };
+    }
+    let mut corruptions = vec![];
+    let mut entries = vec![];
+    let mut offset = 0;
+    for line in contents.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            match serde_json::from_str(line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => corruptions.push(DiskCacheCorruption {
+                    offset: offset + e.column().saturating_sub(1),
+                    message: e.to_string(),
+                }),
+            }
+        }
+        offset += line.len();
+    }
+    let (entries, has_duplicates) = dedup(entries);
+    let needs_rewrite = !corruptions.is_empty() || has_duplicates;
+    LoadedEntries {
+        entries,
+        corruptions,
+        needs_rewrite,
+    }
+}
+
+/// keeps the first entry for each key
+fn dedup<Seed, Request, Response>(
+    entries: Vec<LlmCacheEntry<Seed, Request, Response>>,
+) -> (Vec<LlmCacheEntry<Seed, Request, Response>>, bool)
+where
+    Seed: IsDiskCacheSeed,
+    Request: IsDiskCacheRequest,
+    Response: IsDiskCacheResponse,
+{
+    let len = entries.len();
+    let mut keys = HashSet::new();
+    let entries: Vec<_> = entries
+        .into_iter()
+        .filter(|entry| keys.insert((entry.seed, entry.request.clone())))
+        .collect();
+    let has_duplicates = entries.len() < len;
+    (entries, has_duplicates)
+}
+
+/// converts the one-based line and column reported by `serde_json` into a byte offset
+fn line_column_offset(contents: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = contents
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    (line_start + column.saturating_sub(1)).min(contents.len())
+}
+
+#[test]
+fn line_column_offset_works() {
+    let contents = "[\n  {\"a\": 1},\n  {\"b\"";
+    assert_eq!(line_column_offset(contents, 1, 1), 0);
+    assert_eq!(line_column_offset(contents, 2, 3), 4);
+    assert_eq!(line_column_offset(contents, 3, 100), contents.len());
+}
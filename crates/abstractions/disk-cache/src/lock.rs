@@ -0,0 +1,138 @@
+use crate::error::{DiskCacheError, DiskCacheResult};
+use crate::path_with_suffix;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Creates the lock file of the cache at `path`, recording the PID of the current process.
+///
+/// A lock file left behind by a process that is no longer running is reclaimed.
+pub(crate) fn acquire_lock_file(path: &Path) -> DiskCacheResult<()> {
+    use lazy_static::lazy_static;
+    use std::sync::Mutex;
+
+    lazy_static! {
+        static ref CLEANUP_HANDLER: Mutex<CleanupHandler> = Mutex::new(CleanupHandler::new());
+    }
+
+    struct CleanupHandler {
+        paths: Vec<PathBuf>,
+    }
+
+    impl CleanupHandler {
+        fn new() -> Self {
+            if let Err(e) = ctrlc::set_handler(move || {
+                for path in &CLEANUP_HANDLER.lock().unwrap().paths {
+                    let _ = fs::remove_file(lock_file_path(path));
+                }
+                std::process::exit(0);
+            }) {
+                eprintln!("Warning: Failed to set Ctrl+C handler: {}", e);
+            }
+
+            Self { paths: Vec::new() }
+        }
+
+        fn register(&mut self, path: PathBuf) {
+            self.paths.push(path);
+        }
+    }
+
+    let lock_path = lock_file_path(path);
+    let mut cleanup_handler = CLEANUP_HANDLER.lock().unwrap();
+    if !try_create_lock_file(&lock_path).map_err(|e| DiskCacheError::Io(lock_path.clone(), e))? {
+        match lock_owner(&lock_path) {
+            Some(pid) if is_process_alive(pid) => {
+                return Err(DiskCacheError::CacheFileLockedByAnotherProcess)
+            }
+            owner => {
+                match owner {
+                    Some(pid) => eprintln!(
+                        "Warning: reclaiming lock file {} of dead process {pid}",
+                        lock_path.display()
+                    ),
+                    None => eprintln!(
+                        "Warning: reclaiming lock file {} without owner",
+                        lock_path.display()
+                    ),
+                }
+                match fs::remove_file(&lock_path) {
+                    Ok(()) => (),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+                    Err(e) => return Err(DiskCacheError::Io(lock_path, e)),
+                }
+                // another process may have reclaimed it first
+                if !try_create_lock_file(&lock_path)
+                    .map_err(|e| DiskCacheError::Io(lock_path.clone(), e))?
+                {
+                    return Err(DiskCacheError::CacheFileLockedByAnotherProcess);
+                }
+            }
+        }
+    }
+
+    // Register this lock file with the cleanup handler
+    cleanup_handler.register(path.to_path_buf());
+
+    Ok(())
+}
+
+pub(crate) fn release_lock_file(path: &Path) {
+    fs::remove_file(lock_file_path(path)).unwrap();
+}
+
+/// Returns `Ok(false)` if the lock file already exists.
+///
+/// The PID is written to a temporary file which is then hard linked into place,
+/// so that other processes never observe a lock file without its owner.
+fn try_create_lock_file(lock_path: &Path) -> io::Result<bool> {
+    let pid = std::process::id();
+    let tmp_path = path_with_suffix(lock_path, &pid.to_string());
+    fs::File::create(&tmp_path)?.write_all(pid.to_string().as_bytes())?;
+    let result = fs::hard_link(&tmp_path, lock_path);
+    fs::remove_file(&tmp_path)?;
+    match result {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Lock files created by earlier versions are empty and have no owner.
+fn lock_owner(lock_path: &Path) -> Option<u32> {
+    fs::read_to_string(lock_path).ok()?.trim().parse().ok()
+}
+
+#[cfg(unix)]
+fn is_process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // signal 0 performs the permission and existence checks without sending anything
+    if unsafe { libc::kill(pid, 0) } == 0 {
+        return true;
+    }
+    io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Liveness can't be checked here, so locks are never reclaimed.
+#[cfg(not(unix))]
+fn is_process_alive(_pid: u32) -> bool {
+    true
+}
+
+pub(crate) fn lock_file_path(path: &Path) -> PathBuf {
+    path_with_suffix(path, "lock")
+}
+
+#[test]
+fn lock_file_path_works() {
+    assert_eq!(
+        lock_file_path(Path::new("path/to/cache")),
+        PathBuf::from("path/to/cache.lock")
+    );
+    assert_eq!(
+        lock_file_path(Path::new("path/to/cache.json")),
+        PathBuf::from("path/to/cache.json.lock")
+    );
+}
@@ -0,0 +1,12 @@
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiskCacheMode {
+    /// Locks the cache file and appends new responses to it.
+    #[default]
+    ReadWrite,
+    /// Shares the cache file with other processes; new responses are only kept in memory.
+    ReadOnly,
+    /// Shares the cache file with other processes; every miss is an error.
+    ///
+    /// Used to run deterministically without calling out to anything.
+    Replay,
+}
@@ -1,12 +1,16 @@
-use crate::error::DiskCacheResult;
+use crate::error::{DiskCacheError, DiskCacheResult};
+use crate::path_with_suffix;
 use crate::{entry::LlmCacheEntry, seed::IsDiskCacheSeed};
 use attach::Attach;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::{channel, Sender};
 use std::{fs, marker::PhantomData};
 
+/// The cache file is stored as JSON lines, one entry per line,
+/// so that saving a new entry only appends to the end of the file.
 pub struct LlmCacheSaveThread<Db, Seed, Request, Response>
 where
     Seed: IsDiskCacheSeed,
@@ -23,11 +27,18 @@ where
     Response: Serialize + DeserializeOwned + Clone + Send + 'static,
 {
     db: Db,
-    sender: Sender<String>,
+    sender: Sender<LlmCacheSaveMessage>,
     thread: std::thread::JoinHandle<()>,
     phantom: PhantomData<(Seed, Request, Response)>,
 }
 
+enum LlmCacheSaveMessage {
+    /// lines to be appended to the end of the file
+    Append(String),
+    /// full content replacing the file
+    Rewrite(String),
+}
+
 impl<Db, Seed, Request, Response> LlmCacheSaveThread<Db, Seed, Request, Response>
 where
     Db: Attach,
@@ -35,17 +46,17 @@ where
     Request: Serialize + DeserializeOwned + Eq + std::hash::Hash + Clone + Send + 'static,
     Response: Serialize + DeserializeOwned + Clone + Send + 'static,
 {
-    pub fn new(
-        db: Db,
-        path: PathBuf,
-        mut entries: Vec<LlmCacheEntry<Seed, Request, Response>>,
-    ) -> Self {
+    pub fn new(db: Db, path: PathBuf) -> Self {
         let (sender, receiver) = channel();
 
         let thread = std::thread::spawn(move || {
-            while let Ok(content) = receiver.recv() {
-                if let Err(e) = fs::write(&path, content) {
-                    todo!()
+            while let Ok(message) = receiver.recv() {
+                let result = match message {
+                    LlmCacheSaveMessage::Append(lines) => append(&path, &lines),
+                    LlmCacheSaveMessage::Rewrite(content) => rewrite(&path, &content),
+                };
+                if let Err(e) = result {
+                    eprintln!("Error saving cache file {}: {e}", path.display());
                 }
             }
         });
@@ -60,8 +71,18 @@ where
         }
     }
 
-    pub fn save(&self, entries: &[LlmCacheEntry<Seed, Request, Response>]) -> DiskCacheResult<()> {
-        self.internal.as_ref().unwrap().save(entries)
+    /// appends a single entry to the end of the cache file
+    pub fn append(&self, entry: &LlmCacheEntry<Seed, Request, Response>) -> DiskCacheResult<()> {
+        self.internal.as_ref().unwrap().append(entry)
+    }
+
+    /// replaces the whole cache file,
+    /// used when the file on disk is in the legacy format or contains garbage
+    pub fn rewrite(
+        &self,
+        entries: &[LlmCacheEntry<Seed, Request, Response>],
+    ) -> DiskCacheResult<()> {
+        self.internal.as_ref().unwrap().rewrite(entries)
     }
 }
 
@@ -72,17 +93,51 @@ where
     Request: Serialize + DeserializeOwned + Eq + std::hash::Hash + Clone + Send + 'static,
     Response: Serialize + DeserializeOwned + Clone + Send + 'static,
 {
-    fn save(&self, entries: &[LlmCacheEntry<Seed, Request, Response>]) -> DiskCacheResult<()> {
+    fn append(&self, entry: &LlmCacheEntry<Seed, Request, Response>) -> DiskCacheResult<()> {
+        let line = self.db.attach(|| entry_line(entry));
+        self.send(LlmCacheSaveMessage::Append(line))
+    }
+
+    fn rewrite(&self, entries: &[LlmCacheEntry<Seed, Request, Response>]) -> DiskCacheResult<()> {
+        let content = self
+            .db
+            .attach(|| entries.iter().map(entry_line).collect::<String>());
+        self.send(LlmCacheSaveMessage::Rewrite(content))
+    }
+
+    fn send(&self, message: LlmCacheSaveMessage) -> DiskCacheResult<()> {
         self.sender
-            .send(
-                self.db
-                    .attach(|| serde_json::to_string_pretty(entries).unwrap()),
-            )
-            .map_err(|_| todo!())?;
-        Ok(())
+            .send(message)
+            .map_err(|_| DiskCacheError::SaveThreadStopped)
     }
 }
 
+fn entry_line<Seed, Request, Response>(entry: &LlmCacheEntry<Seed, Request, Response>) -> String
+where
+    Seed: Serialize,
+    Request: Serialize,
+    Response: Serialize,
+{
+    let mut line = serde_json::to_string(entry).unwrap();
+    line.push('\n');
+    line
+}
+
+fn append(path: &Path, lines: &str) -> std::io::Result<()> {
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(lines.as_bytes())
+}
+
+/// writes to a temporary file first so that a crash never leaves a half-written cache file
+fn rewrite(path: &Path, content: &str) -> std::io::Result<()> {
+    let tmp_path = path_with_suffix(path, "tmp");
+    fs::write(&tmp_path, content)?;
+    fs::rename(&tmp_path, path)
+}
+
 impl<Db, Seed, Request, Response> Drop for LlmCacheSaveThread<Db, Seed, Request, Response>
 where
     Seed: IsDiskCacheSeed,
@@ -43,3 +43,323 @@ fn llm_cache_file_save_works() {
         assert_eq!(entries[0].response, "response");
     }
 }
+
+#[test]
+fn llm_cache_file_is_appended_line_by_line() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    {
+        let cache =
+            DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone())
+                .unwrap();
+        for i in 0..3 {
+            cache
+                .get_or_call::<DiskCacheError>((), format!("request{i}"), async |request| {
+                    Ok(request.replace("request", "response"))
+                })
+                .unwrap();
+        }
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 3);
+    {
+        let cache =
+            DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone())
+                .unwrap();
+        cache
+            .get_or_call::<DiskCacheError>((), "request3".to_string(), async |_| {
+                Ok("response3".to_string())
+            })
+            .unwrap();
+        assert_eq!(cache.entries.read().unwrap().len(), 4);
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 4);
+}
+
+#[test]
+fn llm_cache_legacy_file_is_migrated() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let legacy_entries = vec![LlmCacheEntry::new((), "a".to_string(), "A".to_string())];
+    fs::write(
+        &path,
+        serde_json::to_string_pretty(&legacy_entries).unwrap(),
+    )
+    .unwrap();
+    {
+        let cache =
+            DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone())
+                .unwrap();
+        let response = cache
+            .get_or_call::<DiskCacheError>((), "a".to_string(), async |_| unreachable!())
+            .unwrap();
+        assert_eq!(response, "A");
+        cache
+            .get_or_call::<DiskCacheError>((), "b".to_string(), async |_| Ok("B".to_string()))
+            .unwrap();
+    }
+    let contents = fs::read_to_string(&path).unwrap();
+    assert_eq!(contents.lines().count(), 2);
+    let cache =
+        DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    let entries = cache.entries.read().unwrap();
+    assert_eq!(entries[0].response, "A");
+    assert_eq!(entries[1].response, "B");
+}
+
+#[test]
+fn llm_cache_calls_for_different_keys_run_in_parallel() {
+    use std::sync::atomic::AtomicUsize;
+
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let cache =
+        DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    let running = AtomicUsize::new(0);
+    let max_running = AtomicUsize::new(0);
+    std::thread::scope(|s| {
+        for i in 0..2 {
+            let (cache, running, max_running) = (&cache, &running, &max_running);
+            s.spawn(move || {
+                cache
+                    .get_or_call::<DiskCacheError>((), format!("request{i}"), async |_| {
+                        let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_running.fetch_max(n, Ordering::SeqCst);
+                        std::thread::sleep(std::time::Duration::from_millis(200));
+                        running.fetch_sub(1, Ordering::SeqCst);
+                        Ok("response".to_string())
+                    })
+                    .unwrap();
+            });
+        }
+    });
+    assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    assert_eq!(cache.entries.read().unwrap().len(), 2);
+}
+
+#[test]
+fn llm_cache_calls_for_the_same_key_are_deduplicated() {
+    use std::sync::atomic::AtomicUsize;
+
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let cache =
+        DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    let calls = AtomicUsize::new(0);
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            let (cache, calls) = (&cache, &calls);
+            s.spawn(move || {
+                let response = cache
+                    .get_or_call::<DiskCacheError>((), "request".to_string(), async |_| {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        std::thread::sleep(std::time::Duration::from_millis(100));
+                        Ok("response".to_string())
+                    })
+                    .unwrap();
+                assert_eq!(response, "response");
+            });
+        }
+    });
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(cache.entries.read().unwrap().len(), 1);
+}
+
+#[test]
+fn llm_cache_failed_calls_leave_nothing_in_flight() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let cache =
+        DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    assert!(matches!(
+        cache.get_or_call::<DiskCacheError>((), "request".to_string(), async |_| {
+            Err(DiskCacheError::SaveThreadStopped)
+        }),
+        Err(DiskCacheError::SaveThreadStopped)
+    ));
+    assert!(cache.in_flight.is_empty());
+    assert!(cache.entries.read().unwrap().is_empty());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        cache.get_or_call::<DiskCacheError>((), "request".to_string(), async |_| panic!())
+    }));
+    assert!(result.is_err());
+    assert!(cache.in_flight.is_empty());
+    // the key can still be retried
+    let response = cache
+        .get_or_call::<DiskCacheError>((), "request".to_string(), async |_| {
+            Ok("response".to_string())
+        })
+        .unwrap();
+    assert_eq!(response, "response");
+    assert!(cache.in_flight.is_empty());
+}
+
+#[test]
+fn llm_cache_corruption_is_reported_and_backed_up() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    {
+        let cache =
+            DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone())
+                .unwrap();
+        for i in 0..2 {
+            cache
+                .get_or_call::<DiskCacheError>((), format!("request{i}"), async |_| {
+                    Ok("response".to_string())
+                })
+                .unwrap();
+        }
+    }
+    // simulate a crash in the middle of appending
+    let contents = fs::read_to_string(&path).unwrap();
+    let truncated = &contents[..contents.len() - 10];
+    fs::write(&path, truncated).unwrap();
+    let first_line_len = contents.lines().next().unwrap().len() + 1;
+    {
+        let cache =
+            DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone())
+                .unwrap();
+        assert_eq!(cache.entries.read().unwrap().len(), 1);
+        assert_eq!(cache.corruptions().len(), 1);
+        assert!(cache.corruptions()[0].offset >= first_line_len);
+        let backups: Vec<_> = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.to_str().unwrap().contains("corrupted"))
+            .collect();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(fs::read_to_string(&backups[0]).unwrap(), truncated);
+        cache
+            .get_or_call::<DiskCacheError>((), "request2".to_string(), async |_| {
+                Ok("response".to_string())
+            })
+            .unwrap();
+    }
+    let cache =
+        DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    assert!(cache.corruptions().is_empty());
+    assert_eq!(cache.entries.read().unwrap().len(), 2);
+}
+
+#[test]
+fn llm_cache_stale_lock_is_reclaimed() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let dead_pid = child.id();
+    child.wait().unwrap();
+    fs::write(lock_file_path(&path), dead_pid.to_string()).unwrap();
+    {
+        let _cache =
+            DiskCache::<(), (), (), ()>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+        assert_eq!(
+            fs::read_to_string(lock_file_path(&path)).unwrap(),
+            std::process::id().to_string()
+        );
+    }
+    // lock files of earlier versions carry no owner
+    fs::write(lock_file_path(&path), "").unwrap();
+    DiskCache::<(), (), (), ()>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    assert!(!lock_file_path(&path).exists());
+}
+
+#[test]
+fn llm_cache_lock_is_released_when_loading_fails() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    // a directory can't be read as a cache file
+    fs::create_dir(&path).unwrap();
+    assert!(matches!(
+        DiskCache::<(), (), (), ()>::new(db, tokio_runtime.clone(), path.clone()),
+        Err(DiskCacheError::Io(..))
+    ));
+    assert!(!lock_file_path(&path).exists());
+    fs::remove_dir(&path).unwrap();
+    DiskCache::<(), (), (), ()>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+}
+
+#[test]
+fn llm_cache_read_only_mode_works() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let cache =
+        DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    cache
+        .get_or_call::<DiskCacheError>((), "a".to_string(), async |_| Ok("A".to_string()))
+        .unwrap();
+    drop(cache);
+    let writer =
+        DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone()).unwrap();
+    let contents = fs::read_to_string(&path).unwrap();
+    {
+        let readers: Vec<_> = (0..2)
+            .map(|_| {
+                DiskCache::<(), (), String, String>::new_read_only(
+                    db,
+                    tokio_runtime.clone(),
+                    path.clone(),
+                )
+                .unwrap()
+            })
+            .collect();
+        for reader in &readers {
+            assert!(reader.is_read_only());
+            let response = reader
+                .get_or_call::<DiskCacheError>((), "a".to_string(), async |_| unreachable!())
+                .unwrap();
+            assert_eq!(response, "A");
+            reader
+                .get_or_call::<DiskCacheError>((), "b".to_string(), async |_| Ok("B".to_string()))
+                .unwrap();
+        }
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap(), contents);
+    assert!(lock_file_path(&path).exists());
+    drop(writer);
+    assert!(!lock_file_path(&path).exists());
+}
+
+#[test]
+fn llm_cache_replay_mode_works() {
+    let db = ();
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cache.json");
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    {
+        let cache =
+            DiskCache::<(), (), String, String>::new(db, tokio_runtime.clone(), path.clone())
+                .unwrap();
+        cache
+            .get_or_call::<DiskCacheError>((), "a".to_string(), async |_| Ok("A".to_string()))
+            .unwrap();
+    }
+    let cache =
+        DiskCache::<(), (), String, String>::new_replay(db, tokio_runtime.clone(), path.clone())
+            .unwrap();
+    assert_eq!(cache.mode(), DiskCacheMode::Replay);
+    let response = cache
+        .get_or_call::<DiskCacheError>((), "a".to_string(), async |_| unreachable!())
+        .unwrap();
+    assert_eq!(response, "A");
+    assert!(matches!(
+        cache.get_or_call::<DiskCacheError>((), "b".to_string(), async |_| unreachable!()),
+        Err(DiskCacheError::ReplayMiss { .. })
+    ));
+}
@@ -144,6 +144,7 @@ pub enum JarIndex {
     HoverJar,
     IdeFmtJar,
     InlayHintsJar,
+    NavigationJar,
     SemanticTokenJar,
     TokenInfoJar,
     // kernel
@@ -181,7 +182,9 @@ pub enum JarIndex {
     // namekian
     NamAstJar,
     // semantics
+    BorrowCheckerJar,
     SemExprJar,
+    SemFmtJar,
     SemItemPathDepsJar,
     SemPlaceContractJar,
     SemVarDepsJar,
@@ -195,6 +198,7 @@ pub enum JarIndex {
     SynDeclJar,
     SynDefnJar,
     SynExprJar,
+    SynFmtJar,
     TomlAstJar,
     CorgiConfigAstJar,
     // visored
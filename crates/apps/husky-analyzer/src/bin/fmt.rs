@@ -0,0 +1,37 @@
+use clap::Parser;
+use glob::glob;
+use husky_analyzer::fmt::fmt_files;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+struct Cli {
+    /// exit with failure instead of writing if any file isn't formatted
+    #[arg(long)]
+    check: bool,
+    src_files: Vec<String>,
+}
+
+impl Cli {
+    fn expanded_src_file_paths(&self) -> Vec<PathBuf> {
+        self.src_files
+            .iter()
+            .flat_map(|pattern| {
+                glob(pattern)
+                    .expect("Failed to read glob pattern")
+                    .filter_map(Result::ok)
+            })
+            .collect()
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match fmt_files(&cli.expanded_src_file_paths(), cli.check) {
+        Ok(true) => (),
+        Ok(false) => std::process::exit(1),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2)
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use husky_text_protocol::range::TextPositionRange;
+use husky_token::{TokenDb, TokenIdx};
 use husky_vfs::{jar::VfsDb, path::module_path::ModulePath};
 
 use crate::*;
@@ -48,3 +49,16 @@ pub(crate) fn module_path_and_range(
     let module_path = db.resolve_module_path_and_update_live_packages(&path)?;
     Ok((module_path, range.into()))
 }
+
+/// `None` if there is no token at the position
+pub(crate) fn module_path_and_token_idx(
+    db: &::salsa::Db,
+    params: &lsp_types::TextDocumentPositionParams,
+) -> Result<Option<(ModulePath, TokenIdx)>> {
+    let path = path_from_url(&params.text_document.uri)?;
+    let module_path = db.resolve_module_path_and_update_live_packages(&path)?;
+    Ok(db
+        .ranged_token_sheet(module_path)
+        .search_token_by_position(params.position.into())
+        .map(|token_idx| (module_path, token_idx)))
+}
@@ -1,6 +1,12 @@
 //! Conversion of husky-lang-server specific types to lsp_types equivalents.
 #![allow(warnings, dead_code)]
 use crate::lsp_ext;
+use husky_entity_kind::{
+    AssocItemKind, EntityKind, MajorFormKind, MajorItemKind, TraitItemKind, TypeItemKind, TypeKind,
+};
+use husky_navigation::{symbol::ItemSymbolKind, target::NavTarget};
+use husky_text_protocol::range::TextPositionRange;
+use husky_vfs::path::module_path::ModulePath;
 use lsp_types::SemanticToken;
 use std::{
     path::{self, Path},
@@ -93,3 +99,77 @@ impl From<lsp_ext::SnippetTextEdit>
         }
     }
 }
+
+pub(crate) fn location(db: &::salsa::Db, nav_target: NavTarget) -> Option<lsp_types::Location> {
+    module_location(db, nav_target.module_path(), nav_target.range())
+}
+
+pub(crate) fn module_location(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    range: TextPositionRange,
+) -> Option<lsp_types::Location> {
+    let path = module_path.abs_path(db)?;
+    Some(lsp_types::Location::new(
+        url_from_path(&path).ok()?,
+        range.into(),
+    ))
+}
+
+pub(crate) fn symbol_kind(kind: ItemSymbolKind) -> lsp_types::SymbolKind {
+    use lsp_types::SymbolKind;
+
+    match kind {
+        ItemSymbolKind::Entity(entity_kind) => match entity_kind {
+            EntityKind::Module => SymbolKind::MODULE,
+            EntityKind::MajorItem {
+                module_item_kind, ..
+            } => match module_item_kind {
+                MajorItemKind::Type(TypeKind::Enum | TypeKind::Inductive) => SymbolKind::ENUM,
+                MajorItemKind::Type(
+                    TypeKind::Record | TypeKind::Struct | TypeKind::Structure | TypeKind::Extern,
+                ) => SymbolKind::STRUCT,
+                MajorItemKind::Trait => SymbolKind::INTERFACE,
+                MajorItemKind::Form(form_kind) => match form_kind {
+                    MajorFormKind::Ritchie(_) => SymbolKind::FUNCTION,
+                    MajorFormKind::TypeAlias | MajorFormKind::TypeVar => SymbolKind::TYPE_PARAMETER,
+                    MajorFormKind::StaticMut | MajorFormKind::StaticVar => SymbolKind::VARIABLE,
+                    MajorFormKind::Val | MajorFormKind::Compterm | MajorFormKind::Conceptual => {
+                        SymbolKind::CONSTANT
+                    }
+                },
+            },
+            EntityKind::AssocItem { assoc_item_kind } => match assoc_item_kind {
+                AssocItemKind::TypeItem(ty_item_kind) => match ty_item_kind {
+                    TypeItemKind::MethodRitchie(_) => SymbolKind::METHOD,
+                    TypeItemKind::AssocRitchie(_) => SymbolKind::FUNCTION,
+                    TypeItemKind::AssocType => SymbolKind::TYPE_PARAMETER,
+                    TypeItemKind::MemoizedField => SymbolKind::FIELD,
+                    TypeItemKind::AssocStaticMut | TypeItemKind::AssocStaticVar => {
+                        SymbolKind::VARIABLE
+                    }
+                    TypeItemKind::AssocVal
+                    | TypeItemKind::AssocCompterm
+                    | TypeItemKind::AssocConceptual => SymbolKind::CONSTANT,
+                },
+                AssocItemKind::TraitItem(trai_item_kind)
+                | AssocItemKind::TraitForTypeItem(trai_item_kind) => match trai_item_kind {
+                    TraitItemKind::MethodRitchie(_) => SymbolKind::METHOD,
+                    TraitItemKind::AssocRitchie(_) => SymbolKind::FUNCTION,
+                    TraitItemKind::AssocType => SymbolKind::TYPE_PARAMETER,
+                    TraitItemKind::MemoizedField => SymbolKind::FIELD,
+                    TraitItemKind::AssocStaticMut | TraitItemKind::AssocStaticVar => {
+                        SymbolKind::VARIABLE
+                    }
+                    TraitItemKind::AssocVal
+                    | TraitItemKind::AssocCompterm
+                    | TraitItemKind::AssocConceptual => SymbolKind::CONSTANT,
+                },
+            },
+            EntityKind::TypeVariant => SymbolKind::ENUM_MEMBER,
+            EntityKind::ImplBlock => SymbolKind::OBJECT,
+            EntityKind::Attr | EntityKind::Script => SymbolKind::KEY,
+        },
+        ItemSymbolKind::Field => SymbolKind::FIELD,
+    }
+}
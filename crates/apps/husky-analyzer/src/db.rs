@@ -31,9 +31,12 @@ use salsa::snapshot::SnapshotClone;
     husky_syn_expr::jar::SynExprJar,
     husky_syn_decl::jar::SynDeclJar,
     husky_syn_defn::jar::SynDefnJar,
+    husky_syn_fmt::jar::SynFmtJar,
     // semantics
     husky_sem_expr::jar::SemExprJar,
     husky_sem_place_contract::jar::SemPlaceContractJar,
+    husky_borrow_checker::jar::BorrowCheckerJar,
+    husky_sem_fmt::jar::SemFmtJar,
     husky_sem_item_path_deps::jar::SemItemPathDepsJar,
     husky_sem_static_mut_deps::jar::SemStaticMutDepsJar,
     husky_sem_var_deps::jar::SemVarDepsJar,
@@ -46,6 +49,7 @@ use salsa::snapshot::SnapshotClone;
     husky_hover::HoverJar,
     husky_ide_fmt::jar::IdeFmtJar,
     husky_inlay_hints::jar::InlayHintsJar,
+    husky_navigation::jar::NavigationJar,
     husky_diagnostics::DiagnosticsJar,
     husky_code_lens::jar::CodeLensJar,
 )]
@@ -2,6 +2,7 @@ mod dispatch;
 mod send_updates;
 
 use crossbeam_channel::select;
+use husky_vfs::watch::DebounceEventResult;
 
 use std::error::Error;
 
@@ -10,12 +11,14 @@ use crate::server::{Server, TaskSet};
 use self::send_updates::send_updates;
 
 pub fn event_loop(connection: lsp_server::Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
-    let mut server = Server::new(connection.sender);
+    let mut server = Server::new(connection.sender)?;
     while let Some(event) = select! {
         recv(connection.receiver) -> msg =>
             msg.ok().map(Event::Lsp),
         recv(server.event_loop_comm.receiver) -> task =>
             Some(Event::Task(task.unwrap())),
+        recv(server.db.events()) -> res =>
+            Some(Event::VfsWatch(res.unwrap())),
     } {
         let loop_start = std::time::Instant::now();
         let task = match event {
@@ -23,13 +26,27 @@ pub fn event_loop(connection: lsp_server::Connection) -> Result<(), Box<dyn Erro
                 dispatch::dispatch_lsp_msg(&mut server, msg, loop_start).expect("todo")
             }
             Event::Task(task) => task,
+            Event::VfsWatch(res) => match server.db.refresh_with(res) {
+                Ok(changed_paths) if changed_paths.is_empty() => TaskSet::Nothing,
+                Ok(_) => TaskSet::SendUpdates,
+                Err(e) => {
+                    tracing::warn!("failed to refresh files changed on disk: {e}");
+                    TaskSet::Nothing
+                }
+            },
         };
         match task {
             TaskSet::Nothing => (),
             TaskSet::Shutdown => {
                 return Ok(());
             } // exit peacefully
-            TaskSet::SendUpdates => send_updates(&server.db, &server.client_comm),
+            TaskSet::SendUpdates => {
+                // live packages are added as documents get opened
+                if let Err(e) = server.db.watch_live_packages() {
+                    tracing::warn!("failed to watch live packages: {e}")
+                }
+                send_updates(&server.db, &server.client_comm)
+            }
             TaskSet::Respond(response) => server.client_comm.respond(response),
         }
     }
@@ -40,4 +57,5 @@ pub fn event_loop(connection: lsp_server::Connection) -> Result<(), Box<dyn Erro
 pub enum Event {
     Lsp(lsp_server::Message),
     Task(TaskSet),
+    VfsWatch(DebounceEventResult),
 }
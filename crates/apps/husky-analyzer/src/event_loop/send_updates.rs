@@ -5,6 +5,7 @@ use husky_vfs::jar::VfsDb;
 pub(crate) fn send_updates(db: &AnalyzerDB, comm: &ClientCommunicator) {
     let live_packages = db.live_packages().unwrap();
     for package_path in live_packages.iter() {
+        comm.send_manifest_diagnostics(db, *package_path);
         let probable_modules = db.collect_probable_modules(*package_path);
         for module_path in probable_modules {
             comm.send_diagnostics(db, module_path);
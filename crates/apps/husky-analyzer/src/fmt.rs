@@ -0,0 +1,42 @@
+//! formatting files from the command line, the check mode is meant for CI
+use crate::*;
+use husky_sem_fmt::{is_module_formatted, module_sem_fmt};
+use husky_vfs::jar::VfsDb;
+use std::path::PathBuf;
+
+/// returns whether all files are formatted, or got formatted when not in check mode;
+/// files that can't be formatted without changing their meaning count as not formatted
+pub fn fmt_files(paths: &[PathBuf], check: bool) -> Result<bool> {
+    let db = AnalyzerDB::default();
+    let mut all_formatted = true;
+    for path in paths {
+        let path = std::fs::canonicalize(path)?;
+        let module_path = db.resolve_module_path_and_update_live_packages(&path)?;
+        if check {
+            match is_module_formatted(&db, module_path) {
+                Ok(true) => (),
+                Ok(false) => {
+                    println!("{} is not formatted", path.display());
+                    all_formatted = false
+                }
+                Err(e) => {
+                    println!("{} can't be formatted: {e}", path.display());
+                    all_formatted = false
+                }
+            }
+        } else {
+            match module_sem_fmt(&db, module_path) {
+                Ok(formatted) => {
+                    if formatted != module_path.raw_text(&db) {
+                        std::fs::write(&path, formatted)?
+                    }
+                }
+                Err(e) => {
+                    println!("{} can't be formatted: {e}", path.display());
+                    all_formatted = false
+                }
+            }
+        }
+    }
+    Ok(all_formatted)
+}
@@ -1,17 +1,26 @@
 //! todo: there should be tests, and there should be submodules
 mod code_lens;
+mod fmt;
+mod goto;
+mod references;
 pub(crate) mod semantic_tokens;
+mod symbol;
 
 pub(crate) use code_lens::*;
 use convert::to_proto;
+pub(crate) use fmt::*;
+pub(crate) use goto::*;
 use husky_inlay_hints::lsp_support::HasLspInlayHints;
+pub(crate) use references::*;
 pub(crate) use semantic_tokens::*;
+pub(crate) use symbol::*;
 
 use crate::{convert::from_proto, *};
 use crate::{
     db::AnalyzerDBSnapshot,
     lsp_ext::{InlayHintsParams, WorkspaceSymbolParams},
 };
+use husky_completion::HuskyCompletionQuery;
 use husky_folding_range::FoldingRangeDb;
 use husky_hover::{HoverDb, HoverResult};
 use husky_semantic_token::SemanticTokenDb;
@@ -59,22 +68,6 @@ pub(crate) fn handle_on_type_formatting(
     Ok(None)
 }
 
-pub(crate) fn handle_document_symbol(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::DocumentSymbolParams,
-) -> Result<Option<lsp_types::DocumentSymbolResponse>> {
-    msg_once!("todo handle_document_symbol");
-    Ok(None)
-}
-
-pub(crate) fn handle_workspace_symbol(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: WorkspaceSymbolParams,
-) -> Result<Option<Vec<SymbolInformation>>> {
-    msg_once!("todo handle workspace symbol!");
-    Ok(None)
-}
-
 pub(crate) fn handle_will_rename_files(
     _snapshot: AnalyzerDBSnapshot,
     _params: lsp_types::RenameFilesParams,
@@ -83,30 +76,6 @@ pub(crate) fn handle_will_rename_files(
     Ok(None)
 }
 
-pub(crate) fn handle_goto_definition(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::GotoDefinitionParams,
-) -> Result<Option<lsp_types::GotoDefinitionResponse>> {
-    msg_once!("todo goto definition!");
-    Ok(None)
-}
-
-pub(crate) fn handle_goto_declaration(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::request::GotoDeclarationParams,
-) -> Result<Option<lsp_types::request::GotoDeclarationResponse>> {
-    msg_once!("todo goto declaration!");
-    Ok(None)
-}
-
-pub(crate) fn handle_goto_type_definition(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::request::GotoTypeDefinitionParams,
-) -> Result<Option<lsp_types::request::GotoTypeDefinitionResponse>> {
-    msg_once!("todo goto type definition!");
-    Ok(None)
-}
-
 pub(crate) fn handle_parent_module(
     _snapshot: AnalyzerDBSnapshot,
     _params: lsp_types::TextDocumentPositionParams,
@@ -116,14 +85,14 @@ pub(crate) fn handle_parent_module(
 }
 
 pub(crate) fn handle_completion(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::CompletionParams,
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::CompletionParams,
 ) -> Result<Option<lsp_types::CompletionResponse>> {
-    msg_once!("todo handle completion!");
-    Ok(None)
-    // let position = FilePosition::from_proto(&*comptime, &params.text_document_position);
-    // let completion_trigger_character = params.context.and_then(|ctx| ctx.trigger_character);
-    // Ok(snapshot.completion(position, completion_trigger_character))
+    let path = from_proto::path_from_url(&params.text_document_position.text_document.uri)?;
+    let module_path = snapshot.resolve_module_path_and_update_live_packages(&path)?;
+    let position: TextPosition = params.text_document_position.position.into();
+    let trigger_character = params.context.and_then(|context| context.trigger_character);
+    Ok(snapshot.completion(module_path, position, trigger_character.as_deref()))
 }
 
 pub(crate) fn handle_completion_resolve(
@@ -165,46 +134,6 @@ pub(crate) fn handle_hover(
     Ok(snapshot.hover_result(module_path, position))
 }
 
-pub(crate) fn handle_prepare_rename(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::TextDocumentPositionParams,
-) -> Result<Option<PrepareRenameResponse>> {
-    msg_once!("todo handle prepare rename!");
-    Ok(None)
-}
-
-pub(crate) fn handle_rename(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: RenameParams,
-) -> Result<Option<WorkspaceEdit>> {
-    msg_once!("todo handle rename!");
-    Ok(None)
-}
-
-pub(crate) fn handle_references(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::ReferenceParams,
-) -> Result<Option<Vec<Location>>> {
-    msg_once!("todo handle references!");
-    Ok(None)
-}
-
-pub(crate) fn handle_formatting(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: DocumentFormattingParams,
-) -> Result<Option<Vec<lsp_types::TextEdit>>> {
-    msg_once!("todo handle formatting!");
-    Ok(None)
-}
-
-pub(crate) fn handle_range_formatting(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::DocumentRangeFormattingParams,
-) -> Result<Option<Vec<lsp_types::TextEdit>>> {
-    msg_once!("todo handle range formatting!");
-    Ok(None)
-}
-
 pub(crate) fn handle_code_action(
     _snapshot: AnalyzerDBSnapshot,
     _params: lsp_types::CodeActionParams,
@@ -221,14 +150,6 @@ pub(crate) fn handle_code_action_resolve(
     Ok(lsp_ext::CodeAction::default())
 }
 
-pub(crate) fn handle_document_highlight(
-    _snapshot: AnalyzerDBSnapshot,
-    _params: lsp_types::DocumentHighlightParams,
-) -> Result<Option<Vec<lsp_types::DocumentHighlight>>> {
-    msg_once!("todo handle document highlight!");
-    Ok(None)
-}
-
 pub(crate) fn handle_ssr(
     _snapshot: AnalyzerDBSnapshot,
     _params: lsp_ext::SsrParams,
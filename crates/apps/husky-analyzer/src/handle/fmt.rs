@@ -0,0 +1,36 @@
+use super::*;
+use husky_ide_fmt::{module_fmt_edits, FmtEdit};
+
+pub(crate) fn handle_formatting(
+    snapshot: AnalyzerDBSnapshot,
+    params: DocumentFormattingParams,
+) -> Result<Option<Vec<lsp_types::TextEdit>>> {
+    let db: &::salsa::Db = &snapshot;
+    let path = from_proto::path_from_url(&params.text_document.uri)?;
+    let module_path = snapshot.resolve_module_path_and_update_live_packages(&path)?;
+    // modules that can't be formatted without changing their meaning are left alone
+    let Ok(edits) = module_fmt_edits(db, module_path, None) else {
+        return Ok(None);
+    };
+    Ok(Some(edits.iter().map(text_edit).collect()))
+}
+
+pub(crate) fn handle_range_formatting(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::DocumentRangeFormattingParams,
+) -> Result<Option<Vec<lsp_types::TextEdit>>> {
+    let db: &::salsa::Db = &snapshot;
+    let path = from_proto::path_from_url(&params.text_document.uri)?;
+    let module_path = snapshot.resolve_module_path_and_update_live_packages(&path)?;
+    let Ok(edits) = module_fmt_edits(db, module_path, Some(params.range.into())) else {
+        return Ok(None);
+    };
+    Ok(Some(edits.iter().map(text_edit).collect()))
+}
+
+fn text_edit(edit: &FmtEdit) -> lsp_types::TextEdit {
+    lsp_types::TextEdit {
+        range: edit.range().into(),
+        new_text: edit.new_text().to_string(),
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+use husky_navigation::{target::NavTarget, NavigationDb};
+use husky_token::TokenIdx;
+use husky_vfs::path::module_path::ModulePath;
+
+pub(crate) fn handle_goto_definition(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::GotoDefinitionParams,
+) -> Result<Option<lsp_types::GotoDefinitionResponse>> {
+    goto(
+        &snapshot,
+        &params.text_document_position_params,
+        |db, module_path, token_idx| db.definition_nav_target(module_path, token_idx),
+    )
+}
+
+pub(crate) fn handle_goto_declaration(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::request::GotoDeclarationParams,
+) -> Result<Option<lsp_types::request::GotoDeclarationResponse>> {
+    goto(
+        &snapshot,
+        &params.text_document_position_params,
+        |db, module_path, token_idx| db.declaration_nav_target(module_path, token_idx),
+    )
+}
+
+pub(crate) fn handle_goto_type_definition(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::request::GotoTypeDefinitionParams,
+) -> Result<Option<lsp_types::request::GotoTypeDefinitionResponse>> {
+    goto(
+        &snapshot,
+        &params.text_document_position_params,
+        |db, module_path, token_idx| db.ty_definition_nav_target(module_path, token_idx),
+    )
+}
+
+fn goto(
+    db: &::salsa::Db,
+    params: &lsp_types::TextDocumentPositionParams,
+    f: impl FnOnce(&::salsa::Db, ModulePath, TokenIdx) -> Option<NavTarget>,
+) -> Result<Option<lsp_types::GotoDefinitionResponse>> {
+    let Some((module_path, token_idx)) = from_proto::module_path_and_token_idx(db, params)? else {
+        return Ok(None);
+    };
+    Ok(f(db, module_path, token_idx)
+        .and_then(|nav_target| to_proto::location(db, nav_target))
+        .map(lsp_types::GotoDefinitionResponse::Scalar))
+}
@@ -0,0 +1,109 @@
+use super::*;
+use husky_navigation::{
+    definition::token_definition,
+    rename::{prepare_rename, rename},
+    usage::{definition_usages, HasModuleUsageSheet, UsageKind},
+};
+use husky_token::TokenDb;
+use std::collections::HashMap;
+
+pub(crate) fn handle_references(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::ReferenceParams,
+) -> Result<Option<Vec<Location>>> {
+    let db: &::salsa::Db = &snapshot;
+    let Some((module_path, token_idx)) =
+        from_proto::module_path_and_token_idx(db, &params.text_document_position)?
+    else {
+        return Ok(None);
+    };
+    let Some(definition) = token_definition(db, module_path, token_idx) else {
+        return Ok(None);
+    };
+    let include_declaration = params.context.include_declaration;
+    Ok(Some(
+        definition_usages(db, definition)
+            .into_iter()
+            .filter(|(_, usage)| include_declaration || usage.kind() != UsageKind::Definition)
+            .filter_map(|(module_path, usage)| {
+                to_proto::module_location(
+                    db,
+                    module_path,
+                    db.ranged_token_sheet(module_path)
+                        .token_text_range(usage.token_idx()),
+                )
+            })
+            .collect(),
+    ))
+}
+
+pub(crate) fn handle_document_highlight(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::DocumentHighlightParams,
+) -> Result<Option<Vec<lsp_types::DocumentHighlight>>> {
+    let db: &::salsa::Db = &snapshot;
+    let Some((module_path, token_idx)) =
+        from_proto::module_path_and_token_idx(db, &params.text_document_position_params)?
+    else {
+        return Ok(None);
+    };
+    let Some(definition) = token_definition(db, module_path, token_idx) else {
+        return Ok(None);
+    };
+    let ranged_token_sheet = db.ranged_token_sheet(module_path);
+    Ok(Some(
+        module_path
+            .usage_sheet(db)
+            .definition_usages(definition)
+            .map(|usage| lsp_types::DocumentHighlight {
+                range: ranged_token_sheet
+                    .token_text_range(usage.token_idx())
+                    .into(),
+                kind: Some(match usage.kind() {
+                    UsageKind::Definition | UsageKind::Write => {
+                        lsp_types::DocumentHighlightKind::WRITE
+                    }
+                    UsageKind::Read => lsp_types::DocumentHighlightKind::READ,
+                }),
+            })
+            .collect(),
+    ))
+}
+
+pub(crate) fn handle_prepare_rename(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>> {
+    let db: &::salsa::Db = &snapshot;
+    let Some((module_path, token_idx)) = from_proto::module_path_and_token_idx(db, &params)? else {
+        return Ok(None);
+    };
+    let (_, range) = prepare_rename(db, module_path, token_idx)?;
+    Ok(Some(PrepareRenameResponse::Range(range.into())))
+}
+
+pub(crate) fn handle_rename(
+    snapshot: AnalyzerDBSnapshot,
+    params: RenameParams,
+) -> Result<Option<WorkspaceEdit>> {
+    let db: &::salsa::Db = &snapshot;
+    let Some((module_path, token_idx)) =
+        from_proto::module_path_and_token_idx(db, &params.text_document_position)?
+    else {
+        return Ok(None);
+    };
+    let mut changes: HashMap<lsp_types::Url, Vec<lsp_types::TextEdit>> = HashMap::default();
+    for (module_path, range) in rename(db, module_path, token_idx, &params.new_name)? {
+        let Some(location) = to_proto::module_location(db, module_path, range) else {
+            continue;
+        };
+        changes
+            .entry(location.uri)
+            .or_default()
+            .push(lsp_types::TextEdit::new(
+                location.range,
+                params.new_name.clone(),
+            ))
+    }
+    Ok(Some(WorkspaceEdit::new(changes)))
+}
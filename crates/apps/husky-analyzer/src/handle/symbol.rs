@@ -0,0 +1,63 @@
+use super::*;
+use crate::lsp_ext::WorkspaceSymbolSearchKind;
+use husky_navigation::symbol::{module_item_symbols, workspace_symbols, ItemSymbol};
+
+pub(crate) fn handle_document_symbol(
+    snapshot: AnalyzerDBSnapshot,
+    params: lsp_types::DocumentSymbolParams,
+) -> Result<Option<lsp_types::DocumentSymbolResponse>> {
+    let db: &::salsa::Db = &snapshot;
+    let path = from_proto::path_from_url(&params.text_document.uri)?;
+    let module_path = snapshot.resolve_module_path_and_update_live_packages(&path)?;
+    Ok(Some(lsp_types::DocumentSymbolResponse::Nested(
+        module_item_symbols(db, module_path)
+            .iter()
+            .map(document_symbol)
+            .collect(),
+    )))
+}
+
+fn document_symbol(item_symbol: &ItemSymbol) -> lsp_types::DocumentSymbol {
+    #[allow(deprecated)]
+    lsp_types::DocumentSymbol {
+        name: item_symbol.name().to_string(),
+        detail: None,
+        kind: to_proto::symbol_kind(item_symbol.kind()),
+        tags: None,
+        deprecated: None,
+        range: item_symbol.range().into(),
+        selection_range: item_symbol.selection_range().into(),
+        children: match item_symbol.children().is_empty() {
+            true => None,
+            false => Some(item_symbol.children().iter().map(document_symbol).collect()),
+        },
+    }
+}
+
+pub(crate) fn handle_workspace_symbol(
+    snapshot: AnalyzerDBSnapshot,
+    params: WorkspaceSymbolParams,
+) -> Result<Option<Vec<SymbolInformation>>> {
+    let db: &::salsa::Db = &snapshot;
+    let only_tys = params.search_kind == Some(WorkspaceSymbolSearchKind::OnlyTypes);
+    Ok(Some(
+        workspace_symbols(db, &params.query, only_tys)
+            .into_iter()
+            .filter_map(|symbol| {
+                #[allow(deprecated)]
+                Some(SymbolInformation {
+                    name: symbol.name().to_string(),
+                    kind: to_proto::symbol_kind(symbol.kind()),
+                    tags: None,
+                    deprecated: None,
+                    location: to_proto::module_location(
+                        db,
+                        symbol.module_path(),
+                        symbol.selection_range(),
+                    )?,
+                    container_name: symbol.container_name().map(ToString::to_string),
+                })
+            })
+            .collect(),
+    ))
+}
@@ -1,6 +1,7 @@
 mod convert;
 mod db;
 mod event_loop;
+pub mod fmt;
 mod handle;
 mod init_connection;
 mod lsp_ext;
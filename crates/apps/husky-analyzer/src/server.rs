@@ -5,25 +5,27 @@ use crate::*;
 use client_comm::ClientCommunicator;
 use crossbeam_channel::Sender;
 use event_loop_comm::EventLoopCommunicator;
+use husky_vfs::{error::VfsResult, watch::WatchedVfs};
 
 use threadpool::ThreadPool;
 
 pub(crate) struct Server {
     pub(crate) client_comm: ClientCommunicator,
     pub(crate) event_loop_comm: EventLoopCommunicator,
-    pub(crate) db: AnalyzerDB,
+    /// picks up the changes made on disk outside the editor, like git checkouts
+    pub(crate) db: WatchedVfs<AnalyzerDB>,
     pub(crate) threadpool: ThreadPool,
 }
 
 impl Server {
-    pub fn new(sender: Sender<lsp_server::Message>) -> Server {
-        Server {
+    pub fn new(sender: Sender<lsp_server::Message>) -> VfsResult<Server> {
+        Ok(Server {
             client_comm: ClientCommunicator::new(sender),
             threadpool: ThreadPool::default(),
             event_loop_comm: EventLoopCommunicator::default(),
             // ad hoc
-            db: Default::default(),
-        }
+            db: WatchedVfs::new(Default::default())?,
+        })
     }
 }
 
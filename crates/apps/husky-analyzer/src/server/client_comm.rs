@@ -4,7 +4,7 @@ use crossbeam_channel::Sender;
 
 use dashmap::{mapref::entry::Entry, DashMap};
 use husky_diagnostics::DiagnosticsDb;
-use husky_vfs::path::module_path::ModulePath;
+use husky_vfs::path::{module_path::ModulePath, package_path::PackagePath};
 use lsp_types::notification::Notification;
 
 use crate::{convert::to_proto::url_from_path, db::AnalyzerDB};
@@ -15,6 +15,7 @@ pub(crate) struct ClientCommunicator {
     pub(crate) sender: Sender<lsp_server::Message>,
     pub(crate) req_queue: ReqQueue,
     diagnostics_sent: DashMap<ModulePath, Vec<lsp_types::Diagnostic>>,
+    manifest_diagnostics_sent: DashMap<PackagePath, Vec<lsp_types::Diagnostic>>,
 }
 
 pub(crate) type ReqHandler = fn(&mut Server, lsp_server::Response);
@@ -26,6 +27,7 @@ impl ClientCommunicator {
             sender,
             req_queue: Default::default(),
             diagnostics_sent: Default::default(),
+            manifest_diagnostics_sent: Default::default(),
         }
     }
     fn send(&self, message: lsp_server::Message) {
@@ -46,23 +48,7 @@ impl ClientCommunicator {
             .diagnostic_iter(db)
             .map(|diagnostic| diagnostic.into())
             .collect();
-        let send_flag = match self.diagnostics_sent.entry(module_path) {
-            Entry::Occupied(mut entry) => {
-                let is_same = entry.get() == &diagnostics;
-                match is_same {
-                    true => false,
-                    false => {
-                        entry.insert(diagnostics.clone());
-                        true
-                    }
-                }
-            }
-            Entry::Vacant(entry) => {
-                entry.insert(diagnostics.clone());
-                true
-            }
-        };
-        if send_flag {
+        if is_new(&self.diagnostics_sent, module_path, &diagnostics) {
             if let Some(virtual_path) = module_path.virtual_path(db) {
                 let path = virtual_path.abs_path(db).unwrap();
                 match url_from_path(&path) {
@@ -75,6 +61,32 @@ impl ClientCommunicator {
         }
     }
 
+    /// diagnostics of `Corgi.toml`, like unresolvable or conflicting dependencies
+    pub(crate) fn send_manifest_diagnostics(&self, db: &AnalyzerDB, package_path: PackagePath) {
+        let diagnostics: Vec<lsp_types::Diagnostic> = db
+            .manifest_diagnostic_sheet(package_path)
+            .diagnostics(db)
+            .iter()
+            .map(|diagnostic| diagnostic.into())
+            .collect();
+        if is_new(&self.manifest_diagnostics_sent, package_path, &diagnostics) {
+            let Ok(path) = package_path
+                .manifest_path(db)
+                .and_then(|manifest_path| manifest_path.path().abs_path(db))
+            else {
+                eprintln!(
+                    "error in locating the manifest of `{}`",
+                    package_path.name_str(db)
+                );
+                return;
+            };
+            match url_from_path(&path) {
+                Ok(url) => self.send_diagnostics_aux(url, diagnostics, None),
+                Err(_) => eprintln!("error in translating path {:?}", path),
+            }
+        }
+    }
+
     fn send_diagnostics_aux(
         &self,
         url: lsp_types::Url,
@@ -134,3 +146,27 @@ impl ClientCommunicator {
         self.show_message(lsp_types::MessageType::ERROR, message)
     }
 }
+
+/// records `diagnostics` as the ones sent for `key`, returns `false` if they're the same as last time
+fn is_new<K: Eq + std::hash::Hash>(
+    sent: &DashMap<K, Vec<lsp_types::Diagnostic>>,
+    key: K,
+    diagnostics: &Vec<lsp_types::Diagnostic>,
+) -> bool {
+    match sent.entry(key) {
+        Entry::Occupied(mut entry) => {
+            let is_same = entry.get() == diagnostics;
+            match is_same {
+                true => false,
+                false => {
+                    entry.insert(diagnostics.clone());
+                    true
+                }
+            }
+        }
+        Entry::Vacant(entry) => {
+            entry.insert(diagnostics.clone());
+            true
+        }
+    }
+}
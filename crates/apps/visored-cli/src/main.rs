@@ -12,6 +12,12 @@ use visored_pipeline::{error::VdPipelineResult, runner::VdPipelineRunner};
 struct Cli {
     #[arg(short, long, default_value = "visored-config.yaml")]
     config: PathBuf,
+    /// Reports of each config go to `<output-dir>/<output_name>`.
+    ///
+    /// Defaults to the directory of the config file,
+    /// so that each config's reports end up in a directory named after its `output_name` next to it.
+    #[arg(short, long)]
+    output_dir: Option<PathBuf>,
     src_files: Vec<String>,
 }
 
@@ -26,6 +32,17 @@ impl Cli {
             })
             .collect()
     }
+
+    fn output_dir(&self) -> PathBuf {
+        match self.output_dir {
+            Some(ref output_dir) => output_dir.clone(),
+            None => self
+                .config
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+        }
+    }
 }
 
 fn main() {
@@ -33,6 +50,7 @@ fn main() {
     let cli = Cli::parse();
     let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
     let src_file_paths = cli.expanded_src_file_paths();
+    let output_dir = cli.output_dir();
     let specs_dir: PathBuf = todo!();
     let lean4_dir = todo!();
     let src_root = todo!();
@@ -44,6 +62,7 @@ fn main() {
         cli.config,
         src_file_paths,
         src_root,
+        output_dir,
     ) {
         Ok(_) => (),
         Err(e) => eprintln!("Error: {}", e),
@@ -59,6 +78,7 @@ fn run(
     config_path: PathBuf,
     src_file_paths: Vec<PathBuf>,
     src_root: &Path,
+    output_dir: PathBuf,
 ) -> VdPipelineResult<()> {
     let mut runner = VdPipelineRunner::new(
         db,
@@ -68,7 +88,8 @@ fn run(
         config_path,
         src_file_paths,
         src_root,
-    )?;
+    )?
+    .with_output_dir(output_dir);
     runner.run_all_single_threaded(AlienSeed::new(0))?;
     Ok(())
 }
@@ -23,12 +23,13 @@ use husky_vfs::{
         linktime_target_path::LinktimeTargetPath,
         package_path::PackagePath,
     },
+    watch::WatchedVfs,
 };
 use std::path::Path;
 
 pub struct DevComptime<Devsoul: IsDevsoul> {
     // TODO: put these two together?
-    db: DevComptimeDb,
+    db: WatchedVfs<DevComptimeDb>,
     target: DevComptimeTarget,
     target_path: Option<LinktimeTargetPath>,
     linktime: Devsoul::Linktime,
@@ -44,7 +45,7 @@ pub enum DevComptimeTarget {
 impl<Devsoul: IsDevsoul> DevComptime<Devsoul> {
     pub fn new(target_crate_path: impl AsRef<Path>) -> VfsResult<Self> {
         let target_crate_path = target_crate_path.as_ref();
-        let db = DevComptimeDb::default();
+        let mut db = WatchedVfs::new(DevComptimeDb::default())?;
         let toolchain = toolchain_config(target_crate_path, &*db).toolchain();
         let target_package_path =
             match PackagePath::new_local_or_toolchain_package(&db, toolchain, target_crate_path) {
@@ -60,6 +61,8 @@ impl<Devsoul: IsDevsoul> DevComptime<Devsoul> {
         else {
             todo!()
         };
+        let target_package_dir = target_package_path.dir(&db)?;
+        db.watch(target_package_dir.data())?;
         let target = DevComptimeTarget::SingleCrate(target_crate_path);
         let target_path = match target {
             DevComptimeTarget::None => None,
@@ -91,6 +94,13 @@ impl<Devsoul: IsDevsoul> DevComptime<Devsoul> {
     pub fn linktime(&self) -> &Devsoul::Linktime {
         &self.linktime
     }
+
+    /// Picks up the changes made on disk to the target package since the last refresh.
+    ///
+    /// Returns whether any source changed.
+    pub fn refresh_from_disk(&mut self) -> VfsResult<bool> {
+        Ok(!self.db.refresh()?.is_empty())
+    }
 }
 
 impl<Devsoul: IsDevsoul> DevComptime<Devsoul> {
@@ -112,17 +122,3 @@ impl<Devsoul: IsDevsoul> DevComptime<Devsoul> {
         &self.db
     }
 }
-
-impl<Devsoul: IsDevsoul> Default for DevComptime<Devsoul>
-where
-    Devsoul::Linktime: Default,
-{
-    fn default() -> Self {
-        Self {
-            target: DevComptimeTarget::None,
-            db: Default::default(),
-            linktime: Default::default(),
-            target_path: None,
-        }
-    }
-}
@@ -0,0 +1,62 @@
+use crate::*;
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __capture_copyable__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn capture_copyable() -> i32 {
+    let a: i32 = 2;
+    let f = |x: i32|x + a;
+    f(1)
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __apply__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+    f(x)
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __closure_as_argument__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn closure_as_argument() -> i32 {
+    let a: i32 = 2;
+    let f = |x: i32|x * a;
+    crate::closure::apply(f, 3)
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __closure_capturing_closure__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn closure_capturing_closure() -> i32 {
+    let a: i32 = 1;
+    let g = |y: i32|y + a;
+    let f = |x: i32|( {
+        g(x) + x
+    });
+    f(100)
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __capture_non_copyable__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn capture_non_copyable() -> i32 {
+    let v: Vec<i32> = vec![1, 2, 3];
+    let f = |x: i32|x + v.ilen();
+    f(1)
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __capture_non_copyable_by_reference__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn capture_non_copyable_by_reference() -> i32 {
+    let v: Vec<i32> = vec![1, 2, 3];
+    let f = |x: i32|x * v.ilen();
+    f(1) + f(2) + v.ilen()
+}
\ No newline at end of file
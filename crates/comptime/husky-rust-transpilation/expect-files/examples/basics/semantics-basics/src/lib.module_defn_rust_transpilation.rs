@@ -2,6 +2,12 @@
 use husky_core::*;
 use ad_hoc_devsoul_dependency::{*, ugly::*};
 
+pub mod closure;
+pub mod pattern;
+
+pub use self::closure::*;
+pub use self::pattern::*;
+
 
 #[rustfmt::skip]
 #[allow(non_upper_case_globals)]
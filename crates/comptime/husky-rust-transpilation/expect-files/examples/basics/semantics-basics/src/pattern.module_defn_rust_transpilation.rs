@@ -0,0 +1,79 @@
+use crate::*;
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __Shape__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+#[ad_hoc_devsoul_dependency::value_conversion]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Point,
+    Circle,
+    Rect,
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __exhaustive__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn exhaustive(shape: &crate::pattern::Shape) -> i32 {
+    match shape{
+        crate::pattern::Shape::Point => {
+            0
+        }
+        crate::pattern::Shape::Circle => {
+            1
+        }
+        crate::pattern::Shape::Rect => {
+            2
+        }
+    }
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __missing_variant__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn missing_variant(shape: &crate::pattern::Shape) -> i32 {
+    match shape{
+        crate::pattern::Shape::Point => {
+            0
+        }
+        crate::pattern::Shape::Circle => {
+            1
+        }
+    }
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __unreachable_variant__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn unreachable_variant(shape: &crate::pattern::Shape) -> i32 {
+    match shape{
+        crate::pattern::Shape::Point => {
+            0
+        }
+        _ => {
+            1
+        }
+        crate::pattern::Shape::Rect => {
+            2
+        }
+    }
+}
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+pub static mut __missing_integer__ITEM_PATH_ID_INTERFACE: Option<__ItemPathIdInterface> = None;
+
+#[rustfmt::skip]
+pub fn missing_integer(n: i32) -> i32 {
+    match n{
+        0 => {
+            0
+        }
+        1 => {
+            1
+        }
+    }
+}
\ No newline at end of file
@@ -13,9 +13,10 @@ pub(crate) use self::keyword::*;
 pub(crate) use self::macro_name::*;
 pub(crate) use self::punctuation::*;
 
-use crate::{expr::role::HirEagerExprRole, *};
+use crate::{error::RustTranspilationError, expr::role::HirEagerExprRole, *};
 use husky_corgi_config::transpilation_setup::{RustTranspilationSetupData, TranspilationSetup};
 use husky_coword::{Ident, Label};
+use husky_entity_path::path::ItemPath;
 use husky_hir_eager_expr::{
     variable::{
         comptime::HirEagerComptimeVariableName,
@@ -46,6 +47,9 @@ pub(crate) struct RustTranspilationBuilderBase<'a> {
     spaced: bool,
     /// None if transpile linkets
     pub(crate) crate_path: Option<CratePath>,
+    /// the item being transpiled, to which unsupported constructs are attributed
+    item_path: Option<ItemPath>,
+    errors: Vec<RustTranspilationError>,
 }
 
 impl<'a> RustTranspilationBuilderBase<'a> {
@@ -65,6 +69,8 @@ impl<'a> RustTranspilationBuilderBase<'a> {
             is_list_start: None,
             spaced: true,
             crate_path,
+            item_path: None,
+            errors: vec![],
         }
     }
 
@@ -72,6 +78,10 @@ impl<'a> RustTranspilationBuilderBase<'a> {
         self.result
     }
 
+    pub(crate) fn finish_with_errors(self) -> (String, Vec<RustTranspilationError>) {
+        (self.result, self.errors)
+    }
+
     pub(crate) fn result(&self) -> &str {
         &self.result
     }
@@ -186,6 +196,25 @@ pub(crate) struct RustTranspilationBuilder<'a, 'b, E = ()> {
 }
 
 impl<'a, 'b, E> RustTranspilationBuilder<'a, 'b, E> {
+    pub(crate) fn with_item_path(&mut self, item_path: ItemPath, f: impl FnOnce(&mut Self)) {
+        let item_path = std::mem::replace(&mut self.item_path, Some(item_path));
+        f(self);
+        self.item_path = item_path
+    }
+
+    /// reports the construct as an error of the current item and panics there at runtime instead,
+    /// so that the rest of the package still compiles
+    pub(crate) fn unsupported(&mut self, message: &'static str) {
+        let item_path = self
+            .item_path
+            .expect("unsupported constructs are only within items");
+        self.errors
+            .push(RustTranspilationError::new(item_path, message));
+        self.macro_call(RustMacroName::Todo, |builder| {
+            builder.write_str(&format!("{message:?}"))
+        })
+    }
+
     pub(crate) fn on_fresh_semicolon_line(&mut self, f: impl FnOnce(&mut Self)) {
         self.fresh_line();
         f(self);
@@ -5,7 +5,7 @@ mod major_item;
 mod submodule;
 mod ty_variant;
 
-use crate::*;
+use crate::{error::RustTranspilationError, *};
 use husky_corgi_config::transpilation_setup::TranspilationSetup;
 use husky_entity_kind::{EntityKind, MajorFormKind, MajorItemKind};
 use husky_entity_tree::helpers::paths::{module_item_paths, module_submodule_item_paths};
@@ -22,12 +22,29 @@ use husky_manifest::HasManifest;
 use husky_vfs::path::module_path::ModulePathData;
 use mangle::item_path_id_interface_cache_path;
 
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ModuleDefnRustTranspilation {
+    content: String,
+    errors: Vec<RustTranspilationError>,
+}
+
+impl ModuleDefnRustTranspilation {
+    pub(crate) fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub(crate) fn errors(&self) -> &[RustTranspilationError] {
+        &self.errors
+    }
+}
+
 #[salsa::tracked(return_ref)]
 pub(crate) fn module_defn_rust_transpilation(
     db: &::salsa::Db,
     module_path: ModulePath,
     setup: TranspilationSetup,
-) -> String {
+) -> ModuleDefnRustTranspilation {
     let is_root = module_path.is_root(db);
     let result = if is_root {
         Some(format!(
@@ -90,18 +107,25 @@ use {}::{{*, ugly::*}};
             match hir_defn {
                 HirDefn::MajorItem(hir_defn) => {
                     builder.on_fresh_paragraph(|builder| {
-                        builder.rustfmt_skip();
-                        hir_defn.transpile_to_rust(builder)
+                        builder.with_item_path(item_path, |builder| {
+                            builder.rustfmt_skip();
+                            hir_defn.transpile_to_rust(builder)
+                        })
                     });
                 }
                 HirDefn::ImplBlock(hir_defn) => {
-                    builder.on_fresh_paragraph(|builder| hir_defn.transpile_to_rust(builder));
+                    builder.on_fresh_paragraph(|builder| {
+                        builder.with_item_path(item_path, |builder| {
+                            hir_defn.transpile_to_rust(builder)
+                        })
+                    });
                 }
                 _ => (),
             }
         }
     }
-    builder_base.finish()
+    let (content, errors) = builder_base.finish_with_errors();
+    ModuleDefnRustTranspilation { content, errors }
 }
 
 #[test]
@@ -113,7 +137,9 @@ fn module_defn_rust_transpilation_works() {
                 return "// virtual package".to_string();
             }
             let setup = TranspilationSetup::new_ad_hoc(package_path, db);
-            crate::defn::module_defn_rust_transpilation(db, module_path, setup).to_string()
+            crate::defn::module_defn_rust_transpilation(db, module_path, setup)
+                .content()
+                .to_string()
         },
         &AstTestConfig::new(
             "module_defn_rust_transpilation",
@@ -61,7 +61,11 @@ impl TranspileToRustWith for TraitForTypeImplBlockHirDefn {
             }
             for &(_, trai_for_ty_item_path) in hir_decl.path(db).assoc_item_paths(db) {
                 if let Some(hir_defn) = trai_for_ty_item_path.hir_defn(db) {
-                    builder.on_fresh_paragraph(|builder| hir_defn.transpile_to_rust(builder));
+                    builder.on_fresh_paragraph(|builder| {
+                        builder.with_item_path(trai_for_ty_item_path.into(), |builder| {
+                            hir_defn.transpile_to_rust(builder)
+                        })
+                    });
                 }
             }
         })
@@ -12,7 +12,11 @@ impl TranspileToRustWith for TypeImplBlockHirDefn {
         builder.curly_block(|builder| {
             for &(_, ty_item_path) in hir_decl.path(db).assoc_item_paths(db) {
                 if let Some(hir_defn) = ty_item_path.hir_defn(db) {
-                    builder.on_fresh_paragraph(|builder| hir_defn.transpile_to_rust(builder));
+                    builder.on_fresh_paragraph(|builder| {
+                        builder.with_item_path(ty_item_path.into(), |builder| {
+                            hir_defn.transpile_to_rust(builder)
+                        })
+                    });
                 }
             }
         })
@@ -0,0 +1,54 @@
+use crate::{defn::module_defn_rust_transpilation, *};
+use husky_corgi_config::transpilation_setup::TranspilationSetup;
+use husky_entity_path::path::ItemPath;
+use husky_entity_tree::helpers::paths::crate_module_paths;
+use husky_vfs::path::crate_path::CrateKind;
+
+/// a construct of an item that isn't supported by the transpilation yet,
+/// which is transpiled into a panic so that the other items still compile
+#[salsa::derive_debug_with_db]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustTranspilationError {
+    item_path: ItemPath,
+    message: &'static str,
+}
+
+impl RustTranspilationError {
+    pub(crate) fn new(item_path: ItemPath, message: &'static str) -> Self {
+        Self { item_path, message }
+    }
+
+    pub fn item_path(&self) -> ItemPath {
+        self.item_path
+    }
+
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+/// the errors of the items of the package, in the order of transpilation
+pub fn package_rust_transpilation_errors(
+    db: &::salsa::Db,
+    package_path: PackagePath,
+    setup: TranspilationSetup,
+) -> Vec<&RustTranspilationError> {
+    if package_path.is_virtual(db) {
+        return vec![];
+    }
+    let mut errors = vec![];
+    for &crate_path in package_path
+        .crate_paths(db)
+        .expect("no vfs error at this stage")
+    {
+        match crate_path.kind(db) {
+            CrateKind::Lib | CrateKind::Main => {
+                for &module_path in crate_module_paths(db, crate_path) {
+                    errors.extend(module_defn_rust_transpilation(db, module_path, setup).errors())
+                }
+            }
+            _ => (),
+        }
+    }
+    errors
+}
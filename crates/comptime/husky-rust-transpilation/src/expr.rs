@@ -256,6 +256,19 @@ fn transpile_hir_eager_expr_to_rust(
             path.transpile_to_rust(builder);
             builder.delimited_comma_list(RustDelimiter::Par, arguments)
         }
+        HirEagerExprData::ClosureCall {
+            function,
+            ref arguments,
+        } => {
+            (
+                function,
+                HirEagerExprRole::subexpr(RustPrecedenceRange::Geq(
+                    RustPrecedence::FunctionCallOrArrayIndexing,
+                )),
+            )
+                .transpile_to_rust(builder);
+            builder.delimited_comma_list(RustDelimiter::Par, arguments)
+        }
         HirEagerExprData::PropsStructField {
             self_argument,
             self_argument_ty: self_ty,
@@ -371,9 +384,7 @@ fn transpile_hir_eager_expr_to_rust(
                 }
             }
         }
-        HirEagerExprData::NewTuple { items: _ } => {
-            todo!()
-        }
+        HirEagerExprData::NewTuple { items: _ } => builder.unsupported("tuple expressions"),
         HirEagerExprData::Index {
             self_argument,
             ref items,
@@ -488,8 +499,8 @@ impl TranspileToRustWith<HirEagerExprRegion> for &HirEagerRitchieArgument {
                 HirEagerExprRole::regular_call_item(param.contract),
             )
                 .transpile_to_rust(builder),
-            HirEagerRitchieArgument::Variadic => todo!(),
-            HirEagerRitchieArgument::Keyed => todo!(),
+            HirEagerRitchieArgument::Variadic => builder.unsupported("variadic arguments"),
+            HirEagerRitchieArgument::Keyed => builder.unsupported("keyed arguments"),
         }
     }
 }
@@ -16,8 +16,7 @@ impl TranspileToRustWith<HirEagerExprRegion> for HirEagerPatternIdx {
             } => {
                 if let Some(symbol_modifier) = symbol_modifier {
                     match symbol_modifier {
-                        VariableModifier::Pure => (),
-                        VariableModifier::Owned => todo!(),
+                        VariableModifier::Pure | VariableModifier::Owned => (),
                         VariableModifier::Mut => builder.keyword(RustKeyword::Mut),
                         VariableModifier::Ref => {
                             p!(ident.debug(db));
@@ -162,6 +162,7 @@ impl RustPrecedence {
             | HirEagerExprData::PropsStructField { .. }
             | HirEagerExprData::MemoizedField { .. }
             | HirEagerExprData::MethodRitchieCall { .. }
+            | HirEagerExprData::ClosureCall { .. }
             | HirEagerExprData::Index { .. }
             | HirEagerExprData::Unwrap { .. } => RustPrecedence::Suffix,
             HirEagerExprData::Block { .. } => RustPrecedence::None,
@@ -51,19 +51,21 @@ impl TranspileToRustWith<HirEagerExprRegion> for (IsLastStmt, HirEagerStmtIdx) {
             }
             HirEagerStmtData::Assert { ref condition } => {
                 builder.on_fresh_semicolon_line(|builder| match *condition {
-                    HirEagerCondition::Be { .. } => todo!(),
+                    HirEagerCondition::Be { .. } => {
+                        builder.unsupported("`assert` with a `be` condition")
+                    }
+                    HirEagerCondition::Other {
+                        conversion: ConditionConversion::IntToBool(_),
+                        ..
+                    } => builder.unsupported("`assert` with an integer condition"),
                     HirEagerCondition::Other {
                         opd: hir_eager_expr_idx,
-                        conversion,
+                        conversion: ConditionConversion::None,
                     } => {
                         builder.macro_name(RustMacroName::Assert);
                         builder.delimited_heterogeneous_list_with(RustDelimiter::Par, |builder| {
                             (hir_eager_expr_idx, HirEagerExprRole::new_root())
-                                .transpile_to_rust(builder);
-                            match conversion {
-                                ConditionConversion::None => (),
-                                ConditionConversion::IntToBool(_) => todo!(),
-                            }
+                                .transpile_to_rust(builder)
                         })
                     }
                 })
@@ -239,7 +241,7 @@ impl TranspileToRustWith<HirEagerExprRegion> for (IsLastStmt, HirEagerStmtIdx) {
             HirEagerStmtData::ForIn {
                 condition: _,
                 stmts: _,
-            } => todo!(),
+            } => builder.on_fresh_semicolon_line(|builder| builder.unsupported("`for in` loops")),
             HirEagerStmtData::While {
                 ref condition,
                 stmts,
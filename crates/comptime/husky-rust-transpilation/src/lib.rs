@@ -5,6 +5,7 @@
 mod binding;
 mod builder;
 mod defn;
+pub mod error;
 mod expr;
 mod fmt;
 pub mod jar;
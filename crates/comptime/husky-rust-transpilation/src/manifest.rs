@@ -1,4 +1,4 @@
-use crate::{package::rust_transpilation_packages, *};
+use crate::{package::rust_transpilation_packages, transpile_to_fs::linkets_rust_package_name, *};
 use cargo_manifest::{
     Dependency, DependencyDetail, Edition, InheritedDependencyDetail, Manifest, MaybeInherited,
     Package, Product, Resolver, True, Workspace,
@@ -158,7 +158,7 @@ pub(crate) fn source_package_manifest(
 #[salsa::tracked(return_ref)]
 pub(crate) fn linkets_package_manifest(
     db: &::salsa::Db,
-    target_path: LinktimeTargetPath,
+    package_path: PackagePath,
     transpilation_setup: TranspilationSetup,
 ) -> String {
     let rust_transpilation_setup_data = transpilation_setup.rust_data(db).unwrap();
@@ -168,7 +168,7 @@ pub(crate) fn linkets_package_manifest(
         .to_string()]
     .into_iter()
     .chain(
-        target_path
+        package_path
             .full_dependencies(db)
             .unwrap()
             .iter()
@@ -184,7 +184,7 @@ pub(crate) fn linkets_package_manifest(
     .collect();
     toml::to_string(&Manifest {
         package: Some(Package::<toml::Value> {
-            name: format!("{}-linkets", target_path.name(db).data()),
+            name: linkets_rust_package_name(package_path, db),
             edition: Some(MaybeInherited::Local(Edition::E2021)),
             version: Some(MaybeInherited::Local("0.1.0".to_string())),
             build: None,
@@ -3,6 +3,7 @@ use crate::{
     defn::module_defn_rust_transpilation,
     linket::package_linkets_transpilation,
     manifest::{linkets_package_manifest, source_package_manifest},
+    transpile_to_fs::linkets_rust_package_name,
     *,
 };
 use ::relative_path::RelativePathBuf;
@@ -31,8 +32,14 @@ pub(crate) struct RustTranspilationPackage {
 #[salsa::derive_debug_with_db]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RustTranspilationPackageData {
-    Source { package_path: PackagePath },
-    Linkets,
+    Source {
+        package_path: PackagePath,
+    },
+    /// the cdylib of the linkets of a single package,
+    /// so that it's rebuilt and reloaded only if these linkets change
+    Linkets {
+        package_path: PackagePath,
+    },
 }
 
 impl RustTranspilationPackage {
@@ -44,8 +51,8 @@ impl RustTranspilationPackage {
                     name => name.to_string(),
                 }
             }
-            RustTranspilationPackageData::Linkets => {
-                format!("{}-linkets", self.target_path.name(db).data())
+            RustTranspilationPackageData::Linkets { package_path } => {
+                linkets_rust_package_name(package_path, db)
             }
         }
     }
@@ -71,14 +78,14 @@ impl RustTranspilationPackage {
                     self.name(db)
                 }
             }
-            RustTranspilationPackageData::Linkets => self.name(db),
+            RustTranspilationPackageData::Linkets { .. } => self.name(db),
         }
     }
 
     pub(crate) fn is_virtual_source(self, db: &::salsa::Db) -> bool {
         match self.data {
             RustTranspilationPackageData::Source { package_path } => package_path.is_virtual(db),
-            RustTranspilationPackageData::Linkets => false,
+            RustTranspilationPackageData::Linkets { .. } => false,
         }
     }
 }
@@ -88,25 +95,26 @@ pub(crate) fn rust_transpilation_packages(
     db: &::salsa::Db,
     target_path: LinktimeTargetPath,
 ) -> Vec<RustTranspilationPackage> {
+    let full_dependencies = target_path
+        .full_dependencies(db)
+        .expect("no error at this stage");
     let mut packages = vec![];
     packages.extend(
-        target_path
-            .full_dependencies(db)
-            .expect("no error at this stage")
+        full_dependencies
+            .iter()
+            .map(|&package_path| RustTranspilationPackage {
+                target_path,
+                data: RustTranspilationPackageData::Source { package_path },
+            }),
+    );
+    packages.extend(
+        full_dependencies
             .iter()
-            .flat_map(|&dep_package_path| {
-                [RustTranspilationPackage {
-                    target_path,
-                    data: RustTranspilationPackageData::Source {
-                        package_path: dep_package_path,
-                    },
-                }]
+            .map(|&package_path| RustTranspilationPackage {
+                target_path,
+                data: RustTranspilationPackageData::Linkets { package_path },
             }),
     );
-    packages.push(RustTranspilationPackage {
-        target_path,
-        data: RustTranspilationPackageData::Linkets,
-    });
     packages
 }
 
@@ -136,8 +144,8 @@ impl RustTranspilationPackage {
             package::RustTranspilationPackageData::Source { package_path } => {
                 transpile_source_package_to_fs(setup, workspace_dir, package_path, db)
             }
-            package::RustTranspilationPackageData::Linkets => {
-                transpile_linkets_package_to_fs(setup, workspace_dir, self.target_path, db)
+            package::RustTranspilationPackageData::Linkets { package_path } => {
+                transpile_linkets_package_to_fs(setup, workspace_dir, package_path, db)
             }
         }
     }
@@ -169,7 +177,7 @@ fn transpile_source_package_to_fs(
                     husky_io_utils::diff_write(
                         &module_relative_path_for_transpilation(db, module_path)
                             .to_path(&package_dir),
-                        module_defn_rust_transpilation(db, module_path, setup),
+                        module_defn_rust_transpilation(db, module_path, setup).content(),
                         true,
                     );
                 }
@@ -206,20 +214,20 @@ fn module_relative_path_for_transpilation(
 fn transpile_linkets_package_to_fs(
     setup: TranspilationSetup,
     rust_workspace_dir: &std::path::Path,
-    target_path: LinktimeTargetPath,
+    package_path: PackagePath,
     db: &::salsa::Db,
 ) -> IoResult<()> {
-    let package_dir = rust_workspace_dir.join(format!("{}-linkets", target_path.name(db).data()));
+    let package_dir = rust_workspace_dir.join(linkets_rust_package_name(package_path, db));
     let src_dir = package_dir.join("src");
     let cargo_toml_path = package_dir.join("Cargo.toml");
     husky_io_utils::diff_write(
         &cargo_toml_path,
-        linkets_package_manifest(db, target_path, setup),
+        linkets_package_manifest(db, package_path, setup),
         true,
     );
     husky_io_utils::diff_write(
         &src_dir.join("lib.rs"),
-        package_linkets_transpilation(db, target_path, setup),
+        package_linkets_transpilation(db, package_path, setup),
         true,
     );
     Ok(())
@@ -4,7 +4,11 @@ use crate::{
 };
 use husky_corgi_config::transpilation_setup::TranspilationSetup;
 use husky_io_utils::error::IoResult;
-use husky_vfs::path::linktime_target_path::LinktimeTargetPath;
+use husky_manifest::lockfile::write_lockfile;
+use husky_vfs::path::{
+    linktime_target_path::{LinktimeTargetPath, LinktimeTargetPathData},
+    package_path::PackagePath,
+};
 use is::Is;
 
 pub trait TranspileToFsFull: Is<LinktimeTargetPath> {
@@ -23,6 +27,15 @@ impl TranspileToFsFull for LinktimeTargetPath {
         for package in rust_transpilation_packages(db, self) {
             package.transpile_to_fs(setup, db)?
         }
+        // so that the next build picks the same registry versions
+        if let LinktimeTargetPathData::Package(package_path) = self.data(db) {
+            write_lockfile(db, package_path)?
+        }
         Ok(())
     }
 }
+
+/// the name of the rust package whose cdylib provides the linket impls of `package_path`
+pub fn linkets_rust_package_name(package_path: PackagePath, db: &::salsa::Db) -> String {
+    format!("{}-linkets", package_path.name(db).data())
+}
@@ -69,6 +69,11 @@ pub trait IsRuntimeStorage<LinketImpl: IsLinketImpl>: Default + Send {
         f: impl FnOnce() -> KiControlFlow<(), Infallible, LinketImplTrackedException<LinketImpl>>,
         db: &::salsa::Db,
     ) -> KiControlFlow<(), Infallible, LinketImplTrackedException<LinketImpl>>;
+
+    /// Drops all cached values, e.g. before unloading the linket libraries that computed them.
+    ///
+    /// The caller guarantees that no value obtained from the storage is alive.
+    unsafe fn clear(&self);
 }
 
 pub type DevEvalContextLocalKey<LinketImpl> =
@@ -239,4 +239,12 @@ impl IsRuntimeStorage<LinketImpl> for StandardDevRuntimeStorage {
                 .share_unchecked()
         }
     }
+
+    unsafe fn clear(&self) {
+        self.val_values.clear();
+        self.ki_domain_values.clear();
+        self.ki_values.clear();
+        self.generic_gn_values.clear();
+        self.memo_field_values.clear();
+    }
 }
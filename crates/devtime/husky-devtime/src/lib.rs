@@ -93,6 +93,23 @@ impl<Devsoul: IsDevsoul> Devtime<Devsoul> {
     pub fn target(&self) -> DevComptimeTarget {
         self.runtime.comptime_target()
     }
+
+    /// Picks up the changes made on disk to the target package since the last refresh,
+    /// dropping the caches if any source changed.
+    ///
+    /// Returns whether any source changed.
+    pub fn refresh_from_disk(&mut self) -> VfsResult<bool> {
+        if !self.runtime.as_mut().refresh_from_disk()? {
+            return Ok(false);
+        }
+        self.eager_trace_cache.clear();
+        self.figure_chunk_base_cache.clear();
+        self.vmir_storage = Default::default();
+        // SAFETY: the caches above were the last holders of values computed by the libraries
+        // retired by hot reloads, and no evaluation is in progress as `self` is borrowed mutably
+        unsafe { self.runtime.collect_garbage() }
+        Ok(true)
+    }
 }
 
 impl<Devsoul: IsDevsoul> IsTracetime for Devtime<Devsoul> {
@@ -102,6 +119,16 @@ impl<Devsoul: IsDevsoul> IsTracetime for Devtime<Devsoul> {
 
     type SerdeImpl = serde_impl::json::SerdeJson;
 
+    fn refresh(&mut self) -> bool {
+        match self.refresh_from_disk() {
+            Ok(changed) => changed,
+            Err(e) => {
+                tracing::warn!("failed to refresh files changed on disk: {e}");
+                false
+            }
+        }
+    }
+
     fn trace_bundles(&self) -> &[TraceBundle<Self::Trace>] {
         match self.target() {
             DevComptimeTarget::None => &[],
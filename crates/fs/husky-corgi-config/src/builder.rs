@@ -3,6 +3,7 @@ use crate::*;
 pub(crate) struct CorgiConfigBuilder<'a> {
     db: &'a ::salsa::Db,
     registry_path: Option<RegistryPath>,
+    fmt_section: FmtSection,
     errors: Vec<CorgiConfigError>,
 }
 
@@ -11,6 +12,7 @@ impl<'a> CorgiConfigBuilder<'a> {
         Self {
             db,
             registry_path: Default::default(),
+            fmt_section: Default::default(),
             errors: Default::default(),
         }
     }
@@ -26,14 +28,43 @@ impl<'a> CorgiConfigBuilder<'a> {
                 self.registry_path = Some(RegistryPath::new(path?))
             }
         }
+        if let Some(fmt_section) = corgi_config_ast_sheet.fmt_section() {
+            match fmt_section {
+                Ok(fmt_section) => self.read_fmt_section(fmt_section),
+                Err(e) => self.errors.push(e.into()),
+            }
+        }
         Ok(())
     }
 
+    /// configs are read from the nearest outward, so the first value found wins
+    fn read_fmt_section(&mut self, fmt_section: &CorgiConfigFmtSectionAst) {
+        if self.fmt_section.max_blank_lines.is_none()
+            && let Some(max_blank_lines) = fmt_section.max_blank_lines()
+        {
+            match max_blank_lines {
+                Ok(max_blank_lines) => self.fmt_section.max_blank_lines = Some(max_blank_lines),
+                Err(e) => self.errors.push(e.into()),
+            }
+        }
+        if self.fmt_section.blank_line_between_items.is_none()
+            && let Some(blank_line_between_items) = fmt_section.blank_line_between_items()
+        {
+            match blank_line_between_items {
+                Ok(blank_line_between_items) => {
+                    self.fmt_section.blank_line_between_items = Some(blank_line_between_items)
+                }
+                Err(e) => self.errors.push(e.into()),
+            }
+        }
+    }
+
     pub(crate) fn finish(self) -> CorgiConfig {
         CorgiConfig {
             registry_section: RegistrySection {
                 path: self.registry_path.unwrap_or_else(|| todo!()),
             },
+            fmt_section: self.fmt_section,
             errors: self.errors,
         }
     }
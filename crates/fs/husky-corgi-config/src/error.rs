@@ -19,7 +19,12 @@ impl From<&CorgiConfigError> for CorgiConfigError {
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
-pub enum OriginalCorgiConfigError {}
+pub enum OriginalCorgiConfigError {
+    #[error("expected a boolean")]
+    ExpectedBoolean,
+    #[error("expected a non-negative integer")]
+    ExpectedNonNegativeInteger,
+}
 
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum DerivedCorgiConfigError {}
@@ -37,7 +42,13 @@ impl From<&VfsError> for CorgiConfigError {
 }
 
 impl From<&CorgiConfigAstError> for CorgiConfigError {
-    fn from(_value: &CorgiConfigAstError) -> Self {
-        todo!()
+    fn from(value: &CorgiConfigAstError) -> Self {
+        match value {
+            CorgiConfigAstError::ExpectedBoolean => OriginalCorgiConfigError::ExpectedBoolean,
+            CorgiConfigAstError::ExpectedNonNegativeInteger => {
+                OriginalCorgiConfigError::ExpectedNonNegativeInteger
+            }
+        }
+        .into()
     }
 }
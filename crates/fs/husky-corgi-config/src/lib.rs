@@ -22,9 +22,20 @@ use husky_vfs::{
 #[derive(Debug, PartialEq, Eq)]
 pub struct CorgiConfig {
     registry_section: RegistrySection,
+    fmt_section: FmtSection,
     errors: Vec<CorgiConfigError>,
 }
 
+impl CorgiConfig {
+    pub fn fmt_section(&self) -> &FmtSection {
+        &self.fmt_section
+    }
+
+    pub fn errors(&self) -> &[CorgiConfigError] {
+        &self.errors
+    }
+}
+
 #[salsa::tracked(return_ref)]
 pub(crate) fn package_registry_path(
     db: &::salsa::Db,
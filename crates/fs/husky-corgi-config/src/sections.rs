@@ -1,5 +1,7 @@
+mod fmt;
 mod registry;
 
+pub use self::fmt::*;
 pub use self::registry::*;
 
 use super::*;
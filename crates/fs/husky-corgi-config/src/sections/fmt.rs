@@ -0,0 +1,19 @@
+use super::*;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FmtSection {
+    pub(crate) max_blank_lines: Option<usize>,
+    pub(crate) blank_line_between_items: Option<bool>,
+}
+
+impl FmtSection {
+    /// `None` if no config sets it
+    pub fn max_blank_lines(&self) -> Option<usize> {
+        self.max_blank_lines
+    }
+
+    /// `None` if no config sets it
+    pub fn blank_line_between_items(&self) -> Option<bool> {
+        self.blank_line_between_items
+    }
+}
@@ -1,12 +1,38 @@
-use crate::*;
-use husky_manifest_ast::ManifestDependencyAst;
+use crate::{diagnostic::*, *};
+use husky_coword::Kebab;
+use husky_manifest_ast::{ManifestDependencyAst, ManifestDependencySourceAst};
+use husky_toml_ast::TomlLineGroupIdx;
+use husky_vfs::path::{package_path::PackagePathSource, virtual_path::VirtualPath};
+use std::path::{Component, Path, PathBuf};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct PackageDependency {
+    line_group_idx: TomlLineGroupIdx,
+    requirement: PackageDependencyRequirement,
     package_path: PackagePath,
 }
 
+/// What the manifest asks for, as opposed to what it's resolved to.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PackageDependencyRequirement {
+    name: Kebab,
+    version_req: semver::VersionReq,
+    source: ManifestDependencySourceAst,
+}
+
 impl PackageDependency {
+    pub fn line_group_idx(&self) -> TomlLineGroupIdx {
+        self.line_group_idx
+    }
+
+    pub fn name(&self) -> Kebab {
+        self.requirement.name
+    }
+
+    pub fn requirement(&self) -> &PackageDependencyRequirement {
+        &self.requirement
+    }
+
     pub fn package_path(&self) -> PackagePath {
         self.package_path
     }
@@ -15,18 +41,271 @@ impl PackageDependency {
         db: &::salsa::Db,
         toolchain: Toolchain,
         registry_path: RegistryPath,
+        lock: Option<&CorgiLock>,
         ast: &ManifestDependencyAst,
-    ) -> Self {
-        // ad hoc
-        // todo: check source
-        PackageDependency {
-            package_path: PackagePath::new_registry_package(
-                db,
-                toolchain,
-                ast.name(),
-                registry_path,
-                semver::Version::new(0, 1, 0),
-            ),
+    ) -> Result<Self, ManifestDiagnostic> {
+        let requirement = PackageDependencyRequirement {
+            name: ast.name(),
+            version_req: ast
+                .version_req()
+                .cloned()
+                .unwrap_or(semver::VersionReq::STAR),
+            source: ast.source().clone(),
+        };
+        let locked_versions: Vec<semver::Version> = lock
+            .map(|lock| lock.locked_versions(ast.name().data()).collect())
+            .unwrap_or_default();
+        let package_path = requirement
+            .resolve(db, toolchain, registry_path, locked_versions)
+            .map_err(|kind| ManifestDiagnostic::new(Some(ast.line_group_idx()), kind))?;
+        Ok(Self {
+            line_group_idx: ast.line_group_idx(),
+            requirement,
+            package_path,
+        })
+    }
+}
+
+impl PackageDependencyRequirement {
+    pub fn name(&self) -> Kebab {
+        self.name
+    }
+
+    pub fn version_req(&self) -> &semver::VersionReq {
+        &self.version_req
+    }
+
+    pub fn source(&self) -> &ManifestDependencySourceAst {
+        &self.source
+    }
+
+    /// For registry dependencies, the first of `preferred_versions` that matches and exists wins,
+    /// otherwise the highest matching version in the registry.
+    pub(crate) fn resolve(
+        &self,
+        db: &::salsa::Db,
+        toolchain: Toolchain,
+        registry_path: RegistryPath,
+        preferred_versions: impl IntoIterator<Item = semver::Version>,
+    ) -> Result<PackagePath, ManifestDiagnosticKind> {
+        let name = self.name;
+        match self.source {
+            ManifestDependencySourceAst::Registry => {
+                let available_versions = registry_package_versions(db, registry_path, name.data());
+                let version = preferred_versions
+                    .into_iter()
+                    .find(|version| {
+                        self.version_req.matches(version) && available_versions.contains(version)
+                    })
+                    .or_else(|| {
+                        available_versions
+                            .iter()
+                            .rev()
+                            .find(|version| self.version_req.matches(version))
+                            .cloned()
+                    });
+                match version {
+                    Some(version) => Ok(PackagePath::new_registry_package(
+                        db,
+                        toolchain,
+                        name,
+                        registry_path,
+                        version,
+                    )),
+                    None => Err(ManifestDiagnosticKind::UnresolvableDependency {
+                        name,
+                        version_req: self.version_req.clone(),
+                        available_versions,
+                    }),
+                }
+            }
+            ManifestDependencySourceAst::Path(path) => {
+                // `path` is joined to the manifest dir as written, like `<dir>/../name`,
+                // so it's normalized for the package to be the same however it's reached
+                let Some(package_path) = VirtualPath::try_new(db, normalize_lexically(path.data()))
+                    .ok()
+                    .filter(|dir| matches!(dir.join("Corgi.toml", db).is_file(db), Ok(true)))
+                    .and_then(|dir| {
+                        PackagePath::new_local_or_toolchain_package(db, toolchain, dir.data()).ok()
+                    })
+                else {
+                    return Err(ManifestDiagnosticKind::MissingPathDependency { name, path });
+                };
+                if package_path.name(db) != name {
+                    return Err(ManifestDiagnosticKind::DependencyNameMismatch {
+                        name,
+                        found: package_path.name(db),
+                    });
+                }
+                Ok(package_path)
+            }
+            ManifestDependencySourceAst::Git { ref url, ref rev } => {
+                let url =
+                    url::Url::parse(url).map_err(|e| ManifestDiagnosticKind::InvalidGitUrl {
+                        name,
+                        url: url.clone(),
+                        message: e.to_string(),
+                    })?;
+                let package_path = PackagePath::new_git_package(
+                    db,
+                    toolchain,
+                    name,
+                    registry_path,
+                    url,
+                    rev.clone(),
+                );
+                match package_path.dir(db) {
+                    Ok(dir) if matches!(dir.join("Corgi.toml", db).is_file(db), Ok(true)) => {
+                        Ok(package_path)
+                    }
+                    Ok(dir) => Err(ManifestDiagnosticKind::MissingGitCheckout { name, dir }),
+                    Err(e) => Err(ManifestDiagnosticKind::InvalidGitCheckoutDir {
+                        name,
+                        message: e.to_string(),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+/// Versions of `name` in the registry, in ascending order.
+///
+/// A registry is a directory of `<name>-<major>.<minor>.<patch>` subdirectories.
+///
+/// Read through the VFS, so that adding or removing versions invalidates the resolution.
+fn registry_package_versions(
+    db: &::salsa::Db,
+    registry_path: RegistryPath,
+    name: &str,
+) -> Vec<semver::Version> {
+    let Ok(Some(entries)) = registry_path.path().read_dir(db) else {
+        return vec![];
+    };
+    let mut versions: Vec<semver::Version> = entries
+        .iter()
+        .filter_map(|&entry| {
+            if !entry.is_dir(db).ok()? {
+                return None;
+            }
+            let file_name = entry.data().file_name()?;
+            let version = file_name.to_str()?.strip_prefix(name)?.strip_prefix('-')?;
+            semver::Version::parse(version).ok()
+        })
+        .collect();
+    versions.sort();
+    versions
+}
+
+/// Resolves `.` and `..` without touching the file system, unlike [`std::fs::canonicalize`].
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
         }
     }
+    normalized
+}
+
+/// Replaces registry versions by the ones already chosen elsewhere in the dependency graph
+/// or pinned by the lockfile of the root package, so that every package has a single version if possible.
+pub(crate) fn unify_registry_dependency(
+    db: &::salsa::Db,
+    dependency: &PackageDependency,
+    preferred_versions: impl IntoIterator<Item = semver::Version>,
+) -> PackagePath {
+    let package_path = dependency.package_path;
+    let PackagePathSource::Registry { registry_path, .. } = *package_path.data(db) else {
+        return package_path;
+    };
+    dependency
+        .requirement
+        .resolve(
+            db,
+            package_path.toolchain(db),
+            registry_path,
+            preferred_versions,
+        )
+        .unwrap_or(package_path)
+}
+
+#[test]
+fn package_dependencies_resolve_by_source() {
+    let db = DB::default();
+    let db = &*db;
+    let package_path = dependency_resolution_package_path(db, "resolve");
+    assert_eq!(package_path.manifest_diagnostics(db), &[]);
+    let dependencies = package_path.dependencies(db).unwrap();
+    assert_eq!(
+        dependencies
+            .iter()
+            .map(|dependency| dependency.name().data())
+            .collect::<Vec<_>>(),
+        ["alpha", "beta", "local", "gamma"]
+    );
+    // the highest version matching `0.1`, even with `0.2.0` and `1.0.0` around
+    assert_eq!(
+        registry_version(db, dependencies[0].package_path()).as_deref(),
+        Some("0.1.3")
+    );
+    // `{ version = "0.1" }` is the same as `"0.1"`
+    assert_eq!(
+        registry_version(db, dependencies[1].package_path()).as_deref(),
+        Some("0.1.0")
+    );
+    assert_eq!(
+        dependencies[2].package_path(),
+        dependency_resolution_package_path(db, "local")
+    );
+    let PackagePathSource::Git { url, rev, .. } = dependencies[3].package_path().data(db) else {
+        panic!("expect a git package")
+    };
+    assert_eq!(url.as_str(), "https://github.com/husky-lang-org/gamma");
+    assert_eq!(rev.as_deref(), Some("a1b2c3"));
+    assert!(dependencies[3]
+        .package_path()
+        .dir(db)
+        .unwrap()
+        .data()
+        .ends_with("registry/git/gamma-a1b2c3"));
+}
+
+#[test]
+fn full_dependencies_unify_registry_versions() {
+    let db = DB::default();
+    let db = &*db;
+    // `beta` asks for `alpha = "0.1"` as well, which is unified with the one of `resolve`
+    let full_dependencies = dependency_resolution_package_path(db, "resolve")
+        .full_dependencies(db)
+        .unwrap();
+    let alphas: Vec<_> = full_dependencies
+        .iter()
+        .filter(|package_path| package_path.name_str(db) == "alpha")
+        .map(|&package_path| registry_version(db, package_path))
+        .collect();
+    assert_eq!(alphas, [Some("0.1.3".to_string())]);
+}
+
+#[test]
+fn lockfile_pins_registry_versions() {
+    let db = DB::default();
+    let db = &*db;
+    // `0.1.3` matches as well, but `Corgi.lock` pins `0.1.0`
+    let dependencies = dependency_resolution_package_path(db, "locked")
+        .dependencies(db)
+        .unwrap();
+    assert_eq!(
+        registry_version(db, dependencies[0].package_path()).as_deref(),
+        Some("0.1.0")
+    );
 }
@@ -0,0 +1,272 @@
+use crate::{lockfile::package_lock, *};
+use husky_coword::Kebab;
+use husky_toml_ast::TomlLineGroupIdx;
+use husky_vfs::path::package_path::PackagePathSource;
+use husky_vfs::path::virtual_path::VirtualPath;
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ManifestDiagnostic {
+    /// `None` if it's about the manifest as a whole
+    line_group_idx: Option<TomlLineGroupIdx>,
+    kind: ManifestDiagnosticKind,
+}
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ManifestDiagnosticKind {
+    Ast {
+        message: String,
+    },
+    UnresolvableDependency {
+        name: Kebab,
+        version_req: semver::VersionReq,
+        available_versions: Vec<semver::Version>,
+    },
+    MissingPathDependency {
+        name: Kebab,
+        path: VirtualPath,
+    },
+    DependencyNameMismatch {
+        name: Kebab,
+        found: Kebab,
+    },
+    InvalidGitUrl {
+        name: Kebab,
+        url: String,
+        message: String,
+    },
+    MissingGitCheckout {
+        name: Kebab,
+        dir: VirtualPath,
+    },
+    /// the checkout directory under the registry can't be made into a virtual path
+    InvalidGitCheckoutDir {
+        name: Kebab,
+        message: String,
+    },
+    /// different packages of the same name in the full dependencies
+    ConflictingDependencies {
+        name: Kebab,
+        package_paths: Vec<PackagePath>,
+    },
+    InvalidLockfile {
+        message: String,
+    },
+}
+
+impl ManifestDiagnostic {
+    pub(crate) fn new(
+        line_group_idx: Option<TomlLineGroupIdx>,
+        kind: ManifestDiagnosticKind,
+    ) -> Self {
+        Self {
+            line_group_idx,
+            kind,
+        }
+    }
+
+    pub fn line_group_idx(&self) -> Option<TomlLineGroupIdx> {
+        self.line_group_idx
+    }
+
+    pub fn kind(&self) -> &ManifestDiagnosticKind {
+        &self.kind
+    }
+
+    pub fn message(&self, db: &::salsa::Db) -> String {
+        match self.kind {
+            ManifestDiagnosticKind::Ast { ref message } => message.clone(),
+            ManifestDiagnosticKind::UnresolvableDependency {
+                name,
+                ref version_req,
+                ref available_versions,
+            } => match available_versions.is_empty() {
+                true => format!("no version of `{}` in the registry", name.data()),
+                false => format!(
+                    "no version of `{}` matches `{version_req}`, available versions are {}",
+                    name.data(),
+                    available_versions
+                        .iter()
+                        .map(|version| format!("`{version}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            ManifestDiagnosticKind::MissingPathDependency { name, path } => format!(
+                "no package `{}` at `{}`",
+                name.data(),
+                path.data().display()
+            ),
+            ManifestDiagnosticKind::DependencyNameMismatch { name, found } => format!(
+                "expected package `{}`, found package `{}`",
+                name.data(),
+                found.data()
+            ),
+            ManifestDiagnosticKind::InvalidGitUrl {
+                name,
+                ref url,
+                ref message,
+            } => format!("invalid git url `{url}` for `{}`: {message}", name.data()),
+            ManifestDiagnosticKind::MissingGitCheckout { name, dir } => format!(
+                "git dependency `{}` isn't vendored at `{}`",
+                name.data(),
+                dir.data().display()
+            ),
+            ManifestDiagnosticKind::InvalidGitCheckoutDir { name, ref message } => format!(
+                "invalid checkout directory for git dependency `{}`: {message}",
+                name.data()
+            ),
+            ManifestDiagnosticKind::ConflictingDependencies {
+                name,
+                ref package_paths,
+            } => format!(
+                "conflicting requirements for `{}`, resolved to {}",
+                name.data(),
+                package_paths
+                    .iter()
+                    .map(|package_path| match package_path.data(db) {
+                        PackagePathSource::Registry { version, .. } => format!("`{version}`"),
+                        PackagePathSource::Library => "the toolchain library".to_string(),
+                        PackagePathSource::Local { path } => format!("`{}`", path.data().display()),
+                        PackagePathSource::Git { url, .. } => format!("`{url}`"),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            ),
+            ManifestDiagnosticKind::InvalidLockfile { ref message } => {
+                format!("invalid `{CORGI_LOCK_FILE_NAME}`: {message}")
+            }
+        }
+    }
+}
+
+pub trait HasManifestDiagnostics: Copy {
+    fn manifest_diagnostics(self, db: &::salsa::Db) -> &[ManifestDiagnostic];
+}
+
+impl HasManifestDiagnostics for PackagePath {
+    fn manifest_diagnostics(self, db: &::salsa::Db) -> &[ManifestDiagnostic] {
+        package_manifest_diagnostics(db, self)
+    }
+}
+
+#[salsa::tracked(return_ref)]
+pub(crate) fn package_manifest_diagnostics(
+    db: &::salsa::Db,
+    package_path: PackagePath,
+) -> Vec<ManifestDiagnostic> {
+    let mut diagnostics = vec![];
+    if let Ok(manifest_ast_sheet) = package_path.manifest_ast_sheet(db) {
+        diagnostics.extend(manifest_ast_sheet.errors().iter().map(|error| {
+            ManifestDiagnostic::new(
+                error.line_group_idx(),
+                ManifestDiagnosticKind::Ast {
+                    message: error.to_string(),
+                },
+            )
+        }))
+    }
+    if let Some(Err(message)) = package_lock(db, package_path) {
+        diagnostics.push(ManifestDiagnostic::new(
+            None,
+            ManifestDiagnosticKind::InvalidLockfile {
+                message: message.to_string(),
+            },
+        ))
+    }
+    let Ok(manifest) = package_path.manifest(db) else {
+        return diagnostics;
+    };
+    diagnostics.extend(manifest.diagnostics(db).iter().cloned());
+    if let Ok(full_dependencies) = package_path.full_dependencies(db) {
+        let mut names: Vec<Kebab> = vec![];
+        for package_path in full_dependencies {
+            let name = package_path.name(db);
+            if names.contains(&name) {
+                continue;
+            }
+            names.push(name);
+            let package_paths: Vec<PackagePath> = full_dependencies
+                .iter()
+                .copied()
+                .filter(|package_path| package_path.name(db) == name)
+                .collect();
+            if package_paths.len() > 1 {
+                let line_group_idx = manifest
+                    .dependencies(db)
+                    .data(db)
+                    .iter()
+                    .find(|dependency| dependency.name() == name)
+                    .map(|dependency| dependency.line_group_idx());
+                diagnostics.push(ManifestDiagnostic::new(
+                    line_group_idx,
+                    ManifestDiagnosticKind::ConflictingDependencies {
+                        name,
+                        package_paths,
+                    },
+                ))
+            }
+        }
+    }
+    diagnostics
+}
+
+#[test]
+fn conflicting_dependencies_are_diagnosed() {
+    let db = DB::default();
+    let db = &*db;
+    // `alpha = "1"`, while `beta` asks for `alpha = "0.1"`
+    let package_path = dependency_resolution_package_path(db, "conflict");
+    let diagnostics = package_path.manifest_diagnostics(db);
+    assert_eq!(diagnostics.len(), 1);
+    let ManifestDiagnosticKind::ConflictingDependencies {
+        name,
+        ref package_paths,
+    } = *diagnostics[0].kind()
+    else {
+        panic!("expect conflicting dependencies, got {:?}", diagnostics[0])
+    };
+    assert_eq!(name.data(), "alpha");
+    assert_eq!(
+        package_paths
+            .iter()
+            .map(|&package_path| registry_version(db, package_path))
+            .collect::<Vec<_>>(),
+        [Some("1.0.0".to_string()), Some("0.1.3".to_string())]
+    );
+    // on the line of `alpha`
+    assert_eq!(
+        diagnostics[0].line_group_idx(),
+        Some(package_path.dependencies(db).unwrap()[0].line_group_idx())
+    );
+    assert_eq!(
+        diagnostics[0].message(db),
+        "conflicting requirements for `alpha`, resolved to `1.0.0` and `0.1.3`"
+    );
+}
+
+#[test]
+fn unresolvable_dependencies_are_diagnosed() {
+    let db = DB::default();
+    let db = &*db;
+    let package_path = dependency_resolution_package_path(db, "unresolvable");
+    assert_eq!(package_path.dependencies(db).unwrap(), &[]);
+    let messages: Vec<String> = package_path
+        .manifest_diagnostics(db)
+        .iter()
+        .map(|diagnostic| diagnostic.message(db))
+        .collect();
+    assert_eq!(messages.len(), 4);
+    assert_eq!(
+        messages[0],
+        "no version of `alpha` matches `^2`, available versions are `0.1.0`, `0.1.3`, `0.2.0`, `1.0.0`"
+    );
+    assert!(messages[1].starts_with("no package `nowhere` at `"));
+    assert_eq!(
+        messages[2],
+        "expected package `renamed`, found package `local`"
+    );
+    assert!(messages[3].starts_with("git dependency `gamma` isn't vendored at `"));
+    assert!(messages[3].ends_with("gamma-d4e5f6`"));
+}
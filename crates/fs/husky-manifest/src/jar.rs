@@ -9,6 +9,8 @@ pub struct ManifestJar(
     full_dependent_package_paths_aux,
     PackageDevDependenciesSection,
     package_dev_dependencies_unchecked,
+    crate::lockfile::package_lock_aux,
+    crate::diagnostic::package_manifest_diagnostics,
     crate::synopsis::package::package_synopsis,
     crate::helpers::upstream::linktime_target_path_all_upstream_packages,
 );
@@ -1,8 +1,10 @@
 #![feature(if_let_guard)]
 pub mod dependency;
+pub mod diagnostic;
 pub mod error;
 pub mod helpers;
 pub mod jar;
+pub mod lockfile;
 pub mod manifest;
 pub mod sections;
 pub mod synopsis;
@@ -12,13 +14,17 @@ mod tests;
 pub use self::manifest::*;
 
 use self::dependency::*;
+use self::diagnostic::*;
 use self::error::*;
 use self::jar::ManifestJar as Jar;
+use self::lockfile::*;
 use self::sections::*;
 #[cfg(test)]
 use self::tests::*;
 use husky_corgi_config::HasCorgiConfig;
-use husky_manifest_ast::{HasPackageManifestAstSheet, PackageManifestAstSheet};
+use husky_manifest_ast::{
+    HasPackageManifestAstSheet, ManifestDependencyAst, PackageManifestAstSheet,
+};
 use husky_vfs::{
     path::package_path::{PackagePath, RegistryPath},
     toolchain::Toolchain,
@@ -30,6 +36,9 @@ pub struct PackageManifest {
     dependencies: PackageDependenciesSection,
     // intentially private
     dev_dependencies: PackageDevDependenciesSection,
+    /// dependencies failing to resolve, which are left out of the sections
+    #[return_ref]
+    diagnostics: Vec<ManifestDiagnostic>,
 }
 
 pub(crate) fn package_manifest(
@@ -48,6 +57,7 @@ pub(crate) fn package_manifest_aux(
         db,
         package_path.toolchain(db),
         package_path.registry_path(db)?,
+        package_lock(db, package_path).and_then(|lock| lock.ok()),
         package_path.manifest_ast_sheet(db)?,
     ))
 }
@@ -57,8 +67,20 @@ impl PackageManifest {
         db: &::salsa::Db,
         toolchain: Toolchain,
         registry_path: RegistryPath,
+        lock: Option<&CorgiLock>,
         manifest_ast: &PackageManifestAstSheet,
     ) -> Self {
+        let mut diagnostics = vec![];
+        let mut resolve = |dependency_asts: &[ManifestDependencyAst]| -> Vec<PackageDependency> {
+            dependency_asts
+                .iter()
+                .filter_map(|dependency_ast| {
+                    PackageDependency::from_ast(db, toolchain, registry_path, lock, dependency_ast)
+                        .map_err(|diagnostic| diagnostics.push(diagnostic))
+                        .ok()
+                })
+                .collect()
+        };
         let dependencies_section = PackageDependenciesSection::new(
             db,
             manifest_ast
@@ -66,45 +88,24 @@ impl PackageManifest {
                 .as_ref()
                 .map(|s| s.as_ref().ok())
                 .flatten()
-                .map(|dependencies_section_ast| {
-                    dependencies_section_ast
-                        .deps()
-                        .iter()
-                        .map(|dependency_ast| {
-                            PackageDependency::from_ast(
-                                db,
-                                toolchain,
-                                registry_path,
-                                dependency_ast,
-                            )
-                        })
-                        .collect()
-                })
+                .map(|dependencies_section_ast| resolve(dependencies_section_ast.deps()))
                 .unwrap_or_default(),
         );
         let dev_dependencies_section = PackageDevDependenciesSection::new(
             db,
             manifest_ast
-                .dependencies_section()
+                .dev_dependencies_section()
                 .as_ref()
                 .map(|s| s.as_ref().ok())
                 .flatten()
-                .map(|dev_dependencies_section_ast| {
-                    dev_dependencies_section_ast
-                        .deps()
-                        .iter()
-                        .map(|dependency_ast| {
-                            PackageDependency::from_ast(
-                                db,
-                                toolchain,
-                                registry_path,
-                                dependency_ast,
-                            )
-                        })
-                        .collect()
-                })
+                .map(|dev_dependencies_section_ast| resolve(dev_dependencies_section_ast.deps()))
                 .unwrap_or_default(),
         );
-        Self::new(db, dependencies_section, dev_dependencies_section)
+        Self::new(
+            db,
+            dependencies_section,
+            dev_dependencies_section,
+            diagnostics,
+        )
     }
 }
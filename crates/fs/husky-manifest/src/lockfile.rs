@@ -0,0 +1,153 @@
+use crate::*;
+use husky_io_utils::error::{IoError, IoResult};
+use husky_vfs::path::package_path::PackagePathSource;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub const CORGI_LOCK_FILE_NAME: &str = "Corgi.lock";
+
+const CORGI_LOCK_HEADER: &str =
+    "# This file is generated by corgi. It is not intended for manual editing.\n";
+
+/// The resolved dependencies of a package, so that builds don't change as the registry grows.
+///
+/// Only registry versions are pinned, path dependencies and git checkouts are pinned by themselves.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorgiLock {
+    #[serde(default, rename = "package")]
+    packages: Vec<CorgiLockPackage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorgiLockPackage {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// `library`, `registry`, `path+<dir>` or `git+<url>[#<rev>]`
+    pub source: String,
+}
+
+impl CorgiLock {
+    pub fn new(db: &::salsa::Db, package_paths: &[PackagePath]) -> Self {
+        let mut packages: Vec<CorgiLockPackage> = package_paths
+            .iter()
+            .map(|&package_path| {
+                let (version, source) = match package_path.data(db) {
+                    PackagePathSource::Library => (None, "library".to_string()),
+                    PackagePathSource::Registry { version, .. } => {
+                        (Some(version.to_string()), "registry".to_string())
+                    }
+                    PackagePathSource::Local { path } => {
+                        (None, format!("path+{}", path.data().display()))
+                    }
+                    PackagePathSource::Git { url, rev, .. } => (
+                        None,
+                        match rev {
+                            Some(rev) => format!("git+{url}#{rev}"),
+                            None => format!("git+{url}"),
+                        },
+                    ),
+                };
+                CorgiLockPackage {
+                    name: package_path.name_string(db),
+                    version,
+                    source,
+                }
+            })
+            .collect();
+        packages.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+        Self { packages }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    pub fn to_toml(&self) -> String {
+        CORGI_LOCK_HEADER.to_string() + &toml::to_string(self).unwrap()
+    }
+
+    pub fn packages(&self) -> &[CorgiLockPackage] {
+        &self.packages
+    }
+
+    /// the registry versions of `name`, usually at most one
+    pub fn locked_versions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = semver::Version> + 'a {
+        self.packages
+            .iter()
+            .filter(move |package| package.name == name && package.source == "registry")
+            .filter_map(|package| semver::Version::parse(package.version.as_ref()?).ok())
+    }
+}
+
+/// `None` if there's no lockfile next to the manifest.
+pub(crate) fn package_lock(
+    db: &::salsa::Db,
+    package_path: PackagePath,
+) -> Option<Result<&CorgiLock, &str>> {
+    package_lock_aux(db, package_path)
+        .as_ref()
+        .map(|lock| lock.as_ref().map_err(|e| e as &str))
+}
+
+#[salsa::tracked(return_ref)]
+pub(crate) fn package_lock_aux(
+    db: &::salsa::Db,
+    package_path: PackagePath,
+) -> Option<Result<CorgiLock, String>> {
+    let text = package_path
+        .dir(db)
+        .ok()?
+        .join(CORGI_LOCK_FILE_NAME, db)
+        .text(db)
+        .ok()??;
+    Some(CorgiLock::parse(text))
+}
+
+/// Writes the lockfile of the full dependencies next to the manifest, if it changes,
+/// where [`package_lock`] reads it back to pin the registry versions of later builds.
+pub fn write_lockfile(db: &::salsa::Db, package_path: PackagePath) -> IoResult<()> {
+    let path = package_path
+        .dir(db)
+        .and_then(|dir| dir.abs_path(db))
+        .map_err(|e| IoError::Io(PathBuf::from(CORGI_LOCK_FILE_NAME), e.to_string()))?
+        .join(CORGI_LOCK_FILE_NAME);
+    let full_dependencies = package_path
+        .full_dependencies(db)
+        .map_err(|e| IoError::Io(path.clone(), e.to_string()))?;
+    // skip the first because it's always equal to package_path
+    let text = CorgiLock::new(db, &full_dependencies[1..]).to_toml();
+    if std::fs::read_to_string(&path).is_ok_and(|text_on_disk| text_on_disk == text) {
+        return Ok(());
+    }
+    std::fs::write(&path, text).map_err(|e| IoError::Io(path, e.to_string()))
+}
+
+#[test]
+fn corgi_lock_roundtrip_works() {
+    let lock = CorgiLock {
+        packages: vec![
+            CorgiLockPackage {
+                name: "malamute".to_string(),
+                version: Some("0.1.0".to_string()),
+                source: "registry".to_string(),
+            },
+            CorgiLockPackage {
+                name: "mnist".to_string(),
+                version: None,
+                source: "git+https://github.com/husky-lang-org/mnist#a1b2c3".to_string(),
+            },
+        ],
+    };
+    let text = lock.to_toml();
+    assert!(text.starts_with(CORGI_LOCK_HEADER));
+    assert_eq!(CorgiLock::parse(&text), Ok(lock.clone()));
+    assert_eq!(
+        lock.locked_versions("malamute").collect::<Vec<_>>(),
+        vec![semver::Version::new(0, 1, 0)]
+    );
+    assert_eq!(lock.locked_versions("mnist").count(), 0);
+}
@@ -1,5 +1,5 @@
 use super::*;
-use husky_vfs::jar::VfsDb;
+use husky_vfs::{jar::VfsDb, path::package_path::PackagePathSource};
 use vec_like::VecSet;
 
 #[salsa::tracked]
@@ -68,16 +68,30 @@ pub(crate) fn full_dependent_package_paths_aux(
     let mut package_paths: VecSet<PackagePath> = VecSet::new_one_elem_set(package_path);
     package_paths.insert(db.vfs_path_menu(package_path.toolchain(db)).core_package());
     // todo: insert std??
+    let lock = package_lock(db, package_path).and_then(|lock| lock.ok());
     let mut first_unsearched = 0usize;
     while first_unsearched < package_paths.len() {
         let first_unsearched = std::mem::replace(&mut first_unsearched, package_paths.len());
         for i in first_unsearched..package_paths.len() {
-            package_paths.extend(
-                package_dependencies_unchecked(db, package_paths[i])?
-                    .data(db)
+            for dep in package_dependencies_unchecked(db, package_paths[i])?.data(db) {
+                // versions already in the graph come before the pinned ones
+                let selected_versions: Vec<semver::Version> = package_paths
                     .iter()
-                    .map(|dep| dep.package_path()),
-            );
+                    .filter(|package_path| package_path.name(db) == dep.name())
+                    .filter_map(|package_path| match package_path.data(db) {
+                        PackagePathSource::Registry { version, .. } => Some(version.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                let locked_versions = lock
+                    .into_iter()
+                    .flat_map(|lock| lock.locked_versions(dep.name().data()));
+                package_paths.insert(unify_registry_dependency(
+                    db,
+                    dep,
+                    selected_versions.into_iter().chain(locked_versions),
+                ))
+            }
         }
     }
     Ok(package_paths)
@@ -1,5 +1,8 @@
 pub(crate) use husky_vfs::test_helpers::*;
 
+use husky_path_utils::HuskyLangDevPaths;
+use husky_vfs::path::package_path::{PackagePath, PackagePathSource};
+
 #[salsa::db(
     husky_vfs::jar::VfsJar,
     husky_coword::jar::CowordJar,
@@ -12,3 +15,25 @@ pub(crate) use husky_vfs::test_helpers::*;
 )]
 #[derive(Default)]
 pub(crate) struct DB;
+
+/// the packages under `anti-examples/dependency-resolution`,
+/// whose registry is `anti-examples/dependency-resolution/registry`
+pub(crate) fn dependency_resolution_package_path(db: &::salsa::Db, name: &str) -> PackagePath {
+    PackagePath::new_local_or_toolchain_package(
+        db,
+        db.dev_toolchain().unwrap(),
+        &HuskyLangDevPaths::new()
+            .root()
+            .join("anti-examples/dependency-resolution")
+            .join(name),
+    )
+    .unwrap()
+}
+
+/// `None` unless it's a registry package
+pub(crate) fn registry_version(db: &::salsa::Db, package_path: PackagePath) -> Option<String> {
+    match package_path.data(db) {
+        PackagePathSource::Registry { version, .. } => Some(version.to_string()),
+        _ => None,
+    }
+}
@@ -10,7 +10,6 @@ pub struct VfsCache {
     corgi_install_path: FsSpecsResult<PathBuf>,
     huskyup_install_path: FsSpecsResult<PathBuf>,
     current_dir: PathBuf,
-    // watcher: Option<VfsWatcher>,
 }
 
 impl Default for VfsCache {
@@ -34,7 +33,6 @@ impl Default for VfsCache {
                 Ok(dir) => std::path::absolute(dir).expect("valid path"),
                 Err(_e) => todo!(),
             },
-            // watcher: None,
         }
     }
 }
@@ -58,15 +56,6 @@ impl VfsCache {
         self.huskyup_install_path.as_ref()
     }
 
-    // pub fn watcher(&self) -> Option<&VfsWatcher> {
-    //     self.watcher.as_ref()
-    // }
-
-    // pub(crate) fn set_watcher(&mut self, watcher: VfsWatcher) {
-    //     assert!(self.watcher.is_none());
-    //     self.watcher = Some(watcher)
-    // }
-
     pub fn current_dir(&self) -> &Path {
         &self.current_dir
     }
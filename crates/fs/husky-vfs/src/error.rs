@@ -40,6 +40,8 @@ pub enum VfsError {
     FsSpecs(#[from] FsSpecsError),
     #[error("FailToReadPackageNameFromManifest")]
     FailToReadPackageNameFromManifest,
+    #[error("watch error: {error_message}")]
+    Watch { error_message: String },
 }
 
 impl From<&VfsError> for VfsError {
@@ -64,4 +66,10 @@ impl VfsError {
             error_message: e.to_string(),
         }
     }
+
+    pub(crate) fn new_watch_error(e: notify_debouncer_mini::notify::Error) -> VfsError {
+        VfsError::Watch {
+            error_message: e.to_string(),
+        }
+    }
 }
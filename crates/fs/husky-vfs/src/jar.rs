@@ -52,20 +52,11 @@ impl VfsDbInner for Db {
             {
                 // If the file already exists in our cache then just return it.
                 Entry::Occupied(entry) => *entry.get(),
-                // If we haven't read this file yet set up the watch, read the
+                // If we haven't read this file yet, read the
                 // contents, store it in the cache, and return it.
                 Entry::Vacant(entry) => {
                     let path = abs_path.data();
-                    //  &path.path(self);
-                    // ad hoc
-                    // if let Some(watcher) = self.watcher() {
-                    //     let watcher = &mut watcher.0.lock().unwrap();
-                    //     watcher
-                    //         .watcher()
-                    //         .watch(path, RecursiveMode::NonRecursive)
-                    //         .unwrap();
-                    // }
-                    let content = read_file_content(path);
+                    let content = read_file_content(self, path);
                     *entry.insert(File::new(
                         self,
                         abs_path.clone(),
@@ -94,7 +85,7 @@ impl VfsDbInner for Db {
     where
         Db: 'static,
     {
-        let content = read_file_content(&path);
+        let content = read_file_content(self, &path);
         self.set_content(path, content)
     }
 
@@ -110,19 +101,10 @@ impl VfsDbInner for Db {
         {
             // If the file already exists in our cache then just return it.
             Entry::Occupied(entry) => *entry.get(),
-            // If we haven't read this file yet set up the watch, read the
+            // If we haven't read this file yet, read the
             // contents, store it in the cache, and return it.
             Entry::Vacant(entry) => {
-                //  &path.path(self);
-                // ad hoc
-                // if let Some(watcher) = self.watcher() {
-                //     let watcher = &mut watcher.0.lock().unwrap();
-                //     watcher
-                //         .watcher()
-                //         .watch(path, RecursiveMode::NonRecursive)
-                //         .unwrap();
-                // }
-                let content = read_file_content(path);
+                let content = read_file_content(self, path);
                 *entry.insert(File::new(self, virtual_path.clone(), content, durability))
             }
         };
@@ -255,7 +237,7 @@ impl VfsDb for Db {
     }
 }
 
-fn read_file_content(path: &Path) -> FileContent {
+pub(crate) fn read_file_content(db: &::salsa::Db, path: &Path) -> FileContent {
     if !path.exists() {
         FileContent::NotExists
     } else if path.is_file() {
@@ -264,13 +246,28 @@ fn read_file_content(path: &Path) -> FileContent {
             Err(e) => FileContent::Err(VfsError::new_io_error(path.to_owned(), e)),
         }
     } else if path.is_dir() {
-        // ad hoc
-        FileContent::Directory(vec![])
+        match read_dir_entries(db, path) {
+            Ok(entries) => FileContent::Directory(entries),
+            Err(e) => FileContent::Err(e),
+        }
     } else {
         todo!()
     }
 }
 
+/// sorted for reproducibility
+fn read_dir_entries(db: &::salsa::Db, path: &Path) -> VfsResult<Vec<VirtualPath>> {
+    let mut entries = std::fs::read_dir(path)
+        .map_err(|e| VfsError::new_io_error(path.to_owned(), e))?
+        .map(|entry| {
+            let entry = entry.map_err(|e| VfsError::new_io_error(path.to_owned(), e))?;
+            VirtualPath::try_new(db, entry.path())
+        })
+        .collect::<VfsResult<Vec<_>>>()?;
+    entries.sort_by(|a, b| a.data().cmp(b.data()));
+    Ok(entries)
+}
+
 #[salsa::jar]
 pub struct VfsJar(
     VfsCache,
@@ -315,8 +312,4 @@ impl VfsJar {
     pub(crate) fn cache(&self) -> &VfsCache {
         &self.0
     }
-
-    // pub(crate) fn set_watcher(&mut self, watcher: VfsWatcher) {
-    //     self.0.set_watcher(watcher)
-    // }
 }
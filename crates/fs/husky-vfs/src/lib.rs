@@ -17,7 +17,7 @@ pub mod test_helpers;
 mod tests;
 pub mod toolchain;
 pub mod toolchain_config;
-mod watch;
+pub mod watch;
 
 pub use self::cache::VfsCache;
 #[cfg(feature = "lsp_support")]
@@ -27,8 +27,11 @@ pub enum PackagePathSource {
     Local {
         path: VirtualPath,
     },
+    /// vendored under `<registry>/git/<name>-<rev>`, or `<registry>/git/<name>` without `rev`
     Git {
+        registry_path: RegistryPath,
         url: Url,
+        rev: Option<String>,
     },
 }
 
@@ -90,6 +93,26 @@ impl PackagePath {
         )
     }
 
+    pub fn new_git_package(
+        db: &::salsa::Db,
+        toolchain: Toolchain,
+        name: Kebab,
+        registry_path: RegistryPath,
+        url: Url,
+        rev: Option<String>,
+    ) -> Self {
+        PackagePath::new_inner(
+            db,
+            toolchain,
+            name,
+            PackagePathSource::Git {
+                registry_path,
+                url,
+                rev,
+            },
+        )
+    }
+
     pub fn ident(self, db: &::salsa::Db) -> Ident {
         self.name(db).ident(db)
     }
@@ -172,7 +195,15 @@ pub(crate) fn package_dir(db: &::salsa::Db, package: PackagePath) -> VfsResult<V
             )),
         ),
         PackagePathSource::Local { path } => Ok(path.clone()),
-        PackagePathSource::Git { .. } => todo!(),
+        PackagePathSource::Git {
+            registry_path, rev, ..
+        } => VirtualPath::try_new(
+            db,
+            registry_path.path().data().join("git").join(match rev {
+                Some(rev) => format!("{}-{}", package.name(db).data(), rev),
+                None => package.name(db).data().to_string(),
+            }),
+        ),
     }
 }
 
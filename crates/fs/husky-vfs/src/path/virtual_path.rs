@@ -83,10 +83,35 @@ impl VirtualPath {
                 VfsError::PathUtils(_) => todo!(),
                 VfsError::FsSpecs(_) => todo!(),
                 VfsError::FailToReadPackageNameFromManifest => todo!(),
+                // not raised by file lookups, passed on as is
+                VfsError::Watch { .. } => Err(e),
             },
         }
     }
 
+    pub fn is_file(self, db: &::salsa::Db) -> VfsResult<bool> {
+        Ok(matches!(
+            self.file(db)?.content(db),
+            FileContent::OnDisk(_) | FileContent::LiveDoc(_)
+        ))
+    }
+
+    pub fn is_dir(self, db: &::salsa::Db) -> VfsResult<bool> {
+        Ok(matches!(
+            self.file(db)?.content(db),
+            FileContent::Directory(_)
+        ))
+    }
+
+    /// the entries of the directory, sorted, or `None` if it's not a directory
+    pub fn read_dir(self, db: &::salsa::Db) -> VfsResult<Option<&[VirtualPath]>> {
+        match self.file(db)?.content(db) {
+            FileContent::Directory(entries) => Ok(Some(entries)),
+            FileContent::Err(e) => Err(e.clone()),
+            _ => Ok(None),
+        }
+    }
+
     pub fn text<'a>(self, db: &'a ::salsa::Db) -> VfsResult<Option<&'a str>> {
         let file = self.file(db)?;
         Ok(file.text(db)?)
@@ -1,35 +1,183 @@
 use crate::{
-    // watch::{WatchedVfs, DEBOUNCE_TEST_SLEEP_TIME},
+    watch::{refresh_changed_paths, WatchedVfs},
     *,
 };
+use notify_debouncer_mini::{notify, DebouncedEvent, DebouncedEventKind};
+use std::time::{Duration, Instant};
 
 #[salsa::db(Jar, husky_coword::jar::CowordJar)]
 #[derive(Default)]
 pub(crate) struct DB;
 
+fn content<'a>(db: &'a DB, path: &Path) -> &'a FileContent {
+    db.file_from_virtual_path(VirtualPath::try_new(db, path).unwrap())
+        .unwrap()
+        .content(db)
+}
+
+#[test]
+fn watched_vfs_refreshes_from_events() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let a_path = tempdir.path().join("a.hsy");
+    let b_path = tempdir.path().join("b.hsy");
+    std::fs::write(&a_path, "a").unwrap();
+    std::fs::write(&b_path, "b").unwrap();
+    let mut db = WatchedVfs::new(DB::default()).unwrap();
+    db.watch(tempdir.path()).unwrap();
+    assert_eq!(content(&db, &a_path), &FileContent::OnDisk("a".to_owned()));
+    assert_eq!(content(&db, &b_path), &FileContent::OnDisk("b".to_owned()));
+    let event = |path: &Path| DebouncedEvent {
+        path: path.to_owned(),
+        kind: DebouncedEventKind::Any,
+    };
+
+    std::fs::write(&a_path, "a2").unwrap();
+    std::fs::write(&b_path, "b2").unwrap();
+    assert_eq!(
+        db.refresh_from_events([Ok(vec![event(&a_path)])]).unwrap(),
+        [VirtualPath::try_new(&db, &a_path)
+            .unwrap()
+            .data()
+            .to_owned()]
+    );
+    assert_eq!(content(&db, &a_path), &FileContent::OnDisk("a2".to_owned()));
+    assert_eq!(content(&db, &b_path), &FileContent::OnDisk("b".to_owned()));
+
+    // events may be lost on a watcher error, so everything watched is reread
+    std::fs::remove_file(&a_path).unwrap();
+    assert_eq!(
+        db.refresh_from_events([Err(notify::Error::generic("queue overflow"))])
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(content(&db, &a_path), &FileContent::NotExists);
+    assert_eq!(content(&db, &b_path), &FileContent::OnDisk("b2".to_owned()));
+}
+
 #[cfg(target_os = "linux")]
 #[test]
 fn watcher_works() {
-    // let db = DB::default();
-    // let tempdir = tempfile::tempdir().unwrap();
-    // let some_pkg_dir = tempdir.path().join("somepath");
-    // std::fs::create_dir(&some_pkg_dir).unwrap();
-    // let path = some_pkg_dir.join("Corgi.toml");
-    // let abs_path: VirtualPath = VirtualPath::try_new(&db, &path).unwrap();
-    // let db = WatchedVfs::new(db);
-
-    // std::fs::write(&path, "Hello, world!").expect("can't write");
-    // assert!(db.query(|db| db
-    //     .file_from_virtual_path(abs_path)
-    //     .unwrap()
-    //     .content(db.deref())
-    //     == &FileContent::OnDisk("Hello, world!".to_owned())),);
-    // std::fs::write(&path, "Hello, world!2").expect("can't write");
-    // let _a = DEBOUNCE_TEST_SLEEP_TIME;
-    // std::thread::sleep(DEBOUNCE_TEST_SLEEP_TIME);
-    // assert!(db.query(|db| db
-    //     .file_from_virtual_path(abs_path)
-    //     .unwrap()
-    //     .content(db.deref())
-    //     == &FileContent::OnDisk("Hello, world!2".to_owned())))
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    let db = DB::default();
+    let tempdir = tempfile::tempdir().unwrap();
+    let some_pkg_dir = tempdir.path().join("somepath");
+    std::fs::create_dir(&some_pkg_dir).unwrap();
+    let path = some_pkg_dir.join("Corgi.toml");
+    let mut db = WatchedVfs::new(db).unwrap();
+    db.watch(tempdir.path()).unwrap();
+    let abs_path = VirtualPath::try_new(&db, &path).unwrap().data().to_owned();
+    // the events of a write may come in several batches, and those of earlier writes may come late
+    let refresh_until_changed = |db: &mut WatchedVfs<DB>| {
+        let deadline = Instant::now() + TIMEOUT;
+        while !db
+            .wait_and_refresh(deadline.saturating_duration_since(Instant::now()))
+            .unwrap()
+            .contains(&abs_path)
+        {
+            assert!(Instant::now() < deadline, "no change within {TIMEOUT:?}")
+        }
+    };
+
+    std::fs::write(&path, "Hello, world!").expect("can't write");
+    assert_eq!(
+        content(&db, &path),
+        &FileContent::OnDisk("Hello, world!".to_owned())
+    );
+    std::fs::write(&path, "Hello, world!2").expect("can't write");
+    refresh_until_changed(&mut db);
+    assert_eq!(
+        content(&db, &path),
+        &FileContent::OnDisk("Hello, world!2".to_owned())
+    );
+    std::fs::remove_file(&path).unwrap();
+    refresh_until_changed(&mut db);
+    assert_eq!(content(&db, &path), &FileContent::NotExists);
+}
+
+#[test]
+fn refresh_changed_paths_works() {
+    let mut db = DB::default();
+    let tempdir = tempfile::tempdir().unwrap();
+    let src_dir = tempdir.path().join("src");
+    let submodule_dir = src_dir.join("a");
+    std::fs::create_dir_all(&submodule_dir).unwrap();
+    let lib_path = src_dir.join("lib.hsy");
+    let new_module_path = src_dir.join("b.hsy");
+    let nested_module_path = submodule_dir.join("c.hsy");
+    let live_path = src_dir.join("d.hsy");
+    std::fs::write(&lib_path, "mod a").unwrap();
+    std::fs::write(&nested_module_path, "c").unwrap();
+    std::fs::write(&live_path, "d").unwrap();
+    assert_eq!(content(&db, &new_module_path), &FileContent::NotExists);
+    assert_eq!(
+        content(&db, &nested_module_path),
+        &FileContent::OnDisk("c".to_owned())
+    );
+    db.set_content(&live_path, FileContent::LiveDoc("d2".to_owned()))
+        .unwrap();
+
+    // as after a git checkout adding `b.hsy`, removing `a/` and rewriting `d.hsy`
+    std::fs::write(&new_module_path, "b").unwrap();
+    std::fs::remove_dir_all(&submodule_dir).unwrap();
+    std::fs::write(&live_path, "d3").unwrap();
+    let changed_paths = refresh_changed_paths(
+        &mut db,
+        [new_module_path.clone(), submodule_dir, live_path.clone()],
+    )
+    .unwrap();
+    assert_eq!(
+        changed_paths,
+        [&nested_module_path, &new_module_path]
+            .map(|path| VirtualPath::try_new(&db, path).unwrap().data().to_owned())
+    );
+    assert_eq!(
+        content(&db, &new_module_path),
+        &FileContent::OnDisk("b".to_owned())
+    );
+    assert_eq!(content(&db, &nested_module_path), &FileContent::NotExists);
+    assert_eq!(
+        content(&db, &live_path),
+        &FileContent::LiveDoc("d2".to_owned())
+    );
+    assert_eq!(
+        content(&db, &lib_path),
+        &FileContent::OnDisk("mod a".to_owned())
+    );
+}
+
+#[test]
+fn refresh_changed_paths_refreshes_directory_entries() {
+    let mut db = DB::default();
+    let tempdir = tempfile::tempdir().unwrap();
+    let registry_dir = tempdir.path().join("registry");
+    let old_version_dir = registry_dir.join("alpha-0.1.0");
+    let new_version_dir = registry_dir.join("alpha-0.2.0");
+    std::fs::create_dir_all(&old_version_dir).unwrap();
+    let registry_path = VirtualPath::try_new(&db, &registry_dir).unwrap();
+    let entries = |db: &DB| -> Vec<PathBuf> {
+        registry_path
+            .read_dir(db)
+            .unwrap()
+            .unwrap()
+            .iter()
+            .map(|entry| entry.data().to_owned())
+            .collect()
+    };
+    let old_version_path = VirtualPath::try_new(&db, &old_version_dir).unwrap();
+    assert_eq!(entries(&db), [old_version_path.data()]);
+    assert_eq!(old_version_path.is_dir(&db), Ok(true));
+
+    // a new entry changes the content of its parent, even if only the entry is reported
+    std::fs::create_dir(&new_version_dir).unwrap();
+    assert_eq!(
+        refresh_changed_paths(&mut db, [new_version_dir.clone()]).unwrap(),
+        [registry_path.data()]
+    );
+    let new_version_path = VirtualPath::try_new(&db, &new_version_dir).unwrap();
+    assert_eq!(
+        entries(&db),
+        [old_version_path.data(), new_version_path.data()]
+    );
 }
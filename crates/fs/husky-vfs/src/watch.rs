@@ -1,206 +1,203 @@
-// use crate::*;
-// use crossbeam_channel::{select, unbounded, Receiver, Sender};
-// use eyre::Result;
-// use notify_debouncer_mini::{
-//     new_debouncer, notify::RecommendedWatcher, DebounceEventResult, DebouncedEvent, Debouncer,
-// };
-// use std::{
-//     marker::PhantomData,
-//     sync::{Arc, Mutex},
-//     thread,
-//     time::Duration,
-// };
-
-// pub trait WatchableVfsDb: VfsDb + Send {
-//     fn watcher(&self) -> Option<&VfsWatcher>;
-// }
-
-// impl<T> WatchableVfsDb for T
-// where
-//     T: VfsDb + Send,
-// {
-//     fn watcher(&self) -> Option<&VfsWatcher> {
-//         self.vfs_jar().cache().watcher()
-//     }
-// }
-
-// #[derive(Clone)]
-// pub struct VfsWatcher(pub(crate) Arc<Mutex<Debouncer<RecommendedWatcher>>>);
-
-// impl VfsWatcher {
-//     fn new(debounce_tx: Sender<DebounceEventResult>) -> Self {
-//         VfsWatcher(Arc::new(Mutex::new(
-//             new_debouncer(DEBOUNCE_TIMEOUT, debounce_tx).unwrap(),
-//         )))
-//     }
-// }
-
-// const DEBOUNCE_TIMEOUT_RAW: u64 = 50;
-
-// const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(DEBOUNCE_TIMEOUT_RAW);
-
-// #[cfg(test)]
-// pub(crate) const DEBOUNCE_TEST_SLEEP_TIME: Duration =
-//     Duration::from_millis(DEBOUNCE_TIMEOUT_RAW * 4);
-
-// pub struct WatchedVfs {
-//     event_tx: Sender<VfsWatcherEvent>,
-//     snapshot_rx: Receiver<Snapshot>,
-// }
-
-// impl Default for WatchedVfs {
-//     fn default() -> Self {
-//         Self::new(Default::default())
-//     }
-// }
-
-// impl<DB: WatchableVfsDb> WatchedVfs {
-//     pub fn query<S>(&self, f: impl FnOnce(salsa::Snapshot) -> S) -> S {
-//         match self.event_tx.send(VfsWatcherEvent::Snapshot) {
-//             Ok(_) => (),
-//             Err(_) => todo!(),
-//         };
-//         match self.snapshot_rx.recv() {
-//             Ok(snapshot) => f(snapshot),
-//             Err(_e) => {
-//                 todo!()
-//             }
-//         }
-//     }
-// }
-
-// pub struct VfsWatcherInstance<DB: WatchableVfsDb> {
-//     db: DB,
-//     debounce_rx: Receiver<DebounceEventResult>,
-//     event_rx: Receiver<VfsWatcherEvent>,
-//     snapshot_tx: Sender<Snapshot<DB>>,
-// }
-
-// pub enum VfsWatcherEvent {
-//     Snapshot,
-//     Close,
-// }
-
-// impl<V: WatchableVfsDb> Drop for WatchedVfs<V>
-// where
-//     V: ParallelDatabase,
-// {
-//     fn drop(&mut self) {
-//         match self.event_tx.send(VfsWatcherEvent::Close) {
-//             Ok(_) => (),
-//             Err(e) => eprintln!("error {e} in sending VfsWatcherEvent::Close"),
-//         }
-//     }
-// }
-
-// impl<DB: WatchableVfsDb> WatchedVfs<DB>
-// where
-//     DB: ParallelDatabase + 'static,
-// {
-//     pub(crate) fn new(mut db: DB) -> Self {
-//         let (event_tx, event_rx) = unbounded();
-//         let (debounce_tx, debounce_rx) = unbounded();
-//         let (snapshot_tx, snapshot_rx) = unbounded();
-//         db.vfs_jar_mut().set_watcher(VfsWatcher::new(debounce_tx));
-//         thread::spawn(|| {
-//             match VfsWatcherInstance::new(db, debounce_rx, event_rx, snapshot_tx).run() {
-//                 Ok(_) => (),
-//                 Err(_) => todo!(),
-//             }
-//         });
-//         Self {
-//             event_tx,
-//             snapshot_rx,
-//         }
-//     }
-// }
-
-// impl<DB: WatchableVfsDb> VfsWatcherInstance<DB>
-// where
-//     DB: ParallelDatabase + 'static,
-// {
-//     fn new(
-//         db: DB,
-//         debounce_rx: Receiver<DebounceEventResult>,
-//         event_rx: Receiver<VfsWatcherEvent>,
-//         snapshot_tx: Sender<Snapshot<DB>>,
-//     ) -> Self {
-//         Self {
-//             db,
-//             debounce_rx,
-//             event_rx,
-//             snapshot_tx,
-//         }
-//     }
-
-//     fn run(mut self) -> Result<()> {
-//         // HELP: GENGTENG
-//         loop {
-//             select! {
-//                 recv(self.debounce_rx) -> debounce => match debounce {
-//                         Ok(Ok(debounced_events)) => self.process_debounced_events(debounced_events),
-//                         Ok(Err(_)) => todo!(),
-//                         Err(_) => todo!(),
-//                     },
-//                 recv(self.event_rx) -> event_result => match event_result {
-//                     Ok(event) => match event {
-//                         VfsWatcherEvent::Snapshot => match self.snapshot_tx.send(self.db.snapshot()) {
-//                             Ok(_) => (),
-//                             Err(_) => todo!(),
-//                         },
-//                         VfsWatcherEvent::Close => return Ok(()),
-//                     },
-//                     Err(_) => todo!(),
-//                 },
-//             }
-//         }
-//         // let initial_file_path = std::env::args_os()
-//         //     .nth(1)
-//         //     .ok_or_else(|| eyre!("Usage: ./lazy-input <input-file>"))?;
-
-//         // // Create the initial input using the input method so that changes to it
-//         // // will be watched like the other files.
-//         // let initial = db.input(initial_file_path.into())?;
-//         // loop {
-//         //     // Compile the code starting at the provided input, this will read other
-//         //     // needed files using the on-demand mechanism.
-//         //     let sum = compile(&db, initial);
-//         //     let diagnostics = compile::accumulated::<Diagnostic>(&db, initial);
-//         //     if diagnostics.is_empty() {
-//         //         println!("Sum is: {}", sum);
-//         //     } else {
-//         //         for diagnostic in diagnostics {
-//         //             println!("{}", diagnostic);
-//         //         }
-//         //     }
-
-//         //     for log in db.logs.lock().unwrap().drain(..) {
-//         //         eprintln!("{}", log);
-//         //     }
-
-//         //     // Wait for file change events, the output can't change unless the
-//         //     // inputs change.
-//         //     for event in rx.recv()?.unwrap() {
-//         //         let path = event.path.canonicalize().wrap_err_with(|| {
-//         //             format!("Failed to canonicalize path {}", event.path.display())
-//         //         })?;
-//         //         let file = match db.files.get(&path) {
-//         //             Some(file) => *file,
-//         //             None => continue,
-//         //         };
-//         //         // `path` has changed, so read it and update the contents to match.
-//         //         // This creates a new revision and causes the incremental algorithm
-//         //         // to kick in, just like any other update to a salsa input.
-//         //         let contents = std::fs::read_to_string(path)
-//         //             .wrap_err_with(|| format!("Failed to read file {}", event.path.display()))?;
-//         //         file.set_contents(&mut db).to(contents);
-//         //     }
-//         // }
-//     }
-
-//     fn process_debounced_events(&mut self, events: Vec<DebouncedEvent>) {
-//         for event in events {
-//             self.db.refresh_file_from_disk(&event.path).unwrap()
-//         }
-//     }
-// }
+use crate::*;
+use crossbeam_channel::{Receiver, RecvTimeoutError};
+pub use notify_debouncer_mini::DebounceEventResult;
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    Debouncer,
+};
+use salsa::Db;
+use std::time::Duration;
+
+pub trait WatchableVfsDb: std::ops::Deref<Target = Db> + std::ops::DerefMut {}
+
+impl<T> WatchableVfsDb for T where T: std::ops::Deref<Target = Db> + std::ops::DerefMut {}
+
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// A db whose cached files follow the disk.
+///
+/// Debounced create, modify, delete and rename events under the watched directories queue up
+/// until a refresh feeds them into the vfs cache, bumping the salsa inputs of the files whose
+/// content changed. Refreshing is up to the owner, so that the db is never mutated behind its
+/// back, e.g. in the middle of a request. Owners with an event loop select on [`Self::events`]
+/// and call [`Self::refresh_with`], the others call [`Self::refresh`] before serving requests.
+pub struct WatchedVfs<DB: WatchableVfsDb> {
+    db: DB,
+    debouncer: Debouncer<RecommendedWatcher>,
+    events: Receiver<DebounceEventResult>,
+    watched_dirs: Vec<PathBuf>,
+}
+
+impl<DB: WatchableVfsDb> WatchedVfs<DB> {
+    pub fn new(db: DB) -> VfsResult<Self> {
+        let (sender, events) = crossbeam_channel::unbounded();
+        let debouncer = new_debouncer(DEBOUNCE_TIMEOUT, move |res: DebounceEventResult| {
+            // the receiver is only gone when `self` is being dropped
+            let _ = sender.send(res);
+        })
+        .map_err(VfsError::new_watch_error)?;
+        Ok(Self {
+            db,
+            debouncer,
+            events,
+            watched_dirs: vec![],
+        })
+    }
+
+    /// Watches `dir` recursively, including the directories created in it later.
+    ///
+    /// Does nothing if `dir` is already watched.
+    pub fn watch(&mut self, dir: &Path) -> VfsResult<()> {
+        let dir = std::path::absolute(dir).map_err(|e| VfsError::FailToAbsolutize {
+            path: dir.to_owned(),
+            error_message: e.to_string(),
+        })?;
+        if self
+            .watched_dirs
+            .iter()
+            .any(|watched_dir| dir.starts_with(watched_dir))
+        {
+            return Ok(());
+        }
+        self.debouncer
+            .watcher()
+            .watch(&dir, RecursiveMode::Recursive)
+            .map_err(VfsError::new_watch_error)?;
+        self.watched_dirs.push(dir);
+        Ok(())
+    }
+
+    /// Watches the directories of the live packages, see [`VfsDb::live_packages`].
+    pub fn watch_live_packages(&mut self) -> VfsResult<()> {
+        let db: &Db = &self.db;
+        let package_dirs = db
+            .live_packages()
+            // the set of live packages stays valid even if a writer panicked
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|package_path| Ok(package_path.dir(db)?.data().to_owned()))
+            .collect::<VfsResult<Vec<PathBuf>>>()?;
+        for package_dir in package_dirs {
+            self.watch(&package_dir)?
+        }
+        Ok(())
+    }
+
+    /// Debounced events not yet refreshed from.
+    pub fn events(&self) -> &Receiver<DebounceEventResult> {
+        &self.events
+    }
+
+    /// Refreshes from the events received so far.
+    ///
+    /// Returns the paths whose content changed.
+    pub fn refresh(&mut self) -> VfsResult<Vec<PathBuf>> {
+        let events: Vec<_> = self.events.try_iter().collect();
+        self.refresh_from_events(events)
+    }
+
+    /// Refreshes from `received`, taken from [`Self::events`], together with the events received so far.
+    ///
+    /// Returns the paths whose content changed.
+    pub fn refresh_with(&mut self, received: DebounceEventResult) -> VfsResult<Vec<PathBuf>> {
+        let events: Vec<_> = std::iter::once(received)
+            .chain(self.events.try_iter())
+            .collect();
+        self.refresh_from_events(events)
+    }
+
+    /// Waits at most `timeout` for events and then refreshes from all of them.
+    ///
+    /// Returns the paths whose content changed, which is empty if nothing arrives in time.
+    pub fn wait_and_refresh(&mut self, timeout: Duration) -> VfsResult<Vec<PathBuf>> {
+        match self.events.recv_timeout(timeout) {
+            Ok(received) => self.refresh_with(received),
+            Err(RecvTimeoutError::Timeout) => Ok(vec![]),
+            Err(RecvTimeoutError::Disconnected) => unreachable!("the debouncer lives in `self`"),
+        }
+    }
+
+    /// A watcher error means events may be lost, e.g. after an overflow of the OS queue,
+    /// so everything cached under the watched directories is reread.
+    pub(crate) fn refresh_from_events(
+        &mut self,
+        events: impl IntoIterator<Item = DebounceEventResult>,
+    ) -> VfsResult<Vec<PathBuf>> {
+        let mut changed_paths: Vec<PathBuf> = vec![];
+        for res in events {
+            match res {
+                Ok(events) => changed_paths.extend(events.into_iter().map(|event| event.path)),
+                Err(e) => {
+                    tracing::warn!("vfs watcher error: {e}, rereading all watched files");
+                    changed_paths.extend(self.watched_dirs.iter().cloned())
+                }
+            }
+        }
+        if changed_paths.is_empty() {
+            return Ok(vec![]);
+        }
+        refresh_changed_paths(&mut self.db, changed_paths)
+    }
+}
+
+impl<DB: WatchableVfsDb> std::ops::Deref for WatchedVfs<DB> {
+    type Target = DB;
+
+    fn deref(&self) -> &Self::Target {
+        &self.db
+    }
+}
+
+/// For changes not coming from the disk, like live documents from an editor.
+impl<DB: WatchableVfsDb> std::ops::DerefMut for WatchedVfs<DB> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.db
+    }
+}
+
+/// Rereads every cached file at or under the changed paths, and sets the content of those that differ.
+///
+/// Returns the paths whose content changed, sorted.
+///
+/// A directory removed or renamed as a whole, e.g. by a git checkout, may be reported without its
+/// descendants, hence the descendants. Paths queried before they exist, like the file of a module
+/// not yet added or a missing `Corgi.toml`, are cached as [`FileContent::NotExists`] and get
+/// refreshed when created. Paths never queried have no dependents, so there's nothing to bump.
+/// Directories are refreshed when their entries change, as their content is the list of entries.
+///
+/// Live documents are owned by the editor and are left alone.
+pub(crate) fn refresh_changed_paths(
+    db: &mut Db,
+    changed_paths: impl IntoIterator<Item = PathBuf>,
+) -> VfsResult<Vec<PathBuf>> {
+    let changed_paths = changed_paths
+        .into_iter()
+        .map(|path| Ok(VirtualPathBuf::try_new(db, &path)?.path().to_owned()))
+        .collect::<VfsResult<Vec<_>>>()?;
+    let mut updates: Vec<(PathBuf, FileContent)> = vec![];
+    for entry in db.vfs_cache().files().iter() {
+        let path = entry.key();
+        if !changed_paths.iter().any(|changed_path| {
+            path.starts_with(changed_path) || changed_path.parent() == Some(path.as_path())
+        }) {
+            continue;
+        }
+        let content = entry.value().content(db);
+        if let FileContent::LiveDoc(_) = content {
+            continue;
+        }
+        let new_content = read_file_content(db, path);
+        if &new_content != content {
+            updates.push((path.clone(), new_content))
+        }
+    }
+    // sort is important for reproducibility
+    updates.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut updated_paths = vec![];
+    for (path, content) in updates {
+        db.set_content(&path, content)?;
+        updated_paths.push(path)
+    }
+    Ok(updated_paths)
+}
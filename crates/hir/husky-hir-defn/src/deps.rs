@@ -112,6 +112,7 @@ impl<'a> HirDefnDepsBuilder<'a> {
                 }
                 HirEagerExprData::As { opd: _, ty } => self.add_hir_ty(ty),
                 HirEagerExprData::Closure { .. } => (),
+                HirEagerExprData::ClosureCall { .. } => (),
             }
         }
     }
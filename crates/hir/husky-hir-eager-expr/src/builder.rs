@@ -278,6 +278,12 @@ impl<'db> HirEagerExprBuilder<'db> {
         &self.hir_eager_expr_arena
     }
 
+    pub(crate) fn variable_to_hir_eager_runtime_variable_map(
+        &self,
+    ) -> &VariableMap<HirEagerRuntimeVariableIdx> {
+        &self.variable_to_hir_eager_runtime_variable_map
+    }
+
     pub(crate) fn syn_pattern_ty(&self, syn_pattern: SynPatternIdx) -> EthTerm {
         self.sem_expr_region_data
             .syn_pattern_ty(syn_pattern, self.db)
@@ -1,7 +1,9 @@
 mod call_list;
+mod closure;
 mod html;
 
 pub use self::call_list::*;
+pub use self::closure::*;
 pub use self::html::*;
 
 use crate::{
@@ -209,6 +211,12 @@ pub enum HirEagerExprData {
         parameters: SmallVec<[HirEagerClosureParameterPattern; 4]>,
         return_ty: Option<HirType>,
         body: HirEagerExprIdx,
+        captures: HirEagerClosureCaptures,
+    },
+    /// call of a runtime ritchie value, say a closure
+    ClosureCall {
+        function: HirEagerExprIdx,
+        arguments: SmallVec<[HirEagerRitchieArgument; 4]>,
     },
     // todo: handle container
     EmptyHtmlTag {
@@ -464,7 +472,10 @@ impl ToHirEager for SemExprIdx {
                         //     ..
                         // } => todo!(),
                     },
-                    _ => todo!(),
+                    _ => HirEagerExprData::ClosureCall {
+                        function: function_sem_expr_idx.to_hir_eager(builder),
+                        arguments: item_groups,
+                    },
                 }
             }
             SemExprData::Ritchie { .. } => todo!(),
@@ -617,19 +628,30 @@ impl ToHirEager for SemExprIdx {
                 stmts: stmts.to_hir_eager(builder),
             },
             SemExprData::Closure {
+                closure_kind_regional_token_idx,
+                lvert_regional_token_idx,
                 ref parameter_obelisks,
                 return_ty,
                 body,
                 ..
-            } => HirEagerExprData::Closure {
-                parameters: parameter_obelisks
+            } => {
+                let closure_start = builder.closure_start(
+                    closure_kind_regional_token_idx.unwrap_or(lvert_regional_token_idx),
+                );
+                let parameters = parameter_obelisks
                     .iter()
                     .map(|param| param.to_hir_eager(builder))
-                    .collect(),
-                return_ty: return_ty
-                    .map(|(_, return_ty, _)| builder.expr_term_hir_ty(return_ty).unwrap()),
-                body: body.to_hir_eager(builder),
-            },
+                    .collect();
+                let return_ty =
+                    return_ty.map(|(_, return_ty, _)| builder.expr_term_hir_ty(return_ty).unwrap());
+                let body = body.to_hir_eager(builder);
+                HirEagerExprData::Closure {
+                    parameters,
+                    return_ty,
+                    body,
+                    captures: builder.closure_captures(closure_start),
+                }
+            }
         };
         let ty = self.ty(builder.sem_expr_arena_ref2());
         let base_ty = HirType::from_fly_base(
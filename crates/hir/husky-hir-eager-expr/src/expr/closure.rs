@@ -0,0 +1,87 @@
+use super::*;
+use husky_regional_token::RegionalTokenIdx;
+
+/// recorded right before a closure is lowered
+///
+/// every expression allocated after this point and before the closure expression itself
+/// belongs to the closure, and every variable declared from `regional_token_idx` on
+/// is bound within the closure, whatever the form of its declaration.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HirEagerClosureStart {
+    expr: usize,
+    regional_token_idx: RegionalTokenIdx,
+}
+
+/// a runtime variable bound outside of a closure but used inside of it
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct HirEagerClosureCapture {
+    variable_idx: HirEagerRuntimeVariableIdx,
+    is_always_copyable: bool,
+}
+
+impl HirEagerClosureCapture {
+    pub fn variable_idx(self) -> HirEagerRuntimeVariableIdx {
+        self.variable_idx
+    }
+
+    pub fn is_always_copyable(self) -> bool {
+        self.is_always_copyable
+    }
+}
+
+pub type HirEagerClosureCaptures = SmallVec<[HirEagerClosureCapture; 4]>;
+
+impl<'db> HirEagerExprBuilder<'db> {
+    /// `regional_token_idx` is the first token of the closure
+    pub(crate) fn closure_start(
+        &self,
+        regional_token_idx: RegionalTokenIdx,
+    ) -> HirEagerClosureStart {
+        HirEagerClosureStart {
+            expr: self.hir_eager_expr_arena().len(),
+            regional_token_idx,
+        }
+    }
+
+    /// captures ordered by variable index
+    pub(crate) fn closure_captures(&self, start: HirEagerClosureStart) -> HirEagerClosureCaptures {
+        let mut captures = HirEagerClosureCaptures::default();
+        for (_, entry) in self
+            .hir_eager_expr_arena()
+            .indexed_iter_with_start(start.expr)
+        {
+            if let HirEagerExprData::RuntimeVariable(variable_idx) = *entry.data()
+                && !self.is_variable_declared_from(variable_idx, start.regional_token_idx)
+                && !captures
+                    .iter()
+                    .any(|capture| capture.variable_idx == variable_idx)
+            {
+                captures.push(HirEagerClosureCapture {
+                    variable_idx,
+                    is_always_copyable: entry.is_base_ty_always_copyable(),
+                })
+            }
+        }
+        captures.sort_by_key(|capture| capture.variable_idx.index());
+        captures
+    }
+
+    /// inherited variables and `self` are declared before anything in the region
+    fn is_variable_declared_from(
+        &self,
+        variable_idx: HirEagerRuntimeVariableIdx,
+        regional_token_idx: RegionalTokenIdx,
+    ) -> bool {
+        let Some(current_variable_idx) = self
+            .variable_to_hir_eager_runtime_variable_map()
+            .get_current_variable_idx_by_value(&variable_idx)
+        else {
+            return false;
+        };
+        self.syn_expr_region_data()
+            .variable_region()
+            .current_variable_arena()[current_variable_idx]
+            .access_start()
+            >= regional_token_idx
+    }
+}
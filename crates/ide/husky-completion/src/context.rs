@@ -0,0 +1,190 @@
+use crate::{item::*, keyword::keyword_completion_items, region::*, *};
+use husky_entity_path::path::{major_item::MajorItemPath, EntityPath};
+use husky_entity_tree::{presheet::UseOneRuleState, sheet::HasEntityTreeSheet};
+use husky_token::TokenDb;
+use husky_token_data::{Punctuation, TokenData};
+use husky_token_info::{TokenInfoData, TokenInfoDb};
+use lsp_types::CompletionItem;
+
+pub(crate) enum CompletionContext {
+    /// right after `.`, completing fields and methods of the receiver
+    Field {
+        module_path: ModulePath,
+        receiver_token_idx: TokenIdx,
+    },
+    /// right after `::`, completing items under the parent
+    Path {
+        module_path: ModulePath,
+        parent_token_idx: TokenIdx,
+    },
+    /// completing variables in scope, items of the module and keywords
+    Scope {
+        module_path: ModulePath,
+        /// the last token before the cursor, `None` at the start of the file
+        token_idx: Option<TokenIdx>,
+    },
+}
+
+impl CompletionContext {
+    pub(crate) fn new(
+        db: &::salsa::Db,
+        module_path: ModulePath,
+        position: TextPosition,
+        trigger_character: Option<&str>,
+    ) -> Option<Self> {
+        let ranged_token_sheet = db.ranged_token_sheet(module_path);
+        let tokens = ranged_token_sheet.tokens(db);
+        let ranges = ranged_token_sheet.token_text_ranges();
+        let len = ranges.partition_point(|range| range.start < position);
+        if len == 0 {
+            return Some(CompletionContext::Scope {
+                module_path,
+                token_idx: None,
+            });
+        }
+        let last = len - 1;
+        // skip the word being typed
+        let context_index = match tokens[last] {
+            TokenData::Ident(_) | TokenData::Keyword(_) if ranges[last].end >= position => {
+                last.checked_sub(1)
+            }
+            _ => Some(last),
+        };
+        if let Some(index) = context_index.filter(|&index| index > 0) {
+            if tokens[index] == TokenData::Punctuation(Punctuation::DOT) {
+                return Some(CompletionContext::Field {
+                    module_path,
+                    receiver_token_idx: token_idx(index - 1),
+                });
+            }
+            if tokens[index] == TokenData::Punctuation(Punctuation::COLON_COLON) {
+                return Some(CompletionContext::Path {
+                    module_path,
+                    parent_token_idx: token_idx(index - 1),
+                });
+            }
+        }
+        match trigger_character {
+            // `.` in literals or `:` not followed by another `:`
+            Some(_) => None,
+            None => Some(CompletionContext::Scope {
+                module_path,
+                token_idx: Some(token_idx(last)),
+            }),
+        }
+    }
+
+    pub(crate) fn completion_items(self, db: &::salsa::Db) -> Vec<CompletionItem> {
+        match self {
+            CompletionContext::Field {
+                module_path,
+                receiver_token_idx,
+            } => field_completion_items(db, module_path, receiver_token_idx),
+            CompletionContext::Path {
+                module_path,
+                parent_token_idx,
+            } => path_completion_items(db, module_path, parent_token_idx),
+            CompletionContext::Scope {
+                module_path,
+                token_idx,
+            } => scope_completion_items(db, module_path, token_idx),
+        }
+    }
+}
+
+fn token_idx(index: usize) -> TokenIdx {
+    unsafe { TokenIdx::from_usize_index_ext(index) }
+}
+
+fn field_completion_items(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    receiver_token_idx: TokenIdx,
+) -> Vec<CompletionItem> {
+    match receiver_ty_path(db, module_path, receiver_token_idx) {
+        Some(ty_path) => ty_member_completion_items(db, ty_path),
+        None => vec![],
+    }
+}
+
+fn path_completion_items(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    parent_token_idx: TokenIdx,
+) -> Vec<CompletionItem> {
+    let Some(parent) = token_entity_path(db, module_path, parent_token_idx) else {
+        return vec![];
+    };
+    match parent {
+        EntityPath::Module(_, parent_module_path) => {
+            module_symbol_completion_items(db, parent_module_path, module_path)
+        }
+        EntityPath::MajorItem(MajorItemPath::Type(ty_path)) => {
+            ty_assoc_completion_items(db, ty_path)
+        }
+        EntityPath::MajorItem(MajorItemPath::Trait(trai_path)) => {
+            trai_assoc_completion_items(db, trai_path)
+        }
+        EntityPath::MajorItem(MajorItemPath::Form(_))
+        | EntityPath::AssocItem(_)
+        | EntityPath::TypeVariant(_, _)
+        | EntityPath::ImplBlock(_)
+        | EntityPath::Attr(_, _) => vec![],
+    }
+}
+
+fn scope_completion_items(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: Option<TokenIdx>,
+) -> Vec<CompletionItem> {
+    let mut items = vec![];
+    if let Some(token_idx) = token_idx {
+        items.extend(variable_completion_items(db, module_path, token_idx))
+    }
+    items.extend(module_symbol_completion_items(db, module_path, module_path));
+    items.extend(keyword_completion_items());
+    items
+}
+
+/// the entity the token before `::` refers to
+fn token_entity_path(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: TokenIdx,
+) -> Option<EntityPath> {
+    let token_info_sheet = db.token_info_sheet_ref(module_path).ok()?;
+    token_info_sheet[token_idx]
+        .iter()
+        .rev()
+        .find_map(|token_info| match *token_info.data() {
+            TokenInfoData::Entity(path) => Some(path),
+            TokenInfoData::UseExpr {
+                state:
+                    UseOneRuleState::Resolved {
+                        original_symbol: Some(original_symbol),
+                    },
+                ..
+            } => Some(original_symbol.principal_entity_path(db).into()),
+            _ => None,
+        })
+}
+
+fn module_symbol_completion_items(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    reference_module_path: ModulePath,
+) -> Vec<CompletionItem> {
+    module_path
+        .item_tree_sheet(db)
+        .module_symbols()
+        .visible_entries(db, reference_module_path.into())
+        .map(|entry| {
+            entity_completion_item(
+                entry.ident(),
+                entry.symbol().principal_entity_path(db).into(),
+                db,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,146 @@
+use crate::*;
+use husky_coword::Ident;
+use husky_dec_signature::signature::{major_item::ty::TypeDecTemplate, HasDecTemplate};
+use husky_entity_kind::{
+    EntityKind, MajorFormKind, MajorItemKind, TraitItemKind, TypeItemKind, TypeKind,
+};
+use husky_entity_path::path::{
+    major_item::{trai::TraitPath, ty::TypePath},
+    EntityPath,
+};
+use husky_entity_tree::node::{
+    assoc_item::ty_item::HasItemPathsMap, ty_variant::HasTypeVariantPaths, HasAssocItemPaths,
+};
+use lsp_types::{CompletionItem, CompletionItemKind};
+
+fn completion_item(ident: Ident, kind: CompletionItemKind) -> CompletionItem {
+    CompletionItem {
+        label: ident.data().to_string(),
+        kind: Some(kind),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn variable_completion_item(ident: Ident) -> CompletionItem {
+    completion_item(ident, CompletionItemKind::VARIABLE)
+}
+
+pub(crate) fn entity_completion_item(
+    ident: Ident,
+    path: EntityPath,
+    db: &::salsa::Db,
+) -> CompletionItem {
+    let kind = match path.item_kind(db) {
+        EntityKind::Module => CompletionItemKind::MODULE,
+        EntityKind::MajorItem {
+            module_item_kind, ..
+        } => match module_item_kind {
+            MajorItemKind::Type(TypeKind::Enum | TypeKind::Inductive) => CompletionItemKind::ENUM,
+            MajorItemKind::Type(_) => CompletionItemKind::STRUCT,
+            MajorItemKind::Form(MajorFormKind::Ritchie(_)) => CompletionItemKind::FUNCTION,
+            MajorItemKind::Form(MajorFormKind::TypeAlias | MajorFormKind::TypeVar) => {
+                CompletionItemKind::TYPE_PARAMETER
+            }
+            MajorItemKind::Form(MajorFormKind::StaticMut | MajorFormKind::StaticVar) => {
+                CompletionItemKind::VARIABLE
+            }
+            MajorItemKind::Form(_) => CompletionItemKind::CONSTANT,
+            MajorItemKind::Trait => CompletionItemKind::INTERFACE,
+        },
+        EntityKind::AssocItem { .. } => CompletionItemKind::FUNCTION,
+        EntityKind::TypeVariant => CompletionItemKind::ENUM_MEMBER,
+        EntityKind::ImplBlock | EntityKind::Attr | EntityKind::Script => CompletionItemKind::TEXT,
+    };
+    completion_item(ident, kind)
+}
+
+fn ty_item_completion_item_kind(ty_item_kind: TypeItemKind) -> CompletionItemKind {
+    match ty_item_kind {
+        TypeItemKind::MethodRitchie(_) => CompletionItemKind::METHOD,
+        TypeItemKind::MemoizedField => CompletionItemKind::FIELD,
+        TypeItemKind::AssocRitchie(_) => CompletionItemKind::FUNCTION,
+        TypeItemKind::AssocType => CompletionItemKind::TYPE_PARAMETER,
+        TypeItemKind::AssocStaticMut | TypeItemKind::AssocStaticVar => CompletionItemKind::VARIABLE,
+        TypeItemKind::AssocVal | TypeItemKind::AssocConceptual | TypeItemKind::AssocCompterm => {
+            CompletionItemKind::CONSTANT
+        }
+    }
+}
+
+fn trai_item_completion_item_kind(trai_item_kind: TraitItemKind) -> CompletionItemKind {
+    match trai_item_kind {
+        TraitItemKind::MethodRitchie(_) => CompletionItemKind::METHOD,
+        TraitItemKind::MemoizedField => CompletionItemKind::FIELD,
+        TraitItemKind::AssocRitchie(_) => CompletionItemKind::FUNCTION,
+        TraitItemKind::AssocType => CompletionItemKind::TYPE_PARAMETER,
+        TraitItemKind::AssocStaticMut | TraitItemKind::AssocStaticVar => {
+            CompletionItemKind::VARIABLE
+        }
+        TraitItemKind::AssocVal | TraitItemKind::AssocConceptual | TraitItemKind::AssocCompterm => {
+            CompletionItemKind::CONSTANT
+        }
+    }
+}
+
+/// fields, memoized fields and methods, i.e., what can follow `.`
+pub(crate) fn ty_member_completion_items(
+    db: &::salsa::Db,
+    ty_path: TypePath,
+) -> Vec<CompletionItem> {
+    let mut items: Vec<CompletionItem> = vec![];
+    if let Ok(TypeDecTemplate::PropsStruct(tmpl)) = ty_path.dec_template(db) {
+        items.extend(
+            tmpl.fields(db)
+                .iter()
+                .map(|field| completion_item(field.ident(), CompletionItemKind::FIELD)),
+        )
+    }
+    items.extend(ty_path.item_paths_map(db).iter().filter_map(
+        |&(ident, (ty_item_kind, ref result))| {
+            result.as_ref().ok()?;
+            match ty_item_kind {
+                TypeItemKind::MethodRitchie(_) | TypeItemKind::MemoizedField => Some(
+                    completion_item(ident, ty_item_completion_item_kind(ty_item_kind)),
+                ),
+                _ => None,
+            }
+        },
+    ));
+    items
+}
+
+/// variants and associated items, i.e., what can follow `::`
+pub(crate) fn ty_assoc_completion_items(
+    db: &::salsa::Db,
+    ty_path: TypePath,
+) -> Vec<CompletionItem> {
+    let variants = ty_path
+        .ty_variant_paths(db)
+        .iter()
+        .map(|&(ident, _)| completion_item(ident, CompletionItemKind::ENUM_MEMBER));
+    let items =
+        ty_path
+            .item_paths_map(db)
+            .iter()
+            .filter_map(|&(ident, (ty_item_kind, ref result))| {
+                result.as_ref().ok()?;
+                Some(completion_item(
+                    ident,
+                    ty_item_completion_item_kind(ty_item_kind),
+                ))
+            });
+    variants.chain(items).collect()
+}
+
+pub(crate) fn trai_assoc_completion_items(
+    db: &::salsa::Db,
+    trai_path: TraitPath,
+) -> Vec<CompletionItem> {
+    trai_path
+        .assoc_item_paths(db)
+        .iter()
+        .map(|&(ident, path)| {
+            completion_item(ident, trai_item_completion_item_kind(path.item_kind(db)))
+        })
+        .collect()
+}
@@ -0,0 +1,38 @@
+use lsp_types::{CompletionItem, CompletionItemKind, InsertTextFormat};
+
+/// keywords with snippets following the indentation-based syntax
+const KEYWORD_SNIPPETS: &[(&str, &str)] = &[
+    ("let", "let ${1:name} = ${2:value}"),
+    ("if", "if ${1:condition}:\n    $0"),
+    ("elif", "elif ${1:condition}:\n    $0"),
+    ("else", "else:\n    $0"),
+    ("match", "match ${1:value} with\n| ${2:pattern} => $0"),
+    ("for", "for ${1:range}:\n    $0"),
+    ("forext", "forext ${1:range}:\n    $0"),
+    ("while", "while ${1:condition}:\n    $0"),
+    ("do", "do while ${1:condition}:\n    $0"),
+    ("break", "break"),
+    ("return", "return $0"),
+    ("assert", "assert ${1:condition}"),
+    ("require", "require ${1:condition}"),
+    ("use", "use ${1:path}"),
+    ("mod", "mod ${1:name}"),
+    ("pub", "pub "),
+    ("fn", "fn ${1:name}(${2}) -> ${3:Type}:\n    $0"),
+    ("struct", "struct ${1:Name} {\n    $0\n}"),
+    ("enum", "enum ${1:Name}\n| $0"),
+    ("trait", "trait ${1:Name}:\n    $0"),
+    ("impl", "impl ${1:Type}:\n    $0"),
+];
+
+pub(crate) fn keyword_completion_items() -> impl Iterator<Item = CompletionItem> {
+    KEYWORD_SNIPPETS
+        .iter()
+        .map(|&(keyword, snippet)| CompletionItem {
+            label: keyword.to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            insert_text: Some(snippet.to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            ..Default::default()
+        })
+}
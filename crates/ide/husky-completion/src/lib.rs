@@ -1,3 +1,18 @@
+//! context-aware completion, robust against half-typed code
+//!
+//! the context is decided by tokens alone,
+//! and semantic information is taken from the nearest region that still parses
+mod context;
+mod item;
 mod jar;
+mod keyword;
+mod region;
+#[cfg(test)]
+mod tests;
 
 pub use self::jar::*;
+
+use self::context::*;
+use husky_text_protocol::position::TextPosition;
+use husky_token::TokenIdx;
+use husky_vfs::path::module_path::ModulePath;
@@ -0,0 +1,114 @@
+use crate::{item::variable_completion_item, *};
+use either::Either;
+use husky_entity_path::{
+    path::major_item::ty::{PreludeTypePath, TypePath},
+    region::RegionPath,
+};
+use husky_entity_tree::helpers::{paths::module_item_paths, tokra_region::HasRegionalTokenIdxBase};
+use husky_fly_term::{FlyBaseTypeData, FlyTerm, FlyTerms};
+use husky_regional_token::RegionalTokenIdx;
+use husky_sem_expr::helpers::{
+    path::{sem_expr_region_from_region_path, syn_expr_region_from_region_path},
+    range::sem_expr_range_region,
+};
+use husky_syn_expr::variable::InheritedVariableKind;
+use lsp_types::CompletionItem;
+
+/// the region containing the token, with definitions tried before declarations
+pub(crate) fn enclosing_region(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: TokenIdx,
+) -> Option<(RegionPath, RegionalTokenIdx)> {
+    module_item_paths(db, module_path)
+        .iter()
+        .flat_map(|&item_path| {
+            [
+                RegionPath::ItemDefn(item_path),
+                RegionPath::ItemDecl(item_path),
+            ]
+        })
+        .find_map(|region_path| {
+            let regional_token_idx_base = region_path.regional_token_idx_base(db)?;
+            let start = regional_token_idx_base.index_base();
+            (start..start + regional_token_idx_base.len())
+                .contains(&token_idx.index())
+                .then(|| {
+                    (
+                        region_path,
+                        RegionalTokenIdx::from_token_idx(token_idx, regional_token_idx_base),
+                    )
+                })
+        })
+}
+
+/// the base type of the smallest expression ending at the token before `.`
+pub(crate) fn receiver_ty_path(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    receiver_token_idx: TokenIdx,
+) -> Option<TypePath> {
+    let (region_path, regional_token_idx) = enclosing_region(db, module_path, receiver_token_idx)?;
+    let sem_expr_region = sem_expr_region_from_region_path(region_path, db)?;
+    let sem_expr_region_data = sem_expr_region.data(db);
+    let range_region_data = sem_expr_range_region(db, sem_expr_region).data(db);
+    let receiver = sem_expr_region_data
+        .sem_expr_arena()
+        .indexed_iter()
+        .map(|(sem_expr_idx, _)| sem_expr_idx)
+        .filter(|&sem_expr_idx| {
+            range_region_data[sem_expr_idx].end().index() == regional_token_idx.index() + 1
+        })
+        .min_by_key(|&sem_expr_idx| {
+            let range = &range_region_data[sem_expr_idx];
+            range.end().index() - range.start().index()
+        })?;
+    let ty = *receiver
+        .ty_result(sem_expr_region_data.sem_expr_arena2())
+        .as_ref()
+        .ok()?;
+    base_ty_path(db, ty, sem_expr_region_data.fly_term_region().terms())
+}
+
+/// see through indirections like `&` and `~`
+fn base_ty_path(db: &::salsa::Db, ty: FlyTerm, terms: &FlyTerms) -> Option<TypePath> {
+    match ty.base_ty_data_inner(db, terms) {
+        FlyBaseTypeData::TypeOntology {
+            refined_ty_path: Either::Left(PreludeTypePath::Indirection(_)),
+            ty_arguments: &[argument],
+            ..
+        } => base_ty_path(db, argument, terms),
+        FlyBaseTypeData::TypeOntology { ty_path, .. } => Some(ty_path),
+        _ => None,
+    }
+}
+
+/// variables of the enclosing region accessible at the token and those inherited from ancestors
+pub(crate) fn variable_completion_items(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: TokenIdx,
+) -> Vec<CompletionItem> {
+    let Some((region_path, regional_token_idx)) = enclosing_region(db, module_path, token_idx)
+    else {
+        return vec![];
+    };
+    let Some(syn_expr_region) = syn_expr_region_from_region_path(region_path, db) else {
+        return vec![];
+    };
+    let variable_region = syn_expr_region.data(db).variable_region();
+    let current_variables = variable_region
+        .current_variables()
+        .filter(|entry| {
+            entry.access_start() <= regional_token_idx
+                && entry
+                    .access_end()
+                    .map_or(true, |end| regional_token_idx < end.regional_token_idx())
+        })
+        .filter_map(|entry| Some(variable_completion_item(entry.ident()?)));
+    let inherited_variables = variable_region
+        .inherited_variables()
+        .filter(|entry| !matches!(entry.kind(), InheritedVariableKind::ReplLocal))
+        .filter_map(|entry| Some(variable_completion_item(entry.ident()?)));
+    current_variables.chain(inherited_variables).collect()
+}
@@ -0,0 +1,205 @@
+pub(crate) use husky_ast::test_helpers::*;
+
+use crate::*;
+use husky_corgi_config::jar::CorgiConfigJar;
+use husky_corgi_config_ast::CorgiConfigAstJar;
+use husky_coword::jar::CowordJar;
+use husky_dec_signature::jar::DecSignatureJar;
+use husky_entity_tree::jar::EntityTreeJar;
+use husky_eth_signature::jar::EthSignatureJar;
+use husky_eth_term::jar::EthTermJar;
+use husky_fly_term::jar::FlyTermJar;
+use husky_manifest::jar::ManifestJar;
+use husky_manifest_ast::jar::ManifestAstJar;
+use husky_path_utils::HuskyLangDevPaths;
+use husky_sem_expr::SemExprJar;
+use husky_syn_decl::jar::SynDeclJar;
+use husky_syn_defn::jar::SynDefnJar;
+use husky_syn_expr::jar::SynExprJar;
+use husky_term_prelude::jar::TermPreludeJar;
+use husky_token::{TokenDb, TokenJar};
+use husky_token_data::{Punctuation, TokenData};
+use husky_token_info::TokenInfoJar;
+use husky_toml_ast::TomlAstJar;
+use husky_vfs::jar::VfsDb;
+use lsp_types::{CompletionItemKind, CompletionResponse};
+
+#[salsa::db(
+    husky_vfs::jar::VfsJar,
+    CowordJar,
+    husky_text::jar::TextJar,
+    husky_token_data::jar::TokenDataJar,
+    TokenJar,
+    TokenInfoJar,
+    husky_entity_path::jar::EntityPathJar,
+    husky_toml_token::jar::TomlTokenJar,
+    TomlAstJar,
+    ManifestAstJar,
+    CorgiConfigJar,
+    CorgiConfigAstJar,
+    ManifestJar,
+    husky_ast::jar::AstJar,
+    EntityTreeJar,
+    SynDeclJar,
+    SynDefnJar,
+    SynExprJar,
+    husky_place::jar::PlaceJar,
+    TermPreludeJar,
+    husky_dec_term::jar::DecTermJar,
+    DecSignatureJar,
+    husky_dec_ty::jar::DecTypeJar,
+    EthTermJar,
+    EthSignatureJar,
+    FlyTermJar,
+    SemExprJar,
+    husky_sem_place_contract::jar::SemPlaceContractJar
+)]
+#[derive(Default)]
+pub(crate) struct DB;
+
+fn line_segment_module_path(db: &::salsa::Db) -> ModulePath {
+    db.resolve_module_path_and_update_live_packages(
+        &HuskyLangDevPaths::new()
+            .examples_dir()
+            .join("mnist-classifier/src/line_segment_sketch/line_segment.hsy"),
+    )
+    .unwrap()
+}
+
+/// labels and kinds of the completion items at `position`
+fn completion_labels(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    position: TextPosition,
+    trigger_character: Option<&str>,
+) -> Option<Vec<(String, Option<CompletionItemKind>)>> {
+    match db.completion(module_path, position, trigger_character)? {
+        CompletionResponse::Array(items) => Some(
+            items
+                .into_iter()
+                .map(|item| (item.label, item.kind))
+                .collect(),
+        ),
+        CompletionResponse::List(_) => unreachable!(),
+    }
+}
+
+fn line_segment_completion_labels(
+    db: &::salsa::Db,
+    position: (u32, u32),
+    trigger_character: Option<&str>,
+) -> Option<Vec<(String, Option<CompletionItemKind>)>> {
+    completion_labels(
+        db,
+        line_segment_module_path(db),
+        position.into(),
+        trigger_character,
+    )
+}
+
+fn has_label(
+    labels: &[(String, Option<CompletionItemKind>)],
+    label: &str,
+    kind: CompletionItemKind,
+) -> bool {
+    labels
+        .iter()
+        .any(|(label1, kind1)| label1 == label && *kind1 == Some(kind))
+}
+
+/// completion right after every `.` and `::`, as if the character had just been typed
+#[test]
+fn trigger_completion_works() {
+    DB::ast_rich_test_debug(
+        |db, module_path| -> Vec<(TextPosition, Vec<(String, Option<CompletionItemKind>)>)> {
+            let ranged_token_sheet = db.ranged_token_sheet(module_path);
+            let tokens = ranged_token_sheet.tokens(db);
+            ranged_token_sheet
+                .token_index_iter()
+                .filter_map(|token_idx| {
+                    let trigger_character = match tokens[token_idx.index()] {
+                        TokenData::Punctuation(Punctuation::DOT) => ".",
+                        TokenData::Punctuation(Punctuation::COLON_COLON) => ":",
+                        _ => return None,
+                    };
+                    let position = ranged_token_sheet.token_text_range(token_idx).end;
+                    Some((
+                        position,
+                        completion_labels(db, module_path, position, Some(trigger_character))?,
+                    ))
+                })
+                .collect()
+        },
+        &AstTestConfig::new(
+            "trigger_completion",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::IDE,
+        ),
+    )
+}
+
+#[test]
+fn field_completion_works() {
+    let db = &*DB::default();
+    // right after the `.` of `self.start`
+    let labels = line_segment_completion_labels(db, (9, 13), Some(".")).unwrap();
+    assert!(has_label(&labels, "start", CompletionItemKind::FIELD));
+    assert!(has_label(&labels, "end", CompletionItemKind::FIELD));
+    assert!(!labels.iter().any(|(label, _)| label == "let"));
+    // halfway through the field
+    assert_eq!(
+        line_segment_completion_labels(db, (9, 15), None).unwrap(),
+        labels
+    );
+}
+
+#[test]
+fn scope_completion_works() {
+    let db = &*DB::default();
+    // typing `ap` of `ap.norm()` in `dist_to_point`
+    let labels = line_segment_completion_labels(db, (15, 14), None).unwrap();
+    assert!(has_label(&labels, "pt", CompletionItemKind::VARIABLE));
+    assert!(has_label(&labels, "ab", CompletionItemKind::VARIABLE));
+    assert!(has_label(&labels, "ap", CompletionItemKind::VARIABLE));
+    assert!(has_label(
+        &labels,
+        "LineSegment",
+        CompletionItemKind::STRUCT
+    ));
+    assert!(has_label(&labels, "let", CompletionItemKind::KEYWORD));
+    // variables of `dist_to_point` are out of scope in `displacement`
+    let labels = line_segment_completion_labels(db, (9, 10), None).unwrap();
+    assert!(!labels
+        .iter()
+        .any(|(label, _)| label == "pt" || label == "ab" || label == "ap"));
+    assert!(has_label(
+        &labels,
+        "LineSegment",
+        CompletionItemKind::STRUCT
+    ));
+}
+
+#[test]
+fn scope_completion_at_file_start_works() {
+    let db = &*DB::default();
+    let labels = line_segment_completion_labels(db, (0, 0), None).unwrap();
+    assert!(!labels
+        .iter()
+        .any(|(_, kind)| *kind == Some(CompletionItemKind::VARIABLE)));
+    assert!(has_label(
+        &labels,
+        "LineSegment",
+        CompletionItemKind::STRUCT
+    ));
+    assert!(has_label(&labels, "fn", CompletionItemKind::KEYWORD));
+}
+
+#[test]
+fn stray_trigger_character_gives_nothing() {
+    let db = &*DB::default();
+    // the `.` inside the literal `0.0`
+    assert_eq!(
+        line_segment_completion_labels(db, (14, 26), Some(".")),
+        None
+    );
+}
@@ -2,10 +2,20 @@ use crate::*;
 use husky_ast::{AstSheet, HasAstSheet};
 use husky_entity_tree::region_path::SynNodeRegionPath;
 use husky_fly_term::FlyTermRegion;
-use husky_regional_token::{RegionalTokenIdxBase, RegionalTokenIdxRange, RegionalTokenStreamState};
-use husky_sem_expr::*;
-use husky_syn_expr::{expr::SynExprIdx, range::SynExprRangeRegion, region::SynExprRegion};
+use husky_regional_token::{
+    RegionalTokenIdx, RegionalTokenIdxBase, RegionalTokenIdxRange, RegionalTokenStreamState,
+};
+use husky_sem_expr::{helpers::range::sem_expr_range_region, *};
+use husky_syn_expr::{
+    expr::SynExprIdx,
+    pattern::SynPatternIdx,
+    range::SynExprRangeRegion,
+    region::{SynExprRegion, SynExprRegionData},
+};
 use husky_token::{verse::idx::TokenVerseIdx, TokenDb, TokenIdx, TokenIdxRange, TokenStreamState};
+use husky_toml_ast::TomlLineGroupIdx;
+use husky_toml_token::{jar::TomlTokenDb, TomlTokenSheet};
+use husky_vfs::path::package_path::PackagePath;
 
 pub(crate) struct SheetDiagnosticsContext<'a> {
     db: &'a ::salsa::Db,
@@ -64,6 +74,8 @@ impl<'a> SheetDiagnosticsContext<'a> {
 pub(crate) struct RegionDiagnosticsContext<'a> {
     db: &'a ::salsa::Db,
     ranged_token_sheet: &'a RangedTokenSheet,
+    syn_expr_region_data: &'a SynExprRegionData,
+    sem_expr_region: SemExprRegion,
     sem_expr_region_data: &'a SemExprRegionData,
     expr_range_region: &'a SynExprRangeRegion,
     regional_token_idx_base: RegionalTokenIdxBase,
@@ -71,10 +83,11 @@ pub(crate) struct RegionDiagnosticsContext<'a> {
 
 impl<'a> RegionDiagnosticsContext<'a> {
     pub(crate) fn new(db: &'a ::salsa::Db, syn_expr_region: SynExprRegion) -> Self {
-        let syn_expr_region_data = &syn_expr_region.data(db);
+        let syn_expr_region_data = syn_expr_region.data(db);
         let module_path = syn_expr_region_data.path().module_path(db);
         let ranged_token_sheet = db.ranged_token_sheet(module_path);
-        let sem_expr_region_data = db.sem_expr_region(syn_expr_region).data(db);
+        let sem_expr_region = db.sem_expr_region(syn_expr_region);
+        let sem_expr_region_data = sem_expr_region.data(db);
         let syn_expr_range_region = syn_expr_region.range_region(db);
         let regional_token_idx_base = match syn_expr_region_data.path() {
             SynNodeRegionPath::CrateDecl(_) => todo!(),
@@ -86,6 +99,8 @@ impl<'a> RegionDiagnosticsContext<'a> {
         Self {
             db,
             ranged_token_sheet,
+            syn_expr_region_data,
+            sem_expr_region,
             sem_expr_region_data,
             expr_range_region: syn_expr_range_region,
             regional_token_idx_base,
@@ -96,6 +111,14 @@ impl<'a> RegionDiagnosticsContext<'a> {
         self.db
     }
 
+    pub(crate) fn syn_expr_region_data(&self) -> &'a SynExprRegionData {
+        self.syn_expr_region_data
+    }
+
+    pub(crate) fn sem_expr_region(&self) -> SemExprRegion {
+        self.sem_expr_region
+    }
+
     pub(crate) fn sem_expr_region_data(&self) -> &SemExprRegionData {
         self.sem_expr_region_data
     }
@@ -110,6 +133,24 @@ impl<'a> RegionDiagnosticsContext<'a> {
         )
     }
 
+    pub(crate) fn sem_expr_text_range(&self, sem_expr_idx: SemExprIdx) -> TextPositionRange {
+        let sem_expr_range_region_data =
+            sem_expr_range_region(self.db, self.sem_expr_region).data(self.db);
+        self.tokens_text_range(sem_expr_range_region_data[sem_expr_idx])
+    }
+
+    pub(crate) fn pattern_text_range(&self, syn_pattern_idx: SynPatternIdx) -> TextPositionRange {
+        self.tokens_text_range(self.expr_range_region[syn_pattern_idx])
+    }
+
+    pub(crate) fn regional_token_text_range(
+        &self,
+        regional_token_idx: RegionalTokenIdx,
+    ) -> TextPositionRange {
+        self.ranged_token_sheet
+            .token_text_range(regional_token_idx.token_idx(self.regional_token_idx_base))
+    }
+
     pub(crate) fn tokens_text_range(
         &self,
         regional_token_idx_range: RegionalTokenIdxRange,
@@ -137,3 +178,37 @@ impl<'a> RegionDiagnosticsContext<'a> {
         )
     }
 }
+
+pub(crate) struct ManifestDiagnosticsContext<'a> {
+    db: &'a ::salsa::Db,
+    /// `None` if the manifest can't be read, then there's no line group to point at
+    toml_token_sheet: Option<&'a TomlTokenSheet>,
+}
+
+impl<'a> ManifestDiagnosticsContext<'a> {
+    pub(crate) fn new(db: &'a ::salsa::Db, package_path: PackagePath) -> Self {
+        Self {
+            db,
+            toml_token_sheet: package_path
+                .manifest_path(db)
+                .ok()
+                .and_then(|manifest_path| db.toml_token_sheet(manifest_path.path()).ok())
+                .flatten(),
+        }
+    }
+
+    pub(crate) fn db(&self) -> &'a ::salsa::Db {
+        self.db
+    }
+
+    pub(crate) fn line_group_text_range(
+        &self,
+        line_group_idx: TomlLineGroupIdx,
+    ) -> TextPositionRange {
+        self.toml_token_sheet
+            .and_then(|toml_token_sheet| {
+                toml_token_sheet.line_group_text_range(line_group_idx.index())
+            })
+            .unwrap_or_default()
+    }
+}
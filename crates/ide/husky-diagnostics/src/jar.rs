@@ -1,11 +1,18 @@
 use crate::*;
+use husky_vfs::path::package_path::PackagePath;
 
 pub trait DiagnosticsDb {
     fn diagnostic_sheet(&self, module_path: ModulePath) -> DiagnosticSheet;
+
+    fn manifest_diagnostic_sheet(&self, package_path: PackagePath) -> ManifestDiagnosticSheet;
 }
 
 impl DiagnosticsDb for ::salsa::Db {
     fn diagnostic_sheet(&self, module_path: ModulePath) -> DiagnosticSheet {
         diagnostic_sheet(self, module_path)
     }
+
+    fn manifest_diagnostic_sheet(&self, package_path: PackagePath) -> ManifestDiagnosticSheet {
+        manifest_diagnostic_sheet(self, package_path)
+    }
 }
@@ -35,6 +35,12 @@ pub struct DiagnosticsJar(
     expr_diagnostic_sheet,
     ExprTypeDiagnosticSheet,
     expr_ty_diagnostic_sheet,
+    PatternDiagnosticSheet,
+    pattern_diagnostic_sheet,
+    BorrowDiagnosticSheet,
+    borrow_diagnostic_sheet,
+    ManifestDiagnosticSheet,
+    manifest_diagnostic_sheet,
 );
 
 #[derive(Debug, PartialEq, Eq, Clone)]
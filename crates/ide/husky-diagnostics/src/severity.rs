@@ -3,8 +3,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum DiagnosticSeverity {
     Error,
-    // We don't actually emit this one yet, but we should at some point.
-    // Warning,
+    Warning,
     WeakWarning,
 }
 
@@ -12,6 +11,7 @@ impl Into<lsp_types::DiagnosticSeverity> for DiagnosticSeverity {
     fn into(self) -> lsp_types::DiagnosticSeverity {
         match self {
             DiagnosticSeverity::Error => lsp_types::DiagnosticSeverity::ERROR,
+            DiagnosticSeverity::Warning => lsp_types::DiagnosticSeverity::WARNING,
             DiagnosticSeverity::WeakWarning => lsp_types::DiagnosticSeverity::HINT,
         }
     }
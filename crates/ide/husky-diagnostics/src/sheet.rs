@@ -1,12 +1,18 @@
 mod ast;
+mod borrow;
 mod decl;
+mod manifest;
+mod pattern;
 mod sem_expr;
 mod syn_expr;
 mod syn_tree;
 mod token;
 
 pub(crate) use self::ast::*;
+pub(crate) use self::borrow::*;
 pub(crate) use self::decl::*;
+pub(crate) use self::manifest::*;
+pub(crate) use self::pattern::*;
 pub(crate) use self::sem_expr::*;
 pub(crate) use self::syn_expr::*;
 pub(crate) use self::syn_tree::*;
@@ -22,6 +28,8 @@ pub struct DiagnosticSheet {
     pub expr_diagnostic_sheet: ExprDiagnosticSheet,
     pub decl_diagnostic_sheet: DeclDiagnosticSheet,
     pub expr_ty_diagnostic_sheet: ExprTypeDiagnosticSheet,
+    pub pattern_diagnostic_sheet: PatternDiagnosticSheet,
+    pub borrow_diagnostic_sheet: BorrowDiagnosticSheet,
 }
 
 #[salsa::tracked(jar = DiagnosticsJar)]
@@ -34,6 +42,8 @@ pub(crate) fn diagnostic_sheet(db: &::salsa::Db, module_path: ModulePath) -> Dia
         expr_diagnostic_sheet(db, module_path),
         decl_diagnostic_sheet(db, module_path),
         expr_ty_diagnostic_sheet(db, module_path),
+        pattern_diagnostic_sheet(db, module_path),
+        borrow_diagnostic_sheet(db, module_path),
     )
 }
 
@@ -50,6 +60,8 @@ impl DiagnosticSheet {
             .chain(self.expr_diagnostic_sheet(db).diagnostics(db).iter())
             .chain(self.decl_diagnostic_sheet(db).diagnostics(db).iter())
             .chain(self.expr_ty_diagnostic_sheet(db).diagnostics(db).iter())
+            .chain(self.pattern_diagnostic_sheet(db).diagnostics(db).iter())
+            .chain(self.borrow_diagnostic_sheet(db).diagnostics(db).iter())
     }
 }
 
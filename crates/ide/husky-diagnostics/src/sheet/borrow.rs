@@ -0,0 +1,80 @@
+use super::*;
+use husky_borrow_checker::{
+    error::BorrowError,
+    region::borrow_check_region,
+    show_place,
+    state::{Access, LoanKind},
+};
+use husky_syn_defn::module_item_syn_node_defns;
+use husky_syn_expr::region::SynExprRegion;
+
+#[salsa::tracked(db = DiagnosticsDb, jar = DiagnosticsJar)]
+pub struct BorrowDiagnosticSheet {
+    #[return_ref]
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[salsa::tracked(jar = DiagnosticsJar)]
+pub(crate) fn borrow_diagnostic_sheet(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+) -> BorrowDiagnosticSheet {
+    let mut diagnostics = vec![];
+    for (_, defn) in module_item_syn_node_defns(db, module_path) {
+        if let Some(defn) = defn {
+            collect_borrow_diagnostics(db, defn.syn_expr_region, &mut diagnostics);
+        }
+    }
+    BorrowDiagnosticSheet::new(db, diagnostics)
+}
+
+fn collect_borrow_diagnostics(
+    db: &::salsa::Db,
+    syn_expr_region: SynExprRegion,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let ctx: RegionDiagnosticsContext = RegionDiagnosticsContext::new(db, syn_expr_region);
+    for error in borrow_check_region(db, ctx.sem_expr_region()).errors() {
+        diagnostics.push(error.to_diagnostic(&ctx))
+    }
+}
+
+impl Diagnose for BorrowError {
+    type Context<'a> = RegionDiagnosticsContext<'a>;
+
+    fn message(&self, ctx: &RegionDiagnosticsContext) -> String {
+        let place = show_place(ctx.db(), self.place());
+        match *self {
+            BorrowError::UseAfterMove { .. } => {
+                format!("Borrow Error: use of moved value `{place}`")
+            }
+            BorrowError::ConflictingBorrow {
+                access, loan_kind, ..
+            } => match (access, loan_kind) {
+                (Access::Read, _) => {
+                    format!("Borrow Error: cannot use `{place}` because it is mutably borrowed")
+                }
+                (Access::Write, LoanKind::Shared) => format!(
+                    "Borrow Error: cannot borrow `{place}` as mutable because it is also borrowed as shared"
+                ),
+                (Access::Write, LoanKind::Mut) => format!(
+                    "Borrow Error: cannot borrow `{place}` as mutable more than once at a time"
+                ),
+                (Access::Move, _) => {
+                    format!("Borrow Error: cannot move out of `{place}` because it is borrowed")
+                }
+            },
+            BorrowError::EscapingReference { .. } => {
+                format!("Borrow Error: cannot return a reference to local variable `{place}`")
+            }
+        }
+    }
+
+    fn severity(&self) -> DiagnosticSeverity {
+        DiagnosticSeverity::Error
+    }
+
+    fn range(&self, ctx: &RegionDiagnosticsContext) -> TextPositionRange {
+        ctx.sem_expr_text_range(self.expr())
+    }
+}
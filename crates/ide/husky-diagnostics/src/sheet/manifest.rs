@@ -0,0 +1,46 @@
+use super::*;
+use husky_manifest::diagnostic::{HasManifestDiagnostics, ManifestDiagnostic};
+use husky_vfs::path::package_path::PackagePath;
+
+/// Diagnostics of `Corgi.toml`, reported on the manifest rather than on any module.
+#[salsa::tracked(db = DiagnosticsDb, jar = DiagnosticsJar)]
+pub struct ManifestDiagnosticSheet {
+    #[return_ref]
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[salsa::tracked(jar = DiagnosticsJar)]
+pub(crate) fn manifest_diagnostic_sheet(
+    db: &::salsa::Db,
+    package_path: PackagePath,
+) -> ManifestDiagnosticSheet {
+    let ctx = ManifestDiagnosticsContext::new(db, package_path);
+    ManifestDiagnosticSheet::new(
+        db,
+        package_path
+            .manifest_diagnostics(db)
+            .iter()
+            .map(|diagnostic| diagnostic.to_diagnostic(&ctx))
+            .collect(),
+    )
+}
+
+impl Diagnose for ManifestDiagnostic {
+    type Context<'a> = ManifestDiagnosticsContext<'a>;
+
+    fn message(&self, ctx: &ManifestDiagnosticsContext) -> String {
+        ManifestDiagnostic::message(self, ctx.db())
+    }
+
+    fn severity(&self) -> DiagnosticSeverity {
+        DiagnosticSeverity::Error
+    }
+
+    fn range(&self, ctx: &ManifestDiagnosticsContext) -> TextPositionRange {
+        match self.line_group_idx() {
+            Some(line_group_idx) => ctx.line_group_text_range(line_group_idx),
+            // about the manifest as a whole
+            None => Default::default(),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use super::*;
+use husky_pattern_analysis::{analyze_match_stmt, PatternType};
+use husky_regional_token::MatchRegionalToken;
+use husky_sem_expr::SemStmtData;
+use husky_syn_defn::module_item_syn_node_defns;
+use husky_syn_expr::{pattern::SynPatternIdx, region::SynExprRegion};
+
+#[salsa::tracked(db = DiagnosticsDb, jar = DiagnosticsJar)]
+pub struct PatternDiagnosticSheet {
+    #[return_ref]
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[salsa::tracked(jar = DiagnosticsJar)]
+pub(crate) fn pattern_diagnostic_sheet(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+) -> PatternDiagnosticSheet {
+    let mut diagnostics = vec![];
+    for (_, defn) in module_item_syn_node_defns(db, module_path) {
+        if let Some(defn) = defn {
+            collect_pattern_diagnostics(db, defn.syn_expr_region, &mut diagnostics);
+        }
+    }
+    PatternDiagnosticSheet::new(db, diagnostics)
+}
+
+fn collect_pattern_diagnostics(
+    db: &::salsa::Db,
+    syn_expr_region: SynExprRegion,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let ctx: RegionDiagnosticsContext = RegionDiagnosticsContext::new(db, syn_expr_region);
+    let sem_expr_region_data = ctx.sem_expr_region_data();
+    for sem_stmt_entry in sem_expr_region_data.sem_stmt_arena().iter() {
+        let Ok(&SemStmtData::Match {
+            match_token,
+            opd,
+            ref case_branches,
+            ..
+        }) = sem_stmt_entry.data_result()
+        else {
+            continue;
+        };
+        let Some(opd_ty) = PatternType::from_sem_expr(opd, sem_expr_region_data) else {
+            continue;
+        };
+        // analysis errors are bugs or complexity overflows, neither of which is the user's fault
+        let Ok(analysis) = analyze_match_stmt(
+            db,
+            ctx.syn_expr_region_data(),
+            sem_expr_region_data,
+            opd_ty,
+            case_branches,
+        ) else {
+            continue;
+        };
+        if !analysis.missing_patterns().is_empty() {
+            diagnostics.push(
+                PatternDiagnostic::NonExhaustiveMatch {
+                    match_token,
+                    missing_patterns: analysis.missing_patterns(),
+                }
+                .to_diagnostic(&ctx),
+            )
+        }
+        for &i in analysis.unreachable_branches() {
+            diagnostics.push(
+                PatternDiagnostic::UnreachableBranch {
+                    syn_pattern_idx: case_branches[i]
+                        .case_pattern_sem_obelisk
+                        .syn_pattern_root()
+                        .syn_pattern_idx(),
+                }
+                .to_diagnostic(&ctx),
+            )
+        }
+    }
+}
+
+enum PatternDiagnostic<'a> {
+    NonExhaustiveMatch {
+        match_token: MatchRegionalToken,
+        missing_patterns: &'a [String],
+    },
+    UnreachableBranch {
+        syn_pattern_idx: SynPatternIdx,
+    },
+}
+
+/// at most this many witnesses are listed in the message
+const MAX_SHOWN_MISSING_PATTERNS: usize = 3;
+
+impl<'b> Diagnose for PatternDiagnostic<'b> {
+    type Context<'a> = RegionDiagnosticsContext<'a>;
+
+    fn message(&self, _ctx: &RegionDiagnosticsContext) -> String {
+        match self {
+            PatternDiagnostic::NonExhaustiveMatch {
+                missing_patterns, ..
+            } => {
+                let shown = missing_patterns
+                    .iter()
+                    .take(MAX_SHOWN_MISSING_PATTERNS)
+                    .map(|pattern| format!("`{pattern}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match missing_patterns.len() {
+                    len if len > MAX_SHOWN_MISSING_PATTERNS => format!(
+                        "Pattern Error: non-exhaustive match, patterns {shown} and {} more not covered",
+                        len - MAX_SHOWN_MISSING_PATTERNS
+                    ),
+                    1 => format!("Pattern Error: non-exhaustive match, pattern {shown} not covered"),
+                    _ => format!("Pattern Error: non-exhaustive match, patterns {shown} not covered"),
+                }
+            }
+            PatternDiagnostic::UnreachableBranch { .. } => {
+                "Pattern Error: unreachable branch, already covered by branches above".to_string()
+            }
+        }
+    }
+
+    fn severity(&self) -> DiagnosticSeverity {
+        match self {
+            PatternDiagnostic::NonExhaustiveMatch { .. } => DiagnosticSeverity::Error,
+            PatternDiagnostic::UnreachableBranch { .. } => DiagnosticSeverity::Warning,
+        }
+    }
+
+    fn range(&self, ctx: &RegionDiagnosticsContext) -> TextPositionRange {
+        match *self {
+            PatternDiagnostic::NonExhaustiveMatch { match_token, .. } => {
+                ctx.regional_token_text_range(match_token.regional_token_idx())
+            }
+            PatternDiagnostic::UnreachableBranch { syn_pattern_idx } => {
+                ctx.pattern_text_range(syn_pattern_idx)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn pattern_example_diagnostics(db: &::salsa::Db) -> Vec<(DiagnosticSeverity, u32, String)> {
+    use husky_path_utils::HuskyLangDevPaths;
+    use husky_vfs::jar::VfsDb;
+
+    let module_path = db
+        .resolve_module_path_and_update_live_packages(
+            &HuskyLangDevPaths::new()
+                .examples_dir()
+                .join("basics/semantics-basics/src/pattern.hsy"),
+        )
+        .unwrap();
+    pattern_diagnostic_sheet(db, module_path)
+        .diagnostics(db)
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.severity,
+                diagnostic.range.start.line.0,
+                diagnostic.message.clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn pattern_diagnostics_works() {
+    let db = &*DB::default();
+    let diagnostics = pattern_example_diagnostics(db);
+    // `exhaustive` is fine
+    assert!(diagnostics.iter().all(|&(_, line, _)| !(6..10).contains(&line)));
+    let non_exhaustive_lines: Vec<_> = diagnostics
+        .iter()
+        .filter(|(severity, _, message)| {
+            *severity == DiagnosticSeverity::Error
+                && message.starts_with("Pattern Error: non-exhaustive match")
+        })
+        .map(|&(_, line, _)| line)
+        .collect();
+    assert_eq!(non_exhaustive_lines, [12, 23]);
+    let unreachable_lines: Vec<_> = diagnostics
+        .iter()
+        .filter(|(severity, _, _)| *severity == DiagnosticSeverity::Warning)
+        .map(|&(_, line, _)| line)
+        .collect();
+    assert_eq!(unreachable_lines, [20]);
+}
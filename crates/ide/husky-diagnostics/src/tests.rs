@@ -46,7 +46,9 @@ use husky_toml_ast::TomlAstJar;
     DecSignatureJar,
     husky_place::jar::PlaceJar,
     FlyTermJar,
-    SemExprJar
+    SemExprJar,
+    husky_sem_place_contract::jar::SemPlaceContractJar,
+    husky_borrow_checker::jar::BorrowCheckerJar
 )]
 #[derive(Default)]
 pub(crate) struct DB;
@@ -1 +1,59 @@
+//! formatting edits for the language server
 pub mod jar;
+
+use husky_sem_fmt::{error::SemFmtResult, module_sem_fmt};
+use husky_text_protocol::range::TextPositionRange;
+use husky_vfs::path::module_path::ModulePath;
+use similar::{DiffTag, TextDiff};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FmtEdit {
+    range: TextPositionRange,
+    new_text: String,
+}
+
+/// # getters
+impl FmtEdit {
+    pub fn range(&self) -> TextPositionRange {
+        self.range
+    }
+
+    pub fn new_text(&self) -> &str {
+        &self.new_text
+    }
+}
+
+/// line-wise edits turning the module into its formatted form;
+/// with `range` given, only the changes touching the lines in `range` are kept
+pub fn module_fmt_edits(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    range: Option<TextPositionRange>,
+) -> SemFmtResult<Vec<FmtEdit>> {
+    let formatted = module_sem_fmt(db, module_path)
+        .as_ref()
+        .map_err(Clone::clone)?;
+    let diff = TextDiff::from_lines(module_path.raw_text(db), formatted);
+    let new_lines = diff.new_slices();
+    Ok(diff
+        .ops()
+        .iter()
+        .filter_map(|op| {
+            let (tag, old_lines, new_line_range) = op.as_tag_tuple();
+            if tag == DiffTag::Equal {
+                return None;
+            }
+            let (start_line, end_line) = (old_lines.start as u32, old_lines.end as u32);
+            if let Some(range) = range {
+                // an insertion touches the line it is inserted before
+                if end_line.max(start_line + 1) <= range.start.i() || start_line > range.end.i() {
+                    return None;
+                }
+            }
+            Some(FmtEdit {
+                range: TextPositionRange::from_u32((start_line, 0)..(end_line, 0)),
+                new_text: new_lines[new_line_range].concat(),
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,148 @@
+use crate::*;
+use husky_entity_path::path::{EntityPath, ItemPath};
+use husky_entity_tree::{node::ItemSynNodePath, presheet::UseOneRuleState};
+use husky_fly_term::FlyBaseTypeData;
+use husky_sem_expr::helpers::path::sem_expr_region_from_region_path;
+use husky_syn_expr::{
+    region::SynExprRegion,
+    variable::{CurrentVariableIdx, InheritedVariableIdx},
+};
+use husky_token_info::{ExprRegionLeash, TokenInfo, TokenInfoData, TokenInfoDb, TokenInfoSource};
+
+/// what a token refers to
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Definition {
+    Entity(EntityPath),
+    /// the token is itself part of the declaration of an item
+    ItemSynNode(ItemSynNodePath),
+    /// inherited variables are resolved to the current variable in the ancestor region
+    Variable {
+        syn_expr_region: ExprRegionLeash,
+        current_variable_idx: CurrentVariableIdx,
+    },
+}
+
+/// later token infos are more specific, so they are tried first
+pub fn token_definition(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: TokenIdx,
+) -> Option<Definition> {
+    let token_info_sheet = db.token_info_sheet_ref(module_path).ok()?;
+    token_info_sheet[token_idx]
+        .iter()
+        .rev()
+        .find_map(|token_info| Definition::from_token_info(token_info, db))
+}
+
+/// the definition of the base type of the expression the token belongs to
+pub fn token_ty_definition(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: TokenIdx,
+) -> Option<Definition> {
+    let token_info_sheet = db.token_info_sheet_ref(module_path).ok()?;
+    token_info_sheet[token_idx]
+        .iter()
+        .rev()
+        .find_map(|token_info| match token_info.source() {
+            TokenInfoSource::SemExpr(region_path, sem_expr_idx) => {
+                let sem_expr_region_data =
+                    sem_expr_region_from_region_path(region_path, db)?.data(db);
+                let ty = *sem_expr_idx
+                    .ty_result(sem_expr_region_data.sem_expr_arena2())
+                    .as_ref()
+                    .ok()?;
+                match ty.base_ty_data_inner(db, sem_expr_region_data.fly_term_region().terms()) {
+                    FlyBaseTypeData::TypeOntology { ty_path, .. } => {
+                        Some(Definition::Entity(ty_path.into()))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+}
+
+impl Definition {
+    pub(crate) fn from_token_info(token_info: &TokenInfo, db: &::salsa::Db) -> Option<Self> {
+        match *token_info.data() {
+            TokenInfoData::Entity(path) => Some(Definition::Entity(path)),
+            TokenInfoData::EntityNode(syn_node_path, _) => Some(
+                match syn_node_path
+                    .unambiguous_item_path(db)
+                    .and_then(|item_path| item_entity_path(item_path, db))
+                {
+                    Some(path) => Definition::Entity(path),
+                    None => Definition::ItemSynNode(syn_node_path),
+                },
+            ),
+            TokenInfoData::CurrentVariable {
+                current_variable_idx,
+                syn_expr_region,
+                ..
+            } => Some(Definition::Variable {
+                syn_expr_region,
+                current_variable_idx,
+            }),
+            TokenInfoData::InheritedVariable {
+                inherited_variable_idx,
+                syn_expr_region,
+                ..
+            } => inherited_variable_definition(*syn_expr_region, inherited_variable_idx, db),
+            TokenInfoData::UseExpr {
+                state:
+                    UseOneRuleState::Resolved {
+                        original_symbol: Some(original_symbol),
+                    },
+                ..
+            } => Some(Definition::Entity(
+                original_symbol.principal_entity_path(db).into(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// so that declaration sites compare equal to usages elsewhere
+fn item_entity_path(item_path: ItemPath, db: &::salsa::Db) -> Option<EntityPath> {
+    match item_path {
+        ItemPath::Submodule(room, path) => {
+            Some(EntityPath::Module(room, path.self_module_path(db)))
+        }
+        ItemPath::MajorItem(path) => Some(path.into()),
+        ItemPath::AssocItem(path) => Some(path.into()),
+        ItemPath::TypeVariant(room, path) => Some(EntityPath::TypeVariant(room, path)),
+        ItemPath::ImplBlock(path) => Some(path.into()),
+        ItemPath::Attr(room, path) => Some(EntityPath::Attr(room, path)),
+        ItemPath::Script(_, _) => None,
+    }
+}
+
+/// a region inherits the inherited variables of its parent followed by the current ones, in order,
+/// so the index alone determines the definition, even if the identifier is shadowed
+fn inherited_variable_definition(
+    syn_expr_region: SynExprRegion,
+    inherited_variable_idx: InheritedVariableIdx,
+    db: &::salsa::Db,
+) -> Option<Definition> {
+    let mut region = syn_expr_region;
+    let mut index = inherited_variable_idx.index();
+    loop {
+        let parent = region.data(db).parent()?;
+        let parent_variable_region = parent.data(db).variable_region();
+        let inherited_variables_len = parent_variable_region.inherited_variable_arena().len();
+        if index < inherited_variables_len {
+            region = parent;
+            continue;
+        }
+        let current_variable_idx = parent_variable_region
+            .current_variable_indices()
+            .nth(index - inherited_variables_len)?;
+        return Some(Definition::Variable {
+            syn_expr_region: parent.into(),
+            current_variable_idx,
+        });
+    }
+}
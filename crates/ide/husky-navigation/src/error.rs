@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum RenameError {
+    #[error("no renameable symbol at the cursor")]
+    NoDefinition,
+    #[error("renaming modules is not supported yet")]
+    Module,
+    #[error("this symbol can't be renamed")]
+    NotRenameable,
+    #[error("the symbol is defined outside the workspace")]
+    OutsideWorkspace,
+    #[error("`{0}` is not a valid identifier")]
+    InvalidIdent(String),
+    #[error("`{0}` is already defined in this scope")]
+    Collision(String),
+    #[error("`{0}` would be shadowed by another definition")]
+    Shadowed(String),
+}
+
+pub type RenameResult<T> = Result<T, RenameError>;
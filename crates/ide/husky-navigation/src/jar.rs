@@ -0,0 +1,4 @@
+use crate::{symbol::module_item_symbols, usage::module_usage_sheet};
+
+#[salsa::jar]
+pub struct NavigationJar(module_usage_sheet, module_item_symbols);
@@ -0,0 +1,64 @@
+//! goto definition, declaration and type definition, references and rename, symbols
+pub mod definition;
+pub mod error;
+pub mod jar;
+pub mod rename;
+pub mod symbol;
+pub mod target;
+#[cfg(test)]
+mod tests;
+pub mod usage;
+
+use self::definition::*;
+use self::jar::NavigationJar as Jar;
+use self::target::*;
+use husky_token::TokenIdx;
+use husky_vfs::path::module_path::ModulePath;
+
+pub trait NavigationDb {
+    fn definition_nav_target(
+        &self,
+        module_path: ModulePath,
+        token_idx: TokenIdx,
+    ) -> Option<NavTarget>;
+
+    /// there is no separate declaration for items and variables in Husky,
+    /// so this coincides with `definition_nav_target`
+    fn declaration_nav_target(
+        &self,
+        module_path: ModulePath,
+        token_idx: TokenIdx,
+    ) -> Option<NavTarget>;
+
+    fn ty_definition_nav_target(
+        &self,
+        module_path: ModulePath,
+        token_idx: TokenIdx,
+    ) -> Option<NavTarget>;
+}
+
+impl NavigationDb for ::salsa::Db {
+    fn definition_nav_target(
+        &self,
+        module_path: ModulePath,
+        token_idx: TokenIdx,
+    ) -> Option<NavTarget> {
+        token_definition(self, module_path, token_idx)?.nav_target(self)
+    }
+
+    fn declaration_nav_target(
+        &self,
+        module_path: ModulePath,
+        token_idx: TokenIdx,
+    ) -> Option<NavTarget> {
+        self.definition_nav_target(module_path, token_idx)
+    }
+
+    fn ty_definition_nav_target(
+        &self,
+        module_path: ModulePath,
+        token_idx: TokenIdx,
+    ) -> Option<NavTarget> {
+        token_ty_definition(self, module_path, token_idx)?.nav_target(self)
+    }
+}
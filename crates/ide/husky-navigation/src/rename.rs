@@ -0,0 +1,337 @@
+use crate::{error::*, usage::*, *};
+use husky_coword::Ident;
+use husky_entity_path::path::{
+    assoc_item::AssocItemPath, impl_block::trai_for_ty_impl_block::TraitForTypeImplBlockPath,
+    major_item::trai::TraitPath, major_item::ty::TypePath, EntityPath, ItemPath,
+};
+use husky_entity_tree::{helpers::paths::module_item_paths, sheet::HasEntityTreeSheet};
+use husky_regional_token::RegionalTokenIdx;
+use husky_syn_expr::{region::SynExprRegion, variable::CurrentVariableEntry};
+use husky_text_protocol::range::TextPositionRange;
+use husky_token::TokenDb;
+use husky_token_data::TokenData;
+use husky_vfs::jar::VfsDb;
+
+/// checks that the token can be renamed and returns its definition and range
+pub fn prepare_rename(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: TokenIdx,
+) -> RenameResult<(Definition, TextPositionRange)> {
+    let TokenData::Ident(_) = db.token_sheet_data(module_path)[token_idx] else {
+        Err(RenameError::NoDefinition)?
+    };
+    let definition =
+        token_definition(db, module_path, token_idx).ok_or(RenameError::NoDefinition)?;
+    match definition {
+        Definition::Entity(EntityPath::Module(_, _)) => Err(RenameError::Module)?,
+        Definition::Entity(EntityPath::ImplBlock(_)) | Definition::ItemSynNode(_) => {
+            Err(RenameError::NotRenameable)?
+        }
+        Definition::Entity(_) | Definition::Variable { .. } => (),
+    }
+    let home_module_path = definition
+        .module_path(db)
+        .ok_or(RenameError::NoDefinition)?;
+    let live_packages = db
+        .live_packages()
+        .map_err(|_| RenameError::OutsideWorkspace)?;
+    if !live_packages.has(home_module_path.package_path(db)) {
+        Err(RenameError::OutsideWorkspace)?
+    }
+    Ok((
+        definition,
+        db.ranged_token_sheet(module_path)
+            .token_text_range(token_idx),
+    ))
+}
+
+/// the ranges to be replaced by the new name, in all modules of the workspace
+pub fn rename(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_idx: TokenIdx,
+    new_name: &str,
+) -> RenameResult<Vec<(ModulePath, TextPositionRange)>> {
+    let (definition, _) = prepare_rename(db, module_path, token_idx)?;
+    let TokenData::Ident(old_ident) = db.token_sheet_data(module_path)[token_idx] else {
+        unreachable!("checked in `prepare_rename`")
+    };
+    let new_ident =
+        Ident::from_ref(db, new_name).ok_or(RenameError::InvalidIdent(new_name.to_string()))?;
+    if new_ident == old_ident {
+        return Ok(vec![]);
+    }
+    let usages = definition_usages(db, definition);
+    let conflict_checker = RenameConflictChecker {
+        db,
+        definition,
+        new_ident,
+        new_name,
+        usages: &usages,
+    };
+    match definition {
+        Definition::Variable {
+            syn_expr_region,
+            current_variable_idx,
+        } => conflict_checker.check_variable(
+            *syn_expr_region,
+            &syn_expr_region.data(db).variable_region()[current_variable_idx],
+        )?,
+        Definition::Entity(path) => conflict_checker.check_item(path)?,
+        Definition::ItemSynNode(_) => unreachable!("checked in `prepare_rename`"),
+    }
+    // only tokens spelling out the old name are replaced,
+    // keywords like `Self` referring to the same definition stay untouched
+    Ok(usages
+        .iter()
+        .filter(|(module_path, usage)| {
+            db.token_sheet_data(*module_path)[usage.token_idx()] == TokenData::Ident(old_ident)
+        })
+        .map(|&(module_path, usage)| {
+            (
+                module_path,
+                db.ranged_token_sheet(module_path)
+                    .token_text_range(usage.token_idx()),
+            )
+        })
+        .collect())
+}
+
+struct RenameConflictChecker<'a> {
+    db: &'a ::salsa::Db,
+    definition: Definition,
+    new_ident: Ident,
+    new_name: &'a str,
+    usages: &'a [(ModulePath, Usage)],
+}
+
+impl<'a> RenameConflictChecker<'a> {
+    fn check_variable(
+        &self,
+        home_region: SynExprRegion,
+        entry: &CurrentVariableEntry,
+    ) -> RenameResult<()> {
+        let db = self.db;
+        let home_region_data = home_region.data(db);
+        let variable_region = home_region_data.variable_region();
+        // another variable of the same name alive at the same time
+        if variable_region
+            .current_variables()
+            .filter(|other| !std::ptr::eq(*other, entry))
+            .any(|other| {
+                other.ident() == Some(self.new_ident) && access_ranges_overlap(entry, other)
+            })
+        {
+            Err(self.collision())?
+        }
+        if variable_region
+            .inherited_variables()
+            .any(|inherited| inherited.ident() == Some(self.new_ident))
+        {
+            Err(self.shadowed())?
+        }
+        for &(_, usage) in self.usages {
+            let Some((region, regional_token_idx)) = usage.region() else {
+                continue;
+            };
+            if region == home_region || !is_descendant_region(db, region, home_region) {
+                continue;
+            }
+            // nearer regions binding the new name would capture the usage
+            if current_variable_accessible(db, region, regional_token_idx, self.new_ident)
+                || ancestors_between(db, region, home_region)
+                    .any(|ancestor| has_current_variable(db, ancestor, self.new_ident))
+            {
+                Err(self.shadowed())?
+            }
+        }
+        // the renamed variable would capture usages of items of the new name
+        let home_module_path = home_region_data.path().module_path(db);
+        for usage in home_module_path.usage_sheet(db).usages() {
+            if usage.definition() == self.definition
+                || !matches!(usage.definition(), Definition::Entity(_))
+            {
+                continue;
+            }
+            let Some((region, regional_token_idx)) = usage.region() else {
+                continue;
+            };
+            if db.token_sheet_data(home_module_path)[usage.token_idx()]
+                != TokenData::Ident(self.new_ident)
+            {
+                continue;
+            }
+            if (region == home_region && is_accessible(entry, regional_token_idx))
+                || (region != home_region && is_descendant_region(db, region, home_region))
+            {
+                Err(self.collision())?
+            }
+        }
+        Ok(())
+    }
+
+    fn check_item(&self, path: EntityPath) -> RenameResult<()> {
+        let db = self.db;
+        let Some(home_module_path) = self.definition.module_path(db) else {
+            return Ok(());
+        };
+        let item_path: ItemPath = match path {
+            EntityPath::Module(_, _) | EntityPath::ImplBlock(_) => Err(RenameError::NotRenameable)?,
+            EntityPath::MajorItem(path) => path.into(),
+            EntityPath::AssocItem(path) => path.into(),
+            EntityPath::TypeVariant(room, path) => ItemPath::TypeVariant(room, path),
+            EntityPath::Attr(room, path) => ItemPath::Attr(room, path),
+        };
+        let parent = ItemParent::new(item_path, db);
+        if module_item_paths(db, home_module_path)
+            .iter()
+            .any(|&sibling| {
+                sibling != item_path
+                    && ItemParent::new(sibling, db) == parent
+                    && sibling.ident(db) == Some(self.new_ident)
+            })
+        {
+            Err(self.collision())?
+        }
+        // only items referred to by their bare names can collide with or be shadowed by symbols
+        if parent != ItemParent::Module {
+            return Ok(());
+        }
+        let mut module_paths: Vec<ModulePath> = vec![home_module_path];
+        for &(module_path, _) in self.usages {
+            if !module_paths.contains(&module_path) {
+                module_paths.push(module_path)
+            }
+        }
+        for module_path in module_paths {
+            if module_path
+                .item_tree_sheet(db)
+                .module_symbols()
+                .resolve_ident(db, module_path.into(), self.new_ident)
+                .is_some()
+            {
+                Err(self.collision())?
+            }
+        }
+        for &(_, usage) in self.usages {
+            if usage.kind() == UsageKind::Definition {
+                continue;
+            }
+            let Some((region, regional_token_idx)) = usage.region() else {
+                continue;
+            };
+            if current_variable_accessible(db, region, regional_token_idx, self.new_ident)
+                || region
+                    .data(db)
+                    .variable_region()
+                    .inherited_variables()
+                    .any(|inherited| inherited.ident() == Some(self.new_ident))
+            {
+                Err(self.shadowed())?
+            }
+        }
+        Ok(())
+    }
+
+    fn collision(&self) -> RenameError {
+        RenameError::Collision(self.new_name.to_string())
+    }
+
+    fn shadowed(&self) -> RenameError {
+        RenameError::Shadowed(self.new_name.to_string())
+    }
+}
+
+/// items sharing a parent share a namespace
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum ItemParent {
+    Module,
+    Type(TypePath),
+    Trait(TraitPath),
+    TraitForTypeImplBlock(TraitForTypeImplBlockPath),
+    /// attributes and other items that never collide
+    None,
+}
+
+impl ItemParent {
+    fn new(item_path: ItemPath, db: &::salsa::Db) -> Self {
+        match item_path {
+            ItemPath::Submodule(_, _) | ItemPath::MajorItem(_) => ItemParent::Module,
+            ItemPath::TypeVariant(_, path) => ItemParent::Type(path.parent_ty_path(db)),
+            ItemPath::AssocItem(AssocItemPath::TypeItem(path)) => {
+                ItemParent::Type(path.ty_path(db))
+            }
+            ItemPath::AssocItem(AssocItemPath::TraitItem(path)) => {
+                ItemParent::Trait(path.trai_path(db))
+            }
+            ItemPath::AssocItem(AssocItemPath::TraitForTypeItem(path)) => {
+                ItemParent::TraitForTypeImplBlock(path.impl_block(db))
+            }
+            ItemPath::ImplBlock(_) | ItemPath::Attr(_, _) | ItemPath::Script(_, _) => {
+                ItemParent::None
+            }
+        }
+    }
+}
+
+fn is_accessible(entry: &CurrentVariableEntry, regional_token_idx: RegionalTokenIdx) -> bool {
+    entry.access_start() <= regional_token_idx
+        && entry
+            .access_end()
+            .map_or(true, |end| regional_token_idx < end.regional_token_idx())
+}
+
+/// `None` as the access end means the variable is accessible till the end of the region
+fn access_ranges_overlap(a: &CurrentVariableEntry, b: &CurrentVariableEntry) -> bool {
+    let ends_after = |entry: &CurrentVariableEntry, start: RegionalTokenIdx| {
+        entry
+            .access_end()
+            .map_or(true, |end| start < end.regional_token_idx())
+    };
+    ends_after(a, b.access_start()) && ends_after(b, a.access_start())
+}
+
+fn current_variable_accessible(
+    db: &::salsa::Db,
+    region: SynExprRegion,
+    regional_token_idx: RegionalTokenIdx,
+    ident: Ident,
+) -> bool {
+    region
+        .data(db)
+        .variable_region()
+        .current_variables()
+        .any(|entry| entry.ident() == Some(ident) && is_accessible(entry, regional_token_idx))
+}
+
+fn has_current_variable(db: &::salsa::Db, region: SynExprRegion, ident: Ident) -> bool {
+    region
+        .data(db)
+        .variable_region()
+        .current_variables()
+        .any(|entry| entry.ident() == Some(ident))
+}
+
+fn is_descendant_region(db: &::salsa::Db, region: SynExprRegion, ancestor: SynExprRegion) -> bool {
+    let mut parent = region.data(db).parent();
+    while let Some(region) = parent {
+        if region == ancestor {
+            return true;
+        }
+        parent = region.data(db).parent()
+    }
+    false
+}
+
+/// strict ancestors of `region` that are strict descendants of `ancestor`
+fn ancestors_between<'a>(
+    db: &'a ::salsa::Db,
+    region: SynExprRegion,
+    ancestor: SynExprRegion,
+) -> impl Iterator<Item = SynExprRegion> + 'a {
+    std::iter::successors(region.data(db).parent(), move |region| {
+        region.data(db).parent()
+    })
+    .take_while(move |&region| region != ancestor)
+}
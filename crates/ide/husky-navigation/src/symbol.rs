@@ -0,0 +1,289 @@
+//! document outline and workspace-wide symbol search
+use crate::*;
+use husky_entity_kind::{EntityKind, MajorItemKind};
+use husky_entity_path::path::impl_block::TypeSketch;
+use husky_entity_tree::{
+    jar::EntityTreeDb,
+    node::{
+        impl_block::ImplBlockSynNodePath, major_item::MajorItemSynNodePath, ItemSynNodePath,
+        ItemSynNodePathId,
+    },
+};
+use husky_fuzzy_search_utils::fuzzy_search;
+use husky_syn_decl::decl::{major_item::ty::TypeSynDecl, HasSynDecl};
+use husky_text_protocol::range::TextPositionRange;
+use husky_token::TokenDb;
+use husky_vfs::jar::VfsDb;
+use vec_like::VecSet;
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ItemSymbol {
+    name: String,
+    kind: ItemSymbolKind,
+    /// the whole item, including its body
+    range: TextPositionRange,
+    /// the identifier, or the head of impl blocks
+    selection_range: TextPositionRange,
+    children: Vec<ItemSymbol>,
+}
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ItemSymbolKind {
+    Entity(EntityKind),
+    Field,
+}
+
+/// # getters
+impl ItemSymbol {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> ItemSymbolKind {
+        self.kind
+    }
+
+    pub fn range(&self) -> TextPositionRange {
+        self.range
+    }
+
+    pub fn selection_range(&self) -> TextPositionRange {
+        self.selection_range
+    }
+
+    pub fn children(&self) -> &[ItemSymbol] {
+        &self.children
+    }
+}
+
+impl ItemSymbolKind {
+    pub fn is_ty(self) -> bool {
+        matches!(
+            self,
+            ItemSymbolKind::Entity(EntityKind::MajorItem {
+                module_item_kind: MajorItemKind::Type(_) | MajorItemKind::Trait,
+                ..
+            })
+        )
+    }
+}
+
+/// the outline of a module, in the order of appearance,
+/// including its submodules but not their items, which belong to the outlines of their own files
+#[salsa::tracked(return_ref)]
+pub fn module_item_symbols(db: &::salsa::Db, module_path: ModulePath) -> Vec<ItemSymbol> {
+    let item_tree_sheet = db.item_syn_tree_sheet(module_path);
+    let mut symbols: Vec<ItemSymbol> = item_tree_sheet
+        .major_item_syn_node_paths()
+        .filter_map(|syn_node_path| {
+            let children = match syn_node_path {
+                ItemSynNodePath::MajorItem(MajorItemSynNodePath::Type(ty_syn_node_path)) => {
+                    let mut children = ty_field_symbols(db, syn_node_path);
+                    children.extend(
+                        ty_syn_node_path
+                            .ty_variant_syn_node_paths(db)
+                            .filter_map(|path| item_symbol(db, path.into(), vec![])),
+                    );
+                    children
+                }
+                ItemSynNodePath::MajorItem(MajorItemSynNodePath::Trait(trai_syn_node_path)) => {
+                    trai_syn_node_path
+                        .item_node_paths(db)
+                        .filter_map(|path| item_symbol(db, path.into(), vec![]))
+                        .collect()
+                }
+                _ => vec![],
+            };
+            item_symbol(db, syn_node_path, children)
+        })
+        .collect();
+    symbols.extend(
+        item_tree_sheet
+            .impl_block_syn_node_paths()
+            .filter_map(|syn_node_path| {
+                let children = match syn_node_path {
+                    ImplBlockSynNodePath::TypeImplBlock(syn_node_path) => syn_node_path
+                        .item_syn_node_paths(db)
+                        .filter_map(|path| item_symbol(db, path.into(), vec![]))
+                        .collect(),
+                    ImplBlockSynNodePath::TraitForTypeImplBlock(syn_node_path) => syn_node_path
+                        .item_syn_node_paths(db)
+                        .filter_map(|path| item_symbol(db, path.into(), vec![]))
+                        .collect(),
+                    ImplBlockSynNodePath::IllFormedImplBlock(_) => return None,
+                };
+                item_symbol(db, syn_node_path.into(), children)
+            }),
+    );
+    symbols.sort_by_key(|symbol| symbol.range.start);
+    symbols
+}
+
+/// `None` for ambiguous or ill-formed items, which have no name to show
+fn item_symbol(
+    db: &::salsa::Db,
+    syn_node_path: ItemSynNodePath,
+    mut children: Vec<ItemSymbol>,
+) -> Option<ItemSymbol> {
+    let item_path = syn_node_path.unambiguous_item_path(db)?;
+    let name = match syn_node_path {
+        ItemSynNodePath::ImplBlock(syn_node_path) => impl_block_symbol_name(db, syn_node_path)?,
+        _ => item_path.ident(db)?.data().to_string(),
+    };
+    let module_path = syn_node_path.module_path(db);
+    let ranged_token_sheet = db.ranged_token_sheet(module_path);
+    let selection_range = ranged_token_sheet.token_text_range(syn_node_path.nav_token_idx(db)?);
+    let range = ranged_token_sheet.tokens_text_range(syn_node_path.ast_token_idx_range(db)?);
+    children.sort_by_key(|symbol| symbol.range.start);
+    Some(ItemSymbol {
+        name,
+        kind: ItemSymbolKind::Entity(item_path.entity_kind(db)),
+        range,
+        selection_range,
+        children,
+    })
+}
+
+fn impl_block_symbol_name(db: &::salsa::Db, syn_node_path: ImplBlockSynNodePath) -> Option<String> {
+    match syn_node_path {
+        ImplBlockSynNodePath::TypeImplBlock(syn_node_path) => Some(format!(
+            "impl {}",
+            syn_node_path.ty_path(db).ident(db).data()
+        )),
+        ImplBlockSynNodePath::TraitForTypeImplBlock(syn_node_path) => Some(format!(
+            "impl {} for {}",
+            syn_node_path.trai_path(db).ident(db).data(),
+            match syn_node_path.ty_sketch(db) {
+                TypeSketch::DeriveAny => "_",
+                TypeSketch::Path(ty_path) => ty_path.ident(db).data(),
+            }
+        )),
+        ImplBlockSynNodePath::IllFormedImplBlock(_) => None,
+    }
+}
+
+/// fields of props structs, which aren't items on their own
+fn ty_field_symbols(db: &::salsa::Db, syn_node_path: ItemSynNodePath) -> Vec<ItemSymbol> {
+    let ItemSynNodePath::MajorItem(MajorItemSynNodePath::Type(ty_syn_node_path)) = syn_node_path
+    else {
+        unreachable!()
+    };
+    let Some(ty_path) = ty_syn_node_path.unambiguous_item_path(db) else {
+        return vec![];
+    };
+    let Ok(TypeSynDecl::PropsStruct(decl)) = ty_path.syn_decl(db) else {
+        return vec![];
+    };
+    let syn_node_path_id: ItemSynNodePathId = *syn_node_path;
+    let regional_token_idx_base = syn_node_path_id.decl_regional_token_idx_base(db);
+    let ranged_token_sheet = db.ranged_token_sheet(syn_node_path.module_path(db));
+    decl.fields(db)
+        .iter()
+        .map(|field| {
+            let range = ranged_token_sheet.token_text_range(
+                field
+                    .ident_token()
+                    .regional_token_idx()
+                    .token_idx(regional_token_idx_base),
+            );
+            ItemSymbol {
+                name: field.ident().data().to_string(),
+                kind: ItemSymbolKind::Field,
+                range,
+                selection_range: range,
+                children: vec![],
+            }
+        })
+        .collect()
+}
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WorkspaceSymbol {
+    module_path: ModulePath,
+    name: String,
+    kind: ItemSymbolKind,
+    selection_range: TextPositionRange,
+    container_name: Option<String>,
+}
+
+/// # getters
+impl WorkspaceSymbol {
+    pub fn module_path(&self) -> ModulePath {
+        self.module_path
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> ItemSymbolKind {
+        self.kind
+    }
+
+    pub fn selection_range(&self) -> TextPositionRange {
+        self.selection_range
+    }
+
+    pub fn container_name(&self) -> Option<&str> {
+        self.container_name.as_deref()
+    }
+}
+
+/// editors ask again as the query gets longer, so there's no need to return everything
+pub const MAX_WORKSPACE_SYMBOLS: usize = 128;
+
+/// searches the symbols of all live packages, best matches first
+pub fn workspace_symbols(db: &::salsa::Db, query: &str, only_tys: bool) -> Vec<WorkspaceSymbol> {
+    let Ok(live_packages) = db.live_packages() else {
+        return vec![];
+    };
+    let package_paths: VecSet<_> = live_packages.clone();
+    drop(live_packages);
+    let mut symbols: Vec<WorkspaceSymbol> = vec![];
+    for &package_path in package_paths.data() {
+        for module_path in db.collect_probable_modules(package_path) {
+            // items of submodules are told apart by their module
+            let module_name = (!module_path.is_root(db)).then(|| module_path.to_string_with_db(db));
+            collect_workspace_symbols(
+                module_path,
+                module_item_symbols(db, module_path),
+                module_name.as_deref(),
+                only_tys,
+                &mut symbols,
+            )
+        }
+    }
+    let mut symbols = fuzzy_search(query, symbols, |symbol| &symbol.name);
+    symbols.truncate(MAX_WORKSPACE_SYMBOLS);
+    symbols
+}
+
+fn collect_workspace_symbols(
+    module_path: ModulePath,
+    item_symbols: &[ItemSymbol],
+    container_name: Option<&str>,
+    only_tys: bool,
+    symbols: &mut Vec<WorkspaceSymbol>,
+) {
+    for item_symbol in item_symbols {
+        if !only_tys || item_symbol.kind.is_ty() {
+            symbols.push(WorkspaceSymbol {
+                module_path,
+                name: item_symbol.name.clone(),
+                kind: item_symbol.kind,
+                selection_range: item_symbol.selection_range,
+                container_name: container_name.map(ToString::to_string),
+            })
+        }
+        collect_workspace_symbols(
+            module_path,
+            &item_symbol.children,
+            Some(&item_symbol.name),
+            only_tys,
+            symbols,
+        )
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{usage::*, *};
+use husky_entity_path::path::{EntityPath, ItemPath};
+use husky_entity_tree::node::{HasSynNodePath, ItemSynNodePath};
+use husky_text_protocol::range::TextPositionRange;
+use husky_token::TokenDb;
+
+/// a range in a module, possibly of another crate or of the toolchain library
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct NavTarget {
+    module_path: ModulePath,
+    range: TextPositionRange,
+}
+
+/// # getters
+impl NavTarget {
+    pub fn module_path(self) -> ModulePath {
+        self.module_path
+    }
+
+    pub fn range(self) -> TextPositionRange {
+        self.range
+    }
+}
+
+impl NavTarget {
+    fn new_module_start(module_path: ModulePath) -> Self {
+        Self {
+            module_path,
+            range: TextPositionRange::from_u32((0, 0)..(0, 0)),
+        }
+    }
+
+    fn new_token(db: &::salsa::Db, module_path: ModulePath, token_idx: TokenIdx) -> Self {
+        Self {
+            module_path,
+            range: db
+                .ranged_token_sheet(module_path)
+                .token_text_range(token_idx),
+        }
+    }
+}
+
+impl Definition {
+    pub fn nav_target(self, db: &::salsa::Db) -> Option<NavTarget> {
+        match self {
+            Definition::Entity(EntityPath::Module(_, module_path)) => {
+                Some(NavTarget::new_module_start(module_path))
+            }
+            Definition::Entity(path) => item_nav_target(entity_syn_node_path(path, db)?, db),
+            Definition::ItemSynNode(syn_node_path) => item_nav_target(syn_node_path, db),
+            Definition::Variable { .. } => variable_nav_target(self, db),
+        }
+    }
+
+    /// the module where the definition lives
+    pub fn module_path(self, db: &::salsa::Db) -> Option<ModulePath> {
+        match self {
+            Definition::Entity(EntityPath::Module(_, module_path)) => Some(module_path),
+            Definition::Entity(path) => Some(entity_syn_node_path(path, db)?.module_path(db)),
+            Definition::ItemSynNode(syn_node_path) => Some(syn_node_path.module_path(db)),
+            Definition::Variable {
+                syn_expr_region, ..
+            } => Some(syn_expr_region.data(db).path().module_path(db)),
+        }
+    }
+}
+
+/// `None` for modules, which are not declared by an ast of their own
+fn entity_syn_node_path(path: EntityPath, db: &::salsa::Db) -> Option<ItemSynNodePath> {
+    let item_path: ItemPath = match path {
+        EntityPath::Module(_, _) => return None,
+        EntityPath::MajorItem(path) => path.into(),
+        EntityPath::AssocItem(path) => path.into(),
+        EntityPath::TypeVariant(_, path) => path.into(),
+        EntityPath::ImplBlock(path) => path.into(),
+        EntityPath::Attr(_, path) => path.into(),
+    };
+    Some(item_path.syn_node_path(db))
+}
+
+fn item_nav_target(syn_node_path: ItemSynNodePath, db: &::salsa::Db) -> Option<NavTarget> {
+    let token_idx = syn_node_path.nav_token_idx(db)?;
+    Some(NavTarget::new_token(
+        db,
+        syn_node_path.module_path(db),
+        token_idx,
+    ))
+}
+
+/// the binding site is the usage of kind definition,
+/// falling back to the first occurrence for variables bound inside expressions like loops
+fn variable_nav_target(definition: Definition, db: &::salsa::Db) -> Option<NavTarget> {
+    let module_path = definition.module_path(db)?;
+    let usage_sheet = module_path.usage_sheet(db);
+    let usage = usage_sheet
+        .definition_usages(definition)
+        .find(|usage| usage.kind() == UsageKind::Definition)
+        .or_else(|| usage_sheet.definition_usages(definition).next())?;
+    Some(NavTarget::new_token(db, module_path, usage.token_idx()))
+}
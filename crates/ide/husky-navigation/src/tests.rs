@@ -0,0 +1,277 @@
+pub(crate) use husky_ast::test_helpers::*;
+
+use crate::{
+    definition::token_definition,
+    error::RenameError,
+    rename::rename,
+    symbol::{module_item_symbols, workspace_symbols},
+    usage::{definition_usages, module_usage_sheet, UsageKind},
+    *,
+};
+use husky_corgi_config::jar::CorgiConfigJar;
+use husky_corgi_config_ast::CorgiConfigAstJar;
+use husky_coword::jar::CowordJar;
+use husky_dec_signature::jar::DecSignatureJar;
+use husky_entity_tree::jar::EntityTreeJar;
+use husky_eth_signature::jar::EthSignatureJar;
+use husky_eth_term::jar::EthTermJar;
+use husky_fly_term::jar::FlyTermJar;
+use husky_manifest::jar::ManifestJar;
+use husky_manifest_ast::jar::ManifestAstJar;
+use husky_path_utils::HuskyLangDevPaths;
+use husky_sem_expr::SemExprJar;
+use husky_syn_decl::jar::SynDeclJar;
+use husky_syn_defn::jar::SynDefnJar;
+use husky_syn_expr::jar::SynExprJar;
+use husky_term_prelude::jar::TermPreludeJar;
+use husky_text_protocol::position::TextPosition;
+use husky_token::{TokenDb, TokenJar};
+use husky_token_info::TokenInfoJar;
+use husky_toml_ast::TomlAstJar;
+use husky_vfs::jar::VfsDb;
+
+#[salsa::db(
+    husky_vfs::jar::VfsJar,
+    CowordJar,
+    husky_text::jar::TextJar,
+    husky_token_data::jar::TokenDataJar,
+    TokenJar,
+    TokenInfoJar,
+    husky_entity_path::jar::EntityPathJar,
+    husky_toml_token::jar::TomlTokenJar,
+    TomlAstJar,
+    ManifestAstJar,
+    CorgiConfigJar,
+    CorgiConfigAstJar,
+    ManifestJar,
+    husky_ast::jar::AstJar,
+    EntityTreeJar,
+    SynDeclJar,
+    SynDefnJar,
+    SynExprJar,
+    husky_place::jar::PlaceJar,
+    TermPreludeJar,
+    husky_dec_term::jar::DecTermJar,
+    DecSignatureJar,
+    husky_dec_ty::jar::DecTypeJar,
+    EthTermJar,
+    EthSignatureJar,
+    FlyTermJar,
+    SemExprJar,
+    husky_sem_place_contract::jar::SemPlaceContractJar,
+    Jar
+)]
+#[derive(Default)]
+pub(crate) struct DB;
+
+/// a module of the examples corpus, whose package is made live so that it can be renamed in
+fn example_module_path(db: &::salsa::Db, relative_path: &str) -> ModulePath {
+    db.resolve_module_path_and_update_live_packages(
+        &HuskyLangDevPaths::new().examples_dir().join(relative_path),
+    )
+    .unwrap()
+}
+
+fn closure_module_path(db: &::salsa::Db) -> ModulePath {
+    example_module_path(db, "basics/semantics-basics/src/closure.hsy")
+}
+
+fn token_idx(db: &::salsa::Db, module_path: ModulePath, position: (u32, u32)) -> TokenIdx {
+    db.ranged_token_sheet(module_path)
+        .search_token_by_position(position.into())
+        .unwrap()
+}
+
+#[test]
+fn definition_nav_target_works() {
+    DB::ast_rich_test_debug_with_db(
+        |db, module_path| -> Vec<(TokenIdx, NavTarget)> {
+            db.ranged_token_sheet(module_path)
+                .token_index_iter()
+                .filter_map(|token_idx| {
+                    Some((token_idx, db.definition_nav_target(module_path, token_idx)?))
+                })
+                .collect()
+        },
+        &AstTestConfig::new(
+            "definition_nav_target",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::IDE,
+        ),
+    )
+}
+
+/// the starts of the ranges `rename` replaces
+fn rename_starts(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    position: (u32, u32),
+    new_name: &str,
+) -> Result<Vec<TextPosition>, RenameError> {
+    rename(
+        db,
+        module_path,
+        token_idx(db, module_path, position),
+        new_name,
+    )
+    .map(|ranges| {
+        ranges
+            .into_iter()
+            .map(|(module_path1, range)| {
+                assert_eq!(module_path1, module_path);
+                range.start
+            })
+            .collect()
+    })
+}
+
+#[test]
+fn definition_usages_works() {
+    DB::ast_rich_test_debug_with_db(
+        |db, module_path| -> Vec<(TokenIdx, Vec<(ModulePath, TokenIdx, UsageKind)>)> {
+            module_usage_sheet(db, module_path)
+                .usages()
+                .iter()
+                .filter(|usage| usage.kind() == UsageKind::Definition)
+                .map(|usage| {
+                    (
+                        usage.token_idx(),
+                        definition_usages(db, usage.definition())
+                            .into_iter()
+                            .map(|(module_path, usage)| {
+                                (module_path, usage.token_idx(), usage.kind())
+                            })
+                            .collect(),
+                    )
+                })
+                .collect()
+        },
+        &AstTestConfig::new(
+            "definition_usages",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::IDE,
+        ),
+    )
+}
+
+#[test]
+fn references_works() {
+    let db = &*DB::default();
+    let module_path = closure_module_path(db);
+    // `a` of `capture_copyable`, captured by `f`
+    let definition = token_definition(db, module_path, token_idx(db, module_path, (1, 8))).unwrap();
+    assert_eq!(
+        definition_usages(db, definition)
+            .iter()
+            .map(|(_, usage)| usage.kind())
+            .collect::<Vec<_>>(),
+        [UsageKind::Definition, UsageKind::Read]
+    );
+    // `apply` is referred to by its definition and its call
+    let definition = token_definition(db, module_path, token_idx(db, module_path, (5, 3))).unwrap();
+    assert_eq!(definition_usages(db, definition).len(), 2);
+}
+
+#[test]
+fn rename_variable_works() {
+    let db = &*DB::default();
+    let module_path = closure_module_path(db);
+    assert_eq!(
+        rename_starts(db, module_path, (1, 8), "b"),
+        Ok(vec![
+            TextPosition::from((1, 8)),
+            TextPosition::from((2, 25))
+        ])
+    );
+}
+
+#[test]
+fn rename_variable_collision_works() {
+    let db = &*DB::default();
+    let module_path = closure_module_path(db);
+    // `f` is alive together with `a`
+    assert_eq!(
+        rename_starts(db, module_path, (2, 8), "a"),
+        Err(RenameError::Collision("a".to_string()))
+    );
+    // so is the closure parameter
+    assert_eq!(
+        rename_starts(db, module_path, (2, 13), "a"),
+        Err(RenameError::Collision("a".to_string()))
+    );
+}
+
+#[test]
+fn rename_variable_shadowing_works() {
+    let db = &*DB::default();
+    let module_path = example_module_path(
+        db,
+        "mnist-classifier/src/line_segment_sketch/line_segment.hsy",
+    );
+    // `ab` would hide the parameter `pt` from the rest of `dist_to_point`
+    assert_eq!(
+        rename_starts(db, module_path, (12, 12), "pt"),
+        Err(RenameError::Shadowed("pt".to_string()))
+    );
+}
+
+#[test]
+fn rename_item_works() {
+    let db = &*DB::default();
+    let module_path = closure_module_path(db);
+    assert_eq!(
+        rename_starts(db, module_path, (5, 3), "call"),
+        Ok(vec![
+            TextPosition::from((5, 3)),
+            TextPosition::from((11, 4))
+        ])
+    );
+    assert_eq!(
+        rename_starts(db, module_path, (5, 3), "capture_copyable"),
+        Err(RenameError::Collision("capture_copyable".to_string()))
+    );
+}
+
+#[test]
+fn module_item_symbols_works() {
+    DB::ast_rich_test_debug_with_db(
+        |db, module_path| module_item_symbols(db, module_path),
+        &AstTestConfig::new(
+            "module_item_symbols",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::IDE,
+        ),
+    )
+}
+
+#[test]
+fn workspace_symbols_works() {
+    let db = &*DB::default();
+    example_module_path(db, "basics/syntax-basics/src/lib.hsy");
+    let containers = |query| {
+        workspace_symbols(db, query, false)
+            .into_iter()
+            .filter(|symbol| symbol.name() == query)
+            .map(|symbol| symbol.container_name().map(ToString::to_string))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(containers("defn"), [None]);
+    assert_eq!(
+        containers("enum_ty"),
+        [Some("syntax_basics::defn::major_item::ty".to_string())]
+    );
+    assert_eq!(
+        containers("A"),
+        [Some(
+            "syntax_basics::defn::major_item::ty::enum_ty".to_string()
+        )]
+    );
+    assert_eq!(containers("UnitVariant"), [Some("A".to_string())]);
+    assert_eq!(
+        workspace_symbols(db, "A", true)
+            .iter()
+            .map(|symbol| symbol.name())
+            .collect::<Vec<_>>(),
+        ["A"]
+    );
+}
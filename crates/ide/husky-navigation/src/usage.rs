@@ -0,0 +1,232 @@
+use crate::*;
+use husky_entity_path::region::RegionPath;
+use husky_regional_token::RegionalTokenIdx;
+use husky_sem_expr::{
+    helpers::path::{sem_expr_region_from_region_path, syn_expr_region_from_region_path},
+    SemExprData, SemExprIdx,
+};
+use husky_sem_opr::binary::SemBinaryOpr;
+use husky_syn_expr::region::SynExprRegion;
+use husky_token_info::{ExprRegionLeash, TokenInfo, TokenInfoData, TokenInfoDb, TokenInfoSource};
+use husky_vfs::jar::VfsDb;
+use vec_like::VecSet;
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UsageKind {
+    /// the declaration of an item or the binding of a variable
+    Definition,
+    Read,
+    /// left hand side of an assignment
+    Write,
+}
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Usage {
+    token_idx: TokenIdx,
+    /// the region the token belongs to, used for checking shadowing
+    region: Option<(ExprRegionLeash, RegionalTokenIdx)>,
+    definition: Definition,
+    kind: UsageKind,
+}
+
+/// # getters
+impl Usage {
+    pub fn token_idx(self) -> TokenIdx {
+        self.token_idx
+    }
+
+    pub fn region(self) -> Option<(SynExprRegion, RegionalTokenIdx)> {
+        self.region
+            .map(|(syn_expr_region, regional_token_idx)| (*syn_expr_region, regional_token_idx))
+    }
+
+    pub fn definition(self) -> Definition {
+        self.definition
+    }
+
+    pub fn kind(self) -> UsageKind {
+        self.kind
+    }
+}
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModuleUsageSheet {
+    usages: Vec<Usage>,
+}
+
+impl ModuleUsageSheet {
+    pub fn usages(&self) -> &[Usage] {
+        &self.usages
+    }
+
+    pub fn definition_usages<'a>(
+        &'a self,
+        definition: Definition,
+    ) -> impl Iterator<Item = Usage> + 'a {
+        self.usages
+            .iter()
+            .copied()
+            .filter(move |usage| usage.definition == definition)
+    }
+}
+
+pub trait HasModuleUsageSheet: Copy {
+    fn usage_sheet(self, db: &::salsa::Db) -> &ModuleUsageSheet;
+}
+
+impl HasModuleUsageSheet for ModulePath {
+    fn usage_sheet(self, db: &::salsa::Db) -> &ModuleUsageSheet {
+        module_usage_sheet(db, self)
+    }
+}
+
+#[salsa::tracked(return_ref)]
+pub(crate) fn module_usage_sheet(db: &::salsa::Db, module_path: ModulePath) -> ModuleUsageSheet {
+    let Ok(token_info_sheet) = db.token_info_sheet_ref(module_path) else {
+        return ModuleUsageSheet { usages: vec![] };
+    };
+    let mut assignee_table = AssigneeTable::default();
+    let usages = token_info_sheet
+        .indexed_iter()
+        .filter_map(|(token_idx, token_infos)| {
+            let (token_info, definition) = token_infos.iter().rev().find_map(|token_info| {
+                Some((token_info, Definition::from_token_info(token_info, db)?))
+            })?;
+            Some(Usage {
+                token_idx,
+                region: usage_region(token_info, db),
+                definition,
+                kind: assignee_table.usage_kind(token_info, db),
+            })
+        })
+        .collect();
+    ModuleUsageSheet { usages }
+}
+
+fn usage_region(
+    token_info: &TokenInfo,
+    db: &::salsa::Db,
+) -> Option<(ExprRegionLeash, RegionalTokenIdx)> {
+    let regional_token_idx = token_info.regional_token_idx()?;
+    let syn_expr_region = match token_info.source() {
+        TokenInfoSource::SemExpr(region_path, _) | TokenInfoSource::Pattern(region_path, _) => {
+            syn_expr_region_from_region_path(region_path, db)?.into()
+        }
+        _ => match *token_info.data() {
+            TokenInfoData::CurrentVariable {
+                syn_expr_region, ..
+            }
+            | TokenInfoData::InheritedVariable {
+                syn_expr_region, ..
+            } => syn_expr_region,
+            _ => return None,
+        },
+    };
+    Some((syn_expr_region, regional_token_idx))
+}
+
+/// expressions assigned to, collected lazily per region
+#[derive(Default)]
+struct AssigneeTable {
+    regions: Vec<(RegionPath, VecSet<SemExprIdx>)>,
+}
+
+impl AssigneeTable {
+    fn usage_kind(&mut self, token_info: &TokenInfo, db: &::salsa::Db) -> UsageKind {
+        match token_info.source() {
+            TokenInfoSource::SemExpr(region_path, sem_expr_idx) => {
+                if self.assignees(region_path, db).has(sem_expr_idx) {
+                    UsageKind::Write
+                } else {
+                    UsageKind::Read
+                }
+            }
+            TokenInfoSource::Pattern(_, _) | TokenInfoSource::TemplateParameter(_) => {
+                UsageKind::Definition
+            }
+            _ => match token_info.data() {
+                TokenInfoData::EntityNode(_, _) | TokenInfoData::CurrentVariable { .. } => {
+                    UsageKind::Definition
+                }
+                _ => UsageKind::Read,
+            },
+        }
+    }
+
+    fn assignees(&mut self, region_path: RegionPath, db: &::salsa::Db) -> &VecSet<SemExprIdx> {
+        let index = match self
+            .regions
+            .iter()
+            .position(|(path, _)| *path == region_path)
+        {
+            Some(index) => index,
+            None => {
+                self.regions
+                    .push((region_path, calc_assignees(region_path, db)));
+                self.regions.len() - 1
+            }
+        };
+        &self.regions[index].1
+    }
+}
+
+fn calc_assignees(region_path: RegionPath, db: &::salsa::Db) -> VecSet<SemExprIdx> {
+    let mut assignees = VecSet::default();
+    let Some(sem_expr_region) = sem_expr_region_from_region_path(region_path, db) else {
+        return assignees;
+    };
+    for (_, entry) in sem_expr_region.data(db).sem_expr_arena().indexed_iter() {
+        if let Some(&SemExprData::Binary {
+            lopd,
+            opr: SemBinaryOpr::Assign | SemBinaryOpr::AssignClosed(_) | SemBinaryOpr::AssignShift(_),
+            ..
+        }) = entry.data_ok()
+        {
+            assignees.insert(lopd)
+        }
+    }
+    assignees
+}
+
+/// modules where usages of the definition can be found
+///
+/// items can be used by all live packages, i.e., the ones opened in the workspace,
+/// variables only by the module they are bound in
+pub(crate) fn definition_search_scope(
+    db: &::salsa::Db,
+    definition: Definition,
+) -> Option<Vec<ModulePath>> {
+    let home_module_path = definition.module_path(db)?;
+    match definition {
+        Definition::Variable { .. } => Some(vec![home_module_path]),
+        Definition::Entity(_) | Definition::ItemSynNode(_) => {
+            let mut package_paths: VecSet<_> = db.live_packages().ok()?.clone();
+            package_paths.insert(home_module_path.package_path(db));
+            Some(
+                package_paths
+                    .data()
+                    .iter()
+                    .flat_map(|&package_path| db.collect_probable_modules(package_path))
+                    .collect(),
+            )
+        }
+    }
+}
+
+pub fn definition_usages(db: &::salsa::Db, definition: Definition) -> Vec<(ModulePath, Usage)> {
+    let Some(module_paths) = definition_search_scope(db, definition) else {
+        return vec![];
+    };
+    module_paths
+        .into_iter()
+        .flat_map(|module_path| {
+            module_path
+                .usage_sheet(db)
+                .definition_usages(definition)
+                .map(move |usage| (module_path, usage))
+        })
+        .collect()
+}
@@ -29,6 +29,7 @@ impl std::ops::Index<TokenIdx> for TokenInfoSheet {
     }
 }
 
+#[derive(Clone, Copy)]
 pub struct TokenInfoSheetRef<'a> {
     token_infos_list: &'a [TokenInfos],
 }
@@ -41,6 +42,20 @@ impl<'a> std::ops::Index<TokenIdx> for TokenInfoSheetRef<'a> {
     }
 }
 
+impl<'a> TokenInfoSheetRef<'a> {
+    pub fn indexed_iter(self) -> impl Iterator<Item = (TokenIdx, &'a [TokenInfo])> {
+        self.token_infos_list
+            .iter()
+            .enumerate()
+            .map(|(index, infos)| {
+                (
+                    unsafe { TokenIdx::from_usize_index_ext(index) },
+                    infos.as_ref(),
+                )
+            })
+    }
+}
+
 impl TokenInfoSheet {
     pub(crate) fn new(token_sheet: &TokenSheetData) -> Self {
         TokenInfoSheet {
@@ -0,0 +1,96 @@
+use crate::{
+    expr::{IeHirExprArena, IeHirExprEntry, IeHirExprIdx, IeHirExprIdxRange},
+    helpers::fmt::{IeHirExprFormatter, IeHirExprFormatterConfig},
+    item_defn::{
+        IeHirItemDefnArena, IeHirItemDefnComment, IeHirItemDefnCommentMap, IeHirItemDefnData,
+        IeHirItemDefnIdx, IeHirItemDefnIdxRange,
+    },
+    stmt::{IeHirStmtArena, IeHirStmtData, IeHirStmtIdx, IeHirStmtIdxRange},
+};
+use eterned::db::EternerDb;
+
+#[derive(Default)]
+pub struct IeHirExprConstructor {
+    expr_arena: IeHirExprArena,
+    stmt_arena: IeHirStmtArena,
+    item_defn_arena: IeHirItemDefnArena,
+    item_defn_comments: IeHirItemDefnCommentMap,
+}
+
+impl IeHirExprConstructor {
+    pub fn formatter<'a>(
+        &'a self,
+        config: &'a IeHirExprFormatterConfig,
+        db: &'a EternerDb,
+    ) -> IeHirExprFormatter<'a> {
+        IeHirExprFormatter::new(
+            db,
+            self.expr_arena.as_arena_ref(),
+            self.stmt_arena.as_arena_ref(),
+            self.item_defn_arena.as_arena_ref(),
+            &self.item_defn_comments,
+            config,
+        )
+    }
+}
+
+impl IeHirExprConstructor {
+    pub fn alloc_expr(&mut self, entry: IeHirExprEntry) -> IeHirExprIdx {
+        self.expr_arena.alloc_one(entry)
+    }
+
+    pub fn alloc_exprs(
+        &mut self,
+        entries: impl IntoIterator<Item = IeHirExprEntry>,
+    ) -> IeHirExprIdxRange {
+        self.expr_arena.alloc_batch(entries)
+    }
+
+    pub fn alloc_stmt(&mut self, data: IeHirStmtData) -> IeHirStmtIdx {
+        self.stmt_arena.alloc_one(data)
+    }
+
+    pub fn alloc_stmts(
+        &mut self,
+        data: impl IntoIterator<Item = IeHirStmtData>,
+    ) -> IeHirStmtIdxRange {
+        self.stmt_arena.alloc_batch(data)
+    }
+
+    pub fn alloc_item_defn(
+        &mut self,
+        data: IeHirItemDefnData,
+        comment: IeHirItemDefnComment,
+    ) -> IeHirItemDefnIdx {
+        let item_defn = self.item_defn_arena.alloc_one(data);
+        self.item_defn_comments.insert_next(item_defn, comment);
+        item_defn
+    }
+
+    pub fn alloc_item_defns(
+        &mut self,
+        item_defns: impl IntoIterator<Item = IeHirItemDefnData>,
+        comments: impl IntoIterator<Item = IeHirItemDefnComment>,
+    ) -> IeHirItemDefnIdxRange {
+        let item_defns = self.item_defn_arena.alloc_batch(item_defns);
+        self.item_defn_comments
+            .insert_next_batch(item_defns, comments);
+        item_defns
+    }
+
+    pub fn finish(
+        self,
+    ) -> (
+        IeHirExprArena,
+        IeHirStmtArena,
+        IeHirItemDefnArena,
+        IeHirItemDefnCommentMap,
+    ) {
+        (
+            self.expr_arena,
+            self.stmt_arena,
+            self.item_defn_arena,
+            self.item_defn_comments,
+        )
+    }
+}
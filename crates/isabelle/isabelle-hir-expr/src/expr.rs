@@ -1,9 +1,87 @@
-use idx_arena::{Arena, ArenaIdx, ArenaIdxRange};
+use crate::{
+    ident::IeIdent,
+    item_path::IeItemPath,
+    opr::{IeBinaryOpr, IePrefixOpr, IePriority},
+};
+use idx_arena::{Arena, ArenaIdx, ArenaIdxRange, ArenaRef};
 
+#[derive(Debug, PartialEq, Eq)]
 pub enum IeHirExprData {
-    // Add variants here as needed
+    Literal(String),
+    ItemPath(IeItemPath),
+    Variable {
+        ident: IeIdent,
+    },
+    Application {
+        function: IeHirFunc,
+        arguments: IeHirExprIdxRange,
+    },
+    /// terms can't be `sorry` in isabelle, so whatever isn't supported yet becomes `undefined`
+    Undefined,
 }
 
-pub type IeHirExprArena = Arena<IeHirExprData>;
-pub type IeHirExprIdx = ArenaIdx<IeHirExprData>;
-pub type IeHirExprIdxRange = ArenaIdxRange<IeHirExprData>;
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IeHirFunc {
+    BinaryOpr(IeBinaryOpr),
+    PrefixOpr(IePrefixOpr),
+    ItemPath(IeItemPath),
+}
+
+pub struct IeHirExprEntry {
+    data: IeHirExprData,
+    ty_ascription: Option<IeHirExprIdx>,
+}
+
+pub type IeHirExprArena = Arena<IeHirExprEntry>;
+pub type IeHirExprArenaRef<'a> = ArenaRef<'a, IeHirExprEntry>;
+pub type IeHirExprIdx = ArenaIdx<IeHirExprEntry>;
+pub type IeHirExprIdxRange = ArenaIdxRange<IeHirExprEntry>;
+
+impl IeHirExprEntry {
+    pub fn new(data: IeHirExprData, ty_ascription: Option<IeHirExprIdx>) -> Self {
+        Self {
+            data,
+            ty_ascription,
+        }
+    }
+}
+
+impl IeHirExprEntry {
+    pub fn data(&self) -> &IeHirExprData {
+        &self.data
+    }
+
+    pub fn ty_ascription(&self) -> Option<IeHirExprIdx> {
+        self.ty_ascription
+    }
+}
+
+impl IeHirExprData {
+    pub(crate) fn outer_priority(&self) -> IePriority {
+        match *self {
+            // negative numerals are parsed as negations
+            IeHirExprData::Literal(ref literal) if literal.starts_with('-') => {
+                IePrefixOpr::Neg.priority()
+            }
+            IeHirExprData::Literal(_)
+            | IeHirExprData::ItemPath(_)
+            | IeHirExprData::Variable { .. }
+            | IeHirExprData::Undefined => IePriority::ATOM,
+            IeHirExprData::Application { function, .. } => match function {
+                IeHirFunc::BinaryOpr(opr) => opr.priority(),
+                IeHirFunc::PrefixOpr(opr) => opr.priority(),
+                IeHirFunc::ItemPath(_) => IePriority::APPLICATION,
+            },
+        }
+    }
+
+    pub(crate) fn children(&self) -> Vec<IeHirExprIdx> {
+        match *self {
+            IeHirExprData::Literal(_)
+            | IeHirExprData::ItemPath(_)
+            | IeHirExprData::Variable { .. }
+            | IeHirExprData::Undefined => vec![],
+            IeHirExprData::Application { arguments, .. } => arguments.into_iter().collect(),
+        }
+    }
+}
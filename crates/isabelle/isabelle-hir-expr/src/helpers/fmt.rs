@@ -0,0 +1,416 @@
+use crate::{
+    expr::{IeHirExprArenaRef, IeHirExprData, IeHirExprIdx, IeHirFunc},
+    ident::IeIdent,
+    item_defn::{
+        IeHirAssume, IeHirFix, IeHirItemDefnArenaRef, IeHirItemDefnComment, IeHirItemDefnData,
+        IeHirItemDefnGroupMeta, IeHirItemDefnIdx, IeHirItemDefnIdxRange, IeHirItemDefnOrderedMap,
+    },
+    opr::{IeBinaryOpr, IePriority},
+    proof::{IeHirMethod, IeHirProof},
+    stmt::{IeHirStmtArenaRef, IeHirStmtData, IeHirStmtIdx, IeHirStmtIdxRange},
+};
+use eterned::db::EternerDb;
+
+pub struct IeHirExprFormatter<'a> {
+    db: &'a EternerDb,
+    expr_arena: IeHirExprArenaRef<'a>,
+    stmt_arena: IeHirStmtArenaRef<'a>,
+    defn_arena: IeHirItemDefnArenaRef<'a>,
+    defn_comments: &'a IeHirItemDefnOrderedMap<IeHirItemDefnComment>,
+    config: &'a IeHirExprFormatterConfig,
+    result: String,
+    indent_level: usize,
+}
+
+pub struct IeHirExprFormatterConfig {
+    spaces_per_indent: usize,
+}
+
+impl Default for IeHirExprFormatterConfig {
+    fn default() -> Self {
+        Self {
+            spaces_per_indent: 2,
+        }
+    }
+}
+
+impl<'a> IeHirExprFormatter<'a> {
+    pub fn new(
+        db: &'a EternerDb,
+        expr_arena: IeHirExprArenaRef<'a>,
+        stmt_arena: IeHirStmtArenaRef<'a>,
+        defn_arena: IeHirItemDefnArenaRef<'a>,
+        defn_comments: &'a IeHirItemDefnOrderedMap<IeHirItemDefnComment>,
+        config: &'a IeHirExprFormatterConfig,
+    ) -> Self {
+        Self {
+            db,
+            expr_arena,
+            stmt_arena,
+            defn_arena,
+            defn_comments,
+            config,
+            result: Default::default(),
+            indent_level: 0,
+        }
+    }
+}
+
+impl<'a> IeHirExprFormatter<'a> {
+    pub fn db(&self) -> &'a EternerDb {
+        self.db
+    }
+}
+
+/// # terms
+impl<'a> IeHirExprFormatter<'a> {
+    pub fn format_expr_ext(&mut self, expr: IeHirExprIdx) {
+        self.format_expr(expr, IePriority::MIN);
+    }
+
+    fn format_expr(&mut self, expr: IeHirExprIdx, min_priority: IePriority) {
+        let expr_arena = self.expr_arena;
+        let expr_entry = &expr_arena[expr];
+        let needs_bracket = expr_entry.data().outer_priority() < min_priority
+            || expr_entry.ty_ascription().is_some();
+        if needs_bracket {
+            self.result += "(";
+        }
+        self.format_expr_inner(expr);
+        if let Some(ty_ascription) = expr_entry.ty_ascription() {
+            self.result += " :: ";
+            self.format_expr_ext(ty_ascription);
+        }
+        if needs_bracket {
+            self.result += ")";
+        }
+    }
+
+    fn format_expr_inner(&mut self, expr: IeHirExprIdx) {
+        let expr_arena = self.expr_arena;
+        match *expr_arena[expr].data() {
+            IeHirExprData::Literal(ref literal) => self.result += literal,
+            IeHirExprData::ItemPath(item_path) => self.result += item_path.code(),
+            IeHirExprData::Variable { ident } => self.result += ident.data(),
+            IeHirExprData::Application {
+                function,
+                arguments,
+            } => match function {
+                IeHirFunc::BinaryOpr(opr) => {
+                    debug_assert_eq!(arguments.len(), 2);
+                    self.format_expr(arguments.first().unwrap(), opr.left_priority());
+                    self.result += " ";
+                    self.result += opr.code();
+                    self.result += " ";
+                    self.format_expr(arguments.last().unwrap(), opr.right_priority());
+                }
+                IeHirFunc::PrefixOpr(opr) => {
+                    debug_assert_eq!(arguments.len(), 1);
+                    self.result += opr.code();
+                    self.format_expr(arguments.first().unwrap(), opr.operand_priority());
+                }
+                IeHirFunc::ItemPath(item_path) => {
+                    self.result += item_path.code();
+                    for argument in arguments {
+                        self.result += " ";
+                        self.format_expr(argument, IePriority::ATOM);
+                    }
+                }
+            },
+            IeHirExprData::Undefined => self.result += "undefined",
+        }
+    }
+
+    /// inner syntax is quoted within outer syntax
+    fn format_prop(&mut self, prop: IeHirExprIdx) {
+        self.result += "\"";
+        self.format_expr_ext(prop);
+        self.result += "\"";
+    }
+}
+
+/// # theory commands
+impl<'a> IeHirExprFormatter<'a> {
+    pub fn format_defns(&mut self, defns: IeHirItemDefnIdxRange) {
+        for (i, defn) in defns.into_iter().enumerate() {
+            if i > 0 {
+                self.result += "\n";
+            }
+            self.format_defn(defn);
+        }
+    }
+
+    pub fn format_defn(&mut self, defn: IeHirItemDefnIdx) {
+        self.make_sure_new_paragraph();
+        self.format_defn_comment(defn);
+        let defn_arena = self.defn_arena;
+        match defn_arena[defn] {
+            IeHirItemDefnData::Lemma {
+                ident,
+                ref fixes,
+                ref assumes,
+                shows,
+                ref proof,
+            } => {
+                self.result += "lemma ";
+                self.result += ident.data();
+                self.result += ":";
+                self.indented(|slf| {
+                    slf.format_context_elements(fixes, assumes);
+                    slf.make_sure_new_line();
+                    slf.result += "shows ";
+                    match shows {
+                        Some(shows) => slf.format_prop(shows),
+                        None => slf.result += "True",
+                    }
+                });
+                self.make_sure_new_line();
+                self.format_proof(proof);
+            }
+            IeHirItemDefnData::Context {
+                ref fixes,
+                ref assumes,
+                defns,
+            } => {
+                self.result += "context";
+                if fixes.is_empty() && assumes.is_empty() {
+                    self.result += " begin";
+                } else {
+                    self.indented(|slf| slf.format_context_elements(fixes, assumes));
+                    self.make_sure_new_line();
+                    self.result += "begin";
+                }
+                self.format_defns(defns);
+                self.make_sure_new_line();
+                self.result += "end\n";
+            }
+            IeHirItemDefnData::Group { defns, ref meta } => match *meta {
+                IeHirItemDefnGroupMeta::Division(Some(ref markup)) => {
+                    self.result += markup.command.code();
+                    self.result += " \\<open>";
+                    self.result += &markup.title;
+                    self.result += "\\<close>\n";
+                    self.format_defns(defns);
+                }
+                IeHirItemDefnGroupMeta::Environment(ref name) => {
+                    self.result += "(* ";
+                    self.result += name;
+                    self.result += " *)";
+                    self.make_sure_new_line();
+                    self.result += "context begin";
+                    self.format_defns(defns);
+                    self.make_sure_new_line();
+                    self.result += "end\n";
+                }
+                IeHirItemDefnGroupMeta::Division(None)
+                | IeHirItemDefnGroupMeta::Paragraph
+                | IeHirItemDefnGroupMeta::Sentence => self.format_defns(defns),
+            },
+        }
+    }
+
+    fn format_context_elements(&mut self, fixes: &[IeHirFix], assumes: &[IeHirAssume]) {
+        for fix in fixes {
+            self.make_sure_new_line();
+            self.result += "fixes ";
+            self.result += fix.ident.data();
+            self.result += " :: ";
+            self.format_expr_ext(fix.ty);
+        }
+        for assume in assumes {
+            self.make_sure_new_line();
+            self.result += "assumes ";
+            self.result += assume.ident.data();
+            self.result += ": ";
+            self.format_prop(assume.prop);
+        }
+    }
+
+    pub fn format_defn_comment(&mut self, defn: IeHirItemDefnIdx) {
+        match self.defn_comments[defn] {
+            IeHirItemDefnComment::Void => {}
+            IeHirItemDefnComment::Lines(ref lines) => {
+                for line in lines {
+                    self.make_sure_new_line();
+                    self.result += "(* ";
+                    self.result += line;
+                    self.result += " *)";
+                }
+                self.make_sure_new_line();
+            }
+            IeHirItemDefnComment::Qed => {
+                self.make_sure_new_line();
+                self.result += "(* qed *)";
+                self.make_sure_new_line();
+            }
+        }
+    }
+}
+
+/// # proofs
+impl<'a> IeHirExprFormatter<'a> {
+    pub fn format_proof(&mut self, proof: &IeHirProof) {
+        match *proof {
+            IeHirProof::By(ref method) => {
+                self.result += "by ";
+                self.format_method(method);
+            }
+            IeHirProof::This => self.result += ".",
+            IeHirProof::Block { stmts } => {
+                self.result += "proof -";
+                self.indented(|slf| slf.format_stmts(stmts));
+                self.make_sure_new_line();
+                self.result += "qed";
+            }
+            IeHirProof::Sorry => self.result += "sorry",
+        }
+    }
+
+    fn format_method(&mut self, method: &IeHirMethod) {
+        match *method {
+            IeHirMethod::Auto => self.result += "auto",
+            IeHirMethod::Simp { ref add } if add.is_empty() => self.result += "simp",
+            IeHirMethod::Simp { ref add } => {
+                self.result += "(simp add:";
+                for fact in add {
+                    self.result += " ";
+                    self.result += fact.code();
+                }
+                self.result += ")";
+            }
+            IeHirMethod::Algebra => self.result += "algebra",
+            IeHirMethod::Linarith => self.result += "linarith",
+        }
+    }
+
+    pub fn format_stmts(&mut self, stmts: IeHirStmtIdxRange) {
+        for stmt in stmts {
+            self.make_sure_new_line();
+            self.format_stmt(stmt);
+        }
+    }
+
+    fn format_stmt(&mut self, stmt: IeHirStmtIdx) {
+        let stmt_arena = self.stmt_arena;
+        match stmt_arena[stmt] {
+            IeHirStmtData::Define { ident, assignment } => {
+                self.result += "define ";
+                self.result += ident.data();
+                self.result += " where \"";
+                self.result += ident.data();
+                self.result += " = ";
+                self.format_expr(assignment, IeBinaryOpr::Eq.right_priority());
+                self.result += "\"";
+            }
+            IeHirStmtData::Have {
+                ident,
+                ref facts,
+                prop,
+                ref proof,
+            } => {
+                self.format_facts(facts);
+                self.result += "have ";
+                if let Some(ident) = ident {
+                    self.result += ident.data();
+                    self.result += ": ";
+                }
+                self.format_prop(prop);
+                self.result += " ";
+                self.format_proof(proof);
+            }
+            IeHirStmtData::Show {
+                ref facts,
+                prop,
+                ref proof,
+            } => {
+                self.format_facts(facts);
+                self.result += "show ";
+                match prop {
+                    Some(prop) => self.format_prop(prop),
+                    None => self.result += "?thesis",
+                }
+                self.result += " ";
+                self.format_proof(proof);
+            }
+            IeHirStmtData::Calculation {
+                leader,
+                ref steps,
+                ident,
+                conclusion,
+            } => {
+                for (i, &(opr, follower)) in steps.iter().enumerate() {
+                    self.make_sure_new_line();
+                    if i == 0 {
+                        self.result += "have \"";
+                        self.format_expr(leader, opr.left_priority());
+                    } else {
+                        self.result += "also have \"...";
+                    }
+                    self.result += " ";
+                    self.result += opr.code();
+                    self.result += " ";
+                    self.format_expr(follower, opr.right_priority());
+                    // AD HOC
+                    self.result += "\" by auto";
+                }
+                self.make_sure_new_line();
+                self.result += "finally have ";
+                self.result += ident.data();
+                self.result += ": ";
+                self.format_prop(conclusion);
+                self.result += " .";
+            }
+        }
+    }
+
+    fn format_facts(&mut self, facts: &[IeIdent]) {
+        if facts.is_empty() {
+            return;
+        }
+        self.result += "from";
+        for fact in facts {
+            self.result += " ";
+            self.result += fact.data();
+        }
+        self.result += " ";
+    }
+}
+
+/// # layout
+impl<'a> IeHirExprFormatter<'a> {
+    fn indented(&mut self, f: impl FnOnce(&mut Self)) {
+        self.make_sure_new_line();
+        self.indent_level += 1;
+        f(self);
+        self.indent_level -= 1;
+    }
+
+    fn make_sure_new_line(&mut self) {
+        let result_trimmed = self.result.trim_end_matches(' ');
+        if !result_trimmed.is_empty() && !result_trimmed.ends_with('\n') {
+            self.result += "\n";
+        }
+        let number_of_existing_spaces = self.result.len() - self.result.trim_end_matches(' ').len();
+        for _ in number_of_existing_spaces..(self.indent_level * self.config.spaces_per_indent) {
+            self.result.push(' ');
+        }
+    }
+
+    fn make_sure_new_paragraph(&mut self) {
+        debug_assert_eq!(self.indent_level, 0);
+        self.make_sure_new_line();
+        if !self.result.is_empty() {
+            let last_line = self.result.lines().last().unwrap_or("");
+            if !last_line.starts_with("context")
+                && !last_line.starts_with("begin")
+                && !self.result.ends_with("\n\n")
+            {
+                self.result += "\n";
+            }
+        }
+    }
+
+    pub fn finish(self) -> String {
+        self.result
+    }
+}
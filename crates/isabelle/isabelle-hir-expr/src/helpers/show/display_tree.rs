@@ -0,0 +1,122 @@
+use crate::{
+    expr::{IeHirExprArenaRef, IeHirExprData, IeHirExprIdx, IeHirFunc},
+    item_defn::{
+        IeHirItemDefnArenaRef, IeHirItemDefnChild, IeHirItemDefnData, IeHirItemDefnIdx,
+        IeHirItemDefnIdxRange,
+    },
+    proof::IeHirProof,
+    stmt::{IeHirStmtArenaRef, IeHirStmtIdx, IeHirStmtIdxRange},
+};
+use eterned::db::EternerDb;
+use husky_tree_utils::display::DisplayTree;
+
+pub struct IeHirExprDisplayTreeBuilder<'a> {
+    db: &'a EternerDb,
+    expr_arena: IeHirExprArenaRef<'a>,
+    stmt_arena: IeHirStmtArenaRef<'a>,
+    defn_arena: IeHirItemDefnArenaRef<'a>,
+}
+
+impl<'a> IeHirExprDisplayTreeBuilder<'a> {
+    pub fn new(
+        db: &'a EternerDb,
+        expr_arena: IeHirExprArenaRef<'a>,
+        stmt_arena: IeHirStmtArenaRef<'a>,
+        defn_arena: IeHirItemDefnArenaRef<'a>,
+    ) -> Self {
+        Self {
+            db,
+            expr_arena,
+            stmt_arena,
+            defn_arena,
+        }
+    }
+}
+
+impl<'a> IeHirExprDisplayTreeBuilder<'a> {
+    pub fn db(&self) -> &'a EternerDb {
+        self.db
+    }
+}
+
+impl<'a> IeHirExprDisplayTreeBuilder<'a> {
+    pub fn render_expr(&self, expr: IeHirExprIdx) -> DisplayTree {
+        let value = match *self.expr_arena[expr].data() {
+            IeHirExprData::Literal(ref literal) => format!("literal: `{literal}`"),
+            IeHirExprData::ItemPath(item_path) => format!("item path: `{}`", item_path.code()),
+            IeHirExprData::Variable { ident } => format!("variable: `{}`", ident.data()),
+            IeHirExprData::Application { function, .. } => match function {
+                IeHirFunc::BinaryOpr(opr) => format!("application: `{}`", opr.code()),
+                IeHirFunc::PrefixOpr(opr) => format!("application: `{}`", opr.code().trim_end()),
+                IeHirFunc::ItemPath(item_path) => format!("application: `{}`", item_path.code()),
+            },
+            IeHirExprData::Undefined => "undefined".to_string(),
+        };
+        let children = self.expr_arena[expr].data().children();
+        DisplayTree::new(
+            value,
+            children.into_iter().map(|c| self.render_expr(c)).collect(),
+        )
+    }
+
+    pub fn render_defns_together(&self, defns: IeHirItemDefnIdxRange) -> DisplayTree {
+        let children = self.render_defns(defns);
+        DisplayTree::new("defns".to_string(), children)
+    }
+
+    pub fn render_defns(&self, defns: IeHirItemDefnIdxRange) -> Vec<DisplayTree> {
+        defns
+            .into_iter()
+            .map(|defn| self.render_defn(defn))
+            .collect()
+    }
+
+    pub fn render_defn(&self, defn: IeHirItemDefnIdx) -> DisplayTree {
+        let defn_data = &self.defn_arena[defn];
+        let value = match *defn_data {
+            IeHirItemDefnData::Lemma { ident, .. } => format!("lemma: `{}`", ident.data()),
+            IeHirItemDefnData::Context { .. } => "context".to_string(),
+            IeHirItemDefnData::Group { ref meta, .. } => format!("group: `{meta}`"),
+        };
+        let mut children: Vec<_> = defn_data
+            .children()
+            .into_iter()
+            .map(|c| self.render_defn_child(c))
+            .collect();
+        if let IeHirItemDefnData::Lemma { ref proof, .. } = *defn_data {
+            children.push(self.render_proof(proof));
+        }
+        DisplayTree::new(value, children)
+    }
+
+    fn render_defn_child(&self, child: IeHirItemDefnChild) -> DisplayTree {
+        match child {
+            IeHirItemDefnChild::Defn(defn) => self.render_defn(defn),
+            IeHirItemDefnChild::Expr(expr) => self.render_expr(expr),
+        }
+    }
+
+    fn render_proof(&self, proof: &IeHirProof) -> DisplayTree {
+        match *proof {
+            IeHirProof::Block { stmts } => self.render_stmts_together(stmts),
+            _ => DisplayTree::new(format!("proof: `{proof:?}`"), vec![]),
+        }
+    }
+
+    pub fn render_stmts_together(&self, stmts: IeHirStmtIdxRange) -> DisplayTree {
+        let children = self.render_stmts(stmts);
+        DisplayTree::new("stmts".to_string(), children)
+    }
+
+    pub fn render_stmts(&self, stmts: IeHirStmtIdxRange) -> Vec<DisplayTree> {
+        stmts
+            .into_iter()
+            .map(|stmt| self.render_stmt(stmt))
+            .collect()
+    }
+
+    pub fn render_stmt(&self, stmt: IeHirStmtIdx) -> DisplayTree {
+        let value = format!("stmt: `{:?}`", self.stmt_arena[stmt]);
+        DisplayTree::new(value, vec![])
+    }
+}
@@ -0,0 +1,38 @@
+use base_coword::BaseCoword;
+use eterned::db::EternerDb;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct IeIdent(BaseCoword);
+
+impl IeIdent {
+    pub fn from_owned(ident: String, db: &EternerDb) -> Self {
+        assert!(Self::is_valid(&ident));
+        IeIdent(BaseCoword::new(ident, db))
+    }
+
+    pub fn from_ref(ident: &str, db: &EternerDb) -> Self {
+        assert!(Self::is_valid(ident));
+        IeIdent(BaseCoword::from_ref(ident, db))
+    }
+
+    /// isabelle identifiers start with a letter and continue with letters, digits, `_` and `'`
+    pub fn is_valid(ident: &str) -> bool {
+        let mut chars = ident.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '\'')
+    }
+
+    pub fn is_reserved(ident: &str) -> bool {
+        // keywords of the outer syntax that can't be used as names of facts or variables,
+        // together with constants of HOL that single letters would otherwise shadow
+        const RESERVED_KEYWORDS: &[&str] = &[
+            "and", "assumes", "begin", "defines", "end", "fixes", "for", "if", "in", "is", "let",
+            "lemma", "obtains", "shows", "then", "theorem", "where", "o",
+        ];
+        RESERVED_KEYWORDS.contains(&ident)
+    }
+
+    pub fn data(self) -> &'static str {
+        self.0.data()
+    }
+}
@@ -0,0 +1,148 @@
+//! theory-level commands, grouped into an arena like the lean ones
+use crate::{expr::IeHirExprIdx, ident::IeIdent, proof::IeHirProof};
+use idx_arena::{ordered_map::ArenaOrderedMap, Arena, ArenaIdx, ArenaIdxRange, ArenaRef};
+
+pub enum IeHirItemDefnData {
+    /// `shows True` if `shows` is `None`
+    Lemma {
+        ident: IeIdent,
+        fixes: Vec<IeHirFix>,
+        assumes: Vec<IeHirAssume>,
+        shows: Option<IeHirExprIdx>,
+        proof: IeHirProof,
+    },
+    /// `context fixes … assumes … begin … end`
+    Context {
+        fixes: Vec<IeHirFix>,
+        assumes: Vec<IeHirAssume>,
+        defns: IeHirItemDefnIdxRange,
+    },
+    Group {
+        defns: IeHirItemDefnIdxRange,
+        meta: IeHirItemDefnGroupMeta,
+    },
+}
+
+pub struct IeHirFix {
+    pub ident: IeIdent,
+    pub ty: IeHirExprIdx,
+}
+
+pub struct IeHirAssume {
+    pub ident: IeIdent,
+    pub prop: IeHirExprIdx,
+}
+
+pub enum IeHirItemDefnChild {
+    Expr(IeHirExprIdx),
+    Defn(IeHirItemDefnIdx),
+}
+
+impl IeHirItemDefnData {
+    /// the proof of a lemma is not included, as it isn't indexed
+    pub(crate) fn children(&self) -> Vec<IeHirItemDefnChild> {
+        match *self {
+            IeHirItemDefnData::Lemma {
+                ref fixes,
+                ref assumes,
+                shows,
+                ..
+            } => {
+                let mut children: Vec<_> = fixes
+                    .iter()
+                    .map(|fix| IeHirItemDefnChild::Expr(fix.ty))
+                    .chain(
+                        assumes
+                            .iter()
+                            .map(|assume| IeHirItemDefnChild::Expr(assume.prop)),
+                    )
+                    .collect();
+                if let Some(shows) = shows {
+                    children.push(IeHirItemDefnChild::Expr(shows));
+                }
+                children
+            }
+            IeHirItemDefnData::Context {
+                ref fixes,
+                ref assumes,
+                defns,
+            } => fixes
+                .iter()
+                .map(|fix| IeHirItemDefnChild::Expr(fix.ty))
+                .chain(
+                    assumes
+                        .iter()
+                        .map(|assume| IeHirItemDefnChild::Expr(assume.prop)),
+                )
+                .chain(defns.into_iter().map(IeHirItemDefnChild::Defn))
+                .collect(),
+            IeHirItemDefnData::Group { defns, .. } => {
+                defns.into_iter().map(IeHirItemDefnChild::Defn).collect()
+            }
+        }
+    }
+}
+
+pub enum IeHirItemDefnGroupMeta {
+    Paragraph,
+    Sentence,
+    /// marked by a document markup command like `section ‹Section1›`
+    Division(Option<IeHirDivisionMarkup>),
+    /// facts share one namespace within a theory, so this is just an unnamed context
+    Environment(String),
+}
+
+pub struct IeHirDivisionMarkup {
+    pub command: IeMarkupCommand,
+    pub title: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IeMarkupCommand {
+    Chapter,
+    Section,
+    Subsection,
+    Subsubsection,
+}
+
+impl IeMarkupCommand {
+    pub fn code(self) -> &'static str {
+        match self {
+            IeMarkupCommand::Chapter => "chapter",
+            IeMarkupCommand::Section => "section",
+            IeMarkupCommand::Subsection => "subsection",
+            IeMarkupCommand::Subsubsection => "subsubsection",
+        }
+    }
+}
+
+pub type IeHirItemDefnArena = Arena<IeHirItemDefnData>;
+pub type IeHirItemDefnOrderedMap<T> = ArenaOrderedMap<IeHirItemDefnData, T>;
+pub type IeHirItemDefnArenaRef<'a> = ArenaRef<'a, IeHirItemDefnData>;
+pub type IeHirItemDefnIdx = ArenaIdx<IeHirItemDefnData>;
+pub type IeHirItemDefnIdxRange = ArenaIdxRange<IeHirItemDefnData>;
+
+impl std::fmt::Display for IeHirItemDefnGroupMeta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IeHirItemDefnGroupMeta::Paragraph => write!(f, "paragraph"),
+            IeHirItemDefnGroupMeta::Sentence => write!(f, "sentence"),
+            IeHirItemDefnGroupMeta::Division(_) => write!(f, "division"),
+            IeHirItemDefnGroupMeta::Environment(_) => write!(f, "environment"),
+        }
+    }
+}
+
+pub enum IeHirItemDefnComment {
+    Void,
+    Lines(Vec<String>),
+    Qed,
+}
+
+pub type IeHirItemDefnCommentMap = IeHirItemDefnOrderedMap<IeHirItemDefnComment>;
+
+impl IeHirItemDefnComment {
+    pub fn from_latex_source(input: &str) -> Self {
+        Self::Lines(input.lines().map(|line| line.to_string()).collect())
+    }
+}
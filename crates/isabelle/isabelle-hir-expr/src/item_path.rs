@@ -0,0 +1,49 @@
+/// types and constants of `Complex_Main` that visored translates to
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IeItemPath {
+    /// # types
+    Nat,
+    Int,
+    Rat,
+    Real,
+    Complex,
+    Bool,
+    /// # constants
+    Sqrt,
+    OfNat,
+    OfInt,
+    OfRat,
+    Ln,
+    Exp,
+    Gcd,
+    Max,
+    Min,
+    Binomial,
+    Floor,
+    Abs,
+}
+
+impl IeItemPath {
+    pub fn code(self) -> &'static str {
+        match self {
+            IeItemPath::Nat => "nat",
+            IeItemPath::Int => "int",
+            IeItemPath::Rat => "rat",
+            IeItemPath::Real => "real",
+            IeItemPath::Complex => "complex",
+            IeItemPath::Bool => "bool",
+            IeItemPath::Sqrt => "sqrt",
+            IeItemPath::OfNat => "of_nat",
+            IeItemPath::OfInt => "of_int",
+            IeItemPath::OfRat => "of_rat",
+            IeItemPath::Ln => "ln",
+            IeItemPath::Exp => "exp",
+            IeItemPath::Gcd => "gcd",
+            IeItemPath::Max => "max",
+            IeItemPath::Min => "min",
+            IeItemPath::Binomial => "binomial",
+            IeItemPath::Floor => "floor",
+            IeItemPath::Abs => "abs",
+        }
+    }
+}
@@ -1,2 +1,10 @@
+pub mod constructor;
 pub mod expr;
+pub mod helpers;
+pub mod ident;
+pub mod item_defn;
+pub mod item_path;
+pub mod opr;
+pub mod proof;
 pub mod stmt;
+pub mod theorem;
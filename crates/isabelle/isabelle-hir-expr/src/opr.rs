@@ -0,0 +1,148 @@
+//! operators of HOL, following the priorities of their mixfix annotations
+
+/// priority of a term in the sense of isabelle's mixfix annotations,
+/// ranging from `0` for arbitrary terms to `1000` for atoms
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct IePriority(pub u16);
+
+impl IePriority {
+    pub const MIN: Self = IePriority(0);
+    pub const APPLICATION: Self = IePriority(999);
+    pub const ATOM: Self = IePriority(1000);
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IeBinaryOpr {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Eq,
+    Ne,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    Dvd,
+    Implies,
+    Iff,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IeAssociativity {
+    Left,
+    Right,
+    None,
+}
+
+impl IeBinaryOpr {
+    pub fn code(self) -> &'static str {
+        match self {
+            IeBinaryOpr::Add => "+",
+            IeBinaryOpr::Sub => "-",
+            IeBinaryOpr::Mul => "*",
+            IeBinaryOpr::Div => "/",
+            IeBinaryOpr::Pow => "^",
+            IeBinaryOpr::Eq => "=",
+            IeBinaryOpr::Ne => "\\<noteq>",
+            IeBinaryOpr::Lt => "<",
+            IeBinaryOpr::Gt => ">",
+            IeBinaryOpr::Le => "\\<le>",
+            IeBinaryOpr::Ge => "\\<ge>",
+            IeBinaryOpr::Dvd => "dvd",
+            IeBinaryOpr::Implies => "\\<longrightarrow>",
+            IeBinaryOpr::Iff => "\\<longleftrightarrow>",
+        }
+    }
+
+    pub fn priority(self) -> IePriority {
+        match self {
+            IeBinaryOpr::Eq
+            | IeBinaryOpr::Ne
+            | IeBinaryOpr::Lt
+            | IeBinaryOpr::Gt
+            | IeBinaryOpr::Le
+            | IeBinaryOpr::Ge
+            | IeBinaryOpr::Dvd => IePriority(50),
+            IeBinaryOpr::Implies | IeBinaryOpr::Iff => IePriority(25),
+            IeBinaryOpr::Add | IeBinaryOpr::Sub => IePriority(65),
+            IeBinaryOpr::Mul | IeBinaryOpr::Div => IePriority(70),
+            IeBinaryOpr::Pow => IePriority(80),
+        }
+    }
+
+    pub fn associativity(self) -> IeAssociativity {
+        match self {
+            IeBinaryOpr::Eq
+            | IeBinaryOpr::Ne
+            | IeBinaryOpr::Add
+            | IeBinaryOpr::Sub
+            | IeBinaryOpr::Mul
+            | IeBinaryOpr::Div => IeAssociativity::Left,
+            IeBinaryOpr::Pow | IeBinaryOpr::Implies | IeBinaryOpr::Iff => IeAssociativity::Right,
+            IeBinaryOpr::Lt
+            | IeBinaryOpr::Gt
+            | IeBinaryOpr::Le
+            | IeBinaryOpr::Ge
+            | IeBinaryOpr::Dvd => IeAssociativity::None,
+        }
+    }
+
+    /// the least priority of the left operand that goes without brackets
+    pub fn left_priority(self) -> IePriority {
+        let IePriority(p) = self.priority();
+        match self.associativity() {
+            IeAssociativity::Left => IePriority(p),
+            IeAssociativity::Right | IeAssociativity::None => IePriority(p + 1),
+        }
+    }
+
+    /// the least priority of the right operand that goes without brackets
+    pub fn right_priority(self) -> IePriority {
+        let IePriority(p) = self.priority();
+        match self.associativity() {
+            IeAssociativity::Right => IePriority(p),
+            IeAssociativity::Left | IeAssociativity::None => IePriority(p + 1),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IePrefixOpr {
+    Neg,
+}
+
+impl IePrefixOpr {
+    /// followed by a space, as `--` would start a comment in older isabelle
+    pub fn code(self) -> &'static str {
+        match self {
+            IePrefixOpr::Neg => "- ",
+        }
+    }
+
+    pub fn priority(self) -> IePriority {
+        match self {
+            IePrefixOpr::Neg => IePriority(80),
+        }
+    }
+
+    pub fn operand_priority(self) -> IePriority {
+        match self {
+            IePrefixOpr::Neg => IePriority(81),
+        }
+    }
+}
+
+#[test]
+fn ie_binary_opr_priorities_work() {
+    // `a - b - c` parses as `(a - b) - c`
+    assert_eq!(IeBinaryOpr::Sub.left_priority(), IePriority(65));
+    assert_eq!(IeBinaryOpr::Sub.right_priority(), IePriority(66));
+    // `a ^ b ^ c` parses as `a ^ (b ^ c)`
+    assert_eq!(IeBinaryOpr::Pow.left_priority(), IePriority(81));
+    assert_eq!(IeBinaryOpr::Pow.right_priority(), IePriority(80));
+    // `a < b < c` doesn't parse
+    assert_eq!(IeBinaryOpr::Lt.left_priority(), IePriority(51));
+    assert_eq!(IeBinaryOpr::Lt.right_priority(), IePriority(51));
+}
@@ -0,0 +1,38 @@
+use crate::{ident::IeIdent, stmt::IeHirStmtIdxRange, theorem::IeTheoremPath};
+use smallvec::SmallVec;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IeHirProof {
+    /// `by <method>`
+    By(IeHirMethod),
+    /// `.`, closing the goal with the chained facts
+    This,
+    /// `proof - … qed`
+    Block {
+        stmts: IeHirStmtIdxRange,
+    },
+    Sorry,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IeHirMethod {
+    Auto,
+    Simp { add: SmallVec<[IeHirFact; 2]> },
+    Algebra,
+    Linarith,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IeHirFact {
+    Ident(IeIdent),
+    Theorem(IeTheoremPath),
+}
+
+impl IeHirFact {
+    pub fn code(self) -> &'static str {
+        match self {
+            IeHirFact::Ident(ident) => ident.data(),
+            IeHirFact::Theorem(path) => path.code(),
+        }
+    }
+}
@@ -1 +1,38 @@
-pub enum IeHirStmtData {}
+//! Isar proof elements
+use crate::{expr::IeHirExprIdx, ident::IeIdent, opr::IeBinaryOpr, proof::IeHirProof};
+use idx_arena::{Arena, ArenaIdx, ArenaIdxRange, ArenaRef};
+use smallvec::SmallVec;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IeHirStmtData {
+    /// `define x where "x = e"`
+    Define {
+        ident: IeIdent,
+        assignment: IeHirExprIdx,
+    },
+    /// `from <facts> have h: "…"`
+    Have {
+        ident: Option<IeIdent>,
+        facts: SmallVec<[IeIdent; 2]>,
+        prop: IeHirExprIdx,
+        proof: IeHirProof,
+    },
+    /// `show ?thesis` if `prop` is `None`
+    Show {
+        facts: SmallVec<[IeIdent; 2]>,
+        prop: Option<IeHirExprIdx>,
+        proof: IeHirProof,
+    },
+    /// `have "a = b" … also have "… < c" … finally have h: "a < c" .`
+    Calculation {
+        leader: IeHirExprIdx,
+        steps: SmallVec<[(IeBinaryOpr, IeHirExprIdx); 4]>,
+        ident: IeIdent,
+        conclusion: IeHirExprIdx,
+    },
+}
+
+pub type IeHirStmtArena = Arena<IeHirStmtData>;
+pub type IeHirStmtArenaRef<'a> = ArenaRef<'a, IeHirStmtData>;
+pub type IeHirStmtIdx = ArenaIdx<IeHirStmtData>;
+pub type IeHirStmtIdxRange = ArenaIdxRange<IeHirStmtData>;
@@ -0,0 +1,30 @@
+/// theorems of `Complex_Main` that visored applies
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IeTheoremPath {
+    ZeroLePower2,
+    ZeroLePower,
+    ZeroLessPower,
+    /// unlike lean's `Real.sqrt_nonneg`, this needs `0 ≤ x`, as isabelle's `sqrt` is odd
+    RealSqrtGeZero,
+    RealSqrtAbs,
+    MultNonnegNonneg,
+    MultPosPos,
+    DivideNonnegNonneg,
+    DividePosPos,
+}
+
+impl IeTheoremPath {
+    pub fn code(self) -> &'static str {
+        match self {
+            IeTheoremPath::ZeroLePower2 => "zero_le_power2",
+            IeTheoremPath::ZeroLePower => "zero_le_power",
+            IeTheoremPath::ZeroLessPower => "zero_less_power",
+            IeTheoremPath::RealSqrtGeZero => "real_sqrt_ge_zero",
+            IeTheoremPath::RealSqrtAbs => "real_sqrt_abs",
+            IeTheoremPath::MultNonnegNonneg => "mult_nonneg_nonneg",
+            IeTheoremPath::MultPosPos => "mult_pos_pos",
+            IeTheoremPath::DivideNonnegNonneg => "divide_nonneg_nonneg",
+            IeTheoremPath::DividePosPos => "divide_pos_pos",
+        }
+    }
+}
@@ -549,7 +549,8 @@ impl<'a> DecTermEngine<'a> {
                     SynPrefixOpr::Tilde => {
                         DecTerm::LeashOrBitNot(self.syn_expr_region_data.path().toolchain(self.db))
                     }
-                    SynPrefixOpr::Ref => todo!(),
+                    // there is no lifetime to borrow from yet
+                    SynPrefixOpr::Ref => self.dec_term_menu.static_ref_ty(),
                     SynPrefixOpr::Option => self.dec_term_menu.option_ty_path(),
                 };
                 Ok(DecApplication::new(self.db, tmpl, opd).into())
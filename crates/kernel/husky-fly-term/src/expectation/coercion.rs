@@ -243,13 +243,49 @@ impl ExpectCoercion {
             FlyBaseTypeData::Hole(_, _) => AltNone,
             FlyBaseTypeData::Sort(_) => todo!(),
             FlyBaseTypeData::Ritchie {
-                ritchie_kind,
-                parameter_contracted_tys,
-                return_ty,
-            } => {
-                p!(src_base_ty_data.debug(db), dst_base_ty_data.debug(db));
-                todo!()
-            }
+                ritchie_kind: RitchieKind::Type(_),
+                parameter_contracted_tys: src_parameter_contracted_tys,
+                return_ty: src_return_ty,
+            } => match dst_base_ty_data {
+                // closures and function items are called alike, so they coerce into each other
+                // as long as the signatures agree
+                FlyBaseTypeData::Ritchie {
+                    ritchie_kind: RitchieKind::Type(_),
+                    parameter_contracted_tys: dst_parameter_contracted_tys,
+                    return_ty: dst_return_ty,
+                } if src_parameter_contracted_tys.len() == dst_parameter_contracted_tys.len() => {
+                    let mut actions = smallvec![];
+                    for (src_parameter, dst_parameter) in std::iter::zip(
+                        src_parameter_contracted_tys.iter(),
+                        dst_parameter_contracted_tys.iter(),
+                    ) {
+                        match (src_parameter, dst_parameter) {
+                            (
+                                FlyRitchieParameter::Simple(src_parameter),
+                                FlyRitchieParameter::Simple(dst_parameter),
+                            ) if src_parameter.contract() == dst_parameter.contract() => (),
+                            _ => return AltNone,
+                        }
+                        if src_parameter.ty() != dst_parameter.ty() {
+                            actions.push(FlyTermResolveAction::AddExpectation {
+                                src: state.child_src(),
+                                expectee: dst_parameter.ty(),
+                                expectation: ExpectSubtypeOrEqual::new(src_parameter.ty()).into(),
+                            })
+                        }
+                    }
+                    if src_return_ty != dst_return_ty {
+                        actions.push(FlyTermResolveAction::AddExpectation {
+                            src: state.child_src(),
+                            expectee: src_return_ty,
+                            expectation: ExpectSubtypeOrEqual::new(dst_return_ty).into(),
+                        })
+                    }
+                    state.set_result(outcome_result, actions)
+                }
+                _ => AltNone,
+            },
+            FlyBaseTypeData::Ritchie { .. } => AltNone,
             FlyBaseTypeData::SymbolicVariable {
                 symbolic_variable: term,
             } => AltNone,
@@ -26,8 +26,28 @@ impl ExpectCoercion {
                 ty_arguments: expected_ty_arguments,
                 ty_ethereal_term,
             } => match prelude_indirection_ty_path {
-                PreludeIndirectionTypePath::Ref => todo!(),
-                PreludeIndirectionTypePath::RefMut => todo!(),
+                PreludeIndirectionTypePath::Ref => {
+                    debug_assert_eq!(expected_ty_arguments.len(), 2);
+                    self.try_finalize_coercion(
+                        state.expectee(),
+                        expected_ty_arguments[1],
+                        RedirectionFlyCoercion::Reref,
+                        db,
+                        terms,
+                        state,
+                    )
+                }
+                PreludeIndirectionTypePath::RefMut => {
+                    debug_assert_eq!(expected_ty_arguments.len(), 2);
+                    self.try_finalize_coercion(
+                        state.expectee(),
+                        expected_ty_arguments[1],
+                        RedirectionFlyCoercion::RerefMut,
+                        db,
+                        terms,
+                        state,
+                    )
+                }
                 PreludeIndirectionTypePath::Leash => {
                     debug_assert_eq!(expected_ty_arguments.len(), 1);
                     // todo: check place
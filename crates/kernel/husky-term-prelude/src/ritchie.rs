@@ -24,7 +24,8 @@ impl RitchieTypeKind {
         match self {
             RitchieTypeKind::Item(ritchie_item_kind) => Contract::Pure,
             RitchieTypeKind::Closure(ritchie_closure_kind) => match ritchie_closure_kind {
-                RitchieClosureKind::Fn => todo!(),
+                // calling doesn't change the captures
+                RitchieClosureKind::Fn => Contract::Pure,
                 RitchieClosureKind::Gn => todo!(),
                 RitchieClosureKind::Vn => todo!(),
                 RitchieClosureKind::Pn => todo!(),
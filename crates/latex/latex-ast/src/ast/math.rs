@@ -22,7 +22,7 @@ use latex_math_letter::letter::styled::LxMathLetterStyle;
 use latex_token::{
     idx::{LxMathTokenIdx, LxNameTokenIdx, LxTokenIdxRange},
     token::{
-        math::{digit::LxMathDigit, LxMathDelimiter},
+        math::{digit::LxMathDigit, LxMathDelimiter, LxMathTokenError},
         name::LxNameTokenData,
     },
 };
@@ -41,6 +41,8 @@ pub enum LxMathAstData {
     },
     Punctuation(LxMathTokenIdx, LxMathPunctuation),
     Digit(LxMathTokenIdx, LxMathDigit),
+    /// a malformed token, like the use of a malformed macro, kept so that later stages can report it
+    Error(LxMathTokenIdx, LxMathTokenError),
     /// not obtained through parsing, but through ui
     TextEdit {
         buffer: String,
@@ -190,7 +192,7 @@ impl<'a> LxAstParser<'a> {
             }
             LxMathTokenData::Subscript => todo!(),
             LxMathTokenData::Superscript => todo!(),
-            LxMathTokenData::Error(_) => todo!(),
+            LxMathTokenData::Error(error) => LxMathAstData::Error(token_idx, error),
             LxMathTokenData::MathModeEnd => unreachable!(),
         })
     }
@@ -245,7 +247,8 @@ impl<'a> LxAstParser<'a> {
             LxMathTokenData::Other(_) => todo!(),
             LxMathTokenData::Subscript => todo!(),
             LxMathTokenData::Superscript => todo!(),
-            LxMathTokenData::Error(_) => todo!(),
+            // `parse_math_asts` takes error tokens as asts
+            LxMathTokenData::Error(_) => unreachable!(),
             LxMathTokenData::MathModeEnd => todo!(),
         }
     }
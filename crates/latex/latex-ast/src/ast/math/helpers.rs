@@ -22,6 +22,7 @@ impl LxMathAstData {
             LxMathAstData::StyledLetter { .. } => vec![],
             LxMathAstData::Punctuation(_, _) => vec![],
             LxMathAstData::Digit(_, _) => vec![],
+            LxMathAstData::Error(_, _) => vec![],
             LxMathAstData::TextEdit { ref buffer } => vec![],
             LxMathAstData::CompleteCommand {
                 command_token_idx,
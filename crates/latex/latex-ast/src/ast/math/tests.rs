@@ -230,3 +230,69 @@ fn parse_styled_letter_works() {
         "#]],
     );
 }
+
+#[test]
+fn parse_user_defined_macros_works() {
+    t(
+        r"\newcommand{\R}{\mathbb{R}} x\in\R",
+        expect![[r#"
+            ├─ "x" plain letter
+            ├─ "\\in" complete command
+            └─ "\\R" styled letter
+        "#]],
+    );
+    t(
+        r"\newcommand{\sq}[1]{{#1}^2} \sq{x+1}=\sq y",
+        expect![[r#"
+            ├─ "\\sq{x+1}" attach
+            │ ├─ "\\sq{x+1}" delimited
+            │ │ ├─ "\\sq{x+1}" plain letter
+            │ │ ├─ "\\sq{x+1}" punctuation
+            │ │ └─ "\\sq{x+1}" digit
+            │ └─ "\\sq{x+1}" digit
+            ├─ "=" punctuation
+            └─ "\\sq y" attach
+              ├─ "\\sq y" delimited
+              │ └─ "\\sq y" plain letter
+              └─ "\\sq y" digit
+        "#]],
+    );
+    t(
+        r"\def\half#1{\frac{#1}{2}} \half{\pi}",
+        expect![[r#"
+            └─ "\\half{\\pi}" complete command
+              ├─ \half{\pi}
+              │ └─ "\\half{\\pi}" complete command
+              └─ \half{\pi}
+                └─ "\\half{\\pi}" digit
+        "#]],
+    );
+    t(
+        r"\DeclareMathOperator{\lcm}{lcm} \lcm",
+        expect![[r#"
+            └─ "\\lcm" complete command
+              └─ \lcm
+                └─ "\\lcm" word
+        "#]],
+    );
+}
+
+#[test]
+fn parse_malformed_macros_works() {
+    t(
+        r"\newcommand{\f}[1]{#1} x+\f",
+        expect![[r#"
+            ├─ "x" plain letter
+            ├─ "+" punctuation
+            └─ "\\f" error: Macro(MissingArgument)
+        "#]],
+    );
+    t(
+        r"\def\x{\x} \x=1",
+        expect![[r#"
+            ├─ "\\x" error: Macro(ExpansionTooDeep)
+            ├─ "=" punctuation
+            └─ "1" digit
+        "#]],
+    );
+}
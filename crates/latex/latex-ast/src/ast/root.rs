@@ -18,7 +18,7 @@ use latex_token::{
     idx::{LxNameTokenIdx, LxRootTokenIdx},
     token::{
         name::LxNameTokenData,
-        root::{LxRootDelimiter, LxRootTokenData},
+        root::{LxRootDelimiter, LxRootTokenData, LxRootTokenError},
         spec::{LxSpecDelimiter, LxSpecTokenData},
     },
 };
@@ -45,6 +45,8 @@ pub enum LxRootAstData {
         end_rcurl_token_idx: LxRootTokenIdx,
         environment_signature: LxEnvironmentSignature,
     },
+    /// a malformed token, like a malformed macro definition, kept so that later stages can report it
+    Error(LxRootTokenIdx, LxRootTokenError),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -106,6 +108,7 @@ impl<'a> LxAstParser<'a> {
             }
             LxRootTokenData::LeftDelimiter(root_delimiter) => todo!(),
             LxRootTokenData::RightDelimiter(root_delimiter) => todo!(),
+            LxRootTokenData::Error(error) => Some(LxRootAstData::Error(token_idx, error)),
         }
     }
 
@@ -32,6 +32,7 @@ impl LxRootAstData {
                     asts.into_iter().map(LxRootAstChild::RoseAst).collect()
                 }
             },
+            LxRootAstData::Error(_, _) => vec![],
         }
     }
 }
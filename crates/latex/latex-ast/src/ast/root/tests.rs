@@ -77,3 +77,59 @@ fn parse_document_environment_works() {
         "#]],
     );
 }
+
+#[test]
+fn parse_document_with_user_defined_macros_works() {
+    t(
+        r#"\documentclass{article}
+\newcommand{\R}{\mathbb{R}}
+\newcommand{\norm}[2][2]{\sqrt[#1]{#2}}
+\begin{document}
+\renewcommand{\R}{\mathbb{C}}
+Let $x\in\R$ and $y=\norm{x}$.
+\end{document}"#,
+        expect![[r#"
+            ├─ "\\documentclass{article}" complete command
+            │ └─ article
+            └─ "\\begin{document}\n\\renewcommand{\\R}{\\mathbb{C}}\nLet $x\\in\\R$ and $y=\\norm{x}$.\n\\end{document}" environment
+              ├─ "Let" word
+              ├─ "$x\\in\\R$" math
+              │ ├─ "x" plain letter
+              │ ├─ "\\in" complete command
+              │ └─ "\\R" styled letter
+              ├─ "and" word
+              ├─ "$y=\\norm{x}$" math
+              │ ├─ "y" plain letter
+              │ ├─ "=" punctuation
+              │ └─ "\\norm{x}" complete command
+              │   └─ \norm{x}
+              │     └─ "\\norm{x}" plain letter
+              └─ "." punctuation
+        "#]],
+    );
+}
+
+#[test]
+fn parse_document_with_malformed_macros_works() {
+    t(
+        r#"\documentclass{article}
+\newcommand{\g}{#1}
+\begin{document}
+\newcommand{\h}{#1}
+Let $x=1$.
+\end{document}"#,
+        expect![[r#"
+            ├─ "\\documentclass{article}" complete command
+            │ └─ article
+            ├─ "\\newcommand{\\g}{#1}" error: Macro(InvalidParameterInBody)
+            └─ "\\begin{document}\n\\newcommand{\\h}{#1}\nLet $x=1$.\n\\end{document}" environment
+              ├─ "\\newcommand{\\h}{#1}" error: Macro(InvalidParameterInBody)
+              ├─ "Let" word
+              ├─ "$x=1$" math
+              │ ├─ "x" plain letter
+              │ ├─ "=" punctuation
+              │ └─ "1" digit
+              └─ "." punctuation
+        "#]],
+    );
+}
@@ -18,7 +18,9 @@ use latex_environment::signature::LxEnvironmentSignature;
 use latex_rose_punctuation::LxRosePunctuation;
 use latex_token::{
     idx::{LxNameTokenIdx, LxRoseTokenIdx},
-    token::rose::{LxRoseDelimiter, LxRoseEmbeddedMathDelimiter, LxRoseTokenData},
+    token::rose::{
+        LxRoseDelimiter, LxRoseEmbeddedMathDelimiter, LxRoseTokenData, LxRoseTokenError,
+    },
 };
 use smallvec::{smallvec, SmallVec};
 
@@ -61,6 +63,8 @@ pub enum LxRoseAstData {
         end_rcurl_token_idx: LxRoseTokenIdx,
     },
     NewParagraph(LxRoseTokenIdx),
+    /// a malformed token, like a malformed macro definition, kept so that later stages can report it
+    Error(LxRoseTokenIdx, LxRoseTokenError),
 }
 
 pub type LxRoseAstArena = Arena<LxRoseAstData>;
@@ -101,6 +105,7 @@ impl LxRoseAstData {
                 LxAstIdxRange::Root(arena_idx_range) => todo!(),
             },
             LxRoseAstData::NewParagraph(_) => vec![],
+            LxRoseAstData::Error(_, _) => vec![],
         }
     }
 }
@@ -145,6 +150,7 @@ impl<'a> LxAstParser<'a> {
                 todo!()
             }
             LxRoseTokenData::RightDelimiter(lx_rose_delimiter) => todo!(),
+            LxRoseTokenData::Error(error) => LxRoseAstData::Error(token_idx, error),
         })
     }
 
@@ -143,6 +143,7 @@ impl<'a> LxAstDisplayTreeBuilder<'a> {
             LxMathAstData::StyledLetter { .. } => format!("{:?} styled letter", value),
             LxMathAstData::Punctuation(_, _) => format!("{:?} punctuation", value),
             LxMathAstData::Digit(_, _) => format!("{:?} digit", value),
+            LxMathAstData::Error(_, error) => format!("{:?} error: {:?}", value, error),
             LxMathAstData::Lefted {
                 left_command_token_idx,
                 argument,
@@ -196,7 +197,18 @@ impl<'a> LxAstDisplayTreeBuilder<'a> {
                     let grandchildren = self.render_math_asts(range);
                     DisplayTree::new(value, grandchildren)
                 }
-                LxMathCommandArgumentAsts::Rose(range) => todo!(),
+                LxMathCommandArgumentAsts::Rose(range) => {
+                    let value = if range.is_empty() {
+                        "".to_string()
+                    } else {
+                        let range = self.ast_token_idx_range_map[range.start()]
+                            .join(self.ast_token_idx_range_map[range.last().unwrap()]);
+                        self.input[self.token_storage.token_idx_range_offset_range(range)]
+                            .to_string()
+                    };
+                    let grandchildren = self.render_rose_asts(range);
+                    DisplayTree::new(value, grandchildren)
+                }
                 LxMathCommandArgumentAsts::Letter(_, _) => todo!(),
             },
             LxMathCompleteCommandArgument::MathAst(arena_idx) => todo!(),
@@ -227,6 +239,7 @@ impl<'a> LxAstDisplayTreeBuilder<'a> {
             LxRoseAstData::CompleteCommand { .. } => format!("{:?} complete command", value),
             LxRoseAstData::Environment { .. } => format!("{:?} environment", value),
             LxRoseAstData::NewParagraph(_) => format!("{:?} new paragraph", value),
+            LxRoseAstData::Error(_, error) => format!("{:?} error: {:?}", value, error),
         };
         DisplayTree::new(
             value,
@@ -263,6 +276,7 @@ impl<'a> LxAstDisplayTreeBuilder<'a> {
         let value = match self.ast_arena.root()[ast] {
             LxRootAstData::CompleteCommand { .. } => format!("{:?} complete command", value),
             LxRootAstData::Environment { .. } => format!("{:?} environment", value),
+            LxRootAstData::Error(_, error) => format!("{:?} error: {:?}", value, error),
         };
         DisplayTree::new(
             value,
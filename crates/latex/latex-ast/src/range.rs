@@ -169,6 +169,7 @@ impl<'a> LxAstTokenIdxRangeCalculator<'a> {
             } => LxTokenIdxRange::new_closed(*style_command_token_idx, *style_rcurl_token_idx),
             LxMathAstData::Punctuation(idx, _) => LxTokenIdxRange::new_single(*idx),
             LxMathAstData::Digit(idx, _) => LxTokenIdxRange::new_single(*idx),
+            LxMathAstData::Error(idx, _) => LxTokenIdxRange::new_single(*idx),
             LxMathAstData::TextEdit { ref buffer } => todo!(),
             LxMathAstData::Attach { base, ref scripts } => {
                 let mut range = self.get_math_ast_range(base);
@@ -254,6 +255,7 @@ impl<'a> LxAstTokenIdxRangeCalculator<'a> {
                 end_rcurl_token_idx,
                 ..
             } => LxTokenIdxRange::new_closed(*begin_command_token_idx, *end_rcurl_token_idx),
+            LxRootAstData::Error(token_idx, _) => LxTokenIdxRange::new_single(*token_idx),
         }
     }
 
@@ -304,6 +306,7 @@ impl<'a> LxAstTokenIdxRangeCalculator<'a> {
                 ..
             } => LxTokenIdxRange::new_closed(*begin_command_token_idx, *end_rcurl_token_idx),
             LxRoseAstData::NewParagraph(token_idx) => LxTokenIdxRange::new_single(*token_idx),
+            LxRoseAstData::Error(token_idx, _) => LxTokenIdxRange::new_single(*token_idx),
         }
     }
 
@@ -10,6 +10,12 @@ pub struct LxCommandPathMenu {
     pub usepackage: LxCommandPath,
     pub documentclass: LxCommandPath,
     pub newtheorem: LxCommandPath,
+    // - macros
+    pub newcommand: LxCommandPath,
+    pub renewcommand: LxCommandPath,
+    pub providecommand: LxCommandPath,
+    pub def: LxCommandPath,
+    pub declare_math_operator: LxCommandPath,
     // - divisions
     pub part: LxCommandPath,
     pub chapter: LxCommandPath,
@@ -58,9 +64,32 @@ pub struct LxCommandPathMenu {
     // --- functions
     pub sin: LxCommandPath,
     pub cos: LxCommandPath,
+    pub log: LxCommandPath,
+    pub exp: LxCommandPath,
+    pub lim: LxCommandPath,
+    pub max: LxCommandPath,
+    pub min: LxCommandPath,
+    pub gcd: LxCommandPath,
+    pub operatorname: LxCommandPath,
+    // --- number theory
+    pub mid: LxCommandPath,
+    pub pmod: LxCommandPath,
+    pub equiv: LxCommandPath,
+    // --- logic
+    pub to: LxCommandPath,
+    pub forall: LxCommandPath,
+    pub exists: LxCommandPath,
+    pub implies: LxCommandPath,
+    pub iff: LxCommandPath,
+    // -- delimiters
+    pub lfloor: LxCommandPath,
+    pub rfloor: LxCommandPath,
+    pub lvert: LxCommandPath,
+    pub rvert: LxCommandPath,
     // -- layouts
     pub sqrt: LxCommandPath,
     pub frac: LxCommandPath,
+    pub binom: LxCommandPath,
     // -- environments
     pub text: LxCommandPath,
     // - others
@@ -79,6 +108,12 @@ impl LxCommandPathMenu {
             usepackage: p("usepackage"),
             documentclass: p("documentclass"),
             newtheorem: p("newtheorem"),
+            // - macros
+            newcommand: p("newcommand"),
+            renewcommand: p("renewcommand"),
+            providecommand: p("providecommand"),
+            def: p("def"),
+            declare_math_operator: p("DeclareMathOperator"),
             // - divisions
             part: p("part"),
             chapter: p("chapter"),
@@ -127,9 +162,32 @@ impl LxCommandPathMenu {
             // -- functions
             sin: p("sin"),
             cos: p("cos"),
+            log: p("log"),
+            exp: p("exp"),
+            lim: p("lim"),
+            max: p("max"),
+            min: p("min"),
+            gcd: p("gcd"),
+            operatorname: p("operatorname"),
+            // -- number theory
+            mid: p("mid"),
+            pmod: p("pmod"),
+            equiv: p("equiv"),
+            // -- logic
+            to: p("to"),
+            forall: p("forall"),
+            exists: p("exists"),
+            implies: p("implies"),
+            iff: p("iff"),
+            // -- delimiters
+            lfloor: p("lfloor"),
+            rfloor: p("rfloor"),
+            lvert: p("lvert"),
+            rvert: p("rvert"),
             // -- layouts
             sqrt: p("sqrt"),
             frac: p("frac"),
+            binom: p("binom"),
             text: p("text"),
             left: p("left"),
             right: p("right"),
@@ -149,9 +149,32 @@ impl LxCommandSignatureTable {
             // -- functions
             sin,
             cos,
+            log,
+            exp,
+            lim,
+            max,
+            min,
+            gcd,
+            operatorname,
+            // -- number theory
+            mid,
+            pmod,
+            equiv,
+            // -- logic
+            to,
+            forall,
+            exists,
+            implies,
+            iff,
+            // -- delimiters
+            lfloor,
+            rfloor,
+            lvert,
+            rvert,
             // -- layouts
             sqrt,
             frac,
+            binom,
             text,
             ..
         } = *lx_command_path_menu(db);
@@ -212,12 +235,43 @@ impl LxCommandSignatureTable {
             (sqrt, &[LxMode::Math], &[LxCommandParameterMode::Math]),
             (sin, &[LxMode::Math], &[]),
             (cos, &[LxMode::Math], &[]),
+            (log, &[LxMode::Math], &[]),
+            (exp, &[LxMode::Math], &[]),
+            (lim, &[LxMode::Math], &[]),
+            (max, &[LxMode::Math], &[]),
+            (min, &[LxMode::Math], &[]),
+            (gcd, &[LxMode::Math], &[]),
+            (
+                operatorname,
+                &[LxMode::Math],
+                &[LxCommandParameterMode::Rose],
+            ),
+            // -- number theory
+            (mid, &[LxMode::Math], &[]),
+            (pmod, &[LxMode::Math], &[LxCommandParameterMode::Math]),
+            (equiv, &[LxMode::Math], &[]),
+            // -- logic
+            (to, &[LxMode::Math], &[]),
+            (forall, &[LxMode::Math], &[]),
+            (exists, &[LxMode::Math], &[]),
+            (implies, &[LxMode::Math], &[]),
+            (iff, &[LxMode::Math], &[]),
+            // -- delimiters
+            (lfloor, &[LxMode::Math], &[]),
+            (rfloor, &[LxMode::Math], &[]),
+            (lvert, &[LxMode::Math], &[]),
+            (rvert, &[LxMode::Math], &[]),
             // -- layouts
             (
                 frac,
                 &[LxMode::Math],
                 &[LxCommandParameterMode::Math, LxCommandParameterMode::Math],
             ),
+            (
+                binom,
+                &[LxMode::Math],
+                &[LxCommandParameterMode::Math, LxCommandParameterMode::Math],
+            ),
             (text, &[LxMode::Math], &[LxCommandParameterMode::Rose]),
         ]
     }
@@ -59,6 +59,8 @@ pub enum LxMathPunctuation {
     EscapedLcurl,
     /// Escaped right curly `}`
     EscapedRcurl,
+    /// Comma `,`
+    Comma,
     Ldot,
 }
 
@@ -95,6 +97,7 @@ impl LxMathPunctuation {
             LxMathPunctuation::Rbox => "]",
             LxMathPunctuation::EscapedLcurl => "\\{",
             LxMathPunctuation::EscapedRcurl => "\\}",
+            LxMathPunctuation::Comma => ",",
             LxMathPunctuation::Ldot => ".",
         }
     }
@@ -127,6 +130,7 @@ impl LxMathPunctuation {
             ')' => Some(LxMathPunctuation::Rpar),
             '[' => Some(LxMathPunctuation::Lbox),
             ']' => Some(LxMathPunctuation::Rbox),
+            ',' => Some(LxMathPunctuation::Comma),
             _ => None,
         }
     }
@@ -0,0 +1,477 @@
+//! user-defined macros, i.e., `\newcommand`, `\renewcommand`, `\providecommand`,
+//! simple `\def` and `\DeclareMathOperator`.
+//!
+//! definitions are consumed by the lexer without producing tokens;
+//! uses are replaced by their expansions, lexed in place,
+//! with every token of an expansion ranged as the macro use, arguments included.
+//! malformed definitions and uses become error tokens ranged in the same way.
+#[cfg(test)]
+mod tests;
+
+use crate::lexer::LxLexer;
+use base_coword::BaseCoword;
+use husky_text_protocol::{char::TextCharIter, offset::TextOffsetRange, range::TextPositionRange};
+use latex_command::path::{menu::lx_command_path_menu, LxCommandName};
+use rustc_hash::FxHashMap;
+
+/// TeX would loop forever on recursive macros, we give up instead
+const MAX_MACRO_EXPANSION_DEPTH: usize = 64;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LxMacroError {
+    ExpectedCommandName,
+    InvalidCommandName,
+    ExpectedRcurl,
+    /// `\newcommand` takes at most 9 parameters
+    InvalidParameterCount,
+    OptionalDefaultWithoutParameters,
+    /// only `#1#2...` is supported after `\def\name`
+    UnsupportedDefParameterText,
+    /// `#` followed by neither `#` nor the number of a parameter
+    InvalidParameterInBody,
+    UnbalancedBraces,
+    UnclosedLbox,
+    MissingArgument,
+    /// most likely a recursive macro
+    ExpansionTooDeep,
+}
+
+pub type LxMacroResult<T> = Result<T, LxMacroError>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LxMacroDefinition {
+    parameter_count: u8,
+    /// if present, the first parameter is optional and defaults to this
+    optional_default: Option<BaseCoword>,
+    body: BaseCoword,
+}
+
+impl LxMacroDefinition {
+    pub fn parameter_count(&self) -> u8 {
+        self.parameter_count
+    }
+
+    pub fn optional_default(&self) -> Option<BaseCoword> {
+        self.optional_default
+    }
+
+    pub fn body(&self) -> BaseCoword {
+        self.body
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LxMacroTable {
+    definitions: FxHashMap<LxCommandName, LxMacroDefinition>,
+}
+
+impl LxMacroTable {
+    pub fn definition(&self, command_name: LxCommandName) -> Option<&LxMacroDefinition> {
+        self.definitions.get(&command_name)
+    }
+
+    pub fn definitions(&self) -> &FxHashMap<LxCommandName, LxMacroDefinition> {
+        &self.definitions
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct LxMacroExpansion<'a> {
+    /// chars of the enclosing text, restored once the expansion is exhausted
+    outer_chars: TextCharIter<'a>,
+    /// ranges of the outermost macro use, shared by all tokens of the expansion
+    offset_range: TextOffsetRange,
+    range: TextPositionRange,
+}
+
+/// what's needed to restore the lexer after peeking
+pub(crate) struct LxLexerCursor<'a> {
+    chars: TextCharIter<'a>,
+    expansions: Vec<LxMacroExpansion<'a>>,
+}
+
+/// # getters
+impl<'a> LxLexer<'a> {
+    /// the ranges of the outermost macro use if we are inside an expansion
+    pub(crate) fn expansion_ranges(&self) -> Option<(TextOffsetRange, TextPositionRange)> {
+        self.expansions
+            .last()
+            .map(|expansion| (expansion.offset_range, expansion.range))
+    }
+}
+
+/// # actions
+impl<'a> LxLexer<'a> {
+    pub(crate) fn cursor(&self) -> LxLexerCursor<'a> {
+        LxLexerCursor {
+            chars: self.chars.clone(),
+            expansions: self.expansions.clone(),
+        }
+    }
+
+    pub(crate) fn restore_cursor(&mut self, cursor: LxLexerCursor<'a>) {
+        self.chars = cursor.chars;
+        self.expansions = cursor.expansions;
+    }
+
+    /// wraps a mode specific lexing function so that
+    /// macro definitions are consumed and macro uses are expanded
+    pub(crate) fn next_ranged_expanded_token_data<T>(
+        &mut self,
+        next_ranged_token_data: impl Fn(&mut Self) -> Option<(TextOffsetRange, TextPositionRange, T)>,
+        command_name: impl Fn(&T) -> Option<LxCommandName>,
+        macro_error: impl Fn(LxMacroError) -> T,
+    ) -> Option<(TextOffsetRange, TextPositionRange, T)> {
+        loop {
+            let Some((offset_range, range, token_data)) = next_ranged_token_data(self) else {
+                if self.leave_exhausted_expansion() {
+                    continue;
+                }
+                return None;
+            };
+            if let Some(command_name) = command_name(&token_data) {
+                let outer_ranges = self.expansion_ranges();
+                match self.process_macro_command(command_name, outer_ranges, offset_range, range) {
+                    Ok(true) => continue,
+                    Ok(false) => (),
+                    Err(error) => {
+                        let (offset_range, range) =
+                            self.macro_command_ranges(outer_ranges, offset_range, range);
+                        return Some((offset_range, range, macro_error(error)));
+                    }
+                }
+            }
+            return Some((offset_range, range, token_data));
+        }
+    }
+
+    fn leave_exhausted_expansion(&mut self) -> bool {
+        if !self.chars.remaining_str().is_empty() {
+            return false;
+        }
+        let Some(expansion) = self.expansions.pop() else {
+            return false;
+        };
+        self.chars = expansion.outer_chars;
+        true
+    }
+
+    /// the ranges from the start of a macro command to the current position,
+    /// or those of the outermost macro use if the command comes from an expansion
+    ///
+    /// `outer_ranges` are the expansion ranges before the command is processed,
+    /// which are left if the arguments run past the end of the expansion
+    fn macro_command_ranges(
+        &self,
+        outer_ranges: Option<(TextOffsetRange, TextPositionRange)>,
+        offset_range: TextOffsetRange,
+        range: TextPositionRange,
+    ) -> (TextOffsetRange, TextPositionRange) {
+        if let Some(ranges) = self.expansion_ranges() {
+            return ranges;
+        }
+        let (offset_range, range) = outer_ranges.unwrap_or((offset_range, range));
+        (
+            TextOffsetRange {
+                start: offset_range.start,
+                end: self.chars.current_offset(),
+            },
+            TextPositionRange {
+                start: range.start,
+                end: self.chars.current_position(),
+            },
+        )
+    }
+
+    /// returns true if the command is consumed as a macro definition or a macro use
+    fn process_macro_command(
+        &mut self,
+        command_name: LxCommandName,
+        outer_ranges: Option<(TextOffsetRange, TextPositionRange)>,
+        offset_range: TextOffsetRange,
+        range: TextPositionRange,
+    ) -> LxMacroResult<bool> {
+        let menu = lx_command_path_menu(self.db());
+        if command_name == menu.newcommand.name() || command_name == menu.renewcommand.name() {
+            self.define_by_newcommand(false)?;
+        } else if command_name == menu.providecommand.name() {
+            self.define_by_newcommand(true)?;
+        } else if command_name == menu.def.name() {
+            self.define_by_def()?;
+        } else if command_name == menu.declare_math_operator.name() {
+            self.define_by_declare_math_operator()?;
+        } else if let Some(&definition) = self.macro_table.definition(command_name) {
+            self.expand_macro(definition, outer_ranges, offset_range, range)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// `\newcommand{\name}[n][default]{body}`, braces around `\name` being optional
+    fn define_by_newcommand(&mut self, keep_existing: bool) -> LxMacroResult<()> {
+        self.eat_raw_star();
+        let command_name = self.next_raw_command_name()?;
+        let parameter_count = match self.next_raw_optional_argument()? {
+            Some(parameter_count) => match parameter_count.trim().parse::<u8>() {
+                Ok(parameter_count) if parameter_count <= 9 => parameter_count,
+                _ => return Err(LxMacroError::InvalidParameterCount),
+            },
+            None => 0,
+        };
+        let optional_default = self
+            .next_raw_optional_argument()?
+            .map(|default| BaseCoword::from_ref(default, self.db()));
+        if optional_default.is_some() && parameter_count == 0 {
+            return Err(LxMacroError::OptionalDefaultWithoutParameters);
+        }
+        let body = self.next_raw_argument()?;
+        substitute_macro_arguments(body, &[""; 9][..parameter_count as usize])?;
+        let body = BaseCoword::from_ref(body, self.db());
+        if keep_existing && self.macro_table.definitions.contains_key(&command_name) {
+            return Ok(());
+        }
+        self.macro_table.definitions.insert(
+            command_name,
+            LxMacroDefinition {
+                parameter_count,
+                optional_default,
+                body,
+            },
+        );
+        Ok(())
+    }
+
+    /// `\def\name#1#2{body}`, delimited parameters are not supported
+    fn define_by_def(&mut self) -> LxMacroResult<()> {
+        let command_name = self.next_raw_command_name()?;
+        let mut parameter_count = 0;
+        loop {
+            self.eat_raw_spaces();
+            if !self.chars.eat_char_if(|c| c == '#') {
+                break;
+            }
+            match self.chars.next() {
+                Some(c) if c.to_digit(10) == Some(parameter_count as u32 + 1) => {
+                    parameter_count += 1
+                }
+                _ => return Err(LxMacroError::UnsupportedDefParameterText),
+            }
+        }
+        let body = self.next_raw_argument()?;
+        substitute_macro_arguments(body, &[""; 9][..parameter_count as usize])?;
+        let body = BaseCoword::from_ref(body, self.db());
+        self.macro_table.definitions.insert(
+            command_name,
+            LxMacroDefinition {
+                parameter_count,
+                optional_default: None,
+                body,
+            },
+        );
+        Ok(())
+    }
+
+    /// `\DeclareMathOperator{\name}{text}`, expanding to `\operatorname{text}`
+    fn define_by_declare_math_operator(&mut self) -> LxMacroResult<()> {
+        // limits placement doesn't matter to us, so `*` is ignored
+        self.eat_raw_star();
+        let command_name = self.next_raw_command_name()?;
+        let text = self.next_raw_argument()?;
+        let body = format!("\\operatorname{{{text}}}");
+        substitute_macro_arguments(&body, &[])?;
+        let body = BaseCoword::from_ref(&body, self.db());
+        self.macro_table.definitions.insert(
+            command_name,
+            LxMacroDefinition {
+                parameter_count: 0,
+                optional_default: None,
+                body,
+            },
+        );
+        Ok(())
+    }
+
+    fn expand_macro(
+        &mut self,
+        definition: LxMacroDefinition,
+        outer_ranges: Option<(TextOffsetRange, TextPositionRange)>,
+        offset_range: TextOffsetRange,
+        range: TextPositionRange,
+    ) -> LxMacroResult<()> {
+        if self.expansions.len() >= MAX_MACRO_EXPANSION_DEPTH {
+            return Err(LxMacroError::ExpansionTooDeep);
+        }
+        let mut arguments: Vec<&'a str> = vec![];
+        for i in 0..definition.parameter_count {
+            let argument = match (i, definition.optional_default) {
+                (0, Some(default)) => self.next_raw_optional_argument()?.unwrap_or(default.data()),
+                _ => self.next_raw_argument()?,
+            };
+            arguments.push(argument);
+        }
+        let expansion = substitute_macro_arguments(definition.body.data(), &arguments)?;
+        let expansion = BaseCoword::from_ref(&expansion, self.db()).data();
+        let (offset_range, range) = self.macro_command_ranges(outer_ranges, offset_range, range);
+        let outer_chars = std::mem::replace(&mut self.chars, TextCharIter::new(expansion));
+        self.expansions.push(LxMacroExpansion {
+            outer_chars,
+            offset_range,
+            range,
+        });
+        Ok(())
+    }
+}
+
+/// # raw reading
+///
+/// definitions and arguments are read as raw text, not as tokens
+impl<'a> LxLexer<'a> {
+    fn eat_raw_spaces(&mut self) {
+        loop {
+            self.eat_spaces_and_tabs_and_lines_and_comments();
+            if !self.leave_exhausted_expansion() {
+                break;
+            }
+        }
+    }
+
+    /// `rest` must be a suffix of the remaining str
+    fn eat_raw_until(&mut self, rest: &str) {
+        while self.chars.remaining_str().len() > rest.len() {
+            self.chars.eat_char();
+        }
+    }
+
+    fn eat_raw_star(&mut self) {
+        self.eat_raw_spaces();
+        self.chars.eat_char_if(|c| c == '*');
+    }
+
+    /// `\name` or `{\name}`
+    fn next_raw_command_name(&mut self) -> LxMacroResult<LxCommandName> {
+        self.eat_raw_spaces();
+        let braced = self.chars.eat_char_if(|c| c == '{');
+        if braced {
+            self.eat_raw_spaces();
+        }
+        if !self.chars.eat_char_if(|c| c == '\\') {
+            return Err(LxMacroError::ExpectedCommandName);
+        }
+        let name = match self.chars.peek() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                self.chars.next_str_slice_while(|c| c.is_ascii_alphabetic())
+            }
+            Some(c) => {
+                let remaining = self.chars.remaining_str();
+                self.chars.eat_char();
+                &remaining[..c.len_utf8()]
+            }
+            None => return Err(LxMacroError::ExpectedCommandName),
+        };
+        let Ok(command_name) = LxCommandName::new2(name, self.db()) else {
+            return Err(LxMacroError::InvalidCommandName);
+        };
+        if braced {
+            self.eat_raw_spaces();
+            if !self.chars.eat_char_if(|c| c == '}') {
+                return Err(LxMacroError::ExpectedRcurl);
+            }
+        }
+        Ok(command_name)
+    }
+
+    /// an undelimited argument, i.e., a balanced group without its braces or a single token
+    fn next_raw_argument(&mut self) -> LxMacroResult<&'a str> {
+        self.eat_raw_spaces();
+        let remaining = self.chars.remaining_str();
+        match self.chars.peek() {
+            Some('{') => {
+                self.chars.eat_char();
+                let inner = self.chars.remaining_str();
+                let len = raw_balanced_len(inner, '}');
+                let Some(rest) = inner[len..].strip_prefix('}') else {
+                    return Err(LxMacroError::UnbalancedBraces);
+                };
+                self.eat_raw_until(rest);
+                Ok(&inner[..len])
+            }
+            Some('\\') => {
+                self.chars.eat_char();
+                match self.chars.peek() {
+                    Some(c) if c.is_ascii_alphabetic() => {
+                        self.chars.eat_chars_while(|c| c.is_ascii_alphabetic())
+                    }
+                    Some(_) => self.chars.eat_char(),
+                    None => return Err(LxMacroError::ExpectedCommandName),
+                }
+                let len = remaining.len() - self.chars.remaining_str().len();
+                Ok(&remaining[..len])
+            }
+            // a closing brace ends the group the macro is used in
+            None | Some('}') => Err(LxMacroError::MissingArgument),
+            Some(c) => {
+                self.chars.eat_char();
+                Ok(&remaining[..c.len_utf8()])
+            }
+        }
+    }
+
+    /// `[...]`, if any
+    fn next_raw_optional_argument(&mut self) -> LxMacroResult<Option<&'a str>> {
+        self.eat_raw_spaces();
+        if self.chars.peek() != Some('[') {
+            return Ok(None);
+        }
+        self.chars.eat_char();
+        let inner = self.chars.remaining_str();
+        let len = raw_balanced_len(inner, ']');
+        let Some(rest) = inner[len..].strip_prefix(']') else {
+            return Err(LxMacroError::UnclosedLbox);
+        };
+        self.eat_raw_until(rest);
+        Ok(Some(&inner[..len]))
+    }
+}
+
+/// the length of the longest prefix with balanced braces not containing `end` at depth zero
+fn raw_balanced_len(s: &str, end: char) -> usize {
+    let mut depth = 0usize;
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            c if c == end && depth == 0 => return i,
+            _ => (),
+        }
+    }
+    s.len()
+}
+
+/// replaces `#1` to `#9` with arguments and `##` with `#`
+fn substitute_macro_arguments(body: &str, arguments: &[&str]) -> LxMacroResult<String> {
+    let mut expansion = String::with_capacity(body.len());
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c != '#' {
+            expansion.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('#') => expansion.push('#'),
+            Some(d)
+                if let Some(argument) = d
+                    .to_digit(10)
+                    .and_then(|d| arguments.get((d as usize).wrapping_sub(1))) =>
+            {
+                expansion.push_str(argument)
+            }
+            _ => return Err(LxMacroError::InvalidParameterInBody),
+        }
+    }
+    Ok(expansion)
+}
@@ -0,0 +1,152 @@
+use super::*;
+use crate::{lane::LxTokenLane, storage::LxTokenStorage, *};
+use eterned::db::EternerDb;
+
+fn t(input: &str, expected: &Expect) {
+    let db = &EternerDb::default();
+    let mut storage = LxTokenStorage::default();
+    let tokens: Vec<_> = LxLexer::new(db, input, LxTokenLane::Main, &mut storage)
+        .into_math_stream()
+        .collect();
+    let show: String = tokens
+        .into_iter()
+        .map(|(idx, token_data)| {
+            format!(
+                "{} {:?}\n",
+                &input[storage.token_offset_range(*idx)],
+                token_data
+            )
+        })
+        .collect();
+    expected.assert_eq(&show);
+}
+
+#[test]
+fn macro_expansion_works() {
+    t(
+        r"\newcommand{\R}{\mathbb{R}} x\in\R",
+        &expect![[r#"
+            x Letter(LowerLatin(X))
+            \in Command(LettersOnly(LettersOnlyLxCommandName(Coword("in"))))
+            \R Command(LettersOnly(LettersOnlyLxCommandName(Coword("mathbb"))))
+            \R LeftDelimiter(Curl)
+            \R Letter(UpperLatin(R))
+            \R RightDelimiter(Curl)
+        "#]],
+    );
+    t(
+        r"\newcommand\abs[1]{|#1|} \abs{x+1}",
+        &expect![[r#"
+            \abs{x+1} Other('|')
+            \abs{x+1} Letter(LowerLatin(X))
+            \abs{x+1} Punctuation(Add)
+            \abs{x+1} Digit(One)
+            \abs{x+1} Other('|')
+        "#]],
+    );
+    t(
+        r"\newcommand{\seq}[2][n]{#2_{#1}} \seq{a}=\seq[k]{b}",
+        &expect![[r#"
+            \seq{a} Letter(LowerLatin(A))
+            \seq{a} Subscript
+            \seq{a} LeftDelimiter(Curl)
+            \seq{a} Letter(LowerLatin(N))
+            \seq{a} RightDelimiter(Curl)
+            = Punctuation(Equals)
+            \seq[k]{b} Letter(LowerLatin(B))
+            \seq[k]{b} Subscript
+            \seq[k]{b} LeftDelimiter(Curl)
+            \seq[k]{b} Letter(LowerLatin(K))
+            \seq[k]{b} RightDelimiter(Curl)
+        "#]],
+    );
+    t(
+        r"\def\sq#1{#1^2} \sq x",
+        &expect![[r#"
+            \sq x Letter(LowerLatin(X))
+            \sq x Superscript
+            \sq x Digit(Two)
+        "#]],
+    );
+    t(
+        r"\DeclareMathOperator{\lcm}{lcm} \lcm",
+        &expect![[r#"
+            \lcm Command(LettersOnly(LettersOnlyLxCommandName(Coword("operatorname"))))
+            \lcm LeftDelimiter(Curl)
+            \lcm Letter(LowerLatin(L))
+            \lcm Letter(LowerLatin(C))
+            \lcm Letter(LowerLatin(M))
+            \lcm RightDelimiter(Curl)
+        "#]],
+    );
+    t(
+        r"\newcommand{\N}{\mathbb{N}} \newcommand{\Np}{\N^+} \renewcommand{\N}{\mathbb{Z}} \Np",
+        &expect![[r#"
+            \Np Command(LettersOnly(LettersOnlyLxCommandName(Coword("mathbb"))))
+            \Np LeftDelimiter(Curl)
+            \Np Letter(UpperLatin(Z))
+            \Np RightDelimiter(Curl)
+            \Np Superscript
+            \Np Punctuation(Add)
+        "#]],
+    );
+    // the argument of `\f` comes after the use of `\g`
+    t(
+        r"\newcommand{\g}{\f} \newcommand{\f}[1]{#1!} \g{x}",
+        &expect![[r#"
+            \g{x} Letter(LowerLatin(X))
+            \g{x} Other('!')
+        "#]],
+    );
+    t(
+        r"\providecommand{\R}{\mathbb{R}} \providecommand{\R}{\mathbb{C}} \R",
+        &expect![[r#"
+            \R Command(LettersOnly(LettersOnlyLxCommandName(Coword("mathbb"))))
+            \R LeftDelimiter(Curl)
+            \R Letter(UpperLatin(R))
+            \R RightDelimiter(Curl)
+        "#]],
+    );
+}
+
+#[test]
+fn malformed_macro_works() {
+    t(
+        r"\newcommand{\f}[1]{#1} x+\f",
+        &expect![[r#"
+            x Letter(LowerLatin(X))
+            + Punctuation(Add)
+            \f Error(Macro(MissingArgument))
+        "#]],
+    );
+    t(
+        r"\def\x{\x} \x",
+        &expect![[r#"
+            \x Error(Macro(ExpansionTooDeep))
+        "#]],
+    );
+    t(
+        r"\newcommand{\f}[10]{#1} \newcommand{\g}{#1} \def\h#2{#2} x",
+        &expect![[r#"
+            \newcommand{\f}[10] Error(Macro(InvalidParameterCount))
+            { LeftDelimiter(Curl)
+            # Other('#')
+            1 Digit(One)
+            } RightDelimiter(Curl)
+            \newcommand{\g}{#1} Error(Macro(InvalidParameterInBody))
+            \def\h#2 Error(Macro(UnsupportedDefParameterText))
+            { LeftDelimiter(Curl)
+            # Other('#')
+            2 Digit(Two)
+            } RightDelimiter(Curl)
+            x Letter(LowerLatin(X))
+        "#]],
+    );
+    t(
+        r"\newcommand{\f}[2]{#1+#2} \f{x",
+        &expect![[r#"
+            \f{ Error(Macro(UnbalancedBraces))
+            x Letter(LowerLatin(X))
+        "#]],
+    );
+}
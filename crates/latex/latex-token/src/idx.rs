@@ -314,6 +314,10 @@ impl LxTokenIdxRange {
         }
     }
 
+    pub fn contains(self, other: Self) -> bool {
+        self.lane == other.lane && self.start <= other.start && other.end <= self.end
+    }
+
     pub fn join(self, other: Self) -> Self {
         Self {
             lane: self.lane,
@@ -1,4 +1,5 @@
 use crate::{
+    expansion::{LxMacroExpansion, LxMacroTable},
     idx::{
         LxLispTokenIdx, LxMathTokenIdx, LxNameTokenIdx, LxRootTokenIdx, LxRoseTokenIdx,
         LxSpecTokenIdx,
@@ -29,6 +30,8 @@ pub struct LxLexer<'a> {
     pub(crate) chars: TextCharIter<'a>,
     lane: LxTokenLane,
     pub(crate) storage: &'a mut LxTokenStorage,
+    pub(crate) macro_table: LxMacroTable,
+    pub(crate) expansions: Vec<LxMacroExpansion<'a>>,
 }
 
 /// # constructor
@@ -45,6 +48,8 @@ impl<'a> LxLexer<'a> {
             chars: TextCharIter::new(input),
             lane,
             storage,
+            macro_table: Default::default(),
+            expansions: vec![],
         }
     }
 }
@@ -58,6 +63,10 @@ impl<'a> LxLexer<'a> {
     pub fn input(&self) -> &'a str {
         self.input
     }
+
+    pub fn macro_table(&self) -> &LxMacroTable {
+        &self.macro_table
+    }
 }
 
 /// # actions
@@ -106,6 +115,7 @@ impl<'a> LxLexer<'a> {
         range: TextPositionRange,
         token_data: LxRootTokenData,
     ) -> LxRootTokenIdx {
+        let (offset_range, range) = self.expansion_ranges().unwrap_or((offset_range, range));
         self.storage
             .alloc_root_token(self.lane, offset_range, range, token_data)
     }
@@ -116,6 +126,7 @@ impl<'a> LxLexer<'a> {
         range: TextPositionRange,
         token_data: LxRoseTokenData,
     ) -> LxRoseTokenIdx {
+        let (offset_range, range) = self.expansion_ranges().unwrap_or((offset_range, range));
         self.storage
             .alloc_rose_token(self.lane, offset_range, range, token_data)
     }
@@ -126,6 +137,7 @@ impl<'a> LxLexer<'a> {
         range: TextPositionRange,
         token_data: LxMathTokenData,
     ) -> LxMathTokenIdx {
+        let (offset_range, range) = self.expansion_ranges().unwrap_or((offset_range, range));
         self.storage
             .alloc_math_token(self.lane, offset_range, range, token_data)
     }
@@ -136,6 +148,7 @@ impl<'a> LxLexer<'a> {
         range: TextPositionRange,
         token_data: LxNameTokenData,
     ) -> LxNameTokenIdx {
+        let (offset_range, range) = self.expansion_ranges().unwrap_or((offset_range, range));
         self.storage
             .alloc_coword_token(self.lane, offset_range, range, token_data)
     }
@@ -146,6 +159,7 @@ impl<'a> LxLexer<'a> {
         range: TextPositionRange,
         token_data: LxSpecTokenData,
     ) -> LxSpecTokenIdx {
+        let (offset_range, range) = self.expansion_ranges().unwrap_or((offset_range, range));
         self.storage
             .alloc_spec_token(self.lane, offset_range, range, token_data)
     }
@@ -156,6 +170,7 @@ impl<'a> LxLexer<'a> {
         range: TextPositionRange,
         token_data: LxLispTokenData,
     ) -> LxLispTokenIdx {
+        let (offset_range, range) = self.expansion_ranges().unwrap_or((offset_range, range));
         self.storage
             .alloc_lisp_token(self.lane, offset_range, range, token_data)
     }
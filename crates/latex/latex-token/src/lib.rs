@@ -1,5 +1,6 @@
 #![feature(if_let_guard)]
 #![feature(step_trait)]
+pub mod expansion;
 pub mod idx;
 pub mod lane;
 pub mod lexer;
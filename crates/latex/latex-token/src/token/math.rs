@@ -2,7 +2,7 @@ pub mod digit;
 
 use self::digit::LxMathDigit;
 use super::*;
-use crate::idx::LxMathTokenIdx;
+use crate::{expansion::LxMacroError, idx::LxMathTokenIdx};
 use eterned::db::EternerDb;
 use husky_text_protocol::{offset::TextOffsetRange, range::TextPositionRange};
 use latex_command::path::{LxCommandName, LxCommandNameResult};
@@ -47,6 +47,7 @@ impl LxMathDelimiter {
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LxMathTokenError {
     UnexpectedNewParagraph,
+    Macro(LxMacroError),
 }
 
 impl<'a> LxLexer<'a> {
@@ -60,6 +61,19 @@ impl<'a> LxLexer<'a> {
 
     fn next_ranged_math_token_data(
         &mut self,
+    ) -> Option<(TextOffsetRange, TextPositionRange, LxMathTokenData)> {
+        self.next_ranged_expanded_token_data(
+            Self::next_ranged_math_token_data_unexpanded,
+            |token_data| match *token_data {
+                LxMathTokenData::Command(command_name) => Some(command_name),
+                _ => None,
+            },
+            |error| LxMathTokenData::Error(LxMathTokenError::Macro(error)),
+        )
+    }
+
+    fn next_ranged_math_token_data_unexpanded(
+        &mut self,
     ) -> Option<(TextOffsetRange, TextPositionRange, LxMathTokenData)> {
         self.eat_spaces_and_tabs();
         let mut start_offset = self.chars.current_offset();
@@ -85,9 +99,9 @@ impl<'a> LxLexer<'a> {
     }
 
     pub fn peek_math_token_data(&mut self) -> Option<LxMathTokenData> {
-        let chars = self.chars.clone();
+        let cursor = self.cursor();
         let (_, _, token_data) = self.next_ranged_math_token_data()?;
-        self.chars = chars;
+        self.restore_cursor(cursor);
         Some(token_data)
     }
     pub(crate) fn next_math_token_data(&mut self) -> Option<LxMathTokenData> {
@@ -1,5 +1,5 @@
 use super::*;
-use crate::idx::LxRootTokenIdx;
+use crate::{expansion::LxMacroError, idx::LxRootTokenIdx};
 use eterned::db::EternerDb;
 use husky_text_protocol::{offset::TextOffsetRange, range::TextPositionRange};
 use latex_command::path::LxCommandName;
@@ -9,6 +9,7 @@ pub enum LxRootTokenData {
     Command(LxCommandName),
     LeftDelimiter(LxRootDelimiter),
     RightDelimiter(LxRootDelimiter),
+    Error(LxRootTokenError),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -19,6 +20,11 @@ pub enum LxRootDelimiter {
     Box,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LxRootTokenError {
+    Macro(LxMacroError),
+}
+
 impl<'a> LxLexer<'a> {
     pub fn next_root_token(&mut self) -> Option<(LxRootTokenIdx, LxRootTokenData)> {
         let (offset_range, range, token_data) = self.next_ranged_root_token()?;
@@ -30,6 +36,19 @@ impl<'a> LxLexer<'a> {
 
     fn next_ranged_root_token(
         &mut self,
+    ) -> Option<(TextOffsetRange, TextPositionRange, LxRootTokenData)> {
+        self.next_ranged_expanded_token_data(
+            Self::next_ranged_root_token_unexpanded,
+            |token_data| match *token_data {
+                LxRootTokenData::Command(command_name) => Some(command_name),
+                _ => None,
+            },
+            |error| LxRootTokenData::Error(LxRootTokenError::Macro(error)),
+        )
+    }
+
+    fn next_ranged_root_token_unexpanded(
+        &mut self,
     ) -> Option<(TextOffsetRange, TextPositionRange, LxRootTokenData)> {
         self.eat_spaces_and_tabs_and_lines_and_comments();
         let mut start_offset = self.chars.current_offset();
@@ -44,9 +63,9 @@ impl<'a> LxLexer<'a> {
     }
 
     pub fn peek_root_token_data(&mut self) -> Option<LxRootTokenData> {
-        let chars = self.chars.clone();
+        let cursor = self.cursor();
         let (_, _, token_data) = self.next_ranged_root_token()?;
-        self.chars = chars;
+        self.restore_cursor(cursor);
         Some(token_data)
     }
     pub(crate) fn next_root_token_data(&mut self) -> Option<LxRootTokenData> {
@@ -1,5 +1,5 @@
 use super::*;
-use crate::idx::LxRoseTokenIdx;
+use crate::{expansion::LxMacroError, idx::LxRoseTokenIdx};
 use base_coword::BaseCoword;
 use husky_text_protocol::{offset::TextOffsetRange, range::TextPositionRange};
 use latex_command::path::LxCommandName;
@@ -15,6 +15,7 @@ pub enum LxRoseTokenData {
     LeftDelimiter(LxRoseDelimiter),
     RightDelimiter(LxRoseDelimiter),
     EmbeddedMathDelimiter(LxRoseEmbeddedMathDelimiter),
+    Error(LxRoseTokenError),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -22,6 +23,11 @@ pub enum LxRoseDelimiter {
     Curl,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LxRoseTokenError {
+    Macro(LxMacroError),
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LxRoseEmbeddedMathDelimiter {
     /// `$`
@@ -62,6 +68,19 @@ impl<'a> LxLexer<'a> {
 
     fn next_ranged_rose_token_data(
         &mut self,
+    ) -> Option<(TextOffsetRange, TextPositionRange, LxRoseTokenData)> {
+        self.next_ranged_expanded_token_data(
+            Self::next_ranged_rose_token_data_unexpanded,
+            |token_data| match *token_data {
+                LxRoseTokenData::Command(command_name) => Some(command_name),
+                _ => None,
+            },
+            |error| LxRoseTokenData::Error(LxRoseTokenError::Macro(error)),
+        )
+    }
+
+    fn next_ranged_rose_token_data_unexpanded(
+        &mut self,
     ) -> Option<(TextOffsetRange, TextPositionRange, LxRoseTokenData)> {
         self.eat_spaces_and_tabs_and_comments();
         let mut start_offset = self.chars.current_offset();
@@ -89,9 +108,9 @@ impl<'a> LxLexer<'a> {
     }
 
     pub fn peek_rose_token_data(&mut self) -> Option<LxRoseTokenData> {
-        let chars = self.chars.clone();
+        let cursor = self.cursor();
         let (_, _, token_data) = self.next_ranged_rose_token_data()?;
-        self.chars = chars;
+        self.restore_cursor(cursor);
         Some(token_data)
     }
 
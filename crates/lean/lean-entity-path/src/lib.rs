@@ -27,6 +27,20 @@ pub enum LnItemPath {
     Ge,
     Eq,
     RealSqrt,
+    Dvd,
+    Implies,
+    Iff,
+    RealLog,
+    RealExp,
+    NatGcd,
+    IntGcd,
+    Max,
+    Min,
+    NatChoose,
+    IntFloor,
+    Abs,
+    LimUnder,
+    Nhds,
     Prop,
 }
 
@@ -48,6 +62,20 @@ impl LnItemPath {
     pub const GE: Self = LnItemPath::Ge;
     pub const EQ: Self = LnItemPath::Eq;
     pub const REAL_SQRT: Self = LnItemPath::RealSqrt;
+    pub const DVD: Self = LnItemPath::Dvd;
+    pub const IMPLIES: Self = LnItemPath::Implies;
+    pub const IFF: Self = LnItemPath::Iff;
+    pub const REAL_LOG: Self = LnItemPath::RealLog;
+    pub const REAL_EXP: Self = LnItemPath::RealExp;
+    pub const NAT_GCD: Self = LnItemPath::NatGcd;
+    pub const INT_GCD: Self = LnItemPath::IntGcd;
+    pub const MAX: Self = LnItemPath::Max;
+    pub const MIN: Self = LnItemPath::Min;
+    pub const NAT_CHOOSE: Self = LnItemPath::NatChoose;
+    pub const INT_FLOOR: Self = LnItemPath::IntFloor;
+    pub const ABS: Self = LnItemPath::Abs;
+    pub const LIM_UNDER: Self = LnItemPath::LimUnder;
+    pub const NHDS: Self = LnItemPath::Nhds;
 }
 
 impl LnItemPath {
@@ -68,6 +96,20 @@ impl LnItemPath {
             LnItemPath::Ge => "≥".to_string(),
             LnItemPath::Eq => "=".to_string(),
             LnItemPath::RealSqrt => "√".to_string(),
+            LnItemPath::Dvd => "∣".to_string(),
+            LnItemPath::Implies => "→".to_string(),
+            LnItemPath::Iff => "↔".to_string(),
+            LnItemPath::RealLog => "Real.log".to_string(),
+            LnItemPath::RealExp => "Real.exp".to_string(),
+            LnItemPath::NatGcd => "Nat.gcd".to_string(),
+            LnItemPath::IntGcd => "Int.gcd".to_string(),
+            LnItemPath::Max => "max".to_string(),
+            LnItemPath::Min => "min".to_string(),
+            LnItemPath::NatChoose => "Nat.choose".to_string(),
+            LnItemPath::IntFloor => "Int.floor".to_string(),
+            LnItemPath::Abs => "abs".to_string(),
+            LnItemPath::LimUnder => "limUnder".to_string(),
+            LnItemPath::Nhds => "nhds".to_string(),
             LnItemPath::Prop => "Prop".to_string(),
         }
     }
@@ -17,6 +17,18 @@ pub struct LnItemPathMenu {
     pub ge: LnItemPath,
     pub eq: LnItemPath,
     pub real_sqrt: LnItemPath,
+    pub dvd: LnItemPath,
+    pub implies: LnItemPath,
+    pub iff: LnItemPath,
+    pub real_log: LnItemPath,
+    pub real_exp: LnItemPath,
+    pub nat_gcd: LnItemPath,
+    pub int_gcd: LnItemPath,
+    pub max: LnItemPath,
+    pub min: LnItemPath,
+    pub nat_choose: LnItemPath,
+    pub int_floor: LnItemPath,
+    pub abs: LnItemPath,
 }
 
 impl LnItemPathMenu {
@@ -36,6 +48,18 @@ impl LnItemPathMenu {
             ge: LnItemPath::GE,
             eq: LnItemPath::EQ,
             real_sqrt: LnItemPath::REAL_SQRT,
+            dvd: LnItemPath::DVD,
+            implies: LnItemPath::IMPLIES,
+            iff: LnItemPath::IFF,
+            real_log: LnItemPath::REAL_LOG,
+            real_exp: LnItemPath::REAL_EXP,
+            nat_gcd: LnItemPath::NAT_GCD,
+            int_gcd: LnItemPath::INT_GCD,
+            max: LnItemPath::MAX,
+            min: LnItemPath::MIN,
+            nat_choose: LnItemPath::NAT_CHOOSE,
+            int_floor: LnItemPath::INT_FLOOR,
+            abs: LnItemPath::ABS,
         }
     }
 }
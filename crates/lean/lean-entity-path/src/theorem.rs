@@ -1,6 +1,14 @@
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LnTheoremPath {
     SquareNonnegative,
+    PowNonnegative,
+    PowPositive,
+    SqrtNonnegative,
+    SqrtSquare,
+    MulNonnegative,
+    MulPositive,
+    DivNonnegative,
+    DivPositive,
 }
 
 impl LnTheoremPath {
@@ -11,6 +19,14 @@ impl LnTheoremPath {
     pub fn code(&self) -> &str {
         match self {
             Self::SquareNonnegative => "sq_nonneg",
+            Self::PowNonnegative => "pow_nonneg",
+            Self::PowPositive => "pow_pos",
+            Self::SqrtNonnegative => "Real.sqrt_nonneg",
+            Self::SqrtSquare => "Real.sqrt_sq",
+            Self::MulNonnegative => "mul_nonneg",
+            Self::MulPositive => "mul_pos",
+            Self::DivNonnegative => "div_nonneg",
+            Self::DivPositive => "div_pos",
         }
     }
 }
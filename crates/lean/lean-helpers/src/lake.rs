@@ -0,0 +1,53 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+pub struct LeanElaborationOutcome {
+    pub success: bool,
+    /// combined stdout and stderr of `lean`, containing the messages
+    pub output: String,
+}
+
+/// Elaborates `file` with `lake env lean` in the Lake project containing it.
+pub fn lake_env_lean(file: &Path) -> std::io::Result<LeanElaborationOutcome> {
+    let file = file.canonicalize()?;
+    let Some(project_dir) = lake_project_dir(&file) else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("no Lake project contains `{}`", file.display()),
+        ));
+    };
+    let output = Command::new("lake")
+        .args(["env", "lean"])
+        .arg(&file)
+        .current_dir(project_dir)
+        .output()?;
+    let mut messages = String::from_utf8_lossy(&output.stdout).into_owned();
+    messages += &String::from_utf8_lossy(&output.stderr);
+    Ok(LeanElaborationOutcome {
+        success: output.status.success(),
+        output: messages,
+    })
+}
+
+/// the nearest ancestor with a lakefile
+pub fn lake_project_dir(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|dir| dir.join("lakefile.toml").is_file() || dir.join("lakefile.lean").is_file())
+        .map(Path::to_path_buf)
+}
+
+#[test]
+fn lake_project_dir_works() {
+    let dir = tempfile::tempdir().unwrap();
+    let project_dir = dir.path().join("project");
+    let src_dir = project_dir.join("Project");
+    std::fs::create_dir_all(&src_dir).unwrap();
+    assert_eq!(lake_project_dir(&src_dir.join("Basic.lean")), None);
+    std::fs::write(project_dir.join("lakefile.toml"), "name = \"project\"\n").unwrap();
+    assert_eq!(
+        lake_project_dir(&src_dir.join("Basic.lean")),
+        Some(project_dir)
+    );
+}
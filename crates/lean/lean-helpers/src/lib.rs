@@ -1 +1,2 @@
+pub mod lake;
 pub mod obvious;
@@ -24,6 +24,12 @@ pub enum LnMirExprData {
         parameters: LnMirLambdaParameters,
         body: LnMirExprIdx,
     },
+    /// `∀ x : α, P` or `∃ x : α, P`
+    Quantified {
+        quantifier: LnMirQuantifier,
+        parameter: LnMirLambdaParameter,
+        body: LnMirExprIdx,
+    },
     Application {
         function: LnMirFunc,
         arguments: LnMirExprIdxRange,
@@ -73,7 +79,8 @@ impl LnMirExprData {
             // LnMirExprData::Prefix { opr, opd } => todo!(),
             // LnMirExprData::Suffix { opd, opr } => todo!(),
             // LnMirExprData::Binary { lopd, opr, ropd } => opr.outer_precedence(),
-            LnMirExprData::Lambda { parameters, body } => todo!(),
+            // both extend as far to the right as possible
+            LnMirExprData::Lambda { .. } | LnMirExprData::Quantified { .. } => LnPrecedence::Min,
             LnMirExprData::Application {
                 function,
                 arguments,
@@ -91,7 +98,16 @@ impl LnMirExprData {
             LnMirExprData::Lambda {
                 ref parameters,
                 body,
-            } => todo!(),
+            } => parameters
+                .iter()
+                .map(|parameter| parameter.ty)
+                .chain([body])
+                .collect(),
+            LnMirExprData::Quantified {
+                ref parameter,
+                body,
+                ..
+            } => vec![parameter.ty, body],
             LnMirExprData::Application {
                 function,
                 arguments,
@@ -108,6 +124,10 @@ pub struct LnMirLambdaParameter {
 }
 
 impl LnMirLambdaParameter {
+    pub fn new(ident: LnIdent, ty: LnMirExprIdx) -> Self {
+        Self { ident, ty }
+    }
+
     pub fn ident(&self) -> LnIdent {
         self.ident
     }
@@ -118,3 +138,18 @@ impl LnMirLambdaParameter {
 }
 
 pub type LnMirLambdaParameters = SmallVec<[LnMirLambdaParameter; 4]>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LnMirQuantifier {
+    Forall,
+    Exists,
+}
+
+impl LnMirQuantifier {
+    pub fn fmt_str(self) -> &'static str {
+        match self {
+            LnMirQuantifier::Forall => "∀",
+            LnMirQuantifier::Exists => "∃",
+        }
+    }
+}
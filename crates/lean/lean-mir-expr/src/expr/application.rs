@@ -149,7 +149,28 @@ pub struct LnMirFuncKeyMenu {
     pub int_ge: LnMirFuncKey,
     pub rat_ge: LnMirFuncKey,
     pub real_ge: LnMirFuncKey,
+    pub nat_dvd: LnMirFuncKey,
+    pub int_dvd: LnMirFuncKey,
+    pub prop_implies: LnMirFuncKey,
+    pub prop_iff: LnMirFuncKey,
     pub real_sqrt: LnMirFuncKey,
+    pub real_log: LnMirFuncKey,
+    pub real_exp: LnMirFuncKey,
+    pub nat_gcd: LnMirFuncKey,
+    pub int_gcd: LnMirFuncKey,
+    pub nat_max: LnMirFuncKey,
+    pub int_max: LnMirFuncKey,
+    pub rat_max: LnMirFuncKey,
+    pub real_max: LnMirFuncKey,
+    pub nat_min: LnMirFuncKey,
+    pub int_min: LnMirFuncKey,
+    pub rat_min: LnMirFuncKey,
+    pub real_min: LnMirFuncKey,
+    pub nat_binom: LnMirFuncKey,
+    pub real_floor: LnMirFuncKey,
+    pub int_abs: LnMirFuncKey,
+    pub rat_abs: LnMirFuncKey,
+    pub real_abs: LnMirFuncKey,
 }
 
 impl LnMirFuncKeyMenu {
@@ -157,7 +178,19 @@ impl LnMirFuncKeyMenu {
         use LnBinaryOpr::*;
         use LnPrefixOpr::*;
 
-        let LnItemPathMenu { real_sqrt, .. } = *ln_item_path_menu;
+        let LnItemPathMenu {
+            real_sqrt,
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            max,
+            min,
+            nat_choose,
+            int_floor,
+            abs,
+            ..
+        } = *ln_item_path_menu;
         let LnInstantiationMenu {
             int_pos,
             rat_pos,
@@ -215,6 +248,10 @@ impl LnMirFuncKeyMenu {
             int_ge,
             rat_ge,
             real_ge,
+            nat_dvd,
+            int_dvd,
+            prop_implies,
+            prop_iff,
         } = *ln_instantiation_menu(db);
         let i = |instantiation| LnMirFuncKey::ItemPath(instantiation);
         let p = |opr, instantiation| LnMirFuncKey::PrefixOpr { opr, instantiation };
@@ -276,7 +313,28 @@ impl LnMirFuncKeyMenu {
             int_ge: b(Ge, int_ge),
             rat_ge: b(Ge, rat_ge),
             real_ge: b(Ge, real_ge),
+            nat_dvd: b(Dvd, nat_dvd),
+            int_dvd: b(Dvd, int_dvd),
+            prop_implies: b(Implies, prop_implies),
+            prop_iff: b(Iff, prop_iff),
             real_sqrt: i(real_sqrt),
+            real_log: i(real_log),
+            real_exp: i(real_exp),
+            nat_gcd: i(nat_gcd),
+            int_gcd: i(int_gcd),
+            nat_max: i(max),
+            int_max: i(max),
+            rat_max: i(max),
+            real_max: i(max),
+            nat_min: i(min),
+            int_min: i(min),
+            rat_min: i(min),
+            real_min: i(min),
+            nat_binom: i(nat_choose),
+            real_floor: i(int_floor),
+            int_abs: i(abs),
+            rat_abs: i(abs),
+            real_abs: i(abs),
         }
     }
 }
@@ -15,6 +15,22 @@ pub fn deep_expr_eq(a: LnMirExprIdx, b: LnMirExprIdx, arena: LnMirExprArenaRef)
                 body: b_body,
             },
         ) => a_params == b_params && deep_expr_eq(a_body, b_body, arena),
+        (
+            &LnMirExprData::Quantified {
+                quantifier: a_quantifier,
+                parameter: ref a_param,
+                body: a_body,
+            },
+            &LnMirExprData::Quantified {
+                quantifier: b_quantifier,
+                parameter: ref b_param,
+                body: b_body,
+            },
+        ) => {
+            a_quantifier == b_quantifier
+                && a_param == b_param
+                && deep_expr_eq(a_body, b_body, arena)
+        }
         (
             LnMirExprData::Application {
                 function: a_fn,
@@ -123,7 +123,7 @@ impl<'a> LnMirExprFormatter<'a> {
                 ref parameters,
                 body,
             } => {
-                self.result.push('λ');
+                self.result += "λ ";
                 for (i, param) in parameters.iter().enumerate() {
                     if i > 0 {
                         self.result.push(' ');
@@ -139,6 +139,19 @@ impl<'a> LnMirExprFormatter<'a> {
                 }
                 self.format_expr(body, multiline, LnPrecedenceRange::Any);
             }
+            LnMirExprData::Quantified {
+                quantifier,
+                ref parameter,
+                body,
+            } => {
+                self.result += quantifier.fmt_str();
+                self.result.push(' ');
+                self.result += parameter.ident().data();
+                self.result.push_str(" : ");
+                self.format_expr(parameter.ty(), false, LnPrecedenceRange::Any);
+                self.result += ", ";
+                self.format_expr(body, subexpr_try_multiline, LnPrecedenceRange::Any);
+            }
             LnMirExprData::Application {
                 function,
                 arguments,
@@ -54,6 +54,9 @@ impl<'a> LnMirExprDisplayTreeBuilder<'a> {
                 ref parameters,
                 body,
             } => format!("lambda"),
+            LnMirExprData::Quantified { quantifier, .. } => {
+                format!("quantified: `{}`", quantifier.fmt_str())
+            }
             LnMirExprData::Application {
                 function,
                 arguments,
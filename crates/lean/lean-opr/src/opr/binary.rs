@@ -13,6 +13,9 @@ pub enum LnBinaryOpr {
     Gt,
     Le,
     Ge,
+    Dvd,
+    Implies,
+    Iff,
 }
 
 impl LnBinaryOpr {
@@ -29,6 +32,9 @@ impl LnBinaryOpr {
             LnBinaryOpr::Gt => " > ",
             LnBinaryOpr::Le => " ≤ ",
             LnBinaryOpr::Ge => " ≥ ",
+            LnBinaryOpr::Dvd => " ∣ ",
+            LnBinaryOpr::Implies => " → ",
+            LnBinaryOpr::Iff => " ↔ ",
         }
     }
 
@@ -45,6 +51,9 @@ impl LnBinaryOpr {
             LnBinaryOpr::Gt => LnPrecedenceRange::NoLess(LnPrecedence::Relation),
             LnBinaryOpr::Le => LnPrecedenceRange::NoLess(LnPrecedence::Relation),
             LnBinaryOpr::Ge => LnPrecedenceRange::NoLess(LnPrecedence::Relation),
+            LnBinaryOpr::Dvd => LnPrecedenceRange::NoLess(LnPrecedence::Relation),
+            LnBinaryOpr::Implies => LnPrecedenceRange::Greater(LnPrecedence::Logic),
+            LnBinaryOpr::Iff => LnPrecedenceRange::Greater(LnPrecedence::Logic),
         }
     }
 
@@ -61,6 +70,10 @@ impl LnBinaryOpr {
             LnBinaryOpr::Gt => LnPrecedenceRange::Greater(LnPrecedence::Relation),
             LnBinaryOpr::Le => LnPrecedenceRange::Greater(LnPrecedence::Relation),
             LnBinaryOpr::Ge => LnPrecedenceRange::Greater(LnPrecedence::Relation),
+            LnBinaryOpr::Dvd => LnPrecedenceRange::Greater(LnPrecedence::Relation),
+            // `→` is right associative
+            LnBinaryOpr::Implies => LnPrecedenceRange::NoLess(LnPrecedence::Logic),
+            LnBinaryOpr::Iff => LnPrecedenceRange::Greater(LnPrecedence::Logic),
         }
     }
 
@@ -77,6 +90,9 @@ impl LnBinaryOpr {
             LnBinaryOpr::Gt => LnPrecedence::Relation,
             LnBinaryOpr::Le => LnPrecedence::Relation,
             LnBinaryOpr::Ge => LnPrecedence::Relation,
+            LnBinaryOpr::Dvd => LnPrecedence::Relation,
+            LnBinaryOpr::Implies => LnPrecedence::Logic,
+            LnBinaryOpr::Iff => LnPrecedence::Logic,
         }
     }
 }
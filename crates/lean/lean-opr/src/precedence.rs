@@ -2,6 +2,7 @@
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum LnPrecedence {
     Min,
+    Logic,
     Relation,
     AddSub,
     MulDiv,
@@ -63,6 +63,10 @@ pub struct LnInstantiationMenu {
     pub int_ge: LnInstantiation,
     pub rat_ge: LnInstantiation,
     pub real_ge: LnInstantiation,
+    pub nat_dvd: LnInstantiation,
+    pub int_dvd: LnInstantiation,
+    pub prop_implies: LnInstantiation,
+    pub prop_iff: LnInstantiation,
 }
 
 impl LnInstantiationMenu {
@@ -76,6 +80,9 @@ impl LnInstantiationMenu {
             eq,
             le,
             ge,
+            dvd,
+            implies,
+            iff,
             ..
         } = *ln_item_path_menu;
         let LnTermMenu {
@@ -144,6 +151,10 @@ impl LnInstantiationMenu {
             int_ge: t(ge, smallvec![int]),
             rat_ge: t(ge, smallvec![rat]),
             real_ge: t(ge, smallvec![real]),
+            nat_dvd: t(dvd, smallvec![nat]),
+            int_dvd: t(dvd, smallvec![int]),
+            prop_implies: t(implies, smallvec![]),
+            prop_iff: t(iff, smallvec![]),
         }
     }
 }
@@ -29,6 +29,18 @@ impl LnTermMenu {
             ge,
             eq,
             real_sqrt,
+            dvd,
+            implies,
+            iff,
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            max,
+            min,
+            nat_choose,
+            int_floor,
+            abs,
         } = *ln_item_path_menu;
         Self {
             nat: LnTerm::new_item_path(nat),
@@ -18,3 +18,12 @@ pub(crate) fn lex_tracked<'a>(db: &::salsa::Db, input: &str) -> RangedTokenSheet
     tokenizer.push_tokens(pretoken_iter);
     tokenizer.finish()
 }
+
+/// lexes without creating a token sheet, so it can be used outside tracked context,
+/// e.g. for checking that a reformatted text still lexes to the same tokens
+pub fn lex_untracked(db: &::salsa::Db, input: &str) -> UntrackedTokenSheet {
+    let pretoken_iter = PretokenStream::new(db, TextCharIter::new(input));
+    let mut tokenizer = Tokenizer::new(db);
+    tokenizer.push_tokens(pretoken_iter);
+    tokenizer.finish_untracked()
+}
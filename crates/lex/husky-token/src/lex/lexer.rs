@@ -33,6 +33,10 @@ impl<'token> Tokenizer<'token> {
         RangedTokenSheet::new(self.db, self.token_datas, self.token_ranges, self.comments)
     }
 
+    pub fn finish_untracked(self) -> UntrackedTokenSheet {
+        UntrackedTokenSheet::new(self.token_datas, self.token_ranges, self.comments)
+    }
+
     pub(crate) fn push_tokens(&mut self, pretoken_iter: impl Iterator<Item = RangedPretoken>) {
         for pretoken in pretoken_iter {
             match self.resolve_token(pretoken) {
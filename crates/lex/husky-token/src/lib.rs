@@ -18,6 +18,7 @@ pub mod verse;
 
 pub use self::helpers::*;
 pub use self::jar::*;
+pub use self::lex::lex_untracked;
 pub use self::sheet::*;
 pub use self::stream::*;
 pub use self::token_idx::*;
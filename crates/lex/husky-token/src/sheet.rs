@@ -137,6 +137,50 @@ impl RangedTokenSheet {
     }
 }
 
+/// tokens lexed outside of tracked context, without an underlying `TokenSheet`
+#[derive(Debug, PartialEq, Eq)]
+pub struct UntrackedTokenSheet {
+    tokens: Vec<TokenData>,
+    token_ranges: Vec<TextPositionRange>,
+    token_verses: TokenVerses,
+    comments: Vec<Comment>,
+}
+
+impl UntrackedTokenSheet {
+    pub(crate) fn new(
+        tokens: Vec<TokenData>,
+        token_ranges: Vec<TextPositionRange>,
+        comments: Vec<Comment>,
+    ) -> Self {
+        let token_verses = TokenVerses::new(&tokens, &token_ranges);
+        Self {
+            tokens,
+            token_ranges,
+            token_verses,
+            comments,
+        }
+    }
+}
+
+/// # getters
+impl UntrackedTokenSheet {
+    pub fn tokens(&self) -> &[TokenData] {
+        &self.tokens
+    }
+
+    pub fn token_text_ranges(&self) -> &[TextPositionRange] {
+        &self.token_ranges
+    }
+
+    pub fn token_verses(&self) -> &TokenVerses {
+        &self.token_verses
+    }
+
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+}
+
 #[salsa::tracked(jar = TokenJar, return_ref)]
 pub(crate) fn ranged_token_sheet(db: &::salsa::Db, module_path: ModulePath) -> RangedTokenSheet {
     crate::lex::lex_tracked(db, module_path.raw_text(db))
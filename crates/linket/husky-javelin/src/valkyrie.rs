@@ -232,7 +232,8 @@ impl<'db> ValkyrieRidesBuilder<'db> {
                 | HirEagerExprData::Todo
                 | HirEagerExprData::Unreachable
                 | HirEagerExprData::Unwrap { .. }
-                | HirEagerExprData::Closure { .. } => (),
+                | HirEagerExprData::Closure { .. }
+                | HirEagerExprData::ClosureCall { .. } => (),
             }
         }
         for _data in hir_eager_expr_region.stmt_arena(db) {
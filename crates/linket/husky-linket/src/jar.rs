@@ -5,6 +5,7 @@ pub struct LinketJar(
     crate::linket::package_linkets,
     crate::linket::target_linkets,
     crate::linket::target_linket_item_path_id_interfaces,
+    crate::linket::package_linket_item_path_id_interfaces,
     crate::template_argument::ty::LinTypePathLeading,
     crate::template_argument::ty::LinRitchieType,
     crate::version_stamp::LinketVersionStamp,
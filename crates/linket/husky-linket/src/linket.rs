@@ -628,6 +628,22 @@ pub fn target_linket_item_path_id_interfaces(
         .collect()
 }
 
+/// aligned with [`package_linkets`]
+#[salsa::tracked(return_ref)]
+pub fn package_linket_item_path_id_interfaces(
+    db: &::salsa::Db,
+    package_path: PackagePath,
+) -> Vec<Option<ItemPathIdInterface>> {
+    package_linkets(db, package_path)
+        .iter()
+        .map(|linket| {
+            linket
+                .path_and_instantiation_for_definition(db)
+                .map(|(path, _)| (*path).into())
+        })
+        .collect()
+}
+
 #[test]
 fn package_linkets_works() {
     DB::ast_rich_test_debug_with_db(
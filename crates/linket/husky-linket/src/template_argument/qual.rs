@@ -79,7 +79,7 @@ impl LinQual {
                 None => LinQual::Ref,
             },
             HirQuary::Transient => todo!(),
-            HirQuary::RefMut { place, lifetime } => todo!(),
+            HirQuary::RefMut { .. } => LinQual::Mut,
             HirQuary::Leashed { place_idx } => LinQual::Ref,
             HirQuary::Todo => todo!(),
             HirQuary::Variable(_) => todo!(),
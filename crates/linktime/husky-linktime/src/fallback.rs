@@ -0,0 +1,115 @@
+use husky_entity_path::path::ItemPath;
+use husky_linket::linket::Linket;
+use husky_vfs::path::package_path::PackagePath;
+
+/// which linkets are interpreted from their VMIR instead of run compiled, and why
+#[salsa::derive_debug_with_db]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LinktimeFallbackReport {
+    fallbacks: Vec<LinktimeFallback>,
+}
+
+/// a linket that is interpreted
+#[salsa::derive_debug_with_db]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinktimeFallback {
+    package_path: PackagePath,
+    linket: Linket,
+    reason: LinktimeFallbackReason,
+}
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinktimeFallbackReason {
+    /// the item uses constructs not supported by the transpilation yet,
+    /// or the package failed to be written
+    TranspilationFailed { message: String },
+    /// the compiled item would call an interpreted one
+    DependsOnInterpreted { item_path: ItemPath },
+    CompilationFailed { message: String },
+}
+
+impl LinktimeFallbackReport {
+    pub fn new(fallbacks: Vec<LinktimeFallback>) -> Self {
+        Self { fallbacks }
+    }
+
+    pub fn fallbacks(&self) -> &[LinktimeFallback] {
+        &self.fallbacks
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fallbacks.is_empty()
+    }
+
+    pub fn interpreted_linkets(
+        &self,
+    ) -> impl Iterator<Item = (Linket, &LinktimeFallbackReason)> + '_ {
+        self.fallbacks
+            .iter()
+            .map(|fallback| (fallback.linket, &fallback.reason))
+    }
+
+    /// one line for each interpreted item
+    pub fn message(&self, db: &::salsa::Db) -> String {
+        self.fallbacks
+            .iter()
+            .map(|fallback| fallback.message(db))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl LinktimeFallback {
+    pub fn new(package_path: PackagePath, linket: Linket, reason: LinktimeFallbackReason) -> Self {
+        Self {
+            package_path,
+            linket,
+            reason,
+        }
+    }
+
+    pub fn package_path(&self) -> PackagePath {
+        self.package_path
+    }
+
+    pub fn linket(&self) -> Linket {
+        self.linket
+    }
+
+    pub fn reason(&self) -> &LinktimeFallbackReason {
+        &self.reason
+    }
+
+    pub fn message(&self, db: &::salsa::Db) -> String {
+        use salsa::{DebugWithDb, DisplayWithDb};
+
+        let item = match self.linket.path_and_instantiation_for_definition(db) {
+            Some((item_path, _)) => format!("{}", item_path.display(db)),
+            None => format!("{:?}", self.linket.debug(db)),
+        };
+        format!(
+            "`{item}` of `{}` is interpreted, {}",
+            self.package_path.name_str(db),
+            self.reason.message(db)
+        )
+    }
+}
+
+impl LinktimeFallbackReason {
+    pub fn message(&self, db: &::salsa::Db) -> String {
+        use salsa::DisplayWithDb;
+
+        match self {
+            LinktimeFallbackReason::TranspilationFailed { message } => {
+                format!("failed to transpile: {message}")
+            }
+            LinktimeFallbackReason::DependsOnInterpreted { item_path } => {
+                format!("depends on `{}`, which is interpreted", item_path.display(db))
+            }
+            LinktimeFallbackReason::CompilationFailed { message } => {
+                format!("failed to compile: {message}")
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
+pub mod fallback;
 pub mod helpers;
 
+use self::fallback::LinktimeFallbackReport;
 use husky_linket::linket::Linket;
 use husky_linket_impl::dev_eval_context::IsDevRuntimeInterfaceDyn;
 use husky_linket_impl::linket_impl::IsLinketImpl;
@@ -11,4 +13,16 @@ pub trait IsLinktime: Sized + Send {
     fn linket_impl(&self, linket: Linket, db: &::salsa::Db) -> Self::LinketImpl;
     fn new(target_path: LinktimeTargetPath, db: &::salsa::Db) -> Self;
     fn init(&self, runtime: &'static dyn IsDevRuntimeInterfaceDyn<Self::LinketImpl>);
+    /// Unloads the code replaced by hot reloads.
+    ///
+    /// The caller guarantees that no value computed by the replaced code is alive.
+    unsafe fn release_retired_libraries(&self) {}
+    /// `true` if the linket failed to transpile or compile, so that it has no linket impl
+    /// and is to be interpreted from its VMIR instead.
+    fn is_linket_interpreted(&self, _linket: Linket, _db: &::salsa::Db) -> bool {
+        false
+    }
+    fn fallback_report(&self, _db: &::salsa::Db) -> LinktimeFallbackReport {
+        Default::default()
+    }
 }
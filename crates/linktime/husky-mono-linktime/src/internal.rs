@@ -1,11 +1,15 @@
 mod library;
 mod linket_impls;
+mod untranspilable;
 
-use self::linket_impls::generate_linket_impl_map;
+use self::linket_impls::extend_linket_impl_map;
+use self::untranspilable::untranspilable_linkets;
 use self::{library::MonoLinketsLibrary, linket_impls::LinketImplMap};
 use crate::*;
-use husky_linket::{linket::LinketData, version_stamp::LinketVersionStamp};
-use husky_vfs::path::linktime_target_path::LinktimeTargetPath;
+use husky_linket::{linket::package_linkets, version_stamp::LinketVersionStamp};
+use husky_linktime::fallback::{LinktimeFallback, LinktimeFallbackReason, LinktimeFallbackReport};
+use husky_manifest::HasManifest;
+use husky_vfs::path::{linktime_target_path::LinktimeTargetPath, package_path::PackagePath};
 use version_stamp::HasVersionStamp;
 
 pub struct MonoLinktimeInternal<LinketImpl>
@@ -13,10 +17,23 @@ where
     LinketImpl: IsLinketImpl,
 {
     target_path: LinktimeTargetPath,
-    /// this is needed to kep Box<dyn StaticDyn> valid
-    past_libraries: Vec<MonoLinketsLibrary<LinketImpl>>,
-    current_library: MonoLinketsLibrary<LinketImpl>,
+    runtime: Option<&'static dyn IsDevRuntimeInterfaceDyn<LinketImpl>>,
+    /// one for each package in the full dependencies of the target
+    libraries: fxhash::FxHashMap<PackagePath, MonoLinketsLibrary<LinketImpl>>,
+    /// this is needed to kep Box<dyn StaticDyn> valid,
+    /// until values computed by them are gone
+    retired_libraries: Vec<MonoLinketsLibrary<LinketImpl>>,
     linket_impl_map: LinketImplMap<LinketImpl>,
+    /// packages with linkets that failed to transpile or compile, interpreted from VMIR instead
+    fallbacks: fxhash::FxHashMap<PackagePath, MonoLinktimeFallback>,
+    interpreted_linkets: fxhash::FxHashMap<Linket, LinketVersionStamp>,
+}
+
+struct MonoLinktimeFallback {
+    /// the linkets of the package at the time of the failure, so that we retry only if they change
+    linkets: Vec<(Linket, LinketVersionStamp)>,
+    /// all of the linkets if the package failed to compile, otherwise those failing to transpile
+    interpreted: Vec<(Linket, LinktimeFallbackReason)>,
 }
 
 impl<LinketImpl: IsLinketImpl> MonoLinktimeInternal<LinketImpl>
@@ -24,21 +41,26 @@ where
     LinketImpl: IsLinketImpl,
 {
     pub(crate) fn new(target_path: LinktimeTargetPath, db: &::salsa::Db) -> Self {
-        let Ok(current_library) = MonoLinketsLibrary::new(target_path, db) else {
-            panic!("error in generating libraries")
-        };
-        let linket_impl_map = generate_linket_impl_map(target_path, &current_library, db);
-        Self {
+        let mut slf = Self {
             target_path,
-            past_libraries: vec![],
-            current_library,
-            linket_impl_map,
-        }
+            runtime: None,
+            libraries: Default::default(),
+            retired_libraries: vec![],
+            linket_impl_map: Default::default(),
+            fallbacks: Default::default(),
+            interpreted_linkets: Default::default(),
+        };
+        slf.reload(db);
+        slf
     }
 
     /// although nothing on this side is modified, we do have modification on the linket side.
     pub(crate) fn init(&mut self, runtime: &'static dyn IsDevRuntimeInterfaceDyn<LinketImpl>) {
-        self.current_library.init(runtime);
+        assert!(self.runtime.is_none());
+        self.runtime = Some(runtime);
+        for library in self.libraries.values_mut() {
+            library.init(runtime)
+        }
     }
 }
 
@@ -47,46 +69,7 @@ where
     LinketImpl: IsLinketImpl,
 {
     pub(crate) fn get_linket_impl(&self, linket: Linket, db: &::salsa::Db) -> Option<LinketImpl> {
-        let Some(&(version_stamp, linket_impl)) = self.linket_impl_map.get(&linket) else {
-            use husky_print_utils::p;
-            use salsa::DebugWithDb;
-            let linkets: Vec<Linket> = self.linket_impl_map.clone().into_keys().collect();
-            let linkets_filtered: Vec<Linket> = linkets
-                .iter()
-                .copied()
-                .filter(|linket| match linket.data(db) {
-                    LinketData::EnumVariantConstructor {
-                        self_ty,
-                        path,
-                        instantiation,
-                    } if path.ident(db).data() == "Unknown" => true,
-                    _ => false,
-                })
-                .collect();
-            {
-                use similar::ChangeTag;
-                let l0 = format!("{:#?}", linkets_filtered[0].data(db).debug(db));
-                let l1 = format!("{:#?}", linket.data(db).debug(db));
-                let diff = similar::TextDiff::from_lines(&l0, &l1);
-                println!("-----------------------------------------------");
-                for change in diff.iter_all_changes() {
-                    let sign = match change.tag() {
-                        ChangeTag::Delete => "-",
-                        ChangeTag::Insert => "+",
-                        ChangeTag::Equal => " ",
-                    };
-                    print!("{}{}", sign, change);
-                }
-                println!("-----------------------------------------------");
-            }
-            p!(
-                linkets_filtered.debug(db),
-                linkets_filtered[0] == linket,
-                linket.data(db).debug(db),
-                linket
-            );
-            unreachable!()
-        };
+        let &(version_stamp, linket_impl) = self.linket_impl_map.get(&linket)?;
         (version_stamp == linket.version_stamp(db)).then_some(linket_impl)
     }
 
@@ -96,15 +79,18 @@ where
         key: Linket,
         db: &::salsa::Db,
     ) -> LinketImpl {
-        let (deps, linket) = self
-            .linket_impl_map
-            .get(&key)
-            .copied()
-            .expect("should be some");
-        if deps == key.version_stamp(db) {
-            return linket;
+        if let Some(linket_impl) = self.get_linket_impl(key, db) {
+            return linket_impl;
         }
         self.reload(db);
+        if self.interpreted_linkets.contains_key(&key) {
+            use ::salsa::DebugWithDb;
+
+            panic!(
+                "`{:?}` failed to transpile or compile and is to be interpreted, see the fallback report",
+                key.debug(db)
+            )
+        }
         self.linket_impl_map
             .get(&key)
             .copied()
@@ -112,12 +98,171 @@ where
             .1
     }
 
+    /// `None` if it's not known yet for the current version of the linket
+    pub(crate) fn is_linket_interpreted(&self, linket: Linket, db: &::salsa::Db) -> Option<bool> {
+        let version_stamp = linket.version_stamp(db);
+        // checked first, as the compiled library has a panicking impl for items failing to transpile
+        if let Some(&linket_version_stamp) = self.interpreted_linkets.get(&linket) {
+            return (linket_version_stamp == version_stamp).then_some(true);
+        }
+        let &(linket_version_stamp, _) = self.linket_impl_map.get(&linket)?;
+        (linket_version_stamp == version_stamp).then_some(false)
+    }
+
+    pub(crate) fn is_linket_interpreted_with_reload(
+        &mut self,
+        linket: Linket,
+        db: &::salsa::Db,
+    ) -> bool {
+        if let Some(interpreted) = self.is_linket_interpreted(linket, db) {
+            return interpreted;
+        }
+        self.reload(db);
+        // linkets outside of the packages, like vec constructors, are always compiled
+        self.is_linket_interpreted(linket, db).unwrap_or_default()
+    }
+
+    pub(crate) fn fallback_report(&self, db: &::salsa::Db) -> LinktimeFallbackReport {
+        // nothing is reloaded while the dependencies are broken, see `reload`
+        let package_paths = self.target_path.full_dependencies(db).unwrap_or_default();
+        LinktimeFallbackReport::new(
+            package_paths
+                .iter()
+                .filter_map(|package_path| Some((package_path, self.fallbacks.get(package_path)?)))
+                .flat_map(|(&package_path, fallback)| {
+                    fallback.interpreted.iter().map(move |(linket, reason)| {
+                        LinktimeFallback::new(package_path, *linket, reason.clone())
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// rebuilds and reloads only the libraries of the packages whose linkets changed,
+    /// linkets that fail to transpile or compile fall back to VMIR interpretation
+    ///
+    /// keeps the current libraries if the dependencies can't be resolved,
+    /// the manifest diagnostics tell the user what's wrong
     fn reload(&mut self, db: &::salsa::Db) {
-        let libraries = std::mem::replace(
-            &mut self.current_library,
-            MonoLinketsLibrary::new(self.target_path, db).unwrap(),
+        let package_paths = match self.target_path.full_dependencies(db) {
+            Ok(package_paths) => package_paths,
+            Err(e) => {
+                tracing::warn!("failed to resolve the dependencies, skipping reload: {e}");
+                return;
+            }
+        };
+        let stale_package_paths: Vec<PackagePath> = package_paths
+            .iter()
+            .copied()
+            .filter(|&package_path| self.is_package_stale(package_path, db))
+            .collect();
+        let removed_package_paths: Vec<PackagePath> = self
+            .libraries
+            .keys()
+            .chain(self.fallbacks.keys())
+            .copied()
+            .filter(|package_path| !package_paths.contains(package_path))
+            .collect();
+        for package_path in removed_package_paths {
+            self.retire_library(package_path);
+            self.remove_fallback(package_path)
+        }
+        if stale_package_paths.is_empty() {
+            return;
+        }
+        for (package_path, library) in
+            MonoLinketsLibrary::new_batch(self.target_path, &stale_package_paths, db)
+        {
+            self.retire_library(package_path);
+            self.remove_fallback(package_path);
+            match library {
+                Ok(mut library) => {
+                    if let Some(runtime) = self.runtime {
+                        library.init(runtime)
+                    }
+                    extend_linket_impl_map(&mut self.linket_impl_map, &library);
+                    self.libraries.insert(package_path, library);
+                    let interpreted = untranspilable_linkets(self.target_path, package_path, db);
+                    if !interpreted.is_empty() {
+                        self.fall_back(package_path, interpreted, db)
+                    }
+                }
+                Err(reason) => {
+                    let interpreted = package_linkets(db, package_path)
+                        .iter()
+                        .map(|&linket| (linket, reason.clone()))
+                        .collect();
+                    self.fall_back(package_path, interpreted, db)
+                }
+            }
+        }
+    }
+
+    fn is_package_stale(&self, package_path: PackagePath, db: &::salsa::Db) -> bool {
+        let old_linkets = match self.libraries.get(&package_path) {
+            Some(library) => library.linkets(),
+            None => match self.fallbacks.get(&package_path) {
+                Some(fallback) => fallback.linkets.as_slice(),
+                None => return true,
+            },
+        };
+        let linkets = package_linkets(db, package_path);
+        linkets.len() != old_linkets.len()
+            || linkets
+                .iter()
+                .zip(old_linkets)
+                .any(|(&linket, &(old_linket, old_version_stamp))| {
+                    linket != old_linket || linket.version_stamp(db) != old_version_stamp
+                })
+    }
+
+    fn fall_back(
+        &mut self,
+        package_path: PackagePath,
+        interpreted: Vec<(Linket, LinktimeFallbackReason)>,
+        db: &::salsa::Db,
+    ) {
+        let linkets: Vec<(Linket, LinketVersionStamp)> = package_linkets(db, package_path)
+            .iter()
+            .map(|&linket| (linket, linket.version_stamp(db)))
+            .collect();
+        self.interpreted_linkets.extend(
+            interpreted
+                .iter()
+                .map(|&(linket, _)| (linket, linket.version_stamp(db))),
+        );
+        self.fallbacks.insert(
+            package_path,
+            MonoLinktimeFallback {
+                linkets,
+                interpreted,
+            },
         );
-        self.past_libraries.push(libraries);
-        self.linket_impl_map = generate_linket_impl_map(self.target_path, &self.current_library, db)
+    }
+
+    fn remove_fallback(&mut self, package_path: PackagePath) {
+        let Some(fallback) = self.fallbacks.remove(&package_path) else {
+            return;
+        };
+        for (linket, _) in fallback.interpreted {
+            self.interpreted_linkets.remove(&linket);
+        }
+    }
+
+    fn retire_library(&mut self, package_path: PackagePath) {
+        let Some(library) = self.libraries.remove(&package_path) else {
+            return;
+        };
+        for (linket, _) in library.linkets() {
+            self.linket_impl_map.remove(linket);
+        }
+        self.retired_libraries.push(library)
+    }
+
+    /// Unloads the libraries replaced by reloads.
+    ///
+    /// The caller guarantees that no value computed by them is alive.
+    pub(crate) unsafe fn release_retired_libraries(&mut self) {
+        self.retired_libraries.clear()
     }
 }
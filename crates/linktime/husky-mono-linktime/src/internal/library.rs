@@ -1,68 +1,227 @@
 use super::*;
 use husky_any_linket_impls::{LinketImplsGetter, LINKET_IMPLS_GETTER_IDENT};
-use husky_cargo_utils::compile::compile_workspace;
+use husky_cargo_utils::compile::compile_workspace_packages;
 use husky_corgi_config::transpilation_setup::HasTranspilationSetup;
 use husky_item_path_interface::ItemPathIdInterface;
-use husky_linket::linket::{target_linket_item_path_id_interfaces, target_linkets};
+use husky_linket::linket::{package_linket_item_path_id_interfaces, package_linkets};
 use husky_linket_impl::{dev_eval_context::DevEvalContextGuard, linket_impls::LinketImpls};
-use husky_manifest::helpers::upstream::HasAllUpstreamPackages;
-use husky_rust_transpilation::transpile_to_fs::TranspileToFsFull;
+use husky_linktime::fallback::LinktimeFallbackReason;
+use husky_rust_transpilation::transpile_to_fs::{linkets_rust_package_name, TranspileToFsFull};
 use husky_vfs::path::package_path::PackagePath;
 use libloading::Library;
-use std::path::PathBuf;
-use vec_like::{VecMap, VecPairMap};
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
+/// the linket impls of a single package
 pub struct MonoLinketsLibrary<LinketImpl: IsLinketImpl> {
     // the order is important, the guard must be dropped before cdylib,
     // because the drop will invoke functions in the cdylib
     dev_eval_context_guard: Option<DevEvalContextGuard>,
-    pub cdylib: Cdylib,
     pub linket_impls: LinketImpls<LinketImpl>,
+    /// the linkets at the time of compilation, aligned with `linket_impls`
+    linkets: Vec<(Linket, LinketVersionStamp)>,
+    pub cdylib: Cdylib,
 }
 
+/// a loaded copy of a cdylib, deleted once unloaded
 #[salsa::derive_debug_with_db]
-pub struct Cdylib(Library);
+pub struct Cdylib {
+    /// always `Some` until dropped
+    library: Option<Library>,
+    copy_path: PathBuf,
+}
+
+/// distinguishes the copies of rebuilt cdylibs
+static CDYLIB_GENERATION: AtomicUsize = AtomicUsize::new(0);
 
 impl Cdylib {
+    /// Loads a copy of the cdylib instead of itself.
+    ///
+    /// The dynamic loader doesn't load a path again as long as the old library under that path
+    /// is still loaded, so a rebuilt cdylib would silently resolve to the stale one.
+    unsafe fn load(path: &Path) -> Result<Self, String> {
+        let generation = CDYLIB_GENERATION.fetch_add(1, Ordering::Relaxed);
+        let mut file_name = path
+            .file_stem()
+            .ok_or_else(|| format!("invalid cdylib path `{}`", path.display()))?
+            .to_owned();
+        file_name.push(format!("-{generation}"));
+        let copy_path: PathBuf = match path.extension() {
+            Some(extension) => path.with_file_name(file_name).with_extension(extension),
+            None => path.with_file_name(file_name),
+        };
+        std::fs::copy(path, &copy_path).map_err(|e| {
+            format!(
+                "failed to copy cdylib `{}` to `{}`: {e}",
+                path.display(),
+                copy_path.display()
+            )
+        })?;
+        match Library::new(&copy_path) {
+            Ok(library) => Ok(Cdylib {
+                library: Some(library),
+                copy_path,
+            }),
+            Err(e) => {
+                let _ = std::fs::remove_file(&copy_path);
+                Err(format!(
+                    "failed to load cdylib `{}`: {e}",
+                    copy_path.display()
+                ))
+            }
+        }
+    }
+
+    fn library(&self) -> &Library {
+        self.library.as_ref().expect("only taken on drop")
+    }
+
     fn linket_impls<LinketImpl: IsLinketImpl>(
         &self,
         item_path_id_interfaces: &[Option<ItemPathIdInterface>],
-    ) -> LinketImpls<LinketImpl> {
+    ) -> Result<LinketImpls<LinketImpl>, String> {
         let linket_impls_getter: libloading::Symbol<LinketImplsGetter> =
-            unsafe { self.0.get(LINKET_IMPLS_GETTER_IDENT).unwrap() };
-        linket_impls_getter(item_path_id_interfaces).downcast()
+            unsafe { self.library().get(LINKET_IMPLS_GETTER_IDENT) }.map_err(|e| {
+                format!(
+                    "failed to find the linket impls getter in cdylib `{}`: {e}",
+                    self.copy_path.display()
+                )
+            })?;
+        Ok(linket_impls_getter(item_path_id_interfaces).downcast())
+    }
+}
+
+impl Drop for Cdylib {
+    fn drop(&mut self) {
+        // unload first, the copy isn't needed afterwards
+        std::mem::drop(self.library.take());
+        if let Err(e) = std::fs::remove_file(&self.copy_path) {
+            tracing::warn!(
+                "failed to remove cdylib copy `{}`: {e}",
+                self.copy_path.display()
+            )
+        }
     }
 }
 
 impl<LinketImpl: IsLinketImpl> MonoLinketsLibrary<LinketImpl> {
-    pub(super) fn new(target_path: LinktimeTargetPath, db: &::salsa::Db) -> Result<Self, ()> {
+    /// Transpiles the whole target, but only builds and loads the linkets of `package_paths`.
+    ///
+    /// Cargo doesn't rebuild what hasn't changed, so the cost is mostly that of the given packages.
+    /// A package that fails to compile doesn't stop the others.
+    pub(super) fn new_batch(
+        target_path: LinktimeTargetPath,
+        package_paths: &[PackagePath],
+        db: &::salsa::Db,
+    ) -> Vec<(PackagePath, Result<Self, LinktimeFallbackReason>)> {
         // useful for debugging
-        match std::env::var("SKIP_COMPILATION") {
+        let transpilation = match std::env::var("SKIP_COMPILATION") {
             Ok(s) => {
                 assert_eq!(s, "1");
+                Ok(())
+            }
+            Err(_) => target_path.transpile_to_fs_full(target_path.transpilation_setup(db), db),
+        };
+        // items failing to transpile are `todo!()`s in the rust code, see `untranspilable_linkets`
+        if let Err(error) = transpilation {
+            let reason = LinktimeFallbackReason::TranspilationFailed {
+                message: error.to_string(),
+            };
+            return package_paths
+                .iter()
+                .map(|&package_path| (package_path, Err(reason.clone())))
+                .collect();
+        }
+        let mut libraries = vec![];
+        match Self::compile_batch(target_path, package_paths, db) {
+            Ok(cdylibs) => libraries.extend(package_paths.iter().copied().zip(cdylibs).map(
+                |(package_path, cdylib)| {
+                    let library = Self::new(package_path, cdylib, db)
+                        .map_err(|message| LinktimeFallbackReason::CompilationFailed { message });
+                    (package_path, library)
+                },
+            )),
+            // compile one by one to find out which ones are to blame
+            Err(_) if package_paths.len() > 1 => {
+                for &package_path in package_paths {
+                    let library = match Self::compile_batch(target_path, &[package_path], db) {
+                        Ok(mut cdylibs) => Self::new(package_path, cdylibs.pop().unwrap(), db),
+                        Err(message) => Err(message),
+                    }
+                    .map_err(|message| LinktimeFallbackReason::CompilationFailed { message });
+                    libraries.push((package_path, library))
+                }
             }
-            Err(_) => {
-                match target_path.transpile_to_fs_full(target_path.transpilation_setup(db), db) {
-                    Ok(()) => (),
-                    Err(_) => todo!(),
-                };
+            Err(message) => {
+                libraries.extend(package_paths.iter().map(|&package_path| {
+                    (
+                        package_path,
+                        Err(LinktimeFallbackReason::CompilationFailed {
+                            message: message.clone(),
+                        }),
+                    )
+                }))
             }
         }
-        let rust_workspace_dir = target_path.rust_workspace_abs_dir(db);
-        let cdylib: Cdylib = compile_workspace(
+        libraries
+    }
+
+    /// one cdylib for each package, in the same order
+    ///
+    /// Fails if cargo fails or if any of the cdylibs fails to load.
+    fn compile_batch(
+        target_path: LinktimeTargetPath,
+        package_paths: &[PackagePath],
+        db: &::salsa::Db,
+    ) -> Result<Vec<Cdylib>, String> {
+        if package_paths.is_empty() {
+            return Ok(vec![]);
+        }
+        let rust_package_names: Vec<String> = package_paths
+            .iter()
+            .map(|&package_path| linkets_rust_package_name(package_path, db))
+            .collect();
+        let mut cdylibs: Vec<(String, Cdylib)> = compile_workspace_packages(
             target_path.rust_workspace_manifest_path(db),
+            rust_package_names.clone(),
             |compilation| unsafe {
-                assert_eq!(compilation.cdylibs.len(), 1, "expect only one dylib");
-                let cdylib = &compilation.cdylibs[0];
-                Cdylib(Library::new(cdylib.path.clone()).unwrap())
+                compilation
+                    .cdylibs
+                    .iter()
+                    .map(|cdylib| {
+                        Ok((
+                            cdylib.unit.pkg.name().to_string(),
+                            Cdylib::load(&cdylib.path)?,
+                        ))
+                    })
+                    .collect::<Result<_, String>>()
             },
-        )?;
-        let item_path_id_interfaces = target_linket_item_path_id_interfaces(db, target_path);
-        let linket_impls = cdylib.linket_impls(item_path_id_interfaces);
+        )??;
+        rust_package_names
+            .into_iter()
+            .map(|rust_package_name| {
+                let idx = cdylibs
+                    .iter()
+                    .position(|(name, _)| *name == rust_package_name)
+                    .ok_or_else(|| format!("no cdylib built for `{rust_package_name}`"))?;
+                Ok(cdylibs.swap_remove(idx).1)
+            })
+            .collect()
+    }
+
+    fn new(package_path: PackagePath, cdylib: Cdylib, db: &::salsa::Db) -> Result<Self, String> {
+        let item_path_id_interfaces = package_linket_item_path_id_interfaces(db, package_path);
+        let linket_impls = cdylib.linket_impls(item_path_id_interfaces)?;
         Ok(Self {
             dev_eval_context_guard: None,
-            cdylib,
             linket_impls,
+            linkets: package_linkets(db, package_path)
+                .iter()
+                .map(|&linket| (linket, linket.version_stamp(db)))
+                .collect(),
+            cdylib,
         })
     }
 
@@ -83,15 +242,19 @@ impl<LinketImpl: IsLinketImpl> MonoLinketsLibrary<LinketImpl> {
 #[test]
 fn new_mono_linkets_libary_works() {
     use husky_dev_comptime::db::DevComptimeDb;
+    use husky_manifest::HasManifest;
     use husky_standard_linket_impl::StandardLinketImpl;
 
     DevComptimeDb::vfs_plain_test(
         |db, package_path: PackagePath| {
-            MonoLinketsLibrary::<StandardLinketImpl>::new(
-                LinktimeTargetPath::new_package(package_path, db),
+            let target_path = LinktimeTargetPath::new_package(package_path, db);
+            for (_, library) in MonoLinketsLibrary::<StandardLinketImpl>::new_batch(
+                target_path,
+                target_path.full_dependencies(db).unwrap(),
                 db,
-            )
-            .unwrap();
+            ) {
+                library.unwrap();
+            }
         },
         &VfsTestConfig::new(
             "generate_linket_storage",
@@ -106,6 +269,10 @@ impl<LinketImpl: IsLinketImpl> MonoLinketsLibrary<LinketImpl> {
     pub fn linket_impls(&self) -> &LinketImpls<LinketImpl> {
         &self.linket_impls
     }
+
+    pub fn linkets(&self) -> &[(Linket, LinketVersionStamp)] {
+        &self.linkets
+    }
 }
 
 /// # actions
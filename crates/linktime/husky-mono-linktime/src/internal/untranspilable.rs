@@ -0,0 +1,69 @@
+use super::*;
+use husky_corgi_config::transpilation_setup::HasTranspilationSetup;
+use husky_entity_path::path::ItemPath;
+use husky_rust_transpilation::error::package_rust_transpilation_errors;
+use husky_sem_item_path_deps::item_path_deps::item_sem_item_path_deps;
+
+/// The linkets of the package that are to be interpreted, each with a reason.
+///
+/// Those of items that failed to transpile, and those of items depending on them,
+/// as the compiled code of the latter calls the compiled code of the former.
+pub(super) fn untranspilable_linkets(
+    target_path: LinktimeTargetPath,
+    package_path: PackagePath,
+    db: &::salsa::Db,
+) -> Vec<(Linket, LinktimeFallbackReason)> {
+    let setup = target_path.transpilation_setup(db);
+    let mut messages: fxhash::FxHashMap<ItemPath, Vec<&'static str>> = Default::default();
+    // items call into their dependencies, which may be in any package of the target
+    for &dep in target_path.full_dependencies(db).unwrap_or_default() {
+        for error in package_rust_transpilation_errors(db, dep, setup) {
+            messages
+                .entry(error.item_path())
+                .or_default()
+                .push(error.message())
+        }
+    }
+    if messages.is_empty() {
+        return vec![];
+    }
+    package_linkets(db, package_path)
+        .iter()
+        .filter_map(|&linket| {
+            let (item_path, _) = linket.path_and_instantiation_for_definition(db)?;
+            let reason = match messages.get(&item_path) {
+                Some(messages) => LinktimeFallbackReason::TranspilationFailed {
+                    message: messages.join(", "),
+                },
+                None => LinktimeFallbackReason::DependsOnInterpreted {
+                    item_path: untranspilable_dep(item_path, &messages, db)?,
+                },
+            };
+            Some((linket, reason))
+        })
+        .collect()
+}
+
+/// an item failing to transpile that `item_path` depends on, directly or not
+fn untranspilable_dep(
+    item_path: ItemPath,
+    messages: &fxhash::FxHashMap<ItemPath, Vec<&'static str>>,
+    db: &::salsa::Db,
+) -> Option<ItemPath> {
+    let mut visited: fxhash::FxHashSet<ItemPath> = Default::default();
+    let mut stack = vec![item_path];
+    while let Some(item_path) = stack.pop() {
+        let Ok(deps) = item_sem_item_path_deps(db, *item_path) else {
+            continue;
+        };
+        for &dep in deps.iter() {
+            if messages.contains_key(&dep) {
+                return Some(dep);
+            }
+            if visited.insert(dep) {
+                stack.push(dep)
+            }
+        }
+    }
+    None
+}
@@ -7,11 +7,13 @@ use self::internal::MonoLinktimeInternal;
 use self::tests::*;
 use husky_linket::linket::Linket;
 use husky_linket_impl::{dev_eval_context::IsDevRuntimeInterfaceDyn, linket_impl::IsLinketImpl};
-use husky_linktime::IsLinktime;
+use husky_linktime::{fallback::LinktimeFallbackReport, IsLinktime};
 use husky_vfs::path::linktime_target_path::LinktimeTargetPath;
 use std::collections::HashMap;
 
-// this will transpile everything compilable to Rust
+// this will transpile everything compilable to Rust,
+// with a cdylib of linket impls for each package so that a change only rebuilds the packages affected,
+// the packages that fail to transpile or compile are interpreted from VMIR instead
 pub struct MonoLinktime<LinketImpl>
 where
     LinketImpl: IsLinketImpl,
@@ -46,4 +48,88 @@ where
         let mut internal = self.internal.write().unwrap();
         internal.init(runtime)
     }
+
+    fn is_linket_interpreted(&self, linket: Linket, db: &::salsa::Db) -> bool {
+        if let Some(interpreted) = self
+            .internal
+            .read()
+            .expect("todo")
+            .is_linket_interpreted(linket, db)
+        {
+            interpreted
+        } else {
+            self.internal
+                .write()
+                .expect("todo")
+                .is_linket_interpreted_with_reload(linket, db)
+        }
+    }
+
+    fn fallback_report(&self, db: &::salsa::Db) -> LinktimeFallbackReport {
+        self.internal.read().expect("todo").fallback_report(db)
+    }
+
+    unsafe fn release_retired_libraries(&self) {
+        let mut internal = self.internal.write().unwrap();
+        internal.release_retired_libraries()
+    }
+}
+
+#[test]
+fn untranspilable_items_fall_back_to_interpretation() {
+    use husky_dev_comptime::db::DevComptimeDb;
+    use husky_linket::linket::package_linkets;
+    use husky_linktime::fallback::LinktimeFallbackReason;
+    use husky_path_utils::HuskyLangDevPaths;
+    use husky_standard_linket_impl::StandardLinketImpl;
+    use husky_vfs::path::package_path::PackagePath;
+
+    let db = &*DevComptimeDb::default();
+    let package_path = PackagePath::new_local_or_toolchain_package(
+        db,
+        db.dev_toolchain().unwrap(),
+        &HuskyLangDevPaths::new()
+            .root()
+            .join("anti-examples/untranspilable"),
+    )
+    .unwrap();
+    let target_path = LinktimeTargetPath::new_package(package_path, db);
+    let linktime = MonoLinktime::<StandardLinketImpl>::new(target_path, db);
+    let linket = |ident: &str| {
+        package_linkets(db, package_path)
+            .iter()
+            .copied()
+            .find(|linket| {
+                linket
+                    .path_and_instantiation_for_definition(db)
+                    .and_then(|(item_path, _)| item_path.ident(db))
+                    .is_some_and(|item_ident| item_ident.data() == ident)
+            })
+            .unwrap()
+    };
+    let assert_nonzero = linket("assert_nonzero");
+    let double_nonzero = linket("double_nonzero");
+    assert!(linktime.is_linket_interpreted(assert_nonzero, db));
+    assert!(linktime.is_linket_interpreted(double_nonzero, db));
+    assert!(!linktime.is_linket_interpreted(linket("double"), db));
+    let report = linktime.fallback_report(db);
+    let [assert_nonzero_fallback, double_nonzero_fallback] = report.fallbacks() else {
+        panic!(
+            "expect only the two items to fall back, got:\n{}",
+            report.message(db)
+        )
+    };
+    assert_eq!(assert_nonzero_fallback.linket(), assert_nonzero);
+    assert_eq!(
+        assert_nonzero_fallback.reason(),
+        &LinktimeFallbackReason::TranspilationFailed {
+            message: "`assert` with an integer condition".to_string()
+        }
+    );
+    assert_eq!(double_nonzero_fallback.linket(), double_nonzero);
+    assert!(matches!(
+        double_nonzero_fallback.reason(),
+        &LinktimeFallbackReason::DependsOnInterpreted { item_path }
+            if item_path.ident(db).unwrap().data() == "assert_nonzero"
+    ));
 }
@@ -0,0 +1,51 @@
+use crate::{
+    error::{AllLlmsResult, AnyLlmError},
+    mock::AllLlmsMock,
+};
+use disk_cache::mode::DiskCacheMode;
+
+pub const BACKEND_ENV_VAR: &str = "HUSKY_LLM_BACKEND";
+
+/// Decides what happens when a request is not in the disk caches.
+#[derive(Debug, Default, Clone)]
+pub enum AllLlmsBackend {
+    /// Calls out to the network and records the response.
+    #[default]
+    Record,
+    /// Fails with a clear error, never calling out.
+    Replay,
+    /// Answers everything with a scripted model, touching neither the disk nor the network.
+    Mock(AllLlmsMock),
+}
+
+impl AllLlmsBackend {
+    /// Reads `HUSKY_LLM_BACKEND`, which is either `record` or `replay`, defaulting to `record`.
+    ///
+    /// The mock backend can only be set up in code.
+    pub fn from_env() -> AllLlmsResult<Self> {
+        match std::env::var(BACKEND_ENV_VAR) {
+            Ok(backend) => backend.as_str().try_into(),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub(crate) fn disk_cache_mode(&self) -> Option<DiskCacheMode> {
+        match self {
+            AllLlmsBackend::Record => Some(DiskCacheMode::ReadWrite),
+            AllLlmsBackend::Replay => Some(DiskCacheMode::Replay),
+            AllLlmsBackend::Mock(_) => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for AllLlmsBackend {
+    type Error = AnyLlmError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "record" => Ok(AllLlmsBackend::Record),
+            "replay" => Ok(AllLlmsBackend::Replay),
+            _ => Err(AnyLlmError::InvalidBackend(value.to_string())),
+        }
+    }
+}
@@ -1,5 +1,8 @@
+use crate::model::AllLlmModel;
 use gemini::error::GeminiError;
+use llm_prelude::error::LlmError;
 use openai::error::OpenaiError;
+use sglang::error::SglangError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum AnyLlmError {
@@ -7,8 +10,16 @@ pub enum AnyLlmError {
     OpenAi(#[from] OpenaiError),
     #[error("Gemini error: {0}")]
     Gemini(#[from] GeminiError),
+    #[error("Sglang error: {0}")]
+    Sglang(#[from] SglangError),
+    #[error("{0}")]
+    Llm(#[from] LlmError),
     #[error("Invalid cache dir: {0}")]
     InvalidCacheDir(#[from] std::io::Error),
+    #[error("Invalid backend `{0}`, expected `record` or `replay`")]
+    InvalidBackend(String),
+    #[error("No mock rule of {model:?} matches prompt: {prompt}")]
+    MockMiss { model: AllLlmModel, prompt: String },
 }
 
 pub type AllLlmsResult<T> = Result<T, AnyLlmError>;
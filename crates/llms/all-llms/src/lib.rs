@@ -1,28 +1,68 @@
-mod error;
+pub mod backend;
+pub mod error;
+pub mod mock;
 pub mod model;
 #[cfg(test)]
 mod tests;
 pub mod transformation;
 
+use backend::AllLlmsBackend;
 use error::{AllLlmsResult, AnyLlmError};
 use eterned::db::EternerDb;
 use gemini::client::GeminiClient;
+use llm_prelude::{
+    message::LlmMessage,
+    middleware::usage::LlmUsageReport,
+    structured::{self, IsLlmStructuredOutput},
+};
+use mock::AllLlmsMock;
 use model::AllLlmModel;
 use openai::OpenaiClient;
+use sglang::client::SglangClient;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+/// Name of the file in the cache directory that accumulates the usage of all runs.
+pub const USAGE_FILE_NAME: &str = "usage.json";
+
 pub struct AllLlmsClient<'db> {
-    openai: OpenaiClient<'db>,
-    gemini: GeminiClient<'db>,
+    inner: AllLlmsClientInner<'db>,
+}
+
+enum AllLlmsClientInner<'db> {
+    Remote {
+        cache_dir: PathBuf,
+        openai: OpenaiClient<'db>,
+        gemini: GeminiClient<'db>,
+        sglang: SglangClient<'db>,
+    },
+    Mock(AllLlmsMock),
 }
 
 impl<'db> AllLlmsClient<'db> {
+    /// The backend is read from the environment, see [`AllLlmsBackend::from_env`].
     pub fn new(
         db: &'db EternerDb,
         tokio_runtime: Arc<tokio::runtime::Runtime>,
         cache_dir: PathBuf,
     ) -> AllLlmsResult<Self> {
+        Self::new_with_backend(db, tokio_runtime, cache_dir, AllLlmsBackend::from_env()?)
+    }
+
+    pub fn new_with_backend(
+        db: &'db EternerDb,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        cache_dir: PathBuf,
+        backend: AllLlmsBackend,
+    ) -> AllLlmsResult<Self> {
+        let cache_mode = match backend {
+            AllLlmsBackend::Mock(mock) => {
+                return Ok(Self {
+                    inner: AllLlmsClientInner::Mock(mock),
+                })
+            }
+            ref backend => backend.disk_cache_mode().unwrap(),
+        };
         assert!(cache_dir.exists());
         assert!(cache_dir.is_dir());
         let openai_cache_dir = &cache_dir.join("openai");
@@ -43,25 +83,130 @@ impl<'db> AllLlmsClient<'db> {
             todo!()
             // return Err(AllLlmsError::InvalidCacheDir(gemini_cache_dir.to_owned()));
         }
+        let sglang_cache_dir = cache_dir.join("sglang");
+        if !sglang_cache_dir.exists() {
+            std::fs::create_dir_all(&sglang_cache_dir)
+                .map_err(|e| AnyLlmError::InvalidCacheDir(e))?;
+        }
         Ok(Self {
-            openai: OpenaiClient::new(db, tokio_runtime.clone(), openai_cache_dir)?,
-            gemini: GeminiClient::new(db, tokio_runtime, gemini_cache_dir)?,
+            inner: AllLlmsClientInner::Remote {
+                openai: OpenaiClient::new_with_cache_mode(
+                    db,
+                    tokio_runtime.clone(),
+                    openai_cache_dir,
+                    cache_mode,
+                )?,
+                gemini: GeminiClient::new_with_cache_mode(
+                    db,
+                    tokio_runtime.clone(),
+                    gemini_cache_dir,
+                    cache_mode,
+                )?,
+                sglang: SglangClient::new_with_cache_mode(
+                    db,
+                    tokio_runtime,
+                    sglang_cache_dir,
+                    cache_mode,
+                )?,
+                cache_dir,
+            },
         })
     }
 }
 
 impl<'db> AllLlmsClient<'db> {
     pub fn generate_text(&self, model: AllLlmModel, prompt: String) -> AllLlmsResult<String> {
+        let (openai, gemini, sglang) = match self.inner {
+            AllLlmsClientInner::Remote {
+                ref openai,
+                ref gemini,
+                ref sglang,
+                ..
+            } => (openai, gemini, sglang),
+            AllLlmsClientInner::Mock(ref mock) => return mock.generate_text(model, &prompt),
+        };
         match model {
-            AllLlmModel::Openai(openai_model) => self
-                .openai
+            AllLlmModel::Openai(openai_model) => openai
                 .generate_text(openai_model, prompt)
                 .map_err(Into::into),
-            AllLlmModel::Gemini(gemini_model) => self
-                .gemini
+            AllLlmModel::Gemini(gemini_model) => gemini
                 .generate_text(gemini_model, prompt)
                 .map_err(Into::into),
-            AllLlmModel::Sglang(sglang_model) => todo!(),
+            AllLlmModel::Sglang(sglang_model) => sglang
+                .generate_text(sglang_model, prompt)
+                .map_err(Into::into),
+        }
+    }
+}
+
+impl<'db> AllLlmsClient<'db> {
+    pub fn chat(&self, model: AllLlmModel, messages: Vec<LlmMessage>) -> AllLlmsResult<String> {
+        let (openai, gemini, sglang) = match self.inner {
+            AllLlmsClientInner::Remote {
+                ref openai,
+                ref gemini,
+                ref sglang,
+                ..
+            } => (openai, gemini, sglang),
+            AllLlmsClientInner::Mock(ref mock) => return mock.chat(model, &messages),
+        };
+        match model {
+            AllLlmModel::Openai(openai_model) => {
+                openai.chat(openai_model, messages).map_err(Into::into)
+            }
+            AllLlmModel::Gemini(gemini_model) => {
+                gemini.chat(gemini_model, messages).map_err(Into::into)
+            }
+            AllLlmModel::Sglang(sglang_model) => {
+                sglang.chat(sglang_model, messages).map_err(Into::into)
+            }
+        }
+    }
+
+    /// Asks for JSON conforming to the schema of `T`,
+    /// re-asking with the parse error up to [`structured::DEFAULT_MAX_REASKS`] times.
+    pub fn generate_structured<T: IsLlmStructuredOutput>(
+        &self,
+        model: AllLlmModel,
+        messages: &[LlmMessage],
+    ) -> AllLlmsResult<T> {
+        structured::generate_structured(messages, structured::DEFAULT_MAX_REASKS, |messages| {
+            self.chat(model, messages.to_vec())
+        })
+    }
+}
+
+impl<'db> AllLlmsClient<'db> {
+    /// usage of the requests that missed the caches, per model
+    pub fn usage(&self) -> LlmUsageReport {
+        let mut usage = LlmUsageReport::default();
+        if let AllLlmsClientInner::Remote {
+            ref openai,
+            ref gemini,
+            ref sglang,
+            ..
+        } = self.inner
+        {
+            usage.merge(&openai.usage());
+            usage.merge(&gemini.usage());
+            usage.merge(&sglang.usage());
+        }
+        usage
+    }
+}
+
+impl<'db> Drop for AllLlmsClient<'db> {
+    fn drop(&mut self) {
+        let AllLlmsClientInner::Remote { ref cache_dir, .. } = self.inner else {
+            return;
+        };
+        let usage = self.usage();
+        if usage.is_empty() {
+            return;
+        }
+        let path = cache_dir.join(USAGE_FILE_NAME);
+        if let Err(e) = usage.accumulate_into_file(&path) {
+            tracing::warn!("failed to save LLM usage to {}: {e}", path.display());
         }
     }
 }
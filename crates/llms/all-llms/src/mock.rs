@@ -0,0 +1,102 @@
+use crate::{
+    error::{AllLlmsResult, AnyLlmError},
+    model::AllLlmModel,
+};
+use llm_prelude::message::LlmMessage;
+use std::sync::Arc;
+
+/// A scripted model for deterministic tests.
+///
+/// Rules are tried in the order they are added; the first one that matches answers the prompt.
+#[derive(Debug, Default, Clone)]
+pub struct AllLlmsMock {
+    rules: Vec<AllLlmsMockRule>,
+}
+
+#[derive(Clone)]
+pub enum AllLlmsMockRule {
+    /// answers prompts equal to `prompt`
+    Exact { prompt: String, response: String },
+    /// answers prompts containing `pattern`
+    Contains { pattern: String, response: String },
+    /// answers whatever the function returns `Some` for
+    Fn(Arc<dyn Fn(AllLlmModel, &str) -> Option<String> + Send + Sync>),
+}
+
+impl std::fmt::Debug for AllLlmsMockRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exact { prompt, response } => f
+                .debug_struct("Exact")
+                .field("prompt", prompt)
+                .field("response", response)
+                .finish(),
+            Self::Contains { pattern, response } => f
+                .debug_struct("Contains")
+                .field("pattern", pattern)
+                .field("response", response)
+                .finish(),
+            Self::Fn(_) => f.write_str("Fn(..)"),
+        }
+    }
+}
+
+impl AllLlmsMock {
+    pub fn with_exact(mut self, prompt: impl Into<String>, response: impl Into<String>) -> Self {
+        self.rules.push(AllLlmsMockRule::Exact {
+            prompt: prompt.into(),
+            response: response.into(),
+        });
+        self
+    }
+
+    pub fn with_contains(
+        mut self,
+        pattern: impl Into<String>,
+        response: impl Into<String>,
+    ) -> Self {
+        self.rules.push(AllLlmsMockRule::Contains {
+            pattern: pattern.into(),
+            response: response.into(),
+        });
+        self
+    }
+
+    pub fn with_fn(
+        mut self,
+        f: impl Fn(AllLlmModel, &str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.rules.push(AllLlmsMockRule::Fn(Arc::new(f)));
+        self
+    }
+}
+
+impl AllLlmsMock {
+    pub fn generate_text(&self, model: AllLlmModel, prompt: &str) -> AllLlmsResult<String> {
+        self.rules
+            .iter()
+            .find_map(|rule| match rule {
+                AllLlmsMockRule::Exact {
+                    prompt: expected,
+                    response,
+                } => (prompt == expected).then(|| response.clone()),
+                AllLlmsMockRule::Contains { pattern, response } => {
+                    prompt.contains(pattern.as_str()).then(|| response.clone())
+                }
+                AllLlmsMockRule::Fn(f) => f(model, prompt),
+            })
+            .ok_or_else(|| AnyLlmError::MockMiss {
+                model,
+                prompt: prompt.to_string(),
+            })
+    }
+
+    /// Rules are matched against the last message.
+    pub fn chat(&self, model: AllLlmModel, messages: &[LlmMessage]) -> AllLlmsResult<String> {
+        let prompt = messages
+            .last()
+            .map(|message| message.content.as_str())
+            .unwrap_or_default();
+        self.generate_text(model, prompt)
+    }
+}
@@ -13,6 +13,8 @@ impl AllLlmModel {
     pub const GPT_4O: Self = Self::Openai(OpenaiModel::Gpt4o);
     pub const GEMINI_1_5_FLASH: Self = Self::Gemini(GeminiModel::Gemini1_5Flash);
     pub const GEMINI_1_5_PRO: Self = Self::Gemini(GeminiModel::Gemini1_5Pro);
+    pub const LLAMA_3_1_8B_INSTRUCT: Self = Self::Sglang(SglangModel::Llama3_1_8bInstruct);
+    pub const QWEN2_7B_INSTRUCT: Self = Self::Sglang(SglangModel::Qwen2_7bInstruct);
 }
 
 impl AllLlmModel {
@@ -33,6 +35,8 @@ impl TryFrom<&str> for AllLlmModel {
             "gpt-4o" => Ok(AllLlmModel::GPT_4O),
             "gemini-1.5-flash" => Ok(AllLlmModel::GEMINI_1_5_FLASH),
             "gemini-1.5-pro" => Ok(AllLlmModel::GEMINI_1_5_PRO),
+            "llama-3.1-8b-instruct" => Ok(AllLlmModel::LLAMA_3_1_8B_INSTRUCT),
+            "qwen2-7b-instruct" => Ok(AllLlmModel::QWEN2_7B_INSTRUCT),
             _ => Err(()),
         }
     }
@@ -57,3 +61,17 @@ impl<'de> serde::Deserialize<'de> for AllLlmModel {
             .map_err(|_| serde::de::Error::custom(format!("unknown model: {}", s)))
     }
 }
+
+#[test]
+fn all_llm_model_try_from_works() {
+    for model in [
+        AllLlmModel::GPT_4O,
+        AllLlmModel::GEMINI_1_5_FLASH,
+        AllLlmModel::GEMINI_1_5_PRO,
+        AllLlmModel::LLAMA_3_1_8B_INSTRUCT,
+        AllLlmModel::QWEN2_7B_INSTRUCT,
+    ] {
+        assert_eq!(AllLlmModel::try_from(model.as_str()), Ok(model));
+    }
+    assert_eq!(AllLlmModel::try_from("gpt-5"), Err(()));
+}
@@ -1,8 +1,15 @@
+use crate::{backend::AllLlmsBackend, error::AnyLlmError, mock::AllLlmsMock};
 use crate::{model::AllLlmModel, AllLlmsClient};
 use alien_seed::attach::with_seed;
 use alien_seed::AlienSeed;
+use disk_cache::error::DiskCacheError;
 use eterned::db::EternerDb;
 use expect_test::expect;
+use gemini::error::GeminiError;
+use llm_prelude::message::LlmMessage;
+use openai::error::OpenaiError;
+use schemars::JsonSchema;
+use serde::Deserialize;
 use std::{path::PathBuf, sync::Arc};
 
 #[test]
@@ -45,3 +52,108 @@ fn all_llms_client_works() {
     );
     })
 }
+
+#[test]
+fn all_llms_client_mock_works() {
+    let db = &EternerDb::default();
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let mock = AllLlmsMock::default()
+        .with_exact("ping", "pong")
+        .with_contains("Soifon", "Because of Yoruichi.")
+        .with_fn(|model, prompt| {
+            prompt
+                .strip_prefix("echo ")
+                .map(|rest| format!("{}: {rest}", model.as_str()))
+        });
+    let client = AllLlmsClient::new_with_backend(
+        db,
+        tokio_runtime,
+        PathBuf::from("caches/does-not-exist"),
+        AllLlmsBackend::Mock(mock),
+    )
+    .unwrap();
+    let generate_text =
+        |prompt: &str| client.generate_text(AllLlmModel::GPT_4O, prompt.to_string());
+    assert_eq!(generate_text("ping").unwrap(), "pong");
+    assert_eq!(
+        generate_text("Why does Soifon hate Urahara?").unwrap(),
+        "Because of Yoruichi."
+    );
+    assert_eq!(generate_text("echo hello").unwrap(), "gpt-4o: hello");
+    assert!(matches!(
+        generate_text("pong"),
+        Err(AnyLlmError::MockMiss { .. })
+    ));
+}
+
+#[test]
+fn all_llms_client_replay_works() {
+    let db = &EternerDb::default();
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = AllLlmsClient::new_with_backend(
+        db,
+        tokio_runtime,
+        cache_dir.path().to_path_buf(),
+        AllLlmsBackend::Replay,
+    )
+    .unwrap();
+    with_seed(AlienSeed::new(0), || {
+        assert!(matches!(
+            client.generate_text(AllLlmModel::GEMINI_1_5_FLASH, "hello".to_string()),
+            Err(AnyLlmError::Gemini(GeminiError::DiskCache(
+                DiskCacheError::ReplayMiss { .. }
+            )))
+        ));
+        assert!(matches!(
+            client.generate_text(AllLlmModel::GPT_4O, "hello".to_string()),
+            Err(AnyLlmError::OpenAi(OpenaiError::DiskCache(
+                DiskCacheError::ReplayMiss { .. }
+            )))
+        ));
+    })
+}
+
+#[test]
+fn all_llms_client_generate_structured_works() {
+    #[derive(Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+    struct Answer {
+        character: String,
+        reasons: Vec<String>,
+    }
+
+    let db = &EternerDb::default();
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let mock = AllLlmsMock::default()
+        .with_contains(
+            "is invalid",
+            r#"```json
+{"character": "Soifon", "reasons": ["Yoruichi"]}
+```"#,
+        )
+        .with_contains("Soifon", "Because of Yoruichi.");
+    let client = AllLlmsClient::new_with_backend(
+        db,
+        tokio_runtime,
+        PathBuf::from("caches/does-not-exist"),
+        AllLlmsBackend::Mock(mock),
+    )
+    .unwrap();
+    let answer: Answer = client
+        .generate_structured(
+            AllLlmModel::GPT_4O,
+            &[LlmMessage::user("Why does Soifon hate Urahara?")],
+        )
+        .unwrap();
+    assert_eq!(
+        answer,
+        Answer {
+            character: "Soifon".to_string(),
+            reasons: vec!["Yoruichi".to_string()],
+        }
+    );
+    assert!(matches!(
+        client.generate_structured::<Answer>(AllLlmModel::GPT_4O, &[LlmMessage::user("ping")]),
+        Err(AnyLlmError::MockMiss { .. })
+    ));
+}
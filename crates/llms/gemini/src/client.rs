@@ -1,13 +1,21 @@
-mod free;
+mod call;
 mod meta;
-mod paid;
 
 use self::meta::*;
 use crate::{request::GeminiRawRequest, response::GeminiRawResponse, *};
 use alien_seed::{attach::attached_seed, AlienSeed};
-use disk_cache::DiskCache;
+use disk_cache::{mode::DiskCacheMode, DiskCache};
 use enum_index::full_map::EnumFullVecMap;
 use eterned::db::EternerDb;
+use llm_prelude::{
+    message::LlmMessage,
+    middleware::{
+        rate_limit::LlmRateLimit,
+        retry::LlmRetryPolicy,
+        usage::{LlmTokenUsage, LlmUsageReport},
+        LlmMiddleware,
+    },
+};
 use model::GeminiModel;
 use request::GeminiRequest;
 use reqwest::Client;
@@ -21,6 +29,14 @@ use usage_cap::UsageCap;
 
 const DEFAULT_RETRY_DELAY_ON_FREE: std::time::Duration = std::time::Duration::from_secs(30);
 const DEFAULT_RETRY_DELAY_ON_PAID: std::time::Duration = std::time::Duration::from_secs(10);
+const DEFAULT_RATE_LIMIT_ON_FREE: LlmRateLimit = LlmRateLimit {
+    max_concurrency: 1,
+    requests_per_minute: Some(15),
+};
+const DEFAULT_RATE_LIMIT_ON_PAID: LlmRateLimit = LlmRateLimit {
+    max_concurrency: 8,
+    requests_per_minute: None,
+};
 
 pub struct GeminiClient<'db> {
     caches: EnumFullVecMap<
@@ -30,8 +46,7 @@ pub struct GeminiClient<'db> {
     /// `None` means disabled
     meta: Option<GeminiClientMeta>,
     client: Client,
-    retry_delay_on_free: std::time::Duration,
-    retry_delay_on_paid: std::time::Duration,
+    middleware: LlmMiddleware,
 }
 
 impl<'db> GeminiClient<'db> {
@@ -39,26 +54,45 @@ impl<'db> GeminiClient<'db> {
         db: &'db EternerDb,
         tokio_runtime: Arc<tokio::runtime::Runtime>,
         cache_dir: &Path,
+    ) -> GeminiResult<Self> {
+        Self::new_with_cache_mode(db, tokio_runtime, cache_dir, DiskCacheMode::ReadWrite)
+    }
+
+    pub fn new_with_cache_mode(
+        db: &'db EternerDb,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        cache_dir: &Path,
+        cache_mode: DiskCacheMode,
     ) -> GeminiResult<Self> {
         let meta = GeminiClientMeta::new()?;
         let caches = EnumFullVecMap::try_new(|model: GeminiModel| {
             if !cache_dir.is_dir() {
                 return Err(GeminiError::InvalidCacheDir(cache_dir.to_owned()));
             }
-            DiskCache::new(
+            DiskCache::new_with_mode(
                 db,
                 tokio_runtime.clone(),
                 cache_dir.join(format!("{}.json", model.as_str())),
+                cache_mode,
             )
             .map_err(Into::into)
         })?;
 
+        let (retry_delay, rate_limit) = match meta.as_ref().map(|meta| meta.tier) {
+            Some(GeminiTier::Free) | None => {
+                (DEFAULT_RETRY_DELAY_ON_FREE, DEFAULT_RATE_LIMIT_ON_FREE)
+            }
+            Some(GeminiTier::Paid) => (DEFAULT_RETRY_DELAY_ON_PAID, DEFAULT_RATE_LIMIT_ON_PAID),
+        };
         Ok(Self {
             caches,
             meta,
             client: Client::new(),
-            retry_delay_on_free: DEFAULT_RETRY_DELAY_ON_FREE,
-            retry_delay_on_paid: DEFAULT_RETRY_DELAY_ON_PAID,
+            middleware: LlmMiddleware::new(
+                "gemini",
+                LlmRetryPolicy::default().with_initial_delay(retry_delay),
+                rate_limit,
+            )?,
         })
     }
 }
@@ -75,6 +109,11 @@ impl<'db> GeminiClient<'db> {
     pub fn tier(&self) -> Option<GeminiTier> {
         self.meta.as_ref().map(|meta| meta.tier)
     }
+
+    /// usage of the requests that missed the cache
+    pub fn usage(&self) -> LlmUsageReport {
+        self.middleware.usage()
+    }
 }
 
 impl<'db> GeminiClient<'db> {
@@ -93,6 +132,12 @@ impl<'db> GeminiClient<'db> {
         }
     }
 
+    pub fn chat(&self, model: GeminiModel, messages: Vec<LlmMessage>) -> GeminiResult<String> {
+        match self.generate(model, GeminiRequest::Chat { messages })? {
+            GeminiResponse::TextGeneration { text } => Ok(text),
+        }
+    }
+
     pub fn generate(
         &self,
         model: GeminiModel,
@@ -103,29 +148,34 @@ impl<'db> GeminiClient<'db> {
             attached_seed(),
             request,
             async |request| -> GeminiResult<GeminiResponse> {
-                match try_call_gemini::<GeminiResult<GeminiResponse>>(
-                    min_usage,
-                    async || -> (usize, GeminiResult<GeminiResponse>) {
-                        let Some(tier) = self.tier() else {
-                            return (0, Err(GeminiError::GeminiDisabled));
-                        };
-                        match tier {
-                            GeminiTier::Free => self.generate_on_free_aux(model, request).await,
-                            GeminiTier::Paid => self.generate_on_paid_aux(model, request).await,
-                        }
-                    },
-                )
-                .await?
-                {
-                    Ok(result) => match result {
-                        Ok(s) => Ok(s),
-                        Err(e) => Err(e),
-                    },
-                    Err(e) => todo!(),
-                }
+                self.middleware
+                    .call(model.as_str(), model.pricing(), async || {
+                        self.generate_capped(model, request, min_usage).await
+                    })
+                    .await
             },
         )?;
 
         Ok(response)
     }
+
+    async fn generate_capped(
+        &self,
+        model: GeminiModel,
+        request: &GeminiRequest,
+        min_usage: usize,
+    ) -> GeminiResult<(GeminiResponse, LlmTokenUsage)> {
+        match try_call_gemini(min_usage, async || {
+            if self.tier().is_none() {
+                return (0, Err(GeminiError::GeminiDisabled));
+            }
+            self.generate_aux(model, request).await
+        })
+        .await?
+        {
+            Ok(result) => result,
+            // the cap ran out after the call, but the response is still good
+            Err((result, _)) => result,
+        }
+    }
 }
@@ -0,0 +1,77 @@
+use super::*;
+
+impl<'db> GeminiClient<'db> {
+    /// A single attempt; retrying is left to the middleware.
+    pub(super) async fn generate_aux(
+        &self,
+        model: GeminiModel,
+        request: &GeminiRequest,
+    ) -> (usize, GeminiResult<(GeminiResponse, LlmTokenUsage)>) {
+        let mut usage = 0;
+        let raw_request: GeminiRawRequest = request.into();
+        let api_key = match self.api_key() {
+            Some(api_key) => api_key,
+            None => return (usage, Err(GeminiError::GeminiDisabled)),
+        };
+        let response = match self
+            .client
+            .post(format!(
+                "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent?key={}",
+                model.url_name(),
+                api_key
+            ))
+            .json(&raw_request)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => return (usage, Err(e.into())),
+        };
+
+        let response_bytes = match response.bytes().await {
+            Ok(bytes) => {
+                usage += POST_CALL_USAGE_MULTIPLIER * bytes.len();
+                bytes
+            }
+            Err(e) => return (usage, Err(e.into())),
+        };
+
+        match parse_response_result(&response_bytes) {
+            Ok(resp_result) => match resp_result {
+                Ok(resp) => {
+                    usage +=
+                        POST_CALL_USAGE_MULTIPLIER * resp.candidates[0].content.parts[0].text.len();
+                    let token_usage = match resp.usage_metadata {
+                        Some(ref metadata) => LlmTokenUsage {
+                            prompt_tokens: metadata.prompt_token_count,
+                            completion_tokens: metadata.candidates_token_count,
+                        },
+                        None => {
+                            let input = match request {
+                                GeminiRequest::TextGeneration { input } => input.clone(),
+                                GeminiRequest::Chat { messages } => messages
+                                    .iter()
+                                    .map(|message| message.content.as_str())
+                                    .collect(),
+                            };
+                            LlmTokenUsage::estimate(
+                                &input,
+                                &resp.candidates[0].content.parts[0].text,
+                            )
+                        }
+                    };
+                    (usage, Ok(((resp, request).into(), token_usage)))
+                }
+                Err(e) => (
+                    usage,
+                    Err(GeminiError::ApiError {
+                        code: e.error.code,
+                        message: e.error.message,
+                        status: e.error.status,
+                    }),
+                ),
+            },
+            Err(e) => (usage, Err(e)),
+        }
+    }
+}
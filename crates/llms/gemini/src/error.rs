@@ -1,4 +1,8 @@
 use disk_cache::error::DiskCacheError;
+use llm_prelude::{
+    error::LlmError,
+    middleware::retry::{IsLlmCallError, LlmRetryHint},
+};
 use std::path::PathBuf;
 use thiserror::Error;
 use usage_cap::error::UsageCapError;
@@ -30,6 +34,29 @@ pub enum GeminiError {
     InvalidTier(String),
     #[error("Gemini is disabled")]
     GeminiDisabled,
+    #[error("{0}")]
+    Llm(#[from] LlmError),
 }
 
 pub type GeminiResult<T> = Result<T, GeminiError>;
+
+impl IsLlmCallError for GeminiError {
+    fn retry_hint(&self) -> LlmRetryHint {
+        match self {
+            GeminiError::RequestFailed(_) => LlmRetryHint::Transient,
+            GeminiError::ApiError { code, status, .. } => match status.as_str() {
+                "RESOURCE_EXHAUSTED" => LlmRetryHint::RateLimited { retry_after: None },
+                "UNAVAILABLE" | "INTERNAL" | "DEADLINE_EXCEEDED" => LlmRetryHint::Transient,
+                _ => LlmRetryHint::from_http_status(*code as u16),
+            },
+            GeminiError::DiskCache(_)
+            | GeminiError::UsageCap(_)
+            | GeminiError::ResponseParseFailed { .. }
+            | GeminiError::InvalidCacheDir(_)
+            | GeminiError::ApiKeyNotSet
+            | GeminiError::InvalidTier(_)
+            | GeminiError::GeminiDisabled
+            | GeminiError::Llm(_) => LlmRetryHint::Fatal,
+        }
+    }
+}
@@ -12,5 +12,4 @@ pub mod tier;
 use self::{
     cap::*,
     error::{GeminiError, GeminiResult},
-    raw::*,
 };
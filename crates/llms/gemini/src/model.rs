@@ -1,4 +1,5 @@
 use enum_index::IsEnumIndex;
+use llm_prelude::middleware::usage::LlmPricing;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IsEnumIndex)]
 pub enum GeminiModel {
@@ -14,6 +15,20 @@ impl GeminiModel {
         }
     }
 
+    /// list prices for prompts up to 128k tokens
+    pub fn pricing(&self) -> LlmPricing {
+        match self {
+            GeminiModel::Gemini1_5Flash => LlmPricing {
+                prompt: 0.075,
+                completion: 0.30,
+            },
+            GeminiModel::Gemini1_5Pro => LlmPricing {
+                prompt: 1.25,
+                completion: 5.00,
+            },
+        }
+    }
+
     pub fn url_name(&self) -> &'static str {
         match self {
             GeminiModel::Gemini1_5Flash => "gemini-1.5-flash",
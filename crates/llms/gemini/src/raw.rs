@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, Hash)]
 pub struct GeminiRawContent {
+    /// `user` or `model`; omitted for single-turn requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
     pub parts: Vec<GeminiRawPart>,
 }
 
@@ -9,3 +12,12 @@ pub struct GeminiRawContent {
 pub struct GeminiRawPart {
     pub text: String,
 }
+
+impl GeminiRawContent {
+    pub fn text(role: Option<&str>, text: impl Into<String>) -> Self {
+        Self {
+            role: role.map(ToString::to_string),
+            parts: vec![GeminiRawPart { text: text.into() }],
+        }
+    }
+}
@@ -1,26 +1,51 @@
-use crate::{raw::GeminiRawContent, GeminiRawPart};
+use crate::raw::GeminiRawContent;
+use llm_prelude::message::{LlmMessage, LlmRole};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
 pub struct GeminiRawRequest {
+    #[serde(rename = "systemInstruction", skip_serializing_if = "Option::is_none")]
+    pub system_instruction: Option<GeminiRawContent>,
     pub contents: Vec<GeminiRawContent>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, Hash)]
 pub enum GeminiRequest {
     TextGeneration { input: String },
+    Chat { messages: Vec<LlmMessage> },
 }
 
 impl Into<GeminiRawRequest> for &GeminiRequest {
     fn into(self) -> GeminiRawRequest {
         match self {
             GeminiRequest::TextGeneration { input } => GeminiRawRequest {
-                contents: vec![GeminiRawContent {
-                    parts: vec![GeminiRawPart {
-                        text: input.clone(),
-                    }],
-                }],
+                system_instruction: None,
+                contents: vec![GeminiRawContent::text(None, input.clone())],
             },
+            GeminiRequest::Chat { messages } => {
+                // gemini takes system messages separately
+                let system = messages
+                    .iter()
+                    .filter(|message| message.role == LlmRole::System)
+                    .map(|message| message.content.as_str())
+                    .collect::<Vec<_>>();
+                GeminiRawRequest {
+                    system_instruction: (!system.is_empty())
+                        .then(|| GeminiRawContent::text(None, system.join("\n\n"))),
+                    contents: messages
+                        .iter()
+                        .filter_map(|message| match message.role {
+                            LlmRole::System => None,
+                            LlmRole::User => {
+                                Some(GeminiRawContent::text(Some("user"), &message.content))
+                            }
+                            LlmRole::Assistant => {
+                                Some(GeminiRawContent::text(Some("model"), &message.content))
+                            }
+                        })
+                        .collect(),
+                }
+            }
         }
     }
 }
@@ -29,6 +54,9 @@ impl GeminiRequest {
     pub fn min_usage(&self) -> usize {
         match self {
             GeminiRequest::TextGeneration { input } => input.len(),
+            GeminiRequest::Chat { messages } => {
+                messages.iter().map(|message| message.content.len()).sum()
+            }
         }
     }
 }
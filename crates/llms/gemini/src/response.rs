@@ -6,6 +6,16 @@ use thiserror::Error;
 #[derive(Deserialize)]
 pub struct GeminiRawResponse {
     pub candidates: Vec<Candidate>,
+    #[serde(rename = "usageMetadata", default)]
+    pub usage_metadata: Option<GeminiRawUsageMetadata>,
+}
+
+#[derive(Deserialize)]
+pub struct GeminiRawUsageMetadata {
+    #[serde(rename = "promptTokenCount", default)]
+    pub prompt_token_count: usize,
+    #[serde(rename = "candidatesTokenCount", default)]
+    pub candidates_token_count: usize,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -16,9 +26,11 @@ pub enum GeminiResponse {
 impl From<(GeminiRawResponse, &GeminiRequest)> for GeminiResponse {
     fn from((raw, request): (GeminiRawResponse, &GeminiRequest)) -> Self {
         match request {
-            GeminiRequest::TextGeneration { .. } => GeminiResponse::TextGeneration {
-                text: raw.candidates[0].content.parts[0].text.clone(),
-            },
+            GeminiRequest::TextGeneration { .. } | GeminiRequest::Chat { .. } => {
+                GeminiResponse::TextGeneration {
+                    text: raw.candidates[0].content.parts[0].text.clone(),
+                }
+            }
         }
     }
 }
@@ -1,4 +1,13 @@
 #[derive(Debug, thiserror::Error)]
-pub enum LlmError {}
+pub enum LlmError {
+    #[error("invalid structured output after {attempts} attempts: {error}, last output: {output}")]
+    InvalidStructuredOutput {
+        attempts: usize,
+        error: String,
+        output: String,
+    },
+    #[error("invalid value `{value}` for {var_name}, must be a number")]
+    InvalidRateLimitEnvVar { var_name: String, value: String },
+}
 
 pub type LlmResult<T> = Result<T, LlmError>;
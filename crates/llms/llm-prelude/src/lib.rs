@@ -1,7 +1,10 @@
 #![feature(async_closure)]
 pub mod error;
+pub mod message;
+pub mod middleware;
 pub mod request;
 pub mod response;
+pub mod structured;
 pub mod transformation;
 
 use self::{
@@ -12,13 +15,22 @@ use self::{
 use alien_seed::{attach::attached_seed, AlienSeed};
 use attach::Attach;
 use disk_cache::{error::DiskCacheError, DiskCache};
+use message::LlmMessage;
 use request::{chat_completion::LlmChatCompletionRequest, LlmRequest};
 use response::{chat_completion::LlmChatCompletionResponse, LlmResponse};
 use serde::{Deserialize, Serialize};
+use structured::IsLlmStructuredOutput;
 
 #[sealed::sealed]
 pub trait IsLlm: IsLlmImpl {
     fn chat_completion(&self, prompt: &str) -> Result<Self::ChatCompletionResponse, Self::Error>;
+    fn chat(&self, messages: &[LlmMessage]) -> Result<Self::ChatCompletionResponse, Self::Error>;
+    /// re-asks up to `max_reasks` times if the response doesn't conform to the schema of `O`
+    fn structured_chat<O: IsLlmStructuredOutput>(
+        &self,
+        messages: &[LlmMessage],
+        max_reasks: usize,
+    ) -> Result<O, Self::Error>;
     // TODO: what's the difference between chat_completion_with_caching and chat_completion_without_caching?
     fn chat_completion_without_caching(
         &self,
@@ -36,6 +48,7 @@ pub trait IsLlmImpl {
         + Into<Self::Request>;
     type ChatCompletionResponse: TryFrom<Self::Response>
         + From<LlmChatCompletionResponse>
+        + Into<LlmChatCompletionResponse>
         + Into<Self::Response>;
 
     fn cache(&self) -> &DiskCache<Self::Db, AlienSeed, Self::Request, Self::Response>;
@@ -48,8 +61,12 @@ pub trait IsLlmImpl {
 #[sealed::sealed]
 impl<T: IsLlmImpl> IsLlm for T {
     fn chat_completion(&self, prompt: &str) -> Result<Self::ChatCompletionResponse, Self::Error> {
+        self.chat(&[LlmMessage::user(prompt)])
+    }
+
+    fn chat(&self, messages: &[LlmMessage]) -> Result<Self::ChatCompletionResponse, Self::Error> {
         let request: Self::ChatCompletionRequest = LlmChatCompletionRequest {
-            prompt: prompt.to_string(),
+            messages: messages.to_vec(),
         }
         .into();
         self.cache()
@@ -67,6 +84,17 @@ impl<T: IsLlmImpl> IsLlm for T {
             })
     }
 
+    fn structured_chat<O: IsLlmStructuredOutput>(
+        &self,
+        messages: &[LlmMessage],
+        max_reasks: usize,
+    ) -> Result<O, Self::Error> {
+        structured::generate_structured(messages, max_reasks, |messages| {
+            let response: LlmChatCompletionResponse = self.chat(messages)?.into();
+            Ok(response.content)
+        })
+    }
+
     fn chat_completion_without_caching(
         &self,
         prompt: &str,
@@ -0,0 +1,108 @@
+//! Shared layer around remote LLM calls: retries with exponential backoff and jitter,
+//! per-provider concurrency and rate limits, and token/cost accounting.
+pub mod rate_limit;
+pub mod retry;
+#[cfg(test)]
+mod tests;
+pub mod usage;
+
+use self::{
+    rate_limit::{provider_rate_limiter, LlmRateLimit, LlmRateLimiter},
+    retry::{IsLlmCallError, LlmRetryHint, LlmRetryPolicy},
+    usage::{LlmPricing, LlmTokenUsage, LlmUsageReport},
+};
+use crate::error::LlmResult;
+use std::sync::{Arc, Mutex};
+
+/// Each client owns one middleware, so the usage recorded here is the usage of that client,
+/// while the rate limiter is shared by all clients of the same provider in the process.
+pub struct LlmMiddleware {
+    provider: &'static str,
+    retry_policy: LlmRetryPolicy,
+    rate_limiter: Arc<LlmRateLimiter>,
+    usage: Mutex<LlmUsageReport>,
+}
+
+impl LlmMiddleware {
+    /// `provider` also names the environment variables overriding `default_rate_limit`,
+    /// see [`LlmRateLimit::from_env`].
+    pub fn new(
+        provider: &'static str,
+        retry_policy: LlmRetryPolicy,
+        default_rate_limit: LlmRateLimit,
+    ) -> LlmResult<Self> {
+        Ok(Self {
+            provider,
+            retry_policy,
+            rate_limiter: provider_rate_limiter(
+                provider,
+                LlmRateLimit::from_env(provider, default_rate_limit)?,
+            ),
+            usage: Default::default(),
+        })
+    }
+}
+
+/// # getters
+impl LlmMiddleware {
+    pub fn provider(&self) -> &'static str {
+        self.provider
+    }
+
+    pub fn retry_policy(&self) -> &LlmRetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn usage(&self) -> LlmUsageReport {
+        self.usage.lock().unwrap().clone()
+    }
+}
+
+impl LlmMiddleware {
+    /// Calls `f` until it succeeds, it fails with a fatal error, or the retries run out.
+    ///
+    /// Every attempt waits for the provider's rate limiter first.
+    pub async fn call<R, E>(
+        &self,
+        model: &str,
+        pricing: LlmPricing,
+        mut f: impl async FnMut() -> Result<(R, LlmTokenUsage), E>,
+    ) -> Result<R, E>
+    where
+        E: IsLlmCallError + std::fmt::Display,
+    {
+        let mut attempt = 0;
+        loop {
+            let result = {
+                let _permit = self.rate_limiter.acquire().await;
+                f().await
+            };
+            let e = match result {
+                Ok((r, token_usage)) => {
+                    self.usage
+                        .lock()
+                        .unwrap()
+                        .record(model, token_usage, pricing);
+                    return Ok(r);
+                }
+                Err(e) => e,
+            };
+            self.usage.lock().unwrap().record_failure(model);
+            let retry_after = match e.retry_hint() {
+                LlmRetryHint::Fatal => return Err(e),
+                LlmRetryHint::Transient => None,
+                LlmRetryHint::RateLimited { retry_after } => retry_after,
+            };
+            if attempt >= self.retry_policy.max_retries {
+                return Err(e);
+            }
+            let delay = self.retry_policy.delay(attempt, retry_after);
+            tracing::info!(
+                "{} call to `{model}` failed: {e}, retrying in {delay:?}...",
+                self.provider
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::{LlmError, LlmResult};
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LlmRateLimit {
+    pub max_concurrency: usize,
+    /// `None` means unlimited
+    pub requests_per_minute: Option<u32>,
+}
+
+impl LlmRateLimit {
+    /// Overrides `default` with `HUSKY_{PROVIDER}_MAX_CONCURRENCY` and `HUSKY_{PROVIDER}_REQUESTS_PER_MINUTE`.
+    pub fn from_env(provider: &str, default: Self) -> LlmResult<Self> {
+        fn var<T: std::str::FromStr>(provider: &str, name: &str) -> LlmResult<Option<T>> {
+            let var_name = format!("HUSKY_{}_{name}", provider.to_uppercase());
+            let Ok(value) = std::env::var(&var_name) else {
+                return Ok(None);
+            };
+            match value.parse() {
+                Ok(n) => Ok(Some(n)),
+                Err(_) => Err(LlmError::InvalidRateLimitEnvVar { var_name, value }),
+            }
+        }
+
+        Ok(Self {
+            max_concurrency: var(provider, "MAX_CONCURRENCY")?.unwrap_or(default.max_concurrency),
+            requests_per_minute: var(provider, "REQUESTS_PER_MINUTE")?
+                .or(default.requests_per_minute),
+        })
+    }
+}
+
+pub struct LlmRateLimiter {
+    semaphore: Semaphore,
+    /// minimal interval between the starts of two requests
+    min_interval: Option<Duration>,
+    next_start: Mutex<Option<Instant>>,
+}
+
+impl LlmRateLimiter {
+    pub fn new(rate_limit: LlmRateLimit) -> Self {
+        Self {
+            semaphore: Semaphore::new(rate_limit.max_concurrency.max(1)),
+            min_interval: rate_limit
+                .requests_per_minute
+                .map(|n| Duration::from_secs(60) / n.max(1)),
+            next_start: Mutex::new(None),
+        }
+    }
+
+    /// Waits for a free slot; the request counts as running until the permit is dropped.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        let permit = self.semaphore.acquire().await.unwrap();
+        if let Some(min_interval) = self.min_interval {
+            let mut next_start = self.next_start.lock().await;
+            let now = Instant::now();
+            let start = match *next_start {
+                Some(next_start) if next_start > now => next_start,
+                _ => now,
+            };
+            *next_start = Some(start + min_interval);
+            drop(next_start);
+            tokio::time::sleep_until(start).await;
+        }
+        permit
+    }
+}
+
+/// The rate limiter shared by all clients of `provider` in this process.
+///
+/// The limit is fixed by whichever client asks first.
+pub fn provider_rate_limiter(
+    provider: &'static str,
+    rate_limit: LlmRateLimit,
+) -> Arc<LlmRateLimiter> {
+    lazy_static! {
+        static ref RATE_LIMITERS: DashMap<&'static str, Arc<LlmRateLimiter>> = Default::default();
+    }
+
+    RATE_LIMITERS
+        .entry(provider)
+        .or_insert_with(|| Arc::new(LlmRateLimiter::new(rate_limit)))
+        .clone()
+}
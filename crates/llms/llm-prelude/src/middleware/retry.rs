@@ -0,0 +1,80 @@
+use rand::Rng;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LlmRetryPolicy {
+    /// number of attempts after the first one
+    pub max_retries: usize,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for LlmRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl LlmRetryPolicy {
+    pub const NO_RETRY: Self = Self {
+        max_retries: 0,
+        initial_delay: Duration::ZERO,
+        max_delay: Duration::ZERO,
+        multiplier: 1.0,
+    };
+
+    pub fn with_initial_delay(self, initial_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            max_delay: self.max_delay.max(initial_delay),
+            ..self
+        }
+    }
+
+    /// The backoff for the `attempt`-th retry (starting from zero), randomized in `[backoff / 2, backoff]`
+    /// so that clients failing together don't retry together.
+    ///
+    /// A delay requested by the provider is always respected.
+    pub fn delay(&self, attempt: usize, retry_after: Option<Duration>) -> Duration {
+        let backoff = self
+            .initial_delay
+            .mul_f64(self.multiplier.powi(attempt.min(i32::MAX as usize) as i32))
+            .min(self.max_delay);
+        let jittered = backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0));
+        match retry_after {
+            Some(retry_after) => jittered.max(retry_after),
+            None => jittered,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlmRetryHint {
+    /// retrying won't help, e.g., a missing API key or a malformed request
+    Fatal,
+    /// network errors, server errors, overloaded servers
+    Transient,
+    /// the provider asked us to slow down
+    RateLimited { retry_after: Option<Duration> },
+}
+
+pub trait IsLlmCallError {
+    fn retry_hint(&self) -> LlmRetryHint;
+}
+
+impl LlmRetryHint {
+    /// the usual interpretation of an HTTP status code
+    pub fn from_http_status(status: u16) -> Self {
+        match status {
+            429 => LlmRetryHint::RateLimited { retry_after: None },
+            408 | 500..=599 => LlmRetryHint::Transient,
+            _ => LlmRetryHint::Fatal,
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[derive(Debug)]
+enum MockError {
+    Transient,
+    Fatal,
+}
+
+impl std::fmt::Display for MockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl IsLlmCallError for MockError {
+    fn retry_hint(&self) -> LlmRetryHint {
+        match self {
+            MockError::Transient => LlmRetryHint::Transient,
+            MockError::Fatal => LlmRetryHint::Fatal,
+        }
+    }
+}
+
+const FAST_RETRY: LlmRetryPolicy = LlmRetryPolicy {
+    max_retries: 3,
+    initial_delay: Duration::from_millis(1),
+    max_delay: Duration::from_millis(4),
+    multiplier: 2.0,
+};
+
+const UNLIMITED: LlmRateLimit = LlmRateLimit {
+    max_concurrency: 64,
+    requests_per_minute: None,
+};
+
+const PRICING: LlmPricing = LlmPricing {
+    prompt: 1.0,
+    completion: 2.0,
+};
+
+#[test]
+fn llm_retry_policy_delay_works() {
+    let policy = LlmRetryPolicy {
+        max_retries: 10,
+        initial_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(8),
+        multiplier: 2.0,
+    };
+    for (attempt, backoff) in [(0, 1), (1, 2), (2, 4), (3, 8), (9, 8)] {
+        let backoff = Duration::from_secs(backoff);
+        let delay = policy.delay(attempt, None);
+        assert!(
+            backoff / 2 <= delay && delay <= backoff,
+            "{attempt}: {delay:?}"
+        );
+    }
+    assert!(policy.delay(0, Some(Duration::from_secs(30))) >= Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn llm_middleware_retries_transient_errors() {
+    let middleware = LlmMiddleware::new("mock-transient", FAST_RETRY, UNLIMITED).unwrap();
+    let attempts = AtomicUsize::new(0);
+    let result = middleware
+        .call("mock", PRICING, async || {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(MockError::Transient),
+                _ => Ok((
+                    "ok",
+                    LlmTokenUsage {
+                        prompt_tokens: 1_000_000,
+                        completion_tokens: 500_000,
+                    },
+                )),
+            }
+        })
+        .await;
+    assert_eq!(result.unwrap(), "ok");
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    let usage = middleware.usage().total();
+    assert_eq!(usage.requests, 1);
+    assert_eq!(usage.failed_requests, 2);
+    assert_eq!(usage.cost, 2.0);
+}
+
+#[tokio::test]
+async fn llm_middleware_gives_up() {
+    let middleware = LlmMiddleware::new("mock-give-up", FAST_RETRY, UNLIMITED).unwrap();
+    let attempts = AtomicUsize::new(0);
+    let result = middleware
+        .call("mock", PRICING, async || -> Result<((), _), _> {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(MockError::Fatal)
+        })
+        .await;
+    assert!(matches!(result, Err(MockError::Fatal)));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    let result = middleware
+        .call("mock", PRICING, async || -> Result<((), _), _> {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(MockError::Transient)
+        })
+        .await;
+    assert!(matches!(result, Err(MockError::Transient)));
+    assert_eq!(
+        attempts.load(Ordering::SeqCst),
+        1 + 1 + FAST_RETRY.max_retries
+    );
+}
+
+#[tokio::test]
+async fn llm_rate_limiter_works() {
+    let limiter = LlmRateLimiter::new(LlmRateLimit {
+        max_concurrency: 2,
+        requests_per_minute: Some(600),
+    });
+    let running = AtomicUsize::new(0);
+    let max_running = AtomicUsize::new(0);
+    let start = tokio::time::Instant::now();
+    let run = async || {
+        let _permit = limiter.acquire().await;
+        let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+        max_running.fetch_max(n, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        running.fetch_sub(1, Ordering::SeqCst);
+    };
+    tokio::join!(run(), run(), run(), run());
+    assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    // 600 requests per minute means the fourth one starts at least 300ms after the first
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[test]
+fn llm_rate_limit_from_env_rejects_invalid_numbers() {
+    std::env::set_var("HUSKY_MOCK-INVALID-ENV_MAX_CONCURRENCY", "many");
+    assert!(matches!(
+        LlmRateLimit::from_env("mock-invalid-env", UNLIMITED),
+        Err(crate::error::LlmError::InvalidRateLimitEnvVar { .. })
+    ));
+    assert!(LlmMiddleware::new("mock-invalid-env", FAST_RETRY, UNLIMITED).is_err());
+    std::env::set_var("HUSKY_MOCK-INVALID-ENV_MAX_CONCURRENCY", "3");
+    assert_eq!(
+        LlmRateLimit::from_env("mock-invalid-env", UNLIMITED)
+            .unwrap()
+            .max_concurrency,
+        3
+    );
+}
+
+#[test]
+fn llm_usage_report_accumulates_into_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("usage.json");
+    let mut report = LlmUsageReport::default();
+    report.record("a", LlmTokenUsage::estimate("abcd", "abcdefgh"), PRICING);
+    report.record_failure("b");
+    report.accumulate_into_file(&path).unwrap();
+    report.accumulate_into_file(&path).unwrap();
+    let stored: LlmUsageReport =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(
+        stored.models()["a"],
+        usage::LlmUsage {
+            requests: 2,
+            failed_requests: 0,
+            prompt_tokens: 2,
+            completion_tokens: 4,
+            cost: 10.0 / 1_000_000.0,
+        }
+    );
+    assert_eq!(stored.models()["b"].failed_requests, 2);
+}
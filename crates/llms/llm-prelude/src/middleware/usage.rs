@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LlmTokenUsage {
+    pub prompt_tokens: usize,
+    pub completion_tokens: usize,
+}
+
+impl LlmTokenUsage {
+    /// A rough estimate of four bytes per token, for providers that don't report usage.
+    pub fn estimate(prompt: &str, completion: &str) -> Self {
+        Self {
+            prompt_tokens: prompt.len().div_ceil(4),
+            completion_tokens: completion.len().div_ceil(4),
+        }
+    }
+}
+
+/// Prices in US dollars per million tokens.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LlmPricing {
+    pub prompt: f64,
+    pub completion: f64,
+}
+
+impl LlmPricing {
+    /// for self-hosted models
+    pub const FREE: Self = Self {
+        prompt: 0.0,
+        completion: 0.0,
+    };
+
+    pub fn cost(self, token_usage: LlmTokenUsage) -> f64 {
+        (token_usage.prompt_tokens as f64 * self.prompt
+            + token_usage.completion_tokens as f64 * self.completion)
+            / 1_000_000.0
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LlmUsage {
+    pub requests: usize,
+    pub failed_requests: usize,
+    pub prompt_tokens: usize,
+    pub completion_tokens: usize,
+    /// estimated, in US dollars
+    pub cost: f64,
+}
+
+impl std::ops::AddAssign for LlmUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.requests += other.requests;
+        self.failed_requests += other.failed_requests;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.cost += other.cost;
+    }
+}
+
+/// Usage per model, keyed by model name.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LlmUsageReport {
+    models: BTreeMap<String, LlmUsage>,
+}
+
+impl LlmUsageReport {
+    pub fn models(&self) -> &BTreeMap<String, LlmUsage> {
+        &self.models
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+
+    pub fn total(&self) -> LlmUsage {
+        let mut total = LlmUsage::default();
+        for &usage in self.models.values() {
+            total += usage;
+        }
+        total
+    }
+}
+
+impl LlmUsageReport {
+    pub fn record(&mut self, model: &str, token_usage: LlmTokenUsage, pricing: LlmPricing) {
+        *self.models.entry(model.to_string()).or_default() += LlmUsage {
+            requests: 1,
+            failed_requests: 0,
+            prompt_tokens: token_usage.prompt_tokens,
+            completion_tokens: token_usage.completion_tokens,
+            cost: pricing.cost(token_usage),
+        };
+    }
+
+    pub fn record_failure(&mut self, model: &str) {
+        self.models
+            .entry(model.to_string())
+            .or_default()
+            .failed_requests += 1;
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for (model, &usage) in &other.models {
+            *self.models.entry(model.clone()).or_default() += usage;
+        }
+    }
+
+    /// Adds this report to the one stored at `path`, so that the file accumulates over runs.
+    pub fn accumulate_into_file(&self, path: &Path) -> std::io::Result<()> {
+        let mut report = Self::default();
+        if path.exists() {
+            let contents = std::fs::read_to_string(path)?;
+            report = serde_json::from_str(&contents)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        }
+        report.merge(self);
+        std::fs::write(path, serde_json::to_string_pretty(&report).unwrap())
+    }
+}
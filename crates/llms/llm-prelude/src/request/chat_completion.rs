@@ -1,8 +1,9 @@
 use super::*;
+use crate::message::LlmMessage;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct LlmChatCompletionRequest {
-    pub prompt: String,
+    pub messages: Vec<LlmMessage>,
 }
 
 impl IsLlmRequest for LlmChatCompletionRequest {
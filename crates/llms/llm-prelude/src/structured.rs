@@ -0,0 +1,86 @@
+//! Structured output: the response is asked to be JSON conforming to a schema derived from a Rust type,
+//! validated by deserializing into that type, and re-asked with the error when invalid.
+#[cfg(test)]
+mod tests;
+
+use crate::{error::LlmError, message::LlmMessage};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+
+pub const DEFAULT_MAX_REASKS: usize = 2;
+
+pub trait IsLlmStructuredOutput: DeserializeOwned + JsonSchema {}
+
+impl<T: DeserializeOwned + JsonSchema> IsLlmStructuredOutput for T {}
+
+/// the JSON schema of `T`, pretty printed
+pub fn structured_output_schema<T: IsLlmStructuredOutput>() -> String {
+    serde_json::to_string_pretty(&schemars::schema_for!(T)).unwrap()
+}
+
+pub fn structured_output_instruction(schema: &str) -> String {
+    format!(
+        r#"Respond with a single JSON value conforming to the following JSON schema. Don't include any other text in any case.
+
+```json
+{schema}
+```"#
+    )
+}
+
+/// Parses the response, tolerating a surrounding markdown code block.
+pub fn parse_structured_output<T: IsLlmStructuredOutput>(output: &str) -> Result<T, String> {
+    serde_json::from_str(strip_code_block(output)).map_err(|e| e.to_string())
+}
+
+fn strip_code_block(output: &str) -> &str {
+    let output = output.trim();
+    let Some(rest) = output.strip_prefix("```") else {
+        return output;
+    };
+    let rest = rest.strip_prefix("json").unwrap_or(rest);
+    rest.strip_suffix("```").unwrap_or(rest).trim()
+}
+
+/// Drives a structured conversation.
+///
+/// `chat` sends the messages and returns the content of the reply.
+/// The schema instruction is prepended as a system message;
+/// each invalid reply is followed by a user message pointing out the error, up to `max_reasks` times.
+/// Every attempt is a distinct message list, so each one is cached on its own.
+pub fn generate_structured<T, E>(
+    messages: &[LlmMessage],
+    max_reasks: usize,
+    mut chat: impl FnMut(&[LlmMessage]) -> Result<String, E>,
+) -> Result<T, E>
+where
+    T: IsLlmStructuredOutput,
+    E: From<LlmError>,
+{
+    let schema = structured_output_schema::<T>();
+    let mut messages: Vec<LlmMessage> =
+        std::iter::once(LlmMessage::system(structured_output_instruction(&schema)))
+            .chain(messages.iter().cloned())
+            .collect();
+    let mut attempt = 0;
+    loop {
+        let output = chat(&messages)?;
+        let error = match parse_structured_output(&output) {
+            Ok(t) => return Ok(t),
+            Err(error) => error,
+        };
+        if attempt >= max_reasks {
+            return Err(LlmError::InvalidStructuredOutput {
+                attempts: attempt + 1,
+                error,
+                output,
+            }
+            .into());
+        }
+        messages.push(LlmMessage::assistant(output));
+        messages.push(LlmMessage::user(format!(
+            "The response above is invalid: {error}. Respond again with only the JSON value conforming to the schema."
+        )));
+        attempt += 1;
+    }
+}
@@ -0,0 +1,62 @@
+use super::*;
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+struct Sketch {
+    goal: String,
+    steps: Vec<String>,
+}
+
+#[test]
+fn parse_structured_output_works() {
+    let expected = Sketch {
+        goal: "x = 1".to_string(),
+        steps: vec!["linarith".to_string()],
+    };
+    let json = r#"{"goal": "x = 1", "steps": ["linarith"]}"#;
+    assert_eq!(parse_structured_output::<Sketch>(json), Ok(expected));
+    let fenced = format!("```json\n{json}\n```");
+    assert!(parse_structured_output::<Sketch>(&fenced).is_ok());
+    assert!(parse_structured_output::<Sketch>(r#"{"goal": "x = 1"}"#).is_err());
+}
+
+#[test]
+fn generate_structured_reasks_on_invalid_output() {
+    let mut transcripts = vec![];
+    let sketch: Sketch = generate_structured::<_, LlmError>(
+        &[LlmMessage::user("prove x = 1")],
+        DEFAULT_MAX_REASKS,
+        |messages| {
+            transcripts.push(messages.to_vec());
+            Ok(match transcripts.len() {
+                1 => "Sure! Here's the sketch.".to_string(),
+                _ => r#"{"goal": "x = 1", "steps": []}"#.to_string(),
+            })
+        },
+    )
+    .unwrap();
+    assert_eq!(sketch.goal, "x = 1");
+    assert_eq!(transcripts.len(), 2);
+    // system instruction, the question, the invalid reply and the complaint
+    assert_eq!(transcripts[1].len(), 4);
+    assert!(transcripts[1][0].content.contains("\"steps\""));
+    assert_eq!(
+        transcripts[1][2],
+        LlmMessage::assistant("Sure! Here's the sketch.")
+    );
+}
+
+#[test]
+fn generate_structured_gives_up() {
+    let mut attempts = 0;
+    let result =
+        generate_structured::<Sketch, LlmError>(&[LlmMessage::user("prove x = 1")], 1, |_| {
+            attempts += 1;
+            Ok("no".to_string())
+        });
+    assert!(matches!(
+        result,
+        Err(LlmError::InvalidStructuredOutput { attempts: 2, .. })
+    ));
+    assert_eq!(attempts, 2);
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests;
 
+use crate::message::LlmMessage;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -36,7 +37,19 @@ pub enum LlmStringTransformationInstruction {
 
 impl<Model> LlmStringTransformation<Model> {
     pub fn prompt(&self, input: &str) -> String {
-        let mut prompt = self.instruction.prompt(input);
+        self.with_examples(self.instruction.prompt(input))
+    }
+
+    /// The same task as a conversation: the instructions and examples go to the system message,
+    /// the input is the user message.
+    pub fn messages(&self, input: &str) -> Vec<LlmMessage> {
+        vec![
+            LlmMessage::system(self.with_examples(self.instruction.system_prompt())),
+            LlmMessage::user(input),
+        ]
+    }
+
+    fn with_examples(&self, mut prompt: String) -> String {
         if self.examples.len() > 0 {
             prompt += r#"
 
@@ -95,7 +108,32 @@ impl LlmStringTransformationInstruction {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl LlmStringTransformationInstruction {
+    pub fn system_prompt(&self) -> String {
+        match self {
+            LlmStringTransformationInstruction::MainInputSide { main, side } => {
+                let mut prompt = format!(
+                    r#"You're doing some edits on user provided inputs. You will be given instructions here and input as the user message. You should only return the edited input. Don't include any other text in any case.
+
+----- MAIN INSTRUCTIONS -----
+{main}
+"#
+                );
+                if let Some(side) = side {
+                    prompt += &format!(
+                        r#"
+----- SIDE INSTRUCTIONS -----
+{side}
+"#
+                    );
+                }
+                prompt
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LlmStringTransformationRecord<Model> {
     pub transformation: LlmStringTransformation<Model>,
     pub input: String,
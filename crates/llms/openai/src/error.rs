@@ -1,5 +1,9 @@
 use crate::*;
 use disk_cache::error::DiskCacheError;
+use llm_prelude::{
+    error::LlmError,
+    middleware::retry::{IsLlmCallError, LlmRetryHint},
+};
 use usage_cap::error::UsageCapError;
 
 #[derive(Debug, thiserror::Error)]
@@ -14,8 +18,31 @@ pub enum OpenaiError {
     Ext(#[from] Box<dyn std::error::Error>),
     #[error("No choices returned")]
     NoChoicesReturned,
-    #[error("OpenAI chat completion error")]
-    ExtChatCompletion,
+    #[error("OpenAI chat completion error: {0}")]
+    ExtChatCompletion(String),
+    #[error("{0}")]
+    Llm(#[from] LlmError),
 }
 
 pub type OpenaiResult<T> = Result<T, OpenaiError>;
+
+impl IsLlmCallError for OpenaiError {
+    fn retry_hint(&self) -> LlmRetryHint {
+        match self {
+            // API errors are reported as `"{status}: {body}"`
+            OpenaiError::ExtChatCompletion(message) => match message
+                .split_once(':')
+                .and_then(|(status, _)| status.trim().parse::<u16>().ok())
+            {
+                Some(status) => LlmRetryHint::from_http_status(status),
+                None => LlmRetryHint::Transient,
+            },
+            OpenaiError::NoChoicesReturned => LlmRetryHint::Transient,
+            OpenaiError::DiskCache(_)
+            | OpenaiError::UsageCap(_)
+            | OpenaiError::EnvApiKeyNotSet
+            | OpenaiError::Ext(_)
+            | OpenaiError::Llm(_) => LlmRetryHint::Fatal,
+        }
+    }
+}
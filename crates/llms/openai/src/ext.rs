@@ -17,7 +17,7 @@ impl<'db> OpenaiClient<'db> {
         let response = client_ext
             .chat_completion(request)
             .await
-            .map_err(|_| OpenaiError::ExtChatCompletion)?;
+            .map_err(|e| OpenaiError::ExtChatCompletion(e.to_string()))?;
 
         // Extract the message content from the first choice
         Ok(response
@@ -12,10 +12,19 @@ use alien_seed::{
     AlienSeed,
 };
 use cap::try_call_openai;
-use disk_cache::DiskCache;
+use disk_cache::{mode::DiskCacheMode, DiskCache};
 use enum_index::full_map::EnumFullVecMap;
 use eterned::db::EternerDb;
 use lazy_static::lazy_static;
+use llm_prelude::{
+    message::LlmMessage,
+    middleware::{
+        rate_limit::LlmRateLimit,
+        retry::LlmRetryPolicy,
+        usage::{LlmTokenUsage, LlmUsageReport},
+        LlmMiddleware,
+    },
+};
 use model::OpenaiModel;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -31,55 +40,114 @@ pub struct OpenaiClient<'db> {
     >,
     /// None if the environment variable `OPENAI_API_KEY` is not set.
     client_ext: Option<ext::OpenAIClient>,
+    middleware: LlmMiddleware,
 }
 
+const DEFAULT_RATE_LIMIT: LlmRateLimit = LlmRateLimit {
+    max_concurrency: 8,
+    requests_per_minute: None,
+};
+
 impl<'db> OpenaiClient<'db> {
     pub fn new(
         db: &'db EternerDb,
         tokio_runtime: Arc<tokio::runtime::Runtime>,
         cache_dir: &Path,
+    ) -> OpenaiResult<Self> {
+        Self::new_with_cache_mode(db, tokio_runtime, cache_dir, DiskCacheMode::ReadWrite)
+    }
+
+    pub fn new_with_cache_mode(
+        db: &'db EternerDb,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        cache_dir: &Path,
+        cache_mode: DiskCacheMode,
     ) -> OpenaiResult<Self> {
         let api_key = std::env::var("OPENAI_API_KEY").ok();
         Ok(Self {
             caches: EnumFullVecMap::try_new(|model: OpenaiModel| {
-                DiskCache::new(
+                DiskCache::new_with_mode(
                     db,
                     tokio_runtime.clone(),
                     cache_dir.join(format!("{}.json", model.as_str())),
+                    cache_mode,
                 )
             })?,
             client_ext: match api_key {
                 Some(api_key) => Some(ext::OpenAIClient::builder().with_api_key(api_key).build()?),
                 None => None,
             },
+            middleware: LlmMiddleware::new(
+                "openai",
+                LlmRetryPolicy::default(),
+                DEFAULT_RATE_LIMIT,
+            )?,
         })
     }
 }
 
+impl<'db> OpenaiClient<'db> {
+    /// usage of the requests that missed the cache
+    pub fn usage(&self) -> LlmUsageReport {
+        self.middleware.usage()
+    }
+}
+
 impl<'db> OpenaiClient<'db> {
     pub fn generate_text(&self, model: OpenaiModel, input: String) -> OpenaiResult<String> {
-        let min_usage = input.len();
-        let request = OpenaiRequest::TextGeneration { input };
+        self.generate(model, OpenaiRequest::TextGeneration { input })
+    }
+
+    pub fn chat(&self, model: OpenaiModel, messages: Vec<LlmMessage>) -> OpenaiResult<String> {
+        self.generate(model, OpenaiRequest::Chat { messages })
+    }
+
+    fn generate(&self, model: OpenaiModel, request: OpenaiRequest) -> OpenaiResult<String> {
+        let min_usage = request.min_usage();
         let OpenaiResponse::TextGeneration(response) = self.caches[model].get_or_call(
             attached_seed(),
             request,
             async |request| -> OpenaiResult<OpenaiResponse> {
-                match try_call_openai::<OpenaiResult<String>>(min_usage, async || {
-                    self.complete_chat_aux(model, request).await
-                })
-                .await?
-                {
-                    Ok(result) => match result {
-                        Ok(s) => Ok(OpenaiResponse::TextGeneration(s)),
-                        Err(e) => Err(todo!()),
-                    },
-                    Err(e) => todo!(),
-                }
+                let content = self
+                    .middleware
+                    .call(model.as_str(), model.pricing(), async || {
+                        self.complete_chat_capped(model, request, min_usage).await
+                    })
+                    .await?;
+                Ok(OpenaiResponse::TextGeneration(content))
             },
         )?;
         Ok(response)
     }
 
+    async fn complete_chat_capped(
+        &self,
+        model: OpenaiModel,
+        request: &OpenaiRequest,
+        min_usage: usize,
+    ) -> OpenaiResult<(String, LlmTokenUsage)> {
+        let content = match try_call_openai::<OpenaiResult<String>>(min_usage, async || {
+            self.complete_chat_aux(model, request).await
+        })
+        .await?
+        {
+            Ok(result) => result?,
+            // the cap ran out after the call, but the response is still good
+            Err((result, _)) => result?,
+        };
+        let token_usage = match request {
+            OpenaiRequest::TextGeneration { input } => LlmTokenUsage::estimate(input, &content),
+            OpenaiRequest::Chat { messages } => LlmTokenUsage::estimate(
+                &messages
+                    .iter()
+                    .map(|message| message.content.as_str())
+                    .collect::<String>(),
+                &content,
+            ),
+        };
+        Ok((content, token_usage))
+    }
+
     async fn complete_chat_aux(
         &self,
         model: OpenaiModel,
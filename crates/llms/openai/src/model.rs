@@ -1,4 +1,5 @@
 use enum_index::IsEnumIndex;
+use llm_prelude::middleware::usage::LlmPricing;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IsEnumIndex)]
 #[repr(u8)]
@@ -12,4 +13,13 @@ impl OpenaiModel {
             OpenaiModel::Gpt4o => "gpt-4o",
         }
     }
+
+    pub fn pricing(&self) -> LlmPricing {
+        match self {
+            OpenaiModel::Gpt4o => LlmPricing {
+                prompt: 2.50,
+                completion: 10.00,
+            },
+        }
+    }
 }
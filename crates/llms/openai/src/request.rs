@@ -1,4 +1,5 @@
 use crate::*;
+use llm_prelude::message::{LlmMessage, LlmRole};
 use openai_api_rs::v1::chat_completion::{
     ChatCompletionMessage, ChatCompletionRequest, Content, MessageRole,
 };
@@ -6,6 +7,7 @@ use openai_api_rs::v1::chat_completion::{
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OpenaiRequest {
     TextGeneration { input: String },
+    Chat { messages: Vec<LlmMessage> },
 }
 
 pub(crate) enum OaiRequestExt {
@@ -14,19 +16,44 @@ pub(crate) enum OaiRequestExt {
 
 impl OpenaiRequest {
     pub fn ext(&self, model: OpenaiModel) -> OaiRequestExt {
-        match self {
+        let messages = match self {
             OpenaiRequest::TextGeneration { input } => {
-                OaiRequestExt::ChatCompletion(ChatCompletionRequest::new(
-                    model.as_str().to_string(),
-                    vec![ChatCompletionMessage {
-                        role: MessageRole::user,
-                        content: Content::Text(input.clone()),
-                        name: None,
-                        tool_calls: None,
-                        tool_call_id: None,
-                    }],
-                ))
+                vec![chat_completion_message(MessageRole::user, input)]
+            }
+            OpenaiRequest::Chat { messages } => messages
+                .iter()
+                .map(|message| {
+                    let role = match message.role {
+                        LlmRole::System => MessageRole::system,
+                        LlmRole::User => MessageRole::user,
+                        LlmRole::Assistant => MessageRole::assistant,
+                    };
+                    chat_completion_message(role, &message.content)
+                })
+                .collect(),
+        };
+        OaiRequestExt::ChatCompletion(ChatCompletionRequest::new(
+            model.as_str().to_string(),
+            messages,
+        ))
+    }
+
+    pub fn min_usage(&self) -> usize {
+        match self {
+            OpenaiRequest::TextGeneration { input } => input.len(),
+            OpenaiRequest::Chat { messages } => {
+                messages.iter().map(|message| message.content.len()).sum()
             }
         }
     }
 }
+
+fn chat_completion_message(role: MessageRole, content: &str) -> ChatCompletionMessage {
+    ChatCompletionMessage {
+        role,
+        content: Content::Text(content.to_string()),
+        name: None,
+        tool_calls: None,
+        tool_call_id: None,
+    }
+}
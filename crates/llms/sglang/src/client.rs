@@ -1,19 +1,44 @@
-use crate::{request::SglangRequest, response::SglangResponse, *};
-use alien_seed::AlienSeed;
-use disk_cache::DiskCache;
+use crate::{
+    raw::{SglangRawRequest, SglangRawResponse},
+    request::SglangRequest,
+    response::SglangResponse,
+    *,
+};
+use alien_seed::{attach::attached_seed, AlienSeed};
+use disk_cache::{mode::DiskCacheMode, DiskCache};
 use enum_index::full_map::EnumFullVecMap;
 use eterned::db::EternerDb;
+use llm_prelude::{
+    message::LlmMessage,
+    middleware::{
+        rate_limit::LlmRateLimit,
+        retry::LlmRetryPolicy,
+        usage::{LlmPricing, LlmTokenUsage, LlmUsageReport},
+        LlmMiddleware,
+    },
+};
 use model::SglangModel;
 use reqwest::Client;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+pub const BASE_URL_ENV_VAR: &str = "SGLANG_BASE_URL";
+/// the default address of `python -m sglang.launch_server`
+pub const DEFAULT_BASE_URL: &str = "http://127.0.0.1:30000";
+/// a local server batches requests itself, so it's only lightly throttled
+const DEFAULT_RATE_LIMIT: LlmRateLimit = LlmRateLimit {
+    max_concurrency: 16,
+    requests_per_minute: None,
+};
+
 pub struct SglangClient<'db> {
     caches: EnumFullVecMap<
         SglangModel,
         DiskCache<&'db EternerDb, AlienSeed, SglangRequest, SglangResponse>,
     >,
     client: Client,
+    base_url: String,
+    middleware: LlmMiddleware,
 }
 
 impl<'db> SglangClient<'db> {
@@ -21,6 +46,16 @@ impl<'db> SglangClient<'db> {
         db: &'db EternerDb,
         tokio_runtime: Arc<tokio::runtime::Runtime>,
         cache_dir: PathBuf,
+    ) -> SglangResult<Self> {
+        Self::new_with_cache_mode(db, tokio_runtime, cache_dir, DiskCacheMode::ReadWrite)
+    }
+
+    /// The base URL is read from `SGLANG_BASE_URL`, falling back to [`DEFAULT_BASE_URL`].
+    pub fn new_with_cache_mode(
+        db: &'db EternerDb,
+        tokio_runtime: Arc<tokio::runtime::Runtime>,
+        cache_dir: PathBuf,
+        cache_mode: DiskCacheMode,
     ) -> SglangResult<Self> {
         if !cache_dir.is_dir() {
             return Err(SglangError::InvalidCacheDir(cache_dir));
@@ -30,10 +65,136 @@ impl<'db> SglangClient<'db> {
             if !cache_dir.is_dir() {
                 return Err(SglangError::InvalidCacheDir(cache_dir.clone()));
             }
-            DiskCache::new(db, tokio_runtime.clone(), cache_dir.join(model.as_str()))
-                .map_err(Into::into)
+            DiskCache::new_with_mode(
+                db,
+                tokio_runtime.clone(),
+                cache_dir.join(model.as_str()),
+                cache_mode,
+            )
+            .map_err(Into::into)
         })?;
         let client = Client::new();
-        Ok(Self { caches, client })
+        let base_url =
+            std::env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Self {
+            caches,
+            client,
+            base_url,
+            middleware: LlmMiddleware::new("sglang", LlmRetryPolicy::default(), DEFAULT_RATE_LIMIT)?,
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+impl<'db> SglangClient<'db> {
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// usage of the requests that missed the cache
+    pub fn usage(&self) -> LlmUsageReport {
+        self.middleware.usage()
+    }
+}
+
+impl<'db> SglangClient<'db> {
+    pub fn generate_text(
+        &self,
+        model: SglangModel,
+        input: impl Into<String>,
+    ) -> SglangResult<String> {
+        match self.generate(
+            model,
+            SglangRequest::TextGeneration {
+                input: input.into(),
+            },
+        )? {
+            SglangResponse::TextGeneration { output } => Ok(output),
+        }
+    }
+
+    pub fn chat(&self, model: SglangModel, messages: Vec<LlmMessage>) -> SglangResult<String> {
+        match self.generate(model, SglangRequest::Chat { messages })? {
+            SglangResponse::TextGeneration { output } => Ok(output),
+        }
+    }
+
+    pub fn generate(
+        &self,
+        model: SglangModel,
+        request: SglangRequest,
+    ) -> SglangResult<SglangResponse> {
+        self.caches[model].get_or_call(
+            attached_seed(),
+            request,
+            async |request| -> SglangResult<SglangResponse> {
+                self.middleware
+                    .call(model.as_str(), LlmPricing::FREE, async || {
+                        self.generate_aux(model, request).await
+                    })
+                    .await
+            },
+        )
+    }
+
+    async fn generate_aux(
+        &self,
+        model: SglangModel,
+        request: &SglangRequest,
+    ) -> SglangResult<(SglangResponse, LlmTokenUsage)> {
+        let response = self
+            .client
+            .post(format!(
+                "{}/v1/chat/completions",
+                self.base_url.trim_end_matches('/')
+            ))
+            .json(&SglangRawRequest::new(model, request))
+            .send()
+            .await?;
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            return Err(SglangError::ApiError {
+                code: status.as_u16() as i32,
+                message: response_text,
+                status: status.canonical_reason().unwrap_or_default().to_string(),
+            });
+        }
+        let raw_response: SglangRawResponse =
+            serde_json::from_str(&response_text).map_err(|error| {
+                SglangError::ResponseParseFailed {
+                    error,
+                    response_text: response_text.clone(),
+                }
+            })?;
+        let Some(choice) = raw_response.choices.into_iter().next() else {
+            return Err(SglangError::NoChoicesReturned);
+        };
+        let token_usage = match raw_response.usage {
+            Some(usage) => LlmTokenUsage {
+                prompt_tokens: usage.prompt_tokens,
+                completion_tokens: usage.completion_tokens,
+            },
+            None => {
+                let input = match request {
+                    SglangRequest::TextGeneration { input } => input.clone(),
+                    SglangRequest::Chat { messages } => messages
+                        .iter()
+                        .map(|message| message.content.as_str())
+                        .collect(),
+                };
+                LlmTokenUsage::estimate(&input, &choice.message.content)
+            }
+        };
+        Ok((
+            SglangResponse::TextGeneration {
+                output: choice.message.content,
+            },
+            token_usage,
+        ))
     }
 }
@@ -1,6 +1,10 @@
 use std::path::PathBuf;
 
 use disk_cache::error::DiskCacheError;
+use llm_prelude::{
+    error::LlmError,
+    middleware::retry::{IsLlmCallError, LlmRetryHint},
+};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -20,8 +24,27 @@ pub enum SglangError {
         message: String,
         status: String,
     },
+    #[error("No choices returned")]
+    NoChoicesReturned,
     #[error("Invalid cache directory: {0}")]
     InvalidCacheDir(PathBuf),
+    #[error("{0}")]
+    Llm(#[from] LlmError),
 }
 
 pub type SglangResult<T> = Result<T, SglangError>;
+
+impl IsLlmCallError for SglangError {
+    fn retry_hint(&self) -> LlmRetryHint {
+        match self {
+            SglangError::RequestFailed(_) | SglangError::NoChoicesReturned => {
+                LlmRetryHint::Transient
+            }
+            SglangError::ApiError { code, .. } => LlmRetryHint::from_http_status(*code as u16),
+            SglangError::DiskCache(_)
+            | SglangError::ResponseParseFailed { .. }
+            | SglangError::InvalidCacheDir(_)
+            | SglangError::Llm(_) => LlmRetryHint::Fatal,
+        }
+    }
+}
@@ -1,6 +1,8 @@
+#![feature(async_closure)]
 pub mod client;
 pub mod error;
 pub mod model;
+pub mod raw;
 pub mod request;
 pub mod response;
 
@@ -4,12 +4,14 @@ use enum_index::IsEnumIndex;
 #[repr(u8)]
 pub enum SglangModel {
     Llama3_1_8bInstruct,
+    Qwen2_7bInstruct,
 }
 
 impl SglangModel {
     pub fn as_str(&self) -> &str {
         match self {
             SglangModel::Llama3_1_8bInstruct => "llama-3.1-8b-instruct",
+            SglangModel::Qwen2_7bInstruct => "qwen2-7b-instruct",
         }
     }
 }
@@ -0,0 +1,61 @@
+use crate::{model::SglangModel, request::SglangRequest, *};
+use llm_prelude::message::LlmMessage;
+
+/// Body of an OpenAI-compatible `/v1/chat/completions` request.
+#[derive(Serialize)]
+pub struct SglangRawRequest {
+    pub model: String,
+    pub messages: Vec<SglangRawMessage>,
+    pub temperature: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SglangRawMessage {
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SglangRawResponse {
+    pub choices: Vec<SglangRawChoice>,
+    #[serde(default)]
+    pub usage: Option<SglangRawUsage>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SglangRawUsage {
+    pub prompt_tokens: usize,
+    pub completion_tokens: usize,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SglangRawChoice {
+    pub message: SglangRawMessage,
+}
+
+impl SglangRawRequest {
+    pub fn new(model: SglangModel, request: &SglangRequest) -> Self {
+        let messages = match request {
+            SglangRequest::TextGeneration { input } => vec![SglangRawMessage {
+                role: "user".to_string(),
+                content: input.clone(),
+            }],
+            SglangRequest::Chat { messages } => messages.iter().map(Into::into).collect(),
+        };
+        Self {
+            model: model.as_str().to_string(),
+            messages,
+            // responses are cached per seed, so sampling is kept deterministic
+            temperature: 0.0,
+        }
+    }
+}
+
+impl From<&LlmMessage> for SglangRawMessage {
+    fn from(message: &LlmMessage) -> Self {
+        Self {
+            role: message.role.as_str().to_string(),
+            content: message.content.clone(),
+        }
+    }
+}
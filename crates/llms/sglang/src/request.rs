@@ -1,6 +1,8 @@
 use crate::*;
+use llm_prelude::message::LlmMessage;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SglangRequest {
     TextGeneration { input: String },
+    Chat { messages: Vec<LlmMessage> },
 }
@@ -57,8 +57,8 @@ where
 
     fn process_response(&mut self, response: TraceResponse<TraceProtocol>) {
         match response {
+            // also sent after the server's sources change, replacing the stale traces
             TraceResponse::Init { trace_synchrotron } => {
-                debug_assert!(self.trace_synchrotron.is_none());
                 self.trace_synchrotron = Some(trace_synchrotron)
             }
             TraceResponse::TakeTraceSynchrotronActionsDiff {
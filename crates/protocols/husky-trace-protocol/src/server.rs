@@ -178,6 +178,18 @@ impl<Tracetime: IsTracetime> TraceServer<Tracetime> {
         self.cache_peripheries()
     }
 
+    /// Starts over with the traces recomputed if the sources changed, returning whether they did.
+    fn refresh(&mut self) -> bool {
+        if !self.tracetime.refresh() {
+            return false;
+        }
+        self.trace_synchrotron = None;
+        self.value_presenter_cache = Default::default();
+        self.visual_cache = Default::default();
+        self.init();
+        true
+    }
+
     #[track_caller]
     fn trace_synchrotron(&self) -> &TraceSynchrotron<Tracetime::TraceProtocol> {
         self.trace_synchrotron.as_ref().unwrap()
@@ -213,6 +225,7 @@ but client's trace protocol is of type `{trace_protocol_type_name}`."#,
                         std::any::type_name::<Tracetime::TraceProtocol>()
                     )));
                 }
+                self.refresh();
                 let Some(trace_synchrotron) = self.trace_synchrotron.clone() else {
                     unreachable!()
                 };
@@ -225,6 +238,13 @@ but client's trace protocol is of type `{trace_protocol_type_name}`."#,
                 let Some(ref mut _cache) = self.trace_synchrotron else {
                     unreachable!()
                 };
+                // the view action was taken against the stale traces, so the client starts over
+                if self.refresh() {
+                    let Some(trace_synchrotron) = self.trace_synchrotron.clone() else {
+                        unreachable!()
+                    };
+                    return Some(TraceResponse::Init { trace_synchrotron });
+                }
                 assert_eq!(self.trace_synchrotron().status(), trace_synchrotron_status);
                 self.take_view_action(view_action);
                 let trace_synchrotron_actions_diff = self
@@ -410,6 +430,13 @@ pub trait IsTracetime: Send + 'static + Sized {
 
     type SerdeImpl: serde_impl::IsSerdeImpl;
 
+    /// Picks up source changes, returning whether there are any.
+    ///
+    /// Called before serving requests; if it returns `true`, the traces are recomputed from scratch.
+    fn refresh(&mut self) -> bool {
+        false
+    }
+
     /// final
     fn serve_traces(self, addr: impl std::fmt::Debug + ToSocketAddrs) {
         TraceServer::new(self).easy_serve(addr)
@@ -33,6 +33,7 @@ use husky_linket_impl::{
     linket_impl::{IsLinketImpl, LinketImplKiControlFlow, LinketImplTrackedExceptedValue},
     pedestal::IsPedestal,
 };
+use husky_linktime::IsLinktime;
 use husky_value::ki_control_flow::KiControlFlow;
 use husky_vfs::{error::VfsResult, path::linktime_target_path::LinktimeTargetPath};
 use husky_vm::runtime::IsVmRuntime;
@@ -122,18 +123,28 @@ impl<Devsoul: IsDevsoul> DevRuntime<Devsoul> {
     pub fn comptime(&self) -> &DevComptime<Devsoul> {
         &self.comptime
     }
-}
 
-impl<Devsoul: IsDevsoul> Default for DevRuntime<Devsoul>
-where
-    Devsoul::Linktime: Default,
-{
-    fn default() -> Self {
-        Self {
-            comptime: Default::default(),
-            storage: Default::default(),
-            config: Default::default(),
+    /// Drops the cached values and then unloads the linket libraries replaced by hot reloads.
+    ///
+    /// The caller guarantees that no value obtained from the runtime is alive,
+    /// i.e., it's called between evaluations.
+    pub unsafe fn collect_garbage(&self) {
+        self.storage.clear();
+        self.comptime.linktime().release_retired_libraries()
+    }
+
+    /// Picks up the changes made on disk to the target package since the last refresh,
+    /// dropping the cached values if any source changed.
+    ///
+    /// Returns whether any source changed.
+    pub fn refresh_from_disk(self: Pin<&mut Self>) -> VfsResult<bool> {
+        // SAFETY: nothing is moved out of `self`
+        let slf = unsafe { self.get_unchecked_mut() };
+        if !slf.comptime.refresh_from_disk()? {
+            return Ok(false);
         }
+        slf.storage.clear();
+        Ok(true)
     }
 }
 
@@ -0,0 +1,311 @@
+use crate::{
+    error::BorrowError,
+    place::{is_local_place, places_overlap, RegionalPlace},
+    region::BorrowCheckRegion,
+    state::{Access, BorrowState, Loan},
+};
+use either::Either;
+use husky_entity_path::path::major_item::ty::{PreludeIndirectionTypePath, PreludeTypePath};
+use husky_fly_term::{quary::FlyQuary, FlyBaseTypeData, FlyTerm};
+use husky_sem_expr::{SemExprData, SemExprDb, SemExprIdx, SemExprRegion, SemExprRegionData};
+use husky_sem_place_contract::region::{sem_place_contract_region, SemPlaceContractRegion};
+use husky_syn_expr::variable::CurrentVariableIdx;
+use husky_term_prelude::Contract;
+use rustc_hash::FxHashMap;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum BorrowCheckPass {
+    /// records when each variable is last used, which is when the loans it holds end
+    Liveness,
+    Check,
+}
+
+pub(crate) struct BorrowChecker<'a> {
+    db: &'a ::salsa::Db,
+    sem_expr_region: SemExprRegion,
+    /// where the places of inherited variables are registered
+    parent_sem_expr_region: Option<SemExprRegion>,
+    sem_expr_region_data: &'a SemExprRegionData,
+    sem_place_contract_region: &'a SemPlaceContractRegion,
+    pass: BorrowCheckPass,
+    /// advances with every expression visited, in evaluation order
+    time: usize,
+    last_uses: FxHashMap<CurrentVariableIdx, usize>,
+    state: BorrowState,
+    /// states at the `break`s of each enclosing loop, innermost last
+    break_states: Vec<Vec<BorrowState>>,
+    /// loans of the arguments of the last call returning a reference,
+    /// which live on in the variables the reference is bound to
+    returned_loans: Option<(SemExprIdx, Vec<Loan>)>,
+    errors: Vec<BorrowError>,
+}
+
+impl<'a> BorrowChecker<'a> {
+    pub(crate) fn new(db: &'a ::salsa::Db, sem_expr_region: SemExprRegion) -> Self {
+        Self {
+            db,
+            sem_expr_region,
+            parent_sem_expr_region: sem_expr_region
+                .syn_expr_region(db)
+                .data(db)
+                .parent()
+                .map(|parent| db.sem_expr_region(parent)),
+            sem_expr_region_data: sem_expr_region.data(db),
+            sem_place_contract_region: sem_place_contract_region(db, sem_expr_region),
+            pass: BorrowCheckPass::Liveness,
+            time: 0,
+            last_uses: Default::default(),
+            state: Default::default(),
+            break_states: vec![],
+            returned_loans: None,
+            errors: vec![],
+        }
+    }
+}
+
+/// # getters
+impl<'a> BorrowChecker<'a> {
+    pub(crate) fn db(&self) -> &'a ::salsa::Db {
+        self.db
+    }
+
+    pub(crate) fn sem_expr_region_data(&self) -> &'a SemExprRegionData {
+        self.sem_expr_region_data
+    }
+
+    pub(crate) fn sem_expr_region(&self) -> SemExprRegion {
+        self.sem_expr_region
+    }
+
+    pub(crate) fn place(&self, expr: SemExprIdx) -> Option<RegionalPlace> {
+        let place = self.quary(expr)?.place()?;
+        let region = match self.is_inherited(expr) {
+            true => self.parent_sem_expr_region?,
+            false => self.sem_expr_region,
+        };
+        Some(RegionalPlace::new(region, place))
+    }
+
+    /// whether the place of `expr` is rooted at an inherited variable
+    fn is_inherited(&self, expr: SemExprIdx) -> bool {
+        match *expr.data(self.sem_expr_region_data.sem_expr_arena()) {
+            SemExprData::InheritedVariable { .. } => true,
+            SemExprData::Field { self_argument, .. }
+            | SemExprData::Index { self_argument, .. }
+            | SemExprData::MethodRitchieCall { self_argument, .. } => {
+                self.is_inherited(self_argument)
+            }
+            SemExprData::Delimitered { item, .. } => self.is_inherited(item),
+            SemExprData::Prefix { opd, .. }
+            | SemExprData::Suffix { opd, .. }
+            | SemExprData::Unveil { opd, .. }
+            | SemExprData::Unwrap { opd, .. } => self.is_inherited(opd),
+            _ => false,
+        }
+    }
+
+    fn quary(&self, expr: SemExprIdx) -> Option<FlyQuary> {
+        expr.ty(self.sem_expr_region_data.sem_expr_arena2()).quary()
+    }
+
+    pub(crate) fn contract(&self, expr: SemExprIdx, place: RegionalPlace) -> Option<Contract> {
+        self.sem_place_contract_region.get(expr)?.get(place.place())
+    }
+
+    /// `None` if unknown
+    pub(crate) fn always_copyable(&self, expr: SemExprIdx) -> Option<bool> {
+        expr.ty(self.sem_expr_region_data.sem_expr_arena2())
+            .always_copyable(self.db, self.sem_expr_region_data.fly_term_region().terms())
+            .ok()
+            .flatten()
+    }
+
+    /// only values held directly by a variable can be moved out,
+    /// those behind references are copied or cloned
+    fn is_moved_out(&self, expr: SemExprIdx) -> bool {
+        matches!(
+            self.quary(expr),
+            Some(
+                FlyQuary::StackPure { .. }
+                    | FlyQuary::ImmutableOnStack { .. }
+                    | FlyQuary::MutableOnStack { .. }
+            )
+        ) && self.always_copyable(expr) == Some(false)
+    }
+
+    pub(crate) fn access_kind(&self, expr: SemExprIdx, contract: Contract) -> Access {
+        match contract {
+            Contract::Move if self.is_moved_out(expr) => Access::Move,
+            Contract::BorrowMut => Access::Write,
+            _ => Access::Read,
+        }
+    }
+
+    pub(crate) fn is_reference_ty(&self, ty: FlyTerm) -> bool {
+        matches!(
+            ty.base_ty_data_inner(self.db, self.sem_expr_region_data.fly_term_region().terms()),
+            FlyBaseTypeData::TypeOntology {
+                refined_ty_path: Either::Left(PreludeTypePath::Indirection(
+                    PreludeIndirectionTypePath::Ref
+                        | PreludeIndirectionTypePath::RefMut
+                        | PreludeIndirectionTypePath::At
+                )),
+                ..
+            }
+        )
+    }
+
+    fn is_loan_live(&self, loan: &Loan) -> bool {
+        match loan.holders {
+            None => true,
+            Some(holders) => holders.into_iter().any(|holder| {
+                self.last_uses
+                    .get(&holder)
+                    .is_some_and(|&last_use| last_use >= self.time)
+            }),
+        }
+    }
+}
+
+/// # actions
+impl<'a> BorrowChecker<'a> {
+    pub(crate) fn check_all(mut self) -> BorrowCheckRegion {
+        for pass in [BorrowCheckPass::Liveness, BorrowCheckPass::Check] {
+            self.pass = pass;
+            self.time = 0;
+            self.state = Default::default();
+            self.check_root_body();
+        }
+        BorrowCheckRegion::new(self.errors)
+    }
+
+    fn check_root_body(&mut self) {
+        let sem_expr_region_data = self.sem_expr_region_data;
+        let Some(root_body) = sem_expr_region_data.opt_root_body() else {
+            return;
+        };
+        match *root_body.data(sem_expr_region_data.sem_expr_arena()) {
+            SemExprData::Block { stmts } => self.check_stmts(stmts, true),
+            _ => {
+                self.check_expr(root_body);
+                self.check_escape(root_body)
+            }
+        }
+    }
+
+    pub(crate) fn tick(&mut self) {
+        self.time += 1
+    }
+
+    pub(crate) fn use_variable(&mut self, variable: CurrentVariableIdx) {
+        if self.pass == BorrowCheckPass::Liveness {
+            self.last_uses.insert(variable, self.time);
+        }
+    }
+
+    pub(crate) fn state(&self) -> &BorrowState {
+        &self.state
+    }
+
+    pub(crate) fn state_mut(&mut self) -> &mut BorrowState {
+        &mut self.state
+    }
+
+    pub(crate) fn replace_state(&mut self, state: BorrowState) -> BorrowState {
+        std::mem::replace(&mut self.state, state)
+    }
+
+    pub(crate) fn set_returned_loans(&mut self, call: SemExprIdx, loans: Vec<Loan>) {
+        self.returned_loans = Some((call, loans))
+    }
+
+    /// the loans of the arguments of `call` if it returns a reference
+    pub(crate) fn take_returned_loans(&mut self, call: SemExprIdx) -> Vec<Loan> {
+        match self.returned_loans.take() {
+            Some((returned_call, loans)) if returned_call == call => loans,
+            _ => vec![],
+        }
+    }
+
+    /// the current state flows to the end of the innermost loop instead of the next statement
+    pub(crate) fn break_loop(&mut self) {
+        if let Some(break_states) = self.break_states.last_mut() {
+            break_states.push(self.state.clone())
+        }
+        self.state.diverge()
+    }
+
+    /// runs `f` in a loop, returning the states at its `break`s
+    pub(crate) fn with_break_states(&mut self, f: impl FnOnce(&mut Self)) -> Vec<BorrowState> {
+        self.break_states.push(vec![]);
+        f(self);
+        self.break_states.pop().unwrap_or_default()
+    }
+
+    fn report(&mut self, error: BorrowError) {
+        // loop bodies are checked twice
+        if self.pass == BorrowCheckPass::Check && !self.errors.contains(&error) {
+            self.errors.push(error)
+        }
+    }
+
+    /// `via` is the variable through which the place is accessed,
+    /// which doesn't conflict with the loans it holds itself
+    pub(crate) fn access(
+        &mut self,
+        place: RegionalPlace,
+        access: Access,
+        expr: SemExprIdx,
+        via: Option<CurrentVariableIdx>,
+    ) {
+        let db = self.db;
+        if let Some(move_expr) = self.state.moved_overlapping(db, place) {
+            self.report(BorrowError::UseAfterMove {
+                place,
+                expr,
+                move_expr,
+            })
+        }
+        let conflicting_loan = self.state.loans().iter().copied().find(|loan| {
+            loan.conflicts_with(access)
+                && places_overlap(db, loan.place, place)
+                && !loan
+                    .holders
+                    .zip(via)
+                    .is_some_and(|(holders, via)| holders.contains(via))
+                && self.is_loan_live(loan)
+        });
+        if let Some(loan) = conflicting_loan {
+            self.report(BorrowError::ConflictingBorrow {
+                place,
+                expr,
+                access,
+                loan_expr: loan.expr,
+                loan_kind: loan.kind,
+            })
+        }
+        if access == Access::Move {
+            self.state.add_move(place, expr)
+        }
+    }
+
+    /// the function returns `expr`, which must not borrow from its locals
+    pub(crate) fn check_escape(&mut self, expr: SemExprIdx) {
+        let Some(return_ty) = self.sem_expr_region_data.return_ty() else {
+            return;
+        };
+        if !self.is_reference_ty(return_ty.into()) {
+            return;
+        }
+        // the reference is copied out as it is
+        if self.is_reference_ty(expr.ty(self.sem_expr_region_data.sem_expr_arena2())) {
+            return;
+        }
+        let Some(place) = self.place(expr) else {
+            return;
+        };
+        if is_local_place(self.db, self.sem_expr_region, place) {
+            self.report(BorrowError::EscapingReference { place, expr })
+        }
+    }
+}
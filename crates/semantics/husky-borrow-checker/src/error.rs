@@ -0,0 +1,45 @@
+use crate::{
+    place::RegionalPlace,
+    state::{Access, LoanKind},
+};
+use husky_sem_expr::SemExprIdx;
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BorrowError {
+    /// `place` is used by `expr` after being moved by `move_expr`
+    UseAfterMove {
+        place: RegionalPlace,
+        expr: SemExprIdx,
+        move_expr: SemExprIdx,
+    },
+    /// `place` is accessed by `expr` while a loan taken by `loan_expr` is still alive
+    ConflictingBorrow {
+        place: RegionalPlace,
+        expr: SemExprIdx,
+        access: Access,
+        loan_expr: SemExprIdx,
+        loan_kind: LoanKind,
+    },
+    /// a reference to the local `place` is returned by `expr`
+    EscapingReference { place: RegionalPlace, expr: SemExprIdx },
+}
+
+/// # getters
+impl BorrowError {
+    pub fn expr(self) -> SemExprIdx {
+        match self {
+            BorrowError::UseAfterMove { expr, .. }
+            | BorrowError::ConflictingBorrow { expr, .. }
+            | BorrowError::EscapingReference { expr, .. } => expr,
+        }
+    }
+
+    pub fn place(self) -> RegionalPlace {
+        match self {
+            BorrowError::UseAfterMove { place, .. }
+            | BorrowError::ConflictingBorrow { place, .. }
+            | BorrowError::EscapingReference { place, .. } => place,
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{
+    engine::BorrowChecker,
+    place::place_covers,
+    state::{Access, Loan, LoanKind},
+};
+use husky_fly_term::dispatch::field::FieldFlySignature;
+use husky_sem_expr::{SemExprData, SemExprIdx, SemRitchieArgument};
+use husky_sem_opr::binary::SemBinaryOpr;
+use husky_syn_expr::variable::CurrentVariableIdx;
+use husky_term_prelude::Contract;
+
+impl<'a> BorrowChecker<'a> {
+    pub(crate) fn check_expr(&mut self, expr: SemExprIdx) {
+        self.tick();
+        let sem_expr_region_data = self.sem_expr_region_data();
+        match *expr.data(sem_expr_region_data.sem_expr_arena()) {
+            SemExprData::Literal(_, _)
+            | SemExprData::Unit { .. }
+            | SemExprData::PrincipalEntityPath { .. }
+            | SemExprData::MajorItemPathAssocItem { .. }
+            | SemExprData::TypeAsTraitItem { .. }
+            | SemExprData::AssocItem { .. }
+            | SemExprData::FrameVarDecl { .. }
+            | SemExprData::SelfType(_)
+            | SemExprData::At { .. }
+            | SemExprData::FunctionApplication { .. }
+            | SemExprData::Ritchie { .. }
+            | SemExprData::MethodApplication { .. }
+            | SemExprData::CompositionWithList { .. }
+            | SemExprData::BoxColonList { .. }
+            | SemExprData::VecFunctor { .. }
+            | SemExprData::ArrayFunctor { .. }
+            | SemExprData::Sorry { .. }
+            | SemExprData::Todo { .. }
+            | SemExprData::Unreachable { .. } => (),
+            SemExprData::CurrentVariable {
+                current_variable_idx,
+                ..
+            } => {
+                self.use_variable(current_variable_idx);
+                self.access_place_expr(expr, Some(current_variable_idx))
+            }
+            SemExprData::InheritedVariable { .. } | SemExprData::SelfValue(_) => {
+                self.access_place_expr(expr, None)
+            }
+            SemExprData::Field {
+                self_argument,
+                ref dispatch,
+                ..
+            } => match dispatch.signature() {
+                FieldFlySignature::PropsStruct { .. } if self.place(expr).is_some() => {
+                    let via = self.check_field_owner(self_argument);
+                    self.access_place_expr(expr, via)
+                }
+                _ => self.check_expr(self_argument),
+            },
+            SemExprData::Binary {
+                lopd,
+                opr: SemBinaryOpr::Assign,
+                ropd,
+                ..
+            } => {
+                self.check_expr(ropd);
+                self.check_assignee(lopd)
+            }
+            SemExprData::Binary { lopd, ropd, .. } => {
+                self.check_expr(lopd);
+                self.check_expr(ropd)
+            }
+            SemExprData::Be { src, .. } => self.check_expr(src),
+            SemExprData::Prefix { opd, .. }
+            | SemExprData::Suffix { opd, .. }
+            | SemExprData::Unveil { opd, .. }
+            | SemExprData::Unwrap { opd, .. } => self.check_expr(opd),
+            SemExprData::FunctionRitchieCall {
+                function,
+                ref ritchie_parameter_argument_matches,
+                ..
+            } => {
+                self.check_expr(function);
+                self.check_ritchie_arguments(expr, ritchie_parameter_argument_matches)
+            }
+            SemExprData::MethodRitchieCall {
+                self_argument,
+                ref ritchie_parameter_argument_matches,
+                ..
+            } => {
+                // like two-phase borrows in Rust, the self argument isn't borrowed
+                // until the other arguments are evaluated
+                self.check_expr(self_argument);
+                self.check_ritchie_arguments(expr, ritchie_parameter_argument_matches)
+            }
+            SemExprData::TemplateInstantiation { template, .. } => self.check_expr(template),
+            SemExprData::Delimitered { item, .. } => self.check_expr(item),
+            SemExprData::NewTuple { ref items, .. } | SemExprData::NewList { ref items, .. } => {
+                for item in items {
+                    self.check_expr(item.expr)
+                }
+            }
+            SemExprData::Index {
+                self_argument,
+                ref items,
+                ..
+            } => {
+                self.check_expr(self_argument);
+                for item in items {
+                    self.check_expr(item.expr)
+                }
+            }
+            SemExprData::Block { stmts } | SemExprData::NestedBlock { stmts, .. } => {
+                self.check_stmts(stmts, false)
+            }
+            SemExprData::EmptyHtmxTag { ref arguments, .. } => {
+                for argument in arguments {
+                    self.check_expr(argument.expr())
+                }
+            }
+            // todo: captures
+            SemExprData::Closure { .. } => (),
+        }
+    }
+
+    fn access_place_expr(&mut self, expr: SemExprIdx, via: Option<CurrentVariableIdx>) {
+        let Some(place) = self.place(expr) else {
+            return;
+        };
+        let Some(contract) = self.contract(expr, place) else {
+            return;
+        };
+        let access = self.access_kind(expr, contract);
+        self.access(place, access, expr, via)
+    }
+
+    /// the owner is part of the accessed place, so it's not accessed on its own;
+    /// returns the variable at the root
+    fn check_field_owner(&mut self, owner: SemExprIdx) -> Option<CurrentVariableIdx> {
+        let sem_expr_region_data = self.sem_expr_region_data();
+        match *owner.data(sem_expr_region_data.sem_expr_arena()) {
+            SemExprData::CurrentVariable {
+                current_variable_idx,
+                ..
+            } => {
+                self.tick();
+                self.use_variable(current_variable_idx);
+                Some(current_variable_idx)
+            }
+            SemExprData::InheritedVariable { .. } | SemExprData::SelfValue(_) => None,
+            SemExprData::Field {
+                self_argument,
+                ref dispatch,
+                ..
+            } if matches!(dispatch.signature(), FieldFlySignature::PropsStruct { .. }) => {
+                self.check_field_owner(self_argument)
+            }
+            _ => {
+                self.check_expr(owner);
+                None
+            }
+        }
+    }
+
+    /// assigning to a moved place initializes it again
+    fn check_assignee(&mut self, assignee: SemExprIdx) {
+        let Some(place) = self.place(assignee) else {
+            return self.check_expr(assignee);
+        };
+        let sem_expr_region_data = self.sem_expr_region_data();
+        let via = match *assignee.data(sem_expr_region_data.sem_expr_arena()) {
+            SemExprData::CurrentVariable {
+                current_variable_idx,
+                ..
+            } => {
+                self.tick();
+                Some(current_variable_idx)
+            }
+            SemExprData::InheritedVariable { .. } | SemExprData::SelfValue(_) => None,
+            SemExprData::Field {
+                self_argument,
+                ref dispatch,
+                ..
+            } if matches!(dispatch.signature(), FieldFlySignature::PropsStruct { .. }) => {
+                self.check_field_owner(self_argument)
+            }
+            _ => return self.check_expr(assignee),
+        };
+        let db = self.db();
+        self.state_mut()
+            .retain_moves(|moved| !place_covers(db, place, moved));
+        self.access(place, Access::Write, assignee, via)
+    }
+
+    /// arguments passed by reference stay borrowed until the call returns,
+    /// or as long as the returned value if it's a reference
+    fn check_ritchie_arguments(
+        &mut self,
+        call: SemExprIdx,
+        ritchie_arguments: &[SemRitchieArgument],
+    ) {
+        let loans_len = self.state().loans().len();
+        for ritchie_argument in ritchie_arguments {
+            match ritchie_argument {
+                SemRitchieArgument::Simple(_, argument) => {
+                    self.check_ritchie_argument(argument.argument_expr_idx)
+                }
+                SemRitchieArgument::Variadic(_, arguments) => {
+                    for argument in arguments {
+                        self.check_ritchie_argument(argument.argument_expr_idx())
+                    }
+                }
+                SemRitchieArgument::Keyed(_, argument) => {
+                    if let Some(argument) = argument {
+                        self.check_ritchie_argument(argument.argument_expr_idx())
+                    }
+                }
+            }
+        }
+        let loans = self.state_mut().split_off_loans(loans_len);
+        if self.is_reference_ty(call.ty(self.sem_expr_region_data().sem_expr_arena2())) {
+            self.set_returned_loans(call, loans)
+        }
+    }
+
+    fn check_ritchie_argument(&mut self, argument: SemExprIdx) {
+        self.check_expr(argument);
+        let Some(place) = self.place(argument) else {
+            return;
+        };
+        let kind = match self.contract(argument, place) {
+            // copyable values are copied right away
+            Some(Contract::Pure | Contract::Borrow)
+                if self.always_copyable(argument) != Some(true) =>
+            {
+                LoanKind::Shared
+            }
+            Some(Contract::BorrowMut) => LoanKind::Mut,
+            _ => return,
+        };
+        self.state_mut().add_loan(Loan {
+            place,
+            kind,
+            expr: argument,
+            holders: None,
+        })
+    }
+}
@@ -0,0 +1,2 @@
+#[salsa::jar]
+pub struct BorrowCheckerJar(crate::region::borrow_check_region);
@@ -1,14 +1,18 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
+//! moves and borrows checked against the place contracts of `husky-sem-place-contract`,
+//! so that such errors point at the source rather than at the transpiled Rust code
+mod engine;
+pub mod error;
+mod expr;
+pub mod jar;
+mod place;
+pub mod region;
+pub mod state;
+mod stmt;
+#[cfg(test)]
+mod tests;
 
+use self::jar::BorrowCheckerJar as Jar;
 #[cfg(test)]
-mod tests {
-    use super::*;
+use self::tests::*;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use self::place::{show_place, RegionalPlace};
@@ -0,0 +1,119 @@
+use husky_place::{
+    place::{field::FieldName, EthPlace},
+    PlaceInfo, PlaceRegistry,
+};
+use husky_sem_expr::SemExprRegion;
+use husky_syn_expr::variable::CurrentVariableIdxRange;
+
+/// a place together with the region whose place registry indexes it;
+/// inherited variables keep the places of the region they're declared in,
+/// whose indices are unrelated to those of the current region
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RegionalPlace {
+    region: SemExprRegion,
+    place: EthPlace,
+}
+
+impl RegionalPlace {
+    pub(crate) fn new(region: SemExprRegion, place: EthPlace) -> Self {
+        Self { region, place }
+    }
+
+    pub fn region(self) -> SemExprRegion {
+        self.region
+    }
+
+    pub fn place(self) -> EthPlace {
+        self.place
+    }
+
+    fn registry(self, db: &::salsa::Db) -> &PlaceRegistry {
+        self.region.data(db).place_registry()
+    }
+}
+
+/// splits a place into its root and the fields projected from it, outermost last
+fn place_path(db: &::salsa::Db, place: EthPlace) -> (EthPlace, Vec<FieldName>) {
+    match place {
+        EthPlace::Field(field_place) => {
+            let (root, mut fields) = place_path(db, field_place.parent(db));
+            fields.push(field_place.field_name(db));
+            (root, fields)
+        }
+        _ => (place, vec![]),
+    }
+}
+
+pub(crate) fn root_place(db: &::salsa::Db, place: EthPlace) -> EthPlace {
+    place_path(db, place).0
+}
+
+/// whether `place` is `other` or one of its fields
+pub(crate) fn place_covers(db: &::salsa::Db, place: RegionalPlace, other: RegionalPlace) -> bool {
+    let (root, fields) = place_path(db, place.place);
+    let (other_root, other_fields) = place_path(db, other.place);
+    place.region == other.region && root == other_root && other_fields.starts_with(&fields)
+}
+
+/// whether an access to one place might touch the other
+pub(crate) fn places_overlap(db: &::salsa::Db, place: RegionalPlace, other: RegionalPlace) -> bool {
+    place_covers(db, place, other) || place_covers(db, other, place)
+}
+
+/// whether `place` lives in a `let` variable of `region`, which dies with the function
+pub(crate) fn is_local_place(db: &::salsa::Db, region: SemExprRegion, place: RegionalPlace) -> bool {
+    if place.region != region {
+        return false;
+    }
+    match root_place(db, place.place) {
+        EthPlace::Idx(idx) => matches!(place.registry(db)[idx], PlaceInfo::Variable { .. }),
+        _ => false,
+    }
+}
+
+/// whether `place` lives in one of `variables`, which are current variables of `region`
+pub(crate) fn is_place_of_variables(
+    db: &::salsa::Db,
+    region: SemExprRegion,
+    place: RegionalPlace,
+    variables: CurrentVariableIdxRange,
+) -> bool {
+    if place.region != region {
+        return false;
+    }
+    match root_place(db, place.place) {
+        EthPlace::Idx(idx) => match place.registry(db)[idx] {
+            PlaceInfo::Variable {
+                current_variable_idx,
+                ..
+            } => variables.contains(current_variable_idx),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// shows a place the way it's written in source, e.g., `a.x.0`
+pub fn show_place(db: &::salsa::Db, place: RegionalPlace) -> String {
+    show_eth_place(db, place.registry(db), place.place)
+}
+
+fn show_eth_place(db: &::salsa::Db, registry: &PlaceRegistry, place: EthPlace) -> String {
+    match place {
+        EthPlace::Idx(idx) => match registry[idx] {
+            PlaceInfo::SelfValue => "self".to_string(),
+            PlaceInfo::Parameter { ident, .. } | PlaceInfo::Variable { ident, .. } => {
+                ident.data().to_string()
+            }
+        },
+        EthPlace::SymbolicVariable(_) => "_".to_string(),
+        EthPlace::Field(field_place) => {
+            let parent = show_eth_place(db, registry, field_place.parent(db));
+            match field_place.field_name(db) {
+                FieldName::Tuple(index) => format!("{parent}.{index}"),
+                FieldName::Prop(ident) => format!("{parent}.{}", ident.data()),
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+use crate::*;
+use crate::{engine::BorrowChecker, error::BorrowError};
+#[cfg(test)]
+use husky_sem_expr::SemExprDb;
+use husky_sem_expr::{SemExprRegion, SemExprRegionData};
+#[cfg(test)]
+use husky_syn_defn::module_item_syn_defns;
+#[cfg(test)]
+use husky_vfs::path::module_path::ModulePath;
+
+#[salsa::derive_debug_with_db]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BorrowCheckRegion {
+    errors: Vec<BorrowError>,
+}
+
+/// # constructor
+impl BorrowCheckRegion {
+    pub(crate) fn new(errors: Vec<BorrowError>) -> Self {
+        Self { errors }
+    }
+}
+
+/// # getters
+impl BorrowCheckRegion {
+    pub fn errors(&self) -> &[BorrowError] {
+        &self.errors
+    }
+}
+
+#[salsa::tracked(return_ref)]
+pub fn borrow_check_region(db: &::salsa::Db, sem_expr_region: SemExprRegion) -> BorrowCheckRegion {
+    // place contracts are only computed for regions free of semantic errors,
+    // the errors themselves are reported elsewhere
+    if !is_sem_expr_region_ok(sem_expr_region.data(db)) {
+        return Default::default();
+    }
+    BorrowChecker::new(db, sem_expr_region).check_all()
+}
+
+fn is_sem_expr_region_ok(sem_expr_region_data: &SemExprRegionData) -> bool {
+    let sem_expr_arena = sem_expr_region_data.sem_expr_arena2();
+    sem_expr_region_data
+        .sem_expr_arena()
+        .indexed_iter()
+        .all(|(expr, entry)| {
+            entry.data_result().is_ok()
+                && expr.ty_result(sem_expr_arena).is_ok()
+                && expr.expectation_outcome(sem_expr_region_data).is_some()
+        })
+        && sem_expr_region_data
+            .sem_stmt_arena()
+            .iter()
+            .all(|entry| entry.data_result().is_ok())
+}
+
+#[cfg(test)]
+fn defn_borrow_check_regions(db: &::salsa::Db, module_path: ModulePath) -> Vec<&BorrowCheckRegion> {
+    module_item_syn_defns(db, module_path)
+        .iter()
+        .copied()
+        .filter_map(|(_, defn)| {
+            Some(borrow_check_region(
+                db,
+                db.sem_expr_region(defn?.syn_expr_region),
+            ))
+        })
+        .collect()
+}
+
+#[test]
+fn defn_borrow_check_regions_works() {
+    DB::ast_rich_test_debug_with_db(
+        defn_borrow_check_regions,
+        &AstTestConfig::new(
+            "defn_borrow_check_regions",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::SEMANTICS,
+        ),
+    )
+}
+
+/// the errors of the item named `ident` in the `borrow` module of `semantics-basics`
+#[cfg(test)]
+fn borrow_example_errors(db: &::salsa::Db, ident: &str) -> Vec<BorrowError> {
+    use husky_path_utils::HuskyLangDevPaths;
+    use husky_vfs::jar::VfsDb;
+
+    let module_path = db
+        .resolve_module_path_and_update_live_packages(
+            &HuskyLangDevPaths::new()
+                .examples_dir()
+                .join("basics/semantics-basics/src/borrow.hsy"),
+        )
+        .unwrap();
+    let defn = module_item_syn_defns(db, module_path)
+        .iter()
+        .find_map(|&(item_path, defn)| {
+            (item_path.ident(db)?.data() == ident).then_some(defn)
+        })
+        .flatten()
+        .unwrap();
+    borrow_check_region(db, db.sem_expr_region(defn.syn_expr_region))
+        .errors()
+        .to_vec()
+}
+
+#[test]
+fn use_after_move_works() {
+    let db = &*DB::default();
+    assert!(matches!(
+        borrow_example_errors(db, "use_after_move")[..],
+        [BorrowError::UseAfterMove { .. }]
+    ));
+}
+
+#[test]
+fn conflicting_borrow_works() {
+    use crate::state::{Access, LoanKind};
+
+    let db = &*DB::default();
+    assert!(matches!(
+        borrow_example_errors(db, "conflicting_borrow")[..],
+        [BorrowError::ConflictingBorrow {
+            access: Access::Move,
+            loan_kind: LoanKind::Shared,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn move_before_break_works() {
+    let db = &*DB::default();
+    assert!(matches!(
+        borrow_example_errors(db, "move_before_break")[..],
+        [BorrowError::UseAfterMove { .. }]
+    ));
+}
+
+#[test]
+fn two_phase_borrow_works() {
+    let db = &*DB::default();
+    assert_eq!(borrow_example_errors(db, "two_phase_borrow"), []);
+}
+
+#[test]
+fn escaping_reference_works() {
+    let db = &*DB::default();
+    assert!(matches!(
+        borrow_example_errors(db, "escaping_reference")[..],
+        [BorrowError::EscapingReference { .. }]
+    ));
+}
@@ -0,0 +1,129 @@
+use crate::place::{places_overlap, RegionalPlace};
+use husky_sem_expr::SemExprIdx;
+use husky_syn_expr::variable::CurrentVariableIdxRange;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Access {
+    Read,
+    Write,
+    Move,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LoanKind {
+    Shared,
+    Mut,
+}
+
+impl LoanKind {
+    /// shared loans only conflict with writes and moves
+    pub fn conflicts_with(self, access: Access) -> bool {
+        match self {
+            LoanKind::Shared => access != Access::Read,
+            LoanKind::Mut => true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct Loan {
+    pub(crate) place: RegionalPlace,
+    pub(crate) kind: LoanKind,
+    pub(crate) expr: SemExprIdx,
+    /// `None` for a temporary loan held by a call until it returns
+    pub(crate) holders: Option<CurrentVariableIdxRange>,
+}
+
+impl Loan {
+    /// like two-phase borrows in Rust, a temporary mutable loan is only reserved
+    /// while the remaining arguments are evaluated, so they may still read the place
+    pub(crate) fn conflicts_with(&self, access: Access) -> bool {
+        match self.holders {
+            None => LoanKind::Shared.conflicts_with(access),
+            Some(_) => self.kind.conflicts_with(access),
+        }
+    }
+}
+
+/// what is known about moves and loans at a point of the control flow
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub(crate) struct BorrowState {
+    moves: Vec<(RegionalPlace, SemExprIdx)>,
+    loans: Vec<Loan>,
+    /// control flow never reaches here, e.g., after `return`
+    diverged: bool,
+}
+
+/// # getters
+impl BorrowState {
+    pub(crate) fn diverged(&self) -> bool {
+        self.diverged
+    }
+
+    pub(crate) fn loans(&self) -> &[Loan] {
+        &self.loans
+    }
+
+    /// the expression that moved a place overlapping with `place`, if any
+    pub(crate) fn moved_overlapping(
+        &self,
+        db: &::salsa::Db,
+        place: RegionalPlace,
+    ) -> Option<SemExprIdx> {
+        self.moves
+            .iter()
+            .find(|&&(moved, _)| places_overlap(db, moved, place))
+            .map(|&(_, move_expr)| move_expr)
+    }
+}
+
+/// # actions
+impl BorrowState {
+    pub(crate) fn add_move(&mut self, place: RegionalPlace, expr: SemExprIdx) {
+        self.moves.push((place, expr))
+    }
+
+    /// forgets the moves matching `f`, which happens when places are reinitialized
+    pub(crate) fn retain_moves(&mut self, mut f: impl FnMut(RegionalPlace) -> bool) {
+        self.moves.retain(|&(place, _)| f(place))
+    }
+
+    pub(crate) fn add_loan(&mut self, loan: Loan) {
+        self.loans.push(loan)
+    }
+
+    /// ends the loans taken since there were `len` of them, returning them
+    pub(crate) fn split_off_loans(&mut self, len: usize) -> Vec<Loan> {
+        self.loans.split_off(len)
+    }
+
+    pub(crate) fn diverge(&mut self) {
+        self.diverged = true
+    }
+
+    /// joins the states of branches, a place is moved after them if it's moved in any of them
+    pub(crate) fn merge(states: impl IntoIterator<Item = Self>) -> Self {
+        let mut merged: Option<Self> = None;
+        for state in states {
+            merged = Some(match merged {
+                None => state,
+                Some(merged) if merged.diverged => state,
+                Some(merged) if state.diverged => merged,
+                Some(mut merged) => {
+                    for mv in state.moves {
+                        if !merged.moves.contains(&mv) {
+                            merged.moves.push(mv)
+                        }
+                    }
+                    for loan in state.loans {
+                        if !merged.loans.contains(&loan) {
+                            merged.loans.push(loan)
+                        }
+                    }
+                    merged
+                }
+            })
+        }
+        merged.unwrap_or_default()
+    }
+}
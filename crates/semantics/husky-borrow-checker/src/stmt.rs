@@ -0,0 +1,177 @@
+use crate::{
+    engine::BorrowChecker,
+    place::is_place_of_variables,
+    state::{BorrowState, Loan, LoanKind},
+};
+use husky_sem_expr::{stmt::condition::SemCondition, SemStmtData, SemStmtIdx, SemStmtIdxRange};
+use husky_term_prelude::Contract;
+
+impl<'a> BorrowChecker<'a> {
+    /// `tail` means the value of the last statement is returned by the function
+    pub(crate) fn check_stmts(&mut self, stmts: SemStmtIdxRange, tail: bool) {
+        let (non_last_stmts, last_stmt) = stmts.split_last();
+        for non_last_stmt in non_last_stmts {
+            self.check_stmt(non_last_stmt, false)
+        }
+        self.check_stmt(last_stmt, tail)
+    }
+
+    fn check_stmt(&mut self, stmt: SemStmtIdx, tail: bool) {
+        let sem_expr_region_data = self.sem_expr_region_data();
+        match *stmt.data(sem_expr_region_data.sem_stmt_arena()) {
+            SemStmtData::Let {
+                ref let_pattern_sem_obelisk,
+                contract,
+                initial_value,
+                ..
+            } => {
+                self.check_expr(initial_value);
+                let variables = let_pattern_sem_obelisk.variables();
+                // in loops, variables are bound again in every iteration
+                let db = self.db();
+                let region = self.sem_expr_region();
+                self.state_mut()
+                    .retain_moves(|moved| !is_place_of_variables(db, region, moved, variables));
+                // a returned reference keeps the arguments it may point into borrowed
+                for loan in self.take_returned_loans(initial_value) {
+                    self.state_mut().add_loan(Loan {
+                        holders: Some(variables),
+                        ..loan
+                    })
+                }
+                let kind = match contract {
+                    Contract::Borrow => LoanKind::Shared,
+                    Contract::BorrowMut => LoanKind::Mut,
+                    _ => return,
+                };
+                if let Some(place) = self.place(initial_value) {
+                    self.state_mut().add_loan(Loan {
+                        place,
+                        kind,
+                        expr: initial_value,
+                        holders: Some(variables),
+                    })
+                }
+            }
+            SemStmtData::Return { result, .. } => {
+                self.check_expr(result);
+                self.check_escape(result);
+                self.state_mut().diverge()
+            }
+            SemStmtData::Require { condition, .. } | SemStmtData::Assert { condition, .. } => {
+                self.check_condition(condition)
+            }
+            SemStmtData::Break { .. } => self.break_loop(),
+            SemStmtData::Eval {
+                expr,
+                eol_semicolon,
+                ..
+            } => {
+                self.check_expr(expr);
+                if tail && eol_semicolon.is_none() {
+                    self.check_escape(expr)
+                }
+            }
+            SemStmtData::ForBetween {
+                ref particulars,
+                stmts,
+                ..
+            } => {
+                if let Some(bound_expr) = particulars.range().initial_boundary.bound_expr {
+                    self.check_expr(bound_expr)
+                }
+                if let Some(bound_expr) = particulars.range().final_boundary.bound_expr {
+                    self.check_expr(bound_expr)
+                }
+                self.check_loop(|slf| slf.check_stmts(stmts, false))
+            }
+            SemStmtData::ForIn { range, stmts, .. } => {
+                self.check_expr(range);
+                self.check_loop(|slf| slf.check_stmts(stmts, false))
+            }
+            SemStmtData::Forext {
+                ref particulars,
+                stmts,
+                ..
+            } => {
+                self.check_expr(particulars.bound_expr);
+                self.check_loop(|slf| slf.check_stmts(stmts, false))
+            }
+            SemStmtData::While {
+                condition, stmts, ..
+            }
+            | SemStmtData::DoWhile {
+                condition, stmts, ..
+            } => self.check_loop(|slf| {
+                slf.check_condition(condition);
+                slf.check_stmts(stmts, false)
+            }),
+            SemStmtData::IfElse {
+                ref if_branch,
+                ref elif_branches,
+                ref else_branch,
+            } => {
+                self.check_condition(if_branch.condition);
+                let mut exits = vec![self.check_branch(if_branch.stmts, tail)];
+                for elif_branch in elif_branches {
+                    self.check_condition(elif_branch.condition);
+                    exits.push(self.check_branch(elif_branch.stmts, tail))
+                }
+                match else_branch {
+                    Some(else_branch) => exits.push(self.check_branch(else_branch.stmts, tail)),
+                    None => exits.push(self.state().clone()),
+                }
+                self.replace_state(BorrowState::merge(exits));
+            }
+            SemStmtData::Match {
+                opd,
+                ref case_branches,
+                ..
+            } => {
+                self.check_expr(opd);
+                if case_branches.is_empty() {
+                    return;
+                }
+                let exits: Vec<_> = case_branches
+                    .iter()
+                    .map(|case_branch| self.check_branch(case_branch.stmts, tail))
+                    .collect();
+                self.replace_state(BorrowState::merge(exits));
+            }
+            SemStmtData::Narrate { .. } => (),
+        }
+    }
+
+    fn check_condition(&mut self, condition: SemCondition) {
+        match condition {
+            SemCondition::Be { src, .. } => self.check_expr(src),
+            SemCondition::Other { expr, .. } => self.check_expr(expr),
+        }
+    }
+
+    /// returns the state at the end of the branch, leaving the current state as it was
+    fn check_branch(&mut self, stmts: SemStmtIdxRange, tail: bool) -> BorrowState {
+        let entry = self.state().clone();
+        self.check_stmts(stmts, tail);
+        self.replace_state(entry)
+    }
+
+    /// the body is checked twice, so that values moved in one iteration
+    /// are caught when used in the next;
+    /// the loop is left either normally or by `break`
+    fn check_loop(&mut self, mut check_body: impl FnMut(&mut Self)) {
+        let entry = self.state().clone();
+        let break_states = self.with_break_states(|slf| {
+            for _ in 0..2 {
+                if slf.state().diverged() {
+                    break;
+                }
+                check_body(slf)
+            }
+        });
+        let exit = self.replace_state(Default::default());
+        self.replace_state(BorrowState::merge(
+            [entry, exit].into_iter().chain(break_states),
+        ));
+    }
+}
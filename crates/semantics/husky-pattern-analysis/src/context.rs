@@ -1,14 +1,141 @@
-use self::error::FlyPatternAnalysisError;
-use crate::*;
-use husky_entity_path::path::ty_variant::TypeVariantIndex;
-use husky_fly_term::FlyTerm;
+use crate::{error::FlyPatternAnalysisError, int::IntType, ty::PatternType, *};
+use either::Either;
+use husky_dec_signature::signature::{ty_variant::TypeVariantDecTemplate, HasDecTemplate};
+use husky_entity_path::path::{
+    major_item::ty::{PreludeBasicTypePath, PreludeNumTypePath, PreludeTypePath, TypePath},
+    ty_variant::{TypeVariantIndex, TypeVariantPath},
+};
+use husky_entity_tree::node::ty_variant::HasTypeVariantPaths;
+use husky_eth_signature::signature::{
+    major_item::ty::TypeEthTemplate, ty_variant::TypeVariantEthTemplate, HasEthTemplate,
+};
+use husky_fly_term::{FlyBaseTypeData, FlyTerm, FlyTerms};
 use husky_lifetime_utils::capture::Captures;
+use rustc_pattern_analysis::{
+    constructor::{Constructor, ConstructorSet, VariantVisibility},
+    PrivateUninhabitedField,
+};
 
-#[derive(Debug)]
-pub struct PatternAnalysisContext {}
+pub struct PatternAnalysisContext<'a> {
+    db: &'a ::salsa::Db,
+    terms: &'a FlyTerms,
+}
+
+impl<'a> std::fmt::Debug for PatternAnalysisContext<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PatternAnalysisContext")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> PatternAnalysisContext<'a> {
+    pub fn new(db: &'a ::salsa::Db, terms: &'a FlyTerms) -> Self {
+        Self { db, terms }
+    }
+
+    pub fn db(&self) -> &'a ::salsa::Db {
+        self.db
+    }
+
+    /// patterns are matched through indirections like `&` and `~`
+    pub(crate) fn ty_path(&self, ty: &PatternType) -> Option<TypePath> {
+        self.fly_ty_path(ty.fly_term()?)
+    }
+
+    fn fly_ty_path(&self, ty: FlyTerm) -> Option<TypePath> {
+        match ty.base_ty_data_inner(self.db, self.terms) {
+            FlyBaseTypeData::TypeOntology {
+                refined_ty_path: Either::Left(PreludeTypePath::Indirection(_)),
+                ty_arguments: &[argument],
+                ..
+            } => self.fly_ty_path(argument),
+            FlyBaseTypeData::TypeOntology { ty_path, .. } => Some(ty_path),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn int_ty(&self, ty: &PatternType) -> Option<IntType> {
+        match self.ty_path(ty)?.refine(self.db) {
+            Either::Left(PreludeTypePath::Num(PreludeNumTypePath::Int(path))) => Some(path.into()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn ty_variant_path(
+        &self,
+        ty: &PatternType,
+        index: TypeVariantIndex,
+    ) -> Option<TypeVariantPath> {
+        let db = self.db;
+        self.ty_path(ty)?
+            .ty_variant_paths(db)
+            .iter()
+            .find(|&&(_, path)| path.index(db) == index)
+            .map(|&(_, path)| path)
+    }
 
-impl IsPatternAnalyisContext for PatternAnalysisContext {
-    type Type = FlyTerm;
+    fn field_tys(&self, constructor: &Constructor<Self>, ty: &PatternType) -> Vec<PatternType> {
+        let db = self.db;
+        if let PatternType::Tuple(field_tys) = ty {
+            return match constructor {
+                Constructor::Struct => field_tys.clone(),
+                _ => vec![],
+            };
+        }
+        match *constructor {
+            Constructor::Struct => match self.ty_path(ty).map(|ty_path| ty_path.eth_template(db)) {
+                Some(Ok(TypeEthTemplate::PropsStruct(tmpl))) => tmpl
+                    .fields(db)
+                    .iter()
+                    .map(|field| PatternType::Fly(field.ty().into()))
+                    .collect(),
+                Some(Ok(TypeEthTemplate::TupleStruct(tmpl))) => tmpl
+                    .fields(db)
+                    .iter()
+                    .map(|field| PatternType::Fly(field.ty().into()))
+                    .collect(),
+                _ => vec![],
+            },
+            Constructor::Variant(index) => match self.ty_variant_path(ty, index) {
+                Some(path) => self.ty_variant_field_tys(path),
+                None => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    fn ty_variant_field_tys(&self, path: TypeVariantPath) -> Vec<PatternType> {
+        let db = self.db;
+        // ad hoc: props variants don't have ethereal templates yet
+        let Ok(TypeVariantDecTemplate::EnumTuple(_)) = path.dec_template(db) else {
+            return vec![];
+        };
+        match path.eth_template(db) {
+            Ok(TypeVariantEthTemplate::Tuple(tmpl)) => tmpl
+                .instance_constructor_ritchie_ty(db)
+                .parameter_contracted_tys(db)
+                .iter()
+                .map(|parameter| PatternType::Fly(parameter.ty().into()))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// the index of the field named `ident` among the fields of a props struct
+    pub(crate) fn props_field_index(&self, ty: &PatternType, ident: Ident) -> Option<usize> {
+        let db = self.db;
+        match self.ty_path(ty)?.eth_template(db) {
+            Ok(TypeEthTemplate::PropsStruct(tmpl)) => tmpl
+                .fields(db)
+                .iter()
+                .position(|field| field.ident() == ident),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> IsPatternAnalyisContext for PatternAnalysisContext<'a> {
+    type Type = PatternType;
 
     type Error = FlyPatternAnalysisError;
 
@@ -21,51 +148,86 @@ impl IsPatternAnalyisContext for PatternAnalysisContext {
     type PatternDataExtra = ();
 
     fn is_exhaustive_patterns_feature_on(&self) -> bool {
-        todo!()
+        false
     }
 
     fn is_min_exhaustive_patterns_feature_on(&self) -> bool {
-        todo!()
+        false
     }
 
-    fn constructor_arity(
-        &self,
-        constructor: &rustc_pattern_analysis::constructor::Constructor<Self>,
-        ty: &Self::Type,
-    ) -> usize {
-        todo!()
+    fn constructor_arity(&self, constructor: &Constructor<Self>, ty: &Self::Type) -> usize {
+        self.field_tys(constructor, ty).len()
     }
 
-    fn constructor_field_tys<'a>(
-        &'a self,
-        constructor: &'a rustc_pattern_analysis::constructor::Constructor<Self>,
-        ty: &'a Self::Type,
-    ) -> impl Iterator<Item = (Self::Type, rustc_pattern_analysis::PrivateUninhabitedField)>
-           + ExactSizeIterator
-           + Captures<'a> {
-        [].into_iter().map(|_: i32| todo!())
+    fn constructor_field_tys<'b>(
+        &'b self,
+        constructor: &'b Constructor<Self>,
+        ty: &'b Self::Type,
+    ) -> impl Iterator<Item = (Self::Type, PrivateUninhabitedField)> + ExactSizeIterator + Captures<'b>
+    {
+        self.field_tys(constructor, ty)
+            .into_iter()
+            .map(|ty| (ty, PrivateUninhabitedField(false)))
     }
 
-    fn constructors_for_ty(
-        &self,
-        ty: &Self::Type,
-    ) -> Result<rustc_pattern_analysis::constructor::ConstructorSet<Self>, Self::Error> {
-        todo!()
+    fn constructors_for_ty(&self, ty: &Self::Type) -> Result<ConstructorSet<Self>, Self::Error> {
+        let db = self.db;
+        if let PatternType::Tuple(_) = ty {
+            return Ok(ConstructorSet::Struct { empty: false });
+        }
+        let Some(ty_path) = self.ty_path(ty) else {
+            return Ok(ConstructorSet::Unlistable);
+        };
+        Ok(match ty_path.refine(db) {
+            Either::Left(PreludeTypePath::Basic(basic_ty_path)) => match basic_ty_path {
+                PreludeBasicTypePath::Unit => ConstructorSet::Struct { empty: false },
+                PreludeBasicTypePath::Never => ConstructorSet::NoConstructors,
+                PreludeBasicTypePath::Bool => ConstructorSet::Bool,
+            },
+            Either::Left(PreludeTypePath::Num(PreludeNumTypePath::Int(path))) => {
+                ConstructorSet::Integers {
+                    range_1: IntType::from(path).range(),
+                    range_2: None,
+                }
+            }
+            _ => match ty_path.eth_template(db) {
+                Ok(TypeEthTemplate::Enum(_)) => match ty_path.ty_variant_paths(db) {
+                    [] => ConstructorSet::NoConstructors,
+                    ty_variant_paths => ConstructorSet::Variants {
+                        variants: ty_variant_paths
+                            .iter()
+                            .map(|_| VariantVisibility::Visible)
+                            .collect(),
+                        non_exhaustive: false,
+                    },
+                },
+                Ok(
+                    TypeEthTemplate::PropsStruct(_)
+                    | TypeEthTemplate::TupleStruct(_)
+                    | TypeEthTemplate::UnitStruct(_),
+                ) => ConstructorSet::Struct { empty: false },
+                _ => ConstructorSet::Unlistable,
+            },
+        })
     }
 
     fn write_variant_name(
         f: &mut std::fmt::Formatter<'_>,
-        constructor: &rustc_pattern_analysis::constructor::Constructor<Self>,
-        ty: &Self::Type,
+        constructor: &Constructor<Self>,
+        _ty: &Self::Type,
     ) -> std::fmt::Result {
-        todo!()
+        // no database here, see `show_witness` for the real names
+        match constructor {
+            Constructor::Variant(index) => write!(f, "Variant{}", index.raw()),
+            _ => write!(f, "Struct"),
+        }
     }
 
     fn bug(&self, fmt: std::fmt::Arguments<'_>) -> Self::Error {
-        todo!()
+        FlyPatternAnalysisError::Bug(fmt.to_string())
     }
 
     fn complexity_exceeded(&self) -> Result<(), Self::Error> {
-        todo!()
+        Err(FlyPatternAnalysisError::ComplexityExceeded)
     }
 }
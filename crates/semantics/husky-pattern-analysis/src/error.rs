@@ -1,2 +1,11 @@
-#[derive(Debug)]
-pub enum FlyPatternAnalysisError {}
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FlyPatternAnalysisError {
+    #[error("bug: {0}")]
+    Bug(String),
+    #[error("pattern complexity exceeded")]
+    ComplexityExceeded,
+}
+
+pub type FlyPatternAnalysisResult<T> = Result<T, FlyPatternAnalysisError>;
@@ -0,0 +1,146 @@
+use husky_entity_path::path::major_item::ty::PreludeIntTypePath;
+use husky_token_data::IntegerLikeLiteralTokenData;
+use rustc_pattern_analysis::constructor::{IntRange, MaybeInfiniteInt, RangeEnd};
+
+/// bit size and signedness, which decide how values are encoded into `IntRange`s
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct IntType {
+    size: u64,
+    signed: bool,
+}
+
+impl From<PreludeIntTypePath> for IntType {
+    fn from(path: PreludeIntTypePath) -> Self {
+        let (size, signed) = match path {
+            PreludeIntTypePath::I8 => (8, true),
+            PreludeIntTypePath::I16 => (16, true),
+            PreludeIntTypePath::I32 => (32, true),
+            PreludeIntTypePath::I64 | PreludeIntTypePath::ISize => (64, true),
+            PreludeIntTypePath::I128 => (128, true),
+            PreludeIntTypePath::U8 | PreludeIntTypePath::R8 => (8, false),
+            PreludeIntTypePath::U16 | PreludeIntTypePath::R16 => (16, false),
+            PreludeIntTypePath::U32 | PreludeIntTypePath::R32 => (32, false),
+            PreludeIntTypePath::U64
+            | PreludeIntTypePath::USize
+            | PreludeIntTypePath::R64
+            | PreludeIntTypePath::RSize => (64, false),
+            PreludeIntTypePath::U128 | PreludeIntTypePath::R128 => (128, false),
+        };
+        Self { size, signed }
+    }
+}
+
+impl IntType {
+    fn mask(self) -> u128 {
+        match self.size {
+            128 => u128::MAX,
+            size => (1u128 << size) - 1,
+        }
+    }
+
+    fn min(self) -> i128 {
+        match self.signed {
+            true => i128::MIN >> (128 - self.size),
+            false => 0,
+        }
+    }
+
+    /// the maximum as bits, because `u128::MAX` doesn't fit in `i128`
+    fn max_bits(self) -> u128 {
+        match self.signed {
+            true => (i128::MAX >> (128 - self.size)) as u128,
+            false => self.mask(),
+        }
+    }
+
+    /// `None` if the value doesn't fit in the type
+    pub(crate) fn encode(self, value: i128) -> Option<MaybeInfiniteInt> {
+        if value < self.min() || (value >= 0 && value as u128 > self.max_bits()) {
+            return None;
+        }
+        let bits = value as u128 & self.mask();
+        Some(match self.signed {
+            true => MaybeInfiniteInt::new_finite_int(bits, self.size),
+            false => MaybeInfiniteInt::new_finite_uint(bits),
+        })
+    }
+
+    pub(crate) fn range(self) -> IntRange {
+        let lo = self.encode(self.min()).expect("min fits");
+        let hi = match self.signed {
+            true => MaybeInfiniteInt::new_finite_int(self.max_bits(), self.size),
+            false => MaybeInfiniteInt::new_finite_uint(self.max_bits()),
+        };
+        IntRange::from_range(lo, hi, RangeEnd::Included)
+    }
+
+    pub(crate) fn show(self, value: MaybeInfiniteInt) -> String {
+        match self.signed {
+            true => match value.as_finite_int(self.size) {
+                Some(bits) => {
+                    let shift = 128 - self.size;
+                    (((bits << shift) as i128) >> shift).to_string()
+                }
+                None => String::new(),
+            },
+            false => match value.as_finite_uint() {
+                Some(bits) => bits.to_string(),
+                None => String::new(),
+            },
+        }
+    }
+
+    /// `a..=b`, and `a` alone for singletons
+    ///
+    /// inclusive because the exclusive end of a range reaching the maximum doesn't fit in the type
+    pub(crate) fn show_range(self, range: IntRange) -> String {
+        if range.is_singleton() {
+            return self.show(range.lo);
+        }
+        let hi = range.hi.minus_one().expect("ranges are never empty");
+        format!("{}..={}", self.show(range.lo), self.show(hi))
+    }
+}
+
+/// `None` for literals too large to be represented
+pub(crate) fn integer_literal_value(literal: IntegerLikeLiteralTokenData) -> Option<i128> {
+    Some(match literal {
+        IntegerLikeLiteralTokenData::UnspecifiedRegular(i) => i,
+        IntegerLikeLiteralTokenData::UnspecifiedLarge() => return None,
+        IntegerLikeLiteralTokenData::I8(i) => i.into(),
+        IntegerLikeLiteralTokenData::I16(i) => i.into(),
+        IntegerLikeLiteralTokenData::I32(i) => i.into(),
+        IntegerLikeLiteralTokenData::I64(i) => i.into(),
+        IntegerLikeLiteralTokenData::I128(i) => i,
+        IntegerLikeLiteralTokenData::ISize(i) => i as i128,
+        IntegerLikeLiteralTokenData::R8(i) | IntegerLikeLiteralTokenData::U8(i) => i.into(),
+        IntegerLikeLiteralTokenData::R16(i) | IntegerLikeLiteralTokenData::U16(i) => i.into(),
+        IntegerLikeLiteralTokenData::R32(i) | IntegerLikeLiteralTokenData::U32(i) => i.into(),
+        IntegerLikeLiteralTokenData::R64(i) | IntegerLikeLiteralTokenData::U64(i) => i.into(),
+        IntegerLikeLiteralTokenData::R128(i) | IntegerLikeLiteralTokenData::U128(i) => {
+            i128::try_from(i).ok()?
+        }
+        IntegerLikeLiteralTokenData::RSize(i) | IntegerLikeLiteralTokenData::USize(i) => i as i128,
+    })
+}
+
+#[test]
+fn int_type_encoding_works() {
+    let i8_ty = IntType::from(PreludeIntTypePath::I8);
+    assert_eq!(
+        i8_ty.encode(-128),
+        Some(MaybeInfiniteInt::new_finite_uint(0))
+    );
+    assert_eq!(
+        i8_ty.encode(127),
+        Some(MaybeInfiniteInt::new_finite_uint(255))
+    );
+    assert_eq!(i8_ty.encode(128), None);
+    assert_eq!(i8_ty.show(i8_ty.encode(-3).unwrap()), "-3");
+    let u8_ty = IntType::from(PreludeIntTypePath::U8);
+    assert_eq!(u8_ty.encode(-1), None);
+    assert_eq!(u8_ty.show(u8_ty.encode(200).unwrap()), "200");
+    assert_eq!(u8_ty.range().lo, MaybeInfiniteInt::new_finite_uint(0));
+    assert_eq!(u8_ty.show_range(u8_ty.range()), "0..=255");
+    assert_eq!(i8_ty.show_range(i8_ty.range()), "-128..=127");
+}
@@ -0,0 +1,234 @@
+use crate::{int::integer_literal_value, ty::PatternType, *};
+use husky_entity_path::path::ItemPath;
+use husky_syn_expr::{
+    pattern::{SynPatternData, SynPatternIdx},
+    region::SynExprRegionData,
+};
+use husky_token_data::{BoolLiteralTokenData, LiteralTokenData};
+use rustc_pattern_analysis::{
+    constructor::{Constructor, IntRange, OpaqueId, RangeEnd},
+    pattern::{DeconstructedPattern, IndexedPat},
+};
+
+impl<'a> PatternAnalysisContext<'a> {
+    /// lowers a syntactic pattern matched against `ty`,
+    /// falling back to opaque constructors for what can't be analyzed,
+    /// which never count towards exhaustiveness
+    pub(crate) fn lower_syn_pattern(
+        &self,
+        syn_expr_region_data: &SynExprRegionData,
+        syn_pattern: SynPatternIdx,
+        ty: &PatternType,
+    ) -> DeconstructedPattern<Self> {
+        let db = self.db();
+        let (constructor, fields) = match syn_expr_region_data[syn_pattern] {
+            SynPatternData::Literal { literal, .. } => {
+                (self.literal_constructor(literal, ty), vec![])
+            }
+            SynPatternData::Ident { .. } => (Constructor::Wildcard, vec![]),
+            SynPatternData::UnitTypeVariant { path, .. } => {
+                (Constructor::Variant(path.index(db)), vec![])
+            }
+            SynPatternData::Tuple { ref fields, .. }
+            | SynPatternData::TupleStruct { ref fields, .. } => {
+                let constructor = Constructor::Struct;
+                if self.constructor_arity(&constructor, ty) < fields.elements().len() {
+                    return self.lower_without_field_tys(
+                        syn_expr_region_data,
+                        constructor,
+                        fields.elements().iter().copied(),
+                        ty,
+                    );
+                }
+                let fields = self.lower_fields(
+                    syn_expr_region_data,
+                    &constructor,
+                    ty,
+                    fields.elements().iter().copied().enumerate(),
+                );
+                (constructor, fields)
+            }
+            SynPatternData::TupleTypeVariant {
+                path, ref fields, ..
+            } => {
+                let constructor = Constructor::Variant(path.index(db));
+                if self.constructor_arity(&constructor, ty) < fields.elements().len() {
+                    return self.lower_without_field_tys(
+                        syn_expr_region_data,
+                        constructor,
+                        fields.elements().iter().map(|field| field.syn_pattern()),
+                        ty,
+                    );
+                }
+                let fields = self.lower_fields(
+                    syn_expr_region_data,
+                    &constructor,
+                    ty,
+                    fields
+                        .elements()
+                        .iter()
+                        .map(|field| field.syn_pattern())
+                        .enumerate(),
+                );
+                (constructor, fields)
+            }
+            // props variants don't have field types yet
+            SynPatternData::Props {
+                name: Some(ItemPath::TypeVariant(_, path)),
+                ref fields,
+            } => {
+                return self.lower_without_field_tys(
+                    syn_expr_region_data,
+                    Constructor::Variant(path.index(db)),
+                    fields
+                        .elements()
+                        .iter()
+                        .map(|field| field.pattern().syn_pattern()),
+                    ty,
+                )
+            }
+            SynPatternData::Props { ref fields, .. } => {
+                let constructor = Constructor::Struct;
+                let fields = self.lower_fields(
+                    syn_expr_region_data,
+                    &constructor,
+                    ty,
+                    fields.elements().iter().filter_map(|field| {
+                        Some((
+                            self.props_field_index(ty, field.ident())?,
+                            field.pattern().syn_pattern(),
+                        ))
+                    }),
+                );
+                (constructor, fields)
+            }
+            SynPatternData::OneOf { ref options } => (
+                Constructor::Or,
+                options
+                    .elements()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, option)| {
+                        self.lower_syn_pattern(syn_expr_region_data, option.syn_pattern(), ty)
+                            .at_index(i)
+                    })
+                    .collect(),
+            ),
+            SynPatternData::Binding { src, .. } => {
+                return self.lower_syn_pattern(syn_expr_region_data, src, ty)
+            }
+            SynPatternData::Range { start, end, .. } => (
+                self.range_constructor(syn_expr_region_data, start, end, ty),
+                vec![],
+            ),
+        };
+        let arity = match constructor {
+            Constructor::Or => fields.len(),
+            _ => self.constructor_arity(&constructor, ty),
+        };
+        DeconstructedPattern::new(constructor, fields, arity, ty.clone(), ())
+    }
+
+    /// for constructors whose field types are unknown, say those of props variants,
+    /// or with more fields than the type has, which is a type error reported elsewhere;
+    /// the fields are dropped if none of them can fail to match,
+    /// otherwise the pattern is opaque, so that it neither covers nor is covered by other branches
+    fn lower_without_field_tys(
+        &self,
+        syn_expr_region_data: &SynExprRegionData,
+        constructor: Constructor<Self>,
+        fields: impl IntoIterator<Item = SynPatternIdx>,
+        ty: &PatternType,
+    ) -> DeconstructedPattern<Self> {
+        let constructor = if fields
+            .into_iter()
+            .all(|field| is_irrefutable(syn_expr_region_data, field))
+        {
+            match constructor {
+                Constructor::Struct => Constructor::Wildcard,
+                constructor => constructor,
+            }
+        } else {
+            Constructor::Opaque(OpaqueId::new())
+        };
+        DeconstructedPattern::new(constructor, vec![], 0, ty.clone(), ())
+    }
+
+    /// fields are given with their indices, which must be within the arity of the constructor
+    fn lower_fields(
+        &self,
+        syn_expr_region_data: &SynExprRegionData,
+        constructor: &Constructor<Self>,
+        ty: &PatternType,
+        fields: impl Iterator<Item = (usize, SynPatternIdx)>,
+    ) -> Vec<IndexedPat<Self>> {
+        let field_tys: Vec<_> = self
+            .constructor_field_tys(constructor, ty)
+            .map(|(field_ty, _)| field_ty)
+            .collect();
+        fields
+            .filter_map(|(i, field)| {
+                Some(
+                    self.lower_syn_pattern(syn_expr_region_data, field, field_tys.get(i)?)
+                        .at_index(i),
+                )
+            })
+            .collect()
+    }
+
+    fn literal_constructor(&self, literal: LiteralTokenData, ty: &PatternType) -> Constructor<Self> {
+        match literal {
+            LiteralTokenData::Unit => Constructor::Struct,
+            LiteralTokenData::Bool(literal) => {
+                Constructor::Bool(literal == BoolLiteralTokenData::True)
+            }
+            LiteralTokenData::Integer(literal) => match self
+                .int_ty(ty)
+                .zip(integer_literal_value(literal))
+                .and_then(|(int_ty, value)| int_ty.encode(value))
+            {
+                Some(value) => Constructor::IntRange(IntRange::from_singleton(value)),
+                None => Constructor::Opaque(OpaqueId::new()),
+            },
+            LiteralTokenData::Char(_)
+            | LiteralTokenData::String(_)
+            | LiteralTokenData::Float(_) => Constructor::Opaque(OpaqueId::new()),
+        }
+    }
+
+    /// `start..end` with `end` exclusive
+    fn range_constructor(
+        &self,
+        syn_expr_region_data: &SynExprRegionData,
+        start: SynPatternIdx,
+        end: SynPatternIdx,
+        ty: &PatternType,
+    ) -> Constructor<Self> {
+        let bound = |pattern: SynPatternIdx| match syn_expr_region_data[pattern] {
+            SynPatternData::Literal {
+                literal: LiteralTokenData::Integer(literal),
+                ..
+            } => self.int_ty(ty)?.encode(integer_literal_value(literal)?),
+            _ => None,
+        };
+        match (bound(start), bound(end)) {
+            (Some(start), Some(end)) if start < end => {
+                Constructor::IntRange(IntRange::from_range(start, end, RangeEnd::Excluded))
+            }
+            _ => Constructor::Opaque(OpaqueId::new()),
+        }
+    }
+}
+
+/// only bindings and tuples of them, whatever the type
+fn is_irrefutable(syn_expr_region_data: &SynExprRegionData, syn_pattern: SynPatternIdx) -> bool {
+    match syn_expr_region_data[syn_pattern] {
+        SynPatternData::Ident { .. } => true,
+        SynPatternData::Binding { src, .. } => is_irrefutable(syn_expr_region_data, src),
+        SynPatternData::Tuple { ref fields, .. } => fields
+            .elements()
+            .iter()
+            .all(|&field| is_irrefutable(syn_expr_region_data, field)),
+        _ => false,
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{error::FlyPatternAnalysisResult, *};
+use husky_sem_expr::{stmt::match_stmt::SemCaseBranch, SemExprRegionData};
+use husky_syn_expr::region::SynExprRegionData;
+use rustc_pattern_analysis::{
+    pattern::DeconstructedPattern,
+    usefulness::{compute_match_usefulness, PlaceValidity, Usefulness},
+    MatchArm,
+};
+
+/// same as rustc's default `pattern_complexity`
+const PATTERN_COMPLEXITY_LIMIT: usize = 10_000_000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MatchStmtPatternAnalysis {
+    /// witnesses of values not covered by any branch, empty if the match is exhaustive
+    missing_patterns: Vec<String>,
+    /// indices of branches that no value reaches because of the branches above them
+    unreachable_branches: Vec<usize>,
+}
+
+/// # getters
+impl MatchStmtPatternAnalysis {
+    pub fn missing_patterns(&self) -> &[String] {
+        &self.missing_patterns
+    }
+
+    pub fn unreachable_branches(&self) -> &[usize] {
+        &self.unreachable_branches
+    }
+}
+
+pub fn analyze_match_stmt(
+    db: &::salsa::Db,
+    syn_expr_region_data: &SynExprRegionData,
+    sem_expr_region_data: &SemExprRegionData,
+    opd_ty: PatternType,
+    case_branches: &[SemCaseBranch],
+) -> FlyPatternAnalysisResult<MatchStmtPatternAnalysis> {
+    let ctx = PatternAnalysisContext::new(db, sem_expr_region_data.fly_term_region().terms());
+    let patterns: Vec<_> = case_branches
+        .iter()
+        .map(|case_branch| {
+            ctx.lower_syn_pattern(
+                syn_expr_region_data,
+                case_branch
+                    .case_pattern_sem_obelisk
+                    .syn_pattern_root()
+                    .syn_pattern_idx(),
+                &opd_ty,
+            )
+        })
+        .collect();
+    analyze_branches(
+        &ctx,
+        patterns
+            .iter()
+            .zip(case_branches)
+            .map(|(pat, case_branch)| (pat, case_branch.condition().is_some())),
+        opd_ty,
+    )
+}
+
+/// branches are given by their patterns and whether they are guarded
+pub(crate) fn analyze_branches<'a, 'p>(
+    ctx: &PatternAnalysisContext<'a>,
+    branches: impl IntoIterator<Item = (&'p DeconstructedPattern<PatternAnalysisContext<'a>>, bool)>,
+    opd_ty: PatternType,
+) -> FlyPatternAnalysisResult<MatchStmtPatternAnalysis>
+where
+    'a: 'p,
+{
+    let arms: Vec<_> = branches
+        .into_iter()
+        .map(|(pat, has_guard)| MatchArm {
+            pat,
+            has_guard,
+            arm_data: (),
+        })
+        .collect();
+    let report = compute_match_usefulness(
+        ctx,
+        &arms,
+        opd_ty,
+        PlaceValidity::ValidOnly,
+        Some(PATTERN_COMPLEXITY_LIMIT),
+    )?;
+    Ok(MatchStmtPatternAnalysis {
+        missing_patterns: report
+            .non_exhaustiveness_witnesses
+            .iter()
+            .map(|witness| ctx.show_witness(witness))
+            .collect(),
+        unreachable_branches: report
+            .arm_usefulness
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (_, usefulness))| {
+                matches!(usefulness, Usefulness::Redundant).then_some(i)
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,98 @@
+use crate::{match_stmt::analyze_branches, ty::PatternType, *};
+use husky_eth_term::EthTermDb;
+use husky_fly_term::FlyTermRegion;
+use husky_vfs::test_helpers::*;
+use rustc_pattern_analysis::{constructor::Constructor, pattern::DeconstructedPattern};
+
+#[salsa::db(
+    husky_coword::jar::CowordJar,
+    husky_vfs::jar::VfsJar,
+    husky_entity_path::jar::EntityPathJar,
+    husky_token_data::jar::TokenDataJar,
+    husky_token::jar::TokenJar,
+    husky_ast::jar::AstJar,
+    husky_text::jar::TextJar,
+    husky_entity_tree::jar::EntityTreeJar,
+    husky_toml_token::jar::TomlTokenJar,
+    husky_toml_ast::jar::TomlAstJar,
+    husky_manifest_ast::jar::ManifestAstJar,
+    husky_corgi_config::jar::CorgiConfigJar,
+    husky_corgi_config_ast::jar::CorgiConfigAstJar,
+    husky_manifest::jar::ManifestJar,
+    husky_syn_expr::jar::SynExprJar,
+    husky_syn_decl::jar::SynDeclJar,
+    husky_term_prelude::jar::TermPreludeJar,
+    husky_dec_term::jar::DecTermJar,
+    husky_dec_signature::jar::DecSignatureJar,
+    husky_dec_ty::jar::DecTypeJar,
+    husky_eth_term::jar::EthTermJar,
+    husky_eth_signature::jar::EthSignatureJar,
+    husky_place::jar::PlaceJar,
+    husky_fly_term::jar::FlyTermJar
+)]
+#[derive(Default)]
+pub(crate) struct DB;
+
+/// missing patterns and unreachable branches of unguarded branches matching a `(bool, bool)`,
+/// where fields are given as literals, or `None` for wildcards
+fn analyze_bool_pairs(branches: &[[Option<bool>; 2]]) -> (Vec<String>, Vec<usize>) {
+    let db = &*DB::default();
+    let toolchain = db.dev_toolchain().unwrap();
+    let bool_ty = PatternType::Fly(db.eth_term_menu(toolchain).bool_ty_ontology().into());
+    let pair_ty = PatternType::Tuple(vec![bool_ty.clone(), bool_ty.clone()]);
+    let fly_term_region = FlyTermRegion::new(None);
+    let ctx = PatternAnalysisContext::new(db, fly_term_region.terms());
+    let patterns: Vec<_> = branches
+        .iter()
+        .map(|fields| {
+            let fields = fields
+                .iter()
+                .enumerate()
+                .map(|(i, &field)| {
+                    let constructor = match field {
+                        Some(b) => Constructor::Bool(b),
+                        None => Constructor::Wildcard,
+                    };
+                    DeconstructedPattern::new(constructor, vec![], 0, bool_ty.clone(), ())
+                        .at_index(i)
+                })
+                .collect();
+            DeconstructedPattern::new(Constructor::Struct, fields, 2, pair_ty.clone(), ())
+        })
+        .collect();
+    let analysis = analyze_branches(
+        &ctx,
+        patterns.iter().map(|pattern| (pattern, false)),
+        pair_ty,
+    )
+    .unwrap();
+    (
+        analysis.missing_patterns().to_vec(),
+        analysis.unreachable_branches().to_vec(),
+    )
+}
+
+#[test]
+fn refutable_tuple_fields_works() {
+    // `(true, _)` and `(false, _)` together cover all pairs
+    assert_eq!(
+        analyze_bool_pairs(&[[Some(true), None], [Some(false), None]]),
+        (vec![], vec![])
+    );
+    assert_eq!(
+        analyze_bool_pairs(&[[Some(true), None], [Some(false), Some(true)]]),
+        (vec!["(false, false)".to_string()], vec![])
+    );
+}
+
+#[test]
+fn unreachable_tuple_branch_works() {
+    assert_eq!(
+        analyze_bool_pairs(&[
+            [None, Some(true)],
+            [None, Some(false)],
+            [Some(true), Some(false)]
+        ]),
+        (vec![], vec![2])
+    );
+}
@@ -0,0 +1,44 @@
+use husky_fly_term::FlyTerm;
+use husky_sem_expr::{SemExprData, SemExprIdx, SemExprRegionData};
+
+/// the type a pattern is matched against
+///
+/// tuples don't have fly terms of their own, so they are spelled out with their field types
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PatternType {
+    Fly(FlyTerm),
+    Tuple(Vec<PatternType>),
+}
+
+impl From<FlyTerm> for PatternType {
+    fn from(ty: FlyTerm) -> Self {
+        PatternType::Fly(ty)
+    }
+}
+
+impl PatternType {
+    /// the type of the operand of a match, `None` if it isn't inferred
+    pub fn from_sem_expr(
+        sem_expr_idx: SemExprIdx,
+        sem_expr_region_data: &SemExprRegionData,
+    ) -> Option<Self> {
+        let arena = sem_expr_region_data.sem_expr_arena2();
+        match sem_expr_idx.data_result(arena) {
+            Ok(SemExprData::NewTuple { items, .. }) => items
+                .iter()
+                .map(|item| Self::from_sem_expr(item.expr, sem_expr_region_data))
+                .collect::<Option<_>>()
+                .map(PatternType::Tuple),
+            _ => Some(PatternType::Fly(
+                *sem_expr_idx.ty_result(arena).as_ref().ok()?,
+            )),
+        }
+    }
+
+    pub fn fly_term(&self) -> Option<FlyTerm> {
+        match *self {
+            PatternType::Fly(ty) => Some(ty),
+            PatternType::Tuple(_) => None,
+        }
+    }
+}
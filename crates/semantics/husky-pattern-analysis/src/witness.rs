@@ -0,0 +1,65 @@
+use crate::*;
+use husky_eth_signature::signature::{major_item::ty::TypeEthTemplate, HasEthTemplate};
+use rustc_pattern_analysis::{constructor::Constructor, pattern::WitnessPattern};
+
+impl<'a> PatternAnalysisContext<'a> {
+    /// shows a witness in Husky syntax, e.g., `Shape::Circle(_)`
+    pub fn show_witness(&self, witness: &WitnessPattern<Self>) -> String {
+        let db = self.db();
+        let ty = witness.ty();
+        let fields: Vec<String> = witness
+            .iter_fields()
+            .map(|field| self.show_witness(field))
+            .collect();
+        match *witness.constructor() {
+            Constructor::Bool(b) => b.to_string(),
+            Constructor::IntRange(range) => match self.int_ty(ty) {
+                Some(int_ty) => int_ty.show_range(range),
+                None => "_".to_string(),
+            },
+            Constructor::Variant(index) => match self.ty_variant_path(ty, index) {
+                Some(path) => {
+                    let name = format!(
+                        "{}::{}",
+                        path.parent_ty_path(db).ident(db).data(),
+                        path.ident(db).data()
+                    );
+                    match fields.is_empty() {
+                        true => name,
+                        false => format!("{}({})", name, fields.join(", ")),
+                    }
+                }
+                None => "_".to_string(),
+            },
+            Constructor::Struct => {
+                let Some(ty_path) = self.ty_path(ty) else {
+                    return format!("({})", fields.join(", "));
+                };
+                let name = ty_path.ident(db);
+                match ty_path.eth_template(db) {
+                    Ok(TypeEthTemplate::PropsStruct(tmpl)) => format!(
+                        "{} {{ {} }}",
+                        name.data(),
+                        tmpl.fields(db)
+                            .iter()
+                            .zip(&fields)
+                            .map(|(field, pattern)| format!(
+                                "{}: {}",
+                                field.ident().data(),
+                                pattern
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    Ok(TypeEthTemplate::TupleStruct(_)) => {
+                        format!("{}({})", name.data(), fields.join(", "))
+                    }
+                    Ok(TypeEthTemplate::UnitStruct(_)) => name.data().to_string(),
+                    _ => format!("({})", fields.join(", ")),
+                }
+            }
+            Constructor::Or => fields.join(" | "),
+            _ => "_".to_string(),
+        }
+    }
+}
@@ -53,6 +53,8 @@ pub enum DerivedSemExprTermError {
     TypePathTypeError,
     #[error("option application term error")]
     OptionApplicationTerm(EthTermError),
+    #[error("ref application term error")]
+    RefApplicationTerm(EthTermError),
     #[error("explicit application term")]
     ExplicitApplicationTerm(EthTermError),
     #[error("explicit application function term not inferred")]
@@ -27,87 +27,72 @@ impl<'a> SemExprBuilder<'a> {
             .iter()
             .map(|param| self.build_closure_parameter_obelisk(param))
             .collect();
-        let mut param_tys: SemExprTypeResult<Vec<FlyRitchieParameter>> = Ok(vec![]);
-        let (body, return_ty_term) = match destination {
-            FlyTermDestination::Specific(destination)
-                if let FlyTermData::Ritchie {
-                    ritchie_kind,
+        // parameters and return type not annotated are taken from the expected ritchie type, if any
+        let expected_ritchie = match destination {
+            FlyTermDestination::Specific(destination) => match destination.base_term_data(self) {
+                FlyTermData::Ritchie {
                     parameter_contracted_tys,
                     return_ty,
-                } = destination.base_term_data(self) =>
-            {
-                for (i, param) in parameter_obelisk.iter().enumerate() {
-                    let parameter_contracted_ty_expected = parameter_contracted_tys[i];
-                    todo!()
+                    ..
+                } => Some((parameter_contracted_tys.to_vec(), return_ty)),
+                _ => None,
+            },
+            FlyTermDestination::AnyOriginal | FlyTermDestination::AnyDerived => None,
+        };
+        let mut param_tys: Vec<FlyRitchieParameter> = vec![];
+        for (i, param) in parameter_obelisk.iter().enumerate() {
+            match *param {
+                ClosureParameterObelisk::Simple {
+                    syn_pattern_root,
+                    variables,
+                    ty,
+                    ..
+                } => {
+                    let expected_ty = expected_ritchie
+                        .as_ref()
+                        .and_then(|(parameter_contracted_tys, _)| parameter_contracted_tys.get(i))
+                        .map(|parameter_contracted_ty| parameter_contracted_ty.ty());
+                    let ty = match (ty, expected_ty) {
+                        (Some(ty), _) => match self.infer_expr_term(ty) {
+                            Some(ty) => ty,
+                            None => self.new_hole(ty, HoleKind::ImplicitType),
+                        },
+                        (None, Some(expected_ty)) => expected_ty,
+                        (None, None) => self
+                            .new_hole(syn_pattern_root.syn_pattern_idx(), HoleKind::ImplicitType),
+                    };
+                    self.infer_variable_pattern_root_and_symbols_ty(
+                        syn_pattern_root,
+                        ty,
+                        variables,
+                    );
+                    param_tys.push(FlyRitchieSimpleParameter::new(Contract::Pure, ty).into())
                 }
-                todo!()
             }
-            FlyTermDestination::AnyOriginal => {
-                for param in &parameter_obelisk {
-                    match *param {
-                        ClosureParameterObelisk::Simple {
-                            syn_pattern_root,
-                            variables,
-                            colon_token,
-                            ty,
-                        } => {
-                            let ty = match ty {
-                                Some(ty) => match self.infer_expr_term(ty) {
-                                    Some(ty) => ty,
-                                    None => self.new_hole(ty, HoleKind::ImplicitType),
-                                },
-                                None => self.new_hole(
-                                    syn_pattern_root.syn_pattern_idx(),
-                                    HoleKind::ImplicitType,
-                                ),
-                            };
-                            self.infer_variable_pattern_root_and_symbols_ty(
-                                syn_pattern_root,
-                                ty,
-                                variables,
-                            )
-                        }
-                    }
-                }
-                match return_ty {
-                    Some((_, return_ty, _)) => match self.infer_expr_term(return_ty) {
-                        Some(return_ty) => {
-                            self.build_expr_with_ty(body, ExpectCoercion::new_move(return_ty))
-                        }
-                        None => self.build_expr_with_ty(body, ExpectAnyDerived),
-                    },
-                    None => self.build_expr_with_ty(body, ExpectAnyOriginal),
+        }
+        let (body, return_ty_term) = match return_ty {
+            Some((_, return_ty, _)) => match self.infer_expr_term(return_ty) {
+                Some(return_ty) => {
+                    self.build_expr_with_ty(body, ExpectCoercion::new_move(return_ty))
                 }
-            }
-            FlyTermDestination::Specific(_) | FlyTermDestination::AnyDerived => {
-                for param in &parameter_obelisk {
-                    match param {
-                        ClosureParameterObelisk::Simple {
-                            syn_pattern_root,
-                            variables,
-                            colon_token,
-                            ty,
-                        } => todo!(),
-                    }
+                None => self.build_expr_with_ty(body, ExpectAnyDerived),
+            },
+            None => match (expected_ritchie, destination) {
+                (Some((_, return_ty)), _) => {
+                    self.build_expr_with_ty(body, ExpectCoercion::new_move(return_ty))
                 }
-                match return_ty {
-                    Some((_, return_ty, _)) => match self.infer_expr_term(return_ty) {
-                        Some(return_ty) => {
-                            self.build_expr_with_ty(body, ExpectCoercion::new_move(return_ty))
-                        }
-                        None => self.build_expr_with_ty(body, ExpectAnyDerived),
-                    },
-                    None => self.build_expr_with_ty(body, ExpectAnyDerived),
+                (None, FlyTermDestination::AnyOriginal) => {
+                    self.build_expr_with_ty(body, ExpectAnyOriginal)
                 }
+                (None, _) => self.build_expr_with_ty(body, ExpectAnyDerived),
+            },
+        };
+        let return_ty_result = match return_ty_term {
+            Some(return_ty) => {
+                FlyTerm::new_ritchie(self, ritchie_kind, param_tys, return_ty).map_err(Into::into)
             }
+            None => Err(DerivedSemExprTypeError::ClosureReturnTypeNotInferred.into()),
         };
-        let return_ty_result = param_tys
-            .map(|param_tys| match return_ty_term {
-                Some(return_ty) => FlyTerm::new_ritchie(self, ritchie_kind, param_tys, return_ty)
-                    .map_err(Into::into),
-                None => Err(DerivedSemExprTypeError::ClosureReturnTypeNotInferred.into()),
-            })
-            .flatten();
         (
             Ok(SemExprData::Closure {
                 closure_kind_regional_token_idx,
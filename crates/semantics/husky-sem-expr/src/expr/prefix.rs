@@ -158,10 +158,13 @@ impl<'a> SemExprBuilder<'a> {
             SemaPrefixOpr::Not => todo!(),
             SemaPrefixOpr::BitNot => todo!(),
             SemaPrefixOpr::LeashType => Ok(opd_term.leashed(self)?),
+            // there is no lifetime to borrow from yet
             SemaPrefixOpr::RefType => {
-                // let opd_ty = self.infer
-                // match
-                todo!()
+                Ok(
+                    FlyTerm::new_application(self, self.term_menu().static_ref_ty(), opd_term)
+                        .map_err(|e| DerivedSemExprTermError::RefApplicationTerm(e))?
+                        .into(),
+                )
             }
             SemaPrefixOpr::OptionType => {
                 Ok(
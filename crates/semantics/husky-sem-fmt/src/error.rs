@@ -0,0 +1,14 @@
+use husky_text_protocol::range::TextPositionRange;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum SemFmtError {
+    #[error("formatting would change the token at {range}")]
+    TokensChanged { range: TextPositionRange },
+    #[error("formatting would change how tokens are grouped into verses")]
+    TokenVersesChanged,
+    #[error("formatting would change the comment at {range}")]
+    CommentsChanged { range: TextPositionRange },
+}
+
+pub type SemFmtResult<T> = Result<T, SemFmtError>;
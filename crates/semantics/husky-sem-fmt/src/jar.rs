@@ -0,0 +1,2 @@
+#[salsa::jar]
+pub struct SemFmtJar(crate::module_sem_fmt);
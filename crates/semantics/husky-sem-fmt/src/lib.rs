@@ -1,14 +1,104 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
+//! formatting that is checked to keep the meaning of the source,
+//! i.e. the formatted text lexes to the same tokens, token verses and comments as the original
+pub mod error;
+pub mod jar;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+use self::error::*;
+use self::jar::SemFmtJar as Jar;
+use husky_syn_fmt::module_syn_fmt;
+use husky_token::{lex_untracked, verse::TokenVerses, Comment, TokenDb};
+use husky_vfs::path::module_path::ModulePath;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+#[salsa::tracked(jar = Jar, return_ref)]
+pub fn module_sem_fmt(db: &::salsa::Db, module_path: ModulePath) -> SemFmtResult<String> {
+    let formatted = module_syn_fmt(db, module_path);
+    let ranged_token_sheet = db.ranged_token_sheet(module_path);
+    let relexed = lex_untracked(db, formatted);
+    if let Some(i) = (0..ranged_token_sheet.len().max(relexed.tokens().len()))
+        .find(|&i| ranged_token_sheet.tokens(db).get(i) != relexed.tokens().get(i))
+    {
+        return Err(SemFmtError::TokensChanged {
+            range: ranged_token_sheet
+                .token_text_ranges()
+                .get(i)
+                .copied()
+                .unwrap_or_default(),
+        });
+    }
+    if !same_verse_starts(
+        ranged_token_sheet.token_sheet_data(db).token_verses(),
+        relexed.token_verses(),
+    ) {
+        return Err(SemFmtError::TokenVersesChanged);
+    }
+    let raw_text = module_path.raw_text(db);
+    let original_comments = ranged_token_sheet.comments();
+    for (original_comment, comment) in std::iter::zip(original_comments, relexed.comments()) {
+        if original_comment.number_of_preceding_tokens() != comment.number_of_preceding_tokens()
+            || comment_text(raw_text, original_comment) != comment_text(formatted, comment)
+        {
+            return Err(SemFmtError::CommentsChanged {
+                range: original_comment.range(),
+            });
+        }
     }
+    if original_comments.len() != relexed.comments().len() {
+        let matched = original_comments.len().min(relexed.comments().len());
+        return Err(SemFmtError::CommentsChanged {
+            range: original_comments
+                .get(matched)
+                .map(|comment| comment.range())
+                .unwrap_or_default(),
+        });
+    }
+    Ok(formatted.clone())
+}
+
+/// for check mode, `Ok(false)` means the module would be changed by formatting
+pub fn is_module_formatted(db: &::salsa::Db, module_path: ModulePath) -> SemFmtResult<bool> {
+    let formatted = module_sem_fmt(db, module_path)
+        .as_ref()
+        .map_err(Clone::clone)?;
+    Ok(formatted == module_path.raw_text(db))
+}
+
+/// indents are normalized by formatting, so only the starts are compared
+fn same_verse_starts(original: &TokenVerses, relexed: &TokenVerses) -> bool {
+    let starts = |verses: &TokenVerses| {
+        let main_starts: Vec<_> = verses
+            .main_sequence()
+            .verses_data()
+            .iter()
+            .map(|verse_data| verse_data.start())
+            .collect();
+        let nested_starts: Vec<_> = verses
+            .nested_sequences()
+            .iter()
+            .map(|nested_sequence| {
+                (
+                    nested_sequence.lcurl(),
+                    nested_sequence.end(),
+                    nested_sequence
+                        .verses_data()
+                        .iter()
+                        .map(|verse_data| verse_data.start())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        (main_starts, nested_starts)
+    };
+    starts(original) == starts(relexed)
+}
+
+fn comment_text<'a>(text: &'a str, comment: &Comment) -> &'a str {
+    let start = comment.range().start;
+    let Some(line) = text.lines().nth(start.i() as usize) else {
+        return "";
+    };
+    let offset = line
+        .char_indices()
+        .nth(start.j() as usize)
+        .map_or(line.len(), |(offset, _)| offset);
+    line[offset..].trim_end()
 }
@@ -77,12 +77,9 @@ impl<'a> PlaceContractEngine<'a> {
                 self.infer_expr(lopd, lopd_contract, Default::default());
                 self.infer_expr(ropd, ropd_contract, Default::default());
             }
-            SemExprData::Be {
-                src,
-                contract,
-                be_regional_token_idx,
-                ref target,
-            } => todo!(),
+            SemExprData::Be { src, contract, .. } => {
+                self.infer_expr(src, contract, Default::default())
+            }
             SemExprData::Prefix { opr, opd, .. } => {
                 let contract = match opr {
                     SemaPrefixOpr::Minus | SemaPrefixOpr::Not | SemaPrefixOpr::BitNot => {
@@ -147,14 +144,10 @@ impl<'a> PlaceContractEngine<'a> {
                 self.infer_expr(self_argument_sem_expr_idx, self_contract, site.clone());
                 self.infer_ritchie_parameter_argument_matches(ritchie_parameter_argument_matches)
             }
-            SemExprData::TemplateInstantiation {
-                template,
-                ref template_arguments,
-            } => todo!(),
-            SemExprData::At {
-                at_regional_token_idx,
-                place_label_regional_token,
-            } => todo!(),
+            SemExprData::TemplateInstantiation { template, .. } => {
+                self.infer_expr(template, Contract::Pure, Default::default())
+            }
+            SemExprData::At { .. } => (),
             SemExprData::Delimitered { item, .. } => self.infer_expr(item, contract, site.clone()),
             SemExprData::NewTuple { ref items, .. } => {
                 for item in items {
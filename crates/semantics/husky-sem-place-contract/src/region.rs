@@ -22,6 +22,14 @@ impl SemPlaceContractRegion {
     }
 }
 
+/// # getters
+impl SemPlaceContractRegion {
+    /// `None` if the expression is not evaluated at runtime, e.g., a type expression
+    pub fn get(&self, expr: SemExprIdx) -> Option<&SemPlaceContractSite> {
+        self.expr_sites.get(expr)
+    }
+}
+
 impl std::ops::Index<SemExprIdx> for SemPlaceContractRegion {
     type Output = SemPlaceContractSite;
 
@@ -19,4 +19,8 @@ impl SemPlaceContractSite {
     pub fn place_contracts(&self) -> &[(EthPlace, Contract)] {
         &self.place_contracts
     }
+
+    pub fn get(&self, place: EthPlace) -> Option<Contract> {
+        self.place_contracts.get_value(place).copied()
+    }
 }
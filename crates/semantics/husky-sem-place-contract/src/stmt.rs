@@ -57,8 +57,9 @@ impl<'a> PlaceContractEngine<'a> {
                     .map(|expr| self.infer_expr(expr, Contract::Pure, Default::default()));
                 self.infer_stmts(stmts, Contract::Pure, Default::default());
             }
-            SemStmtData::ForIn { .. } => {
-                todo!()
+            SemStmtData::ForIn { range, stmts, .. } => {
+                self.infer_expr(range, Contract::Pure, Default::default());
+                self.infer_stmts(stmts, Contract::Pure, Default::default());
             }
             SemStmtData::Forext {
                 ref particulars,
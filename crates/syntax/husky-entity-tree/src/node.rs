@@ -17,7 +17,7 @@ use crate::*;
 use enum_class::Room32;
 use husky_ast::DefnBlock;
 use husky_entity_path::path::ItemPath;
-use husky_token::IdentToken;
+use husky_token::{IdentToken, TokenIdx, TokenIdxRange};
 use husky_vfs::toolchain::Toolchain;
 use smallvec::{smallvec, SmallVec};
 use vec_like::VecPairMap;
@@ -86,6 +86,30 @@ impl ItemSynNodePathId {
     pub(crate) fn opt_ast_idx(self, db: &::salsa::Db) -> Option<AstIdx> {
         self.data(db).opt_ast_idx(self, db)
     }
+
+    /// the identifier token if there is one,
+    /// otherwise the first token of the ast
+    ///
+    /// `None` for scripts, which have no ast
+    pub fn nav_token_idx(self, db: &::salsa::Db) -> Option<TokenIdx> {
+        let ast_idx = self.opt_ast_idx(db)?;
+        let module_path = self.module_path(db);
+        Some(match module_path.ast_sheet(db)[ast_idx] {
+            AstData::Identifiable { ident_token, .. }
+            | AstData::TypeVariant { ident_token, .. } => ident_token.token_idx(),
+            _ => module_path.ast_token_idx_range_sheet(db)[ast_idx]
+                .start()
+                .token_idx(),
+        })
+    }
+
+    /// the tokens of the whole ast, including its block
+    ///
+    /// `None` for scripts, which have no ast
+    pub fn ast_token_idx_range(self, db: &::salsa::Db) -> Option<TokenIdxRange> {
+        let ast_idx = self.opt_ast_idx(db)?;
+        Some(self.module_path(db).ast_token_idx_range_sheet(db)[ast_idx])
+    }
 }
 
 #[test]
@@ -55,6 +55,17 @@ impl<'a> EntitySymbolTableRef<'a> {
         })
     }
 
+    /// all entries resolvable from the reference module, used for completion
+    pub fn visible_entries(
+        self,
+        db: &'a ::salsa::Db,
+        reference_module_path: ReferenceModulePath,
+    ) -> impl Iterator<Item = &'a EntitySymbolEntry> + 'a {
+        self.0
+            .iter()
+            .filter(move |entry| entry.is_visible_from(db, reference_module_path))
+    }
+
     pub(crate) fn data(&self) -> &'a [EntitySymbolEntry] {
         self.0
     }
@@ -88,6 +88,16 @@ pub struct FieldSynPatternData {
     pattern: SynPatternComponent,
 }
 
+impl FieldSynPatternData {
+    pub fn ident(&self) -> Ident {
+        self.ident.ident()
+    }
+
+    pub fn pattern(&self) -> SynPatternComponent {
+        self.pattern
+    }
+}
+
 pub type SynPatternArena = Arena<SynPatternData>;
 pub type SynPatternIdx = ArenaIdx<SynPatternData>;
 pub type SynPatternIdxRange = ArenaIdxRange<SynPatternData>;
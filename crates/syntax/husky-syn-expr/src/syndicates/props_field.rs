@@ -26,6 +26,10 @@ impl PropsFieldSyndicate {
         self.ident_token.ident()
     }
 
+    pub fn ident_token(&self) -> IdentRegionalToken {
+        self.ident_token
+    }
+
     pub fn colon(&self) -> ColonRegionalToken {
         self.colon
     }
@@ -105,6 +105,14 @@ impl CurrentVariableEntry {
         self.modifier
     }
 
+    pub fn access_start(&self) -> RegionalTokenIdx {
+        self.access_start
+    }
+
+    pub fn access_end(&self) -> Option<RegionalTokenIdxRangeEnd> {
+        self.access_end
+    }
+
     pub fn kind(&self) -> CurrentVariableKind {
         self.data.kind()
     }
@@ -0,0 +1,36 @@
+use crate::*;
+use husky_corgi_config::HasCorgiConfig;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SynFmtConfig {
+    /// consecutive blank lines are collapsed into at most this many
+    pub max_blank_lines: usize,
+    /// separate top-level items by a blank line unless they are one-liners of the same kind, like `use`s or `mod`s
+    pub blank_line_between_items: bool,
+}
+
+impl Default for SynFmtConfig {
+    fn default() -> Self {
+        Self {
+            max_blank_lines: 1,
+            blank_line_between_items: true,
+        }
+    }
+}
+
+/// the `[fmt]` section of the package's corgi config, with unset or unreadable fields left as default
+#[salsa::tracked(jar = Jar, return_ref)]
+pub fn syn_fmt_config(db: &::salsa::Db, module_path: ModulePath) -> SynFmtConfig {
+    let mut config = SynFmtConfig::default();
+    let Ok(corgi_config) = module_path.package_path(db).corgi_config(db) else {
+        return config;
+    };
+    let fmt_section = corgi_config.fmt_section();
+    if let Some(max_blank_lines) = fmt_section.max_blank_lines() {
+        config.max_blank_lines = max_blank_lines
+    }
+    if let Some(blank_line_between_items) = fmt_section.blank_line_between_items() {
+        config.blank_line_between_items = blank_line_between_items
+    }
+    config
+}
@@ -0,0 +1,281 @@
+use crate::{
+    role::{module_token_roles, TokenRole},
+    spacing::{space_between, SpacingToken},
+    *,
+};
+use husky_ast::{AstData, HasAstSheet};
+use husky_text_protocol::{position::TextPosition, range::TextPositionRange};
+use husky_token::{indent::INDENT_INCR, Comment, TokenDb};
+use husky_token_data::TokenData;
+
+pub(crate) struct SynFmtEngine<'a> {
+    config: &'a SynFmtConfig,
+    raw_text: &'a str,
+    /// byte offsets of line starts in `raw_text`
+    line_offsets: Vec<usize>,
+    tokens: &'a [TokenData],
+    token_ranges: &'a [TextPositionRange],
+    comments: &'a [Comment],
+    roles: Vec<Option<TokenRole>>,
+    /// lines starting top-level asts that should be separated from the previous one by a blank line
+    separated_lines: Vec<u32>,
+    /// original indents of the enclosing lines, the innermost last
+    indent_stack: Vec<u32>,
+    fmt_lines: Vec<FmtLine>,
+}
+
+enum FmtLine {
+    Blank,
+    Code { text: String, separated: bool },
+    Comment(String),
+}
+
+impl<'a> SynFmtEngine<'a> {
+    pub(crate) fn new(
+        db: &'a ::salsa::Db,
+        module_path: ModulePath,
+        config: &'a SynFmtConfig,
+    ) -> Self {
+        let ranged_token_sheet = db.ranged_token_sheet(module_path);
+        let tokens = ranged_token_sheet.tokens(db);
+        let token_ranges = ranged_token_sheet.token_text_ranges();
+        let raw_text = module_path.raw_text(db);
+        Self {
+            config,
+            raw_text,
+            line_offsets: std::iter::once(0)
+                .chain(raw_text.match_indices('\n').map(|(offset, _)| offset + 1))
+                .collect(),
+            tokens,
+            token_ranges,
+            comments: ranged_token_sheet.comments(),
+            roles: module_token_roles(db, module_path, tokens.len()),
+            separated_lines: separated_lines(db, module_path, token_ranges),
+            indent_stack: vec![],
+            fmt_lines: vec![],
+        }
+    }
+
+    pub(crate) fn fmt(mut self) -> String {
+        let mut token_index = 0;
+        let mut comment_index = 0;
+        let mut last_line: Option<u32> = None;
+        let comments = self.comments;
+        loop {
+            let token_line = self
+                .token_ranges
+                .get(token_index)
+                .map(|range| range.start.i());
+            let comment_line = comments
+                .get(comment_index)
+                .map(|comment| comment.range().start.i());
+            let comment_first = match (token_line, comment_line) {
+                (None, None) => break,
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+                (Some(token_line), Some(comment_line)) => comment_line < token_line,
+            };
+            if comment_first {
+                let comment = &comments[comment_index];
+                let line = comment.range().start.i();
+                self.push_blank_lines(last_line, line);
+                let level = self.comment_level(comment.range().start.j());
+                let text = indented(level, self.comment_text(comment));
+                self.fmt_lines.push(FmtLine::Comment(text));
+                comment_index += 1;
+                last_line = Some(line)
+            } else {
+                let start = token_index;
+                let line = self.token_ranges[start].start.i();
+                self.push_blank_lines(last_line, line);
+                let mut end_line = self.token_ranges[start].end.i();
+                token_index += 1;
+                while let Some(range) = self.token_ranges.get(token_index) {
+                    if range.start.i() > end_line {
+                        break;
+                    }
+                    end_line = end_line.max(range.end.i());
+                    token_index += 1
+                }
+                let level = self.code_level(self.token_ranges[start].start.j());
+                let mut text = indented(level, &self.code_text(start, token_index));
+                // comments within the lines of the tokens are trailing ones
+                while let Some(comment) = comments.get(comment_index) {
+                    if comment.range().start.i() > end_line {
+                        break;
+                    }
+                    text.push(' ');
+                    text.push_str(self.comment_text(comment));
+                    comment_index += 1
+                }
+                self.fmt_lines.push(FmtLine::Code {
+                    text,
+                    separated: self.separated_lines.binary_search(&line).is_ok(),
+                });
+                last_line = Some(end_line)
+            }
+        }
+        self.finish()
+    }
+
+    fn push_blank_lines(&mut self, last_line: Option<u32>, line: u32) {
+        let Some(last_line) = last_line else { return };
+        let blank_lines = (line - last_line - 1) as usize;
+        for _ in 0..blank_lines.min(self.config.max_blank_lines) {
+            self.fmt_lines.push(FmtLine::Blank)
+        }
+    }
+
+    /// maps the original indent to an indentation level,
+    /// lines indented more than the innermost enclosing line go one level deeper,
+    /// so the relative structure that lexing and parsing rely on is kept
+    fn code_level(&mut self, indent: u32) -> usize {
+        while self.indent_stack.last().is_some_and(|&last| last > indent) {
+            self.indent_stack.pop();
+        }
+        if self.indent_stack.last() != Some(&indent) {
+            self.indent_stack.push(indent)
+        }
+        self.indent_stack.len() - 1
+    }
+
+    /// like `code_level`, but comments don't open or close blocks
+    fn comment_level(&self, indent: u32) -> usize {
+        let enclosing = self
+            .indent_stack
+            .iter()
+            .take_while(|&&enclosing_indent| enclosing_indent <= indent)
+            .count();
+        match self.indent_stack.last() {
+            Some(&last) if enclosing == self.indent_stack.len() && indent > last => enclosing,
+            _ => enclosing.saturating_sub(1),
+        }
+    }
+
+    fn code_text(&self, start: usize, end: usize) -> String {
+        let mut text = String::new();
+        let mut prev: Option<SpacingToken> = None;
+        for i in start..end {
+            let token = SpacingToken {
+                data: self.tokens[i],
+                role: self.roles[i],
+                text: self.token_text(self.token_ranges[i]),
+            };
+            if let Some(prev) = prev {
+                let space = space_between(prev, token).unwrap_or_else(|| {
+                    // keep the original spacing
+                    self.token_ranges[i - 1].end != self.token_ranges[i].start
+                });
+                if space {
+                    text.push(' ')
+                }
+            }
+            text.push_str(token.text);
+            prev = Some(token)
+        }
+        text
+    }
+
+    /// comments run till the end of the line
+    fn comment_text(&self, comment: &Comment) -> &'a str {
+        let text = &self.raw_text[self.offset(comment.range().start)..];
+        text.split('\n').next().unwrap_or_default().trim_end()
+    }
+
+    /// multi-line tokens like string literals are taken as a whole, including the line breaks
+    fn token_text(&self, range: TextPositionRange) -> &'a str {
+        &self.raw_text[self.offset(range.start)..self.offset(range.end)]
+    }
+
+    /// columns count chars
+    fn offset(&self, position: TextPosition) -> usize {
+        let line_offset = self.line_offsets[position.i() as usize];
+        let line = &self.raw_text[line_offset..];
+        line_offset
+            + line
+                .char_indices()
+                .nth(position.j() as usize)
+                .map_or(line.len(), |(offset, _)| offset)
+    }
+
+    fn finish(self) -> String {
+        let mut fmt_lines: Vec<FmtLine> = vec![];
+        for fmt_line in self.fmt_lines {
+            if let FmtLine::Code {
+                separated: true, ..
+            } = fmt_line
+            {
+                if self.config.blank_line_between_items {
+                    // leading comments stay with the item
+                    let mut i = fmt_lines.len();
+                    while i > 0 && matches!(fmt_lines[i - 1], FmtLine::Comment(_)) {
+                        i -= 1
+                    }
+                    if i > 0 && !matches!(fmt_lines[i - 1], FmtLine::Blank) {
+                        fmt_lines.insert(i, FmtLine::Blank)
+                    }
+                }
+            }
+            fmt_lines.push(fmt_line)
+        }
+        while matches!(fmt_lines.last(), Some(FmtLine::Blank)) {
+            fmt_lines.pop();
+        }
+        let mut text = String::new();
+        for fmt_line in fmt_lines
+            .iter()
+            .skip_while(|fmt_line| matches!(fmt_line, FmtLine::Blank))
+        {
+            match fmt_line {
+                FmtLine::Blank => (),
+                FmtLine::Code { text: line, .. } | FmtLine::Comment(line) => text.push_str(line),
+            }
+            text.push('\n')
+        }
+        text
+    }
+}
+
+fn indented(level: usize, text: &str) -> String {
+    let mut indented = " ".repeat(level * INDENT_INCR as usize);
+    indented.push_str(text);
+    indented
+}
+
+/// a blank line goes between top-level asts unless
+/// the previous one is an attribute, or both are one-liners of the same kind
+fn separated_lines(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    token_ranges: &[TextPositionRange],
+) -> Vec<u32> {
+    let ast_sheet = module_path.ast_sheet(db);
+    let ast_token_idx_range_sheet = module_path.ast_token_idx_range_sheet(db);
+    let asts: Vec<(u32, bool, &AstData)> = ast_sheet
+        .top_level_asts_indexed_iter()
+        .filter_map(|(ast_idx, ast)| {
+            let token_idx_range = ast_token_idx_range_sheet[ast_idx];
+            let start = token_idx_range.start().index();
+            let end = token_idx_range.end().index();
+            (end > start).then(|| {
+                let start_line = token_ranges[start].start.i();
+                let multiline = token_ranges[end - 1].end.i() > start_line;
+                (start_line, multiline, ast)
+            })
+        })
+        .collect();
+    asts.windows(2)
+        .filter_map(|window| {
+            let (_, prev_multiline, prev) = window[0];
+            let (line, multiline, ast) = window[1];
+            match (prev, ast) {
+                (AstData::Attr { .. } | AstData::Err { .. }, _) | (_, AstData::Err { .. }) => None,
+                (_, AstData::Attr { .. }) => Some(line),
+                _ => (prev_multiline
+                    || multiline
+                    || std::mem::discriminant(prev) != std::mem::discriminant(ast))
+                .then_some(line),
+            }
+        })
+        .collect()
+}
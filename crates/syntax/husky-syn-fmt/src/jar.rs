@@ -0,0 +1,2 @@
+#[salsa::jar]
+pub struct SynFmtJar(crate::module_syn_fmt, crate::config::syn_fmt_config);
@@ -1,14 +1,23 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
-
+//! comment-preserving formatter for husky source files, working from tokens, asts and syntactic expressions
+//!
+//! the formatter never joins or splits lines, so the line-sensitive parts of lexing and parsing are untouched;
+//! it normalizes indentation, the spacing between tokens, comments and blank lines
+pub mod config;
+mod engine;
+pub mod jar;
+mod role;
+mod spacing;
 #[cfg(test)]
-mod tests {
-    use super::*;
+mod tests;
+
+use self::config::*;
+use self::engine::*;
+use self::jar::SynFmtJar as Jar;
+use husky_vfs::path::module_path::ModulePath;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+/// the formatted text of a module, which might lex differently from the original if the original is ill-formed;
+/// see `husky-sem-fmt` for the checked version
+#[salsa::tracked(jar = Jar, return_ref)]
+pub fn module_syn_fmt(db: &::salsa::Db, module_path: ModulePath) -> String {
+    SynFmtEngine::new(db, module_path, syn_fmt_config(db, module_path)).fmt()
 }
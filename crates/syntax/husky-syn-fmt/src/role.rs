@@ -0,0 +1,99 @@
+//! roles of ambiguous punctuations, read off from syntactic expressions
+//!
+//! `<`, `|`, `*`, `!` and the like mean different things in different places,
+//! and the token data alone can't tell how they should be spaced
+use crate::*;
+use husky_entity_tree::helpers::paths::module_item_syn_node_paths;
+use husky_regional_token::RegionalTokenIdx;
+use husky_syn_decl::decl::HasSynNodeDecl;
+use husky_syn_defn::item_syn_node_defn;
+use husky_syn_expr::{expr::SynExprData, region::SynExprRegion};
+use husky_token::TokenIdx;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum TokenRole {
+    BinaryOpr,
+    PrefixOpr,
+    SuffixOpr,
+    TemplateLangle,
+    TemplateRangle,
+    ClosureLvert,
+    ClosureRvert,
+}
+
+/// indexed by token indices, `None` for tokens not covered by any syntactic expression
+pub(crate) fn module_token_roles(
+    db: &::salsa::Db,
+    module_path: ModulePath,
+    tokens_len: usize,
+) -> Vec<Option<TokenRole>> {
+    let mut roles = vec![None; tokens_len];
+    for &syn_node_path in module_item_syn_node_paths(db, module_path) {
+        if let Some(syn_expr_region) = syn_node_path.syn_node_decl(db).syn_expr_region(db) {
+            collect_token_roles(db, syn_expr_region, &mut roles)
+        }
+        if let Some(defn) = item_syn_node_defn(db, *syn_node_path) {
+            collect_token_roles(db, defn.syn_expr_region, &mut roles)
+        }
+    }
+    roles
+}
+
+fn collect_token_roles(
+    db: &::salsa::Db,
+    syn_expr_region: SynExprRegion,
+    roles: &mut [Option<TokenRole>],
+) {
+    let syn_expr_region_data = syn_expr_region.data(db);
+    let Some(regional_token_idx_base) = syn_expr_region_data.path().regional_token_idx_base(db)
+    else {
+        return;
+    };
+    let mut set = |regional_token_idx: RegionalTokenIdx, role: TokenRole| {
+        let token_idx: TokenIdx = regional_token_idx.token_idx(regional_token_idx_base);
+        if let Some(slot) = roles.get_mut(token_idx.index()) {
+            *slot = Some(role)
+        }
+    };
+    for expr in syn_expr_region_data.expr_arena().iter() {
+        match *expr {
+            SynExprData::Binary {
+                opr_regional_token_idx,
+                ..
+            } => set(opr_regional_token_idx, TokenRole::BinaryOpr),
+            SynExprData::Prefix {
+                opr_regional_token_idx,
+                ..
+            } => set(opr_regional_token_idx, TokenRole::PrefixOpr),
+            SynExprData::Suffix {
+                opr_regional_token_idx,
+                ..
+            } => set(opr_regional_token_idx, TokenRole::SuffixOpr),
+            SynExprData::TemplateInstantiation {
+                ref template_arguments,
+                ..
+            } => {
+                set(
+                    template_arguments.langle_regional_token_idx(),
+                    TokenRole::TemplateLangle,
+                );
+                set(
+                    template_arguments.rangle_regional_token_idx(),
+                    TokenRole::TemplateRangle,
+                )
+            }
+            SynExprData::Closure {
+                lvert_regional_token_idx,
+                rvert_regional_token,
+                ..
+            } => {
+                set(lvert_regional_token_idx, TokenRole::ClosureLvert);
+                set(
+                    rvert_regional_token.regional_token_idx(),
+                    TokenRole::ClosureRvert,
+                )
+            }
+            _ => (),
+        }
+    }
+}
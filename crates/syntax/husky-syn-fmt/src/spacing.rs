@@ -0,0 +1,183 @@
+use crate::role::TokenRole;
+use husky_syn_opr::SynBinaryOpr;
+use husky_token_data::{delimiter::Delimiter, *};
+
+/// what a token wants on one of its sides
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Side {
+    Space,
+    Glue,
+    /// keep whatever the original text has
+    Keep,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SpacingToken<'a> {
+    pub(crate) data: TokenData,
+    pub(crate) role: Option<TokenRole>,
+    pub(crate) text: &'a str,
+}
+
+/// `None` if the original spacing should be kept
+pub(crate) fn space_between(prev: SpacingToken, next: SpacingToken) -> Option<bool> {
+    let space = match (right_side(prev), left_side(prev, next)) {
+        (Side::Glue, _) | (_, Side::Glue) => false,
+        (Side::Keep, _) | (_, Side::Keep) => return None,
+        (Side::Space, Side::Space) => true,
+    };
+    Some(space || would_glue(prev.text, next.text))
+}
+
+fn left_side(prev: SpacingToken, token: SpacingToken) -> Side {
+    match token.data {
+        TokenData::Keyword(_)
+        | TokenData::Ident(_)
+        | TokenData::Label(_)
+        | TokenData::WordOpr(_)
+        | TokenData::Literal(_) => Side::Space,
+        TokenData::Punctuation(punctuation) => match punctuation.mapped() {
+            PunctuationMapped::LeftDelimiter(Delimiter::Par | Delimiter::Box) => {
+                match is_convex(prev) {
+                    true => Side::Glue,
+                    false => Side::Space,
+                }
+            }
+            PunctuationMapped::RightDelimiter(Delimiter::InlineCurl) => match prev.data {
+                TokenData::INLINE_LCURL => Side::Glue,
+                _ => Side::Space,
+            },
+            PunctuationMapped::RightDelimiter(_) => Side::Glue,
+            PunctuationMapped::Binary(SynBinaryOpr::ScopeResolution)
+            | PunctuationMapped::Suffix(_)
+            | PunctuationMapped::ColonColonLa
+            | PunctuationMapped::Dot
+            | PunctuationMapped::DotDot
+            | PunctuationMapped::DotDotDot
+            | PunctuationMapped::Colon
+            | PunctuationMapped::Comma
+            | PunctuationMapped::Semicolon => Side::Glue,
+            PunctuationMapped::Binary(_)
+            | PunctuationMapped::LeftDelimiter(_)
+            | PunctuationMapped::Eq
+            | PunctuationMapped::ColonEq
+            | PunctuationMapped::ColonHyphen
+            | PunctuationMapped::AtEq
+            | PunctuationMapped::HeavyArrow
+            | PunctuationMapped::Minus
+            | PunctuationMapped::Tilde
+            | PunctuationMapped::Pound => Side::Space,
+            _ => match token.role {
+                Some(TokenRole::BinaryOpr | TokenRole::PrefixOpr | TokenRole::ClosureLvert) => {
+                    Side::Space
+                }
+                Some(
+                    TokenRole::SuffixOpr
+                    | TokenRole::TemplateLangle
+                    | TokenRole::TemplateRangle
+                    | TokenRole::ClosureRvert,
+                ) => Side::Glue,
+                None => Side::Keep,
+            },
+        },
+        TokenData::Error(_) => Side::Keep,
+    }
+}
+
+fn right_side(token: SpacingToken) -> Side {
+    match token.data {
+        TokenData::Keyword(_)
+        | TokenData::Ident(_)
+        | TokenData::Label(_)
+        | TokenData::WordOpr(_)
+        | TokenData::Literal(_) => Side::Space,
+        TokenData::Punctuation(punctuation) => match punctuation.mapped() {
+            PunctuationMapped::LeftDelimiter(Delimiter::InlineCurl | Delimiter::NestedCurl) => {
+                Side::Space
+            }
+            PunctuationMapped::LeftDelimiter(_)
+            | PunctuationMapped::Binary(SynBinaryOpr::ScopeResolution)
+            | PunctuationMapped::ColonColonLa
+            | PunctuationMapped::Dot
+            | PunctuationMapped::DotDot
+            | PunctuationMapped::DotDotDot
+            | PunctuationMapped::Minus
+            | PunctuationMapped::Tilde
+            | PunctuationMapped::Pound => Side::Glue,
+            PunctuationMapped::RightDelimiter(_)
+            | PunctuationMapped::Binary(_)
+            | PunctuationMapped::Suffix(_)
+            | PunctuationMapped::Colon
+            | PunctuationMapped::Comma
+            | PunctuationMapped::Semicolon
+            | PunctuationMapped::Eq
+            | PunctuationMapped::ColonEq
+            | PunctuationMapped::ColonHyphen
+            | PunctuationMapped::AtEq
+            | PunctuationMapped::HeavyArrow => Side::Space,
+            _ => match token.role {
+                Some(
+                    TokenRole::BinaryOpr
+                    | TokenRole::SuffixOpr
+                    | TokenRole::TemplateRangle
+                    | TokenRole::ClosureRvert,
+                ) => Side::Space,
+                Some(
+                    TokenRole::PrefixOpr | TokenRole::TemplateLangle | TokenRole::ClosureLvert,
+                ) => Side::Glue,
+                None => Side::Keep,
+            },
+        },
+        TokenData::Error(_) => Side::Keep,
+    }
+}
+
+/// whether a following `(` or `[` is a call or an index rather than a new expression
+fn is_convex(token: SpacingToken) -> bool {
+    match token.data {
+        TokenData::Keyword(Keyword::Pronoun(_)) => true,
+        _ => match token.role {
+            Some(TokenRole::SuffixOpr | TokenRole::TemplateRangle) => true,
+            _ => token.data.right_convexity() == Convexity::Convex,
+        },
+    }
+}
+
+/// punctuations longer than one char,
+/// which a lexer reads greedily out of shorter ones put next to each other
+const MULTI_CHAR_PUNCTUATIONS: &[&str] = &[
+    "==", "=>", ":-", ":=", "::", "::<", "@=", "&&", "&=", "||", "|=", "..", "...", "-=", "--",
+    "->", "<<", "<=", ">>", ">=", "**", "*=", "//", "/>", "/=", "++", "+=", "!=", "!!",
+];
+
+/// whether putting the two texts together changes how they are lexed
+fn would_glue(prev: &str, next: &str) -> bool {
+    let (Some(last), Some(first)) = (prev.chars().last(), next.chars().next()) else {
+        return false;
+    };
+    if is_word_char(last) && is_word_char(first) {
+        return true;
+    }
+    MULTI_CHAR_PUNCTUATIONS.iter().any(|punctuation| {
+        punctuation.len() > prev.len()
+            && punctuation.starts_with(prev)
+            && next.starts_with(&punctuation[prev.len()..])
+    })
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '\''
+}
+
+#[test]
+fn would_glue_works() {
+    assert!(would_glue("a", "b"));
+    assert!(would_glue("1", "a"));
+    assert!(would_glue("-", "-"));
+    assert!(would_glue(">", ">"));
+    assert!(would_glue("..", "."));
+    assert!(would_glue("/", "/"));
+    assert!(!would_glue("a", "("));
+    assert!(!would_glue(")", "."));
+    assert!(!would_glue("!", "."));
+    assert!(!would_glue("(", "-"));
+}
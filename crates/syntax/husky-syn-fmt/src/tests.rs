@@ -0,0 +1,128 @@
+pub(crate) use husky_ast::test_helpers::*;
+
+use crate::*;
+use husky_corgi_config::jar::CorgiConfigJar;
+use husky_corgi_config_ast::CorgiConfigAstJar;
+use husky_coword::jar::CowordJar;
+use husky_entity_tree::jar::EntityTreeJar;
+use husky_manifest::jar::ManifestJar;
+use husky_manifest_ast::jar::ManifestAstJar;
+use husky_path_utils::HuskyLangDevPaths;
+use husky_term_prelude::jar::TermPreludeJar;
+use husky_token::{TokenDb, TokenJar};
+use husky_toml_ast::TomlAstJar;
+use husky_vfs::{jar::VfsDb, set_live_file};
+
+#[salsa::db(
+    CowordJar,
+    husky_vfs::jar::VfsJar,
+    husky_entity_path::jar::EntityPathJar,
+    TermPreludeJar,
+    husky_token_data::jar::TokenDataJar,
+    husky_text::jar::TextJar,
+    TokenJar,
+    husky_ast::jar::AstJar,
+    EntityTreeJar,
+    husky_toml_token::jar::TomlTokenJar,
+    TomlAstJar,
+    ManifestAstJar,
+    CorgiConfigJar,
+    CorgiConfigAstJar,
+    ManifestJar,
+    husky_syn_decl::jar::SynDeclJar,
+    husky_syn_expr::jar::SynExprJar,
+    husky_syn_defn::jar::SynDefnJar,
+    Jar
+)]
+#[derive(Default)]
+pub(crate) struct DB;
+
+fn example_module_path(db: &::salsa::Db, relative_path: &str) -> ModulePath {
+    db.resolve_module_path_and_update_live_packages(
+        &HuskyLangDevPaths::new().examples_dir().join(relative_path),
+    )
+    .unwrap()
+}
+
+#[test]
+fn module_syn_fmt_works() {
+    DB::ast_rich_test_display(
+        |db, module_path| module_syn_fmt(db, module_path).clone(),
+        &AstTestConfig::new(
+            "module_syn_fmt",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::SYNTAX,
+        ),
+    )
+}
+
+#[test]
+fn module_syn_fmt_is_idempotent() {
+    DB::ast_plain_test(
+        |db, module_path: ModulePath| {
+            let Some(path) = module_path.abs_path(db) else {
+                return;
+            };
+            let fmt = module_syn_fmt(db, module_path).clone();
+            // formatting the formatted text in a fresh db
+            let mut db = DB::default();
+            let module_path = db
+                .resolve_module_path_and_update_live_packages(&path)
+                .unwrap();
+            set_live_file(&mut db, &path, fmt.clone()).unwrap();
+            assert_eq!(module_syn_fmt(&db, module_path), &fmt);
+        },
+        &AstTestConfig::new(
+            "module_syn_fmt_is_idempotent",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::SYNTAX,
+        ),
+    )
+}
+
+#[test]
+fn module_syn_fmt_keeps_comments() {
+    DB::ast_plain_test(
+        |db, module_path: ModulePath| {
+            let fmt = module_syn_fmt(db, module_path);
+            let lines: Vec<&str> = module_path.raw_text(db).lines().collect();
+            for comment in db.ranged_token_sheet(module_path).comments() {
+                let start = comment.range().start;
+                let text: String = lines[start.i() as usize]
+                    .chars()
+                    .skip(start.j() as usize)
+                    .collect();
+                assert!(fmt.contains(text.trim_end()), "lost comment `{text}`");
+            }
+        },
+        &AstTestConfig::new(
+            "module_syn_fmt_keeps_comments",
+            FileExtensionConfig::Markdown,
+            TestDomainsConfig::SYNTAX,
+        ),
+    )
+}
+
+#[test]
+fn syn_fmt_config_reads_corgi_config() {
+    let db = DB::default();
+    let db = &*db;
+    assert_eq!(
+        syn_fmt_config(
+            db,
+            example_module_path(db, "basics/semantics-basics/src/lib.hsy")
+        ),
+        &SynFmtConfig::default()
+    );
+    // set by the corgi config of the package
+    assert_eq!(
+        syn_fmt_config(
+            db,
+            example_module_path(db, "basics/syntax-basics/src/lib.hsy")
+        ),
+        &SynFmtConfig {
+            max_blank_lines: 2,
+            blank_line_between_items: false,
+        }
+    );
+}
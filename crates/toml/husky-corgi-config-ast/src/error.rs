@@ -1,5 +1,8 @@
 #[derive(Debug, PartialEq, Eq)]
-pub enum CorgiConfigAstError {}
+pub enum CorgiConfigAstError {
+    ExpectedBoolean,
+    ExpectedNonNegativeInteger,
+}
 
 pub type CorgiConfigAstResult<T> = Result<T, CorgiConfigAstError>;
 
@@ -20,6 +20,7 @@ use husky_vfs::{error::VfsResult, path::virtual_path::VirtualPath};
 #[derive(Debug, PartialEq, Eq)]
 pub struct CorgiConfigAstSheet {
     registry_section: Option<CorgiConfigAstResult<CorgiConfigRegistrySectionAst>>,
+    fmt_section: Option<CorgiConfigAstResult<CorgiConfigFmtSectionAst>>,
 }
 
 impl CorgiConfigAstSheet {
@@ -28,6 +29,10 @@ impl CorgiConfigAstSheet {
     ) -> Option<CorgiConfigAstResultRef<&CorgiConfigRegistrySectionAst>> {
         self.registry_section.as_ref().map(|s| s.as_ref())
     }
+
+    pub fn fmt_section(&self) -> Option<CorgiConfigAstResultRef<&CorgiConfigFmtSectionAst>> {
+        self.fmt_section.as_ref().map(|s| s.as_ref())
+    }
 }
 
 #[salsa::tracked(jar = CorgiConfigAstJar, return_ref)]
@@ -50,5 +55,6 @@ fn transform_corgi_config_ast_sheet<'a, 'b>(
 ) -> CorgiConfigAstSheet {
     CorgiConfigAstSheet {
         registry_section: transformer.transform_normal_section(),
+        fmt_section: transformer.transform_normal_section(),
     }
 }
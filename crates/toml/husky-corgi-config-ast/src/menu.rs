@@ -6,15 +6,25 @@ use crate::*;
 pub struct CorgiConfigAstMenu {
     registry_coword: BaseCoword,
     path_coword: BaseCoword,
+    fmt_coword: BaseCoword,
+    max_blank_lines_coword: BaseCoword,
+    blank_line_between_items_coword: BaseCoword,
 }
 
 impl CorgiConfigAstMenu {
     fn new(db: &::salsa::Db) -> Self {
         let registry_coword = BaseCoword::from_ref("registry", db);
         let path_coword = BaseCoword::from_ref("path", db);
+        let fmt_coword = BaseCoword::from_ref("fmt", db);
+        let max_blank_lines_coword = BaseCoword::from_ref("max-blank-lines", db);
+        let blank_line_between_items_coword =
+            BaseCoword::from_ref("blank-line-between-items", db);
         Self {
             registry_coword,
             path_coword,
+            fmt_coword,
+            max_blank_lines_coword,
+            blank_line_between_items_coword,
         }
     }
 }
@@ -27,6 +37,18 @@ impl CorgiConfigAstMenu {
     pub(crate) fn path_coword(&self) -> BaseCoword {
         self.path_coword
     }
+
+    pub(crate) fn fmt_coword(&self) -> BaseCoword {
+        self.fmt_coword
+    }
+
+    pub(crate) fn max_blank_lines_coword(&self) -> BaseCoword {
+        self.max_blank_lines_coword
+    }
+
+    pub(crate) fn blank_line_between_items_coword(&self) -> BaseCoword {
+        self.blank_line_between_items_coword
+    }
 }
 
 #[salsa::tracked(jar = CorgiConfigAstJar, return_ref)]
@@ -41,4 +63,10 @@ fn corgi_config_ast_menu_works() {
     let menu = corgi_config_ast_menu(db);
     assert_eq!(menu.registry_coword().data(), "registry");
     assert_eq!(menu.path_coword().data(), "path");
+    assert_eq!(menu.fmt_coword().data(), "fmt");
+    assert_eq!(menu.max_blank_lines_coword().data(), "max-blank-lines");
+    assert_eq!(
+        menu.blank_line_between_items_coword().data(),
+        "blank-line-between-items"
+    );
 }
@@ -0,0 +1,43 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CorgiConfigFmtSectionAst {
+    max_blank_lines: Option<CorgiConfigAstResult<usize>>,
+    blank_line_between_items: Option<CorgiConfigAstResult<bool>>,
+}
+
+impl CorgiConfigFmtSectionAst {
+    pub fn max_blank_lines(&self) -> Option<CorgiConfigAstResultRef<usize>> {
+        self.max_blank_lines.as_ref().map(|s| s.as_ref().copied())
+    }
+
+    pub fn blank_line_between_items(&self) -> Option<CorgiConfigAstResultRef<bool>> {
+        self.blank_line_between_items
+            .as_ref()
+            .map(|s| s.as_ref().copied())
+    }
+}
+
+impl TransformFromTomlAst<CorgiConfigAstTransformContext> for CorgiConfigFmtSectionAst {
+    type Ast = TomlSection;
+
+    fn transform_from<'a, 'b>(
+        mut tf: TomlTransformer<'a, 'b, CorgiConfigAstTransformContext, Self::Ast>,
+    ) -> CorgiConfigAstResult<Self> {
+        let menu = tf.menu();
+        let max_blank_lines_key = menu.max_blank_lines_coword();
+        let blank_line_between_items_key = menu.blank_line_between_items_coword();
+        Ok(CorgiConfigFmtSectionAst {
+            max_blank_lines: tf.transform_value(max_blank_lines_key),
+            blank_line_between_items: tf.transform_value(blank_line_between_items_key),
+        })
+    }
+}
+
+impl TransformFromTomlParentKeyed<CorgiConfigAstTransformContext> for CorgiConfigFmtSectionAst {
+    fn key(
+        menu: &<CorgiConfigAstTransformContext as TomlDeserializeContext>::Menu,
+    ) -> husky_coword::BaseCoword {
+        menu.fmt_coword()
+    }
+}
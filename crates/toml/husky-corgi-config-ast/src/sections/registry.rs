@@ -15,7 +15,7 @@ impl TransformFromTomlAst<CorgiConfigAstTransformContext> for CorgiConfigRegistr
     type Ast = TomlSection;
 
     fn transform_from<'a, 'b>(
-        tf: TomlTransformer<'a, 'b, CorgiConfigAstTransformContext, Self::Ast>,
+        mut tf: TomlTransformer<'a, 'b, CorgiConfigAstTransformContext, Self::Ast>,
     ) -> CorgiConfigAstResult<Self> {
         let key = tf.menu().path_coword();
         Ok(CorgiConfigRegistrySectionAst {
@@ -9,3 +9,31 @@ impl TomlDeserializeContext for CorgiConfigAstTransformContext {
     type Menu = CorgiConfigAstMenu;
     type Error = CorgiConfigAstError;
 }
+
+impl TransformFromTomlAst<CorgiConfigAstTransformContext> for bool {
+    type Ast = TomlExpr;
+
+    fn transform_from<'a, 'b>(
+        tf: CorgiConfigAstTransformer<'a, 'b, Self::Ast>,
+    ) -> CorgiConfigAstResult<Self> {
+        match *tf.expr() {
+            TomlExpr::Boolean(b) => Ok(b),
+            _ => Err(CorgiConfigAstError::ExpectedBoolean),
+        }
+    }
+}
+
+impl TransformFromTomlAst<CorgiConfigAstTransformContext> for usize {
+    type Ast = TomlExpr;
+
+    fn transform_from<'a, 'b>(
+        tf: CorgiConfigAstTransformer<'a, 'b, Self::Ast>,
+    ) -> CorgiConfigAstResult<Self> {
+        match *tf.expr() {
+            TomlExpr::Integer(i) => {
+                usize::try_from(i).map_err(|_| CorgiConfigAstError::ExpectedNonNegativeInteger)
+            }
+            _ => Err(CorgiConfigAstError::ExpectedNonNegativeInteger),
+        }
+    }
+}
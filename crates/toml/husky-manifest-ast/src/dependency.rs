@@ -6,22 +6,106 @@ use vec_like::AsVecMapEntry;
 pub struct ManifestDependencyAst {
     line_group_idx: TomlLineGroupIdx,
     name: Kebab,
+    version_req: Option<semver::VersionReq>,
+    source: ManifestDependencySourceAst,
+}
+
+/// Where a dependency comes from.
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ManifestDependencySourceAst {
+    /// `name = "0.1"` or `name = { version = "0.1" }`
+    Registry,
+    /// `name = { path = "../name" }`, relative to the manifest
+    Path(VirtualPath),
+    /// `name = { git = "https://...", rev = "..." }`
+    Git { url: String, rev: Option<String> },
 }
 
 impl TransformFromTomlKeyedAst<ManifestAstTransformContext> for ManifestDependencyAst {
     type KeyedAst = TomlSectionEntry;
 
     fn transform_from<'a, 'b>(
-        transformer: TomlTransformer<'a, 'b, ManifestAstTransformContext, Self::KeyedAst>,
+        mut transformer: TomlTransformer<'a, 'b, ManifestAstTransformContext, Self::KeyedAst>,
     ) -> ManifestAstResult<Self> {
+        let line_group_idx = transformer.line_group_idx();
+        let name = Kebab::from_coword(transformer.db(), transformer.key())
+            .ok_or(OriginalManifestAstError::InvalidName)?;
+        let invalid_spec = || -> ManifestAstError {
+            OriginalManifestAstError::InvalidDependencySpec { line_group_idx }.into()
+        };
+        let mut value_transformer = transformer.value_transformer().ok_or_else(invalid_spec)?;
+        let (version_req, source) = match value_transformer.expr() {
+            TomlExpr::String(version_req) => (
+                Some(parse_version_req(version_req, line_group_idx)?),
+                ManifestDependencySourceAst::Registry,
+            ),
+            TomlExpr::Table(table) => {
+                let menu = manifest_ast_menu(value_transformer.db());
+                if table.keys().any(|key| {
+                    ![
+                        menu.version_coword(),
+                        menu.path_coword(),
+                        menu.git_coword(),
+                        menu.rev_coword(),
+                    ]
+                    .contains(&key)
+                }) {
+                    Err(invalid_spec())?
+                }
+                let mut string_value = |key| -> ManifestAstResult<Option<&str>> {
+                    match value_transformer.table_value_transformer(key) {
+                        Some(tf) => match tf.expr() {
+                            TomlExpr::String(s) => Ok(Some(s as &str)),
+                            _ => Err(invalid_spec()),
+                        },
+                        None => Ok(None),
+                    }
+                };
+                let version_req = string_value(menu.version_coword())?
+                    .map(|version_req| parse_version_req(version_req, line_group_idx))
+                    .transpose()?;
+                let path = string_value(menu.path_coword())?.is_some();
+                let git = string_value(menu.git_coword())?.map(ToString::to_string);
+                let rev = string_value(menu.rev_coword())?.map(ToString::to_string);
+                let source = match (path, git, rev) {
+                    (false, None, None) => ManifestDependencySourceAst::Registry,
+                    (true, None, None) => ManifestDependencySourceAst::Path(
+                        value_transformer
+                            .table_value_transformer(menu.path_coword())
+                            .unwrap()
+                            .transform_into()?,
+                    ),
+                    (false, Some(url), rev) => ManifestDependencySourceAst::Git { url, rev },
+                    _ => Err(invalid_spec())?,
+                };
+                (version_req, source)
+            }
+            _ => Err(invalid_spec())?,
+        };
         Ok(ManifestDependencyAst {
-            line_group_idx: transformer.line_group_idx(),
-            name: Kebab::from_coword(transformer.db(), transformer.key())
-                .ok_or(OriginalManifestAstError::InvalidName)?,
+            line_group_idx,
+            name,
+            version_req,
+            source,
         })
     }
 }
 
+fn parse_version_req(
+    version_req: &str,
+    line_group_idx: TomlLineGroupIdx,
+) -> ManifestAstResult<semver::VersionReq> {
+    semver::VersionReq::parse(version_req).map_err(|e| {
+        OriginalManifestAstError::InvalidDependencyVersionReq {
+            line_group_idx,
+            version_req: version_req.to_string(),
+            message: e.to_string(),
+        }
+        .into()
+    })
+}
+
 impl ManifestDependencyAst {
     pub fn line_group_idx(&self) -> TomlLineGroupIdx {
         self.line_group_idx
@@ -30,6 +114,15 @@ impl ManifestDependencyAst {
     pub fn name(&self) -> Kebab {
         self.name
     }
+
+    /// `None` if not specified, which is as good as `*`
+    pub fn version_req(&self) -> Option<&semver::VersionReq> {
+        self.version_req.as_ref()
+    }
+
+    pub fn source(&self) -> &ManifestDependencySourceAst {
+        &self.source
+    }
 }
 
 impl AsVecMapEntry for ManifestDependencyAst {
@@ -46,19 +139,3 @@ impl AsVecMapEntry for ManifestDependencyAst {
         &self.name
     }
 }
-
-// pub(crate) fn from_toml_section_entry<'a>(
-//     db: &::salsa::Db,
-//     entry: &'a TomlSectionEntry,
-//     builder: &mut ManifestAstTransformer<'a, TomlExpr>,
-//     errors: &mut Vec<ManifestAstError>,
-// ) -> Option<ManifestDependencyAst> {
-//     let Some(name) = Name::from_coword(db, entry.key()) else {
-//         errors.push(todo!());
-//         return None
-//     };
-//     Some(ManifestDependencyAst {
-//         line_group_idx: entry.line_group_idx(),
-//         name,
-//     })
-// }
@@ -1,3 +1,4 @@
+use husky_toml_ast::TomlLineGroupIdx;
 use thiserror::Error;
 
 #[salsa::derive_debug_with_db]
@@ -16,6 +17,14 @@ pub enum OriginalManifestAstError {
     MissingPackageSection,
     #[error("InvalidName")]
     InvalidName,
+    #[error("invalid version requirement `{version_req}`: {message}")]
+    InvalidDependencyVersionReq {
+        line_group_idx: TomlLineGroupIdx,
+        version_req: String,
+        message: String,
+    },
+    #[error("a dependency should be a version requirement or a table with `version`, `path`, `git` or `rev`, and `rev` only goes with `git`")]
+    InvalidDependencySpec { line_group_idx: TomlLineGroupIdx },
     #[error("todo")]
     Todo,
 }
@@ -29,3 +38,16 @@ pub enum DerivedManifestAstError {
 
 pub type ManifestAstResult<T> = Result<T, ManifestAstError>;
 pub type ManifestAstResultRef<'a, T> = Result<T, &'a ManifestAstError>;
+
+impl ManifestAstError {
+    /// where the error is, if it's about a line group
+    pub fn line_group_idx(&self) -> Option<TomlLineGroupIdx> {
+        match self {
+            ManifestAstError::Original(
+                OriginalManifestAstError::InvalidDependencyVersionReq { line_group_idx, .. }
+                | OriginalManifestAstError::InvalidDependencySpec { line_group_idx },
+            ) => Some(*line_group_idx),
+            _ => None,
+        }
+    }
+}
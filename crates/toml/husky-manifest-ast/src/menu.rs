@@ -6,6 +6,10 @@ pub struct ManifestAstMenu {
     dependencies_coword: BaseCoword,
     dev_dependencies_coword: BaseCoword,
     features_coword: BaseCoword,
+    version_coword: BaseCoword,
+    path_coword: BaseCoword,
+    git_coword: BaseCoword,
+    rev_coword: BaseCoword,
 }
 
 #[salsa::tracked(return_ref)]
@@ -19,11 +23,19 @@ impl ManifestAstMenu {
         let dependencies_coword = BaseCoword::from_ref("dependencies", db);
         let dev_dependencies_coword = BaseCoword::from_ref("dev-dependencies", db);
         let features_coword = BaseCoword::from_ref("features", db);
+        let version_coword = BaseCoword::from_ref("version", db);
+        let path_coword = BaseCoword::from_ref("path", db);
+        let git_coword = BaseCoword::from_ref("git", db);
+        let rev_coword = BaseCoword::from_ref("rev", db);
         Self {
             package_coword,
             dependencies_coword,
             dev_dependencies_coword,
             features_coword,
+            version_coword,
+            path_coword,
+            git_coword,
+            rev_coword,
         }
     }
 
@@ -42,4 +54,20 @@ impl ManifestAstMenu {
     pub(crate) fn features_coword(&self) -> BaseCoword {
         self.features_coword
     }
+
+    pub(crate) fn version_coword(&self) -> BaseCoword {
+        self.version_coword
+    }
+
+    pub(crate) fn path_coword(&self) -> BaseCoword {
+        self.path_coword
+    }
+
+    pub(crate) fn git_coword(&self) -> BaseCoword {
+        self.git_coword
+    }
+
+    pub(crate) fn rev_coword(&self) -> BaseCoword {
+        self.rev_coword
+    }
 }
@@ -5,6 +5,12 @@ pub struct ManifestDevDependenciesSectionAst {
     dependencies: Vec<ManifestDependencyAst>,
 }
 
+impl ManifestDevDependenciesSectionAst {
+    pub fn deps(&self) -> &[ManifestDependencyAst] {
+        self.dependencies.as_ref()
+    }
+}
+
 impl TransformFromTomlAst<ManifestAstTransformContext> for ManifestDevDependenciesSectionAst {
     type Ast = TomlSection;
 
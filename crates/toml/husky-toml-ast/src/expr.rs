@@ -21,11 +21,31 @@ pub enum TomlExpr {
     Datetime(toml_datetime::Datetime),
     /// Represents a TOML array
     Array(TomlExprIdxRange),
-    /// Represents a TOML table
-    Table(TomlExprIdxRange),
+    /// Represents a TOML inline table
+    Table(TomlInlineTable),
     Err(TomlAstError),
 }
 
+/// `{ key = value, ... }`, with entries in the written order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TomlInlineTable(Vec<(BaseCoword, TomlExprIdx)>);
+
+impl TomlInlineTable {
+    pub(crate) fn new(entries: Vec<(BaseCoword, TomlExprIdx)>) -> Self {
+        Self(entries)
+    }
+
+    pub fn get(&self, key: BaseCoword) -> Option<TomlExprIdx> {
+        self.0
+            .iter()
+            .find_map(|&(key1, expr)| (key1 == key).then_some(expr))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = BaseCoword> + '_ {
+        self.0.iter().map(|&(key, _)| key)
+    }
+}
+
 pub type TomlExprArena = Arena<TomlExpr>;
 pub type TomlExprIdx = ArenaIdx<TomlExpr>;
 pub type TomlExprIdxRange = ArenaIdxRange<TomlExpr>;
@@ -27,7 +27,7 @@ impl<'a> TomlExprVisitor<'a> {
         self.expr_idx
     }
 
-    pub fn expr(&self) -> &TomlExpr {
+    pub fn expr(&self) -> &'a TomlExpr {
         self.expr
     }
 }
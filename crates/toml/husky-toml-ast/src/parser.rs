@@ -90,17 +90,52 @@ impl<'a> TomlAstParser<'a> {
         }
     }
 
-    fn parse_expr(mut self) -> Option<TomlExprIdx> {
-        Some(self.exprs.alloc_one(match self.tokens.next()?.data() {
+    fn parse_expr(&mut self) -> Option<TomlExprIdx> {
+        let expr = match self.tokens.next()?.data() {
             TomlTokenData::Comment => todo!(),
-            TomlTokenData::Special(_) => todo!(),
+            TomlTokenData::Special(TomlSpecialToken::LeftCurly) => self.parse_inline_table(),
+            // like the `}` of `{ version = }`
+            TomlTokenData::Special(_) => TomlExpr::Err(TomlAstError::Expect),
             TomlTokenData::Word(word) => match word.data() {
                 "true" => TomlExpr::Boolean(true),
                 "false" => TomlExpr::Boolean(false),
-                _ => todo!(),
+                data => match data.parse() {
+                    Ok(i) => TomlExpr::Integer(i),
+                    Err(_) => todo!(),
+                },
             },
             TomlTokenData::StringLiteral { val, multiline: _ } => TomlExpr::String(val.clone()),
             TomlTokenData::Err(_) => todo!(),
-        }))
+        };
+        Some(self.exprs.alloc_one(expr))
+    }
+
+    /// after `{`
+    fn parse_inline_table(&mut self) -> TomlExpr {
+        let mut entries = vec![];
+        loop {
+            let key = match self.tokens.next().map(|token| token.data()) {
+                Some(TomlTokenData::Special(TomlSpecialToken::RightCurly))
+                    if entries.is_empty() =>
+                {
+                    break
+                }
+                Some(TomlTokenData::Word(word)) => *word,
+                _ => return TomlExpr::Err(TomlAstError::Expect),
+            };
+            if self.eat_special(TomlSpecialToken::Equals).is_err() {
+                return TomlExpr::Err(TomlAstError::Expect);
+            }
+            let Some(value) = self.parse_expr() else {
+                return TomlExpr::Err(TomlAstError::Expect);
+            };
+            entries.push((key, value));
+            match self.tokens.next().map(|token| token.data()) {
+                Some(TomlTokenData::Special(TomlSpecialToken::Comma)) => (),
+                Some(TomlTokenData::Special(TomlSpecialToken::RightCurly)) => break,
+                _ => return TomlExpr::Err(TomlAstError::Expect),
+            }
+        }
+        TomlExpr::Table(TomlInlineTable::new(entries))
     }
 }
@@ -11,6 +11,13 @@ pub(super) struct TomlSectionParseIter<'a> {
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct TomlLineGroupIdx(usize);
 
+impl TomlLineGroupIdx {
+    /// the position among [`TomlTokenSheet::line_groups`]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
 impl<'a> Iterator for TomlSectionParseIter<'a> {
     type Item = TomlSection;
 
@@ -2,6 +2,7 @@ pub(crate) use husky_vfs::test_helpers::*;
 
 use crate::*;
 use husky_coword::jar::CowordJar;
+use husky_toml_token::jar::TomlTokenDb;
 
 #[salsa::db(
     CowordJar,
@@ -22,3 +23,57 @@ fn manifest_ast_works() {
         ),
     );
 }
+
+#[test]
+fn parse_inline_table_works() {
+    let db = DB::default();
+    let db = &*db;
+    let sheet = TomlAstSheet::new(
+        db,
+        &TomlTokenSheet::new(db.toml_tokenize(
+            r#"beta = { version = "0.1", path = "../beta" }
+gamma = { git = { url = "https://github.com/husky-lang-org/gamma" } }
+delta = {}
+epsilon = { version = }
+zeta = { version = "0.1" path = "../zeta" }
+"#,
+        )),
+    );
+    let value = |line_group_index: usize| -> &TomlExpr {
+        let TomlLineGroup::KeyValue(_, Some(expr)) = sheet.line_groups[line_group_index] else {
+            panic!("expect a key value")
+        };
+        &sheet.expr_arena[expr]
+    };
+    let table = |line_group_index: usize| -> &TomlInlineTable {
+        let TomlExpr::Table(table) = value(line_group_index) else {
+            panic!("expect an inline table")
+        };
+        table
+    };
+    let string = |table: &TomlInlineTable, key: &str| -> Option<&str> {
+        match sheet.expr_arena[table.get(BaseCoword::from_ref(key, db))?] {
+            TomlExpr::String(ref s) => Some(s),
+            _ => None,
+        }
+    };
+    let beta = table(0);
+    assert_eq!(
+        beta.keys().map(|key| key.data()).collect::<Vec<_>>(),
+        ["version", "path"]
+    );
+    assert_eq!(string(beta, "version"), Some("0.1"));
+    assert_eq!(string(beta, "path"), Some("../beta"));
+    let gamma = table(1);
+    let git = gamma.get(BaseCoword::from_ref("git", db)).unwrap();
+    let TomlExpr::Table(ref git) = sheet.expr_arena[git] else {
+        panic!("expect a nested inline table")
+    };
+    assert_eq!(
+        string(git, "url"),
+        Some("https://github.com/husky-lang-org/gamma")
+    );
+    assert_eq!(table(2).keys().count(), 0);
+    assert_eq!(value(3), &TomlExpr::Err(TomlAstError::Expect));
+    assert_eq!(value(4), &TomlExpr::Err(TomlAstError::Expect));
+}
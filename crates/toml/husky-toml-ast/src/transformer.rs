@@ -99,7 +99,7 @@ impl<'a, 'b, Context: TomlDeserializeContext> TomlTransformer<'a, 'b, Context, T
             db,
             visitor: toml_ast_sheet.root_visitor(),
             menu,
-            path: path.data(),
+            path: path.data().parent().unwrap(),
             errors,
         })
     }
@@ -178,7 +178,7 @@ impl<'a, 'b, Context: TomlDeserializeContext> TomlTransformer<'a, 'b, Context, T
     }
 
     pub fn transform_value<Target>(
-        mut self,
+        &mut self,
         key: BaseCoword,
     ) -> Option<Result<Target, Context::Error>>
     where
@@ -210,7 +210,7 @@ impl<'a, 'b, Context: TomlDeserializeContext> TomlTransformer<'a, 'b, Context, T
 
 // impl section entry transformer
 impl<'a, 'b, Context: TomlDeserializeContext> TomlTransformer<'a, 'b, Context, TomlSectionEntry> {
-    fn value_transformer<'c>(&'c mut self) -> Option<TomlTransformer<'a, 'c, Context, TomlExpr>>
+    pub fn value_transformer<'c>(&'c mut self) -> Option<TomlTransformer<'a, 'c, Context, TomlExpr>>
     where
         'b: 'c,
     {
@@ -236,6 +236,33 @@ impl<'a, 'b, Context: TomlDeserializeContext> TomlTransformer<'a, 'b, Context, T
     }
 }
 
+// impl expr transformer
+impl<'a, 'b, Context: TomlDeserializeContext> TomlTransformer<'a, 'b, Context, TomlExpr> {
+    pub fn expr(&self) -> &'a TomlExpr {
+        self.visitor.expr()
+    }
+
+    /// `None` if the expression isn't an inline table or has no such key
+    pub fn table_value_transformer<'c>(
+        &'c mut self,
+        key: BaseCoword,
+    ) -> Option<TomlTransformer<'a, 'c, Context, TomlExpr>>
+    where
+        'b: 'c,
+    {
+        let TomlExpr::Table(table) = self.visitor.expr() else {
+            return None;
+        };
+        Some(TomlTransformer {
+            db: self.db,
+            visitor: TomlExprVisitor::new(self.visitor.toml_ast_sheet(), table.get(key)?),
+            menu: self.menu,
+            path: self.path,
+            errors: self.errors,
+        })
+    }
+}
+
 pub trait TransformFromTomlParentKeyed<Context>: TransformFromTomlAst<Context>
 where
     Context: TomlDeserializeContext,
@@ -20,6 +20,12 @@ impl TomlTokenSheet {
         (0..self.line_group_starts.len()).map(|line_group_index| self.line_group(line_group_index))
     }
 
+    /// `None` if the line group has no tokens
+    pub fn line_group_text_range(&self, line_group_index: usize) -> Option<TextPositionRange> {
+        let tokens = self.line_group(line_group_index);
+        Some(tokens.first()?.range().join(tokens.last()?.range()))
+    }
+
     fn line_group(&self, line_group_index: usize) -> &[TomlToken] {
         let start = self.line_group_starts[line_group_index];
         let end = if line_group_index + 1 < self.line_group_starts.len() {
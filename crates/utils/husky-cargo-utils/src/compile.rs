@@ -6,20 +6,37 @@ use cargo::{
 pub fn compile_workspace<R>(
     manifest_path: &std::path::Path,
     f: impl FnOnce(Compilation) -> R,
-) -> Result<R, ()> {
+) -> Result<R, String> {
+    compile_workspace_aux(manifest_path, cargo::ops::Packages::Default, f)
+}
+
+/// only builds the given packages of the workspace and whatever they depend on
+pub fn compile_workspace_packages<R>(
+    manifest_path: &std::path::Path,
+    package_names: Vec<String>,
+    f: impl FnOnce(Compilation) -> R,
+) -> Result<R, String> {
+    compile_workspace_aux(
+        manifest_path,
+        cargo::ops::Packages::Packages(package_names),
+        f,
+    )
+}
+
+fn compile_workspace_aux<R>(
+    manifest_path: &std::path::Path,
+    spec: cargo::ops::Packages,
+    f: impl FnOnce(Compilation) -> R,
+) -> Result<R, String> {
     assert!(manifest_path.is_absolute());
-    let gctx = cargo::GlobalContext::default().expect("what the hell");
-    let workspace = Workspace::new(manifest_path, &gctx).expect("what the hell");
+    let gctx = cargo::GlobalContext::default().map_err(|error| format!("{error:#}"))?;
+    let workspace = Workspace::new(manifest_path, &gctx).map_err(|error| format!("{error:#}"))?;
     workspace.gctx().shell().set_verbosity(Verbosity::Quiet);
-    let mut compile_opts =
-        cargo::ops::CompileOptions::new(&gctx, CompileMode::Build).expect("what the hell");
-    compile_opts.spec = cargo::ops::Packages::Default;
+    let mut compile_opts = cargo::ops::CompileOptions::new(&gctx, CompileMode::Build)
+        .map_err(|error| format!("{error:#}"))?;
+    compile_opts.spec = spec;
     match cargo::ops::compile(&workspace, &compile_opts) {
         Ok(compilation) => Ok(f(compilation)),
-        Err(_error) => {
-            // p!(manifest_path, error);
-            // todo!()
-            Err(())
-        }
+        Err(error) => Err(format!("{error:#}")),
     }
 }
@@ -1,5 +1,92 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+//! fuzzy matching of a query against candidate names,
+//! the query matches if its characters appear in order in the candidate, ignoring case
+
+const MATCH_SCORE: i64 = 16;
+const CONSECUTIVE_BONUS: i64 = 8;
+const WORD_START_BONUS: i64 = 12;
+const EXACT_CASE_BONUS: i64 = 1;
+const GAP_PENALTY: i64 = 1;
+
+/// `None` if the query doesn't match, otherwise the score of the best alignment, the higher the better
+///
+/// an empty query matches everything with score zero
+pub fn fuzzy_match_score(query: &str, candidate: &str) -> Option<u32> {
+    let query: Vec<char> = query.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    let Some((&q0, query_rest)) = query.split_first() else {
+        return Some(0);
+    };
+    // `scores[i]` is the best score with the last matched query char at `candidate[i]`
+    let mut scores: Vec<Option<i64>> = (0..candidate.len())
+        .map(|i| {
+            chars_eq_ignoring_case(candidate[i], q0)
+                .then(|| char_score(&candidate, i, q0) - GAP_PENALTY * i as i64)
+        })
+        .collect();
+    for &q in query_rest {
+        let mut next_scores: Vec<Option<i64>> = vec![None; candidate.len()];
+        for i in 0..candidate.len() {
+            if !chars_eq_ignoring_case(candidate[i], q) {
+                continue;
+            }
+            next_scores[i] = (0..i)
+                .filter_map(|k| {
+                    let transition = match k + 1 == i {
+                        true => CONSECUTIVE_BONUS,
+                        false => -GAP_PENALTY * (i - k - 1) as i64,
+                    };
+                    Some(scores[k]? + transition)
+                })
+                .max()
+                .map(|score| score + char_score(&candidate, i, q))
+        }
+        scores = next_scores
+    }
+    let best = scores.into_iter().flatten().max()?;
+    Some(best.max(0) as u32)
+}
+
+/// returns the matched candidates, best first;
+/// candidates with equal scores keep their original order
+pub fn fuzzy_search<T>(
+    query: &str,
+    candidates: impl IntoIterator<Item = T>,
+    name: impl Fn(&T) -> &str,
+) -> Vec<T> {
+    let mut matches: Vec<(u32, T)> = candidates
+        .into_iter()
+        .filter_map(|candidate| Some((fuzzy_match_score(query, name(&candidate))?, candidate)))
+        .collect();
+    matches.sort_by(|(score0, _), (score1, _)| score1.cmp(score0));
+    matches
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+fn char_score(candidate: &[char], i: usize, q: char) -> i64 {
+    let mut score = MATCH_SCORE;
+    if candidate[i] == q {
+        score += EXACT_CASE_BONUS
+    }
+    if is_word_start(candidate, i) {
+        score += WORD_START_BONUS
+    }
+    score
+}
+
+fn chars_eq_ignoring_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// at the start, after a separator, or at a lowercase-to-uppercase transition
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    if i == 0 {
+        return true;
+    }
+    let prev = chars[i - 1];
+    let curr = chars[i];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && curr.is_uppercase())
 }
 
 #[cfg(test)]
@@ -7,8 +94,38 @@ mod tests {
     use super::*;
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn fuzzy_match_score_works() {
+        assert!(fuzzy_match_score("", "anything").is_some());
+        assert!(fuzzy_match_score("cc", "ConnectedComponent").is_some());
+        assert!(fuzzy_match_score("ccp", "connected_component").is_some());
+        assert!(fuzzy_match_score("xyz", "connected_component").is_none());
+        assert!(fuzzy_match_score("ba", "ab").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_score_prefers_better_matches() {
+        let score = |query, candidate| fuzzy_match_score(query, candidate).unwrap();
+        // consecutive
+        assert!(score("conn", "connected") > score("conn", "cotnon"));
+        // word starts
+        assert!(score("cc", "ConnectedComponent") > score("cc", "accent"));
+        // exact case
+        assert!(score("Raw", "RawContour") > score("Raw", "raw_contour"));
+    }
+
+    #[test]
+    fn fuzzy_search_works() {
+        assert_eq!(
+            fuzzy_search(
+                "line",
+                [
+                    "LineSegmentSketch",
+                    "find_concave_components",
+                    "line_segment"
+                ],
+                |s| s
+            ),
+            ["line_segment", "LineSegmentSketch"]
+        );
     }
 }
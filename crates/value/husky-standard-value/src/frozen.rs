@@ -133,6 +133,10 @@ pub enum FrozenValue {
     OptionSizedRef(Option<Arc<dyn FrozenDyn>>),
     OptionSizedRefMut(Option<Arc<dyn FrozenDyn>>),
     Intrinsic(Arc<dyn FrozenDyn>),
+    /// captured values are not frozen, only the closure expression is remembered
+    Closure {
+        expr_raw: usize,
+    },
 }
 
 impl IsFrozenValue for FrozenValue {
@@ -189,6 +193,9 @@ impl IsFrozenValue for FrozenValue {
             FrozenValue::OptionSizedRef(_) => todo!(),
             FrozenValue::OptionSizedRefMut(_) => todo!(),
             FrozenValue::Intrinsic(_) => todo!(),
+            FrozenValue::Closure { expr_raw } => {
+                ValuePresentation::AdHoc(format!("closure#{expr_raw}"))
+            }
         }
     }
 
@@ -232,6 +239,7 @@ impl IsFrozenValue for FrozenValue {
             FrozenValue::OptionSizedRef(_) => todo!(),
             FrozenValue::OptionSizedRefMut(_) => todo!(),
             FrozenValue::Intrinsic(_) => todo!(),
+            FrozenValue::Closure { .. } => Visual::Void,
         }
     }
 }
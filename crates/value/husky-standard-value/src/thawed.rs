@@ -22,7 +22,7 @@ use frozen::FrozenValue;
 use husky_decl_macro_utils::{
     for_all_non_unit_tuple_tys, for_all_primitive_tys, for_all_ritchie_tys,
 };
-use husky_value::{vm_control_flow::VmControlFlow, IsThawedValue};
+use husky_value::{closure::VmClosure, vm_control_flow::VmControlFlow, IsThawedValue};
 use husky_value_macros::thawed_value_ty;
 use husky_value_protocol::presentation::{EnumUnitValuePresenter, ValuePresentation};
 use husky_visual_protocol::{synchrotron::VisualSynchrotron, visual::Visual};
@@ -332,6 +332,8 @@ pub enum ThawedValue {
         index: usize,
         presenter: EnumUnitValuePresenter,
     },
+    /// closure created by the vm
+    Closure(Arc<VmClosure<ThawedValue>>),
 }
 
 pub type ExceptedThawedValue = Excepted<ThawedValue>;
@@ -383,6 +385,17 @@ impl IsThawedValue for ThawedValue {
         todo!()
     }
 
+    fn from_vm_closure(closure: VmClosure<Self>) -> Self {
+        ThawedValue::Closure(Arc::new(closure))
+    }
+
+    fn vm_closure(&self) -> &VmClosure<Self> {
+        match self {
+            ThawedValue::Closure(closure) => closure,
+            _ => unreachable!("not a closure"),
+        }
+    }
+
     fn to_bool(self) -> bool {
         match self {
             ThawedValue::Bool(val) => val,
@@ -504,6 +517,7 @@ impl IsThawedValue for ThawedValue {
             ThawedValue::OptionLeash(_) => todo!(),
             ThawedValue::OptionSizedRef(_) => todo!(),
             ThawedValue::OptionSizedMut(_) => todo!(),
+            ThawedValue::Closure(_) => unreachable!("closures can't be indexed"),
             ThawedValue::EnumUnit { index, presenter } => todo!(),
         }
     }
@@ -564,6 +578,9 @@ impl IsThawedValue for ThawedValue {
             ThawedValue::OptionLeash(_) => todo!(),
             ThawedValue::OptionSizedRef(_) => todo!(),
             ThawedValue::OptionSizedMut(_) => todo!(),
+            ThawedValue::Closure(ref closure) => FrozenValue::Closure {
+                expr_raw: closure.expr_raw(),
+            },
         }
     }
 
@@ -596,14 +613,17 @@ impl IsThawedValue for ThawedValue {
             ThawedValue::F32(_) => todo!(),
             ThawedValue::F64(_) => todo!(),
             ThawedValue::StringLiteral(string_literal_id) => todo!(),
-            ThawedValue::Owned(owned_thawed_value) => todo!(),
+            ThawedValue::Owned(owned_thawed_value) => {
+                ThawedValue::Ref(&**owned_thawed_value as *const dyn ThawedDyn)
+            }
             ThawedValue::Leash(_) => todo!(),
-            ThawedValue::Ref(_) => todo!(),
+            ThawedValue::Ref(ptr) => ThawedValue::Ref(*ptr),
             ThawedValue::Mut(ptr) => todo!(),
             ThawedValue::OptionBox(thawed_dyn) => todo!(),
             ThawedValue::OptionLeash(_) => todo!(),
             ThawedValue::OptionSizedRef(_) => todo!(),
             ThawedValue::OptionSizedMut(ptr) => ThawedValue::OptionSizedMut(*ptr),
+            ThawedValue::Closure(closure) => ThawedValue::Closure(closure.clone()),
             ThawedValue::EnumUnit { index, presenter } => todo!(),
         }
     }
@@ -650,6 +670,8 @@ impl IsThawedValue for ThawedValue {
             ThawedValue::OptionLeash(_) => todo!(),
             ThawedValue::OptionSizedRef(_) => todo!(),
             ThawedValue::OptionSizedMut(ptr) => ThawedValue::OptionSizedMut(*ptr),
+            // closures never mutate their captures
+            ThawedValue::Closure(closure) => ThawedValue::Closure(closure.clone()),
             ThawedValue::EnumUnit { index, presenter } => todo!(),
         }
     }
@@ -687,12 +709,13 @@ impl IsThawedValue for ThawedValue {
             ThawedValue::StringLiteral(_) => todo!(),
             ThawedValue::Owned(_) => todo!(),
             ThawedValue::Leash(_) => todo!(),
-            ThawedValue::Ref(_) => todo!(),
+            ThawedValue::Ref(ptr) => ThawedValue::Ref(ptr),
             ThawedValue::Mut(_) => todo!(),
             ThawedValue::OptionBox(_) => todo!(),
             ThawedValue::OptionLeash(_) => todo!(),
             ThawedValue::OptionSizedRef(_) => todo!(),
             ThawedValue::OptionSizedMut(_) => todo!(),
+            ThawedValue::Closure(ref closure) => ThawedValue::Closure(closure.clone()),
             ThawedValue::EnumUnit { .. } => todo!(),
         }
     }
@@ -789,12 +812,21 @@ impl ThawedValue {
                     .expect("type id is correct");
                 unsafe { std::mem::transmute(slf) }
             }
-            ThawedValue::Ref(_) => todo!(),
+            ThawedValue::Ref(ptr) => {
+                let slf: &T = ((unsafe { &*ptr } as &dyn ThawedDyn) as &dyn std::any::Any)
+                    .downcast_ref()
+                    .expect("type id is correct");
+                unsafe { std::mem::transmute(slf) }
+            }
             ThawedValue::Mut(_) => todo!(),
             ThawedValue::OptionBox(_) => todo!(),
             ThawedValue::OptionLeash(_) => todo!(),
             ThawedValue::OptionSizedRef(_) => todo!(),
             ThawedValue::OptionSizedMut(_) => todo!(),
+            // linkets taking closures are interpreted
+            ThawedValue::Closure(_) => {
+                unreachable!("closures created by the vm never reach compiled code")
+            }
             ThawedValue::EnumUnit { index, presenter } => todo!(),
         }
     }
@@ -1092,6 +1124,7 @@ impl std::ops::Neg for ThawedValue {
             ThawedValue::OptionLeash(_) => todo!(),
             ThawedValue::OptionSizedRef(_) => todo!(),
             ThawedValue::OptionSizedMut(_) => todo!(),
+            ThawedValue::Closure(_) => unreachable!("closures can't be negated"),
             ThawedValue::EnumUnit { index, presenter } => todo!(),
         }
     }
@@ -0,0 +1,38 @@
+/// closure created by the vm
+///
+/// it doesn't know about linkets or vmir, so the linket that created the closure is stored as a raw id,
+/// and the closure expression as a raw arena index into the vmir region of that linket.
+///
+/// copyable captures are stored by value and the others as references into the frame that created the closure,
+/// which outlives every call of the closure, as it would in the transpiled Rust code.
+#[derive(Debug)]
+pub struct VmClosure<ThawedValue> {
+    linket_raw: u32,
+    expr_raw: usize,
+    /// pairs of raw runtime variable index and captured value
+    captures: Vec<(usize, ThawedValue)>,
+}
+
+impl<ThawedValue> VmClosure<ThawedValue> {
+    pub fn new(linket_raw: u32, expr_raw: usize, captures: Vec<(usize, ThawedValue)>) -> Self {
+        Self {
+            linket_raw,
+            expr_raw,
+            captures,
+        }
+    }
+}
+
+impl<ThawedValue> VmClosure<ThawedValue> {
+    pub fn linket_raw(&self) -> u32 {
+        self.linket_raw
+    }
+
+    pub fn expr_raw(&self) -> usize {
+        self.expr_raw
+    }
+
+    pub fn captures(&self) -> &[(usize, ThawedValue)] {
+        &self.captures
+    }
+}
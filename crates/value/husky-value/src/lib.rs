@@ -1,11 +1,13 @@
 #![feature(try_trait_v2)]
 #![feature(try_trait_v2_residual)]
+pub mod closure;
 pub mod exception;
 pub mod ki_control_flow;
 #[cfg(feature = "ugly")]
 pub mod ugly;
 pub mod vm_control_flow;
 
+use closure::VmClosure;
 use exception::IsException;
 use husky_value_protocol::presentation::{
     synchrotron::ValuePresentationSynchrotron, EnumUnitValuePresenter, ValuePresentation,
@@ -203,6 +205,9 @@ pub trait IsThawedValue:
     fn r#move(&mut self) -> Self;
     fn from_str_literal(str_value: Arc<str>) -> Self;
     fn from_enum_index(index: usize, presenter: EnumUnitValuePresenter) -> Self;
+    fn from_vm_closure(closure: VmClosure<Self>) -> Self;
+    /// should unreachable if not a closure created by the vm
+    fn vm_closure(&self) -> &VmClosure<Self>;
     fn to_bool(self) -> bool;
     fn to_i64(self) -> i64;
     fn to_usize(self) -> usize;
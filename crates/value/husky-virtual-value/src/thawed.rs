@@ -1,5 +1,5 @@
 use crate::*;
-use husky_value::{IsThawedValue, IsValue};
+use husky_value::{closure::VmClosure, IsThawedValue, IsValue};
 use husky_value_protocol::presentation::ValuePresentation;
 use husky_value_protocol::presentation::{
     synchrotron::ValuePresentationSynchrotron, EnumUnitValuePresenter, ValuePresenterCache,
@@ -53,6 +53,17 @@ impl IsThawedValue for Value {
         todo!()
     }
 
+    fn from_vm_closure(closure: VmClosure<Self>) -> Self {
+        Value::Closure(std::sync::Arc::new(closure))
+    }
+
+    fn vm_closure(&self) -> &VmClosure<Self> {
+        match self {
+            Value::Closure(closure) => closure,
+            _ => unreachable!("not a closure"),
+        }
+    }
+
     fn to_bool(self) -> bool {
         todo!()
     }
@@ -6,7 +6,7 @@ use crate::{
     exception::{ExceptedValue, Exception},
     slush::SlushValue,
 };
-use husky_value::IsValue;
+use husky_value::{closure::VmClosure, IsValue};
 use husky_value_protocol::presentation::{
     synchrotron::ValuePresentationSynchrotron, EnumUnitValuePresenter, ValuePresentation,
     ValuePresenterCache,
@@ -46,6 +46,7 @@ pub enum Value {
     },
     Vec(Vec<Value>),
     StringLiteral(Arc<str>),
+    Closure(Arc<VmClosure<Value>>),
 }
 
 impl From<std::convert::Infallible> for Value {
@@ -153,6 +154,7 @@ impl IsValue for Value {
             Value::EnumUnit { index, presenter } => todo!(),
             Value::Vec(_) => todo!(),
             Value::StringLiteral(_) => todo!(),
+            Value::Closure(_) => unreachable!("closures can't be unwrapped"),
         }
     }
 
@@ -413,6 +415,7 @@ impl std::ops::Neg for Value {
             Value::EnumUnit { index, presenter } => todo!(),
             Value::Vec(_) => todo!(),
             Value::StringLiteral(_) => todo!(),
+            Value::Closure(_) => unreachable!("closures can't be negated"),
         }
     }
 }
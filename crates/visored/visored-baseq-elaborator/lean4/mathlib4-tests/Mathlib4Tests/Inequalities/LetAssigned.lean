@@ -0,0 +1,67 @@
+import Mathlib
+
+macro "term_trivial": tactic =>`(tactic|
+  first
+  | simp; done
+  | ring; done
+  | ring_nf; done
+  | linarith; done
+  | fail "Could not prove this goal automatically. Afterall, this is an ad hoc implementation."
+)
+
+macro "old_main_hypothesis": tactic =>`(tactic|
+  first
+  | assumption; done
+  | fail "Could not prove this goal automatically. Afterall, this is an ad hoc implementation."
+)
+
+macro "let_assigned": tactic =>`(tactic|
+  first
+  | dsimp; done
+  | fail "Could not prove this goal automatically. Afterall, this is an ad hoc implementation."
+)
+
+macro "term_equivalent": tactic =>`(tactic|
+  first
+  | simp; done
+  | ring; done
+  | ring_nf; done
+  | linarith; done
+  | fail "Could not prove this goal automatically. Afterall, this is an ad hoc implementation."
+)
+
+macro "comm_ring": tactic =>`(tactic|
+  first
+  | ring; done
+  | ring_nf; done
+  | fail "Could not prove this goal automatically. Afterall, this is an ad hoc implementation."
+)
+
+macro "litnum_reduce": tactic =>`(tactic|
+  first
+  | simp; done
+  | simp [*]; done
+  | fail "Could not prove this goal automatically. Afterall, this is an ad hoc implementation."
+)
+
+macro "litnum_bound": tactic =>`(tactic|
+  first
+  | linarith; done
+  | fail "Could not prove this goal automatically. Afterall, this is an ad hoc implementation."
+)
+
+namespace Example1
+def h (x y : ℝ) := by
+  let z := x + y
+  have h1 : z = x + y := by let_assigned
+  have h2 : (x + y) ^ 2 = x ^ 2 + (2 : ℝ) * x * y + y ^ 2 := by comm_ring
+  exact ()
+end Example1
+
+namespace Example2
+def h (a : ℝ) (h1 : a ≤ (1 : ℝ)) := by
+  let b := a + (1 : ℝ)
+  have h2 : b = a + (1 : ℝ) := by let_assigned
+  have h3 : a < (2 : ℝ) := by litnum_bound
+  exact ()
+end Example2
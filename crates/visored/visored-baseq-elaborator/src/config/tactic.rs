@@ -5,6 +5,7 @@ use ordered_float::NotNan;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VdBsqTacticConfig {
     comm_ring: VdBsqCommRingTacticConfig,
+    linarith: VdBsqLinarithTacticConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -13,10 +14,17 @@ pub struct VdBsqCommRingTacticConfig {
     exponential_expansion_limit: usize,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VdBsqLinarithTacticConfig {
+    /// gives up once Fourier–Motzkin elimination produces more constraints than this
+    constraint_limit: usize,
+}
+
 impl VdBsqTacticConfig {
     pub fn new_ad_hoc() -> Self {
         Self {
             comm_ring: VdBsqCommRingTacticConfig::new_ad_hoc(),
+            linarith: VdBsqLinarithTacticConfig::new_ad_hoc(),
         }
     }
 }
@@ -25,6 +33,10 @@ impl VdBsqTacticConfig {
     pub fn comm_ring(&self) -> &VdBsqCommRingTacticConfig {
         &self.comm_ring
     }
+
+    pub fn linarith(&self) -> &VdBsqLinarithTacticConfig {
+        &self.linarith
+    }
 }
 
 impl VdBsqCommRingTacticConfig {
@@ -45,3 +57,17 @@ impl VdBsqCommRingTacticConfig {
         self.exponential_expansion_limit
     }
 }
+
+impl VdBsqLinarithTacticConfig {
+    pub fn new_ad_hoc() -> Self {
+        Self {
+            constraint_limit: 256,
+        }
+    }
+}
+
+impl VdBsqLinarithTacticConfig {
+    pub fn constraint_limit(&self) -> usize {
+        self.constraint_limit
+    }
+}
@@ -295,6 +295,8 @@ impl<'db, 'sess> IsVdMirSequentialElaboratorInner<'db> for VdBsqElaboratorInner<
             VdMirBaseSeparator::Notin => todo!(),
             VdMirBaseSeparator::SetTimes => todo!(),
             VdMirBaseSeparator::TensorOtimes => todo!(),
+            // opaque to the elaborator, so there's nothing inside to elaborate
+            VdMirBaseSeparator::Dvd | VdMirBaseSeparator::Implies | VdMirBaseSeparator::Iff => (),
         }
     }
 
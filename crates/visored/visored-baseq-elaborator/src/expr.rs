@@ -7,7 +7,7 @@ use visored_entity_path::path::VdItemPath;
 use visored_mir_expr::{
     expr::{
         application::VdMirFunc, VdMirExprArena, VdMirExprArenaRef, VdMirExprData, VdMirExprEntry,
-        VdMirExprIdx, VdMirExprIdxRange, VdMirExprMap, VdMirExprOrderedMap,
+        VdMirExprIdx, VdMirExprIdxRange, VdMirExprMap, VdMirExprOrderedMap, VdMirQuantifier,
     },
     hypothesis::constructor::VdMirHypothesisConstructor,
     region::VdMirExprRegionDataRef,
@@ -106,6 +106,17 @@ impl<'sess> VdBsqExprFld<'sess> {
                 }
                 VdMirFunc::InSet => todo!(),
                 VdMirFunc::NormalBaseSqrt(vd_base_sqrt_signature) => todo!(),
+                VdMirFunc::NormalBaseFunction(signature) => {
+                    signature.instantiation().path().show_fmt(f)?;
+                    f.write_str("(")?;
+                    for (i, argument) in arguments.iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(", ")?;
+                        }
+                        argument.show_fmt(VdPrecedenceRange::ANY, f)?;
+                    }
+                    f.write_str(")")
+                }
             },
             VdBsqExprFldData::FoldingSeparatedList { leader, followers } => {
                 let VdMirFunc::NormalBaseSeparator(signature) = followers.first().unwrap().0 else {
@@ -146,6 +157,29 @@ impl<'sess> VdBsqExprFld<'sess> {
                 Ok(())
             }
             VdBsqExprFldData::ItemPath(vd_item_path) => todo!(),
+            VdBsqExprFldData::Quantification {
+                quantifier,
+                letter,
+                domain,
+                body,
+                ..
+            } => {
+                write!(f, "{}{} ∈ ", quantifier.unicode(), letter.unicode())?;
+                domain.show_fmt(VdPrecedenceRange::Greater(VdPrecedence::RELATION), f)?;
+                f.write_str(", ")?;
+                body.show_fmt(VdPrecedenceRange::ANY, f)
+            }
+            VdBsqExprFldData::Limit {
+                letter,
+                approached,
+                body,
+                ..
+            } => {
+                write!(f, "lim_{{{} → ", letter.unicode())?;
+                approached.show_fmt(VdPrecedenceRange::Greater(VdPrecedence::RELATION), f)?;
+                f.write_str("} ")?;
+                body.show_fmt(VdPrecedenceRange::NoLess(VdPrecedence::REDUCE_PREFIX), f)
+            }
         }
     }
 }
@@ -169,6 +203,19 @@ pub enum VdBsqExprFldData<'sess> {
         joined_signature: Option<VdBaseSeparatorSignature>,
     },
     ItemPath(VdItemPath),
+    Quantification {
+        quantifier: VdMirQuantifier,
+        letter: LxMathLetter,
+        variable: VdMirSymbolLocalDefnIdx,
+        domain: VdBsqExprFld<'sess>,
+        body: VdBsqExprFld<'sess>,
+    },
+    Limit {
+        letter: LxMathLetter,
+        variable: VdMirSymbolLocalDefnIdx,
+        approached: VdBsqExprFld<'sess>,
+        body: VdBsqExprFld<'sess>,
+    },
 }
 
 pub type VdBsqExprFollowers<'sess> = SmallVec<[(VdMirFunc, VdBsqExprFld<'sess>); 4]>;
@@ -188,6 +235,8 @@ impl<'sess> VdBsqExprFldData<'sess> {
                 joined_signature,
             } => followers.first().unwrap().0.outer_precedence(),
             VdBsqExprFldData::ItemPath(vd_item_path) => todo!(),
+            VdBsqExprFldData::Quantification { .. } => VdPrecedence::QUANTIFIER,
+            VdBsqExprFldData::Limit { .. } => VdPrecedence::REDUCE_PREFIX,
         }
     }
 }
@@ -217,13 +266,10 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
     ) -> VdBsqExprFldData<'sess> {
         match *entry.data() {
             VdMirExprData::Literal(vd_literal) => VdBsqExprFldData::Literal(vd_literal),
-            VdMirExprData::Variable(local_defn_idx) => {
-                let lx_math_letter =
-                    match *symbol_local_defn_storage.defn_arena()[local_defn_idx].head() {
-                        VdMirSymbolLocalDefnHead::Letter(lx_math_letter) => lx_math_letter,
-                    };
-                VdBsqExprFldData::Variable(lx_math_letter, local_defn_idx)
-            }
+            VdMirExprData::Variable(local_defn_idx) => VdBsqExprFldData::Variable(
+                Self::symbol_letter(local_defn_idx, symbol_local_defn_storage),
+                local_defn_idx,
+            ),
             VdMirExprData::Application {
                 function,
                 arguments,
@@ -257,6 +303,37 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                 joined_signature,
             },
             VdMirExprData::ItemPath(vd_item_path) => VdBsqExprFldData::ItemPath(vd_item_path),
+            VdMirExprData::Quantification {
+                quantifier,
+                variable,
+                domain,
+                body,
+            } => VdBsqExprFldData::Quantification {
+                quantifier,
+                letter: Self::symbol_letter(variable, symbol_local_defn_storage),
+                variable,
+                domain: self.expr_fld(domain),
+                body: self.expr_fld(body),
+            },
+            VdMirExprData::Limit {
+                variable,
+                approached,
+                body,
+            } => VdBsqExprFldData::Limit {
+                letter: Self::symbol_letter(variable, symbol_local_defn_storage),
+                variable,
+                approached: self.expr_fld(approached),
+                body: self.expr_fld(body),
+            },
+        }
+    }
+
+    fn symbol_letter(
+        local_defn_idx: VdMirSymbolLocalDefnIdx,
+        symbol_local_defn_storage: &VdMirSymbolLocalDefnStorage,
+    ) -> LxMathLetter {
+        match *symbol_local_defn_storage.defn_arena()[local_defn_idx].head() {
+            VdMirSymbolLocalDefnHead::Letter(lx_math_letter) => lx_math_letter,
         }
     }
 }
@@ -303,17 +380,52 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
         expr: VdBsqExprFld<'sess>,
         hypothesis_constructor: &mut VdMirHypothesisConstructor<'db, VdBsqHypothesisIdx<'sess>>,
     ) -> VdMirExprIdx {
-        let data = match *expr.data() {
+        let data = self.transcribe_expr_data(expr, hypothesis_constructor);
+        let ty = expr.ty();
+        let expected_ty = expr.expected_ty();
+        hypothesis_constructor.construct_new_expr(data, ty, expected_ty)
+    }
+
+    fn transcribe_expr_data(
+        &self,
+        expr: VdBsqExprFld<'sess>,
+        hypothesis_constructor: &mut VdMirHypothesisConstructor<'db, VdBsqHypothesisIdx<'sess>>,
+    ) -> VdMirExprData {
+        match *expr.data() {
             VdBsqExprFldData::Literal(lit) => VdMirExprData::Literal(lit),
             VdBsqExprFldData::Variable(_, symbol) => VdMirExprData::Variable(symbol),
             VdBsqExprFldData::Application {
                 function,
                 ref arguments,
-            } => todo!(),
+            } => {
+                // arguments must be contiguous, so their descendants are constructed first
+                let arguments: Vec<_> = arguments
+                    .iter()
+                    .map(|&argument| {
+                        (
+                            self.transcribe_expr_data(argument, hypothesis_constructor),
+                            argument.ty(),
+                            argument.expected_ty(),
+                        )
+                    })
+                    .collect();
+                VdMirExprData::Application {
+                    function,
+                    arguments: hypothesis_constructor.construct_new_exprs(arguments),
+                }
+            }
             VdBsqExprFldData::FoldingSeparatedList {
                 leader,
                 ref followers,
-            } => todo!(),
+            } => VdMirExprData::FoldingSeparatedList {
+                leader: self.transcribe_expr(leader, hypothesis_constructor),
+                followers: followers
+                    .iter()
+                    .map(|&(func, follower)| {
+                        (func, self.transcribe_expr(follower, hypothesis_constructor))
+                    })
+                    .collect(),
+            },
             VdBsqExprFldData::ChainingSeparatedList {
                 leader,
                 ref followers,
@@ -329,9 +441,28 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                 joined_signature,
             },
             VdBsqExprFldData::ItemPath(vd_item_path) => todo!(),
-        };
-        let ty = expr.ty();
-        let expected_ty = expr.expected_ty();
-        hypothesis_constructor.construct_new_expr(data, ty, expected_ty)
+            VdBsqExprFldData::Quantification {
+                quantifier,
+                variable,
+                domain,
+                body,
+                ..
+            } => VdMirExprData::Quantification {
+                quantifier,
+                variable,
+                domain: self.transcribe_expr(domain, hypothesis_constructor),
+                body: self.transcribe_expr(body, hypothesis_constructor),
+            },
+            VdBsqExprFldData::Limit {
+                variable,
+                approached,
+                body,
+                ..
+            } => VdMirExprData::Limit {
+                variable,
+                approached: self.transcribe_expr(approached, hypothesis_constructor),
+                body: self.transcribe_expr(body, hypothesis_constructor),
+            },
+        }
     }
 }
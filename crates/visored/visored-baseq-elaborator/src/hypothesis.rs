@@ -63,10 +63,17 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
             VdBsqHypothesisConstruction::Apply {
                 path,
                 is_real_coercion,
+                ref side_conditions,
             } => VdMirHypothesisConstruction::Apply {
                 path,
                 is_real_coercion: self
                     .transcribe_coercion(is_real_coercion, hypothesis_constructor),
+                side_conditions: side_conditions
+                    .iter()
+                    .map(|&side_condition| {
+                        self.transcribe_hypothesis(side_condition, None, hypothesis_constructor)
+                    })
+                    .collect(),
             },
             VdBsqHypothesisConstruction::Assume => VdMirHypothesisConstruction::Assume,
             VdBsqHypothesisConstruction::TermEquivalent { hypothesis } => {
@@ -82,6 +89,7 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
             VdBsqHypothesisConstruction::LetAssigned => VdMirHypothesisConstruction::LetAssigned,
             VdBsqHypothesisConstruction::LitnumReduce => VdMirHypothesisConstruction::LitnumReduce,
             VdBsqHypothesisConstruction::LitnumBound => VdMirHypothesisConstruction::LitnumBound,
+            VdBsqHypothesisConstruction::Linarith => VdMirHypothesisConstruction::Linarith,
         };
         let prop = match explicit_prop {
             Some(prop) => prop,
@@ -1,15 +1,17 @@
 use super::*;
 use crate::coercion::VdBsqCoercion;
+use smallvec::SmallVec;
 use std::marker::PhantomData;
 use visored_entity_path::theorem::VdTheoremPath;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VdBsqHypothesisConstruction<'sess> {
     TermTrivial(bool),
     Assume,
     Apply {
         path: VdTheoremPath,
         is_real_coercion: VdBsqCoercion<'sess>,
+        side_conditions: SmallVec<[VdBsqHypothesisIdx<'sess>; 2]>,
     },
     CommRing,
     TermEquivalent {
@@ -19,4 +21,5 @@ pub enum VdBsqHypothesisConstruction<'sess> {
     LetAssigned,
     LitnumReduce,
     LitnumBound,
+    Linarith,
 }
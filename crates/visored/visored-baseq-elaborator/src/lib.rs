@@ -18,6 +18,7 @@ mod signature;
 pub mod strategy;
 pub mod tactic;
 pub mod term;
+pub mod theorem;
 #[cfg(test)]
 mod tests;
 pub mod variable;
@@ -67,7 +67,9 @@ where
     match *expr.data() {
         VdBsqExprFldData::Literal(_)
         | VdBsqExprFldData::Variable(_, _)
-        | VdBsqExprFldData::ItemPath(_) => Pure(expr),
+        | VdBsqExprFldData::ItemPath(_)
+        | VdBsqExprFldData::Quantification { .. }
+        | VdBsqExprFldData::Limit { .. } => Pure(expr),
         VdBsqExprFldData::Application {
             function,
             ref arguments,
@@ -80,8 +82,9 @@ where
                 | VdMirFunc::NormalBaseBinaryOpr(_)
                 | VdMirFunc::Power(_)
                 | VdMirFunc::InSet
-                | VdMirFunc::NormalBaseSqrt(_) => mapm_collect(arguments, |&argument| f(argument))
-                    .map(|elr, arguments| {
+                | VdMirFunc::NormalBaseSqrt(_)
+                | VdMirFunc::NormalBaseFunction(_) => {
+                    mapm_collect(arguments, |&argument| f(argument)).map(|elr, arguments| {
                         elr.mk_expr(
                             VdBsqExprFldData::Application {
                                 function,
@@ -90,7 +93,8 @@ where
                             expr.ty(),
                             expr.expected_ty(),
                         )
-                    }),
+                    })
+                }
             }
         }
         VdBsqExprFldData::FoldingSeparatedList {
@@ -2,7 +2,9 @@ use crate::tactic::VdBsqTactic;
 use crate::*;
 use config::VdBsqElaboratorConfig;
 use floated_sequential::db::FloaterDb;
+use std::path::Path;
 use strategy::obvious::load_obvious_tactics;
+use theorem::index::{VdBsqTheoremIndex, VdBsqTheoremIndexResult};
 use visored_mir_expr::expr::VdMirExprIdx;
 
 type SuperVarsContext = ();
@@ -13,16 +15,19 @@ pub struct VdBsqSession<'db> {
     floater_db: FloaterDb,
     obvious_tactics: Vec<VdBsqTactic>,
     config: VdBsqElaboratorConfig,
+    theorem_index: VdBsqTheoremIndex,
 }
 
 impl<'db> VdBsqSession<'db> {
-    pub fn new(eterner_db: &'db EternerDb) -> Self {
-        Self {
+    /// loads the theorem table under `specs_dir`, so build one session and share it
+    pub fn new(eterner_db: &'db EternerDb, specs_dir: &Path) -> VdBsqTheoremIndexResult<Self> {
+        Ok(Self {
             eterner_db,
             floater_db: FloaterDb::default(),
             obvious_tactics: load_obvious_tactics(),
             config: VdBsqElaboratorConfig::new_ad_hoc(),
-        }
+            theorem_index: VdBsqTheoremIndex::from_specs_dir(specs_dir)?,
+        })
     }
 }
 
@@ -42,4 +47,8 @@ impl<'db> VdBsqSession<'db> {
     pub fn config(&self) -> &VdBsqElaboratorConfig {
         &self.config
     }
+
+    pub fn theorem_index(&self) -> &VdBsqTheoremIndex {
+        &self.theorem_index
+    }
 }
@@ -1,15 +1,57 @@
 use crate::*;
 use elaborator::VdBsqElaboratorInner;
+use theorem::pattern::VdBsqTheoremRelation;
 use visored_signature::signature::{separator::base::VdBaseSeparatorSignature, VdSignature};
 use visored_term::ty::VdType;
 
 impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
     pub(crate) fn eq_signature(&self, ty: VdType) -> VdBaseSeparatorSignature {
+        self.comparison_signature(VdBsqTheoremRelation::Eq, ty)
+    }
+
+    pub(crate) fn comparison_signature(
+        &self,
+        relation: VdBsqTheoremRelation,
+        ty: VdType,
+    ) -> VdBaseSeparatorSignature {
         let ty_menu = self.ty_menu();
-        if ty == ty_menu.nat {
-            self.signature_menu().nat_eq
+        let signature_menu = self.signature_menu();
+        let [eq, ne, gt, ge] = if ty == ty_menu.nat {
+            [
+                signature_menu.nat_eq,
+                signature_menu.nat_ne,
+                signature_menu.nat_gt,
+                signature_menu.nat_ge,
+            ]
+        } else if ty == ty_menu.int {
+            [
+                signature_menu.int_eq,
+                signature_menu.int_ne,
+                signature_menu.int_gt,
+                signature_menu.int_ge,
+            ]
+        } else if ty == ty_menu.rat {
+            [
+                signature_menu.rat_eq,
+                signature_menu.rat_ne,
+                signature_menu.rat_gt,
+                signature_menu.rat_ge,
+            ]
+        } else if ty == ty_menu.real {
+            [
+                signature_menu.real_eq,
+                signature_menu.real_ne,
+                signature_menu.real_gt,
+                signature_menu.real_ge,
+            ]
         } else {
-            todo!()
+            todo!("ty = {:?}", ty)
+        };
+        match relation {
+            VdBsqTheoremRelation::Eq => eq,
+            VdBsqTheoremRelation::Ne => ne,
+            VdBsqTheoremRelation::Gt => gt,
+            VdBsqTheoremRelation::Ge => ge,
         }
     }
 }
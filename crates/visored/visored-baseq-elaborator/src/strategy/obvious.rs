@@ -37,6 +37,7 @@ pub fn load_obvious_tactics() -> Vec<VdBsqTactic> {
         VdBsqTactic::LitnumReduce,
         VdBsqTactic::LitnumEstimate,
         VdBsqTactic::CommRing,
+        VdBsqTactic::Linarith,
         VdBsqTactic::LibrarySearch,
     ]
 }
@@ -2,6 +2,7 @@ pub mod assumption;
 pub mod comm_ring;
 pub mod kurapika;
 pub mod library_search;
+pub mod linarith;
 pub mod litnum_estimate;
 pub mod litnum_reduce;
 pub mod term_trivial;
@@ -24,6 +25,7 @@ pub enum VdBsqTactic {
     CommRing,
     LitnumReduce,
     LitnumEstimate,
+    Linarith,
 }
 
 // Trivial tactics are not tracked
@@ -36,6 +38,7 @@ pub enum VdBsqTacticCall {
     CommRing,
     LitnumReduce,
     LitnumEstimate,
+    Linarith,
 }
 
 impl VdBsqTactic {
@@ -52,6 +55,7 @@ impl VdBsqTactic {
             VdBsqTactic::CommRing => elaborator.comm_ring(prop),
             VdBsqTactic::LitnumReduce => elaborator.litnum_reduce(prop),
             VdBsqTactic::LitnumEstimate => elaborator.litnum_estimate(prop),
+            VdBsqTactic::Linarith => elaborator.linarith(prop),
         }
     }
 }
@@ -1,20 +1,19 @@
 use super::*;
 use crate::{
-    coercion::VdBsqCoercionOutcome, expr::VdBsqExprFldData,
+    coercion::{VdBsqCoercion, VdBsqTrivialCoercion},
+    expr::VdBsqExprFldData,
     hypothesis::construction::VdBsqHypothesisConstruction,
+    term::litnum::VdBsqLitnumTerm,
+    theorem::{
+        pattern::{VdBsqRelationPattern, VdBsqTermPattern},
+        VdBsqTheoremEntry, VdBsqTheoremParameterTy,
+    },
 };
 use alt_option::*;
 use husky_control_flow_utils::require;
-use visored_entity_path::{
-    path::{
-        set::{VdPreludeSetPath, VdSetPath},
-        VdItemPath,
-    },
-    theorem::VdTheoremPath,
-};
+use smallvec::{smallvec, SmallVec};
 use visored_mir_expr::expr::application::VdMirFunc;
-use visored_mir_opr::separator::VdMirBaseSeparator;
-use visored_term::term::VdTerm;
+use visored_term::ty::VdType;
 
 impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
     pub(crate) fn library_search(&mut self, prop: VdBsqExprFld<'sess>) -> Mhr<'sess> {
@@ -24,41 +23,117 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
     }
 
     fn library_search_inner(&mut self, prop: VdBsqExprFld<'sess>) -> Mhr<'sess> {
-        self.square_nonnegative(prop)?;
+        require!(let Some((relation, lhs, rhs)) = prop.relation_view());
+        let theorem_index = self.session().theorem_index();
+        for candidate in theorem_index.candidates(relation, lhs, rhs) {
+            self.apply_theorem(prop, lhs, rhs, &theorem_index.entries()[candidate])?
+        }
         AltNothing
     }
 
-    fn square_nonnegative(&mut self, prop: VdBsqExprFld<'sess>) -> Mhr<'sess> {
-        use husky_print_utils::*;
-        let VdBsqExprFldData::ChainingSeparatedList {
-            leader,
-            followers,
-            joined_signature: None,
-        } = prop.data()
-        else {
-            return AltNothing;
-        };
-        assert!(followers.len() == 1);
-        let (ge, rhs) = followers[0];
-        let VdMirFunc::NormalBaseSeparator(ge) = ge else {
-            return AltNothing;
-        };
-        require!(ge.opr() == VdMirBaseSeparator::GE);
-        require!(rhs.is_zero());
-        require!(let VdBsqExprFldData::Application {
-            function: pow,
-            arguments: pow_args,
-        } = leader.data());
-        require!(let VdMirFunc::Power(pow) = pow);
-        require!(pow_args[1].eqs_nat128(2));
-        require!(let Some(is_real_coercion) = pow_args[0].is_real(self).coercion());
+    fn apply_theorem(
+        &mut self,
+        prop: VdBsqExprFld<'sess>,
+        lhs: VdBsqExprFld<'sess>,
+        rhs: VdBsqExprFld<'sess>,
+        theorem: &VdBsqTheoremEntry,
+    ) -> Mhr<'sess> {
+        let mut substitution = vec![None; theorem.parameters().len()];
+        require!(theorem.conclusion().lhs().matches(lhs, &mut substitution));
+        require!(theorem.conclusion().rhs().matches(rhs, &mut substitution));
+        let mut is_real_coercion = None;
+        for (parameter, &argument) in std::iter::zip(theorem.parameters(), &substitution) {
+            // parameters only in side conditions would have to be searched for
+            require!(let Some(argument) = argument);
+            match parameter.ty() {
+                VdBsqTheoremParameterTy::Real => {
+                    // obvious coercions aren't replayed in Lean yet, so only trivial ones are taken
+                    require!(
+                        let Some(coercion @ VdBsqCoercion::Trivial(_)) =
+                            argument.is_real(self).coercion()
+                    );
+                    is_real_coercion.get_or_insert(coercion);
+                }
+                VdBsqTheoremParameterTy::Nat => require!(argument.ty() == self.ty_menu().nat),
+            }
+        }
+        let mut side_conditions: SmallVec<[VdBsqHypothesisIdx<'sess>; 2]> = smallvec![];
+        for side_condition in theorem.side_conditions() {
+            require!(
+                let Some(side_condition) =
+                    self.instantiate_side_condition(side_condition, &substitution)
+            );
+            match self.discharge_side_condition(side_condition) {
+                AltJustOk(Ok(hypothesis)) => side_conditions.push(hypothesis),
+                AltJustOk(Err(_)) | AltNothing => return AltNothing,
+                AltJustErr(e) => return AltJustErr(e),
+            }
+        }
         let construction = VdBsqHypothesisConstruction::Apply {
-            path: VdTheoremPath::SquareNonnegative,
-            is_real_coercion,
+            path: theorem.path(),
+            is_real_coercion: is_real_coercion
+                .unwrap_or(VdBsqCoercion::Trivial(VdBsqTrivialCoercion::Identity)),
+            side_conditions,
         };
         let hypothesis = self
             .hypothesis_constructor
             .construct_new_hypothesis(prop, construction);
         AltJustOk(Ok(hypothesis))
     }
+
+    /// side conditions only compare parameters and nonnegative literals,
+    /// so the type of the comparison is the type of the parameters involved
+    ///
+    /// `None` for other side conditions, which aren't supported yet
+    fn instantiate_side_condition(
+        &mut self,
+        side_condition: &VdBsqRelationPattern,
+        substitution: &[Option<VdBsqExprFld<'sess>>],
+    ) -> Option<VdBsqExprFld<'sess>> {
+        let ty = [side_condition.lhs(), side_condition.rhs()]
+            .into_iter()
+            .find_map(|pattern| match *pattern {
+                VdBsqTermPattern::Parameter(index) => Some(substitution[index]?.ty()),
+                _ => None,
+            })?;
+        let lhs = self.instantiate_side_condition_term(side_condition.lhs(), ty, substitution)?;
+        let rhs = self.instantiate_side_condition_term(side_condition.rhs(), ty, substitution)?;
+        let signature = self.comparison_signature(side_condition.relation(), ty);
+        Some(self.mk_expr(
+            VdBsqExprFldData::ChainingSeparatedList {
+                leader: lhs,
+                followers: smallvec![(VdMirFunc::NormalBaseSeparator(signature), rhs)],
+                joined_signature: None,
+            },
+            self.ty_menu().prop,
+            None,
+        ))
+    }
+
+    fn instantiate_side_condition_term(
+        &self,
+        pattern: &VdBsqTermPattern,
+        ty: VdType,
+        substitution: &[Option<VdBsqExprFld<'sess>>],
+    ) -> Option<VdBsqExprFld<'sess>> {
+        match *pattern {
+            VdBsqTermPattern::Parameter(index) => substitution[index],
+            VdBsqTermPattern::Literal(0) => Some(self.mk_zero(Some(ty))),
+            VdBsqTermPattern::Literal(i) if i > 0 => {
+                Some(self.mk_lit(VdBsqLitnumTerm::Int128(i), self.ty_menu().nat, Some(ty)))
+            }
+            VdBsqTermPattern::Literal(_) | VdBsqTermPattern::Application { .. } => None,
+        }
+    }
+
+    fn discharge_side_condition(&mut self, prop: VdBsqExprFld<'sess>) -> Mhr<'sess> {
+        for tactic in self.session().obvious_tactics() {
+            // library search is skipped so that side conditions don't recurse into it
+            if *tactic == VdBsqTactic::LibrarySearch {
+                continue;
+            }
+            tactic.run(prop, self)?
+        }
+        AltNothing
+    }
 }
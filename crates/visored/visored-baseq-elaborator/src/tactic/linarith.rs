@@ -0,0 +1,392 @@
+//! Fourier–Motzkin elimination over the linear parts of the active hypotheses,
+//! with nonlinear monomials treated as atoms.
+use super::*;
+use crate::{
+    foundations::opr::separator::relation::comparison::VdBsqComparisonOpr,
+    hypothesis::construction::VdBsqHypothesisConstruction,
+    term::{
+        comnum::{product::VdBsqProductStem, VdBsqComnumTerm},
+        litnum::VdBsqLitnumTerm,
+        num::VdBsqNumTerm,
+        prop::{num_relation::VdBsqNumRelation, VdBsqPropTerm},
+        VdBsqTerm,
+    },
+};
+use husky_control_flow_utils::require;
+use num_integer::Integer;
+use vec_like::ordered_small_vec_map::OrderedSmallVecPairMap;
+
+impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
+    pub(crate) fn linarith(&mut self, prop: VdBsqExprFld<'sess>) -> Mhr<'sess> {
+        self.with_call(VdBsqTacticCall::Linarith, |slf| slf.linarith_inner(prop))
+    }
+
+    fn linarith_inner(&mut self, prop: VdBsqExprFld<'sess>) -> Mhr<'sess> {
+        let VdBsqTerm::Prop(VdBsqPropTerm::NumRelation(goal)) = prop.term() else {
+            return AltNothing;
+        };
+        let Some(goal_form) = VdBsqLinearForm::from_num_term(goal.lhs_minus_rhs()) else {
+            return AltNothing;
+        };
+        let Some(hypotheses) = self.linarith_hypotheses() else {
+            return AltNothing;
+        };
+        let constraint_limit = self
+            .session()
+            .config()
+            .tactic()
+            .linarith()
+            .constraint_limit();
+        // `goal_form opr 0` holds if every negation of it contradicts the hypotheses
+        let negated_goals: Vec<VdBsqLinearConstraint<VdBsqProductStem<'sess>>> = match goal.opr() {
+            VdBsqComparisonOpr::EQ => vec![
+                VdBsqLinearConstraint::new(goal_form.neg(), VdBsqLinearStrictness::Strict),
+                VdBsqLinearConstraint::new(goal_form, VdBsqLinearStrictness::Strict),
+            ],
+            VdBsqComparisonOpr::NE => return AltNothing,
+            VdBsqComparisonOpr::LT => vec![VdBsqLinearConstraint::new(
+                goal_form,
+                VdBsqLinearStrictness::NonStrict,
+            )],
+            VdBsqComparisonOpr::LE => vec![VdBsqLinearConstraint::new(
+                goal_form,
+                VdBsqLinearStrictness::Strict,
+            )],
+            VdBsqComparisonOpr::GT => vec![VdBsqLinearConstraint::new(
+                goal_form.neg(),
+                VdBsqLinearStrictness::NonStrict,
+            )],
+            VdBsqComparisonOpr::GE => vec![VdBsqLinearConstraint::new(
+                goal_form.neg(),
+                VdBsqLinearStrictness::Strict,
+            )],
+        };
+        for negated_goal in negated_goals {
+            let mut constraints = hypotheses.clone();
+            constraints.push(negated_goal);
+            if !is_infeasible(constraints, constraint_limit) {
+                return AltNothing;
+            }
+        }
+        let hypothesis = self
+            .hypothesis_constructor
+            .construct_new_hypothesis(prop, VdBsqHypothesisConstruction::Linarith);
+        AltJustOk(Ok(hypothesis))
+    }
+
+    /// returns `None` if there are no hypotheses linarith can make use of
+    fn linarith_hypotheses(&self) -> Option<Vec<VdBsqLinearConstraint<VdBsqProductStem<'sess>>>> {
+        let arena = self.hypothesis_constructor.arena();
+        let mut constraints = vec![];
+        for &hypothesis in self
+            .hypothesis_constructor
+            .stack()
+            .active_hypotheses()
+            .data()
+        {
+            let VdBsqTerm::Prop(VdBsqPropTerm::NumRelation(relation)) =
+                arena[hypothesis].expr().term()
+            else {
+                continue;
+            };
+            constraints.extend(VdBsqLinearConstraint::from_num_relation(relation));
+        }
+        require!(!constraints.is_empty());
+        Some(constraints)
+    }
+}
+
+/// `Σ coefficient * atom + constant`, scaled to integer coefficients
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VdBsqLinearForm<K> {
+    coefficients: OrderedSmallVecPairMap<K, i128, 4>,
+    constant: i128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VdBsqLinearStrictness {
+    /// `> 0`
+    Strict,
+    /// `≥ 0`
+    NonStrict,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VdBsqLinearConstraint<K> {
+    form: VdBsqLinearForm<K>,
+    strictness: VdBsqLinearStrictness,
+}
+
+impl<'sess> VdBsqLinearForm<VdBsqProductStem<'sess>> {
+    fn from_num_term(term: VdBsqNumTerm<'sess>) -> Option<Self> {
+        let mut monomials: Vec<(VdBsqProductStem<'sess>, VdBsqLitnumTerm<'sess>)> = vec![];
+        let constant = match term {
+            VdBsqNumTerm::Litnum(litnum) => litnum,
+            VdBsqNumTerm::Comnum(VdBsqComnumTerm::Atom(atom)) => {
+                monomials.push((atom.into(), VdBsqLitnumTerm::ONE));
+                VdBsqLitnumTerm::ZERO
+            }
+            VdBsqNumTerm::Comnum(VdBsqComnumTerm::Product(product)) => {
+                monomials.push((product.stem(), product.litnum_factor()));
+                VdBsqLitnumTerm::ZERO
+            }
+            VdBsqNumTerm::Comnum(VdBsqComnumTerm::Sum(sum)) => {
+                monomials.extend(sum.monomials().iter().copied());
+                sum.constant_term()
+            }
+        };
+        let constant = litnum_to_ratio(constant)?;
+        let monomials = monomials
+            .into_iter()
+            .map(|(stem, coefficient)| Some((stem, litnum_to_ratio(coefficient)?)))
+            .collect::<Option<Vec<_>>>()?;
+        // clear the denominators
+        let mut denominator = constant.1;
+        for &(_, (_, d)) in &monomials {
+            denominator = denominator.lcm(&d);
+        }
+        let mut coefficients: OrderedSmallVecPairMap<_, i128, 4> = Default::default();
+        for (stem, (n, d)) in monomials {
+            coefficients.insert((stem, n.checked_mul(denominator / d)?));
+        }
+        Some(Self {
+            coefficients,
+            constant: constant.0.checked_mul(denominator / constant.1)?,
+        })
+    }
+}
+
+fn litnum_to_ratio(litnum: VdBsqLitnumTerm) -> Option<(i128, i128)> {
+    match litnum {
+        VdBsqLitnumTerm::Int128(i) => Some((i, 1)),
+        VdBsqLitnumTerm::BigInt(_) => None,
+        VdBsqLitnumTerm::Frac128(frac) => Some((frac.numerator(), frac.denominator())),
+    }
+}
+
+impl<'sess> VdBsqLinearConstraint<VdBsqProductStem<'sess>> {
+    /// `=` gives two constraints and `≠` gives none
+    fn from_num_relation(relation: VdBsqNumRelation<'sess>) -> Vec<Self> {
+        let Some(form) = VdBsqLinearForm::from_num_term(relation.lhs_minus_rhs()) else {
+            return vec![];
+        };
+        match relation.opr() {
+            VdBsqComparisonOpr::EQ => vec![
+                Self::new(form.neg(), VdBsqLinearStrictness::NonStrict),
+                Self::new(form, VdBsqLinearStrictness::NonStrict),
+            ],
+            VdBsqComparisonOpr::NE => vec![],
+            VdBsqComparisonOpr::LT => vec![Self::new(form.neg(), VdBsqLinearStrictness::Strict)],
+            VdBsqComparisonOpr::LE => vec![Self::new(form.neg(), VdBsqLinearStrictness::NonStrict)],
+            VdBsqComparisonOpr::GT => vec![Self::new(form, VdBsqLinearStrictness::Strict)],
+            VdBsqComparisonOpr::GE => vec![Self::new(form, VdBsqLinearStrictness::NonStrict)],
+        }
+    }
+}
+
+impl<K> VdBsqLinearForm<K>
+where
+    K: Copy + Ord,
+{
+    fn neg(&self) -> Self {
+        Self {
+            coefficients: self.coefficients.map_collect(|&c| -c),
+            constant: -self.constant,
+        }
+    }
+
+    /// `a * self + b * other` for positive `a` and `b`, `None` on overflow
+    fn combine(&self, a: i128, other: &Self, b: i128) -> Option<Self> {
+        let mut coefficients: OrderedSmallVecPairMap<K, i128, 4> = Default::default();
+        for &(atom, c) in self.coefficients.iter() {
+            coefficients.insert((atom, c.checked_mul(a)?));
+        }
+        for &(atom, c) in other.coefficients.iter() {
+            let c = c.checked_mul(b)?;
+            let entry = coefficients.get_value_mut_or_insert_default(atom);
+            *entry = entry.checked_add(c)?;
+            if *entry == 0 {
+                coefficients.remove(atom);
+            }
+        }
+        let constant = self
+            .constant
+            .checked_mul(a)?
+            .checked_add(other.constant.checked_mul(b)?)?;
+        let mut form = Self {
+            coefficients,
+            constant,
+        };
+        form.reduce();
+        Some(form)
+    }
+
+    /// divides by the gcd of all the coefficients and the constant
+    fn reduce(&mut self) {
+        let gcd = self
+            .coefficients
+            .iter()
+            .fold(self.constant, |gcd, &(_, c)| gcd.gcd(&c));
+        if gcd > 1 {
+            self.coefficients = self.coefficients.map_collect(|&c| c / gcd);
+            self.constant /= gcd;
+        }
+    }
+
+    fn coefficient(&self, atom: K) -> i128 {
+        self.coefficients.get_value(atom).copied().unwrap_or(0)
+    }
+}
+
+impl<K> VdBsqLinearConstraint<K>
+where
+    K: Copy + Ord,
+{
+    fn new(form: VdBsqLinearForm<K>, strictness: VdBsqLinearStrictness) -> Self {
+        Self { form, strictness }
+    }
+
+    /// `Some(true)` if the constraint is `c > 0` or `c ≥ 0` and never holds,
+    /// `Some(false)` if it always holds, `None` if it has atoms
+    fn is_contradictory(&self) -> Option<bool> {
+        if !self.form.coefficients.is_empty() {
+            return None;
+        }
+        Some(match self.strictness {
+            VdBsqLinearStrictness::Strict => self.form.constant <= 0,
+            VdBsqLinearStrictness::NonStrict => self.form.constant < 0,
+        })
+    }
+}
+
+/// Fourier–Motzkin elimination, `false` if feasible or given up
+fn is_infeasible<K>(mut constraints: Vec<VdBsqLinearConstraint<K>>, constraint_limit: usize) -> bool
+where
+    K: Copy + Ord,
+{
+    loop {
+        let mut remaining = vec![];
+        for constraint in constraints {
+            match constraint.is_contradictory() {
+                Some(true) => return true,
+                Some(false) => (),
+                None => {
+                    if !remaining.contains(&constraint) {
+                        remaining.push(constraint)
+                    }
+                }
+            }
+        }
+        if remaining.is_empty() {
+            return false;
+        }
+        // eliminate the atom producing the fewest new constraints
+        let Some(atom) = remaining
+            .iter()
+            .flat_map(|constraint| constraint.form.coefficients.keys())
+            .min_by_key(|&atom| {
+                let (positives, negatives) =
+                    remaining
+                        .iter()
+                        .fold((0usize, 0usize), |(p, n), constraint| {
+                            match constraint.form.coefficient(atom).signum() {
+                                1 => (p + 1, n),
+                                -1 => (p, n + 1),
+                                _ => (p, n),
+                            }
+                        });
+                positives * negatives
+            })
+        else {
+            return false;
+        };
+        let mut positives = vec![];
+        let mut negatives = vec![];
+        constraints = vec![];
+        for constraint in remaining {
+            match constraint.form.coefficient(atom).signum() {
+                1 => positives.push(constraint),
+                -1 => negatives.push(constraint),
+                _ => constraints.push(constraint),
+            }
+        }
+        for positive in &positives {
+            let a = positive.form.coefficient(atom);
+            for negative in &negatives {
+                let b = -negative.form.coefficient(atom);
+                let Some(form) = positive.form.combine(b, &negative.form, a) else {
+                    return false;
+                };
+                let strictness = match (positive.strictness, negative.strictness) {
+                    (VdBsqLinearStrictness::NonStrict, VdBsqLinearStrictness::NonStrict) => {
+                        VdBsqLinearStrictness::NonStrict
+                    }
+                    _ => VdBsqLinearStrictness::Strict,
+                };
+                constraints.push(VdBsqLinearConstraint::new(form, strictness));
+                if constraints.len() > constraint_limit {
+                    return false;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn vd_bsq_linarith_is_infeasible_works() {
+    use VdBsqLinearStrictness::*;
+
+    #[track_caller]
+    fn t(constraints: &[(&[(u32, i128)], i128, VdBsqLinearStrictness)], expected: bool) {
+        let constraints = constraints
+            .iter()
+            .map(|&(coefficients, constant, strictness)| {
+                VdBsqLinearConstraint::new(
+                    VdBsqLinearForm {
+                        coefficients: coefficients.iter().copied().collect(),
+                        constant,
+                    },
+                    strictness,
+                )
+            })
+            .collect();
+        assert_eq!(is_infeasible(constraints, 100), expected);
+    }
+
+    // x > 0, -x > 0
+    t(&[(&[(0, 1)], 0, Strict), (&[(0, -1)], 0, Strict)], true);
+    // x ≥ 0, -x ≥ 0
+    t(
+        &[(&[(0, 1)], 0, NonStrict), (&[(0, -1)], 0, NonStrict)],
+        false,
+    );
+    // b - a ≥ 0, c + 1 - b > 0, a - c - 1 ≥ 0
+    t(
+        &[
+            (&[(0, -1), (1, 1)], 0, NonStrict),
+            (&[(1, -1), (2, 1)], 1, Strict),
+            (&[(0, 1), (2, -1)], -1, NonStrict),
+        ],
+        true,
+    );
+    // 2x - 1 ≥ 0, 1 - 3x ≥ 0
+    t(
+        &[(&[(0, 2)], -1, NonStrict), (&[(0, -3)], 1, NonStrict)],
+        true,
+    );
+    // 2x - 1 ≥ 0, 2 - 3x ≥ 0
+    t(
+        &[(&[(0, 2)], -1, NonStrict), (&[(0, -3)], 2, NonStrict)],
+        false,
+    );
+    // x + y > 0, x - y > 0
+    t(
+        &[
+            (&[(0, 1), (1, 1)], 0, Strict),
+            (&[(0, 1), (1, -1)], 0, Strict),
+        ],
+        false,
+    );
+    // -1 ≥ 0
+    t(&[(&[], -1, NonStrict)], true);
+}
@@ -2,10 +2,11 @@ pub mod builder;
 pub mod comnum;
 pub mod litnum;
 pub mod num;
+pub mod opaque;
 pub mod prop;
 pub mod set;
 
-use self::{comnum::*, litnum::*, num::*, prop::*, set::*};
+use self::{comnum::*, litnum::*, num::*, opaque::*, prop::*, set::*};
 use crate::{
     elaborator::VdBsqElaboratorInner,
     expr::{VdBsqExprFld, VdBsqExprFldData},
@@ -180,6 +181,13 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                     let exponent = VdBsqFrac128::new128(1, 2).unwrap();
                     VdBsqTerm::new_power(radicand, exponent, self.floater_db())
                 }
+                VdMirFunc::NormalBaseFunction(signature) => self.new_opaque_term(
+                    VdBsqOpaqueTermData::Application {
+                        signature,
+                        arguments: arguments.iter().map(|argument| argument.term()).collect(),
+                    },
+                    ty,
+                ),
             },
             VdBsqExprFldData::FoldingSeparatedList {
                 leader,
@@ -194,6 +202,18 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                         self.floater_db(),
                     )
                 };
+                let opaque_separated_list = |slf: &Self| {
+                    slf.new_opaque_term(
+                        VdBsqOpaqueTermData::SeparatedList {
+                            leader: leader.term(),
+                            followers: followers
+                                .iter()
+                                .map(|&(func, follower)| (func, follower.term()))
+                                .collect(),
+                        },
+                        ty,
+                    )
+                };
                 match func {
                     VdMirFunc::NormalBasePrefixOpr(signature) => todo!(),
                     VdMirFunc::NormalBaseSeparator(signature) => match signature.opr() {
@@ -231,11 +251,15 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                         VdMirBaseSeparator::Notin => todo!(),
                         VdMirBaseSeparator::SetTimes => todo!(),
                         VdMirBaseSeparator::TensorOtimes => todo!(),
+                        VdMirBaseSeparator::Dvd
+                        | VdMirBaseSeparator::Implies
+                        | VdMirBaseSeparator::Iff => opaque_separated_list(self),
                     },
                     VdMirFunc::NormalBaseBinaryOpr(signature) => todo!(),
                     VdMirFunc::Power(signature) => todo!(),
                     VdMirFunc::InSet => todo!(),
                     VdMirFunc::NormalBaseSqrt(vd_base_sqrt_signature) => todo!(),
+                    VdMirFunc::NormalBaseFunction(_) => opaque_separated_list(self),
                 }
             }
             VdBsqExprFldData::ChainingSeparatedList {
@@ -264,6 +288,18 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                             self.floater_db(),
                         )
                     };
+                    let opaque_separated_list = |slf: &Self| {
+                        slf.new_opaque_term(
+                            VdBsqOpaqueTermData::SeparatedList {
+                                leader: leader.term(),
+                                followers: followers
+                                    .iter()
+                                    .map(|&(func, follower)| (func, follower.term()))
+                                    .collect(),
+                            },
+                            ty,
+                        )
+                    };
                     match func {
                         VdMirFunc::NormalBasePrefixOpr(signature) => todo!(),
                         VdMirFunc::NormalBaseSeparator(signature) => match signature.opr() {
@@ -299,11 +335,15 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                             VdMirBaseSeparator::Notin => todo!(),
                             VdMirBaseSeparator::SetTimes => todo!(),
                             VdMirBaseSeparator::TensorOtimes => todo!(),
+                            VdMirBaseSeparator::Dvd
+                            | VdMirBaseSeparator::Implies
+                            | VdMirBaseSeparator::Iff => opaque_separated_list(self),
                         },
                         VdMirFunc::NormalBaseBinaryOpr(signature) => todo!(),
                         VdMirFunc::Power(signature) => todo!(),
                         VdMirFunc::InSet => VdBsqPropTerm::InSet.into(),
                         VdMirFunc::NormalBaseSqrt(vd_base_sqrt_signature) => todo!(),
+                        VdMirFunc::NormalBaseFunction(_) => opaque_separated_list(self),
                     }
                 }
             },
@@ -314,6 +354,36 @@ impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
                 VdItemPath::Trait(path) => todo!(),
                 VdItemPath::TraitItem(path) => todo!(),
             },
+            VdBsqExprFldData::Quantification {
+                quantifier,
+                letter,
+                variable,
+                domain,
+                body,
+            } => self.new_opaque_term(
+                VdBsqOpaqueTermData::Quantification {
+                    quantifier,
+                    letter,
+                    variable,
+                    domain: domain.term(),
+                    body: body.term(),
+                },
+                ty,
+            ),
+            VdBsqExprFldData::Limit {
+                letter,
+                variable,
+                approached,
+                body,
+            } => self.new_opaque_term(
+                VdBsqOpaqueTermData::Limit {
+                    letter,
+                    variable,
+                    approached: approached.term(),
+                    body: body.term(),
+                },
+                ty,
+            ),
         }
     }
 }
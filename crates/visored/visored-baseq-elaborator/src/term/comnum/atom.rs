@@ -6,7 +6,7 @@ use visored_opr::precedence::VdPrecedenceRange;
 #[floated]
 pub struct VdBsqAtomTerm<'sess> {
     #[return_ref]
-    data: VdBsqComnumAtomTermData,
+    data: VdBsqComnumAtomTermData<'sess>,
 }
 
 impl<'sess> std::fmt::Debug for VdBsqAtomTerm<'sess> {
@@ -18,8 +18,9 @@ impl<'sess> std::fmt::Debug for VdBsqAtomTerm<'sess> {
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
-pub enum VdBsqComnumAtomTermData {
+pub enum VdBsqComnumAtomTermData<'sess> {
     Variable(LxMathLetter, VdMirSymbolLocalDefnIdx),
+    Opaque(VdBsqOpaqueTerm<'sess>),
 }
 
 impl<'sess> From<VdBsqAtomTerm<'sess>> for VdBsqNumTerm<'sess> {
@@ -29,7 +30,7 @@ impl<'sess> From<VdBsqAtomTerm<'sess>> for VdBsqNumTerm<'sess> {
 }
 
 impl<'sess> VdBsqComnumTerm<'sess> {
-    pub fn new_atom(data: VdBsqComnumAtomTermData, db: &'sess FloaterDb) -> Self {
+    pub fn new_atom(data: VdBsqComnumAtomTermData<'sess>, db: &'sess FloaterDb) -> Self {
         VdBsqComnumTerm::Atom(VdBsqAtomTerm::new_inner(data, db))
     }
 }
@@ -61,7 +62,7 @@ impl<'sess> VdBsqAtomTerm<'sess> {
     }
 }
 
-impl<'sess> VdBsqComnumAtomTermData {
+impl<'sess> VdBsqComnumAtomTermData<'sess> {
     pub fn show_fmt(
         &self,
         precedence_range: VdPrecedenceRange,
@@ -71,12 +72,14 @@ impl<'sess> VdBsqComnumAtomTermData {
             VdBsqComnumAtomTermData::Variable(lx_math_letter, _) => {
                 write!(f, "{}", lx_math_letter.unicode())
             }
+            VdBsqComnumAtomTermData::Opaque(term) => term.show_fmt(precedence_range, f),
         }
     }
 
     pub fn outer_precedence(&self) -> VdPrecedence {
         match self {
             VdBsqComnumAtomTermData::Variable(_, _) => VdPrecedence::ATOM,
+            VdBsqComnumAtomTermData::Opaque(term) => term.outer_precedence(),
         }
     }
 }
@@ -0,0 +1,155 @@
+use super::*;
+use comnum::atom::VdBsqComnumAtomTermData;
+use latex_math_letter::letter::LxMathLetter;
+use visored_mir_expr::expr::VdMirQuantifier;
+use visored_opr::precedence::VdPrecedence;
+use visored_signature::signature::function::VdBaseFunctionSignature;
+
+/// what the elaborator doesn't reason about, like `\log x`, `a \mid b` or `\lim_{x \to 0} f(x)`,
+/// kept whole so that it's only identified with itself
+#[floated]
+pub struct VdBsqOpaqueTerm<'sess> {
+    #[return_ref]
+    pub data: VdBsqOpaqueTermData<'sess>,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum VdBsqOpaqueTermData<'sess> {
+    Application {
+        signature: VdBaseFunctionSignature,
+        arguments: Vec<VdBsqTerm<'sess>>,
+    },
+    SeparatedList {
+        leader: VdBsqTerm<'sess>,
+        followers: Vec<(VdMirFunc, VdBsqTerm<'sess>)>,
+    },
+    Quantification {
+        quantifier: VdMirQuantifier,
+        letter: LxMathLetter,
+        variable: VdMirSymbolLocalDefnIdx,
+        domain: VdBsqTerm<'sess>,
+        body: VdBsqTerm<'sess>,
+    },
+    Limit {
+        letter: LxMathLetter,
+        variable: VdMirSymbolLocalDefnIdx,
+        approached: VdBsqTerm<'sess>,
+        body: VdBsqTerm<'sess>,
+    },
+}
+
+impl<'sess> std::fmt::Debug for VdBsqOpaqueTerm<'sess> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("OpaqueTerm(`")?;
+        self.show_fmt(VdPrecedenceRange::Any, f)?;
+        f.write_str("`)")
+    }
+}
+
+impl<'sess> VdBsqOpaqueTerm<'sess> {
+    pub fn show_fmt(
+        self,
+        precedence_range: VdPrecedenceRange,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        if precedence_range.contains(self.outer_precedence()) {
+            self.show_fmt_inner(f)
+        } else {
+            f.write_str("(")?;
+            self.show_fmt_inner(f)?;
+            f.write_str(")")
+        }
+    }
+
+    fn show_fmt_inner(self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self.data() {
+            VdBsqOpaqueTermData::Application {
+                signature,
+                ref arguments,
+            } => {
+                signature.instantiation().path().show_fmt(f)?;
+                f.write_str("(")?;
+                for (i, argument) in arguments.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    argument.show_fmt(VdPrecedenceRange::ANY, f)?;
+                }
+                f.write_str(")")
+            }
+            VdBsqOpaqueTermData::SeparatedList {
+                leader,
+                ref followers,
+            } => {
+                let precedence_range = match followers[0].0 {
+                    VdMirFunc::NormalBaseSeparator(signature) => {
+                        signature.opr().left_precedence_range()
+                    }
+                    _ => VdPrecedenceRange::ATOM,
+                };
+                leader.show_fmt(precedence_range, f)?;
+                for &(func, follower) in followers {
+                    f.write_str(" ")?;
+                    match func {
+                        VdMirFunc::NormalBaseSeparator(signature) => signature.opr().show_fmt(f)?,
+                        _ => write!(f, "{:?}", func)?,
+                    }
+                    f.write_str(" ")?;
+                    follower.show_fmt(precedence_range, f)?;
+                }
+                Ok(())
+            }
+            VdBsqOpaqueTermData::Quantification {
+                quantifier,
+                letter,
+                domain,
+                body,
+                ..
+            } => {
+                write!(f, "{}{} ∈ ", quantifier.unicode(), letter.unicode())?;
+                domain.show_fmt(VdPrecedenceRange::Greater(VdPrecedence::RELATION), f)?;
+                f.write_str(", ")?;
+                body.show_fmt(VdPrecedenceRange::ANY, f)
+            }
+            VdBsqOpaqueTermData::Limit {
+                letter,
+                approached,
+                body,
+                ..
+            } => {
+                write!(f, "lim_{{{} → ", letter.unicode())?;
+                approached.show_fmt(VdPrecedenceRange::Greater(VdPrecedence::RELATION), f)?;
+                f.write_str("} ")?;
+                body.show_fmt(VdPrecedenceRange::NoLess(VdPrecedence::REDUCE_PREFIX), f)
+            }
+        }
+    }
+
+    pub fn outer_precedence(self) -> VdPrecedence {
+        match *self.data() {
+            VdBsqOpaqueTermData::Application { .. } => VdPrecedence::ATOM,
+            VdBsqOpaqueTermData::SeparatedList { ref followers, .. } => {
+                followers[0].0.outer_precedence()
+            }
+            VdBsqOpaqueTermData::Quantification { .. } => VdPrecedence::QUANTIFIER,
+            VdBsqOpaqueTermData::Limit { .. } => VdPrecedence::REDUCE_PREFIX,
+        }
+    }
+}
+
+impl<'db, 'sess> VdBsqElaboratorInner<'db, 'sess> {
+    /// propositions stay whole, numbers become atoms of sums and products
+    pub(crate) fn new_opaque_term(
+        &self,
+        data: VdBsqOpaqueTermData<'sess>,
+        ty: VdType,
+    ) -> VdBsqTerm<'sess> {
+        let opaque = VdBsqOpaqueTerm::new_inner(data, self.floater_db());
+        if ty == self.ty_menu().prop {
+            VdBsqPropTerm::Opaque(opaque).into()
+        } else {
+            VdBsqComnumTerm::new_atom(VdBsqComnumAtomTermData::Opaque(opaque), self.floater_db())
+                .into()
+        }
+    }
+}
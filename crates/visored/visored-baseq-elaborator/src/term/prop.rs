@@ -11,6 +11,7 @@ pub enum VdBsqPropTerm<'sess> {
     NumChain(VdBsqNumChain<'sess>),
     Trivial(bool),
     InSet,
+    Opaque(VdBsqOpaqueTerm<'sess>),
 }
 
 impl<'sess> std::fmt::Debug for VdBsqPropTerm<'sess> {
@@ -32,6 +33,7 @@ impl<'sess> VdBsqPropTerm<'sess> {
             VdBsqPropTerm::NumChain(_) => todo!(),
             VdBsqPropTerm::Trivial(b) => write!(f, "{}", b),
             VdBsqPropTerm::InSet => todo!(),
+            VdBsqPropTerm::Opaque(term) => term.show_fmt(precedence_range, f),
         }
     }
 }
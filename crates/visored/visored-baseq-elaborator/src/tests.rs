@@ -7,6 +7,10 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
+use visored_isabelle_hol_transpilation::{
+    helpers::tracker::VdIsabelleTranspilationTracker,
+    scheme::dense::VdIsabelleTranspilationDenseScheme,
+};
 use visored_lean_transpilation::scheme::dense::VdLeanTranspilationDenseScheme;
 use visored_models::VdModels;
 use visored_syn_expr::vibe::VdSynExprVibe;
@@ -40,7 +44,7 @@ fn visored_tactic_basic_elaborator_works() {
                 .to_case(Case::Pascal)
                 .with_extension("lean");
             let content = std::fs::read_to_string(&src_file_path).unwrap();
-            let session = &VdBsqSession::new(db);
+            let session = &VdBsqSession::new(db, dev_paths.specs_dir()).unwrap();
             let tracker = VdBsqElaboratorTracker::new(
                 LxDocumentInput {
                     specs_dir: dev_paths.specs_dir().to_path_buf(),
@@ -132,3 +136,55 @@ macro "litnum_bound": tactic =>`(tactic|
         Path::new("../expect-files/visored-pipeline-works"),
     );
 }
+
+/// the same examples transpiled to Isabelle/HOL, with the proofs found by the baseq elaborator
+#[test]
+fn visored_baseq_elaborator_to_isabelle_works() {
+    use expect_test::expect_file;
+    use husky_case_utils::{Case, ToCase};
+    use husky_path_utils::HuskyLangDevPaths;
+    use relative_path::PathExt;
+
+    let db = &EternerDb::default();
+    let dev_paths = HuskyLangDevPaths::new();
+    let session = &VdBsqSession::new(db, dev_paths.specs_dir()).unwrap();
+    let src_root = &PathBuf::from("latex/main");
+    let tex_files = find_files(src_root, |p| {
+        p.extension().map_or(false, |ext| ext == "tex")
+    })
+    .unwrap();
+    let isabelle_dir = Path::new("../isabelle/Main");
+    for src_file_path in tex_files {
+        let relative_path = src_file_path
+            .relative_to(src_root)
+            .unwrap()
+            .to_case(Case::Pascal)
+            .with_extension("thy");
+        let content = std::fs::read_to_string(&src_file_path).unwrap();
+        let tracker = VdIsabelleTranspilationTracker::new(
+            LxDocumentInput {
+                specs_dir: dev_paths.specs_dir().to_path_buf(),
+                file_path: LxFilePath::new(src_file_path, db),
+                content: &content,
+            },
+            &[],
+            &[],
+            &VdModels {},
+            VdSynExprVibe::ROOT_CNL,
+            db,
+            &VdIsabelleTranspilationDenseScheme,
+            |region_data| VdBsqElaborator::new(VdBsqElaboratorInner::new(session, region_data)),
+        );
+        expect_file!(relative_path.to_logical_path(isabelle_dir)).assert_eq(&format!(
+            r#"theory {}
+  imports Complex_Main
+begin
+
+{}
+end
+"#,
+            relative_path.file_stem().unwrap(),
+            tracker.show_fmt(db)
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+pub mod index;
+pub mod pattern;
+
+use self::index::{VdBsqTheoremIndexError, VdBsqTheoremIndexResult};
+use self::pattern::VdBsqRelationPattern;
+use lisp_csv::expr::{LpCsvExpr, LpCsvExprData, LpCsvLiteral};
+use visored_entity_path::theorem::VdTheoremPath;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VdBsqTheoremEntry {
+    path: VdTheoremPath,
+    lean_name: String,
+    parameters: Vec<VdBsqTheoremParameter>,
+    conclusion: VdBsqRelationPattern,
+    side_conditions: Vec<VdBsqRelationPattern>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VdBsqTheoremParameter {
+    ident: String,
+    ty: VdBsqTheoremParameterTy,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VdBsqTheoremParameterTy {
+    /// any expression coercible to a real number
+    Real,
+    Nat,
+}
+
+impl VdBsqTheoremEntry {
+    pub fn path(&self) -> VdTheoremPath {
+        self.path
+    }
+
+    pub fn lean_name(&self) -> &str {
+        &self.lean_name
+    }
+
+    pub fn parameters(&self) -> &[VdBsqTheoremParameter] {
+        &self.parameters
+    }
+
+    pub fn conclusion(&self) -> &VdBsqRelationPattern {
+        &self.conclusion
+    }
+
+    pub fn side_conditions(&self) -> &[VdBsqRelationPattern] {
+        &self.side_conditions
+    }
+}
+
+impl VdBsqTheoremParameter {
+    pub fn ident(&self) -> &str {
+        &self.ident
+    }
+
+    pub fn ty(&self) -> VdBsqTheoremParameterTy {
+        self.ty
+    }
+}
+
+impl VdBsqTheoremEntry {
+    pub(crate) fn from_lp_csv_exprs(exprs: &[LpCsvExpr]) -> VdBsqTheoremIndexResult<Self> {
+        let [path, lean_name, parameters, conclusion, side_conditions] = exprs else {
+            return Err(VdBsqTheoremIndexError::FieldCount(exprs.len()));
+        };
+        let path = match path.data {
+            LpCsvExprData::Ident(ref ident) => VdTheoremPath::from_ident(ident)
+                .ok_or_else(|| VdBsqTheoremIndexError::UnknownTheorem(ident.clone()))?,
+            _ => return Err(VdBsqTheoremIndexError::Malformed("theorem path")),
+        };
+        let lean_name = match lean_name.data {
+            LpCsvExprData::Literal(LpCsvLiteral::String(ref lean_name)) => lean_name.clone(),
+            _ => return Err(VdBsqTheoremIndexError::Malformed("lean name")),
+        };
+        let parameters: Vec<VdBsqTheoremParameter> = match parameters.data {
+            LpCsvExprData::List(ref parameters) => parameters
+                .iter()
+                .map(VdBsqTheoremParameter::from_lp_csv_expr)
+                .collect::<VdBsqTheoremIndexResult<_>>()?,
+            _ => return Err(VdBsqTheoremIndexError::Malformed("parameter list")),
+        };
+        let conclusion = VdBsqRelationPattern::from_lp_csv_expr(conclusion, &parameters)?;
+        let side_conditions = match side_conditions.data {
+            LpCsvExprData::List(ref side_conditions) => side_conditions
+                .iter()
+                .map(|side_condition| {
+                    VdBsqRelationPattern::from_lp_csv_expr(side_condition, &parameters)
+                })
+                .collect::<VdBsqTheoremIndexResult<_>>()?,
+            _ => return Err(VdBsqTheoremIndexError::Malformed("side condition list")),
+        };
+        Ok(Self {
+            path,
+            lean_name,
+            parameters,
+            conclusion,
+            side_conditions,
+        })
+    }
+}
+
+impl VdBsqTheoremParameter {
+    fn from_lp_csv_expr(expr: &LpCsvExpr) -> VdBsqTheoremIndexResult<Self> {
+        let LpCsvExprData::Application(ref exprs) = expr.data else {
+            return Err(VdBsqTheoremIndexError::Malformed("parameter"));
+        };
+        let [ident, ty] = &exprs[..] else {
+            return Err(VdBsqTheoremIndexError::Malformed("parameter"));
+        };
+        let LpCsvExprData::Ident(ref ident) = ident.data else {
+            return Err(VdBsqTheoremIndexError::Malformed("parameter identifier"));
+        };
+        let ty = match ty.data {
+            LpCsvExprData::Ident(ref ty) => match ty.as_str() {
+                "real" => VdBsqTheoremParameterTy::Real,
+                "nat" => VdBsqTheoremParameterTy::Nat,
+                _ => return Err(VdBsqTheoremIndexError::UnknownParameterType(ty.clone())),
+            },
+            _ => return Err(VdBsqTheoremIndexError::Malformed("parameter type")),
+        };
+        Ok(Self {
+            ident: ident.clone(),
+            ty,
+        })
+    }
+}
@@ -0,0 +1,309 @@
+use super::{
+    pattern::{VdBsqTermPattern, VdBsqTermPatternHead, VdBsqTermView, VdBsqTheoremRelation},
+    *,
+};
+use crate::expr::VdBsqExprFld;
+use lisp_csv::{
+    error::LpCsvFileError,
+    file::{LpCsvFile, LpCsvFileData},
+    parse_lp_csv_filepath,
+    row::LpCsvRow,
+};
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VdBsqTheoremIndexError {
+    #[error("failed to load the theorem table: {0}")]
+    LpCsvFile(#[from] LpCsvFileError),
+    #[error("expect a row of separated fields in the theorem table, got a single expression")]
+    ExprRow,
+    #[error("expect 5 fields in a row of the theorem table, got {0}")]
+    FieldCount(usize),
+    #[error("unknown theorem `{0}`")]
+    UnknownTheorem(String),
+    #[error("unknown parameter type `{0}`")]
+    UnknownParameterType(String),
+    #[error("unknown parameter `{0}`")]
+    UnknownParameter(String),
+    #[error("unknown relation `{0}`")]
+    UnknownRelation(String),
+    #[error("unknown head `{0}`")]
+    UnknownHead(String),
+    #[error("expect {expected} arguments for `{head}`, got {actual}")]
+    Arity {
+        head: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("malformed {0} in the theorem table")]
+    Malformed(&'static str),
+}
+
+pub type VdBsqTheoremIndexResult<T> = Result<T, VdBsqTheoremIndexError>;
+
+/// discrimination tree over the conclusions of theorems,
+/// so that only theorems whose conclusions might match the goal up to instantiation are tried
+#[derive(Debug)]
+pub struct VdBsqTheoremIndex {
+    entries: Vec<VdBsqTheoremEntry>,
+    nodes: Vec<VdBsqTheoremIndexNode>,
+}
+
+#[derive(Debug, Default)]
+struct VdBsqTheoremIndexNode {
+    children: Vec<(VdBsqTheoremKey, usize)>,
+    entries: Vec<usize>,
+}
+
+/// keys of a conclusion flattened in preorder
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum VdBsqTheoremKey {
+    Relation(VdBsqTheoremRelation),
+    Head(VdBsqTermPatternHead, usize),
+    Literal(i128),
+    /// a parameter, matching any subterm
+    Star,
+}
+
+impl VdBsqTheoremIndex {
+    pub fn new(entries: Vec<VdBsqTheoremEntry>) -> Self {
+        let mut slf = Self {
+            entries: vec![],
+            nodes: vec![VdBsqTheoremIndexNode::default()],
+        };
+        for entry in entries {
+            slf.insert(entry)
+        }
+        slf
+    }
+
+    /// loads the standard theorem table under `specs_dir`
+    pub fn from_specs_dir(specs_dir: &Path) -> VdBsqTheoremIndexResult<Self> {
+        Self::from_lp_csv_file_path(&specs_dir.join("visored/theorem_table.lpcsv"))
+    }
+
+    pub fn from_lp_csv_file_path(path: &Path) -> VdBsqTheoremIndexResult<Self> {
+        Self::from_lp_csv_file(&parse_lp_csv_filepath(path)?)
+    }
+
+    pub fn from_lp_csv_file(file: &LpCsvFile) -> VdBsqTheoremIndexResult<Self> {
+        match file.data() {
+            LpCsvFileData::Rows(rows) => Ok(Self::new(
+                rows.iter()
+                    .map(|row| match row {
+                        LpCsvRow::Expr(_) => Err(VdBsqTheoremIndexError::ExprRow),
+                        LpCsvRow::SeparatedExprs(exprs) => {
+                            VdBsqTheoremEntry::from_lp_csv_exprs(exprs)
+                        }
+                    })
+                    .collect::<VdBsqTheoremIndexResult<_>>()?,
+            )),
+        }
+    }
+
+    fn insert(&mut self, entry: VdBsqTheoremEntry) {
+        let conclusion = entry.conclusion();
+        let mut keys = vec![VdBsqTheoremKey::Relation(conclusion.relation())];
+        flatten_term_pattern(conclusion.lhs(), &mut keys);
+        flatten_term_pattern(conclusion.rhs(), &mut keys);
+        let mut node = 0;
+        for key in keys {
+            node = match self.nodes[node]
+                .children
+                .iter()
+                .find(|&&(key1, _)| key1 == key)
+            {
+                Some(&(_, child)) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(VdBsqTheoremIndexNode::default());
+                    self.nodes[node].children.push((key, child));
+                    child
+                }
+            }
+        }
+        self.nodes[node].entries.push(self.entries.len());
+        self.entries.push(entry)
+    }
+}
+
+fn flatten_term_pattern(pattern: &VdBsqTermPattern, keys: &mut Vec<VdBsqTheoremKey>) {
+    match *pattern {
+        VdBsqTermPattern::Parameter(_) => keys.push(VdBsqTheoremKey::Star),
+        VdBsqTermPattern::Literal(i) => keys.push(VdBsqTheoremKey::Literal(i)),
+        VdBsqTermPattern::Application {
+            head,
+            ref arguments,
+        } => {
+            keys.push(VdBsqTheoremKey::Head(head, arguments.len()));
+            for argument in arguments {
+                flatten_term_pattern(argument, keys)
+            }
+        }
+    }
+}
+
+impl VdBsqTheoremIndex {
+    pub fn entries(&self) -> &[VdBsqTheoremEntry] {
+        &self.entries
+    }
+
+    /// indices of the entries whose conclusions might match the goal, in the order of the specs
+    pub(crate) fn candidates<'sess>(
+        &self,
+        relation: VdBsqTheoremRelation,
+        lhs: VdBsqExprFld<'sess>,
+        rhs: VdBsqExprFld<'sess>,
+    ) -> Vec<usize> {
+        // `None` stands for an opaque subterm, which is only matched by a star
+        let mut keys = vec![Some(VdBsqTheoremKey::Relation(relation))];
+        let mut ends = vec![0];
+        flatten_goal_term(lhs, &mut keys, &mut ends);
+        flatten_goal_term(rhs, &mut keys, &mut ends);
+        ends[0] = keys.len();
+        self.candidates_aux(&keys, &ends)
+    }
+
+    /// `ends[i]` is the position right after the subterm starting at position `i`
+    fn candidates_aux(&self, keys: &[Option<VdBsqTheoremKey>], ends: &[usize]) -> Vec<usize> {
+        let mut candidates = vec![];
+        self.collect_candidates(0, 0, keys, ends, &mut candidates);
+        candidates.sort();
+        candidates.dedup();
+        candidates
+    }
+
+    fn collect_candidates(
+        &self,
+        node: usize,
+        position: usize,
+        keys: &[Option<VdBsqTheoremKey>],
+        ends: &[usize],
+        candidates: &mut Vec<usize>,
+    ) {
+        let node = &self.nodes[node];
+        if position == keys.len() {
+            candidates.extend(node.entries.iter().copied());
+            return;
+        }
+        for &(key, child) in &node.children {
+            if key == VdBsqTheoremKey::Star {
+                self.collect_candidates(child, ends[position], keys, ends, candidates)
+            } else if keys[position] == Some(key) {
+                self.collect_candidates(child, position + 1, keys, ends, candidates)
+            }
+        }
+    }
+}
+
+fn flatten_goal_term<'sess>(
+    expr: VdBsqExprFld<'sess>,
+    keys: &mut Vec<Option<VdBsqTheoremKey>>,
+    ends: &mut Vec<usize>,
+) {
+    let position = keys.len();
+    keys.push(None);
+    ends.push(position + 1);
+    match expr.term_view() {
+        VdBsqTermView::Literal(i) => keys[position] = Some(VdBsqTheoremKey::Literal(i)),
+        VdBsqTermView::Application { head, arguments } => {
+            keys[position] = Some(VdBsqTheoremKey::Head(head, arguments.len()));
+            for argument in arguments {
+                flatten_goal_term(argument, keys, ends)
+            }
+            ends[position] = keys.len();
+        }
+        VdBsqTermView::Opaque => (),
+    }
+}
+
+#[test]
+fn vd_bsq_theorem_index_from_specs_dir_works() {
+    let dev_paths = husky_path_utils::HuskyLangDevPaths::new();
+    let index = VdBsqTheoremIndex::from_specs_dir(dev_paths.specs_dir()).unwrap();
+    for &path in VdTheoremPath::ALL {
+        assert_eq!(
+            index
+                .entries()
+                .iter()
+                .filter(|entry| entry.path() == path)
+                .count(),
+            1,
+            "theorem `{}` should be in the table exactly once",
+            path.ident()
+        );
+    }
+    let candidate_paths = |keys: &[Option<VdBsqTheoremKey>], ends: &[usize]| -> Vec<_> {
+        index
+            .candidates_aux(keys, ends)
+            .into_iter()
+            .map(|candidate| index.entries()[candidate].path())
+            .collect()
+    };
+    // x^2 ≥ 0
+    assert_eq!(
+        candidate_paths(
+            &[
+                Some(VdBsqTheoremKey::Relation(VdBsqTheoremRelation::Ge)),
+                Some(VdBsqTheoremKey::Head(VdBsqTermPatternHead::Pow, 2)),
+                None,
+                Some(VdBsqTheoremKey::Literal(2)),
+                Some(VdBsqTheoremKey::Literal(0)),
+            ],
+            &[5, 4, 3, 4, 5],
+        ),
+        [
+            VdTheoremPath::SquareNonnegative,
+            VdTheoremPath::PowNonnegative
+        ]
+    );
+    // x * y > 0
+    assert_eq!(
+        candidate_paths(
+            &[
+                Some(VdBsqTheoremKey::Relation(VdBsqTheoremRelation::Gt)),
+                Some(VdBsqTheoremKey::Head(VdBsqTermPatternHead::Mul, 2)),
+                None,
+                None,
+                Some(VdBsqTheoremKey::Literal(0)),
+            ],
+            &[5, 4, 3, 4, 5],
+        ),
+        [VdTheoremPath::MulPositive]
+    );
+    // x ≥ 0
+    assert_eq!(
+        candidate_paths(
+            &[
+                Some(VdBsqTheoremKey::Relation(VdBsqTheoremRelation::Ge)),
+                None,
+                Some(VdBsqTheoremKey::Literal(0)),
+            ],
+            &[3, 2, 3],
+        ),
+        []
+    );
+}
+
+#[test]
+fn vd_bsq_theorem_index_from_malformed_rows_works() {
+    let load = |s: &str| {
+        VdBsqTheoremIndex::from_lp_csv_file(&lisp_csv::parse_lp_csv_file(s).unwrap()).unwrap_err()
+    };
+    assert!(matches!(
+        load(r#"square_nonnegative, "sq_nonneg", [a real], ge (pow a 2) 0"#),
+        VdBsqTheoremIndexError::FieldCount(4)
+    ));
+    assert!(matches!(
+        load(r#"square_positive, "sq_pos", [a real], gt (pow a 2) 0, []"#),
+        VdBsqTheoremIndexError::UnknownTheorem(_)
+    ));
+    assert!(matches!(
+        load(r#"square_nonnegative, "sq_nonneg", [a real], ge (pow b 2) 0, []"#),
+        VdBsqTheoremIndexError::UnknownParameter(_)
+    ));
+    assert!(matches!(
+        load(r#"square_nonnegative, "sq_nonneg", [a real], ge (pow a) 0, []"#),
+        VdBsqTheoremIndexError::Arity { .. }
+    ));
+}
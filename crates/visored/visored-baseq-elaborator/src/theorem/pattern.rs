@@ -0,0 +1,275 @@
+use super::*;
+use crate::expr::{VdBsqExprFld, VdBsqExprFldData};
+use smallvec::SmallVec;
+use visored_mir_expr::expr::application::VdMirFunc;
+use visored_mir_opr::{
+    opr::{binary::VdMirBaseBinaryOpr, prefix::VdMirBasePrefixOpr},
+    separator::VdMirBaseSeparator,
+};
+use visored_term::term::literal::VdLiteralData;
+
+/// `lt` and `le` are normalized into `gt` and `ge` with both sides swapped
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum VdBsqTheoremRelation {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum VdBsqTermPatternHead {
+    Add,
+    Mul,
+    Sub,
+    Div,
+    Neg,
+    Pow,
+    Sqrt,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VdBsqTermPattern {
+    /// index into the parameters of the theorem
+    Parameter(usize),
+    Literal(i128),
+    Application {
+        head: VdBsqTermPatternHead,
+        arguments: Vec<VdBsqTermPattern>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VdBsqRelationPattern {
+    relation: VdBsqTheoremRelation,
+    lhs: VdBsqTermPattern,
+    rhs: VdBsqTermPattern,
+}
+
+impl VdBsqRelationPattern {
+    pub fn relation(&self) -> VdBsqTheoremRelation {
+        self.relation
+    }
+
+    pub fn lhs(&self) -> &VdBsqTermPattern {
+        &self.lhs
+    }
+
+    pub fn rhs(&self) -> &VdBsqTermPattern {
+        &self.rhs
+    }
+}
+
+impl VdBsqRelationPattern {
+    pub(super) fn from_lp_csv_expr(
+        expr: &LpCsvExpr,
+        parameters: &[VdBsqTheoremParameter],
+    ) -> VdBsqTheoremIndexResult<Self> {
+        let LpCsvExprData::Application(ref exprs) = expr.data else {
+            return Err(VdBsqTheoremIndexError::Malformed("relation"));
+        };
+        let [opr, lhs, rhs] = &exprs[..] else {
+            return Err(VdBsqTheoremIndexError::Malformed("relation"));
+        };
+        let LpCsvExprData::Ident(ref opr) = opr.data else {
+            return Err(VdBsqTheoremIndexError::Malformed("relation"));
+        };
+        let lhs = VdBsqTermPattern::from_lp_csv_expr(lhs, parameters)?;
+        let rhs = VdBsqTermPattern::from_lp_csv_expr(rhs, parameters)?;
+        let (relation, lhs, rhs) = match opr.as_str() {
+            "eq" => (VdBsqTheoremRelation::Eq, lhs, rhs),
+            "ne" => (VdBsqTheoremRelation::Ne, lhs, rhs),
+            "gt" => (VdBsqTheoremRelation::Gt, lhs, rhs),
+            "ge" => (VdBsqTheoremRelation::Ge, lhs, rhs),
+            "lt" => (VdBsqTheoremRelation::Gt, rhs, lhs),
+            "le" => (VdBsqTheoremRelation::Ge, rhs, lhs),
+            _ => return Err(VdBsqTheoremIndexError::UnknownRelation(opr.clone())),
+        };
+        Ok(Self { relation, lhs, rhs })
+    }
+}
+
+impl VdBsqTermPattern {
+    fn from_lp_csv_expr(
+        expr: &LpCsvExpr,
+        parameters: &[VdBsqTheoremParameter],
+    ) -> VdBsqTheoremIndexResult<Self> {
+        match expr.data {
+            LpCsvExprData::Literal(LpCsvLiteral::Integer(i)) => {
+                Ok(VdBsqTermPattern::Literal(i as i128))
+            }
+            LpCsvExprData::Ident(ref ident) => parameters
+                .iter()
+                .position(|parameter| parameter.ident() == ident)
+                .map(VdBsqTermPattern::Parameter)
+                .ok_or_else(|| VdBsqTheoremIndexError::UnknownParameter(ident.clone())),
+            LpCsvExprData::Application(ref exprs) => {
+                let Some(LpCsvExprData::Ident(head)) = exprs.first().map(|expr| &expr.data) else {
+                    return Err(VdBsqTheoremIndexError::Malformed("term head"));
+                };
+                let (head_pattern, arity) = match head.as_str() {
+                    "add" => (VdBsqTermPatternHead::Add, 2),
+                    "mul" => (VdBsqTermPatternHead::Mul, 2),
+                    "sub" => (VdBsqTermPatternHead::Sub, 2),
+                    "div" => (VdBsqTermPatternHead::Div, 2),
+                    "neg" => (VdBsqTermPatternHead::Neg, 1),
+                    "pow" => (VdBsqTermPatternHead::Pow, 2),
+                    "sqrt" => (VdBsqTermPatternHead::Sqrt, 1),
+                    _ => return Err(VdBsqTheoremIndexError::UnknownHead(head.clone())),
+                };
+                if exprs.len() != arity + 1 {
+                    return Err(VdBsqTheoremIndexError::Arity {
+                        head: head.clone(),
+                        expected: arity,
+                        actual: exprs.len() - 1,
+                    });
+                }
+                Ok(VdBsqTermPattern::Application {
+                    head: head_pattern,
+                    arguments: exprs[1..]
+                        .iter()
+                        .map(|expr| VdBsqTermPattern::from_lp_csv_expr(expr, parameters))
+                        .collect::<VdBsqTheoremIndexResult<_>>()?,
+                })
+            }
+            LpCsvExprData::Parenthesized(ref expr) => {
+                VdBsqTermPattern::from_lp_csv_expr(expr, parameters)
+            }
+            _ => Err(VdBsqTheoremIndexError::Malformed("term")),
+        }
+    }
+}
+
+impl VdBsqTermPattern {
+    /// extends `substitution` so that the instantiated pattern equals `expr` as terms
+    pub(crate) fn matches<'sess>(
+        &self,
+        expr: VdBsqExprFld<'sess>,
+        substitution: &mut [Option<VdBsqExprFld<'sess>>],
+    ) -> bool {
+        match *self {
+            VdBsqTermPattern::Parameter(index) => match substitution[index] {
+                Some(assigned) => assigned.term() == expr.term(),
+                None => {
+                    substitution[index] = Some(expr);
+                    true
+                }
+            },
+            VdBsqTermPattern::Literal(i) => expr.eqs_nat128(i),
+            VdBsqTermPattern::Application {
+                head,
+                ref arguments,
+            } => match expr.term_view() {
+                VdBsqTermView::Application {
+                    head: expr_head,
+                    arguments: expr_arguments,
+                } => {
+                    head == expr_head
+                        && arguments.len() == expr_arguments.len()
+                        && std::iter::zip(arguments, expr_arguments)
+                            .all(|(argument, expr)| argument.matches(expr, substitution))
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// how an expression looks from the perspective of term patterns
+pub(crate) enum VdBsqTermView<'sess> {
+    Literal(i128),
+    Application {
+        head: VdBsqTermPatternHead,
+        arguments: SmallVec<[VdBsqExprFld<'sess>; 2]>,
+    },
+    /// only matched by parameters
+    Opaque,
+}
+
+impl<'sess> VdBsqExprFld<'sess> {
+    pub(crate) fn relation_view(
+        self,
+    ) -> Option<(
+        VdBsqTheoremRelation,
+        VdBsqExprFld<'sess>,
+        VdBsqExprFld<'sess>,
+    )> {
+        let VdBsqExprFldData::ChainingSeparatedList {
+            leader,
+            ref followers,
+            joined_signature: None,
+        } = *self.data()
+        else {
+            return None;
+        };
+        let [(VdMirFunc::NormalBaseSeparator(signature), follower)] = followers[..] else {
+            return None;
+        };
+        match signature.opr() {
+            VdMirBaseSeparator::Eq => Some((VdBsqTheoremRelation::Eq, leader, follower)),
+            VdMirBaseSeparator::Ne => Some((VdBsqTheoremRelation::Ne, leader, follower)),
+            VdMirBaseSeparator::Gt => Some((VdBsqTheoremRelation::Gt, leader, follower)),
+            VdMirBaseSeparator::Ge => Some((VdBsqTheoremRelation::Ge, leader, follower)),
+            VdMirBaseSeparator::Lt => Some((VdBsqTheoremRelation::Gt, follower, leader)),
+            VdMirBaseSeparator::Le => Some((VdBsqTheoremRelation::Ge, follower, leader)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn term_view(self) -> VdBsqTermView<'sess> {
+        match *self.data() {
+            VdBsqExprFldData::Literal(lit) => match *lit.data() {
+                VdLiteralData::Int128(i) => VdBsqTermView::Literal(i),
+                _ => VdBsqTermView::Opaque,
+            },
+            VdBsqExprFldData::Application {
+                function,
+                ref arguments,
+            } => {
+                let head = match function {
+                    VdMirFunc::NormalBasePrefixOpr(signature) => match signature.opr {
+                        VdMirBasePrefixOpr::RingNeg => VdBsqTermPatternHead::Neg,
+                        _ => return VdBsqTermView::Opaque,
+                    },
+                    VdMirFunc::NormalBaseBinaryOpr(signature) => match signature.opr {
+                        VdMirBaseBinaryOpr::CommRingSub => VdBsqTermPatternHead::Sub,
+                        VdMirBaseBinaryOpr::CommFieldDiv => VdBsqTermPatternHead::Div,
+                    },
+                    VdMirFunc::Power(_) => VdBsqTermPatternHead::Pow,
+                    VdMirFunc::NormalBaseSqrt(_) => VdBsqTermPatternHead::Sqrt,
+                    VdMirFunc::NormalBaseSeparator(_)
+                    | VdMirFunc::InSet
+                    | VdMirFunc::NormalBaseFunction(_) => return VdBsqTermView::Opaque,
+                };
+                VdBsqTermView::Application {
+                    head,
+                    arguments: arguments.iter().copied().collect(),
+                }
+            }
+            VdBsqExprFldData::FoldingSeparatedList {
+                leader,
+                ref followers,
+            } => {
+                let head = match followers[0].0 {
+                    VdMirFunc::NormalBaseSeparator(signature) => match signature.opr() {
+                        VdMirBaseSeparator::CommRingAdd => VdBsqTermPatternHead::Add,
+                        VdMirBaseSeparator::CommRingMul => VdBsqTermPatternHead::Mul,
+                        _ => return VdBsqTermView::Opaque,
+                    },
+                    _ => return VdBsqTermView::Opaque,
+                };
+                VdBsqTermView::Application {
+                    head,
+                    arguments: std::iter::once(leader)
+                        .chain(followers.iter().map(|&(_, follower)| follower))
+                        .collect(),
+                }
+            }
+            VdBsqExprFldData::Variable(_, _)
+            | VdBsqExprFldData::ChainingSeparatedList { .. }
+            | VdBsqExprFldData::ItemPath(_)
+            | VdBsqExprFldData::Quantification { .. }
+            | VdBsqExprFldData::Limit { .. } => VdBsqTermView::Opaque,
+        }
+    }
+}
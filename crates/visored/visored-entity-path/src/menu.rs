@@ -18,6 +18,14 @@ pub struct VdItemPathMenu {
     // # functions
     pub sin: VdFunctionPath,
     pub cos: VdFunctionPath,
+    pub log: VdFunctionPath,
+    pub exp: VdFunctionPath,
+    pub gcd: VdFunctionPath,
+    pub max: VdFunctionPath,
+    pub min: VdFunctionPath,
+    pub binom: VdFunctionPath,
+    pub floor: VdFunctionPath,
+    pub abs: VdFunctionPath,
     // # traits
     pub group: VdTraitPath,
     pub ring: VdTraitPath,
@@ -40,6 +48,9 @@ pub struct VdItemPathMenu {
     pub gt: VdTraitItemPath,
     pub le: VdTraitItemPath,
     pub ge: VdTraitItemPath,
+    pub dvd: VdTraitItemPath,
+    pub implies: VdTraitItemPath,
+    pub iff: VdTraitItemPath,
 }
 
 impl VdItemPathMenu {
@@ -57,6 +68,14 @@ impl VdItemPathMenu {
             // # functions
             sin: VdFunctionPath::SIN,
             cos: VdFunctionPath::COS,
+            log: VdFunctionPath::LOG,
+            exp: VdFunctionPath::EXP,
+            gcd: VdFunctionPath::GCD,
+            max: VdFunctionPath::MAX,
+            min: VdFunctionPath::MIN,
+            binom: VdFunctionPath::BINOM,
+            floor: VdFunctionPath::FLOOR,
+            abs: VdFunctionPath::ABS,
             // # traits
             group: VdTraitPath::GROUP,
             ring: VdTraitPath::RING,
@@ -79,6 +98,9 @@ impl VdItemPathMenu {
             gt: VdTraitItemPath::GT,
             le: VdTraitItemPath::LE,
             ge: VdTraitItemPath::GE,
+            dvd: VdTraitItemPath::DVD,
+            implies: VdTraitItemPath::IMPLIES,
+            iff: VdTraitItemPath::IFF,
         }
     }
 }
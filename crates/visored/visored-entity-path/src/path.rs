@@ -31,6 +31,14 @@ impl VdItemPath {
     // # functions
     pub const SIN: Self = VdItemPath::Function(VdFunctionPath::SIN);
     pub const COS: Self = VdItemPath::Function(VdFunctionPath::COS);
+    pub const LOG: Self = VdItemPath::Function(VdFunctionPath::LOG);
+    pub const EXP: Self = VdItemPath::Function(VdFunctionPath::EXP);
+    pub const GCD: Self = VdItemPath::Function(VdFunctionPath::GCD);
+    pub const MAX: Self = VdItemPath::Function(VdFunctionPath::MAX);
+    pub const MIN: Self = VdItemPath::Function(VdFunctionPath::MIN);
+    pub const BINOM: Self = VdItemPath::Function(VdFunctionPath::BINOM);
+    pub const FLOOR: Self = VdItemPath::Function(VdFunctionPath::FLOOR);
+    pub const ABS: Self = VdItemPath::Function(VdFunctionPath::ABS);
     // # trait items
     pub const EQ: Self = VdItemPath::TraitItem(VdTraitItemPath::EQ);
     pub const NE: Self = VdItemPath::TraitItem(VdTraitItemPath::NE);
@@ -48,6 +56,9 @@ impl VdItemPath {
     pub const RING_SUB: Self = VdItemPath::TraitItem(VdTraitItemPath::RING_SUB);
     pub const FIELD_DIV: Self = VdItemPath::TraitItem(VdTraitItemPath::FIELD_DIV);
     pub const REAL_SQRT: Self = VdItemPath::TraitItem(VdTraitItemPath::REAL_SQRT);
+    pub const DVD: Self = VdItemPath::TraitItem(VdTraitItemPath::DVD);
+    pub const IMPLIES: Self = VdItemPath::TraitItem(VdTraitItemPath::IMPLIES);
+    pub const IFF: Self = VdItemPath::TraitItem(VdTraitItemPath::IFF);
 }
 
 impl VdItemPath {
@@ -72,6 +83,17 @@ impl VdItemPath {
             "ring_power" => VdItemPath::RING_POWER,
             "field_div" => VdItemPath::FIELD_DIV,
             "real_sqrt" => VdItemPath::REAL_SQRT,
+            "dvd" => VdItemPath::DVD,
+            "implies" => VdItemPath::IMPLIES,
+            "iff" => VdItemPath::IFF,
+            "log" => VdItemPath::LOG,
+            "exp" => VdItemPath::EXP,
+            "gcd" => VdItemPath::GCD,
+            "max" => VdItemPath::MAX,
+            "min" => VdItemPath::MIN,
+            "binom" => VdItemPath::BINOM,
+            "floor" => VdItemPath::FLOOR,
+            "abs" => VdItemPath::ABS,
             s => todo!("s = {s:?} not handled"),
         }
     }
@@ -9,16 +9,40 @@ pub enum VdFunctionPath {
 pub enum VdPreludeFunctionPath {
     Sin,
     Cos,
+    Log,
+    Exp,
+    Gcd,
+    Max,
+    Min,
+    Binom,
+    Floor,
+    Abs,
 }
 
 impl VdPreludeFunctionPath {
     pub const SIN: Self = VdPreludeFunctionPath::Sin;
     pub const COS: Self = VdPreludeFunctionPath::Cos;
+    pub const LOG: Self = VdPreludeFunctionPath::Log;
+    pub const EXP: Self = VdPreludeFunctionPath::Exp;
+    pub const GCD: Self = VdPreludeFunctionPath::Gcd;
+    pub const MAX: Self = VdPreludeFunctionPath::Max;
+    pub const MIN: Self = VdPreludeFunctionPath::Min;
+    pub const BINOM: Self = VdPreludeFunctionPath::Binom;
+    pub const FLOOR: Self = VdPreludeFunctionPath::Floor;
+    pub const ABS: Self = VdPreludeFunctionPath::Abs;
 }
 
 impl VdFunctionPath {
     pub const SIN: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::SIN);
     pub const COS: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::COS);
+    pub const LOG: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::LOG);
+    pub const EXP: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::EXP);
+    pub const GCD: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::GCD);
+    pub const MAX: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::MAX);
+    pub const MIN: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::MIN);
+    pub const BINOM: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::BINOM);
+    pub const FLOOR: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::FLOOR);
+    pub const ABS: Self = VdFunctionPath::Prelude(VdPreludeFunctionPath::ABS);
 }
 
 impl VdFunctionPath {
@@ -34,6 +58,14 @@ impl VdPreludeFunctionPath {
         match self {
             VdPreludeFunctionPath::Sin => write!(f, "sin"),
             VdPreludeFunctionPath::Cos => write!(f, "cos"),
+            VdPreludeFunctionPath::Log => write!(f, "log"),
+            VdPreludeFunctionPath::Exp => write!(f, "exp"),
+            VdPreludeFunctionPath::Gcd => write!(f, "gcd"),
+            VdPreludeFunctionPath::Max => write!(f, "max"),
+            VdPreludeFunctionPath::Min => write!(f, "min"),
+            VdPreludeFunctionPath::Binom => write!(f, "binom"),
+            VdPreludeFunctionPath::Floor => write!(f, "floor"),
+            VdPreludeFunctionPath::Abs => write!(f, "abs"),
         }
     }
 }
@@ -20,6 +20,9 @@ pub enum VdTraitItemPath {
     Ge,
     FieldDiv,
     RealSqrt,
+    Dvd,
+    Implies,
+    Iff,
 }
 
 impl VdTraitItemPath {
@@ -41,6 +44,9 @@ impl VdTraitItemPath {
     pub const GT: Self = VdTraitItemPath::Gt;
     pub const LE: Self = VdTraitItemPath::Le;
     pub const GE: Self = VdTraitItemPath::Ge;
+    pub const DVD: Self = VdTraitItemPath::Dvd;
+    pub const IMPLIES: Self = VdTraitItemPath::Implies;
+    pub const IFF: Self = VdTraitItemPath::Iff;
 }
 
 impl VdTraitItemPath {
@@ -64,6 +70,9 @@ impl VdTraitItemPath {
             VdTraitItemPath::Ge => write!(f, "≥(ge)"),
             VdTraitItemPath::FieldDiv => write!(f, "/(field_div)"),
             VdTraitItemPath::RealSqrt => write!(f, "√(real_sqrt)"),
+            VdTraitItemPath::Dvd => write!(f, "∣(dvd)"),
+            VdTraitItemPath::Implies => write!(f, "⟹(implies)"),
+            VdTraitItemPath::Iff => write!(f, "⟺(iff)"),
         }
     }
 }
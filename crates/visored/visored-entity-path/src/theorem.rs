@@ -1,4 +1,47 @@
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
 pub enum VdTheoremPath {
     SquareNonnegative,
+    PowNonnegative,
+    PowPositive,
+    SqrtNonnegative,
+    SqrtSquare,
+    MulNonnegative,
+    MulPositive,
+    DivNonnegative,
+    DivPositive,
+}
+
+impl VdTheoremPath {
+    pub const ALL: &'static [Self] = &[
+        VdTheoremPath::SquareNonnegative,
+        VdTheoremPath::PowNonnegative,
+        VdTheoremPath::PowPositive,
+        VdTheoremPath::SqrtNonnegative,
+        VdTheoremPath::SqrtSquare,
+        VdTheoremPath::MulNonnegative,
+        VdTheoremPath::MulPositive,
+        VdTheoremPath::DivNonnegative,
+        VdTheoremPath::DivPositive,
+    ];
+}
+
+impl VdTheoremPath {
+    /// the identifier used in specs
+    pub fn ident(self) -> &'static str {
+        match self {
+            VdTheoremPath::SquareNonnegative => "square_nonnegative",
+            VdTheoremPath::PowNonnegative => "pow_nonnegative",
+            VdTheoremPath::PowPositive => "pow_positive",
+            VdTheoremPath::SqrtNonnegative => "sqrt_nonnegative",
+            VdTheoremPath::SqrtSquare => "sqrt_square",
+            VdTheoremPath::MulNonnegative => "mul_nonnegative",
+            VdTheoremPath::MulPositive => "mul_positive",
+            VdTheoremPath::DivNonnegative => "div_nonnegative",
+            VdTheoremPath::DivPositive => "div_positive",
+        }
+    }
+
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|path| path.ident() == ident)
+    }
 }
@@ -4,8 +4,9 @@ use crate::dispatch::separator::join::VdBaseChainingSeparatorJoinKey;
 use crate::{
     dispatch::{
         attach::VdAttachGlobalDispatch, binary_opr::VdBinaryOprGlobalDispatch,
-        frac::VdFracGlobalDispatch, prefix_opr::VdPrefixOprGlobalDispatch,
-        separator::VdSeparatorGlobalDispatch, sqrt::VdSqrtGlobalDispatch,
+        frac::VdFracGlobalDispatch, function::VdFunctionGlobalDispatch,
+        prefix_opr::VdPrefixOprGlobalDispatch, separator::VdSeparatorGlobalDispatch,
+        sqrt::VdSqrtGlobalDispatch,
     },
     menu::vd_global_dispatch_menu,
     *,
@@ -13,13 +14,15 @@ use crate::{
 use dispatch::separator::join::VdBaseChainingSeparatorJoinDispatch;
 use eterned::db::EternerDb;
 use rustc_hash::FxHashMap;
+use visored_entity_path::path::function::VdFunctionPath;
 use visored_opr::{
     menu::vd_opr_menu,
     opr::{binary::VdBaseBinaryOpr, prefix::VdBasePrefixOpr},
     separator::VdBaseSeparator,
 };
 use visored_signature::{
-    menu::vd_signature_menu, signature::separator::base::VdBaseSeparatorSignature,
+    menu::vd_signature_menu,
+    signature::{function::VdFunctionParameterTys, separator::base::VdBaseSeparatorSignature},
     table::VdSignatureTable,
 };
 use visored_term::{menu::vd_ty_menu, ty::VdType};
@@ -33,6 +36,7 @@ pub struct VdDefaultGlobalDispatchTable {
     attach_default_dispatch_table: FxHashMap<VdAttachKey, VdAttachGlobalDispatch>,
     base_sqrt_default_dispatch_table: FxHashMap<VdBaseSqrtKey, VdSqrtGlobalDispatch>,
     base_frac_default_dispatch_table: FxHashMap<VdBaseFracKey, VdFracGlobalDispatch>,
+    base_function_default_dispatch_table: FxHashMap<VdBaseFunctionKey, VdFunctionGlobalDispatch>,
     base_chaining_separator_join_dispatch_table:
         FxHashMap<VdBaseChainingSeparatorJoinKey, VdBaseChainingSeparatorJoinDispatch>,
 }
@@ -76,6 +80,12 @@ pub struct VdBaseFracKey {
     pub denominator_ty: VdType,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VdBaseFunctionKey {
+    pub function: VdFunctionPath,
+    pub argument_tys: VdFunctionParameterTys,
+}
+
 impl VdDefaultGlobalDispatchTable {
     pub fn new(
         base_prefix_opr_default_dispatches: impl IntoIterator<
@@ -90,6 +100,9 @@ impl VdDefaultGlobalDispatchTable {
         attach_default_dispatches: impl IntoIterator<Item = (VdAttachKey, VdAttachGlobalDispatch)>,
         sqrt_default_dispatches: impl IntoIterator<Item = (VdBaseSqrtKey, VdSqrtGlobalDispatch)>,
         frac_default_dispatches: impl IntoIterator<Item = (VdBaseFracKey, VdFracGlobalDispatch)>,
+        function_default_dispatches: impl IntoIterator<
+            Item = (VdBaseFunctionKey, VdFunctionGlobalDispatch),
+        >,
         base_chaining_separator_join_default_dispatches: impl IntoIterator<
             Item = (
                 VdBaseChainingSeparatorJoinKey,
@@ -110,6 +123,7 @@ impl VdDefaultGlobalDispatchTable {
             attach_default_dispatch_table: attach_default_dispatches.into_iter().collect(),
             base_sqrt_default_dispatch_table: sqrt_default_dispatches.into_iter().collect(),
             base_frac_default_dispatch_table: frac_default_dispatches.into_iter().collect(),
+            base_function_default_dispatch_table: function_default_dispatches.into_iter().collect(),
             base_chaining_separator_join_dispatch_table:
                 base_chaining_separator_join_default_dispatches
                     .into_iter()
@@ -200,6 +214,19 @@ impl VdDefaultGlobalDispatchTable {
             })
             .copied()
     }
+
+    pub fn base_function_default_dispatch(
+        &self,
+        function: VdFunctionPath,
+        argument_tys: VdFunctionParameterTys,
+    ) -> Option<VdFunctionGlobalDispatch> {
+        self.base_function_default_dispatch_table
+            .get(&VdBaseFunctionKey {
+                function,
+                argument_tys,
+            })
+            .copied()
+    }
 }
 
 impl VdDefaultGlobalDispatchTable {
@@ -18,6 +18,7 @@ impl VdDefaultGlobalDispatchTable {
         let power_file = dir.join("power.lpcsv");
         let base_sqrt_file = dir.join("base_sqrt.lpcsv");
         let base_frac_file = dir.join("base_frac.lpcsv");
+        let base_function_file = dir.join("base_function.lpcsv");
         let base_chaining_separator_join_file = dir.join("base_chaining_separator_join.lpcsv");
         Self::from_lisp_csv_file_paths(
             &base_prefix_opr_file,
@@ -26,6 +27,7 @@ impl VdDefaultGlobalDispatchTable {
             &power_file,
             &base_sqrt_file,
             &base_frac_file,
+            &base_function_file,
             &base_chaining_separator_join_file,
             signature_table,
             db,
@@ -39,6 +41,7 @@ impl VdDefaultGlobalDispatchTable {
         power_file: &Path,
         base_sqrt_file: &Path,
         base_frac_file: &Path,
+        base_function_file: &Path,
         base_chaining_separator_join_file: &Path,
         signature_table: &VdSignatureTable,
         db: &EternerDb,
@@ -49,6 +52,7 @@ impl VdDefaultGlobalDispatchTable {
         let power_file = parse_lp_csv_filepath(power_file).unwrap();
         let base_sqrt_file = parse_lp_csv_filepath(base_sqrt_file).unwrap();
         let base_frac_file = parse_lp_csv_filepath(base_frac_file).unwrap();
+        let base_function_file = parse_lp_csv_filepath(base_function_file).unwrap();
         let base_chaining_separator_join_file =
             parse_lp_csv_filepath(base_chaining_separator_join_file).unwrap();
         Self::from_lisp_csv_files(
@@ -58,6 +62,7 @@ impl VdDefaultGlobalDispatchTable {
             &power_file,
             &base_sqrt_file,
             &base_frac_file,
+            &base_function_file,
             &base_chaining_separator_join_file,
             &signature_table,
             db,
@@ -71,6 +76,7 @@ impl VdDefaultGlobalDispatchTable {
         power_file: &LpCsvFile,
         base_sqrt_file: &LpCsvFile,
         base_frac_file: &LpCsvFile,
+        base_function_file: &LpCsvFile,
         base_chaining_separator_join_file: &LpCsvFile,
         signature_table: &VdSignatureTable,
         db: &EternerDb,
@@ -96,6 +102,11 @@ impl VdDefaultGlobalDispatchTable {
             VdSqrtGlobalDispatch::collect_from_lisp_csv_files(base_sqrt_file, signature_table, db);
         let base_frac_table =
             VdFracGlobalDispatch::collect_from_lisp_csv_files(base_frac_file, signature_table, db);
+        let base_function_table = VdFunctionGlobalDispatch::collect_from_lisp_csv_files(
+            base_function_file,
+            signature_table,
+            db,
+        );
         let base_chaining_separator_join_default_dispatches =
             VdBaseChainingSeparatorJoinDispatch::collect_from_lisp_csv_files(
                 base_chaining_separator_join_file,
@@ -108,6 +119,7 @@ impl VdDefaultGlobalDispatchTable {
             power_table,
             base_sqrt_table,
             base_frac_table,
+            base_function_table,
             base_chaining_separator_join_default_dispatches,
         )
     }
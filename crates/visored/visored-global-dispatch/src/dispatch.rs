@@ -1,6 +1,7 @@
 pub mod attach;
 pub mod binary_opr;
 pub mod frac;
+pub mod function;
 pub mod prefix_opr;
 pub mod separator;
 pub mod sqrt;
@@ -0,0 +1,193 @@
+use super::*;
+use crate::menu::VdGlobalDispatchMenu;
+use default_table::VdBaseFunctionKey;
+use eterned::db::EternerDb;
+use lisp_csv::{
+    expr::LpCsvExprData,
+    file::{LpCsvFile, LpCsvFileData},
+    row::LpCsvRow,
+};
+use visored_entity_path::path::{function::VdFunctionPath, VdItemPath};
+use visored_signature::{
+    signature::{
+        function::{VdBaseFunctionSignature, VdFunctionParameterTys, VdFunctionSignature},
+        VdSignature,
+    },
+    table::VdSignatureTable,
+};
+use visored_term::{menu::VdTypeMenu, ty::VdType};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VdFunctionGlobalDispatch {
+    Base { signature: VdBaseFunctionSignature },
+}
+
+impl VdFunctionGlobalDispatch {
+    pub fn expr_ty(self) -> VdType {
+        match self {
+            VdFunctionGlobalDispatch::Base { signature } => signature.expr_ty(),
+        }
+    }
+}
+
+impl VdFunctionGlobalDispatch {
+    pub fn standard_defaults(
+        vd_ty_menu: &VdTypeMenu,
+        global_dispatch_menu: &VdGlobalDispatchMenu,
+    ) -> impl IntoIterator<Item = (VdBaseFunctionKey, VdFunctionGlobalDispatch)> {
+        let VdTypeMenu {
+            nat,
+            int,
+            rat,
+            real,
+            ..
+        } = *vd_ty_menu;
+        let VdGlobalDispatchMenu {
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            nat_max,
+            int_max,
+            rat_max,
+            real_max,
+            nat_min,
+            int_min,
+            rat_min,
+            real_min,
+            nat_binom,
+            real_floor,
+            int_abs,
+            rat_abs,
+            real_abs,
+            ..
+        } = *global_dispatch_menu;
+        let un = |function, ty| VdBaseFunctionKey {
+            function,
+            argument_tys: VdFunctionParameterTys::Unary(ty),
+        };
+        let bi = |function, lty, rty| VdBaseFunctionKey {
+            function,
+            argument_tys: VdFunctionParameterTys::Binary(lty, rty),
+        };
+        [
+            // ## log
+            (un(VdFunctionPath::LOG, nat), real_log),
+            (un(VdFunctionPath::LOG, int), real_log),
+            (un(VdFunctionPath::LOG, rat), real_log),
+            (un(VdFunctionPath::LOG, real), real_log),
+            // ## exp
+            (un(VdFunctionPath::EXP, nat), real_exp),
+            (un(VdFunctionPath::EXP, int), real_exp),
+            (un(VdFunctionPath::EXP, rat), real_exp),
+            (un(VdFunctionPath::EXP, real), real_exp),
+            // ## gcd
+            (bi(VdFunctionPath::GCD, nat, nat), nat_gcd),
+            (bi(VdFunctionPath::GCD, nat, int), int_gcd),
+            (bi(VdFunctionPath::GCD, int, nat), int_gcd),
+            (bi(VdFunctionPath::GCD, int, int), int_gcd),
+            // ## max
+            (bi(VdFunctionPath::MAX, nat, nat), nat_max),
+            (bi(VdFunctionPath::MAX, int, int), int_max),
+            (bi(VdFunctionPath::MAX, rat, rat), rat_max),
+            (bi(VdFunctionPath::MAX, real, real), real_max),
+            // ## min
+            (bi(VdFunctionPath::MIN, nat, nat), nat_min),
+            (bi(VdFunctionPath::MIN, int, int), int_min),
+            (bi(VdFunctionPath::MIN, rat, rat), rat_min),
+            (bi(VdFunctionPath::MIN, real, real), real_min),
+            // ## binom
+            (bi(VdFunctionPath::BINOM, nat, nat), nat_binom),
+            // ## floor
+            (un(VdFunctionPath::FLOOR, nat), real_floor),
+            (un(VdFunctionPath::FLOOR, int), real_floor),
+            (un(VdFunctionPath::FLOOR, rat), real_floor),
+            (un(VdFunctionPath::FLOOR, real), real_floor),
+            // ## abs
+            (un(VdFunctionPath::ABS, nat), int_abs),
+            (un(VdFunctionPath::ABS, int), int_abs),
+            (un(VdFunctionPath::ABS, rat), rat_abs),
+            (un(VdFunctionPath::ABS, real), real_abs),
+        ]
+    }
+
+    pub fn collect_from_lisp_csv_files<'a>(
+        file: &'a LpCsvFile,
+        signature_table: &'a VdSignatureTable,
+        db: &'a EternerDb,
+    ) -> impl IntoIterator<Item = (VdBaseFunctionKey, VdFunctionGlobalDispatch)> + 'a {
+        let LpCsvFileData::Rows(rows) = file.data();
+        rows.iter()
+            .map(|row| Self::collect_from_csv_row(row, signature_table, db))
+    }
+
+    pub fn collect_from_csv_row(
+        row: &LpCsvRow,
+        signature_table: &VdSignatureTable,
+        db: &EternerDb,
+    ) -> (VdBaseFunctionKey, VdFunctionGlobalDispatch) {
+        let LpCsvRow::SeparatedExprs(exprs) = row else {
+            todo!()
+        };
+        let (function, argument_tys, signature_ident) = match exprs as &[_] {
+            &[ref function, ref argument_ty, ref signature_ident] => (
+                function,
+                VdFunctionParameterTys::Unary(VdType::from_lp_csv_expr(argument_ty, db)),
+                signature_ident,
+            ),
+            &[ref function, ref largument_ty, ref rargument_ty, ref signature_ident] => (
+                function,
+                VdFunctionParameterTys::Binary(
+                    VdType::from_lp_csv_expr(largument_ty, db),
+                    VdType::from_lp_csv_expr(rargument_ty, db),
+                ),
+                signature_ident,
+            ),
+            _ => todo!(),
+        };
+        let VdItemPath::Function(function) = VdItemPath::from_lp_csv_expr(function, db) else {
+            todo!()
+        };
+        let LpCsvExprData::Ident(ref signature_ident) = signature_ident.data else {
+            todo!()
+        };
+        let VdSignature::Function(VdFunctionSignature::Base(signature)) =
+            signature_table[signature_ident]
+        else {
+            todo!()
+        };
+        let dispatch = VdFunctionGlobalDispatch::Base { signature };
+        (
+            VdBaseFunctionKey {
+                function,
+                argument_tys,
+            },
+            dispatch,
+        )
+    }
+}
+
+#[test]
+fn vd_function_global_dispatch_standard_defaults_works() {
+    use crate::default_table::VdDefaultGlobalDispatchTable;
+    use crate::menu::vd_global_dispatch_menu;
+    use visored_term::menu::vd_ty_menu;
+
+    let db = &EternerDb::default();
+    let table = VdDefaultGlobalDispatchTable::from_standard_lisp_csv_file_dir(db);
+    let ty_menu = vd_ty_menu(db);
+    let global_dispatch_menu = vd_global_dispatch_menu(db);
+    for (
+        VdBaseFunctionKey {
+            function,
+            argument_tys,
+        },
+        dispatch,
+    ) in VdFunctionGlobalDispatch::standard_defaults(ty_menu, global_dispatch_menu)
+    {
+        assert_eq!(
+            table.base_function_default_dispatch(function, argument_tys),
+            Some(dispatch)
+        );
+    }
+}
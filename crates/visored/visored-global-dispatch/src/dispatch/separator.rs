@@ -77,6 +77,10 @@ impl VdSeparatorGlobalDispatch {
             le,
             ge,
             r#in,
+            mid,
+            to,
+            implies,
+            iff,
         } = *vd_opr_menu;
         let VdGlobalDispatchMenu {
             int_pos,
@@ -136,10 +140,12 @@ impl VdSeparatorGlobalDispatch {
             rat_ge,
             real_ge,
             in_set,
-            real_sqrt,
-            rat_frac,
-            real_frac,
-            complex_frac,
+            nat_dvd,
+            int_dvd,
+            prop_implies,
+            prop_to,
+            prop_iff,
+            ..
         } = *global_dispatch_menu;
         [
             // ## add
@@ -356,6 +362,17 @@ impl VdSeparatorGlobalDispatch {
             ((rat, r#in, set), in_set),
             ((real, r#in, set), in_set),
             ((complex, r#in, set), in_set),
+            // ## mid
+            ((nat, mid, nat), nat_dvd),
+            ((nat, mid, int), int_dvd),
+            ((int, mid, nat), int_dvd),
+            ((int, mid, int), int_dvd),
+            // ## implies
+            ((prop, implies, prop), prop_implies),
+            // ## to
+            ((prop, to, prop), prop_to),
+            // ## iff
+            ((prop, iff, prop), prop_iff),
         ]
     }
 
@@ -17,8 +17,9 @@ use visored_term::menu::{vd_ty_menu, VdTypeMenu};
 
 use crate::dispatch::{
     attach::VdAttachGlobalDispatch, binary_opr::VdBinaryOprGlobalDispatch,
-    frac::VdFracGlobalDispatch, prefix_opr::VdPrefixOprGlobalDispatch,
-    separator::VdSeparatorGlobalDispatch, sqrt::VdSqrtGlobalDispatch,
+    frac::VdFracGlobalDispatch, function::VdFunctionGlobalDispatch,
+    prefix_opr::VdPrefixOprGlobalDispatch, separator::VdSeparatorGlobalDispatch,
+    sqrt::VdSqrtGlobalDispatch,
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -97,12 +98,46 @@ pub struct VdGlobalDispatchMenu {
     pub real_ge: VdSeparatorGlobalDispatch,
     /// ## in
     pub in_set: VdSeparatorGlobalDispatch,
+    /// ## dvd
+    pub nat_dvd: VdSeparatorGlobalDispatch,
+    pub int_dvd: VdSeparatorGlobalDispatch,
+    /// ## logic
+    pub prop_implies: VdSeparatorGlobalDispatch,
+    /// `\to` between propositions means the same as `\implies`
+    pub prop_to: VdSeparatorGlobalDispatch,
+    pub prop_iff: VdSeparatorGlobalDispatch,
     /// # sqrt
     pub real_sqrt: VdSqrtGlobalDispatch,
     /// # frac
     pub rat_frac: VdFracGlobalDispatch,
     pub real_frac: VdFracGlobalDispatch,
     pub complex_frac: VdFracGlobalDispatch,
+    /// # functions
+    /// ## log
+    pub real_log: VdFunctionGlobalDispatch,
+    /// ## exp
+    pub real_exp: VdFunctionGlobalDispatch,
+    /// ## gcd
+    pub nat_gcd: VdFunctionGlobalDispatch,
+    pub int_gcd: VdFunctionGlobalDispatch,
+    /// ## max
+    pub nat_max: VdFunctionGlobalDispatch,
+    pub int_max: VdFunctionGlobalDispatch,
+    pub rat_max: VdFunctionGlobalDispatch,
+    pub real_max: VdFunctionGlobalDispatch,
+    /// ## min
+    pub nat_min: VdFunctionGlobalDispatch,
+    pub int_min: VdFunctionGlobalDispatch,
+    pub rat_min: VdFunctionGlobalDispatch,
+    pub real_min: VdFunctionGlobalDispatch,
+    /// ## binom
+    pub nat_binom: VdFunctionGlobalDispatch,
+    /// ## floor
+    pub real_floor: VdFunctionGlobalDispatch,
+    /// ## abs
+    pub int_abs: VdFunctionGlobalDispatch,
+    pub rat_abs: VdFunctionGlobalDispatch,
+    pub real_abs: VdFunctionGlobalDispatch,
 }
 
 #[memo(return_ref)]
@@ -178,7 +213,28 @@ impl VdGlobalDispatchMenu {
             int_ge,
             rat_ge,
             real_ge,
+            nat_dvd,
+            int_dvd,
+            prop_implies,
+            prop_iff,
             real_sqrt,
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            nat_max,
+            int_max,
+            rat_max,
+            real_max,
+            nat_min,
+            int_min,
+            rat_min,
+            real_min,
+            nat_binom,
+            real_floor,
+            int_abs,
+            rat_abs,
+            real_abs,
         } = *vd_signature_menu(db);
         let pre = |base_opr, signature| VdPrefixOprGlobalDispatch::Base {
             base_opr,
@@ -199,6 +255,7 @@ impl VdGlobalDispatchMenu {
         let pow = |signature| VdAttachGlobalDispatch::Normal {
             signature: VdAttachSignature::Power(signature),
         };
+        let func = |signature| VdFunctionGlobalDispatch::Base { signature };
         VdGlobalDispatchMenu {
             // # prefix oprs
             // ## pos
@@ -274,6 +331,13 @@ impl VdGlobalDispatchMenu {
             real_ge: chain(VdBaseSeparator::Ge, real_ge),
             // ## in
             in_set: VdSeparatorGlobalDispatch::InSet { expr_ty: prop },
+            // ## dvd
+            nat_dvd: chain(VdBaseSeparator::Mid, nat_dvd),
+            int_dvd: chain(VdBaseSeparator::Mid, int_dvd),
+            // ## logic
+            prop_implies: chain(VdBaseSeparator::Implies, prop_implies),
+            prop_to: chain(VdBaseSeparator::To, prop_implies),
+            prop_iff: chain(VdBaseSeparator::Iff, prop_iff),
             // # sqrt
             real_sqrt: VdSqrtGlobalDispatch::Base {
                 signature: real_sqrt,
@@ -286,6 +350,32 @@ impl VdGlobalDispatchMenu {
             complex_frac: VdFracGlobalDispatch::Div {
                 signature: complex_div,
             },
+            // # functions
+            // ## log
+            real_log: func(real_log),
+            // ## exp
+            real_exp: func(real_exp),
+            // ## gcd
+            nat_gcd: func(nat_gcd),
+            int_gcd: func(int_gcd),
+            // ## max
+            nat_max: func(nat_max),
+            int_max: func(int_max),
+            rat_max: func(rat_max),
+            real_max: func(real_max),
+            // ## min
+            nat_min: func(nat_min),
+            int_min: func(int_min),
+            rat_min: func(rat_min),
+            real_min: func(real_min),
+            // ## binom
+            nat_binom: func(nat_binom),
+            // ## floor
+            real_floor: func(real_floor),
+            // ## abs
+            int_abs: func(int_abs),
+            rat_abs: func(rat_abs),
+            real_abs: func(real_abs),
         }
     }
 }
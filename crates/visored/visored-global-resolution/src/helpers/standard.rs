@@ -43,6 +43,12 @@ fn standard_complete_command_resolution_map(
         usepackage,
         documentclass,
         newtheorem,
+        // macros are expanded by the lexer
+        newcommand: _,
+        renewcommand: _,
+        providecommand: _,
+        def: _,
+        declare_math_operator: _,
         part,
         chapter,
         section,
@@ -90,9 +96,32 @@ fn standard_complete_command_resolution_map(
         // - functions
         sin,
         cos,
+        log,
+        exp,
+        lim,
+        max,
+        min,
+        gcd,
+        operatorname: _,
+        // - number theory
+        mid,
+        pmod,
+        equiv,
+        // - logic
+        to,
+        forall,
+        exists,
+        implies,
+        iff,
+        // - delimiters
+        lfloor,
+        rfloor,
+        lvert,
+        rvert,
         // - layouts
         sqrt,
         frac,
+        binom,
         left,
         right,
         // - environments
@@ -150,8 +179,32 @@ fn standard_complete_command_resolution_map(
             cos,
             VdCompleteCommandGlobalResolution::Item(VdItemPath::COS),
         ),
+        // - functions
+        (log, VdCompleteCommandGlobalResolution::LOG),
+        (exp, VdCompleteCommandGlobalResolution::EXP),
+        (lim, VdCompleteCommandGlobalResolution::LIM),
+        (max, VdCompleteCommandGlobalResolution::MAX),
+        (min, VdCompleteCommandGlobalResolution::MIN),
+        (gcd, VdCompleteCommandGlobalResolution::GCD),
+        // - number theory
+        (mid, VdCompleteCommandGlobalResolution::MID),
+        (pmod, VdCompleteCommandGlobalResolution::PMOD),
+        (equiv, VdCompleteCommandGlobalResolution::EQUIV),
+        // - logic
+        (to, VdCompleteCommandGlobalResolution::TO),
+        (forall, VdCompleteCommandGlobalResolution::FORALL),
+        (exists, VdCompleteCommandGlobalResolution::EXISTS),
+        (implies, VdCompleteCommandGlobalResolution::IMPLIES),
+        (iff, VdCompleteCommandGlobalResolution::IFF),
+        // - delimiters
+        (lfloor, VdCompleteCommandGlobalResolution::LFLOOR),
+        (rfloor, VdCompleteCommandGlobalResolution::RFLOOR),
+        (lvert, VdCompleteCommandGlobalResolution::LVERT),
+        (rvert, VdCompleteCommandGlobalResolution::RVERT),
+        // - layouts
         (sqrt, VdCompleteCommandGlobalResolution::SQRT),
         (frac, VdCompleteCommandGlobalResolution::FRAC),
+        (binom, VdCompleteCommandGlobalResolution::BINOM),
         (text, VdCompleteCommandGlobalResolution::TEXT),
     ])
 }
@@ -216,6 +269,7 @@ fn lx_math_punctuation_standard_resolution(
         LxMathPunctuation::Rbox => Some(VdPunctuationGlobalResolution::Todo),
         LxMathPunctuation::EscapedLcurl => Some(VdPunctuationGlobalResolution::Todo),
         LxMathPunctuation::EscapedRcurl => Some(VdPunctuationGlobalResolution::Todo),
+        LxMathPunctuation::Comma => Some(VdPunctuationGlobalResolution::COMMA),
         LxMathPunctuation::Ldot => Some(VdPunctuationGlobalResolution::Todo),
     }
 }
@@ -3,7 +3,11 @@ use latex_command::path::LxCommandPath;
 use latex_math_letter::letter::LxMathLetter;
 use rustc_hash::FxHashMap;
 use visored_entity_path::path::VdItemPath;
-use visored_opr::{opr::VdBaseOpr, separator::VdBaseSeparator};
+use visored_opr::{
+    delimiter::{VdBaseLeftDelimiter, VdBaseRightDelimiter},
+    opr::VdBaseOpr,
+    separator::VdBaseSeparator,
+};
 use visored_prelude::division::VdDivisionLevel;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,9 +16,13 @@ pub enum VdCompleteCommandGlobalResolution {
     Item(VdItemPath),
     Opr(VdBaseOpr),
     Separator(VdBaseSeparator),
+    LeftDelimiter(VdBaseLeftDelimiter),
+    RightDelimiter(VdBaseRightDelimiter),
     NewDivision(VdDivisionLevel),
     Frac,
+    Binom,
     Sqrt,
+    Pmod,
     Text,
     UsePackage,
     DocumentClass,
@@ -71,8 +79,36 @@ impl VdCompleteCommandGlobalResolution {
     pub const LOWER_GAMMA: Self =
         VdCompleteCommandGlobalResolution::Letter(LxMathLetter::LOWER_GAMMA);
     pub const LOWER_PI: Self = VdCompleteCommandGlobalResolution::Letter(LxMathLetter::LOWER_PI);
+    // -- functions
+    pub const LOG: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::LOG);
+    pub const EXP: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::EXP);
+    pub const LIM: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::LIM);
+    pub const MAX: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::MAX);
+    pub const MIN: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::MIN);
+    pub const GCD: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::GCD);
+    // -- number theory
+    pub const MID: Self = VdCompleteCommandGlobalResolution::Separator(VdBaseSeparator::MID);
+    pub const PMOD: Self = VdCompleteCommandGlobalResolution::Pmod;
+    pub const EQUIV: Self = VdCompleteCommandGlobalResolution::Separator(VdBaseSeparator::EQUIV);
+    // -- logic
+    pub const TO: Self = VdCompleteCommandGlobalResolution::Separator(VdBaseSeparator::TO);
+    pub const FORALL: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::FORALL);
+    pub const EXISTS: Self = VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::EXISTS);
+    pub const IMPLIES: Self =
+        VdCompleteCommandGlobalResolution::Separator(VdBaseSeparator::IMPLIES);
+    pub const IFF: Self = VdCompleteCommandGlobalResolution::Separator(VdBaseSeparator::IFF);
+    // -- delimiters
+    pub const LFLOOR: Self =
+        VdCompleteCommandGlobalResolution::LeftDelimiter(VdBaseLeftDelimiter::LFLOOR);
+    pub const RFLOOR: Self =
+        VdCompleteCommandGlobalResolution::RightDelimiter(VdBaseRightDelimiter::RFLOOR);
+    pub const LVERT: Self =
+        VdCompleteCommandGlobalResolution::LeftDelimiter(VdBaseLeftDelimiter::LVERT);
+    pub const RVERT: Self =
+        VdCompleteCommandGlobalResolution::RightDelimiter(VdBaseRightDelimiter::RVERT);
     // -- layouts
     pub const SQRT: Self = VdCompleteCommandGlobalResolution::Sqrt;
     pub const FRAC: Self = VdCompleteCommandGlobalResolution::Frac;
+    pub const BINOM: Self = VdCompleteCommandGlobalResolution::Binom;
     pub const TEXT: Self = VdCompleteCommandGlobalResolution::Text;
 }
@@ -30,6 +30,7 @@ impl VdPunctuationGlobalResolution {
     pub const GT: Self = Self::Separator(VdBaseSeparator::GT);
     pub const LE: Self = Self::Separator(VdBaseSeparator::LE);
     pub const GE: Self = Self::Separator(VdBaseSeparator::GE);
+    pub const COMMA: Self = Self::Separator(VdBaseSeparator::COMMA);
     pub const LPAR: Self = Self::LeftDelimiter(VdBaseLeftDelimiter::LPAR);
     pub const RPAR: Self = Self::RightDelimiter(VdBaseRightDelimiter::RPAR);
 }
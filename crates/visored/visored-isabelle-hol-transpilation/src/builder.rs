@@ -0,0 +1,262 @@
+mod helpers;
+
+use eterned::db::EternerDb;
+use isabelle_hir_expr::{
+    constructor::IeHirExprConstructor,
+    expr::IeHirExprArena,
+    ident::IeIdent,
+    item_defn::{IeHirItemDefnArena, IeHirItemDefnCommentMap},
+    stmt::IeHirStmtArena,
+};
+use latex_token::storage::LxTokenStorage;
+use rustc_hash::FxHashMap;
+use std::ops::{Deref, DerefMut};
+use visored_entity_path::module::VdModulePath;
+use visored_mir_expr::{
+    expr::VdMirExprArenaRef,
+    hint::VdMirHintArenaRef,
+    hypothesis::{VdMirHypothesisArenaRef, VdMirHypothesisIdx},
+    source_map::VdMirRegionSourceMap,
+    stmt::VdMirStmtArenaRef,
+    symbol::local_defn::{storage::VdMirSymbolLocalDefnStorage, VdMirSymbolLocalDefnIdx},
+};
+use visored_sem_expr::range::VdSemSentenceTokenIdxRangeMap;
+
+use crate::{
+    dictionary::VdIsabelleDictionary, error::VdIsabelleTranspilationError,
+    mangle::VdIsabelleTranspilationMangler, scheme::IsVdIsabelleTranspilationScheme,
+};
+
+pub struct VdIsabelleTranspilationBuilder<'a, S: IsVdIsabelleTranspilationScheme> {
+    db: &'a EternerDb,
+    scheme: &'a S,
+    input: &'a str,
+    isabelle_hir_expr_constructor: IeHirExprConstructor,
+    expr_arena: VdMirExprArenaRef<'a>,
+    stmt_arena: VdMirStmtArenaRef<'a>,
+    hint_arena: VdMirHintArenaRef<'a>,
+    hypothesis_arena: VdMirHypothesisArenaRef<'a>,
+    dictionary: &'a VdIsabelleDictionary,
+    mangler: VdIsabelleTranspilationMangler,
+    /// facts are only visible to proof methods when chained with `from`,
+    /// so we keep track of the names under which hypotheses are stated
+    hypothesis_idents: FxHashMap<VdMirHypothesisIdx, IeIdent>,
+    /// names of the hypotheses visible at the current point of the proof,
+    /// for methods like `linarith` that search the whole context in Lean
+    facts_in_scope: Vec<IeIdent>,
+    current_module_path: VdModulePath,
+    source_map: &'a VdMirRegionSourceMap,
+    sem_sentence_range_map: &'a VdSemSentenceTokenIdxRangeMap,
+    token_storage: &'a LxTokenStorage,
+    cache: S::Cache,
+    errors: Vec<VdIsabelleTranspilationError>,
+}
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub fn new(
+        db: &'a EternerDb,
+        scheme: &'a S,
+        input: &'a str,
+        expr_arena: VdMirExprArenaRef<'a>,
+        stmt_arena: VdMirStmtArenaRef<'a>,
+        hint_arena: VdMirHintArenaRef<'a>,
+        hypothesis_arena: VdMirHypothesisArenaRef<'a>,
+        symbol_local_defn_storage: &'a VdMirSymbolLocalDefnStorage,
+        source_map: &'a VdMirRegionSourceMap,
+        dictionary: &'a VdIsabelleDictionary,
+        root_module_path: VdModulePath,
+        sem_sentence_range_map: &'a VdSemSentenceTokenIdxRangeMap,
+        token_storage: &'a LxTokenStorage,
+    ) -> Self {
+        Self {
+            db,
+            scheme,
+            input,
+            isabelle_hir_expr_constructor: Default::default(),
+            expr_arena,
+            stmt_arena,
+            hint_arena,
+            hypothesis_arena,
+            dictionary,
+            mangler: VdIsabelleTranspilationMangler::new(symbol_local_defn_storage, db),
+            hypothesis_idents: Default::default(),
+            facts_in_scope: vec![],
+            current_module_path: root_module_path,
+            source_map,
+            sem_sentence_range_map,
+            token_storage,
+            cache: S::Cache::default(),
+            errors: vec![],
+        }
+    }
+
+    pub(crate) fn with_module_path<R>(
+        &mut self,
+        module_path: VdModulePath,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        debug_assert_eq!(
+            module_path.parent(),
+            Some(self.current_module_path),
+            "module path = {}, current module path = {}",
+            module_path.show(),
+            self.current_module_path.show(),
+        );
+        let prev_module_path = self.current_module_path;
+        self.current_module_path = module_path;
+        let result = f(self);
+        self.current_module_path = prev_module_path;
+        result
+    }
+
+    pub(crate) fn mangle_symbol(&self, symbol_local_defn: VdMirSymbolLocalDefnIdx) -> IeIdent {
+        self.mangler.mangle_symbol(symbol_local_defn)
+    }
+
+    pub(crate) fn mangle_hypothesis(&mut self) -> IeIdent {
+        self.mangler.mangle_hypothesis(self.db)
+    }
+
+    /// mangles a name for the hypothesis and remembers it for later `from`s
+    pub(crate) fn mangle_hypothesis_for(&mut self, hypothesis: VdMirHypothesisIdx) -> IeIdent {
+        let ident = self.mangle_hypothesis();
+        self.record_hypothesis_ident(hypothesis, ident);
+        ident
+    }
+
+    /// overrides names that are only visible inside an earlier proof block
+    pub(crate) fn record_hypothesis_ident(
+        &mut self,
+        hypothesis: VdMirHypothesisIdx,
+        ident: IeIdent,
+    ) {
+        self.hypothesis_idents.insert(hypothesis, ident);
+        self.facts_in_scope.push(ident);
+    }
+
+    pub(crate) fn hypothesis_ident(&self, hypothesis: VdMirHypothesisIdx) -> Option<IeIdent> {
+        self.hypothesis_idents.get(&hypothesis).copied()
+    }
+
+    pub(crate) fn facts_in_scope(&self) -> &[IeIdent] {
+        &self.facts_in_scope
+    }
+
+    /// the transpilation goes on with a placeholder
+    pub(crate) fn report(&mut self, error: VdIsabelleTranspilationError) {
+        self.errors.push(error)
+    }
+
+    /// hypotheses named inside `f` go out of scope when it returns
+    pub(crate) fn with_fact_scope<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let len = self.facts_in_scope.len();
+        let result = f(self);
+        self.facts_in_scope.truncate(len);
+        result
+    }
+}
+
+impl<'db, Scheme> VdIsabelleTranspilationBuilder<'db, Scheme>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    pub fn db(&self) -> &'db EternerDb {
+        self.db
+    }
+
+    pub fn scheme(&self) -> &'db Scheme {
+        self.scheme
+    }
+
+    pub fn expr_arena(&self) -> VdMirExprArenaRef<'db> {
+        self.expr_arena
+    }
+
+    pub fn stmt_arena(&self) -> VdMirStmtArenaRef<'db> {
+        self.stmt_arena
+    }
+
+    pub fn hint_arena(&self) -> VdMirHintArenaRef<'db> {
+        self.hint_arena
+    }
+
+    pub fn hypothesis_arena(&self) -> VdMirHypothesisArenaRef<'db> {
+        self.hypothesis_arena
+    }
+
+    pub fn source_map(&self) -> &'db VdMirRegionSourceMap {
+        self.source_map
+    }
+
+    pub fn dictionary(&self) -> &'db VdIsabelleDictionary {
+        self.dictionary
+    }
+
+    pub fn input(&self) -> &'db str {
+        self.input
+    }
+
+    pub fn token_storage(&self) -> &'db LxTokenStorage {
+        self.token_storage
+    }
+
+    pub fn sem_sentence_range_map(&self) -> &'db VdSemSentenceTokenIdxRangeMap {
+        self.sem_sentence_range_map
+    }
+
+    pub fn cache(&self) -> &Scheme::Cache {
+        &self.cache
+    }
+
+    pub fn cache_mut(&mut self) -> &mut Scheme::Cache {
+        &mut self.cache
+    }
+}
+
+impl<'db, S> Deref for VdIsabelleTranspilationBuilder<'db, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    type Target = IeHirExprConstructor;
+
+    fn deref(&self) -> &Self::Target {
+        &self.isabelle_hir_expr_constructor
+    }
+}
+
+impl<'db, S> DerefMut for VdIsabelleTranspilationBuilder<'db, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.isabelle_hir_expr_constructor
+    }
+}
+
+impl<'db, S> VdIsabelleTranspilationBuilder<'db, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub fn finish(
+        self,
+    ) -> (
+        IeHirExprArena,
+        IeHirStmtArena,
+        IeHirItemDefnArena,
+        IeHirItemDefnCommentMap,
+        Vec<VdIsabelleTranspilationError>,
+    ) {
+        let (expr_arena, stmt_arena, defn_arena, defn_comments) =
+            self.isabelle_hir_expr_constructor.finish();
+        (
+            expr_arena,
+            stmt_arena,
+            defn_arena,
+            defn_comments,
+            self.errors,
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use super::*;
+use isabelle_hir_expr::{
+    item_defn::IeHirItemDefnComment,
+    proof::{IeHirMethod, IeHirProof},
+};
+use visored_mir_expr::{stmt::VdMirStmtIdx, stmt::VdMirStmtSource};
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    /// the counterpart of lean's `obvious`
+    pub fn default_proof(&mut self) -> IeHirProof {
+        IeHirProof::By(IeHirMethod::Auto)
+    }
+
+    pub(crate) fn item_defn_comment(&self, stmt: VdMirStmtIdx) -> IeHirItemDefnComment {
+        let token_idx_range = match self.source_map()[stmt] {
+            VdMirStmtSource::Block(_)
+            | VdMirStmtSource::Division(_)
+            | VdMirStmtSource::Clause(_) => return IeHirItemDefnComment::Void,
+            VdMirStmtSource::Qed => return IeHirItemDefnComment::Qed,
+            VdMirStmtSource::Sentence(sentence) => self.sem_sentence_range_map()[sentence],
+        };
+        let offset_range = self
+            .token_storage()
+            .token_idx_range_offset_range(token_idx_range);
+        IeHirItemDefnComment::from_latex_source(&self.input()[offset_range])
+    }
+}
@@ -0,0 +1,33 @@
+pub mod func_key;
+pub mod item_path;
+
+use self::{func_key::*, item_path::*};
+use eterned::db::EternerDb;
+use rustc_hash::FxHashMap;
+use visored_entity_path::path::VdItemPath;
+use visored_mir_expr::expr::application::VdMirFuncKey;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VdIsabelleDictionary {
+    item_path_translation_table: VdItemPathDictionary,
+    func_key_translation_table: VdFuncKeyDictionary,
+}
+
+impl VdIsabelleDictionary {
+    pub fn new_standard(db: &EternerDb) -> Self {
+        Self {
+            item_path_translation_table: VdItemPathDictionary::new_standard(db),
+            func_key_translation_table: VdFuncKeyDictionary::new_standard(db),
+        }
+    }
+}
+
+impl VdIsabelleDictionary {
+    pub fn item_path_translation(&self, item_path: VdItemPath) -> Option<&VdItemPathTranslation> {
+        self.item_path_translation_table.get(item_path)
+    }
+
+    pub fn func_key_translation(&self, func_key: VdMirFuncKey) -> Option<&VdFuncKeyTranslation> {
+        self.func_key_translation_table.get(func_key)
+    }
+}
@@ -0,0 +1,221 @@
+//! operators of isabelle are overloaded through type classes,
+//! so unlike lean the translations don't carry instantiations
+use super::*;
+use isabelle_hir_expr::{
+    item_path::IeItemPath,
+    opr::{IeBinaryOpr, IePrefixOpr},
+};
+use visored_mir_expr::expr::application::menu::{vd_mir_func_key_menu, VdMirFuncKeyMenu};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VdFuncKeyTranslation {
+    /// unary plus, which isabelle doesn't have
+    Identity,
+    PrefixOpr(IePrefixOpr),
+    FoldingBinaryOpr(IeBinaryOpr),
+    ChainingBinaryOpr(IeBinaryOpr),
+    Power,
+    Function(IeItemPath),
+    JustBinaryOpr(IeBinaryOpr),
+    InSet,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VdFuncKeyDictionary {
+    translations: FxHashMap<VdMirFuncKey, VdFuncKeyTranslation>,
+}
+
+impl VdFuncKeyDictionary {
+    pub fn new_standard(db: &EternerDb) -> Self {
+        use VdFuncKeyTranslation::*;
+
+        let VdMirFuncKeyMenu {
+            int_pos,
+            rat_pos,
+            real_pos,
+            complex_pos,
+            int_neg,
+            rat_neg,
+            real_neg,
+            complex_neg,
+            int_sub,
+            rat_sub,
+            real_sub,
+            complex_sub,
+            rat_div,
+            real_div,
+            complex_div,
+            nat_add,
+            int_add,
+            rat_add,
+            real_add,
+            complex_add,
+            nat_mul,
+            int_mul,
+            rat_mul,
+            real_mul,
+            complex_mul,
+            nat_to_the_power_of_nat,
+            int_to_the_power_of_nat,
+            rat_to_the_power_of_nat,
+            real_to_the_power_of_nat,
+            complex_to_the_power_of_nat,
+            nat_eq,
+            int_eq,
+            rat_eq,
+            real_eq,
+            complex_eq,
+            nat_ne,
+            int_ne,
+            rat_ne,
+            real_ne,
+            complex_ne,
+            nat_lt,
+            int_lt,
+            rat_lt,
+            real_lt,
+            nat_gt,
+            int_gt,
+            rat_gt,
+            real_gt,
+            nat_le,
+            int_le,
+            rat_le,
+            real_le,
+            nat_ge,
+            int_ge,
+            rat_ge,
+            real_ge,
+            in_set,
+            nat_dvd,
+            int_dvd,
+            prop_implies,
+            prop_iff,
+            real_sqrt,
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            nat_max,
+            int_max,
+            rat_max,
+            real_max,
+            nat_min,
+            int_min,
+            rat_min,
+            real_min,
+            nat_binom,
+            real_floor,
+            int_abs,
+            rat_abs,
+            real_abs,
+        } = *vd_mir_func_key_menu(db);
+        Self::new([
+            (int_pos, Identity),
+            (rat_pos, Identity),
+            (real_pos, Identity),
+            (complex_pos, Identity),
+            (int_neg, PrefixOpr(IePrefixOpr::Neg)),
+            (rat_neg, PrefixOpr(IePrefixOpr::Neg)),
+            (real_neg, PrefixOpr(IePrefixOpr::Neg)),
+            (complex_neg, PrefixOpr(IePrefixOpr::Neg)),
+            (int_sub, JustBinaryOpr(IeBinaryOpr::Sub)),
+            (rat_sub, JustBinaryOpr(IeBinaryOpr::Sub)),
+            (real_sub, JustBinaryOpr(IeBinaryOpr::Sub)),
+            (complex_sub, JustBinaryOpr(IeBinaryOpr::Sub)),
+            (rat_div, JustBinaryOpr(IeBinaryOpr::Div)),
+            (real_div, JustBinaryOpr(IeBinaryOpr::Div)),
+            (complex_div, JustBinaryOpr(IeBinaryOpr::Div)),
+            (nat_add, FoldingBinaryOpr(IeBinaryOpr::Add)),
+            (int_add, FoldingBinaryOpr(IeBinaryOpr::Add)),
+            (rat_add, FoldingBinaryOpr(IeBinaryOpr::Add)),
+            (real_add, FoldingBinaryOpr(IeBinaryOpr::Add)),
+            (complex_add, FoldingBinaryOpr(IeBinaryOpr::Add)),
+            (nat_mul, FoldingBinaryOpr(IeBinaryOpr::Mul)),
+            (int_mul, FoldingBinaryOpr(IeBinaryOpr::Mul)),
+            (rat_mul, FoldingBinaryOpr(IeBinaryOpr::Mul)),
+            (real_mul, FoldingBinaryOpr(IeBinaryOpr::Mul)),
+            (complex_mul, FoldingBinaryOpr(IeBinaryOpr::Mul)),
+            (nat_to_the_power_of_nat, Power),
+            (int_to_the_power_of_nat, Power),
+            (rat_to_the_power_of_nat, Power),
+            (real_to_the_power_of_nat, Power),
+            (complex_to_the_power_of_nat, Power),
+            (nat_eq, ChainingBinaryOpr(IeBinaryOpr::Eq)),
+            (int_eq, ChainingBinaryOpr(IeBinaryOpr::Eq)),
+            (rat_eq, ChainingBinaryOpr(IeBinaryOpr::Eq)),
+            (real_eq, ChainingBinaryOpr(IeBinaryOpr::Eq)),
+            (complex_eq, ChainingBinaryOpr(IeBinaryOpr::Eq)),
+            (nat_ne, ChainingBinaryOpr(IeBinaryOpr::Ne)),
+            (int_ne, ChainingBinaryOpr(IeBinaryOpr::Ne)),
+            (rat_ne, ChainingBinaryOpr(IeBinaryOpr::Ne)),
+            (real_ne, ChainingBinaryOpr(IeBinaryOpr::Ne)),
+            (complex_ne, ChainingBinaryOpr(IeBinaryOpr::Ne)),
+            (nat_lt, ChainingBinaryOpr(IeBinaryOpr::Lt)),
+            (int_lt, ChainingBinaryOpr(IeBinaryOpr::Lt)),
+            (rat_lt, ChainingBinaryOpr(IeBinaryOpr::Lt)),
+            (real_lt, ChainingBinaryOpr(IeBinaryOpr::Lt)),
+            (nat_gt, ChainingBinaryOpr(IeBinaryOpr::Gt)),
+            (int_gt, ChainingBinaryOpr(IeBinaryOpr::Gt)),
+            (rat_gt, ChainingBinaryOpr(IeBinaryOpr::Gt)),
+            (real_gt, ChainingBinaryOpr(IeBinaryOpr::Gt)),
+            (nat_le, ChainingBinaryOpr(IeBinaryOpr::Le)),
+            (int_le, ChainingBinaryOpr(IeBinaryOpr::Le)),
+            (rat_le, ChainingBinaryOpr(IeBinaryOpr::Le)),
+            (real_le, ChainingBinaryOpr(IeBinaryOpr::Le)),
+            (nat_ge, ChainingBinaryOpr(IeBinaryOpr::Ge)),
+            (int_ge, ChainingBinaryOpr(IeBinaryOpr::Ge)),
+            (rat_ge, ChainingBinaryOpr(IeBinaryOpr::Ge)),
+            (real_ge, ChainingBinaryOpr(IeBinaryOpr::Ge)),
+            (in_set, InSet),
+            (nat_dvd, ChainingBinaryOpr(IeBinaryOpr::Dvd)),
+            (int_dvd, ChainingBinaryOpr(IeBinaryOpr::Dvd)),
+            (prop_implies, ChainingBinaryOpr(IeBinaryOpr::Implies)),
+            (prop_iff, ChainingBinaryOpr(IeBinaryOpr::Iff)),
+            (real_sqrt, Function(IeItemPath::Sqrt)),
+            (real_log, Function(IeItemPath::Ln)),
+            (real_exp, Function(IeItemPath::Exp)),
+            (nat_gcd, Function(IeItemPath::Gcd)),
+            (int_gcd, Function(IeItemPath::Gcd)),
+            (nat_max, Function(IeItemPath::Max)),
+            (int_max, Function(IeItemPath::Max)),
+            (rat_max, Function(IeItemPath::Max)),
+            (real_max, Function(IeItemPath::Max)),
+            (nat_min, Function(IeItemPath::Min)),
+            (int_min, Function(IeItemPath::Min)),
+            (rat_min, Function(IeItemPath::Min)),
+            (real_min, Function(IeItemPath::Min)),
+            (nat_binom, Function(IeItemPath::Binomial)),
+            (real_floor, Function(IeItemPath::Floor)),
+            (int_abs, Function(IeItemPath::Abs)),
+            (rat_abs, Function(IeItemPath::Abs)),
+            (real_abs, Function(IeItemPath::Abs)),
+        ])
+    }
+
+    pub fn new(
+        translations: impl IntoIterator<Item = (VdMirFuncKey, VdFuncKeyTranslation)> + Clone,
+    ) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            let mut seen = std::collections::HashMap::new();
+            for (idx, (key, _)) in translations.clone().into_iter().enumerate() {
+                if let Some(prev_idx) = seen.insert(key, idx) {
+                    panic!(
+                        "Duplicate key {:?} found at positions {} and {}",
+                        key, prev_idx, idx
+                    );
+                }
+            }
+        }
+        Self {
+            translations: translations.into_iter().collect(),
+        }
+    }
+}
+
+impl VdFuncKeyDictionary {
+    pub fn get(&self, func_key: VdMirFuncKey) -> Option<&VdFuncKeyTranslation> {
+        self.translations.get(&func_key)
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+use isabelle_hir_expr::item_path::IeItemPath;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VdItemPathTranslation {
+    ItemPath(IeItemPath),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VdItemPathDictionary {
+    translations: FxHashMap<VdItemPath, VdItemPathTranslation>,
+}
+
+impl VdItemPathDictionary {
+    pub fn new(
+        translations: impl IntoIterator<Item = (VdItemPath, VdItemPathTranslation)>,
+    ) -> Self {
+        Self {
+            translations: translations.into_iter().collect(),
+        }
+    }
+
+    pub fn new_standard(_db: &EternerDb) -> Self {
+        Self::new([
+            (
+                VdItemPath::NAT,
+                VdItemPathTranslation::ItemPath(IeItemPath::Nat),
+            ),
+            (
+                VdItemPath::INT,
+                VdItemPathTranslation::ItemPath(IeItemPath::Int),
+            ),
+            (
+                VdItemPath::RAT,
+                VdItemPathTranslation::ItemPath(IeItemPath::Rat),
+            ),
+            (
+                VdItemPath::REAL,
+                VdItemPathTranslation::ItemPath(IeItemPath::Real),
+            ),
+            (
+                VdItemPath::COMPLEX,
+                VdItemPathTranslation::ItemPath(IeItemPath::Complex),
+            ),
+            (
+                VdItemPath::PROP,
+                VdItemPathTranslation::ItemPath(IeItemPath::Bool),
+            ),
+        ])
+    }
+}
+
+impl VdItemPathDictionary {
+    pub(crate) fn get(&self, item_path: VdItemPath) -> Option<&VdItemPathTranslation> {
+        self.translations.get(&item_path)
+    }
+}
@@ -0,0 +1,19 @@
+/// a construct the transpilation doesn't support yet,
+/// which becomes `undefined` in terms and `sorry` in proofs
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum VdIsabelleTranspilationError {
+    #[error("unsupported stmt: {0}")]
+    UnsupportedStmt(&'static str),
+    #[error("unsupported type: {0}")]
+    UnsupportedType(&'static str),
+    #[error("unsupported coercion of a theorem application")]
+    UnsupportedCoercion,
+    #[error("unsupported hypothesis construction: {0}")]
+    UnsupportedHypothesisConstruction(&'static str),
+    #[error("unsupported expr: {0}")]
+    UnsupportedExpr(&'static str),
+    #[error("unsupported separated list: {0}")]
+    UnsupportedSeparatedList(&'static str),
+    #[error("no translation for `{0}`")]
+    MissingTranslation(String),
+}
@@ -0,0 +1,142 @@
+mod application;
+mod separated_list;
+
+use super::VdTranspileToIsabelle;
+use crate::{
+    builder::VdIsabelleTranspilationBuilder,
+    dictionary::{func_key::VdFuncKeyTranslation, item_path::VdItemPathTranslation},
+    error::VdIsabelleTranspilationError,
+    scheme::IsVdIsabelleTranspilationScheme,
+    ty::VdTypeIsabelleTranspilation,
+};
+use either::*;
+use isabelle_hir_expr::{
+    expr::{IeHirExprData, IeHirExprEntry, IeHirExprIdx, IeHirExprIdxRange, IeHirFunc},
+    item_path::IeItemPath,
+};
+use visored_mir_expr::expr::{VdMirExprData, VdMirExprIdx};
+use visored_term::{
+    term::literal::{VdLiteral, VdLiteralData},
+    ty::VdType,
+};
+
+impl<S> VdTranspileToIsabelle<S, IeHirExprIdx> for VdMirExprIdx
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    fn to_isabelle(self, builder: &mut VdIsabelleTranspilationBuilder<S>) -> IeHirExprIdx {
+        let entry = builder.build_expr_entry(self);
+        builder.alloc_expr(entry)
+    }
+}
+
+impl<S, I> VdTranspileToIsabelle<S, IeHirExprIdxRange> for I
+where
+    S: IsVdIsabelleTranspilationScheme,
+    I: Copy + IntoIterator<Item = VdMirExprIdx>,
+{
+    fn to_isabelle(self, builder: &mut VdIsabelleTranspilationBuilder<S>) -> IeHirExprIdxRange {
+        let mut exprs = vec![];
+        for expr in self {
+            exprs.push(builder.build_expr_entry(expr));
+        }
+        builder.alloc_exprs(exprs)
+    }
+}
+
+impl<'db, S> VdIsabelleTranspilationBuilder<'db, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub(crate) fn build_expr_entry(&mut self, expr: VdMirExprIdx) -> IeHirExprEntry {
+        let data = self.build_expr_data(expr);
+        let entry = &self.expr_arena()[expr];
+        let ty = entry.ty();
+        match entry.expected_ty() {
+            Some(expected_ty) if expected_ty != ty => self.build_coercion(data, ty, expected_ty),
+            _ => IeHirExprEntry::new(data, None),
+        }
+    }
+
+    /// isabelle has no implicit coercions, so apart from numerals,
+    /// which are overloaded, terms have to be embedded explicitly
+    pub(crate) fn build_coercion(
+        &mut self,
+        data: IeHirExprData,
+        ty: VdType,
+        expected_ty: VdType,
+    ) -> IeHirExprEntry {
+        let VdTypeIsabelleTranspilation::Type(ty_ascription) = expected_ty.to_isabelle(self);
+        let data = match data {
+            IeHirExprData::Literal(_) => data,
+            _ => {
+                let embedding = match self.ie_item_path_of_vd_ty(ty) {
+                    Some(IeItemPath::Nat) => IeItemPath::OfNat,
+                    Some(IeItemPath::Int) => IeItemPath::OfInt,
+                    Some(IeItemPath::Rat) => IeItemPath::OfRat,
+                    Some(_) => todo!(),
+                    None => {
+                        return IeHirExprEntry::new(IeHirExprData::Undefined, Some(ty_ascription))
+                    }
+                };
+                let argument = IeHirExprEntry::new(data, None);
+                IeHirExprData::Application {
+                    function: IeHirFunc::ItemPath(embedding),
+                    arguments: self.alloc_exprs([argument]),
+                }
+            }
+        };
+        IeHirExprEntry::new(data, Some(ty_ascription))
+    }
+
+    fn build_expr_data(&mut self, expr: VdMirExprIdx) -> IeHirExprData {
+        match *self.expr_arena()[expr].data() {
+            VdMirExprData::Literal(literal) => IeHirExprData::Literal(to_isabelle_literal(literal)),
+            VdMirExprData::ItemPath(item_path) => {
+                let Some(translation) = self.dictionary().item_path_translation(item_path) else {
+                    todo!()
+                };
+                match *translation {
+                    VdItemPathTranslation::ItemPath(item_path) => {
+                        IeHirExprData::ItemPath(item_path)
+                    }
+                }
+            }
+            VdMirExprData::Variable(local_defn) => IeHirExprData::Variable {
+                ident: self.mangle_symbol(local_defn),
+            },
+            VdMirExprData::Application {
+                function,
+                arguments,
+            } => self.build_application(function, arguments),
+            VdMirExprData::FoldingSeparatedList {
+                leader,
+                ref followers,
+            } => self.build_folding_separated_list(leader, followers),
+            VdMirExprData::ChainingSeparatedList {
+                leader,
+                ref followers,
+                joined_signature,
+            } => self.build_chaining_separated_list(leader, followers, joined_signature),
+            VdMirExprData::Quantification { .. } => {
+                self.report(VdIsabelleTranspilationError::UnsupportedExpr(
+                    "quantification",
+                ));
+                IeHirExprData::Undefined
+            }
+            VdMirExprData::Limit { .. } => {
+                self.report(VdIsabelleTranspilationError::UnsupportedExpr("limit"));
+                IeHirExprData::Undefined
+            }
+        }
+    }
+}
+
+fn to_isabelle_literal(literal: VdLiteral) -> String {
+    match *literal.data() {
+        VdLiteralData::Int128(i) => i.to_string(),
+        VdLiteralData::BigInt(ref n) => n.to_string(),
+        VdLiteralData::Float(ref lit) => lit.to_string(),
+        VdLiteralData::SpecialConstant(_) => todo!(),
+    }
+}
@@ -0,0 +1,41 @@
+use super::*;
+use isabelle_hir_expr::opr::IeBinaryOpr;
+use visored_mir_expr::expr::{application::VdMirFunc, VdMirExprIdxRange};
+
+impl<'db, S> VdIsabelleTranspilationBuilder<'db, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub(super) fn build_application(
+        &mut self,
+        func: VdMirFunc,
+        arguments: VdMirExprIdxRange,
+    ) -> IeHirExprData {
+        match func.key_or_expr() {
+            Left(func_key) => {
+                let Some(translation) = self.dictionary().func_key_translation(func_key) else {
+                    todo!("no translation for func key `{:?}`", func_key)
+                };
+                let function = match *translation {
+                    VdFuncKeyTranslation::Identity => {
+                        debug_assert_eq!(arguments.len(), 1);
+                        return self.build_expr_data(arguments.first().unwrap());
+                    }
+                    VdFuncKeyTranslation::PrefixOpr(opr) => IeHirFunc::PrefixOpr(opr),
+                    VdFuncKeyTranslation::FoldingBinaryOpr(_)
+                    | VdFuncKeyTranslation::ChainingBinaryOpr(_) => todo!(),
+                    VdFuncKeyTranslation::JustBinaryOpr(opr) => IeHirFunc::BinaryOpr(opr),
+                    VdFuncKeyTranslation::Power => IeHirFunc::BinaryOpr(IeBinaryOpr::Pow),
+                    VdFuncKeyTranslation::Function(item_path) => IeHirFunc::ItemPath(item_path),
+                    // TODO: implement
+                    VdFuncKeyTranslation::InSet => return IeHirExprData::Undefined,
+                };
+                IeHirExprData::Application {
+                    function,
+                    arguments: arguments.to_isabelle(self),
+                }
+            }
+            Right(_) => todo!(),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::*;
+use crate::error::VdIsabelleTranspilationError;
+use isabelle_hir_expr::opr::IeBinaryOpr;
+use visored_mir_expr::expr::application::VdMirFunc;
+use visored_signature::signature::separator::base::VdBaseSeparatorSignature;
+
+impl<'db, S> VdIsabelleTranspilationBuilder<'db, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub(super) fn build_folding_separated_list(
+        &mut self,
+        leader: VdMirExprIdx,
+        followers: &[(VdMirFunc, VdMirExprIdx)],
+    ) -> IeHirExprData {
+        debug_assert!(followers.len() >= 1);
+        let mut oprs_and_signatures = vec![];
+        for &(func, _) in followers {
+            let VdMirFunc::NormalBaseSeparator(signature) = func else {
+                self.report(VdIsabelleTranspilationError::UnsupportedSeparatedList(
+                    "folding by other than base separators",
+                ));
+                return IeHirExprData::Undefined;
+            };
+            let Some(opr) = self.build_folding_opr(func) else {
+                return IeHirExprData::Undefined;
+            };
+            oprs_and_signatures.push((opr, signature))
+        }
+        let mut follower_iter = std::iter::zip(oprs_and_signatures, followers)
+            .map(|((opr, signature), &(_, follower))| (opr, signature, follower));
+        let leader = self.build_expr_entry(leader);
+        let (fst_opr, fst_signature, fst_follower) = follower_iter.next().unwrap();
+        let fst_follower = self.build_expr_entry(fst_follower);
+        let mut result = IeHirExprData::Application {
+            function: IeHirFunc::BinaryOpr(fst_opr),
+            arguments: self.alloc_exprs([leader, fst_follower]),
+        };
+        let mut result_ty = fst_signature.expr_ty();
+        for (opr, signature, follower) in follower_iter {
+            let follower = self.build_expr_entry(follower);
+            let result_expected_ty = signature.item_ty();
+            let result_entry = if result_expected_ty != result_ty {
+                self.build_coercion(result, result_ty, result_expected_ty)
+            } else {
+                IeHirExprEntry::new(result, None)
+            };
+            result = IeHirExprData::Application {
+                function: IeHirFunc::BinaryOpr(opr),
+                arguments: self.alloc_exprs([result_entry, follower]),
+            };
+            result_ty = signature.expr_ty();
+        }
+        result
+    }
+
+    /// `None` if unsupported, which is reported
+    fn build_folding_opr(&mut self, func: VdMirFunc) -> Option<IeBinaryOpr> {
+        let error = match func.key_or_expr() {
+            Left(func_key) => match self.dictionary().func_key_translation(func_key) {
+                Some(&VdFuncKeyTranslation::FoldingBinaryOpr(opr)) => return Some(opr),
+                Some(_) => VdIsabelleTranspilationError::UnsupportedSeparatedList(
+                    "folding by a non-folding operator",
+                ),
+                None => VdIsabelleTranspilationError::MissingTranslation(format!("{func_key:?}")),
+            },
+            Right(_) => {
+                VdIsabelleTranspilationError::UnsupportedSeparatedList("folding by an expression")
+            }
+        };
+        self.report(error);
+        None
+    }
+
+    pub(super) fn build_chaining_separated_list(
+        &mut self,
+        leader: VdMirExprIdx,
+        followers: &[(VdMirFunc, VdMirExprIdx)],
+        _joined_signature: Option<VdBaseSeparatorSignature>,
+    ) -> IeHirExprData {
+        if followers.len() != 1 {
+            self.report(VdIsabelleTranspilationError::UnsupportedSeparatedList(
+                "chains of more than one relation outside of calculations",
+            ));
+            return IeHirExprData::Undefined;
+        }
+        let (func, follower) = *followers.first().unwrap();
+        if let Left(func_key) = func.key_or_expr() {
+            if let Some(VdFuncKeyTranslation::InSet) =
+                self.dictionary().func_key_translation(func_key)
+            {
+                // TODO: implement
+                return IeHirExprData::Undefined;
+            }
+        }
+        let Some(opr) = func.to_isabelle(self) else {
+            return IeHirExprData::Undefined;
+        };
+        let leader = self.build_expr_entry(leader);
+        let follower = self.build_expr_entry(follower);
+        IeHirExprData::Application {
+            function: IeHirFunc::BinaryOpr(opr),
+            arguments: self.alloc_exprs([leader, follower]),
+        }
+    }
+}
+
+/// the relation a chaining separator stands for, `None` if unsupported, which is reported
+impl<S> VdTranspileToIsabelle<S, Option<IeBinaryOpr>> for VdMirFunc
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    fn to_isabelle(self, builder: &mut VdIsabelleTranspilationBuilder<S>) -> Option<IeBinaryOpr> {
+        let error = match self.key_or_expr() {
+            Left(key) => match builder.dictionary().func_key_translation(key) {
+                Some(&translation) => match translation {
+                    VdFuncKeyTranslation::ChainingBinaryOpr(opr)
+                    | VdFuncKeyTranslation::FoldingBinaryOpr(opr)
+                    | VdFuncKeyTranslation::JustBinaryOpr(opr) => return Some(opr),
+                    VdFuncKeyTranslation::Power => return Some(IeBinaryOpr::Pow),
+                    VdFuncKeyTranslation::Identity
+                    | VdFuncKeyTranslation::PrefixOpr(_)
+                    | VdFuncKeyTranslation::Function(_)
+                    | VdFuncKeyTranslation::InSet => {
+                        VdIsabelleTranspilationError::UnsupportedSeparatedList(
+                            "chaining by a non-binary operator",
+                        )
+                    }
+                },
+                None => VdIsabelleTranspilationError::MissingTranslation(format!("{key:?}")),
+            },
+            Right(_) => {
+                VdIsabelleTranspilationError::UnsupportedSeparatedList("chaining by an expression")
+            }
+        };
+        builder.report(error);
+        None
+    }
+}
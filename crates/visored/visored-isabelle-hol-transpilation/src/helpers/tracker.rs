@@ -0,0 +1,260 @@
+use super::*;
+use crate::{
+    builder::VdIsabelleTranspilationBuilder, dictionary::VdIsabelleDictionary,
+    error::VdIsabelleTranspilationError, scheme::IsVdIsabelleTranspilationScheme,
+    VdTranspileToIsabelle,
+};
+use eterned::db::EternerDb;
+use husky_tree_utils::display::DisplayTree;
+use isabelle_hir_expr::{
+    expr::{IeHirExprArena, IeHirExprIdx},
+    helpers::{
+        fmt::{IeHirExprFormatter, IeHirExprFormatterConfig},
+        show::display_tree::IeHirExprDisplayTreeBuilder,
+    },
+    item_defn::{IeHirItemDefnArena, IeHirItemDefnCommentMap, IeHirItemDefnIdxRange},
+    stmt::IeHirStmtArena,
+};
+use latex_prelude::helper::tracker::{
+    LxDocumentBodyInput, LxDocumentInput, LxFormulaInput, LxPageInput,
+};
+use visored_annotation::annotation::{space::VdSpaceAnnotation, token::VdTokenAnnotation};
+use visored_mir_expr::{
+    elaborator::IsVdMirTacticElaborator,
+    helpers::tracker::{IsVdMirExprInput, VdMirExprTracker},
+    region::VdMirExprRegionDataRef,
+};
+use visored_syn_expr::vibe::VdSynExprVibe;
+
+pub struct VdIsabelleTranspilationTracker<
+    'a,
+    Scheme,
+    Input: IsVdIsabelleTranspilationInput<'a, Scheme>,
+> where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    input: Input,
+    expr_arena: IeHirExprArena,
+    stmt_arena: IeHirStmtArena,
+    defn_arena: IeHirItemDefnArena,
+    defn_comments: IeHirItemDefnCommentMap,
+    errors: Vec<VdIsabelleTranspilationError>,
+    output: Input::VdIsabelleTranspilationOutput,
+}
+
+pub trait IsVdIsabelleTranspilationInput<'a, Scheme>: IsVdMirExprInput<'a>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    type VdIsabelleTranspilationOutput: IsVdIsabelleTranspilationOutput
+        + FromVdTranspileToIsabelle<Scheme, Self::VdMirExprOutput>;
+
+    fn show_fmt(
+        &self,
+        output: Self::VdIsabelleTranspilationOutput,
+        formatter: &mut IeHirExprFormatter,
+    );
+}
+
+pub trait IsVdIsabelleTranspilationOutput: std::fmt::Debug + Copy {
+    fn show_display_tree(self, builder: &IeHirExprDisplayTreeBuilder) -> String;
+}
+
+pub trait FromVdTranspileToIsabelle<Scheme, S>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    fn from_transpile_to_isabelle(
+        s: S,
+        builder: &mut VdIsabelleTranspilationBuilder<Scheme>,
+    ) -> Self;
+}
+
+impl<Scheme, S, T> FromVdTranspileToIsabelle<Scheme, S> for T
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+    S: VdTranspileToIsabelle<Scheme, T>,
+{
+    fn from_transpile_to_isabelle(
+        s: S,
+        builder: &mut VdIsabelleTranspilationBuilder<Scheme>,
+    ) -> Self {
+        s.to_isabelle(builder)
+    }
+}
+
+impl<'a, Scheme, Input> VdIsabelleTranspilationTracker<'a, Scheme, Input>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+    Input: IsVdIsabelleTranspilationInput<'a, Scheme>,
+{
+    pub fn new<'db, Elaborator: IsVdMirTacticElaborator<'db>>(
+        input: Input,
+        token_annotations: &[((&str, &str), VdTokenAnnotation)],
+        space_annotations: &[((&str, &str), VdSpaceAnnotation)],
+        models: &'a VdModels,
+        vibe: VdSynExprVibe,
+        db: &'db EternerDb,
+        scheme: &'db Scheme,
+        gen_elaborator: impl Fn(VdMirExprRegionDataRef) -> Elaborator,
+    ) -> Self {
+        let content = input.content();
+        let VdMirExprTracker {
+            input,
+            root_module_path,
+            expr_arena: vd_mir_expr_arena,
+            stmt_arena: vd_mir_stmt_arena,
+            hint_arena: vd_mir_hint_arena,
+            hypothesis_arena: vd_mir_hypothesis_arena,
+            symbol_local_defn_storage: vd_mir_symbol_local_defn_storage,
+            source_map: vd_mir_source_map,
+            sem_sentence_range_map,
+            token_storage,
+            output,
+            ..
+        } = VdMirExprTracker::new(
+            input,
+            token_annotations,
+            space_annotations,
+            models,
+            vibe,
+            db,
+            gen_elaborator,
+        );
+        let dictionary = &VdIsabelleDictionary::new_standard(db);
+        let mut builder = VdIsabelleTranspilationBuilder::new(
+            db,
+            scheme,
+            content,
+            vd_mir_expr_arena.as_arena_ref(),
+            vd_mir_stmt_arena.as_arena_ref(),
+            vd_mir_hint_arena.as_arena_ref(),
+            vd_mir_hypothesis_arena.as_arena_ref(),
+            &vd_mir_symbol_local_defn_storage,
+            &vd_mir_source_map,
+            dictionary,
+            root_module_path,
+            &sem_sentence_range_map,
+            &token_storage,
+        );
+        let output = FromVdTranspileToIsabelle::from_transpile_to_isabelle(output, &mut builder);
+        let (expr_arena, stmt_arena, defn_arena, defn_comments, errors) = builder.finish();
+        Self {
+            input,
+            expr_arena,
+            stmt_arena,
+            defn_arena,
+            defn_comments,
+            errors,
+            output,
+        }
+    }
+
+    pub fn errors(&self) -> &[VdIsabelleTranspilationError] {
+        &self.errors
+    }
+
+    pub fn show_display_tree(&self, db: &EternerDb) -> String {
+        let builder = IeHirExprDisplayTreeBuilder::new(
+            db,
+            self.expr_arena.as_arena_ref(),
+            self.stmt_arena.as_arena_ref(),
+            self.defn_arena.as_arena_ref(),
+        );
+        self.output.show_display_tree(&builder)
+    }
+
+    pub fn show_fmt(&self, db: &EternerDb) -> String {
+        let fmt_config = Default::default();
+        let mut formatter = self.formatter(&fmt_config, db);
+        self.input.show_fmt(self.output, &mut formatter);
+        formatter.finish()
+    }
+
+    fn formatter<'b>(
+        &'b self,
+        config: &'b IeHirExprFormatterConfig,
+        db: &'b EternerDb,
+    ) -> IeHirExprFormatter<'b> {
+        IeHirExprFormatter::new(
+            db,
+            self.expr_arena.as_arena_ref(),
+            self.stmt_arena.as_arena_ref(),
+            self.defn_arena.as_arena_ref(),
+            &self.defn_comments,
+            config,
+        )
+    }
+}
+
+impl<'a, Scheme> IsVdIsabelleTranspilationInput<'a, Scheme> for LxDocumentInput<'a>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    type VdIsabelleTranspilationOutput = IeHirItemDefnIdxRange;
+
+    fn show_fmt(
+        &self,
+        output: Self::VdIsabelleTranspilationOutput,
+        formatter: &mut IeHirExprFormatter,
+    ) {
+        formatter.format_defns(output);
+    }
+}
+
+impl<'a, Scheme> IsVdIsabelleTranspilationInput<'a, Scheme> for LxDocumentBodyInput<'a>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    type VdIsabelleTranspilationOutput = IeHirItemDefnIdxRange;
+
+    fn show_fmt(
+        &self,
+        output: Self::VdIsabelleTranspilationOutput,
+        formatter: &mut IeHirExprFormatter,
+    ) {
+        formatter.format_defns(output);
+    }
+}
+
+impl<'a, Scheme> IsVdIsabelleTranspilationInput<'a, Scheme> for LxPageInput<'a>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    type VdIsabelleTranspilationOutput = IeHirItemDefnIdxRange;
+
+    fn show_fmt(
+        &self,
+        output: Self::VdIsabelleTranspilationOutput,
+        formatter: &mut IeHirExprFormatter,
+    ) {
+        formatter.format_defns(output);
+    }
+}
+
+impl<'a, Scheme> IsVdIsabelleTranspilationInput<'a, Scheme> for LxFormulaInput<'a>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    type VdIsabelleTranspilationOutput = IeHirExprIdx;
+
+    fn show_fmt(
+        &self,
+        output: Self::VdIsabelleTranspilationOutput,
+        formatter: &mut IeHirExprFormatter,
+    ) {
+        formatter.format_expr_ext(output);
+    }
+}
+
+impl IsVdIsabelleTranspilationOutput for IeHirItemDefnIdxRange {
+    fn show_display_tree(self, builder: &IeHirExprDisplayTreeBuilder) -> String {
+        DisplayTree::show_trees(&builder.render_defns(self), &Default::default())
+    }
+}
+
+impl IsVdIsabelleTranspilationOutput for IeHirExprIdx {
+    fn show_display_tree(self, builder: &IeHirExprDisplayTreeBuilder) -> String {
+        builder.render_expr(self).show(&Default::default())
+    }
+}
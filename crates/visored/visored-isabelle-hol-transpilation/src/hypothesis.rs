@@ -0,0 +1,55 @@
+mod nontrivial_chain;
+mod old_main;
+mod ordinary;
+
+use super::*;
+use isabelle_hir_expr::{
+    proof::{IeHirFact, IeHirMethod, IeHirProof},
+    stmt::IeHirStmtData,
+    theorem::IeTheoremPath,
+};
+use smallvec::{smallvec, SmallVec};
+use visored_entity_path::theorem::VdTheoremPath;
+use visored_mir_expr::{
+    coercion::VdMirCoercion,
+    expr::VdMirExprData,
+    hypothesis::{
+        chunk::VdMirHypothesisChunk, construction::VdMirHypothesisConstruction,
+        VdMirHypothesisEntry, VdMirHypothesisIdx,
+    },
+};
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub(crate) fn build_hypothesis_chunk_stmts(
+        &mut self,
+        hypothesis_chunk: VdMirHypothesisChunk,
+        ie_stmts: &mut Vec<IeHirStmtData>,
+    ) {
+        let new_hypotheses = hypothesis_chunk.new_hypotheses();
+        let main_hypothesis = hypothesis_chunk.main_hypothesis();
+        for hypothesis in new_hypotheses {
+            self.build_hypothesis_stmts(hypothesis, ie_stmts);
+        }
+        if !new_hypotheses.contains(main_hypothesis) {
+            self.build_old_main_hypothesis_stmts(main_hypothesis, ie_stmts);
+        }
+    }
+
+    fn build_hypothesis_stmts(
+        &mut self,
+        hypothesis: VdMirHypothesisIdx,
+        ie_stmts: &mut Vec<IeHirStmtData>,
+    ) {
+        let hypothesis_entry = &self.hypothesis_arena()[hypothesis];
+        match *self.expr_arena()[hypothesis_entry.expr()].data() {
+            VdMirExprData::ChainingSeparatedList {
+                joined_signature: Some(_),
+                ..
+            } => self.build_nontrivial_chain_hypothesis_stmts(hypothesis_entry),
+            _ => self.build_ordinary_hypothesis_stmts(hypothesis, hypothesis_entry, ie_stmts),
+        }
+    }
+}
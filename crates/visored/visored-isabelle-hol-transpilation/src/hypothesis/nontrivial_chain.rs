@@ -0,0 +1,18 @@
+use super::*;
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    /// chains are established by a calculation in the stmt that states them
+    pub(super) fn build_nontrivial_chain_hypothesis_stmts(
+        &mut self,
+        hypothesis_entry: &VdMirHypothesisEntry,
+    ) {
+        match hypothesis_entry.construction() {
+            VdMirHypothesisConstruction::Sorry => (),
+            VdMirHypothesisConstruction::Kurapika => todo!(),
+            _ => unreachable!(),
+        }
+    }
+}
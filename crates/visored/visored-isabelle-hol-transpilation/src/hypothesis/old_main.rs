@@ -0,0 +1,26 @@
+use super::*;
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub(super) fn build_old_main_hypothesis_stmts(
+        &mut self,
+        main_hypothesis: VdMirHypothesisIdx,
+        ie_stmts: &mut Vec<IeHirStmtData>,
+    ) {
+        let (facts, proof) = match self.hypothesis_ident(main_hypothesis) {
+            Some(ident) => (smallvec![ident], IeHirProof::This),
+            None => (smallvec![], self.default_proof()),
+        };
+        let prop = self.hypothesis_arena()[main_hypothesis]
+            .expr()
+            .to_isabelle(self);
+        ie_stmts.push(IeHirStmtData::Have {
+            ident: Some(self.mangle_hypothesis()),
+            facts,
+            prop,
+            proof,
+        });
+    }
+}
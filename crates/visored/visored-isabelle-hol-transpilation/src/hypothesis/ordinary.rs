@@ -0,0 +1,113 @@
+use super::*;
+use crate::error::VdIsabelleTranspilationError;
+use isabelle_hir_expr::ident::IeIdent;
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    pub(super) fn build_ordinary_hypothesis_stmts(
+        &mut self,
+        hypothesis: VdMirHypothesisIdx,
+        hypothesis_entry: &VdMirHypothesisEntry,
+        ie_stmts: &mut Vec<IeHirStmtData>,
+    ) {
+        let mut facts: SmallVec<[_; 2]> = smallvec![];
+        let proof = match *hypothesis_entry.construction() {
+            VdMirHypothesisConstruction::Sorry => self.default_proof(),
+            VdMirHypothesisConstruction::TermTrivial(_) => {
+                IeHirProof::By(IeHirMethod::Simp { add: smallvec![] })
+            }
+            VdMirHypothesisConstruction::Apply {
+                is_real_coercion: VdMirCoercion::Obvious(_),
+                ..
+            } => {
+                self.report(VdIsabelleTranspilationError::UnsupportedCoercion);
+                IeHirProof::Sorry
+            }
+            VdMirHypothesisConstruction::Apply {
+                path,
+                is_real_coercion: VdMirCoercion::Trivial,
+                ref side_conditions,
+            } => {
+                // side conditions are chained in, where simp picks them up as premises
+                facts.extend(
+                    side_conditions
+                        .iter()
+                        .filter_map(|&side_condition| self.hypothesis_ident(side_condition)),
+                );
+                IeHirProof::By(IeHirMethod::Simp {
+                    add: smallvec![IeHirFact::Theorem(ie_theorem_path(path))],
+                })
+            }
+            VdMirHypothesisConstruction::Assume => return,
+            VdMirHypothesisConstruction::TermEquivalent { hypothesis } => {
+                facts.extend(self.hypothesis_ident(hypothesis));
+                IeHirProof::By(IeHirMethod::Simp { add: smallvec![] })
+            }
+            VdMirHypothesisConstruction::CommRing => IeHirProof::By(IeHirMethod::Algebra),
+            VdMirHypothesisConstruction::LetAssigned => IeHirProof::By(IeHirMethod::Simp {
+                add: self.let_assigned_defining_facts(hypothesis_entry),
+            }),
+            VdMirHypothesisConstruction::LitnumReduce => {
+                IeHirProof::By(IeHirMethod::Simp { add: smallvec![] })
+            }
+            // bounds follow from the bounds stated before, just as with `litnum_bound` in Lean,
+            // but unlike in Lean, linarith only sees the facts chained in
+            VdMirHypothesisConstruction::LitnumBound | VdMirHypothesisConstruction::Linarith => {
+                facts.extend(self.facts_in_scope().iter().copied());
+                IeHirProof::By(IeHirMethod::Linarith)
+            }
+            VdMirHypothesisConstruction::Kurapika => {
+                self.report(
+                    VdIsabelleTranspilationError::UnsupportedHypothesisConstruction("kurapika"),
+                );
+                IeHirProof::Sorry
+            }
+        };
+        let prop = hypothesis_entry.expr().to_isabelle(self);
+        let ident = self.mangle_hypothesis_for(hypothesis);
+        ie_stmts.push(IeHirStmtData::Have {
+            ident: Some(ident),
+            facts,
+            prop,
+            proof,
+        });
+    }
+
+    /// `define x where "x = e"` provides the fact `x_def`
+    fn let_assigned_defining_facts(
+        &self,
+        hypothesis_entry: &VdMirHypothesisEntry,
+    ) -> SmallVec<[IeHirFact; 2]> {
+        let VdMirExprData::ChainingSeparatedList { leader, .. } =
+            *self.expr_arena()[hypothesis_entry.expr()].data()
+        else {
+            return smallvec![];
+        };
+        match *self.expr_arena()[leader].data() {
+            VdMirExprData::Variable(local_defn) => {
+                let ident = self.mangle_symbol(local_defn);
+                smallvec![IeHirFact::Ident(IeIdent::from_owned(
+                    format!("{}_def", ident.data()),
+                    self.db(),
+                ))]
+            }
+            _ => smallvec![],
+        }
+    }
+}
+
+fn ie_theorem_path(path: VdTheoremPath) -> IeTheoremPath {
+    match path {
+        VdTheoremPath::SquareNonnegative => IeTheoremPath::ZeroLePower2,
+        VdTheoremPath::PowNonnegative => IeTheoremPath::ZeroLePower,
+        VdTheoremPath::PowPositive => IeTheoremPath::ZeroLessPower,
+        VdTheoremPath::SqrtNonnegative => IeTheoremPath::RealSqrtGeZero,
+        VdTheoremPath::SqrtSquare => IeTheoremPath::RealSqrtAbs,
+        VdTheoremPath::MulNonnegative => IeTheoremPath::MultNonnegNonneg,
+        VdTheoremPath::MulPositive => IeTheoremPath::MultPosPos,
+        VdTheoremPath::DivNonnegative => IeTheoremPath::DivideNonnegNonneg,
+        VdTheoremPath::DivPositive => IeTheoremPath::DividePosPos,
+    }
+}
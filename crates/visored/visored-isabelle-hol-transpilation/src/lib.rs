@@ -1,14 +1,28 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! transpiles visored MIR regions into Isar proof text for Isabelle/HOL,
+//! in parallel with `visored-lean-transpilation`
+mod builder;
+pub mod dictionary;
+pub mod error;
+mod expr;
+pub mod helpers;
+pub mod hypothesis;
+pub mod mangle;
+pub mod markup;
+pub mod scheme;
+pub mod stmt;
+#[cfg(test)]
+mod tests;
+pub mod ty;
 
+use self::builder::VdIsabelleTranspilationBuilder;
+use self::scheme::IsVdIsabelleTranspilationScheme;
 #[cfg(test)]
-mod tests {
-    use super::*;
+use self::tests::*;
+use visored_models::VdModels;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+pub trait VdTranspileToIsabelle<S, T>: Copy
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    fn to_isabelle(self, builder: &mut VdIsabelleTranspilationBuilder<S>) -> T;
 }
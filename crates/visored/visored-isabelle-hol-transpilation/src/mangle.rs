@@ -0,0 +1,100 @@
+use eterned::db::EternerDb;
+use isabelle_hir_expr::ident::IeIdent;
+use rustc_hash::FxHashMap;
+use visored_mir_expr::symbol::local_defn::{
+    storage::VdMirSymbolLocalDefnStorage, VdMirSymbolLocalDefnHead, VdMirSymbolLocalDefnIdx,
+    VdMirSymbolLocalDefnOrderedMap,
+};
+
+/// facts of a theory share a single namespace in isabelle,
+/// so unlike lean we disambiguate across the whole document
+pub struct VdIsabelleTranspilationMangler {
+    local_defn_mangled_symbols: VdMirSymbolLocalDefnOrderedMap<IeIdent>,
+    hypothesis_count: Option<usize>,
+}
+
+impl VdIsabelleTranspilationMangler {
+    pub(crate) fn new(storage: &VdMirSymbolLocalDefnStorage, db: &EternerDb) -> Self {
+        let mut local_defn_mangled_symbols: VdMirSymbolLocalDefnOrderedMap<IeIdent> =
+            Default::default();
+        let mut disambiguator_map: FxHashMap<String, usize> = FxHashMap::default();
+        for (idx, defn) in storage.defn_arena().indexed_iter() {
+            let naive_ident = naive_ident(defn.head());
+            let mangled_ident = mangle_naive_ident(naive_ident, &mut disambiguator_map, db);
+            local_defn_mangled_symbols.insert_next(idx, mangled_ident);
+        }
+        Self {
+            local_defn_mangled_symbols,
+            hypothesis_count: None,
+        }
+    }
+
+    pub(crate) fn mangle_symbol(&self, symbol_local_defn: VdMirSymbolLocalDefnIdx) -> IeIdent {
+        self.local_defn_mangled_symbols[symbol_local_defn]
+    }
+
+    pub(crate) fn mangle_hypothesis(&mut self, db: &EternerDb) -> IeIdent {
+        match self.hypothesis_count {
+            Some(ref mut count) => {
+                *count += 1;
+                IeIdent::from_owned(format!("h{}", count), db)
+            }
+            None => {
+                self.hypothesis_count = Some(0);
+                IeIdent::from_ref("h", db)
+            }
+        }
+    }
+}
+
+/// `\alpha` becomes `alpha`; names clashing with keywords get primed
+fn naive_ident(head: &VdMirSymbolLocalDefnHead) -> String {
+    let mut naive_ident: String = match *head {
+        VdMirSymbolLocalDefnHead::Letter(letter) => letter
+            .latex_code()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect(),
+    };
+    if IeIdent::is_reserved(&naive_ident) {
+        naive_ident.push('\'')
+    }
+    naive_ident
+}
+
+fn mangle_naive_ident(
+    naive_ident: String,
+    disambiguator_map: &mut FxHashMap<String, usize>,
+    db: &EternerDb,
+) -> IeIdent {
+    let mangled = match disambiguator_map.get_mut(&naive_ident) {
+        Some(count) => {
+            *count += 1;
+            format!("{}{}", naive_ident, count)
+        }
+        None => {
+            disambiguator_map.insert(naive_ident.clone(), 0);
+            naive_ident
+        }
+    };
+    IeIdent::from_owned(mangled, db)
+}
+
+#[test]
+fn test_mangle_naive_ident() {
+    use expect_test::expect;
+    let db = &EternerDb::default();
+    let mut disambiguator_map = FxHashMap::default();
+
+    let result1 = mangle_naive_ident("x".to_string(), &mut disambiguator_map, db);
+    expect!["x"].assert_eq(&result1.data());
+
+    let result2 = mangle_naive_ident("x".to_string(), &mut disambiguator_map, db);
+    expect!["x1"].assert_eq(&result2.data());
+
+    let result3 = mangle_naive_ident("y".to_string(), &mut disambiguator_map, db);
+    expect!["y"].assert_eq(&result3.data());
+
+    let result4 = mangle_naive_ident("o'".to_string(), &mut disambiguator_map, db);
+    expect!["o'"].assert_eq(&result4.data());
+}
@@ -0,0 +1,46 @@
+use isabelle_hir_expr::item_defn::{IeHirDivisionMarkup, IeMarkupCommand};
+use visored_entity_path::module::{VdModulePath, VdModulePathData};
+use visored_prelude::division::VdDivisionLevel;
+
+/// isabelle has no namespaces for facts, so divisions are only kept as document markup
+pub fn vd_module_path_to_ie_division_markup(
+    module_path: VdModulePath,
+) -> Option<IeHirDivisionMarkup> {
+    match module_path.data() {
+        VdModulePathData::Division {
+            division_level,
+            disambiguator,
+            ..
+        } => {
+            let command = match division_level {
+                VdDivisionLevel::Part | VdDivisionLevel::Chapter => IeMarkupCommand::Chapter,
+                VdDivisionLevel::Section => IeMarkupCommand::Section,
+                VdDivisionLevel::Subsection => IeMarkupCommand::Subsection,
+                VdDivisionLevel::Subsubsection => IeMarkupCommand::Subsubsection,
+                VdDivisionLevel::Blocks => return None,
+            };
+            Some(IeHirDivisionMarkup {
+                command,
+                title: format!(
+                    "{}{}",
+                    division_level.uppercase_code_name(),
+                    disambiguator + 1
+                ),
+            })
+        }
+        VdModulePathData::Root(_)
+        | VdModulePathData::Paragraph { .. }
+        | VdModulePathData::Environment { .. } => None,
+    }
+}
+
+pub fn vd_module_path_to_ie_environment_name(module_path: VdModulePath) -> String {
+    match module_path.data() {
+        VdModulePathData::Environment {
+            environment_path,
+            disambiguator,
+            ..
+        } => format!("{}{}", environment_path.pascal_ident(), disambiguator + 1),
+        _ => unreachable!(),
+    }
+}
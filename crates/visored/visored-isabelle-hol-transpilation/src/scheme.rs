@@ -0,0 +1,15 @@
+pub mod dense;
+pub mod sparse;
+
+use crate::builder::VdIsabelleTranspilationBuilder;
+use isabelle_hir_expr::item_defn::IeHirItemDefnIdxRange;
+use visored_mir_expr::stmt::VdMirStmtIdxRange;
+
+pub trait IsVdIsabelleTranspilationScheme: Sized {
+    type Cache: Default;
+
+    fn transpile_vd_stmts_to_ie_defns(
+        builder: &mut VdIsabelleTranspilationBuilder<Self>,
+        stmts: VdMirStmtIdxRange,
+    ) -> IeHirItemDefnIdxRange;
+}
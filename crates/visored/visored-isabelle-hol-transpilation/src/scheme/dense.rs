@@ -0,0 +1,17 @@
+use super::*;
+
+pub struct VdIsabelleTranspilationDenseScheme;
+
+#[derive(Default)]
+pub struct VdIsabelleTranspilationDenseCache {}
+
+impl IsVdIsabelleTranspilationScheme for VdIsabelleTranspilationDenseScheme {
+    type Cache = VdIsabelleTranspilationDenseCache;
+
+    fn transpile_vd_stmts_to_ie_defns(
+        builder: &mut VdIsabelleTranspilationBuilder<Self>,
+        stmts: VdMirStmtIdxRange,
+    ) -> IeHirItemDefnIdxRange {
+        builder.transpile_vd_stmts_to_ie_defns(stmts)
+    }
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+pub struct VdIsabelleTranspilationSparseScheme;
+
+impl IsVdIsabelleTranspilationScheme for VdIsabelleTranspilationSparseScheme {
+    type Cache = ();
+
+    fn transpile_vd_stmts_to_ie_defns(
+        builder: &mut VdIsabelleTranspilationBuilder<Self>,
+        stmts: VdMirStmtIdxRange,
+    ) -> IeHirItemDefnIdxRange {
+        builder.transpile_vd_stmts_to_ie_defns(stmts)
+    }
+}
@@ -0,0 +1,85 @@
+mod calculation;
+mod dense;
+mod sparse;
+
+use crate::*;
+use isabelle_hir_expr::{
+    item_defn::{
+        IeHirAssume, IeHirFix, IeHirItemDefnComment, IeHirItemDefnData, IeHirItemDefnGroupMeta,
+        IeHirItemDefnIdxRange,
+    },
+    proof::{IeHirMethod, IeHirProof},
+    stmt::IeHirStmtData,
+};
+use markup::{vd_module_path_to_ie_division_markup, vd_module_path_to_ie_environment_name};
+use smallvec::smallvec;
+use visored_mir_expr::{
+    expr::{VdMirExprData, VdMirExprIdx},
+    pattern::VdMirPattern,
+    stmt::{block::VdMirBlockMeta, VdMirStmtData, VdMirStmtIdx, VdMirStmtIdxRange},
+};
+
+impl<Scheme> VdTranspileToIsabelle<Scheme, IeHirItemDefnIdxRange> for VdMirStmtIdxRange
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    fn to_isabelle(
+        self,
+        builder: &mut VdIsabelleTranspilationBuilder<Scheme>,
+    ) -> IeHirItemDefnIdxRange {
+        Scheme::transpile_vd_stmts_to_ie_defns(builder, self)
+    }
+}
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    fn build_ie_fix(&mut self, pattern: &VdMirPattern, ty: VdMirExprIdx) -> IeHirFix {
+        IeHirFix {
+            ident: match *pattern {
+                VdMirPattern::Letter {
+                    symbol_local_defn, ..
+                } => self.mangle_symbol(symbol_local_defn),
+            },
+            ty: ty.to_isabelle(self),
+        }
+    }
+
+    /// the assumption is recorded so that later stmts can chain it in
+    fn build_ie_assume(&mut self, stmt: VdMirStmtIdx, prop: VdMirExprIdx) -> IeHirAssume {
+        let stmt_arena = self.stmt_arena();
+        let VdMirStmtData::Assume {
+            ref hypothesis_chunk_place,
+            ..
+        } = *stmt_arena[stmt].data()
+        else {
+            unreachable!()
+        };
+        let ident = match hypothesis_chunk_place.get() {
+            Some(Ok(hypothesis_chunk)) => {
+                self.mangle_hypothesis_for(hypothesis_chunk.main_hypothesis())
+            }
+            _ => self.mangle_hypothesis(),
+        };
+        IeHirAssume {
+            ident,
+            prop: prop.to_isabelle(self),
+        }
+    }
+
+    fn build_ie_define_stmt(
+        &mut self,
+        pattern: &VdMirPattern,
+        assignment: VdMirExprIdx,
+    ) -> IeHirStmtData {
+        IeHirStmtData::Define {
+            ident: match *pattern {
+                VdMirPattern::Letter {
+                    symbol_local_defn, ..
+                } => self.mangle_symbol(symbol_local_defn),
+            },
+            assignment: assignment.to_isabelle(self),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::*;
+use isabelle_hir_expr::{
+    expr::{IeHirExprData, IeHirExprEntry, IeHirFunc},
+    opr::IeBinaryOpr,
+};
+use visored_mir_expr::{expr::application::VdMirFunc, hypothesis::VdMirHypothesisIdx};
+use visored_signature::signature::separator::base::VdBaseSeparatorSignature;
+
+impl<'a, S> VdIsabelleTranspilationBuilder<'a, S>
+where
+    S: IsVdIsabelleTranspilationScheme,
+{
+    /// `also` and `finally` take care of the transitivity,
+    /// so unlike lean's `calc` no reordering of equivalences is needed
+    ///
+    /// `None` if some relation isn't supported, which is reported
+    pub(crate) fn build_calculation_stmt(
+        &mut self,
+        leader: VdMirExprIdx,
+        followers: &[(VdMirFunc, VdMirExprIdx)],
+        joined_signature: VdBaseSeparatorSignature,
+        main_hypothesis: VdMirHypothesisIdx,
+    ) -> Option<IeHirStmtData> {
+        debug_assert!(followers.len() >= 2);
+        let oprs = followers
+            .iter()
+            .map(|&(func, _)| func.to_isabelle(self))
+            .collect::<Option<Vec<IeBinaryOpr>>>()?;
+        let conclusion_opr: Option<IeBinaryOpr> =
+            VdMirFunc::NormalBaseSeparator(joined_signature).to_isabelle(self);
+        let conclusion_opr = conclusion_opr?;
+        let ie_leader = leader.to_isabelle(self);
+        let steps = std::iter::zip(oprs, followers)
+            .map(|(opr, &(_, follower))| (opr, follower.to_isabelle(self)))
+            .collect();
+        let conclusion_arguments = [leader, followers.last().unwrap().1].to_isabelle(self);
+        let conclusion = self.alloc_expr(IeHirExprEntry::new(
+            IeHirExprData::Application {
+                function: IeHirFunc::BinaryOpr(conclusion_opr),
+                arguments: conclusion_arguments,
+            },
+            None,
+        ));
+        Some(IeHirStmtData::Calculation {
+            leader: ie_leader,
+            steps,
+            ident: self.mangle_hypothesis_for(main_hypothesis),
+            conclusion,
+        })
+    }
+}
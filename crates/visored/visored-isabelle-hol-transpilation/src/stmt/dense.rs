@@ -0,0 +1,5 @@
+mod defn;
+mod proof;
+
+use super::*;
+use crate::scheme::dense::VdIsabelleTranspilationDenseScheme as Dense;
@@ -0,0 +1,129 @@
+use super::*;
+use std::ops::ControlFlow;
+
+impl<'a> VdIsabelleTranspilationBuilder<'a, Dense> {
+    pub(crate) fn transpile_vd_stmts_to_ie_defns(
+        &mut self,
+        stmts: VdMirStmtIdxRange,
+    ) -> IeHirItemDefnIdxRange {
+        if stmts.is_empty() {
+            return self.alloc_item_defns(vec![], vec![]);
+        }
+        match *self.stmt_arena()[stmts.first().unwrap()].data() {
+            VdMirStmtData::Block { .. } => self.build_ie_item_defns_from_vd_blocks(stmts),
+            _ => {
+                let defn = self.build_ie_lemma_from_vd_stmts(stmts);
+                self.alloc_item_defns([defn], [IeHirItemDefnComment::Void])
+            }
+        }
+    }
+}
+
+impl<'a> VdIsabelleTranspilationBuilder<'a, Dense> {
+    fn build_ie_item_defns_from_vd_blocks(
+        &mut self,
+        stmts: VdMirStmtIdxRange,
+    ) -> IeHirItemDefnIdxRange {
+        let item_defns: Vec<_> = stmts
+            .into_iter()
+            .map(|stmt| self.build_ie_item_defn_from_vd_block(stmt))
+            .collect();
+        let comments: Vec<_> = stmts
+            .into_iter()
+            .map(|stmt| self.item_defn_comment(stmt))
+            .collect();
+        self.alloc_item_defns(item_defns, comments)
+    }
+
+    fn build_ie_item_defn_from_vd_block(&mut self, stmt: VdMirStmtIdx) -> IeHirItemDefnData {
+        match *self.stmt_arena()[stmt].data() {
+            VdMirStmtData::Block { stmts, ref meta } => match *meta {
+                VdMirBlockMeta::Environment(_, _, module_path) => {
+                    let defn = self.with_module_path(module_path, |builder| {
+                        builder.build_ie_lemma_from_vd_stmts(stmts)
+                    });
+                    let defn = self.alloc_item_defn(defn, IeHirItemDefnComment::Void);
+                    IeHirItemDefnData::Group {
+                        defns: IeHirItemDefnIdxRange::new_single(defn),
+                        meta: IeHirItemDefnGroupMeta::Environment(
+                            vd_module_path_to_ie_environment_name(module_path),
+                        ),
+                    }
+                }
+                VdMirBlockMeta::Division(_, module_path) => {
+                    let defns =
+                        self.with_module_path(module_path, |builder| stmts.to_isabelle(builder));
+                    IeHirItemDefnData::Group {
+                        defns,
+                        meta: IeHirItemDefnGroupMeta::Division(
+                            vd_module_path_to_ie_division_markup(module_path),
+                        ),
+                    }
+                }
+            },
+            VdMirStmtData::LetPlaceholder { .. }
+            | VdMirStmtData::Assume { .. }
+            | VdMirStmtData::LetAssigned { .. }
+            | VdMirStmtData::Have { .. }
+            | VdMirStmtData::Show { .. }
+            | VdMirStmtData::Goal { .. }
+            | VdMirStmtData::Qed { .. } => unreachable!(),
+        }
+    }
+
+    /// the leading placeholders and assumptions become `fixes` and `assumes`,
+    /// and the goal if any becomes `shows`
+    fn build_ie_lemma_from_vd_stmts(&mut self, stmts: VdMirStmtIdxRange) -> IeHirItemDefnData {
+        let ident = self.mangle_hypothesis();
+        // the assumptions are only visible inside the lemma
+        self.with_fact_scope(|builder| {
+            let mut fixes: Vec<IeHirFix> = vec![];
+            let mut assumes: Vec<IeHirAssume> = vec![];
+            let mut goal = None;
+            for stmt in stmts {
+                match builder.build_ie_lemma_head_from_vd_stmt(stmt, &mut fixes, &mut assumes) {
+                    ControlFlow::Continue(()) => (),
+                    ControlFlow::Break(goal1) => {
+                        goal = goal1;
+                        break;
+                    }
+                }
+            }
+            let shows = goal.map(|goal| goal.to_isabelle(builder));
+            let proof = builder.build_ie_proof_from_vd_stmts(stmts);
+            IeHirItemDefnData::Lemma {
+                ident,
+                fixes,
+                assumes,
+                shows,
+                proof,
+            }
+        })
+    }
+
+    fn build_ie_lemma_head_from_vd_stmt(
+        &mut self,
+        stmt: VdMirStmtIdx,
+        fixes: &mut Vec<IeHirFix>,
+        assumes: &mut Vec<IeHirAssume>,
+    ) -> ControlFlow<Option<VdMirExprIdx>> {
+        match *self.stmt_arena()[stmt].data() {
+            VdMirStmtData::LetPlaceholder { ref pattern, ty } => {
+                fixes.push(self.build_ie_fix(pattern, ty));
+                ControlFlow::Continue(())
+            }
+            VdMirStmtData::Assume { prop, .. } => {
+                assumes.push(self.build_ie_assume(stmt, prop));
+                ControlFlow::Continue(())
+            }
+            VdMirStmtData::Block { stmts, .. } => {
+                for stmt in stmts {
+                    self.build_ie_lemma_head_from_vd_stmt(stmt, fixes, assumes)?
+                }
+                ControlFlow::Continue(())
+            }
+            VdMirStmtData::Goal { prop } => ControlFlow::Break(Some(prop)),
+            _ => ControlFlow::Break(None),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use super::*;
+use visored_mir_expr::hypothesis::chunk::VdMirHypothesisChunk;
+
+impl<'a> VdIsabelleTranspilationBuilder<'a, Dense> {
+    pub(super) fn build_ie_proof_from_vd_stmts(&mut self, stmts: VdMirStmtIdxRange) -> IeHirProof {
+        let mut ie_stmts = vec![];
+        self.with_fact_scope(|builder| builder.build_ie_stmts_from_vd_stmts(stmts, &mut ie_stmts));
+        if ie_stmts.is_empty() {
+            // `proof - qed` doesn't close even a trivial goal
+            return IeHirProof::By(IeHirMethod::Simp { add: smallvec![] });
+        }
+        IeHirProof::Block {
+            stmts: self.alloc_stmts(ie_stmts),
+        }
+    }
+
+    fn build_ie_stmts_from_vd_stmts(
+        &mut self,
+        stmts: VdMirStmtIdxRange,
+        ie_stmts: &mut Vec<IeHirStmtData>,
+    ) {
+        let Some((stmt, following_stmts)) = stmts.first_and_others() else {
+            return;
+        };
+        match *self.stmt_arena()[stmt].data() {
+            VdMirStmtData::Block { stmts, .. } => {
+                self.build_ie_stmts_from_vd_stmts(stmts, ie_stmts);
+                self.build_ie_stmts_from_vd_stmts(following_stmts, ie_stmts);
+            }
+            VdMirStmtData::LetPlaceholder { .. }
+            | VdMirStmtData::Assume { .. }
+            | VdMirStmtData::Goal { .. } => {
+                // already stated in the head of the lemma
+                self.build_ie_stmts_from_vd_stmts(following_stmts, ie_stmts);
+            }
+            VdMirStmtData::LetAssigned {
+                ref pattern,
+                assignment,
+                hypothesis_chunk_place,
+            } => {
+                ie_stmts.push(self.build_ie_define_stmt(pattern, assignment));
+                self.build_hypothesis_chunk_stmts(hypothesis_chunk_place.unwrap(), ie_stmts);
+                self.build_ie_stmts_from_vd_stmts(following_stmts, ie_stmts);
+            }
+            VdMirStmtData::Have {
+                prop,
+                hypothesis_chunk_place,
+                ..
+            } => {
+                self.build_ie_stmts_from_vd_have(prop, hypothesis_chunk_place.unwrap(), ie_stmts);
+                self.build_ie_stmts_from_vd_stmts(following_stmts, ie_stmts);
+            }
+            VdMirStmtData::Show {
+                prop,
+                goal_and_hypothesis_chunk_place,
+                ..
+            } => {
+                // the following stmts prove the shown prop, so they go into its proof
+                let proof = self.build_ie_proof_from_vd_stmts(following_stmts);
+                let prop = match *self.expr_arena()[prop].data() {
+                    VdMirExprData::ChainingSeparatedList {
+                        joined_signature: Some(_),
+                        ..
+                    } => todo!(),
+                    _ => prop.to_isabelle(self),
+                };
+                ie_stmts.push(IeHirStmtData::Have {
+                    ident: Some(self.mangle_hypothesis()),
+                    facts: smallvec![],
+                    prop,
+                    proof,
+                });
+                if let Some((_, hypothesis_chunk_place)) = goal_and_hypothesis_chunk_place {
+                    self.build_hypothesis_chunk_stmts(hypothesis_chunk_place.unwrap(), ie_stmts);
+                }
+            }
+            VdMirStmtData::Qed {
+                goal_and_hypothesis_chunk_place,
+            } => ie_stmts.push(
+                self.build_ie_qed_stmt(
+                    goal_and_hypothesis_chunk_place
+                        .map(|(_, hypothesis_chunk_place)| hypothesis_chunk_place.unwrap()),
+                ),
+            ),
+        }
+    }
+
+    fn build_ie_stmts_from_vd_have(
+        &mut self,
+        prop: VdMirExprIdx,
+        hypothesis_chunk: VdMirHypothesisChunk,
+        ie_stmts: &mut Vec<IeHirStmtData>,
+    ) {
+        self.build_hypothesis_chunk_stmts(hypothesis_chunk, ie_stmts);
+        if let VdMirExprData::ChainingSeparatedList {
+            leader,
+            ref followers,
+            joined_signature: Some(joined_signature),
+        } = *self.expr_arena()[prop].data()
+        {
+            ie_stmts.extend(self.build_calculation_stmt(
+                leader,
+                followers,
+                joined_signature,
+                hypothesis_chunk.main_hypothesis(),
+            ))
+        }
+    }
+
+    fn build_ie_qed_stmt(
+        &mut self,
+        hypothesis_chunk: Option<VdMirHypothesisChunk>,
+    ) -> IeHirStmtData {
+        let (facts, proof) = match hypothesis_chunk {
+            Some(hypothesis_chunk) => {
+                match self.hypothesis_ident(hypothesis_chunk.main_hypothesis()) {
+                    Some(ident) => (smallvec![ident], IeHirProof::This),
+                    None => (smallvec![], self.default_proof()),
+                }
+            }
+            // the goal is `True` then
+            None => (
+                smallvec![],
+                IeHirProof::By(IeHirMethod::Simp { add: smallvec![] }),
+            ),
+        };
+        IeHirStmtData::Show {
+            facts,
+            prop: None,
+            proof,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+mod have;
+
+use super::*;
+use crate::{
+    error::VdIsabelleTranspilationError,
+    scheme::sparse::VdIsabelleTranspilationSparseScheme as Sparse,
+};
+
+impl<'a> VdIsabelleTranspilationBuilder<'a, Sparse> {
+    pub(crate) fn transpile_vd_stmts_to_ie_defns(
+        &mut self,
+        stmts: VdMirStmtIdxRange,
+    ) -> IeHirItemDefnIdxRange {
+        let mut item_defns = vec![];
+        let mut comments = vec![];
+        let mut stmts = stmts;
+        while let Some((stmt, following_stmts)) = stmts.first_and_others() {
+            match *self.stmt_arena()[stmt].data() {
+                VdMirStmtData::LetPlaceholder { .. } | VdMirStmtData::Assume { .. } => {
+                    // the context extends to the end of the stmts
+                    let (item_defn, comment) = self.build_ie_context_from_vd_stmts(stmts);
+                    item_defns.push(item_defn);
+                    comments.push(comment);
+                    break;
+                }
+                _ => {
+                    if let Some(item_defn) = self.build_ie_item_defn_from_vd_stmt(stmt) {
+                        item_defns.push(item_defn);
+                        comments.push(self.item_defn_comment(stmt));
+                    }
+                }
+            }
+            stmts = following_stmts;
+        }
+        self.alloc_item_defns(item_defns, comments)
+    }
+}
+
+impl<'a> VdIsabelleTranspilationBuilder<'a, Sparse> {
+    /// `None` for stmts that are left out, like unsupported ones
+    fn build_ie_item_defn_from_vd_stmt(&mut self, stmt: VdMirStmtIdx) -> Option<IeHirItemDefnData> {
+        match *self.stmt_arena()[stmt].data() {
+            VdMirStmtData::Block { stmts, ref meta } => {
+                let defns = match *meta {
+                    VdMirBlockMeta::Environment(_, _, module_path)
+                    | VdMirBlockMeta::Division(_, module_path) => {
+                        self.with_module_path(module_path, |builder| stmts.to_isabelle(builder))
+                    }
+                };
+                let meta = match *meta {
+                    VdMirBlockMeta::Division(_, module_path) => IeHirItemDefnGroupMeta::Division(
+                        vd_module_path_to_ie_division_markup(module_path),
+                    ),
+                    VdMirBlockMeta::Environment(_, _, module_path) => {
+                        IeHirItemDefnGroupMeta::Environment(vd_module_path_to_ie_environment_name(
+                            module_path,
+                        ))
+                    }
+                };
+                Some(IeHirItemDefnData::Group { defns, meta })
+            }
+            VdMirStmtData::LetPlaceholder { .. } | VdMirStmtData::Assume { .. } => {
+                unreachable!("handled by contexts")
+            }
+            VdMirStmtData::LetAssigned { .. } => {
+                self.report(VdIsabelleTranspilationError::UnsupportedStmt(
+                    "let assignments outside of proofs",
+                ));
+                None
+            }
+            VdMirStmtData::Goal { .. } => {
+                self.report(VdIsabelleTranspilationError::UnsupportedStmt(
+                    "goals outside of proofs",
+                ));
+                None
+            }
+            VdMirStmtData::Have {
+                prop,
+                hypothesis_chunk_place,
+                ..
+            } => Some(self.build_have_lemma(prop, hypothesis_chunk_place.unwrap())),
+            VdMirStmtData::Show { .. } => {
+                self.report(VdIsabelleTranspilationError::UnsupportedStmt(
+                    "shows outside of proofs",
+                ));
+                None
+            }
+            VdMirStmtData::Qed {
+                goal_and_hypothesis_chunk_place,
+            } => {
+                goal_and_hypothesis_chunk_place?;
+                todo!()
+            }
+        }
+    }
+
+    /// placeholders and assumptions are fixed by an unnamed context,
+    /// so that the lemmas after them can refer to them
+    fn build_ie_context_from_vd_stmts(
+        &mut self,
+        stmts: VdMirStmtIdxRange,
+    ) -> (IeHirItemDefnData, IeHirItemDefnComment) {
+        self.with_fact_scope(|builder| builder.build_ie_context_from_vd_stmts_aux(stmts))
+    }
+
+    fn build_ie_context_from_vd_stmts_aux(
+        &mut self,
+        stmts: VdMirStmtIdxRange,
+    ) -> (IeHirItemDefnData, IeHirItemDefnComment) {
+        let mut fixes = vec![];
+        let mut assumes = vec![];
+        let mut lines = vec![];
+        let mut stmts = stmts;
+        while let Some((stmt, following_stmts)) = stmts.first_and_others() {
+            match *self.stmt_arena()[stmt].data() {
+                VdMirStmtData::LetPlaceholder { ref pattern, ty } => {
+                    fixes.push(self.build_ie_fix(pattern, ty))
+                }
+                VdMirStmtData::Assume { prop, .. } => {
+                    assumes.push(self.build_ie_assume(stmt, prop))
+                }
+                _ => break,
+            }
+            if let IeHirItemDefnComment::Lines(stmt_lines) = self.item_defn_comment(stmt) {
+                lines.extend(stmt_lines)
+            }
+            stmts = following_stmts;
+        }
+        let defns = stmts.to_isabelle(self);
+        let comment = match lines.is_empty() {
+            true => IeHirItemDefnComment::Void,
+            false => IeHirItemDefnComment::Lines(lines),
+        };
+        (
+            IeHirItemDefnData::Context {
+                fixes,
+                assumes,
+                defns,
+            },
+            comment,
+        )
+    }
+}
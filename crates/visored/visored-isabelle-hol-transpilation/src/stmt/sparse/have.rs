@@ -0,0 +1,77 @@
+use super::*;
+use isabelle_hir_expr::expr::{IeHirExprData, IeHirExprEntry, IeHirExprIdx};
+use visored_mir_expr::hypothesis::chunk::VdMirHypothesisChunk;
+
+impl<'a> VdIsabelleTranspilationBuilder<'a, Sparse> {
+    pub(super) fn build_have_lemma(
+        &mut self,
+        prop: VdMirExprIdx,
+        hypothesis_chunk: VdMirHypothesisChunk,
+    ) -> IeHirItemDefnData {
+        let ident = self.mangle_hypothesis();
+        // the stmts of the proof are only visible inside it
+        let (shows, proof) = self.with_fact_scope(|builder| {
+            builder.build_have_lemma_shows_and_proof(prop, hypothesis_chunk)
+        });
+        self.record_hypothesis_ident(hypothesis_chunk.main_hypothesis(), ident);
+        IeHirItemDefnData::Lemma {
+            ident,
+            fixes: vec![],
+            assumes: vec![],
+            shows: Some(shows),
+            proof,
+        }
+    }
+
+    fn build_have_lemma_shows_and_proof(
+        &mut self,
+        prop: VdMirExprIdx,
+        hypothesis_chunk: VdMirHypothesisChunk,
+    ) -> (IeHirExprIdx, IeHirProof) {
+        let mut ie_stmts = vec![];
+        self.build_hypothesis_chunk_stmts(hypothesis_chunk, &mut ie_stmts);
+        let shows = match *self.expr_arena()[prop].data() {
+            VdMirExprData::ChainingSeparatedList {
+                leader,
+                ref followers,
+                joined_signature: Some(joined_signature),
+            } => {
+                match self.build_calculation_stmt(
+                    leader,
+                    followers,
+                    joined_signature,
+                    hypothesis_chunk.main_hypothesis(),
+                ) {
+                    Some(stmt) => {
+                        let IeHirStmtData::Calculation { conclusion, .. } = stmt else {
+                            unreachable!()
+                        };
+                        ie_stmts.push(stmt);
+                        conclusion
+                    }
+                    None => self.alloc_expr(IeHirExprEntry::new(IeHirExprData::Undefined, None)),
+                }
+            }
+            _ => prop.to_isabelle(self),
+        };
+        let proof = match ie_stmts.is_empty() {
+            true => self.default_proof(),
+            false => {
+                let main_hypothesis = hypothesis_chunk.main_hypothesis();
+                let (facts, proof) = match self.hypothesis_ident(main_hypothesis) {
+                    Some(ident) => (smallvec![ident], IeHirProof::This),
+                    None => (smallvec![], self.default_proof()),
+                };
+                ie_stmts.push(IeHirStmtData::Show {
+                    facts,
+                    prop: None,
+                    proof,
+                });
+                IeHirProof::Block {
+                    stmts: self.alloc_stmts(ie_stmts),
+                }
+            }
+        };
+        (shows, proof)
+    }
+}
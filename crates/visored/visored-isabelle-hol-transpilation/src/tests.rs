@@ -0,0 +1,7 @@
+mod body;
+mod document;
+mod expr;
+mod page;
+
+use crate::*;
+pub(crate) use expect_test::*;
@@ -0,0 +1,4 @@
+mod dense;
+mod sparse;
+
+use super::*;
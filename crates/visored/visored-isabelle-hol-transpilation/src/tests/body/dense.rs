@@ -0,0 +1,170 @@
+use super::*;
+use crate::{
+    helpers::tracker::VdIsabelleTranspilationTracker,
+    scheme::dense::VdIsabelleTranspilationDenseScheme,
+};
+use eterned::db::EternerDb;
+use latex_prelude::helper::tracker::LxDocumentBodyInput;
+use latex_vfs::path::LxFilePath;
+use std::path::PathBuf;
+use visored_mir_expr::elaborator::VdMirTrivialElaborator;
+use visored_models::VdModels;
+use visored_syn_expr::vibe::VdSynExprVibe;
+
+fn t(models: &VdModels, content: &str, expected_display_tree: &Expect, expected_fmt: &Expect) {
+    use husky_path_utils::HuskyLangDevPaths;
+
+    let db = &EternerDb::default();
+    let dev_paths = HuskyLangDevPaths::new();
+    let file_path = LxFilePath::new(PathBuf::from(file!()), db);
+    let tracker = VdIsabelleTranspilationTracker::new(
+        LxDocumentBodyInput {
+            specs_dir: dev_paths.specs_dir(),
+            file_path,
+            content,
+        },
+        &[],
+        &[],
+        models,
+        VdSynExprVibe::ROOT_CNL,
+        db,
+        &VdIsabelleTranspilationDenseScheme,
+        |_| VdMirTrivialElaborator::default(),
+    );
+    expected_display_tree.assert_eq(&tracker.show_display_tree(db));
+    expected_fmt.assert_eq(&tracker.show_fmt(db));
+}
+
+#[test]
+fn basic_body_to_isabelle_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "Let $x\\in\\mathbb{N}$.",
+        &expect![[r#"
+            └─ group: `division`
+              └─ lemma: `h`
+                ├─ item path: `nat`
+                └─ stmts
+                  └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+        "#]],
+        &expect![[r#"
+            lemma h:
+              fixes x :: nat
+              shows True
+            proof -
+              show ?thesis by simp
+            qed"#]],
+    );
+    t(
+        models,
+        r#"\begin{example}\end{example}"#,
+        &expect![[r#"
+            └─ group: `division`
+              └─ group: `environment`
+                └─ lemma: `h`
+                  └─ proof: `By(Simp { add: [] })`
+        "#]],
+        &expect![[r#"
+            (* Example1 *)
+            context begin
+            lemma h:
+              shows True
+            by simp
+            end
+        "#]],
+    );
+    t(
+        models,
+        r#"\begin{example}Let $x\in\mathbb{R}$.\end{example}"#,
+        &expect![[r#"
+            └─ group: `division`
+              └─ group: `environment`
+                └─ lemma: `h`
+                  ├─ item path: `real`
+                  └─ stmts
+                    └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+        "#]],
+        &expect![[r#"
+            (* Example1 *)
+            context begin
+            lemma h:
+              fixes x :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed
+            end
+        "#]],
+    );
+    t(
+        models,
+        r#"\section{Introduction}Let $x\in\mathbb{R}$."#,
+        &expect![[r#"
+            └─ group: `division`
+              └─ group: `division`
+                └─ lemma: `h`
+                  ├─ item path: `real`
+                  └─ stmts
+                    └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+        "#]],
+        &expect![[r#"
+            section \<open>Section1\<close>
+
+            lemma h:
+              fixes x :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed"#]],
+    );
+    t(
+        models,
+        r#"\section{Introduction}Let $x\in\mathbb{R}$.\subsection{Hello}Let $y\in\mathbb{R}$.\subsection{World}\subsection{This}\subsubsection{Is}\subsubsection{Bad}"#,
+        &expect![[r#"
+            └─ group: `division`
+              ├─ group: `division`
+              │ └─ lemma: `h`
+              │   ├─ item path: `real`
+              │   └─ stmts
+              │     └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+              ├─ group: `division`
+              │ └─ group: `division`
+              │   └─ lemma: `h1`
+              │     ├─ item path: `real`
+              │     └─ stmts
+              │       └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+              ├─ group: `division`
+              └─ group: `division`
+                ├─ group: `division`
+                └─ group: `division`
+        "#]],
+        &expect![[r#"
+            section \<open>Section1\<close>
+
+            lemma h:
+              fixes x :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed
+
+            subsection \<open>Subsection1\<close>
+
+            lemma h1:
+              fixes y :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed
+
+            subsection \<open>Subsection2\<close>
+
+            subsection \<open>Subsection3\<close>
+
+            subsubsection \<open>Subsubsection1\<close>
+
+            subsubsection \<open>Subsubsection2\<close>
+        "#]],
+    );
+}
@@ -0,0 +1,9 @@
+mod dense;
+mod sparse;
+
+use super::*;
+use crate::helpers::tracker::VdIsabelleTranspilationTracker;
+use eterned::db::EternerDb;
+use latex_prelude::helper::tracker::LxDocumentInput;
+use latex_vfs::path::LxFilePath;
+use std::path::PathBuf;
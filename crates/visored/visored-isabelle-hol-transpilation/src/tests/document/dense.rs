@@ -0,0 +1,193 @@
+use visored_mir_expr::elaborator::VdMirTrivialElaborator;
+use visored_syn_expr::vibe::VdSynExprVibe;
+
+use super::*;
+use crate::scheme::dense::VdIsabelleTranspilationDenseScheme;
+
+fn t(models: &VdModels, content: &str, expected_display_tree: &Expect, expected_fmt: &Expect) {
+    use husky_path_utils::HuskyLangDevPaths;
+
+    let db = &EternerDb::default();
+    let dev_paths = HuskyLangDevPaths::new();
+    let file_path = LxFilePath::new(PathBuf::from(file!()), db);
+    let tracker = VdIsabelleTranspilationTracker::new(
+        LxDocumentInput {
+            specs_dir: dev_paths.specs_dir().to_path_buf(),
+            file_path,
+            content,
+        },
+        &[],
+        &[],
+        models,
+        VdSynExprVibe::ROOT_CNL,
+        db,
+        &VdIsabelleTranspilationDenseScheme,
+        |_| VdMirTrivialElaborator::default(),
+    );
+    expected_display_tree.assert_eq(&tracker.show_display_tree(db));
+    expected_fmt.assert_eq(&tracker.show_fmt(db));
+}
+
+#[test]
+fn basic_document_to_vd_mir_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        r#"\documentclass{article}
+\usepackage{amsmath}
+\begin{document}
+Let $x\in\mathbb{R}$.
+\end{document}"#,
+        &expect![[r#"
+            └─ group: `division`
+              └─ lemma: `h`
+                ├─ item path: `real`
+                └─ stmts
+                  └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+        "#]],
+        &expect![[r#"
+            lemma h:
+              fixes x :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed"#]],
+    );
+    t(
+        models,
+        r#"\documentclass{article}
+\usepackage{amsmath}
+\begin{document}
+\section{Introduction}
+Let $x\in\mathbb{R}$.
+\end{document}"#,
+        &expect![[r#"
+            └─ group: `division`
+              └─ group: `division`
+                └─ lemma: `h`
+                  ├─ item path: `real`
+                  └─ stmts
+                    └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+        "#]],
+        &expect![[r#"
+            section \<open>Section1\<close>
+
+            lemma h:
+              fixes x :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed"#]],
+    );
+    t(
+        models,
+        r#"\documentclass{article}
+\usepackage{amsmath}
+\begin{document}
+\section{Introduction}
+Let $x\in\mathbb{R}$.
+\subsection{Hello}
+Let $y\in\mathbb{R}$.
+\subsection{World}
+\subsection{This}
+\subsubsection{Is}
+\subsubsection{Bad}
+\end{document}"#,
+        &expect![[r#"
+            └─ group: `division`
+              ├─ group: `division`
+              │ └─ lemma: `h`
+              │   ├─ item path: `real`
+              │   └─ stmts
+              │     └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+              ├─ group: `division`
+              │ └─ group: `division`
+              │   └─ lemma: `h1`
+              │     ├─ item path: `real`
+              │     └─ stmts
+              │       └─ stmt: `Show { facts: [], prop: None, proof: By(Simp { add: [] }) }`
+              ├─ group: `division`
+              └─ group: `division`
+                ├─ group: `division`
+                └─ group: `division`
+        "#]],
+        &expect![[r#"
+            section \<open>Section1\<close>
+
+            lemma h:
+              fixes x :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed
+
+            subsection \<open>Subsection1\<close>
+
+            lemma h1:
+              fixes y :: real
+              shows True
+            proof -
+              show ?thesis by simp
+            qed
+
+            subsection \<open>Subsection2\<close>
+
+            subsection \<open>Subsection3\<close>
+
+            subsubsection \<open>Subsubsection1\<close>
+
+            subsubsection \<open>Subsubsection2\<close>
+        "#]],
+    );
+}
+
+#[test]
+fn latex_shorts_to_isabelle_works() {
+    use expect_test::expect_file;
+    use husky_path_utils::HuskyLangDevPaths;
+    use std::fs;
+
+    let db = &EternerDb::default();
+    let dev_paths = HuskyLangDevPaths::new();
+    let projects_dir = dev_paths.projects_dir();
+    let models = &VdModels::new();
+    for file in fs::read_dir(projects_dir.join("ai-math-autoformalization/latex/shorts")).unwrap() {
+        let file = file.unwrap();
+        let file_path = file.path();
+        if file_path.extension() != Some(&std::ffi::OsStr::new("tex")) {
+            continue;
+        }
+        let content = &fs::read_to_string(&file_path).unwrap();
+        let filestem = file_path.file_stem().unwrap().to_str().unwrap();
+        let file_path = LxFilePath::new(file_path.clone(), db);
+        let tracker = VdIsabelleTranspilationTracker::new(
+            LxDocumentInput {
+                specs_dir: dev_paths.specs_dir().to_path_buf(),
+                file_path,
+                content,
+            },
+            &[],
+            &[],
+            models,
+            VdSynExprVibe::ROOT_CNL,
+            db,
+            &VdIsabelleTranspilationDenseScheme,
+            |_| VdMirTrivialElaborator::default(),
+        );
+        expect_file![projects_dir.join(format!(
+            "ai-math-autoformalization/isabelle/Shorts/{}.thy",
+            filestem
+        ))]
+        .assert_eq(&format!(
+            r#"theory {}
+  imports Complex_Main
+begin
+
+{}
+end
+"#,
+            filestem,
+            tracker.show_fmt(db)
+        ));
+    }
+}
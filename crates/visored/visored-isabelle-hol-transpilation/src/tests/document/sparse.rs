@@ -0,0 +1,127 @@
+use super::*;
+use crate::scheme::sparse::VdIsabelleTranspilationSparseScheme;
+use visored_mir_expr::elaborator::VdMirTrivialElaborator;
+use visored_syn_expr::vibe::VdSynExprVibe;
+
+fn t(models: &VdModels, content: &str, expected_display_tree: &Expect, expected_fmt: &Expect) {
+    use husky_path_utils::HuskyLangDevPaths;
+
+    let db = &EternerDb::default();
+    let dev_paths = HuskyLangDevPaths::new();
+    let file_path = LxFilePath::new(PathBuf::from(file!()), db);
+    let tracker = VdIsabelleTranspilationTracker::new(
+        LxDocumentInput {
+            specs_dir: dev_paths.specs_dir().to_path_buf(),
+            file_path,
+            content,
+        },
+        &[],
+        &[],
+        models,
+        VdSynExprVibe::ROOT_CNL,
+        db,
+        &VdIsabelleTranspilationSparseScheme,
+        |_| VdMirTrivialElaborator::default(),
+    );
+    expected_display_tree.assert_eq(&tracker.show_display_tree(db));
+    expected_fmt.assert_eq(&tracker.show_fmt(db));
+}
+
+#[test]
+fn basic_document_to_vd_mir_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        r#"\documentclass{article}
+\usepackage{amsmath}
+\begin{document}
+Let $x\in\mathbb{R}$.
+\end{document}"#,
+        &expect![[r#"
+            └─ group: `division`
+              └─ context
+                └─ item path: `real`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: real
+            begin
+            end
+        "#]],
+    );
+    t(
+        models,
+        r#"\documentclass{article}
+\usepackage{amsmath}
+\begin{document}
+\section{Introduction}
+Let $x\in\mathbb{R}$.
+\end{document}"#,
+        &expect![[r#"
+            └─ group: `division`
+              └─ group: `division`
+                └─ context
+                  └─ item path: `real`
+        "#]],
+        &expect![[r#"
+            section \<open>Section1\<close>
+
+            context
+              fixes x :: real
+            begin
+            end
+        "#]],
+    );
+    t(
+        models,
+        r#"\documentclass{article}
+\usepackage{amsmath}
+\begin{document}
+\section{Introduction}
+Let $x\in\mathbb{R}$.
+\subsection{Hello}
+Let $y\in\mathbb{R}$.
+\subsection{World}
+\subsection{This}
+\subsubsection{Is}
+\subsubsection{Bad}
+\end{document}"#,
+        &expect![[r#"
+            └─ group: `division`
+              ├─ group: `division`
+              │ └─ context
+              │   └─ item path: `real`
+              ├─ group: `division`
+              │ └─ group: `division`
+              │   └─ context
+              │     └─ item path: `real`
+              ├─ group: `division`
+              └─ group: `division`
+                ├─ group: `division`
+                └─ group: `division`
+        "#]],
+        &expect![[r#"
+            section \<open>Section1\<close>
+
+            context
+              fixes x :: real
+            begin
+            end
+
+            subsection \<open>Subsection1\<close>
+
+            context
+              fixes y :: real
+            begin
+            end
+
+            subsection \<open>Subsection2\<close>
+
+            subsection \<open>Subsection3\<close>
+
+            subsubsection \<open>Subsubsection1\<close>
+
+            subsubsection \<open>Subsubsection2\<close>
+        "#]],
+    );
+}
@@ -0,0 +1,191 @@
+use super::*;
+use crate::{
+    error::VdIsabelleTranspilationError, helpers::tracker::VdIsabelleTranspilationTracker,
+    scheme::sparse::VdIsabelleTranspilationSparseScheme,
+};
+use eterned::db::EternerDb;
+use latex_prelude::helper::tracker::LxFormulaInput;
+use latex_vfs::path::LxFilePath;
+use std::path::PathBuf;
+use visored_mir_expr::elaborator::VdMirTrivialElaborator;
+use visored_syn_expr::vibe::VdSynExprVibe;
+
+fn t(models: &VdModels, content: &str, expected_display_tree: &Expect, expected_fmt: &Expect) {
+    t_with_errors(models, content, expected_display_tree, expected_fmt, &[])
+}
+
+fn t_with_errors(
+    models: &VdModels,
+    content: &str,
+    expected_display_tree: &Expect,
+    expected_fmt: &Expect,
+    expected_errors: &[VdIsabelleTranspilationError],
+) {
+    use husky_path_utils::HuskyLangDevPaths;
+
+    let db = &EternerDb::default();
+    let dev_paths = HuskyLangDevPaths::new();
+    let file_path = LxFilePath::new(PathBuf::from(file!()), db);
+    let tracker = VdIsabelleTranspilationTracker::new(
+        LxFormulaInput {
+            specs_dir: dev_paths.specs_dir(),
+            file_path,
+            content,
+        },
+        &[],
+        &[],
+        models,
+        VdSynExprVibe::ROOT_CNL,
+        db,
+        &VdIsabelleTranspilationSparseScheme,
+        |_| VdMirTrivialElaborator::default(),
+    );
+    expected_display_tree.assert_eq(&tracker.show_display_tree(db));
+    expected_fmt.assert_eq(&tracker.show_fmt(db));
+    assert_eq!(tracker.errors(), expected_errors);
+}
+
+#[test]
+fn basic_visored_expr_to_isabelle_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "1",
+        &expect![[r#"
+            literal: `1`
+        "#]],
+        &expect!["1"],
+    );
+    t(
+        models,
+        "-1",
+        &expect![[r#"
+            application: `-`
+            └─ literal: `1`
+        "#]],
+        &expect!["- (1 :: int)"],
+    );
+    t(
+        models,
+        "1 + 1",
+        &expect![[r#"
+            application: `+`
+            ├─ literal: `1`
+            └─ literal: `1`
+        "#]],
+        &expect!["1 + 1"],
+    );
+    t(
+        models,
+        "1 < 2",
+        &expect![[r#"
+            application: `<`
+            ├─ literal: `1`
+            └─ literal: `2`
+        "#]],
+        &expect!["1 < 2"],
+    );
+    t(
+        models,
+        "1\\in\\mathbb{N}",
+        &expect![[r#"
+            undefined
+        "#]],
+        &expect!["undefined"],
+    );
+    t(
+        models,
+        "\\frac{1}{2}",
+        &expect![[r#"
+            application: `/`
+            ├─ literal: `1`
+            └─ literal: `2`
+        "#]],
+        &expect!["(1 :: rat) / (2 :: rat)"],
+    );
+    t(
+        models,
+        "\\sqrt{2}",
+        &expect![[r#"
+            application: `sqrt`
+            └─ literal: `2`
+        "#]],
+        &expect!["sqrt (2 :: real)"],
+    );
+}
+
+#[test]
+fn unsupported_visored_expr_to_isabelle_works() {
+    let models = &VdModels::new();
+    t_with_errors(
+        models,
+        "1 < 2 < 3",
+        &expect![[r#"
+            undefined
+        "#]],
+        &expect!["undefined"],
+        &[VdIsabelleTranspilationError::UnsupportedSeparatedList(
+            "chains of more than one relation outside of calculations",
+        )],
+    );
+}
+
+#[test]
+fn item_path_to_isabelle_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "\\mathbb{N}",
+        &expect![[r#"
+            item path: `nat`
+        "#]],
+        &expect!["nat"],
+    );
+}
+
+#[test]
+fn standard_math_commands_to_isabelle_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "\\gcd(4, 6)",
+        &expect![[r#"
+            application: `gcd`
+            ├─ literal: `4`
+            └─ literal: `6`
+        "#]],
+        &expect!["gcd 4 6"],
+    );
+    t(
+        models,
+        "\\binom{4}{2}",
+        &expect![[r#"
+            application: `binomial`
+            ├─ literal: `4`
+            └─ literal: `2`
+        "#]],
+        &expect!["binomial 4 2"],
+    );
+    t(
+        models,
+        "\\lfloor \\frac{1}{2} \\rfloor",
+        &expect![[r#"
+            application: `floor`
+            └─ application: `of_rat`
+              └─ application: `/`
+                ├─ literal: `1`
+                └─ literal: `2`
+        "#]],
+        &expect!["floor (of_rat ((1 :: rat) / (2 :: rat)) :: real)"],
+    );
+    t(
+        models,
+        "2 \\mid 4",
+        &expect![[r#"
+            application: `dvd`
+            ├─ literal: `2`
+            └─ literal: `4`
+        "#]],
+        &expect!["2 dvd 4"],
+    );
+}
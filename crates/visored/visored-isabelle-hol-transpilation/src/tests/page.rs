@@ -0,0 +1,239 @@
+use super::*;
+use crate::{
+    helpers::tracker::VdIsabelleTranspilationTracker,
+    scheme::sparse::VdIsabelleTranspilationSparseScheme,
+};
+use eterned::db::EternerDb;
+use latex_prelude::helper::tracker::LxPageInput;
+use latex_vfs::path::LxFilePath;
+use std::path::PathBuf;
+use visored_mir_expr::elaborator::VdMirTrivialElaborator;
+use visored_syn_expr::vibe::VdSynExprVibe;
+
+fn t(models: &VdModels, content: &str, expected_display_tree: &Expect, expected_fmt: &Expect) {
+    use husky_path_utils::HuskyLangDevPaths;
+
+    let db = &EternerDb::default();
+    let dev_paths = HuskyLangDevPaths::new();
+    let file_path = LxFilePath::new(PathBuf::from(file!()), db);
+    let tracker = VdIsabelleTranspilationTracker::new(
+        LxPageInput {
+            specs_dir: dev_paths.specs_dir(),
+            file_path,
+            content,
+        },
+        &[],
+        &[],
+        models,
+        VdSynExprVibe::ROOT_CNL,
+        db,
+        &VdIsabelleTranspilationSparseScheme,
+        |_| VdMirTrivialElaborator::default(),
+    );
+    expected_display_tree.assert_eq(&tracker.show_display_tree(db));
+    expected_fmt.assert_eq(&tracker.show_fmt(db));
+}
+
+#[test]
+fn basic_visored_clause_to_isabelle_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "Let $x\\in\\mathbb{N}$.",
+        &expect![[r#"
+            └─ context
+              └─ item path: `nat`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: nat
+            begin
+            end
+        "#]],
+    );
+    t(
+        models,
+        "Let $x\\in\\mathbb{Z}$.",
+        &expect![[r#"
+            └─ context
+              └─ item path: `int`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: int
+            begin
+            end
+        "#]],
+    );
+    t(
+        models,
+        "Let $x\\in\\mathbb{Q}$.",
+        &expect![[r#"
+            └─ context
+              └─ item path: `rat`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: rat
+            begin
+            end
+        "#]],
+    );
+    t(
+        models,
+        "Let $x\\in\\mathbb{R}$.",
+        &expect![[r#"
+            └─ context
+              └─ item path: `real`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: real
+            begin
+            end
+        "#]],
+    );
+    t(
+        models,
+        "Let $x\\in\\mathbb{C}$.",
+        &expect![[r#"
+            └─ context
+              └─ item path: `complex`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: complex
+            begin
+            end
+        "#]],
+    );
+    t(
+        models,
+        "Let $x\\in\\mathbb{R}$. Then $x=x$.",
+        &expect![[r#"
+            └─ context
+              ├─ item path: `real`
+              └─ lemma: `h`
+                ├─ application: `=`
+                │ ├─ variable: `x`
+                │ └─ variable: `x`
+                └─ stmts
+                  ├─ stmt: `Have { ident: Some(IeIdent(Coword("h1"))), facts: [], prop: 3, proof: By(Auto) }`
+                  └─ stmt: `Show { facts: [IeIdent(Coword("h1"))], prop: None, proof: This }`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: real
+            begin
+            lemma h:
+              shows "x = x"
+            proof -
+              have h1: "x = x" by auto
+              from h1 show ?thesis .
+            qed
+            end
+        "#]],
+    );
+    t(
+        models,
+        "Let $x\\in\\mathbb{N}$. Then $2x\\ge x$.",
+        &expect![[r#"
+            └─ context
+              ├─ item path: `nat`
+              └─ lemma: `h`
+                ├─ application: `\<ge>`
+                │ ├─ application: `*`
+                │ │ ├─ literal: `2`
+                │ │ └─ variable: `x`
+                │ └─ variable: `x`
+                └─ stmts
+                  ├─ stmt: `Have { ident: Some(IeIdent(Coword("h1"))), facts: [], prop: 5, proof: By(Auto) }`
+                  └─ stmt: `Show { facts: [IeIdent(Coword("h1"))], prop: None, proof: This }`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: nat
+            begin
+            lemma h:
+              shows "2 * x \<ge> x"
+            proof -
+              have h1: "2 * x \<ge> x" by auto
+              from h1 show ?thesis .
+            qed
+            end
+        "#]],
+    );
+    t(
+        models,
+        "Let $x\\in\\mathbb{R}$. Then ${(x-1)}^2 \\ge 0$. Then $x^2-2x+1 \\ge 0$. Then $x^2 + 1\\ge 2x$.",
+        &expect![[r#"
+            └─ context
+              ├─ item path: `real`
+              ├─ lemma: `h`
+              │ ├─ application: `\<ge>`
+              │ │ ├─ application: `^`
+              │ │ │ ├─ application: `-`
+              │ │ │ │ ├─ variable: `x`
+              │ │ │ │ └─ literal: `1`
+              │ │ │ └─ literal: `2`
+              │ │ └─ literal: `0`
+              │ └─ stmts
+              │   ├─ stmt: `Have { ident: Some(IeIdent(Coword("h1"))), facts: [], prop: 9, proof: By(Auto) }`
+              │   └─ stmt: `Show { facts: [IeIdent(Coword("h1"))], prop: None, proof: This }`
+              ├─ lemma: `h2`
+              │ ├─ application: `\<ge>`
+              │ │ ├─ application: `+`
+              │ │ │ ├─ application: `-`
+              │ │ │ │ ├─ application: `^`
+              │ │ │ │ │ ├─ variable: `x`
+              │ │ │ │ │ └─ literal: `2`
+              │ │ │ │ └─ application: `*`
+              │ │ │ │   ├─ literal: `2`
+              │ │ │ │   └─ variable: `x`
+              │ │ │ └─ literal: `1`
+              │ │ └─ literal: `0`
+              │ └─ stmts
+              │   ├─ stmt: `Have { ident: Some(IeIdent(Coword("h3"))), facts: [], prop: 32, proof: By(Auto) }`
+              │   └─ stmt: `Show { facts: [IeIdent(Coword("h3"))], prop: None, proof: This }`
+              └─ lemma: `h4`
+                ├─ application: `\<ge>`
+                │ ├─ application: `+`
+                │ │ ├─ application: `^`
+                │ │ │ ├─ variable: `x`
+                │ │ │ └─ literal: `2`
+                │ │ └─ literal: `1`
+                │ └─ application: `*`
+                │   ├─ literal: `2`
+                │   └─ variable: `x`
+                └─ stmts
+                  ├─ stmt: `Have { ident: Some(IeIdent(Coword("h5"))), facts: [], prop: 57, proof: By(Auto) }`
+                  └─ stmt: `Show { facts: [IeIdent(Coword("h5"))], prop: None, proof: This }`
+        "#]],
+        &expect![[r#"
+            context
+              fixes x :: real
+            begin
+            lemma h:
+              shows "(x - (1 :: real)) ^ 2 \<ge> (0 :: real)"
+            proof -
+              have h1: "(x - (1 :: real)) ^ 2 \<ge> (0 :: real)" by auto
+              from h1 show ?thesis .
+            qed
+
+            lemma h2:
+              shows "x ^ 2 - (2 :: real) * x + (1 :: real) \<ge> (0 :: real)"
+            proof -
+              have h3: "x ^ 2 - (2 :: real) * x + (1 :: real) \<ge> (0 :: real)" by auto
+              from h3 show ?thesis .
+            qed
+
+            lemma h4:
+              shows "x ^ 2 + (1 :: real) \<ge> (2 :: real) * x"
+            proof -
+              have h5: "x ^ 2 + (1 :: real) \<ge> (2 :: real) * x" by auto
+              from h5 show ?thesis .
+            qed
+            end
+        "#]],
+    );
+}
@@ -0,0 +1,66 @@
+use crate::{error::VdIsabelleTranspilationError, *};
+use dictionary::item_path::VdItemPathTranslation;
+use isabelle_hir_expr::{
+    expr::{IeHirExprData, IeHirExprEntry, IeHirExprIdx},
+    item_path::IeItemPath,
+};
+use visored_entity_path::path::VdItemPath;
+use visored_term::{term::VdTermData, ty::VdType};
+
+pub enum VdTypeIsabelleTranspilation {
+    Type(IeHirExprIdx),
+}
+
+impl<'a, Scheme> VdTranspileToIsabelle<Scheme, VdTypeIsabelleTranspilation> for VdType
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    fn to_isabelle(
+        self,
+        builder: &mut VdIsabelleTranspilationBuilder<Scheme>,
+    ) -> VdTypeIsabelleTranspilation {
+        let data = match builder.ie_item_path_of_vd_ty(self) {
+            Some(item_path) => IeHirExprData::ItemPath(item_path),
+            None => IeHirExprData::Undefined,
+        };
+        VdTypeIsabelleTranspilation::Type(builder.alloc_expr(IeHirExprEntry::new(data, None)))
+    }
+}
+
+impl<'a, Scheme> VdIsabelleTranspilationBuilder<'a, Scheme>
+where
+    Scheme: IsVdIsabelleTranspilationScheme,
+{
+    /// `None` for types other than item paths, which are reported
+    pub(crate) fn ie_item_path_of_vd_ty(&mut self, ty: VdType) -> Option<IeItemPath> {
+        let term = match *ty.data() {
+            VdTermData::ItemPath(ref item_path) => {
+                return self.ie_item_path_of_vd_item_path(item_path.item_path())
+            }
+            VdTermData::Literal(_) => "literal",
+            VdTermData::ForAll(_) => "for all",
+            VdTermData::Exists(_) => "exists",
+            VdTermData::Limit(_) => "limit",
+            VdTermData::Eval(_) => "evaluation",
+            VdTermData::SymbolicVariable(_) => "symbolic variable",
+            VdTermData::AbstractVariable(_) => "abstract variable",
+            VdTermData::StackVariable(_) => "stack variable",
+            VdTermData::Application(_) => "application",
+            VdTermData::Abstraction(_) => "abstraction",
+        };
+        self.report(VdIsabelleTranspilationError::UnsupportedType(term));
+        None
+    }
+
+    fn ie_item_path_of_vd_item_path(&mut self, item_path: VdItemPath) -> Option<IeItemPath> {
+        let Some(translation) = self.dictionary().item_path_translation(item_path) else {
+            self.report(VdIsabelleTranspilationError::MissingTranslation(format!(
+                "{item_path:?}"
+            )));
+            return None;
+        };
+        match *translation {
+            VdItemPathTranslation::ItemPath(ie_item_path) => Some(ie_item_path),
+        }
+    }
+}
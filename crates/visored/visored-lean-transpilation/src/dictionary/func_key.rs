@@ -84,7 +84,28 @@ impl VdFuncKeyDictionary {
             rat_ge,
             real_ge,
             in_set,
+            nat_dvd,
+            int_dvd,
+            prop_implies,
+            prop_iff,
             real_sqrt,
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            nat_max,
+            int_max,
+            rat_max,
+            real_max,
+            nat_min,
+            int_min,
+            rat_min,
+            real_min,
+            nat_binom,
+            real_floor,
+            int_abs,
+            rat_abs,
+            real_abs,
         } = *vd_mir_func_key_menu(db);
         let LnMirFuncKeyMenu {
             int_pos: ln_int_pos,
@@ -144,6 +165,27 @@ impl VdFuncKeyDictionary {
             rat_ge: ln_rat_ge,
             real_ge: ln_real_ge,
             real_sqrt: ln_real_sqrt,
+            nat_dvd: ln_nat_dvd,
+            int_dvd: ln_int_dvd,
+            prop_implies: ln_prop_implies,
+            prop_iff: ln_prop_iff,
+            real_log: ln_real_log,
+            real_exp: ln_real_exp,
+            nat_gcd: ln_nat_gcd,
+            int_gcd: ln_int_gcd,
+            nat_max: ln_nat_max,
+            int_max: ln_int_max,
+            rat_max: ln_rat_max,
+            real_max: ln_real_max,
+            nat_min: ln_nat_min,
+            int_min: ln_int_min,
+            rat_min: ln_rat_min,
+            real_min: ln_real_min,
+            nat_binom: ln_nat_binom,
+            real_floor: ln_real_floor,
+            int_abs: ln_int_abs,
+            rat_abs: ln_rat_abs,
+            real_abs: ln_real_abs,
         } = *ln_mir_func_key_menu(db);
         Self::new([
             (int_pos, PrefixOpr(ln_int_pos)),
@@ -205,7 +247,28 @@ impl VdFuncKeyDictionary {
             (rat_ge, ChainingBinaryOpr(ln_rat_ge)),
             (real_ge, ChainingBinaryOpr(ln_real_ge)),
             (in_set, InSet),
+            (nat_dvd, ChainingBinaryOpr(ln_nat_dvd)),
+            (int_dvd, ChainingBinaryOpr(ln_int_dvd)),
+            (prop_implies, ChainingBinaryOpr(ln_prop_implies)),
+            (prop_iff, ChainingBinaryOpr(ln_prop_iff)),
             (real_sqrt, Function(ln_real_sqrt)),
+            (real_log, Function(ln_real_log)),
+            (real_exp, Function(ln_real_exp)),
+            (nat_gcd, Function(ln_nat_gcd)),
+            (int_gcd, Function(ln_int_gcd)),
+            (nat_max, Function(ln_nat_max)),
+            (int_max, Function(ln_int_max)),
+            (rat_max, Function(ln_rat_max)),
+            (real_max, Function(ln_real_max)),
+            (nat_min, Function(ln_nat_min)),
+            (int_min, Function(ln_int_min)),
+            (rat_min, Function(ln_rat_min)),
+            (real_min, Function(ln_real_min)),
+            (nat_binom, Function(ln_nat_binom)),
+            (real_floor, Function(ln_real_floor)),
+            (int_abs, Function(ln_int_abs)),
+            (rat_abs, Function(ln_rat_abs)),
+            (real_abs, Function(ln_real_abs)),
         ])
     }
 
@@ -1,4 +1,5 @@
 pub mod application;
+mod binder;
 mod separated_list;
 
 use super::VdTranspileToLean;
@@ -97,6 +98,17 @@ where
                 ref followers,
                 joined_signature,
             } => self.build_chaining_separated_list(leader, followers, joined_signature),
+            VdMirExprData::Quantification {
+                quantifier,
+                variable,
+                domain,
+                body,
+            } => self.build_quantification(quantifier, variable, domain, body),
+            VdMirExprData::Limit {
+                variable,
+                approached,
+                body,
+            } => self.build_limit(variable, approached, body),
         }
     }
 }
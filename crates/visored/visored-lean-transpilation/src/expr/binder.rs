@@ -0,0 +1,71 @@
+use super::*;
+use lean_entity_path::LnItemPath;
+use lean_mir_expr::expr::{LnMirLambdaParameter, LnMirQuantifier};
+use smallvec::*;
+use visored_mir_expr::{expr::VdMirQuantifier, symbol::local_defn::VdMirSymbolLocalDefnIdx};
+
+impl<'db, S> VdLeanTranspilationBuilder<'db, S>
+where
+    S: IsVdLeanTranspilationScheme,
+{
+    /// `∀ x : α, P` or `∃ x : α, P`, with the domain as the type of the bound variable
+    pub(super) fn build_quantification(
+        &mut self,
+        quantifier: VdMirQuantifier,
+        variable: VdMirSymbolLocalDefnIdx,
+        domain: VdMirExprIdx,
+        body: VdMirExprIdx,
+    ) -> LnMirExprData {
+        let quantifier = match quantifier {
+            VdMirQuantifier::Forall => LnMirQuantifier::Forall,
+            VdMirQuantifier::Exists => LnMirQuantifier::Exists,
+        };
+        let parameter =
+            LnMirLambdaParameter::new(self.mangle_symbol(variable), domain.to_lean(self));
+        LnMirExprData::Quantified {
+            quantifier,
+            parameter,
+            body: body.to_lean(self),
+        }
+    }
+
+    /// `limUnder (nhds a) (λ x : α => f x)`
+    pub(super) fn build_limit(
+        &mut self,
+        variable: VdMirSymbolLocalDefnIdx,
+        approached: VdMirExprIdx,
+        body: VdMirExprIdx,
+    ) -> LnMirExprData {
+        let approached_entry = &self.expr_arena()[approached];
+        let variable_ty = approached_entry
+            .expected_ty()
+            .unwrap_or(approached_entry.ty());
+        let variable_ty = match variable_ty.to_lean(self) {
+            VdTypeLeanTranspilation::Type(ty) => ty,
+        };
+        let approached = self.build_expr_entry(approached);
+        let nhds = LnMirExprData::Application {
+            function: self.build_item_path_func(LnItemPath::NHDS),
+            arguments: self.alloc_exprs([approached]),
+        };
+        let parameter = LnMirLambdaParameter::new(self.mangle_symbol(variable), variable_ty);
+        let lambda = LnMirExprData::Lambda {
+            parameters: smallvec![parameter],
+            body: body.to_lean(self),
+        };
+        LnMirExprData::Application {
+            function: self.build_item_path_func(LnItemPath::LIM_UNDER),
+            arguments: self.alloc_exprs([
+                LnMirExprEntry::new(nhds, None),
+                LnMirExprEntry::new(lambda, None),
+            ]),
+        }
+    }
+
+    fn build_item_path_func(&mut self, item_path: LnItemPath) -> LnMirFunc {
+        LnMirFunc::Expr(self.alloc_expr(LnMirExprEntry::new(
+            LnMirExprData::ItemPath(item_path),
+            None,
+        )))
+    }
+}
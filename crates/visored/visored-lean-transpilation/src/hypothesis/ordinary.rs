@@ -21,16 +21,22 @@ where
             VdMirHypothesisConstruction::Apply {
                 path,
                 is_real_coercion,
+                side_conditions,
             } => {
                 match is_real_coercion {
                     VdMirCoercion::Trivial => (),
                     VdMirCoercion::Obvious(arena_idx) => todo!("handle this properly."),
                 }
-                self.alloc_tactics([LnMirTacticData::Apply {
-                    path: match path {
-                        VdTheoremPath::SquareNonnegative => LnTheoremPath::SquareNonnegative,
-                    },
-                }])
+                let apply_tactic_data = LnMirTacticData::Apply {
+                    path: ln_theorem_path(*path),
+                };
+                // each side condition is left as a goal, closed by the hypothesis proving it
+                let side_condition_tactics =
+                    side_conditions.iter().map(|_| self.ad_hoc_tactic_data("assumption"));
+                let tactics: Vec<_> = std::iter::once(apply_tactic_data)
+                    .chain(side_condition_tactics)
+                    .collect();
+                self.alloc_tactics(tactics)
             }
             VdMirHypothesisConstruction::Assume => return,
             VdMirHypothesisConstruction::TermEquivalent { hypothesis } => {
@@ -53,6 +59,10 @@ where
                 let ad_hoc_tactic_data = self.ad_hoc_tactic_data("litnum_bound");
                 self.alloc_tactics([ad_hoc_tactic_data])
             }
+            VdMirHypothesisConstruction::Linarith => {
+                let ad_hoc_tactic_data = self.ad_hoc_tactic_data("linarith");
+                self.alloc_tactics([ad_hoc_tactic_data])
+            }
             VdMirHypothesisConstruction::Kurapika => todo!(),
         };
         let construction = self.alloc_expr(LnMirExprEntry::new(
@@ -69,3 +79,17 @@ where
         });
     }
 }
+
+fn ln_theorem_path(path: VdTheoremPath) -> LnTheoremPath {
+    match path {
+        VdTheoremPath::SquareNonnegative => LnTheoremPath::SquareNonnegative,
+        VdTheoremPath::PowNonnegative => LnTheoremPath::PowNonnegative,
+        VdTheoremPath::PowPositive => LnTheoremPath::PowPositive,
+        VdTheoremPath::SqrtNonnegative => LnTheoremPath::SqrtNonnegative,
+        VdTheoremPath::SqrtSquare => LnTheoremPath::SqrtSquare,
+        VdTheoremPath::MulNonnegative => LnTheoremPath::MulNonnegative,
+        VdTheoremPath::MulPositive => LnTheoremPath::MulPositive,
+        VdTheoremPath::DivNonnegative => LnTheoremPath::DivNonnegative,
+        VdTheoremPath::DivPositive => LnTheoremPath::DivPositive,
+    }
+}
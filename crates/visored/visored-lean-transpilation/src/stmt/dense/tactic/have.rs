@@ -188,10 +188,14 @@ fn is_equivalence(func: &VdMirFunc) -> bool {
             VdMirBaseSeparator::Notin => false,
             VdMirBaseSeparator::SetTimes => todo!(),
             VdMirBaseSeparator::TensorOtimes => todo!(),
+            VdMirBaseSeparator::Dvd => false,
+            VdMirBaseSeparator::Implies => false,
+            VdMirBaseSeparator::Iff => true,
         },
         VdMirFunc::NormalBaseBinaryOpr(signature) => todo!(),
         VdMirFunc::Power(signature) => todo!(),
         VdMirFunc::InSet => todo!(),
         VdMirFunc::NormalBaseSqrt(vd_base_sqrt_signature) => todo!(),
+        VdMirFunc::NormalBaseFunction(signature) => todo!(),
     }
 }
@@ -117,3 +117,203 @@ fn item_path_to_lean_works() {
         &expect!["ℕ"],
     );
 }
+
+#[test]
+fn standard_math_commands_to_lean_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "\\log 2",
+        &expect![[r#"
+            application
+            ├─ item path: `Real.log`
+            └─ literal: `2`
+        "#]],
+        &expect!["Real.log (2 : ℝ)"],
+    );
+    t(
+        models,
+        "\\exp(1)",
+        &expect![[r#"
+            application
+            ├─ item path: `Real.exp`
+            └─ literal: `1`
+        "#]],
+        &expect!["Real.exp (1 : ℝ)"],
+    );
+    t(
+        models,
+        "\\gcd(4, 6)",
+        &expect![[r#"
+            application
+            ├─ item path: `Nat.gcd`
+            ├─ literal: `4`
+            └─ literal: `6`
+        "#]],
+        &expect!["Nat.gcd 4 6"],
+    );
+    t(
+        models,
+        "\\max(1, 2)",
+        &expect![[r#"
+            application
+            ├─ item path: `max`
+            ├─ literal: `1`
+            └─ literal: `2`
+        "#]],
+        &expect!["max 1 2"],
+    );
+    t(
+        models,
+        "\\min(1, 2)",
+        &expect![[r#"
+            application
+            ├─ item path: `min`
+            ├─ literal: `1`
+            └─ literal: `2`
+        "#]],
+        &expect!["min 1 2"],
+    );
+    t(
+        models,
+        "\\binom{4}{2}",
+        &expect![[r#"
+            application
+            ├─ item path: `Nat.choose`
+            ├─ literal: `4`
+            └─ literal: `2`
+        "#]],
+        &expect!["Nat.choose 4 2"],
+    );
+    t(
+        models,
+        "\\lfloor \\frac{1}{2} \\rfloor",
+        &expect![[r#"
+            application
+            ├─ item path: `Int.floor`
+            └─ application
+              ├─ literal: `1`
+              └─ literal: `2`
+        "#]],
+        &expect!["Int.floor ((1 : ℚ) / (2 : ℚ) : ℝ)"],
+    );
+    t(
+        models,
+        "\\lvert -1 \\rvert",
+        &expect![[r#"
+            application
+            ├─ item path: `abs`
+            └─ application
+              └─ literal: `1`
+        "#]],
+        &expect!["abs (-(1 : ℤ))"],
+    );
+    t(
+        models,
+        "2 \\mid 4",
+        &expect![[r#"
+            application
+            ├─ literal: `2`
+            └─ literal: `4`
+        "#]],
+        &expect!["2 ∣ 4"],
+    );
+    t(
+        models,
+        "1 < 2 \\implies 2 > 1",
+        &expect![[r#"
+            application
+            ├─ application
+            │ ├─ literal: `1`
+            │ └─ literal: `2`
+            └─ application
+              ├─ literal: `2`
+              └─ literal: `1`
+        "#]],
+        &expect!["1 < 2 → 2 > 1"],
+    );
+    t(
+        models,
+        "1 < 2 \\iff 2 > 1",
+        &expect![[r#"
+            application
+            ├─ application
+            │ ├─ literal: `1`
+            │ └─ literal: `2`
+            └─ application
+              ├─ literal: `2`
+              └─ literal: `1`
+        "#]],
+        &expect!["1 < 2 ↔ 2 > 1"],
+    );
+}
+
+#[test]
+fn binder_and_congruence_to_lean_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "1 < 2 \\to 2 > 1",
+        &expect![[r#"
+            application
+            ├─ application
+            │ ├─ literal: `1`
+            │ └─ literal: `2`
+            └─ application
+              ├─ literal: `2`
+              └─ literal: `1`
+        "#]],
+        &expect!["1 < 2 → 2 > 1"],
+    );
+    t(
+        models,
+        "\\forall x \\in \\mathbb{R}, x = x",
+        &expect![[r#"
+            quantified: `∀`
+            ├─ item path: `ℝ`
+            └─ application
+              ├─ variable: `x`
+              └─ variable: `x`
+        "#]],
+        &expect!["∀ x : ℝ, x = x"],
+    );
+    t(
+        models,
+        "\\exists n \\in \\mathbb{N}, n > 1",
+        &expect![[r#"
+            quantified: `∃`
+            ├─ item path: `ℕ`
+            └─ application
+              ├─ variable: `n`
+              └─ literal: `1`
+        "#]],
+        &expect!["∃ n : ℕ, n > 1"],
+    );
+    t(
+        models,
+        "\\lim_{x \\to 0} x",
+        &expect![[r#"
+            application
+            ├─ item path: `limUnder`
+            ├─ application
+            │ ├─ item path: `nhds`
+            │ └─ literal: `0`
+            └─ lambda
+              ├─ item path: `ℝ`
+              └─ variable: `x`
+        "#]],
+        &expect!["limUnder (nhds (0 : ℝ)) (λ x : ℝ => x)"],
+    );
+    t(
+        models,
+        "7 \\equiv 1 \\pmod{3}",
+        &expect![[r#"
+            application
+            ├─ literal: `3`
+            └─ application
+              ├─ literal: `7`
+              └─ literal: `1`
+        "#]],
+        &expect!["(3 : ℤ) ∣ (7 : ℤ) - (1 : ℤ)"],
+    );
+}
@@ -529,6 +529,16 @@ where
                 )
             }
             VdMirExprData::ItemPath(vd_item_path) => (),
+            VdMirExprData::Quantification { domain, body, .. } => {
+                self.elaborate_expr(domain, hypothesis_constructor);
+                self.elaborate_expr(body, hypothesis_constructor);
+            }
+            VdMirExprData::Limit {
+                approached, body, ..
+            } => {
+                self.elaborate_expr(approached, hypothesis_constructor);
+                self.elaborate_expr(body, hypothesis_constructor);
+            }
         }
         self.inner
             .cache_expr(expr, hypothesis_constructor.region_data());
@@ -567,6 +577,8 @@ where
             VdMirFunc::Power(signature) => (),
             VdMirFunc::InSet => todo!(),
             VdMirFunc::NormalBaseSqrt(signature) => (), // ad hoc, should be merged with power
+            // ad hoc, should check the domain, e.g., that the argument of `\log` is positive
+            VdMirFunc::NormalBaseFunction(signature) => (),
         }
     }
 }
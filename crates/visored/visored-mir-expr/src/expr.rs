@@ -19,17 +19,21 @@ use visored_global_dispatch::dispatch::{
 };
 use visored_global_resolution::resolution::letter::VdLetterGlobalResolution;
 use visored_mir_opr::{opr::binary::VdMirBaseBinaryOpr, separator::VdMirBaseSeparator};
-use visored_opr::opr::binary::VdBaseBinaryOpr;
+use visored_opr::opr::{binary::VdBaseBinaryOpr, prefix::VdBasePrefixOpr};
 use visored_sem_expr::expr::{
+    application::VdSemApplicationDispatch,
     binary::{VdSemBinaryDispatch, VdSemBinaryOpr},
     frac::VdSemFracDispatch,
     letter::VdSemLetterDispatch,
-    prefix::VdSemPrefixDispatch,
+    prefix::{VdSemPrefixDispatch, VdSemPrefixOpr},
     separated_list::VdSemSeparatedListFollowerDispatch,
     sqrt::VdSemSqrtDispatch,
+    suffix::{VdSemSuffixDispatch, VdSemSuffixOpr},
     VdSemExprData, VdSemExprIdx, VdSemExprIdxRange,
 };
-use visored_signature::signature::separator::base::VdBaseSeparatorSignature;
+use visored_signature::signature::{
+    binary_opr::base::VdBaseBinaryOprSignature, separator::base::VdBaseSeparatorSignature,
+};
 use visored_term::{term::literal::VdLiteral, ty::VdType};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -51,6 +55,34 @@ pub enum VdMirExprData {
         joined_signature: Option<VdBaseSeparatorSignature>,
     },
     ItemPath(VdItemPath),
+    /// `\forall x \in S, P` or `\exists x \in S, P`
+    Quantification {
+        quantifier: VdMirQuantifier,
+        variable: VdMirSymbolLocalDefnIdx,
+        domain: VdMirExprIdx,
+        body: VdMirExprIdx,
+    },
+    /// `\lim_{x \to a} f(x)`
+    Limit {
+        variable: VdMirSymbolLocalDefnIdx,
+        approached: VdMirExprIdx,
+        body: VdMirExprIdx,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VdMirQuantifier {
+    Forall,
+    Exists,
+}
+
+impl VdMirQuantifier {
+    pub fn unicode(self) -> &'static str {
+        match self {
+            VdMirQuantifier::Forall => "∀",
+            VdMirQuantifier::Exists => "∃",
+        }
+    }
 }
 
 pub struct VdMirExprEntry {
@@ -125,6 +157,16 @@ impl<'db, const N: usize> ToVdMir<VdMirExprIdxRange, VdMirExprRegionBuilder<'db>
     }
 }
 
+impl<'db> ToVdMir<VdMirExprIdxRange, VdMirExprRegionBuilder<'db>> for &[VdSemExprIdx] {
+    fn to_vd_mir(self, builder: &mut VdMirExprRegionBuilder<'db>) -> VdMirExprIdxRange {
+        let entries = self
+            .iter()
+            .map(|&expr| builder.build_expr_entry(expr))
+            .collect::<Vec<_>>();
+        builder.alloc_exprs(entries)
+    }
+}
+
 impl<'db> VdMirExprRegionBuilder<'db> {
     fn build_expr_entry(&mut self, sem_expr_idx: VdSemExprIdx) -> VdMirExprEntry {
         let data = self.build_expr_data(sem_expr_idx);
@@ -166,12 +208,40 @@ impl<'db> VdMirExprRegionBuilder<'db> {
                         arguments: [opd].to_vd_mir(self),
                     },
                 },
+                VdSemPrefixDispatch::Quantification { variable, domain } => {
+                    let quantifier = match opr {
+                        VdSemPrefixOpr::Base(_, VdBasePrefixOpr::Forall) => VdMirQuantifier::Forall,
+                        VdSemPrefixOpr::Base(_, VdBasePrefixOpr::Exists) => VdMirQuantifier::Exists,
+                        _ => unreachable!(),
+                    };
+                    VdMirExprData::Quantification {
+                        quantifier,
+                        variable: variable.to_vd_mir(self),
+                        domain: domain.to_vd_mir(self),
+                        body: opd.to_vd_mir(self),
+                    }
+                }
+                VdSemPrefixDispatch::Limit {
+                    variable,
+                    approached,
+                } => VdMirExprData::Limit {
+                    variable: variable.to_vd_mir(self),
+                    approached: approached.to_vd_mir(self),
+                    body: opd.to_vd_mir(self),
+                },
+            },
+            VdSemExprData::Suffix { opd, opr, dispatch } => match opr {
+                VdSemSuffixOpr::Base(_, opr) => match opr {},
+                VdSemSuffixOpr::Composite(_, opr) => match opr {},
+                VdSemSuffixOpr::Pmod { modulus, .. } => {
+                    let VdSemSuffixDispatch::Congruence {
+                        rhs,
+                        sub_signature,
+                        dvd_signature,
+                    } = dispatch;
+                    self.build_congruence(opd, rhs, modulus, sub_signature, dvd_signature)
+                }
             },
-            VdSemExprData::Suffix {
-                opd,
-                opr,
-                ref dispatch,
-            } => todo!(),
             VdSemExprData::Attach {
                 base,
                 ref scripts,
@@ -237,6 +307,47 @@ impl<'db> VdMirExprRegionBuilder<'db> {
                     arguments: [radicand].to_vd_mir(self),
                 },
             },
+            VdSemExprData::Application {
+                ref arguments,
+                dispatch,
+                ..
+            } => match dispatch {
+                VdSemApplicationDispatch::Base { signature } => VdMirExprData::Application {
+                    function: VdMirFunc::NormalBaseFunction(signature),
+                    arguments: (arguments as &[_]).to_vd_mir(self),
+                },
+            },
+        }
+    }
+}
+
+impl<'db> VdMirExprRegionBuilder<'db> {
+    /// `a \equiv b \pmod{n}` is lowered to `n \mid a - b`
+    fn build_congruence(
+        &mut self,
+        lhs: VdSemExprIdx,
+        rhs: VdSemExprIdx,
+        modulus: VdSemExprIdx,
+        sub_signature: VdBaseBinaryOprSignature,
+        dvd_signature: VdBaseSeparatorSignature,
+    ) -> VdMirExprData {
+        let leader = modulus.to_vd_mir(self);
+        let difference = VdMirExprEntry::new(
+            VdMirExprData::Application {
+                function: VdMirFunc::NormalBaseBinaryOpr(sub_signature),
+                arguments: [lhs, rhs].to_vd_mir(self),
+            },
+            sub_signature.expr_ty(),
+            None,
+        );
+        let difference = self.alloc_expr(difference);
+        VdMirExprData::ChainingSeparatedList {
+            leader,
+            followers: smallvec::smallvec![(
+                VdMirFunc::NormalBaseSeparator(dvd_signature),
+                difference
+            )],
+            joined_signature: None,
         }
     }
 }
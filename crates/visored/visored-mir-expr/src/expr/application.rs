@@ -3,8 +3,8 @@ use either::*;
 use visored_opr::precedence::VdPrecedence;
 use visored_signature::signature::{
     attach::VdPowerSignature, binary_opr::base::VdBaseBinaryOprSignature,
-    prefix_opr::VdBasePrefixOprSignature, separator::base::VdBaseSeparatorSignature,
-    sqrt::VdBaseSqrtSignature,
+    function::VdBaseFunctionSignature, prefix_opr::VdBasePrefixOprSignature,
+    separator::base::VdBaseSeparatorSignature, sqrt::VdBaseSqrtSignature,
 };
 use visored_term::instantiation::VdInstantiation;
 
@@ -18,6 +18,7 @@ pub enum VdMirFunc {
     Power(VdPowerSignature),
     InSet,
     NormalBaseSqrt(VdBaseSqrtSignature),
+    NormalBaseFunction(VdBaseFunctionSignature),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -28,6 +29,7 @@ pub enum VdMirFuncKey {
     InSet,
     Power(VdInstantiation),
     NormalBaseSqrt(VdInstantiation),
+    NormalBaseFunction(VdInstantiation),
 }
 
 impl VdMirFunc {
@@ -47,6 +49,9 @@ impl VdMirFunc {
             VdMirFunc::NormalBaseSqrt(signature) => {
                 Left(VdMirFuncKey::NormalBaseSqrt(signature.instantiation()))
             }
+            VdMirFunc::NormalBaseFunction(signature) => {
+                Left(VdMirFuncKey::NormalBaseFunction(signature.instantiation()))
+            }
         }
     }
 
@@ -62,6 +67,7 @@ impl VdMirFunc {
             VdMirFunc::Power(signature) => VdPrecedence::ATOM,
             VdMirFunc::InSet => todo!(),
             VdMirFunc::NormalBaseSqrt(signature) => VdPrecedence::ATOM,
+            VdMirFunc::NormalBaseFunction(signature) => VdPrecedence::ATOM,
         }
     }
 }
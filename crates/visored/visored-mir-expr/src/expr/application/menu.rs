@@ -77,8 +77,40 @@ pub struct VdMirFuncKeyMenu {
     pub real_ge: VdMirFuncKey,
     /// ## in
     pub in_set: VdMirFuncKey,
+    /// ## dvd
+    pub nat_dvd: VdMirFuncKey,
+    pub int_dvd: VdMirFuncKey,
+    /// ## logic
+    pub prop_implies: VdMirFuncKey,
+    pub prop_iff: VdMirFuncKey,
     /// ## sqrt
     pub real_sqrt: VdMirFuncKey,
+    /// # functions
+    /// ## log
+    pub real_log: VdMirFuncKey,
+    /// ## exp
+    pub real_exp: VdMirFuncKey,
+    /// ## gcd
+    pub nat_gcd: VdMirFuncKey,
+    pub int_gcd: VdMirFuncKey,
+    /// ## max
+    pub nat_max: VdMirFuncKey,
+    pub int_max: VdMirFuncKey,
+    pub rat_max: VdMirFuncKey,
+    pub real_max: VdMirFuncKey,
+    /// ## min
+    pub nat_min: VdMirFuncKey,
+    pub int_min: VdMirFuncKey,
+    pub rat_min: VdMirFuncKey,
+    pub real_min: VdMirFuncKey,
+    /// ## binom
+    pub nat_binom: VdMirFuncKey,
+    /// ## floor
+    pub real_floor: VdMirFuncKey,
+    /// ## abs
+    pub int_abs: VdMirFuncKey,
+    pub rat_abs: VdMirFuncKey,
+    pub real_abs: VdMirFuncKey,
 }
 
 impl VdMirFuncKeyMenu {
@@ -140,7 +172,28 @@ impl VdMirFuncKeyMenu {
             int_ge,
             rat_ge,
             real_ge,
+            nat_dvd,
+            int_dvd,
+            prop_implies,
+            prop_iff,
             real_sqrt,
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            nat_max,
+            int_max,
+            rat_max,
+            real_max,
+            nat_min,
+            int_min,
+            rat_min,
+            real_min,
+            nat_binom,
+            real_floor,
+            int_abs,
+            rat_abs,
+            real_abs,
         } = *vd_instantiation_menu(db);
         Self {
             int_pos: VdMirFuncKey::NormalBasePrefixOpr(int_pos),
@@ -200,7 +253,28 @@ impl VdMirFuncKeyMenu {
             rat_ge: VdMirFuncKey::NormalBaseSeparator(rat_ge),
             real_ge: VdMirFuncKey::NormalBaseSeparator(real_ge),
             in_set: VdMirFuncKey::InSet,
+            nat_dvd: VdMirFuncKey::NormalBaseSeparator(nat_dvd),
+            int_dvd: VdMirFuncKey::NormalBaseSeparator(int_dvd),
+            prop_implies: VdMirFuncKey::NormalBaseSeparator(prop_implies),
+            prop_iff: VdMirFuncKey::NormalBaseSeparator(prop_iff),
             real_sqrt: VdMirFuncKey::NormalBaseSqrt(real_sqrt),
+            real_log: VdMirFuncKey::NormalBaseFunction(real_log),
+            real_exp: VdMirFuncKey::NormalBaseFunction(real_exp),
+            nat_gcd: VdMirFuncKey::NormalBaseFunction(nat_gcd),
+            int_gcd: VdMirFuncKey::NormalBaseFunction(int_gcd),
+            nat_max: VdMirFuncKey::NormalBaseFunction(nat_max),
+            int_max: VdMirFuncKey::NormalBaseFunction(int_max),
+            rat_max: VdMirFuncKey::NormalBaseFunction(rat_max),
+            real_max: VdMirFuncKey::NormalBaseFunction(real_max),
+            nat_min: VdMirFuncKey::NormalBaseFunction(nat_min),
+            int_min: VdMirFuncKey::NormalBaseFunction(int_min),
+            rat_min: VdMirFuncKey::NormalBaseFunction(rat_min),
+            real_min: VdMirFuncKey::NormalBaseFunction(real_min),
+            nat_binom: VdMirFuncKey::NormalBaseFunction(nat_binom),
+            real_floor: VdMirFuncKey::NormalBaseFunction(real_floor),
+            int_abs: VdMirFuncKey::NormalBaseFunction(int_abs),
+            rat_abs: VdMirFuncKey::NormalBaseFunction(rat_abs),
+            real_abs: VdMirFuncKey::NormalBaseFunction(real_abs),
         }
     }
 }
@@ -56,6 +56,9 @@ impl<'a> VdMirExprDisplayTreeBuilder<'a> {
                     VdMirFunc::NormalBaseSqrt(signature) => {
                         format!("sqrt")
                     }
+                    VdMirFunc::NormalBaseFunction(signature) => {
+                        format!("function")
+                    }
                 };
                 (
                     value,
@@ -66,6 +69,21 @@ impl<'a> VdMirExprDisplayTreeBuilder<'a> {
                 )
             }
             VdMirExprData::ItemPath(item_path) => todo!(),
+            VdMirExprData::Quantification {
+                quantifier,
+                domain,
+                body,
+                ..
+            } => (
+                format!("quantification {}", quantifier.unicode()),
+                vec![self.render_expr(domain), self.render_expr(body)],
+            ),
+            VdMirExprData::Limit {
+                approached, body, ..
+            } => (
+                format!("limit"),
+                vec![self.render_expr(approached), self.render_expr(body)],
+            ),
             VdMirExprData::FoldingSeparatedList {
                 leader,
                 ref followers,
@@ -1,6 +1,7 @@
 use crate::coercion::VdMirCoercion;
 
 use super::*;
+use smallvec::SmallVec;
 use visored_entity_path::theorem::VdTheoremPath;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -8,6 +9,7 @@ pub enum VdMirHypothesisConstruction {
     Apply {
         path: VdTheoremPath,
         is_real_coercion: VdMirCoercion,
+        side_conditions: SmallVec<[VdMirHypothesisIdx; 2]>,
     },
     Assume,
     Sorry,
@@ -19,5 +21,6 @@ pub enum VdMirHypothesisConstruction {
     LetAssigned,
     LitnumReduce,
     LitnumBound,
+    Linarith,
     Kurapika,
 }
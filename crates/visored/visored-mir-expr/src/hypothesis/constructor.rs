@@ -3,7 +3,10 @@ use super::{
     VdMirHypothesisIdx,
 };
 use crate::{
-    expr::{VdMirExprArena, VdMirExprArenaRef, VdMirExprData, VdMirExprEntry, VdMirExprIdx},
+    expr::{
+        VdMirExprArena, VdMirExprArenaRef, VdMirExprData, VdMirExprEntry, VdMirExprIdx,
+        VdMirExprIdxRange,
+    },
     hint::VdMirHintArena,
     hypothesis::{VdMirHypothesisEntry, VdMirHypothesisIdxRange},
     region::VdMirExprRegionDataRef,
@@ -125,6 +128,17 @@ impl<'db, Src> VdMirHypothesisConstructor<'db, Src> {
             .alloc_one(VdMirExprEntry::new(data, ty, expected_ty))
     }
 
+    pub fn construct_new_exprs(
+        &mut self,
+        entries: impl IntoIterator<Item = (VdMirExprData, VdType, Option<VdType>)>,
+    ) -> VdMirExprIdxRange {
+        self.expr_arena.alloc_batch(
+            entries
+                .into_iter()
+                .map(|(data, ty, expected_ty)| VdMirExprEntry::new(data, ty, expected_ty)),
+        )
+    }
+
     pub(crate) fn finish(
         self,
     ) -> (
@@ -264,6 +264,11 @@ impl<'db> VdMirExprRegionBuilder<'db> {
                         VdSemLetClausePlaceholderTypeRepr::Expr(ty_expr) => ty_expr.to_vd_mir(self),
                     },
                 },
+                // the error is kept in the resolution; recover by assuming the formula
+                VdSemLetClauseDispatch::Err(_) => VdMirStmtData::Assume {
+                    prop: formula.to_vd_mir(self),
+                    hypothesis_chunk_place: OncePlace::default(),
+                },
             },
             VdSemClauseData::Assume { formula, .. } => VdMirStmtData::Assume {
                 prop: formula.to_vd_mir(self),
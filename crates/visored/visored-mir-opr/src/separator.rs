@@ -25,6 +25,9 @@ pub enum VdMirBaseSeparator {
     Notin,
     SetTimes,
     TensorOtimes,
+    Dvd,
+    Implies,
+    Iff,
 }
 
 impl VdMirBaseSeparator {
@@ -44,6 +47,9 @@ impl VdMirBaseSeparator {
     pub const SUPSETEQQ: Self = VdMirBaseSeparator::Supseteqq;
     pub const SUBSETNEQ: Self = VdMirBaseSeparator::Subsetneq;
     pub const SUPSETNEQ: Self = VdMirBaseSeparator::Supsetneq;
+    pub const DVD: Self = VdMirBaseSeparator::Dvd;
+    pub const IMPLIES: Self = VdMirBaseSeparator::Implies;
+    pub const IFF: Self = VdMirBaseSeparator::Iff;
 }
 
 impl VdMirBaseSeparator {
@@ -68,7 +74,9 @@ impl VdMirBaseSeparator {
             | VdMirBaseSeparator::Subsetneq
             | VdMirBaseSeparator::Supsetneq
             | VdMirBaseSeparator::In
-            | VdMirBaseSeparator::Notin => VdPrecedence::RELATION,
+            | VdMirBaseSeparator::Notin
+            | VdMirBaseSeparator::Dvd => VdPrecedence::RELATION,
+            VdMirBaseSeparator::Implies | VdMirBaseSeparator::Iff => VdPrecedence::LOGIC,
         }
     }
 
@@ -101,7 +109,9 @@ impl VdMirBaseSeparator {
             | VdMirBaseSeparator::Subsetneq
             | VdMirBaseSeparator::Supsetneq
             | VdMirBaseSeparator::In
-            | VdMirBaseSeparator::Notin => VdSeparatorClass::Relation,
+            | VdMirBaseSeparator::Notin
+            | VdMirBaseSeparator::Dvd => VdSeparatorClass::Relation,
+            VdMirBaseSeparator::Implies | VdMirBaseSeparator::Iff => VdSeparatorClass::Logic,
         }
     }
 }
@@ -129,6 +139,9 @@ impl VdMirBaseSeparator {
             VdMirBaseSeparator::Notin => "∉",
             VdMirBaseSeparator::SetTimes => "×",
             VdMirBaseSeparator::TensorOtimes => "⊗",
+            VdMirBaseSeparator::Dvd => "∣",
+            VdMirBaseSeparator::Implies => "⟹",
+            VdMirBaseSeparator::Iff => "⟺",
         }
     }
 
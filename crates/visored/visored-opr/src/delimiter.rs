@@ -1,11 +1,15 @@
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VdBaseLeftDelimiter {
     Lpar,
+    Lfloor,
+    Lvert,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VdBaseRightDelimiter {
     Rpar,
+    Rfloor,
+    Rvert,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -16,14 +20,20 @@ pub enum VdCompositeRightDelimiter {}
 
 impl VdBaseLeftDelimiter {
     pub const LPAR: Self = VdBaseLeftDelimiter::Lpar;
+    pub const LFLOOR: Self = VdBaseLeftDelimiter::Lfloor;
+    pub const LVERT: Self = VdBaseLeftDelimiter::Lvert;
 }
 
 impl VdBaseRightDelimiter {
     pub const RPAR: Self = VdBaseRightDelimiter::Rpar;
+    pub const RFLOOR: Self = VdBaseRightDelimiter::Rfloor;
+    pub const RVERT: Self = VdBaseRightDelimiter::Rvert;
 
     pub fn latex_code(&self) -> &'static str {
         match self {
             VdBaseRightDelimiter::Rpar => ")",
+            VdBaseRightDelimiter::Rfloor => "\\rfloor",
+            VdBaseRightDelimiter::Rvert => "\\rvert",
         }
     }
 }
@@ -32,6 +42,8 @@ impl VdBaseLeftDelimiter {
     pub fn latex_code(self) -> &'static str {
         match self {
             VdBaseLeftDelimiter::Lpar => "(",
+            VdBaseLeftDelimiter::Lfloor => "\\lfloor",
+            VdBaseLeftDelimiter::Lvert => "\\lvert",
         }
     }
 }
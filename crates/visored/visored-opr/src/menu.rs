@@ -21,6 +21,10 @@ pub struct VdOprMenu {
     pub le: VdBaseSeparator,
     pub ge: VdBaseSeparator,
     pub r#in: VdBaseSeparator,
+    pub mid: VdBaseSeparator,
+    pub to: VdBaseSeparator,
+    pub implies: VdBaseSeparator,
+    pub iff: VdBaseSeparator,
 }
 
 #[memo(return_ref)]
@@ -38,5 +42,9 @@ pub fn vd_opr_menu(db: &EternerDb) -> VdOprMenu {
         le: VdBaseSeparator::LE,
         ge: VdBaseSeparator::GE,
         r#in: VdBaseSeparator::IN,
+        mid: VdBaseSeparator::MID,
+        to: VdBaseSeparator::TO,
+        implies: VdBaseSeparator::IMPLIES,
+        iff: VdBaseSeparator::IFF,
     }
 }
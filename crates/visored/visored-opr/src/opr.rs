@@ -21,6 +21,14 @@ impl VdBaseOpr {
     pub const DIFFERENTIAL: Self = Self::Prefix(VdBasePrefixOpr::DIFFERENTIAL);
     pub const SUM: Self = Self::Prefix(VdBasePrefixOpr::SUM);
     pub const PROD: Self = Self::Prefix(VdBasePrefixOpr::PROD);
+    pub const LOG: Self = Self::Prefix(VdBasePrefixOpr::LOG);
+    pub const EXP: Self = Self::Prefix(VdBasePrefixOpr::EXP);
+    pub const GCD: Self = Self::Prefix(VdBasePrefixOpr::GCD);
+    pub const MAX: Self = Self::Prefix(VdBasePrefixOpr::MAX);
+    pub const MIN: Self = Self::Prefix(VdBasePrefixOpr::MIN);
+    pub const LIM: Self = Self::Prefix(VdBasePrefixOpr::LIM);
+    pub const FORALL: Self = Self::Prefix(VdBasePrefixOpr::FORALL);
+    pub const EXISTS: Self = Self::Prefix(VdBasePrefixOpr::EXISTS);
 
     pub const SUB: Self = Self::Binary(VdBaseBinaryOpr::SUB);
     pub const DIV: Self = Self::Binary(VdBaseBinaryOpr::DIV);
@@ -1,7 +1,10 @@
 use eterned::db::EternerDb;
 use lisp_csv::expr::{LpCsvExpr, LpCsvExprData};
 
-use crate::precedence::{VdPrecedence, VdPrecedenceRange};
+use crate::{
+    precedence::{VdPrecedence, VdPrecedenceRange},
+    separator::VdBaseSeparator,
+};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 pub enum VdBasePrefixOpr {
@@ -11,6 +14,14 @@ pub enum VdBasePrefixOpr {
     Differential,
     Sum,
     Prod,
+    Log,
+    Exp,
+    Gcd,
+    Max,
+    Min,
+    Lim,
+    Forall,
+    Exists,
 }
 
 impl VdBasePrefixOpr {
@@ -20,6 +31,14 @@ impl VdBasePrefixOpr {
     pub const DIFFERENTIAL: Self = Self::Differential;
     pub const SUM: Self = Self::Sum;
     pub const PROD: Self = Self::Prod;
+    pub const LOG: Self = Self::Log;
+    pub const EXP: Self = Self::Exp;
+    pub const GCD: Self = Self::Gcd;
+    pub const MAX: Self = Self::Max;
+    pub const MIN: Self = Self::Min;
+    pub const LIM: Self = Self::Lim;
+    pub const FORALL: Self = Self::Forall;
+    pub const EXISTS: Self = Self::Exists;
 
     pub fn precedence(self) -> VdPrecedence {
         match self {
@@ -28,13 +47,44 @@ impl VdBasePrefixOpr {
             VdBasePrefixOpr::Differential => VdPrecedence::DIFFERENTIAL,
             VdBasePrefixOpr::Sum => todo!(),
             VdBasePrefixOpr::Prod => todo!(),
+            VdBasePrefixOpr::Log
+            | VdBasePrefixOpr::Exp
+            | VdBasePrefixOpr::Gcd
+            | VdBasePrefixOpr::Max
+            | VdBasePrefixOpr::Min => VdPrecedence::FUNCTION_PREFIX,
+            VdBasePrefixOpr::Lim => VdPrecedence::REDUCE_PREFIX,
+            VdBasePrefixOpr::Forall | VdBasePrefixOpr::Exists => VdPrecedence::QUANTIFIER,
+        }
+    }
+
+    /// the separator after the bound variable, `\in` for quantifiers and `\to` for limits
+    pub fn binder_separator(self) -> Option<VdBaseSeparator> {
+        match self {
+            VdBasePrefixOpr::Forall | VdBasePrefixOpr::Exists => Some(VdBaseSeparator::In),
+            VdBasePrefixOpr::Lim => Some(VdBaseSeparator::To),
+            _ => None,
         }
     }
 }
 
 impl VdBasePrefixOpr {
     pub fn latex_code(self) -> &'static str {
-        todo!()
+        match self {
+            VdBasePrefixOpr::Pos => "+",
+            VdBasePrefixOpr::Neg => "-",
+            VdBasePrefixOpr::Integral => "\\int",
+            VdBasePrefixOpr::Differential => "d",
+            VdBasePrefixOpr::Sum => "\\sum",
+            VdBasePrefixOpr::Prod => "\\prod",
+            VdBasePrefixOpr::Log => "\\log",
+            VdBasePrefixOpr::Exp => "\\exp",
+            VdBasePrefixOpr::Gcd => "\\gcd",
+            VdBasePrefixOpr::Max => "\\max",
+            VdBasePrefixOpr::Min => "\\min",
+            VdBasePrefixOpr::Lim => "\\lim",
+            VdBasePrefixOpr::Forall => "\\forall",
+            VdBasePrefixOpr::Exists => "\\exists",
+        }
     }
 
     pub fn precedence_range(self) -> VdPrecedenceRange {
@@ -67,6 +117,11 @@ impl VdBasePrefixOpr {
         match ident.as_str() {
             "pos" => Self::Pos,
             "neg" => Self::Neg,
+            "log" => Self::Log,
+            "exp" => Self::Exp,
+            "gcd" => Self::Gcd,
+            "max" => Self::Max,
+            "min" => Self::Min,
             _ => todo!(),
         }
     }
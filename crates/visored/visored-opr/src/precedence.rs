@@ -13,6 +13,7 @@ impl std::fmt::Debug for VdPrecedence {
             Self::MIN => write!(f, "VdPrecedence::MIN"),
             Self::SEMICOLON => write!(f, "VdPrecedence::SEMICOLON"),
             Self::COMMA => write!(f, "VdPrecedence::COMMA"),
+            Self::LOGIC => write!(f, "VdPrecedence::LOGIC"),
             Self::RELATION => write!(f, "VdPrecedence::EQ"),
             Self::ADD_SUB => write!(f, "VdPrecedence::ADD"),
             Self::MUL_DIV => write!(f, "VdPrecedence::MUL"),
@@ -30,6 +31,7 @@ impl std::fmt::Display for VdPrecedence {
             Self::MIN => write!(f, "VdPrecedence::MIN"),
             Self::SEMICOLON => write!(f, "VdPrecedence::SEMICOLON"),
             Self::COMMA => write!(f, "VdPrecedence::COMMA"),
+            Self::LOGIC => write!(f, "VdPrecedence::LOGIC"),
             Self::RELATION => write!(f, "VdPrecedence::EQ"),
             Self::ADD_SUB => write!(f, "VdPrecedence::ADD"),
             Self::MUL_DIV => write!(f, "VdPrecedence::MUL"),
@@ -44,6 +46,10 @@ impl std::fmt::Display for VdPrecedence {
 impl VdPrecedence {
     pub const MIN: Self = VdPrecedence(0);
     pub const INCOMPLTE_DELIMITED: Self = VdPrecedence(10);
+    /// `\forall` and `\exists` take everything up to the closing delimiter
+    pub const QUANTIFIER: Self = VdPrecedence(200);
+    /// `\implies` and `\iff`
+    pub const LOGIC: Self = VdPrecedence(300);
     pub const RELATION: Self = VdPrecedence(500);
     pub const SEMICOLON: Self = VdPrecedence(1000);
     pub const COMMA: Self = VdPrecedence(5000);
@@ -52,6 +58,8 @@ impl VdPrecedence {
     /// `reduce` is from `map reduce`, examples are like sum/integral/prod
     pub const REDUCE_PREFIX: Self = VdPrecedence(25000);
     pub const DIFFERENTIAL: Self = VdPrecedence(25000);
+    /// `\log`, `\exp`, `\gcd`, `\max`, `\min` applied without parentheses, like `\log x`
+    pub const FUNCTION_PREFIX: Self = VdPrecedence(25000);
     pub const MUL_DIV: Self = VdPrecedence(30000);
     pub const SPACE: Self = VdPrecedence(100000);
     pub const ATOM: Self = VdPrecedence(u64::MAX - 1);
@@ -64,6 +72,14 @@ fn vd_precedence_works() {
     assert!(VdPrecedence::INCOMPLTE_DELIMITED < VdPrecedence::SEMICOLON);
     // a, b < 1
     assert!(VdPrecedence::RELATION < VdPrecedence::COMMA);
+    // a < b \implies b > a
+    assert!(VdPrecedence::LOGIC < VdPrecedence::RELATION);
+    // \forall x, P \implies Q
+    assert!(VdPrecedence::QUANTIFIER < VdPrecedence::LOGIC);
+    // \log x + 1
+    assert!(VdPrecedence::ADD_SUB < VdPrecedence::FUNCTION_PREFIX);
+    // \log x y
+    assert!(VdPrecedence::FUNCTION_PREFIX < VdPrecedence::SPACE);
     // \sum a+ b
     assert!(VdPrecedence::ADD_SUB < VdPrecedence::REDUCE_PREFIX);
     // \sum a b
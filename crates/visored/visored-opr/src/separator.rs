@@ -28,6 +28,11 @@ pub enum VdBaseSeparator {
     Notin,
     Times,
     Otimes,
+    Mid,
+    Equiv,
+    To,
+    Implies,
+    Iff,
 }
 
 impl VdBaseSeparator {
@@ -45,6 +50,11 @@ impl VdBaseSeparator {
     pub const GE: Self = Self::Ge;
     pub const IN: Self = Self::In;
     pub const TIMES: Self = Self::Times;
+    pub const MID: Self = Self::Mid;
+    pub const EQUIV: Self = Self::Equiv;
+    pub const TO: Self = Self::To;
+    pub const IMPLIES: Self = Self::Implies;
+    pub const IFF: Self = Self::Iff;
 }
 
 impl VdBaseSeparator {
@@ -73,7 +83,12 @@ impl VdBaseSeparator {
             | VdBaseSeparator::Subsetneq
             | VdBaseSeparator::Supsetneq
             | VdBaseSeparator::In
-            | VdBaseSeparator::Notin => VdSeparatorClass::Relation,
+            | VdBaseSeparator::Notin
+            | VdBaseSeparator::Mid
+            | VdBaseSeparator::Equiv => VdSeparatorClass::Relation,
+            VdBaseSeparator::To | VdBaseSeparator::Implies | VdBaseSeparator::Iff => {
+                VdSeparatorClass::Logic
+            }
         }
     }
 
@@ -90,7 +105,7 @@ impl VdBaseSeparator {
     pub fn precedence(self) -> VdPrecedence {
         match self {
             VdBaseSeparator::Space => VdPrecedence::SPACE,
-            VdBaseSeparator::Comma => todo!(),
+            VdBaseSeparator::Comma => VdPrecedence::COMMA,
             VdBaseSeparator::Semicolon => VdPrecedence::SEMICOLON,
             VdBaseSeparator::Add => VdPrecedence::ADD_SUB,
             VdBaseSeparator::Mul | VdBaseSeparator::Times | VdBaseSeparator::Otimes => {
@@ -112,7 +127,12 @@ impl VdBaseSeparator {
             | VdBaseSeparator::Subsetneq
             | VdBaseSeparator::Supsetneq
             | VdBaseSeparator::In
-            | VdBaseSeparator::Notin => VdPrecedence::RELATION,
+            | VdBaseSeparator::Notin
+            | VdBaseSeparator::Mid
+            | VdBaseSeparator::Equiv => VdPrecedence::RELATION,
+            VdBaseSeparator::To | VdBaseSeparator::Implies | VdBaseSeparator::Iff => {
+                VdPrecedence::LOGIC
+            }
         }
     }
 
@@ -142,6 +162,11 @@ impl VdBaseSeparator {
             VdBaseSeparator::Notin => "\\notin",
             VdBaseSeparator::Times => "\\times",
             VdBaseSeparator::Otimes => "\\otimes",
+            VdBaseSeparator::Mid => "\\mid",
+            VdBaseSeparator::Equiv => "\\equiv",
+            VdBaseSeparator::To => "\\to",
+            VdBaseSeparator::Implies => "\\implies",
+            VdBaseSeparator::Iff => "\\iff",
         }
     }
 
@@ -171,12 +196,18 @@ impl VdBaseSeparator {
             VdBaseSeparator::Notin => "∉",
             VdBaseSeparator::Times => "×",
             VdBaseSeparator::Otimes => "⊗",
+            VdBaseSeparator::Mid => "∣",
+            VdBaseSeparator::Equiv => "≡",
+            VdBaseSeparator::To => "→",
+            VdBaseSeparator::Implies => "⟹",
+            VdBaseSeparator::Iff => "⟺",
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VdSeparatorClass {
+    Logic,
     Relation,
     Space,
     Comma,
@@ -186,6 +217,7 @@ pub enum VdSeparatorClass {
 }
 
 impl VdSeparatorClass {
+    pub const LOGIC: Self = Self::Logic;
     pub const RELATION: Self = Self::Relation;
     pub const SPACE: Self = Self::Space;
     pub const COMMA: Self = Self::Comma;
@@ -203,6 +235,7 @@ impl VdSeparatorClass {
             VdSeparatorClass::Add => VdPrecedence::ADD_SUB,
             VdSeparatorClass::Mul => VdPrecedence::MUL_DIV,
             VdSeparatorClass::Relation => VdPrecedence::RELATION,
+            VdSeparatorClass::Logic => VdPrecedence::LOGIC,
         }
     }
 
@@ -218,6 +251,7 @@ impl VdSeparatorClass {
             VdSeparatorClass::Add => VdPrecedenceRange::NoLess(VdPrecedence::ADD_SUB),
             VdSeparatorClass::Mul => VdPrecedenceRange::NoLess(VdPrecedence::MUL_DIV),
             VdSeparatorClass::Relation => VdPrecedenceRange::NoLess(VdPrecedence::RELATION),
+            VdSeparatorClass::Logic => VdPrecedenceRange::NoLess(VdPrecedence::LOGIC),
         }
     }
 
@@ -229,6 +263,7 @@ impl VdSeparatorClass {
             VdSeparatorClass::Add => VdPrecedenceRange::Greater(VdPrecedence::ADD_SUB),
             VdSeparatorClass::Mul => VdPrecedenceRange::Greater(VdPrecedence::MUL_DIV),
             VdSeparatorClass::Relation => VdPrecedenceRange::Greater(VdPrecedence::RELATION),
+            VdSeparatorClass::Logic => VdPrecedenceRange::Greater(VdPrecedence::LOGIC),
         }
     }
 }
@@ -258,6 +293,11 @@ impl VdBaseSeparator {
             "times" => VdBaseSeparator::Times,
             "otimes" => VdBaseSeparator::Otimes,
             "cdot" => VdBaseSeparator::Cdot,
+            "mid" => VdBaseSeparator::Mid,
+            "equiv" => VdBaseSeparator::Equiv,
+            "to" => VdBaseSeparator::To,
+            "implies" => VdBaseSeparator::Implies,
+            "iff" => VdBaseSeparator::Iff,
             _ => todo!(),
         }
     }
@@ -20,12 +20,15 @@ pub struct VdPipelineConfig {
     pub index: usize,
     pub data: VdPipelineConfigData,
     pub routing_resolved: VdPipelineModelRoutingResolved,
+    /// `None` means the builtin stages
+    pub stages_resolved: Option<Vec<VdPipelineStageResolved>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct VdPipelineConfigData {
-    // #[serde(default)]
-    // stages: VdPipelineStagesConfig,
+    /// the builtin stages are used if absent
+    #[serde(default)]
+    pub stages: Option<VdPipelineStagesConfig>,
     #[serde(default = "default_cache_dir")]
     pub cache_dir: RelativePathBuf,
     pub output_name: String,
@@ -42,13 +45,25 @@ fn default_true() -> bool {
 }
 
 impl VdPipelineConfig {
-    pub fn new(src_file_path: PathBuf, index: usize, data: VdPipelineConfigData) -> Self {
-        Self {
+    pub fn new(
+        src_file_path: PathBuf,
+        index: usize,
+        data: VdPipelineConfigData,
+    ) -> VdPipelineResult<Self> {
+        let stages_resolved = match data.stages {
+            Some(ref stages) => Some(stages.resolve(
+                &data.presets,
+                src_file_path.parent().unwrap_or(Path::new("")),
+            )?),
+            None => None,
+        };
+        Ok(Self {
             src_file_path,
             index,
             routing_resolved: VdPipelineModelRoutingResolved::new(&data.routing, &data.presets),
+            stages_resolved,
             data,
-        }
+        })
     }
 
     pub fn from_yaml_file(path: impl AsRef<Path>) -> VdPipelineResult<Vec<Arc<Self>>> {
@@ -63,7 +78,7 @@ impl VdPipelineConfig {
             let serde_data = VdPipelineConfigData::deserialize(document).map_err(|e| {
                 VdPipelineError::ConfigParsing(format!("Failed to parse YAML document: {}", e))
             })?;
-            configs.push(Arc::new(Self::new(path.to_path_buf(), index, serde_data)));
+            configs.push(Arc::new(Self::new(path.to_path_buf(), index, serde_data)?));
         }
 
         Ok(configs)
@@ -77,56 +92,107 @@ fn vd_pipeline_config_from_yaml_file_works() {
     assert_eq!(
         &configs,
         &[
-            Arc::new(VdPipelineConfig::new(
-                PathBuf::from("config-examples/standard.yaml"),
-                0,
-                VdPipelineConfigData {
-                    cache_dir: default_cache_dir(),
-                    output_name: "baseline".to_string(),
-                    presets: vec![VdPipelineModelPreset {
-                        name: "gpt-4o".to_string(),
-                        model: AllLlmModel::GPT_4O,
-                    },],
-                    routing: VdPipelineModelRouting {
-                        solver: VdPipelineModelSolverRouting {
-                            mathematical_reasoning: "gpt-4o".to_string(),
-                            mathematical_understanding: "gpt-4o".to_string(),
-                            latex_rewriter: "gpt-4o".to_string(),
-                        },
-                        verifier: VdPipelineModelVerifierRouting {
-                            snl_dispatcher: "gpt-4o".to_string(),
+            Arc::new(
+                VdPipelineConfig::new(
+                    PathBuf::from("config-examples/standard.yaml"),
+                    0,
+                    VdPipelineConfigData {
+                        stages: None,
+                        cache_dir: default_cache_dir(),
+                        output_name: "baseline".to_string(),
+                        presets: vec![VdPipelineModelPreset {
+                            name: "gpt-4o".to_string(),
+                            model: AllLlmModel::GPT_4O,
+                        },],
+                        routing: VdPipelineModelRouting {
+                            solver: VdPipelineModelSolverRouting {
+                                mathematical_reasoning: "gpt-4o".to_string(),
+                                mathematical_understanding: "gpt-4o".to_string(),
+                                latex_rewriter: "gpt-4o".to_string(),
+                            },
+                            verifier: VdPipelineModelVerifierRouting {
+                                snl_dispatcher: "gpt-4o".to_string(),
+                            },
                         },
                     },
-                },
-            )),
-            Arc::new(VdPipelineConfig::new(
-                PathBuf::from("config-examples/standard.yaml"),
-                1,
-                VdPipelineConfigData {
-                    cache_dir: default_cache_dir(),
-                    output_name: "standard".to_string(),
-                    presets: vec![
-                        VdPipelineModelPreset {
-                            name: "gemini-1.5-flash".to_string(),
-                            model: AllLlmModel::GEMINI_1_5_FLASH,
-                        },
-                        VdPipelineModelPreset {
-                            name: "gemini-1.5-pro".to_string(),
-                            model: AllLlmModel::GEMINI_1_5_PRO,
-                        },
-                    ],
-                    routing: VdPipelineModelRouting {
-                        solver: VdPipelineModelSolverRouting {
-                            mathematical_reasoning: "gemini-1.5-pro".to_string(),
-                            mathematical_understanding: "gemini-1.5-pro".to_string(),
-                            latex_rewriter: "gemini-1.5-flash".to_string(),
-                        },
-                        verifier: VdPipelineModelVerifierRouting {
-                            snl_dispatcher: "gemini-1.5-flash".to_string(),
+                )
+                .unwrap()
+            ),
+            Arc::new(
+                VdPipelineConfig::new(
+                    PathBuf::from("config-examples/standard.yaml"),
+                    1,
+                    VdPipelineConfigData {
+                        stages: None,
+                        cache_dir: default_cache_dir(),
+                        output_name: "standard".to_string(),
+                        presets: vec![
+                            VdPipelineModelPreset {
+                                name: "gemini-1.5-flash".to_string(),
+                                model: AllLlmModel::GEMINI_1_5_FLASH,
+                            },
+                            VdPipelineModelPreset {
+                                name: "gemini-1.5-pro".to_string(),
+                                model: AllLlmModel::GEMINI_1_5_PRO,
+                            },
+                        ],
+                        routing: VdPipelineModelRouting {
+                            solver: VdPipelineModelSolverRouting {
+                                mathematical_reasoning: "gemini-1.5-pro".to_string(),
+                                mathematical_understanding: "gemini-1.5-pro".to_string(),
+                                latex_rewriter: "gemini-1.5-flash".to_string(),
+                            },
+                            verifier: VdPipelineModelVerifierRouting {
+                                snl_dispatcher: "gemini-1.5-flash".to_string(),
+                            },
                         },
                     },
-                },
-            )),
+                )
+                .unwrap()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn vd_pipeline_config_with_stages_from_yaml_file_works() {
+    let configs = VdPipelineConfig::from_yaml_file("config-examples/staged.yaml").unwrap();
+    assert_eq!(configs.len(), 1);
+    let stages = configs[0].stages_resolved.as_ref().unwrap();
+    assert_eq!(
+        stages
+            .iter()
+            .map(|stage| (stage.name.as_str(), stage.kind, stage.inputs.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "natural-solution",
+                VdPipelineStageKind::QuestionToNaturalSolution,
+                vec![]
+            ),
+            (
+                "details",
+                VdPipelineStageKind::NaturalSolutionToDetails,
+                vec![0]
+            ),
+            (
+                "lean-sketch",
+                VdPipelineStageKind::DetailsToLeanSketch,
+                vec![1]
+            ),
+            (
+                "lean-proof",
+                VdPipelineStageKind::LeanSketchCompletion,
+                vec![1, 2]
+            ),
+        ]
+    );
+    assert_eq!(stages[3].model.model, AllLlmModel::GEMINI_1_5_PRO);
+    assert_eq!(
+        stages[3].acceptance,
+        vec![
+            VdPipelineStageAcceptance::NoSorry,
+            VdPipelineStageAcceptance::LeanElaborates
         ]
     );
 }
@@ -8,3 +8,29 @@ pub struct VdPipelineModelPreset {
 }
 
 pub type VdPipelineModelPresets = Vec<VdPipelineModelPreset>;
+
+#[test]
+fn vd_pipeline_model_presets_deserialization_works() {
+    let presets: VdPipelineModelPresets = serde_yaml::from_str(
+        r#"
+- model: gpt-4o
+  name: gpt-4o
+- model: llama-3.1-8b-instruct
+  name: local
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        presets,
+        vec![
+            VdPipelineModelPreset {
+                name: "gpt-4o".to_string(),
+                model: AllLlmModel::GPT_4O,
+            },
+            VdPipelineModelPreset {
+                name: "local".to_string(),
+                model: AllLlmModel::LLAMA_3_1_8B_INSTRUCT,
+            },
+        ]
+    );
+}
@@ -1,17 +1,257 @@
 use super::*;
+use std::collections::HashMap;
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+/// An ordered graph of stages.
+///
+/// Each stage reads the question and the outputs of earlier stages only,
+/// so the order in the config is a topological order.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
 pub struct VdPipelineStagesConfig {
-    /// Given a problem, generate a natural solution.
+    pub stages: Vec<VdPipelineStageConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VdPipelineStageConfig {
+    pub name: String,
+    pub kind: VdPipelineStageKind,
+    /// Names of the earlier stages this stage reads.
+    ///
+    /// Defaults to the previous stage, or nothing for the first stage.
     #[serde(default)]
-    question_to_natural_solution_stage: (),
-    /// Given a natural solution, generate a detailed natural solution.
+    pub inputs: Option<Vec<String>>,
+    /// name of a model preset
+    pub model: String,
+    /// path of the prompt template, relative to the config file
+    pub prompt: RelativePathBuf,
+    /// how many times the stage is re-asked after a rejected output
     #[serde(default)]
-    natural_solution_to_details_stage: (),
-    /// Given a detailed natural solution, generate a natural language explanation.
+    pub retries: usize,
     #[serde(default)]
-    details_to_lean_sketch_stage: (),
+    pub acceptance: Vec<VdPipelineStageAcceptance>,
+}
+
+/// The kind decides what's extracted from the response.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum VdPipelineStageKind {
+    /// Given a problem, generate a natural solution.
+    QuestionToNaturalSolution,
+    /// Given a natural solution, generate a detailed natural solution.
+    NaturalSolutionToDetails,
+    /// Given a detailed natural solution, generate a lean sketch.
+    DetailsToLeanSketch,
     /// Given a lean sketch, generate a lean proof.
-    #[serde(default)]
-    lean_sketch_completion_stage: (),
+    LeanSketchCompletion,
+}
+
+impl VdPipelineStageKind {
+    pub fn is_lean(self) -> bool {
+        match self {
+            VdPipelineStageKind::QuestionToNaturalSolution
+            | VdPipelineStageKind::NaturalSolutionToDetails => false,
+            VdPipelineStageKind::DetailsToLeanSketch
+            | VdPipelineStageKind::LeanSketchCompletion => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum VdPipelineStageAcceptance {
+    /// the extracted output isn't blank
+    NonEmpty,
+    /// the extracted output doesn't contain `sorry`
+    NoSorry,
+    /// Lean elaborates the extracted output without errors
+    LeanElaborates,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VdPipelineStageResolved {
+    pub name: String,
+    pub kind: VdPipelineStageKind,
+    /// indices of earlier stages
+    pub inputs: Vec<usize>,
+    pub model: VdPipelineModelPreset,
+    pub prompt_template: String,
+    pub retries: usize,
+    pub acceptance: Vec<VdPipelineStageAcceptance>,
+}
+
+pub const QUESTION_PLACEHOLDER: &str = "question";
+/// the output of the last input, or the question for a stage without inputs
+pub const INPUT_PLACEHOLDER: &str = "input";
+
+impl VdPipelineStagesConfig {
+    /// Checks the graph and reads the prompt templates.
+    ///
+    /// Prompt paths are relative to `config_dir`.
+    pub fn resolve(
+        &self,
+        presets: &VdPipelineModelPresets,
+        config_dir: &Path,
+    ) -> VdPipelineResult<Vec<VdPipelineStageResolved>> {
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        let mut resolved_stages = vec![];
+        for (index, stage) in self.stages.iter().enumerate() {
+            let error = |message: String| VdPipelineError::InvalidStage {
+                stage: stage.name.clone(),
+                message,
+            };
+            if [QUESTION_PLACEHOLDER, INPUT_PLACEHOLDER].contains(&stage.name.as_str()) {
+                return Err(error(format!("`{}` is reserved", stage.name)));
+            }
+            let inputs = match stage.inputs {
+                Some(ref inputs) => inputs
+                    .iter()
+                    .map(|input| {
+                        indices
+                            .get(input.as_str())
+                            .copied()
+                            .ok_or_else(|| error(format!("input `{input}` isn't an earlier stage")))
+                    })
+                    .collect::<VdPipelineResult<Vec<_>>>()?,
+                None => index.checked_sub(1).into_iter().collect(),
+            };
+            let model = presets
+                .iter()
+                .find(|preset| preset.name == stage.model)
+                .ok_or_else(|| error(format!("preset `{}` not found", stage.model)))?
+                .clone();
+            let prompt_path = stage.prompt.to_logical_path(config_dir);
+            let prompt_template = std::fs::read_to_string(&prompt_path)
+                .map_err(|e| VdPipelineError::Io(prompt_path.clone(), e))?;
+            for placeholder in template_placeholders(&prompt_template) {
+                if placeholder != QUESTION_PLACEHOLDER
+                    && placeholder != INPUT_PLACEHOLDER
+                    && !inputs
+                        .iter()
+                        .any(|&input| self.stages[input].name == placeholder)
+                {
+                    return Err(error(format!(
+                        "placeholder `{{{{{placeholder}}}}}` in `{}` is neither `question`, `input` nor an input stage",
+                        prompt_path.display()
+                    )));
+                }
+            }
+            if indices.insert(&stage.name, index).is_some() {
+                return Err(error("duplicate stage name".to_string()));
+            }
+            resolved_stages.push(VdPipelineStageResolved {
+                name: stage.name.clone(),
+                kind: stage.kind,
+                inputs,
+                model,
+                prompt_template,
+                retries: stage.retries,
+                acceptance: stage.acceptance.clone(),
+            });
+        }
+        Ok(resolved_stages)
+    }
+}
+
+impl VdPipelineStageResolved {
+    /// Substitutes `{{question}}`, `{{input}}` and `{{<input stage name>}}`.
+    ///
+    /// `outputs` are the accepted outputs of the stages so far.
+    pub fn render_prompt(
+        &self,
+        stages: &[VdPipelineStageResolved],
+        question: &str,
+        outputs: &[String],
+    ) -> String {
+        let mut prompt = self
+            .prompt_template
+            .replace(&format!("{{{{{QUESTION_PLACEHOLDER}}}}}"), question);
+        for &input in &self.inputs {
+            prompt = prompt.replace(&format!("{{{{{}}}}}", stages[input].name), &outputs[input]);
+        }
+        let input = match self.inputs.last() {
+            Some(&input) => &outputs[input],
+            None => question,
+        };
+        prompt.replace(&format!("{{{{{INPUT_PLACEHOLDER}}}}}"), input)
+    }
+}
+
+/// names like `{{details}}`; other double braces, such as in `^{{2}}`, are left alone
+fn template_placeholders(template: &str) -> impl Iterator<Item = &str> {
+    template.split("{{").skip(1).filter_map(|s| {
+        let name = &s[..s.find("}}")?];
+        (name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        .then_some(name)
+    })
+}
+
+#[test]
+fn vd_pipeline_stages_config_resolve_works() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("solve.md"), "Solve:\n{{question}}\n").unwrap();
+    std::fs::write(
+        dir.path().join("sketch.md"),
+        "Problem:\n{{question}}\nSolution:\n{{solution}}\nFormalize:\n{{input}}\n",
+    )
+    .unwrap();
+    let presets = vec![VdPipelineModelPreset {
+        name: "gpt-4o".to_string(),
+        model: AllLlmModel::GPT_4O,
+    }];
+    let stages: VdPipelineStagesConfig = serde_yaml::from_str(
+        r#"
+- name: solution
+  kind: question-to-natural-solution
+  model: gpt-4o
+  prompt: solve.md
+  acceptance: [non-empty]
+- name: details
+  kind: natural-solution-to-details
+  model: gpt-4o
+  prompt: solve.md
+- name: sketch
+  kind: details-to-lean-sketch
+  inputs: [solution, details]
+  model: gpt-4o
+  prompt: sketch.md
+  retries: 2
+  acceptance: [lean-elaborates]
+"#,
+    )
+    .unwrap();
+    let resolved = stages.resolve(&presets, dir.path()).unwrap();
+    assert_eq!(resolved.len(), 3);
+    assert_eq!(resolved[0].inputs, Vec::<usize>::new());
+    assert_eq!(resolved[1].inputs, vec![0]);
+    assert_eq!(resolved[2].inputs, vec![0, 1]);
+    assert_eq!(resolved[2].retries, 2);
+    assert_eq!(
+        resolved[2].acceptance,
+        vec![VdPipelineStageAcceptance::LeanElaborates]
+    );
+    assert_eq!(
+        resolved[2].render_prompt(
+            &resolved,
+            "1+1=2",
+            &["trivial".to_string(), "by computation".to_string()]
+        ),
+        "Problem:\n1+1=2\nSolution:\ntrivial\nFormalize:\nby computation\n"
+    );
+
+    // a stage can only read earlier stages
+    let mut stages = stages;
+    stages.stages[2].inputs = Some(vec!["proof".to_string()]);
+    assert!(matches!(
+        stages.resolve(&presets, dir.path()),
+        Err(VdPipelineError::InvalidStage { ref stage, .. }) if stage == "sketch"
+    ));
+    // `{{solution}}` isn't available without `solution` being an input
+    stages.stages[2].inputs = Some(vec!["details".to_string()]);
+    assert!(matches!(
+        stages.resolve(&presets, dir.path()),
+        Err(VdPipelineError::InvalidStage { .. })
+    ));
 }
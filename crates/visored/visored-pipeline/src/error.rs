@@ -1,4 +1,6 @@
+use all_llms::error::AnyLlmError;
 use std::path::PathBuf;
+use visored_baseq_elaborator::theorem::index::VdBsqTheoremIndexError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum VdPipelineError {
@@ -6,6 +8,12 @@ pub enum VdPipelineError {
     ConfigParsing(String),
     #[error("IO error for file: {0}, error: {1}")]
     Io(PathBuf, std::io::Error),
+    #[error("invalid stage `{stage}`: {message}")]
+    InvalidStage { stage: String, message: String },
+    #[error("LLM error: {0}")]
+    Llm(#[from] AnyLlmError),
+    #[error("theorem index error: {0}")]
+    TheoremIndex(#[from] VdBsqTheoremIndexError),
 }
 
 pub type VdPipelineResult<T> = Result<T, VdPipelineError>;
@@ -1,6 +1,10 @@
+mod stages;
 mod transformations;
 
-use crate::*;
+use crate::{
+    tracker::{VdPipelineOutcome, VdPipelineStageRecord},
+    *,
+};
 use all_llms::transformation::AllLlmsStringTransformationRecord;
 use all_llms::{model::AllLlmModel, AllLlmsClient};
 use eterned::db::EternerDb;
@@ -36,6 +40,7 @@ pub struct VdPipelineExecutor<'a, 'db> {
     elaborated_proof: Option<(Vec<AllLlmsStringTransformationRecord>, String)>,
     regularized_proof: Option<(Vec<AllLlmsStringTransformationRecord>, String)>,
     lean4_code: Option<String>,
+    stage_records: Option<Vec<VdPipelineStageRecord>>,
 }
 
 impl<'a, 'db> VdPipelineExecutor<'a, 'db> {
@@ -67,16 +72,21 @@ impl<'a, 'db> VdPipelineExecutor<'a, 'db> {
             elaborated_proof: None,
             regularized_proof: None,
             lean4_code: None,
+            stage_records: None,
         }
     }
 }
 
 impl<'a, 'db> VdPipelineExecutor<'a, 'db> {
-    pub(crate) fn execute_all(&mut self) {
-        self.query_raw_proof();
+    pub(crate) fn execute_all(&mut self) -> VdPipelineResult<()> {
+        match self.config.stages_resolved {
+            Some(ref stages) => self.stage_records = Some(self.execute_stages(stages)?),
+            None => self.query_raw_proof()?,
+        }
+        Ok(())
     }
 
-    fn query_raw_proof(&mut self) {
+    fn query_raw_proof(&mut self) -> VdPipelineResult<()> {
         let prompt = format!(
             r#"Please provide the raw solution to the following problem. The solution should be a concise and complete mathematical proof written in LaTeX. If something is straightforward and trivial as seen by college level student, just say that it's trivial and don't ever try to say unnecessary things.
 
@@ -197,7 +207,7 @@ We have $x^2 \ge 0$ because these are real numbers.
         let regularized_proof = extract_proof(&regularized_proof);
         self.regularized_proof = Some((transformations, regularized_proof.clone()));
         let file_path = LxFilePath::new(PathBuf::from(file!()), self.db);
-        let session = &VdBsqSession::new(self.db);
+        let session = &VdBsqSession::new(self.db, self.specs_dir)?;
         let tracker = VdLeanTranspilationTracker::new(
             LxDocumentBodyInput {
                 specs_dir: self.specs_dir,
@@ -232,24 +242,20 @@ We have $x^2 \ge 0$ because these are real numbers.
         diff_write(&lean4_code_path, self.lean4_code.as_ref().unwrap(), true);
         // p!(lake_lean(&lean4_code_path));
         // todo!("compile lean4 code");
+        Ok(())
     }
 
-    pub(crate) fn finish(
-        self,
-    ) -> (
-        String,
-        (Vec<AllLlmsStringTransformationRecord>, String),
-        (Vec<AllLlmsStringTransformationRecord>, String),
-        (Vec<AllLlmsStringTransformationRecord>, String),
-        String,
-    ) {
-        (
-            self.raw_proof.unwrap(),
-            self.simplified_proof.unwrap(),
-            self.elaborated_proof.unwrap(),
-            self.regularized_proof.unwrap(),
-            self.lean4_code.unwrap(),
-        )
+    pub(crate) fn finish(self) -> VdPipelineOutcome {
+        if let Some(stages) = self.stage_records {
+            return VdPipelineOutcome::Staged { stages };
+        }
+        VdPipelineOutcome::Builtin {
+            raw_proof: self.raw_proof.unwrap(),
+            simplified_proof: self.simplified_proof.unwrap(),
+            elaborated_proof: self.elaborated_proof.unwrap(),
+            regularized_proof: self.regularized_proof.unwrap(),
+            lean4_code: self.lean4_code.unwrap(),
+        }
     }
 }
 
@@ -257,16 +263,20 @@ const PROOF_BEGIN: &str = "\\begin{proof}";
 const PROOF_END: &str = "\\end{proof}";
 
 fn extract_proof(s: &str) -> String {
-    assert!(s.contains(PROOF_BEGIN));
-    assert!(s.contains(PROOF_END));
-    let start = s.find(PROOF_BEGIN).unwrap();
-    let end = s.find(PROOF_END).unwrap();
+    try_extract_proof(s).expect("proof environment not found")
+}
+
+fn try_extract_proof(s: &str) -> Option<String> {
+    let start = s.find(PROOF_BEGIN)?;
+    let end = start + s[start..].find(PROOF_END)?;
     let content = &s[start + PROOF_BEGIN.len()..end];
-    content
-        .trim()
-        .replace("$$", "$")
-        .replace("\\[", "$")
-        .replace("\\]", "$")
+    Some(
+        content
+            .trim()
+            .replace("$$", "$")
+            .replace("\\[", "$")
+            .replace("\\]", "$"),
+    )
 }
 
 #[test]
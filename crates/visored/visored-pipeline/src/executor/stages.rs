@@ -0,0 +1,219 @@
+use super::*;
+use crate::config::stages::*;
+use crate::tracker::{VdPipelineStageAttempt, VdPipelineStageRecord};
+use lean_helpers::lake::lake_env_lean;
+use llm_prelude::message::LlmMessage;
+
+impl<'a, 'db> VdPipelineExecutor<'a, 'db> {
+    pub(super) fn execute_stages(
+        &self,
+        stages: &[VdPipelineStageResolved],
+    ) -> VdPipelineResult<Vec<VdPipelineStageRecord>> {
+        execute_stages(
+            &self.llm_client,
+            stages,
+            &self.input.content,
+            |stage, code| self.elaborate_lean(stage, code),
+        )
+    }
+
+    /// Writes `code` to `<lean4_dir>/<output_name>/<file without extension>/example-<index>-<stage>.lean`
+    /// and elaborates it, so that configs sharing stage names don't overwrite each other.
+    ///
+    /// Returns the messages on failure.
+    fn elaborate_lean(&self, stage: &VdPipelineStageResolved, code: &str) -> Result<(), String> {
+        let path = self
+            .input
+            .relative_path
+            .to_logical_path(self.lean4_dir.join(&self.config.data.output_name))
+            .with_extension("")
+            .join(format!(
+                "example-{}-{}.lean",
+                self.input.index + 1,
+                stage.name
+            ));
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        diff_write(&path, code, true);
+        match lake_env_lean(&path) {
+            Ok(outcome) if outcome.success => Ok(()),
+            Ok(outcome) => Err(outcome.output),
+            Err(e) => Err(format!("failed to run Lean: {e}")),
+        }
+    }
+}
+
+/// Runs the stages in order, stopping at the first stage whose attempts are all rejected.
+///
+/// A rejected response is followed up in the same conversation with the reason.
+fn execute_stages(
+    llm_client: &AllLlmsClient,
+    stages: &[VdPipelineStageResolved],
+    question: &str,
+    elaborate_lean: impl Fn(&VdPipelineStageResolved, &str) -> Result<(), String>,
+) -> VdPipelineResult<Vec<VdPipelineStageRecord>> {
+    let mut outputs: Vec<String> = vec![];
+    let mut records = vec![];
+    for stage in stages {
+        let mut messages = vec![LlmMessage::user(
+            stage.render_prompt(stages, question, &outputs),
+        )];
+        let mut attempts = vec![];
+        let mut output = None;
+        for _ in 0..=stage.retries {
+            let response = llm_client.chat(stage.model.model, messages.clone())?;
+            let rejection = match extract_stage_output(stage.kind, &response) {
+                Some(extracted) => match check_stage_output(stage, &extracted, &elaborate_lean) {
+                    Ok(()) => {
+                        output = Some(extracted);
+                        None
+                    }
+                    Err(rejection) => Some(rejection),
+                },
+                None => Some(match stage.kind.is_lean() {
+                    true => "the Lean code should be wrapped in ```lean and ```".to_string(),
+                    false => {
+                        format!("the proof should be wrapped in {PROOF_BEGIN} and {PROOF_END}")
+                    }
+                }),
+            };
+            attempts.push(VdPipelineStageAttempt {
+                response: response.clone(),
+                rejection: rejection.clone(),
+            });
+            let Some(rejection) = rejection else { break };
+            messages.push(LlmMessage::assistant(response));
+            messages.push(LlmMessage::user(format!(
+                "The response is rejected because {rejection}\n\nPlease fix it and respond again in the same format."
+            )));
+        }
+        records.push(VdPipelineStageRecord {
+            name: stage.name.clone(),
+            attempts,
+            output: output.clone(),
+        });
+        match output {
+            Some(output) => outputs.push(output),
+            None => break,
+        }
+    }
+    Ok(records)
+}
+
+fn extract_stage_output(kind: VdPipelineStageKind, response: &str) -> Option<String> {
+    match kind.is_lean() {
+        true => Some(extract_lean_code(response)),
+        false => try_extract_proof(response),
+    }
+}
+
+/// the first ```lean block, or the whole response if there's none
+fn extract_lean_code(response: &str) -> String {
+    for fence in ["```lean4", "```lean"] {
+        if let Some(start) = response.find(fence) {
+            let rest = &response[start + fence.len()..];
+            let end = rest.find("```").unwrap_or(rest.len());
+            return rest[..end].trim().to_string();
+        }
+    }
+    response.trim().to_string()
+}
+
+fn check_stage_output(
+    stage: &VdPipelineStageResolved,
+    output: &str,
+    elaborate_lean: impl Fn(&VdPipelineStageResolved, &str) -> Result<(), String>,
+) -> Result<(), String> {
+    for acceptance in &stage.acceptance {
+        match acceptance {
+            VdPipelineStageAcceptance::NonEmpty => {
+                if output.trim().is_empty() {
+                    return Err("the output is empty".to_string());
+                }
+            }
+            VdPipelineStageAcceptance::NoSorry => {
+                if output.contains("sorry") {
+                    return Err("the output contains `sorry`".to_string());
+                }
+            }
+            VdPipelineStageAcceptance::LeanElaborates => {
+                elaborate_lean(stage, output).map_err(|messages| {
+                    format!("Lean fails to elaborate it with the following messages:\n{messages}")
+                })?
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn execute_stages_works() {
+    use all_llms::{backend::AllLlmsBackend, mock::AllLlmsMock};
+
+    let db = &EternerDb::default();
+    let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+    let mock = AllLlmsMock::default()
+        .with_contains("Solve", "\\begin{proof}\nIt's trivial.\n\\end{proof}")
+        .with_contains(
+            "Lean fails",
+            "```lean\ntheorem t : 1 + 1 = 2 := by norm_num\n```",
+        )
+        .with_contains("Formalize", "```lean\ntheorem t : 1 + 1 = 2 := sorry\n```");
+    let llm_client = AllLlmsClient::new_with_backend(
+        db,
+        tokio_runtime,
+        PathBuf::from("caches/does-not-exist"),
+        AllLlmsBackend::Mock(mock),
+    )
+    .unwrap();
+    let stage =
+        |name: &str, kind, inputs, prompt_template: &str, acceptance| VdPipelineStageResolved {
+            name: name.to_string(),
+            kind,
+            inputs,
+            model: config::model_presets::VdPipelineModelPreset {
+                name: "gpt-4o".to_string(),
+                model: AllLlmModel::GPT_4O,
+            },
+            prompt_template: prompt_template.to_string(),
+            retries: 1,
+            acceptance,
+        };
+    let stages = [
+        stage(
+            "solution",
+            VdPipelineStageKind::QuestionToNaturalSolution,
+            vec![],
+            "Solve {{question}}",
+            vec![VdPipelineStageAcceptance::NonEmpty],
+        ),
+        stage(
+            "proof",
+            VdPipelineStageKind::LeanSketchCompletion,
+            vec![0],
+            "Formalize {{solution}}",
+            vec![VdPipelineStageAcceptance::LeanElaborates],
+        ),
+    ];
+    let elaborate_lean = |_: &VdPipelineStageResolved, code: &str| match code.contains("sorry") {
+        true => Err("declaration uses 'sorry'".to_string()),
+        false => Ok(()),
+    };
+    let records = execute_stages(&llm_client, &stages, "1 + 1 = 2", elaborate_lean).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].output.as_deref(), Some("It's trivial."));
+    assert_eq!(records[1].attempts.len(), 2);
+    assert!(records[1].attempts[0].rejection.is_some());
+    assert_eq!(
+        records[1].output.as_deref(),
+        Some("theorem t : 1 + 1 = 2 := by norm_num")
+    );
+
+    // a stage rejected on every attempt ends the run
+    let mut stages = stages;
+    stages[1].retries = 0;
+    let records = execute_stages(&llm_client, &stages, "1 + 1 = 2", elaborate_lean).unwrap();
+    assert_eq!(records[1].attempts.len(), 1);
+    assert_eq!(records[1].output, None);
+}
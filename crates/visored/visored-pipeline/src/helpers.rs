@@ -3,6 +3,7 @@ use std::path::Path;
 use crate::{
     instance::VdPipelineInstance,
     runner::{VdPipelineInstanceFile, VdPipelineRunner},
+    tracker::VdPipelineOutcome,
 };
 use relative_path::{RelativePathBuf, RelativeToError};
 
@@ -105,10 +106,36 @@ impl VdPipelineInstance {
     pub fn write_result_latex_content(&self, latex_content: &mut String) {
         use std::fmt::Write;
 
-        let tracker = self.tracker();
-        write!(
-            latex_content,
-            r#"
+        let Some(tracker) = self.tracker_opt() else {
+            write!(
+                latex_content,
+                r#"
+
+\begin{{example}}
+Problem:
+\begin{{tcolorbox}}[colback=yellow!10, width=\linewidth]
+{}
+\end{{tcolorbox}}
+\end{{example}}
+
+Failed: \verb|{}|
+"#,
+                self.input().content,
+                self.error().unwrap_or("not run").replace('|', "/")
+            )
+            .unwrap();
+            return;
+        };
+        match tracker.outcome {
+            VdPipelineOutcome::Builtin {
+                ref simplified_proof,
+                ref elaborated_proof,
+                ref regularized_proof,
+                ref lean4_code,
+                ..
+            } => write!(
+                latex_content,
+                r#"
 
 \begin{{example}}
 Problem:
@@ -139,12 +166,67 @@ Lean 4 code:
 \end{{lstlisting}}
 \end{{tcolorbox}}
 "#,
-            tracker.input.content,
-            tracker.simplified_proof.1,
-            tracker.elaborated_proof.1,
-            tracker.regularized_proof.1,
-            tracker.lean4_code
-        )
-        .unwrap();
+                tracker.input.content,
+                simplified_proof.1,
+                elaborated_proof.1,
+                regularized_proof.1,
+                lean4_code
+            )
+            .unwrap(),
+            VdPipelineOutcome::Staged { ref stages } => {
+                write!(
+                    latex_content,
+                    r#"
+
+\begin{{example}}
+Problem:
+\begin{{tcolorbox}}[colback=yellow!10, width=\linewidth]
+{}
+\end{{tcolorbox}}
+\end{{example}}
+"#,
+                    tracker.input.content,
+                )
+                .unwrap();
+                let stage_configs = tracker.config.stages_resolved.as_ref().unwrap();
+                for (record, stage) in stages.iter().zip(stage_configs) {
+                    let Some(ref output) = record.output else {
+                        write!(
+                            latex_content,
+                            "\n{} rejected after {} attempts.\n",
+                            record.name,
+                            record.attempts.len()
+                        )
+                        .unwrap();
+                        break;
+                    };
+                    match stage.kind.is_lean() {
+                        true => write!(
+                            latex_content,
+                            r#"
+{}:
+\begin{{tcolorbox}}[colback=white!10, width=\linewidth]
+\begin{{lstlisting}}[language=Lean4]
+{}
+\end{{lstlisting}}
+\end{{tcolorbox}}
+"#,
+                            record.name, output
+                        ),
+                        false => write!(
+                            latex_content,
+                            r#"
+{}:
+\begin{{tcolorbox}}[colback=blue!10, width=\linewidth]
+{}
+\end{{tcolorbox}}
+"#,
+                            record.name, output
+                        ),
+                    }
+                    .unwrap();
+                }
+            }
+        }
     }
 }
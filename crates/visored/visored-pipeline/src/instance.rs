@@ -5,14 +5,26 @@ use eterned::db::EternerDb;
 use idx_arena::{ArenaIdx, ArenaIdxRange};
 
 use crate::{
-    error::VdPipelineResult, input::VdPipelineInput, tracker::VdPipelineTracker, VdPipelineConfig,
+    error::VdPipelineResult,
+    input::VdPipelineInput,
+    report::{VdPipelineInstanceReport, VdPipelineInstanceStatus},
+    tracker::{VdPipelineOutcome, VdPipelineTracker},
+    VdPipelineConfig,
+};
+use std::{
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
-use std::{path::Path, sync::Arc};
 
 pub struct VdPipelineInstance {
     config: Arc<VdPipelineConfig>,
     input: Arc<VdPipelineInput>,
     tracker: Option<VdPipelineTracker>,
+    /// the error or panic message of a failed run
+    error: Option<String>,
+    /// whether the result is loaded from the report of a previous run
+    resumed: bool,
 }
 
 pub type VdPipelineInstanceIdx = ArenaIdx<VdPipelineInstance>;
@@ -24,18 +36,85 @@ impl VdPipelineInstance {
             config,
             input: src_file,
             tracker: None,
+            error: None,
+            resumed: false,
         }
     }
 }
 
 impl VdPipelineInstance {
+    pub fn config(&self) -> &VdPipelineConfig {
+        &self.config
+    }
+
+    pub fn input(&self) -> &VdPipelineInput {
+        &self.input
+    }
+
     #[track_caller]
     pub fn tracker(&self) -> &VdPipelineTracker {
         self.tracker.as_ref().unwrap()
     }
+
+    pub fn tracker_opt(&self) -> Option<&VdPipelineTracker> {
+        self.tracker.as_ref()
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn resumed(&self) -> bool {
+        self.resumed
+    }
+
+    /// `None` if it hasn't run
+    pub fn status(&self) -> Option<VdPipelineInstanceStatus> {
+        if self.error.is_some() {
+            return Some(VdPipelineInstanceStatus::Failed);
+        }
+        match self.tracker.as_ref()?.outcome {
+            VdPipelineOutcome::Builtin { .. } => Some(VdPipelineInstanceStatus::Succeeded),
+            VdPipelineOutcome::Staged { ref stages } => {
+                let num_stages = self.config.stages_resolved.as_ref().map_or(0, Vec::len);
+                match stages.len() == num_stages
+                    && stages.iter().all(|stage| stage.output.is_some())
+                {
+                    true => Some(VdPipelineInstanceStatus::Succeeded),
+                    false => Some(VdPipelineInstanceStatus::Rejected),
+                }
+            }
+        }
+    }
+
+    /// `<output_dir>/<output_name>/<file without extension>/example-<index>.json`
+    pub fn report_path(&self, output_dir: &Path) -> PathBuf {
+        self.input
+            .relative_path
+            .to_logical_path(output_dir.join(&self.config.data.output_name))
+            .with_extension("")
+            .join(format!("example-{}.json", self.input.index))
+    }
+
+    /// `None` if it hasn't run
+    pub fn report(&self) -> Option<VdPipelineInstanceReport> {
+        Some(VdPipelineInstanceReport {
+            output_name: self.config.data.output_name.clone(),
+            file: self.input.relative_path.clone(),
+            index: self.input.index,
+            status: self.status()?,
+            error: self.error.clone(),
+            outcome: self.tracker.as_ref().map(|tracker| tracker.outcome.clone()),
+        })
+    }
 }
 
 impl VdPipelineInstance {
+    /// Errors and panics of the run are kept in the instance instead of being returned,
+    /// so that other instances go on.
+    ///
+    /// With `output_dir`, the report is saved there,
+    /// and a completed report from a previous run is loaded instead of running again.
     pub fn run(
         &mut self,
         seed: AlienSeed,
@@ -44,18 +123,53 @@ impl VdPipelineInstance {
         // TODO: replace with preloaded specs???
         specs_dir: &Path,
         lean4_dir: &Path,
+        output_dir: Option<&Path>,
     ) -> VdPipelineResult<()> {
-        assert!(self.tracker.is_none());
-        with_seed(seed, || {
-            self.tracker = Some(VdPipelineTracker::new(
-                db,
-                tokio_runtime,
-                specs_dir,
-                lean4_dir,
-                self.input.clone(),
-                self.config.clone(),
-            ));
-        });
+        assert!(self.tracker.is_none() && self.error.is_none());
+        if let Some(output_dir) = output_dir {
+            if let Some(report) = VdPipelineInstanceReport::load(&self.report_path(output_dir)) {
+                if let (true, Some(outcome)) = (report.status.is_completed(), report.outcome) {
+                    self.tracker = Some(VdPipelineTracker {
+                        input: self.input.clone(),
+                        config: self.config.clone(),
+                        outcome,
+                    });
+                    self.resumed = true;
+                    return Ok(());
+                }
+            }
+        }
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            with_seed(seed, || {
+                VdPipelineTracker::new(
+                    db,
+                    tokio_runtime,
+                    specs_dir,
+                    lean4_dir,
+                    self.input.clone(),
+                    self.config.clone(),
+                )
+            })
+        }));
+        match result {
+            Ok(Ok(tracker)) => self.tracker = Some(tracker),
+            Ok(Err(e)) => self.error = Some(e.to_string()),
+            Err(panic) => {
+                self.error = Some(
+                    match panic
+                        .downcast_ref::<&str>()
+                        .map(ToString::to_string)
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                    {
+                        Some(message) => format!("panicked: {message}"),
+                        None => "panicked".to_string(),
+                    },
+                )
+            }
+        }
+        if let Some(output_dir) = output_dir {
+            self.report().unwrap().save(&self.report_path(output_dir))?;
+        }
         Ok(())
     }
 }
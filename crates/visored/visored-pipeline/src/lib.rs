@@ -6,6 +6,8 @@ pub mod helpers;
 pub mod input;
 /// smallest unit of the pipeline
 mod instance;
+/// reports persist instance results and summarize runs
+pub mod report;
 /// runner orchestrates all instances
 pub mod runner;
 #[cfg(test)]
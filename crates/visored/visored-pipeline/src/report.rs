@@ -0,0 +1,220 @@
+use crate::{tracker::VdPipelineOutcome, VdPipelineError, VdPipelineResult};
+use relative_path::{RelativePath, RelativePathBuf};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const SUMMARY_JSON_FILE_NAME: &str = "summary.json";
+pub const SUMMARY_CSV_FILE_NAME: &str = "summary.csv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VdPipelineInstanceStatus {
+    /// every stage is accepted
+    Succeeded,
+    /// some stage is rejected on every attempt
+    Rejected,
+    /// the run stopped with an error or a panic
+    Failed,
+}
+
+impl VdPipelineInstanceStatus {
+    /// completed instances are skipped by reruns, failed ones are retried
+    pub fn is_completed(self) -> bool {
+        match self {
+            VdPipelineInstanceStatus::Succeeded | VdPipelineInstanceStatus::Rejected => true,
+            VdPipelineInstanceStatus::Failed => false,
+        }
+    }
+}
+
+/// What's persisted of an instance, one file per instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VdPipelineInstanceReport {
+    pub output_name: String,
+    pub file: RelativePathBuf,
+    pub index: usize,
+    pub status: VdPipelineInstanceStatus,
+    pub error: Option<String>,
+    pub outcome: Option<VdPipelineOutcome>,
+}
+
+impl VdPipelineInstanceReport {
+    /// `None` if there's no report or it can't be read, so that the instance is run again.
+    pub fn load(path: &Path) -> Option<Self> {
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+    }
+
+    pub fn save(&self, path: &Path) -> VdPipelineResult<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| VdPipelineError::Io(parent.to_path_buf(), e))?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap())
+            .map_err(|e| VdPipelineError::Io(path.to_path_buf(), e))
+    }
+}
+
+/// Success rates per config and per source file, to compare model routings.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VdPipelineRunSummary {
+    pub configs: Vec<VdPipelineSummaryRow>,
+    pub files: Vec<VdPipelineSummaryRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VdPipelineSummaryRow {
+    pub output_name: String,
+    /// `None` for the row of a whole config
+    pub file: Option<RelativePathBuf>,
+    pub total: usize,
+    pub succeeded: usize,
+    pub rejected: usize,
+    pub failed: usize,
+    /// instances that haven't run
+    pub pending: usize,
+    pub success_rate: f64,
+}
+
+impl VdPipelineRunSummary {
+    /// Rows are in the order their config or file first appears.
+    pub fn new<'a>(
+        entries: impl IntoIterator<Item = (&'a str, &'a RelativePath, Option<VdPipelineInstanceStatus>)>,
+    ) -> Self {
+        let mut summary = Self::default();
+        for (output_name, file, status) in entries {
+            summary.row_mut(output_name, None).record(status);
+            summary.row_mut(output_name, Some(file)).record(status);
+        }
+        summary
+    }
+
+    fn row_mut(
+        &mut self,
+        output_name: &str,
+        file: Option<&RelativePath>,
+    ) -> &mut VdPipelineSummaryRow {
+        let rows = match file {
+            Some(_) => &mut self.files,
+            None => &mut self.configs,
+        };
+        let position = rows
+            .iter()
+            .position(|row| row.output_name == output_name && row.file.as_deref() == file);
+        let position = match position {
+            Some(position) => position,
+            None => {
+                rows.push(VdPipelineSummaryRow {
+                    output_name: output_name.to_string(),
+                    file: file.map(ToOwned::to_owned),
+                    total: 0,
+                    succeeded: 0,
+                    rejected: 0,
+                    failed: 0,
+                    pending: 0,
+                    success_rate: 0.0,
+                });
+                rows.len() - 1
+            }
+        };
+        &mut rows[position]
+    }
+
+    /// Writes `summary.json` and `summary.csv` into `dir`.
+    pub fn write(&self, dir: &Path) -> VdPipelineResult<()> {
+        std::fs::create_dir_all(dir).map_err(|e| VdPipelineError::Io(dir.to_path_buf(), e))?;
+        let json_path = dir.join(SUMMARY_JSON_FILE_NAME);
+        std::fs::write(&json_path, serde_json::to_string_pretty(self).unwrap())
+            .map_err(|e| VdPipelineError::Io(json_path, e))?;
+        let csv_path = dir.join(SUMMARY_CSV_FILE_NAME);
+        std::fs::write(&csv_path, self.csv()).map_err(|e| VdPipelineError::Io(csv_path, e))
+    }
+
+    /// one line per row, the config rows first with an empty `file`
+    pub fn csv(&self) -> String {
+        let mut csv =
+            "output_name,file,total,succeeded,rejected,failed,pending,success_rate\n".to_string();
+        for row in self.configs.iter().chain(&self.files) {
+            csv += &format!(
+                "{},{},{},{},{},{},{},{:.4}\n",
+                csv_field(&row.output_name),
+                csv_field(row.file.as_ref().map_or("", |file| file.as_str())),
+                row.total,
+                row.succeeded,
+                row.rejected,
+                row.failed,
+                row.pending,
+                row.success_rate
+            );
+        }
+        csv
+    }
+}
+
+impl VdPipelineSummaryRow {
+    fn record(&mut self, status: Option<VdPipelineInstanceStatus>) {
+        self.total += 1;
+        match status {
+            Some(VdPipelineInstanceStatus::Succeeded) => self.succeeded += 1,
+            Some(VdPipelineInstanceStatus::Rejected) => self.rejected += 1,
+            Some(VdPipelineInstanceStatus::Failed) => self.failed += 1,
+            None => self.pending += 1,
+        }
+        self.success_rate = self.succeeded as f64 / self.total as f64;
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[test]
+fn vd_pipeline_run_summary_works() {
+    use expect_test::expect;
+    use VdPipelineInstanceStatus::*;
+
+    let a = RelativePath::new("batch0.tex");
+    let b = RelativePath::new("batch1.tex");
+    let summary = VdPipelineRunSummary::new([
+        ("baseline", a, Some(Succeeded)),
+        ("standard", a, Some(Rejected)),
+        ("baseline", a, Some(Failed)),
+        ("standard", a, Some(Succeeded)),
+        ("baseline", b, Some(Succeeded)),
+        ("standard", b, None),
+    ]);
+    assert_eq!(summary.configs.len(), 2);
+    assert_eq!(summary.files.len(), 4);
+    expect![[r#"
+        output_name,file,total,succeeded,rejected,failed,pending,success_rate
+        baseline,,3,2,0,1,0,0.6667
+        standard,,3,1,1,0,1,0.3333
+        baseline,batch0.tex,2,1,0,1,0,0.5000
+        standard,batch0.tex,2,1,1,0,0,0.5000
+        baseline,batch1.tex,1,1,0,0,0,1.0000
+        standard,batch1.tex,1,0,0,0,1,0.0000
+    "#]]
+    .assert_eq(&summary.csv());
+}
+
+#[test]
+fn vd_pipeline_instance_report_save_load_works() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("baseline/batch0/example-0.json");
+    assert_eq!(VdPipelineInstanceReport::load(&path), None);
+    let report = VdPipelineInstanceReport {
+        output_name: "baseline".to_string(),
+        file: RelativePathBuf::from("batch0.tex"),
+        index: 0,
+        status: VdPipelineInstanceStatus::Rejected,
+        error: None,
+        outcome: Some(VdPipelineOutcome::Staged { stages: vec![] }),
+    };
+    report.save(&path).unwrap();
+    assert_eq!(VdPipelineInstanceReport::load(&path), Some(report));
+    std::fs::write(&path, "{").unwrap();
+    assert_eq!(VdPipelineInstanceReport::load(&path), None);
+}
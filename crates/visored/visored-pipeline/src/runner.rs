@@ -4,6 +4,7 @@ use crate::{
         storage::VdPipelineInstanceStorage, VdPipelineInstance, VdPipelineInstanceIdx,
         VdPipelineInstanceIdxRange,
     },
+    report::VdPipelineRunSummary,
     VdPipelineConfig, VdPipelineConfigData, VdPipelineResult,
 };
 use alien_seed::AlienSeed;
@@ -21,6 +22,8 @@ pub struct VdPipelineRunner<'db> {
     instance_storage: VdPipelineInstanceStorage,
     instance_files: Vec<VdPipelineInstanceFile>,
     configs: Vec<Arc<VdPipelineConfig>>,
+    /// where reports and the summary are saved, `None` means results are kept in memory only
+    output_dir: Option<PathBuf>,
 }
 
 pub struct VdPipelineInstanceFile {
@@ -73,14 +76,43 @@ impl<'db> VdPipelineRunner<'db> {
             instance_files,
             configs,
             instance_storage,
+            output_dir: None,
         })
     }
+
+    /// Makes runs resumable: each instance's report is saved under `output_dir/<output_name>`,
+    /// and instances completed by a previous run are skipped.
+    pub fn with_output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = Some(output_dir.into());
+        self
+    }
 }
 
 impl<'db> VdPipelineRunner<'db> {
     pub fn instance_files(&self) -> &[VdPipelineInstanceFile] {
         &self.instance_files
     }
+
+    pub fn output_dir(&self) -> Option<&Path> {
+        self.output_dir.as_deref()
+    }
+
+    pub fn summary(&self) -> VdPipelineRunSummary {
+        VdPipelineRunSummary::new(self.instance_files.iter().flat_map(|instance_file| {
+            instance_file
+                .instances
+                .iter()
+                .flat_map(|(_, instance_idx_range)| instance_idx_range)
+                .map(|instance_idx| {
+                    let instance = &self[instance_idx];
+                    (
+                        instance.config().data.output_name.as_str(),
+                        &*instance.input().relative_path,
+                        instance.status(),
+                    )
+                })
+        }))
+    }
 }
 
 impl<'db> std::ops::Index<VdPipelineInstanceIdx> for VdPipelineRunner<'db> {
@@ -92,6 +124,7 @@ impl<'db> std::ops::Index<VdPipelineInstanceIdx> for VdPipelineRunner<'db> {
 }
 
 impl<'db> VdPipelineRunner<'db> {
+    /// Failed instances don't stop the run; only failures to save reports do.
     pub fn run_all_single_threaded(&mut self, seed: AlienSeed) -> VdPipelineResult<()> {
         for instance in self.instance_storage.all_instances_mut() {
             instance.run(
@@ -100,9 +133,10 @@ impl<'db> VdPipelineRunner<'db> {
                 self.tokio_runtime.clone(),
                 self.specs_dir,
                 self.lean4_dir,
+                self.output_dir.as_deref(),
             )?;
         }
-        Ok(())
+        self.write_summary()
     }
 
     pub fn run_all_multi_threaded(&mut self, seed: AlienSeed) -> VdPipelineResult<()> {
@@ -118,8 +152,16 @@ impl<'db> VdPipelineRunner<'db> {
                     self.tokio_runtime.clone(),
                     self.specs_dir,
                     self.lean4_dir,
+                    self.output_dir.as_deref(),
                 )
             })?;
-        Ok(())
+        self.write_summary()
+    }
+
+    fn write_summary(&self) -> VdPipelineResult<()> {
+        match self.output_dir {
+            Some(ref output_dir) => self.summary().write(output_dir),
+            None => Ok(()),
+        }
     }
 }
@@ -1,4 +1,4 @@
-use crate::runner::VdPipelineRunner;
+use crate::{report::SUMMARY_CSV_FILE_NAME, runner::VdPipelineRunner};
 use alien_seed::AlienSeed;
 use eterned::db::EternerDb;
 use std::{
@@ -21,25 +21,54 @@ fn visored_pipeline_works() {
         expect_files_dir: &Path,
     ) {
         let db = &EternerDb::default();
-        let mut runner = VdPipelineRunner::new(
-            db,
-            tokio_runtime,
-            specs_dir,
-            lean4_dir,
-            config_path,
-            src_file_paths,
-            src_root,
-        )
-        .unwrap();
+        let output_dir = tempfile::tempdir().unwrap();
+        let new_runner = || {
+            VdPipelineRunner::new(
+                db,
+                tokio_runtime.clone(),
+                specs_dir,
+                lean4_dir,
+                &config_path,
+                src_file_paths.clone(),
+                src_root,
+            )
+            .unwrap()
+            .with_output_dir(output_dir.path())
+        };
+        let mut runner = new_runner();
         let seed = AlienSeed::new(0);
         runner.run_all_single_threaded(seed).unwrap();
         let latex_files = runner.export_result_latex_files(src_root).unwrap();
-        for latex_file in latex_files {
+        for latex_file in &latex_files {
             use expect_test::expect_file;
 
             expect_file!(latex_file.relative_path.to_logical_path(expect_files_dir))
                 .assert_eq(&latex_file.latex_content);
         }
+        let summary = runner.summary();
+        assert!(summary.configs.iter().all(|row| row.succeeded == row.total));
+        assert!(output_dir.path().join(SUMMARY_CSV_FILE_NAME).is_file());
+
+        // a rerun resumes every instance from the reports
+        let mut runner = new_runner();
+        runner.run_all_single_threaded(seed).unwrap();
+        assert!(runner
+            .instance_files()
+            .iter()
+            .flat_map(|instance_file| &instance_file.instances)
+            .flat_map(|(_, instance_idx_range)| instance_idx_range)
+            .all(|instance_idx| runner[instance_idx].resumed()));
+        let resumed_latex_files = runner.export_result_latex_files(src_root).unwrap();
+        assert_eq!(
+            resumed_latex_files
+                .iter()
+                .map(|latex_file| &latex_file.latex_content)
+                .collect::<Vec<_>>(),
+            latex_files
+                .iter()
+                .map(|latex_file| &latex_file.latex_content)
+                .collect::<Vec<_>>()
+        );
     }
 
     let tokio_runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
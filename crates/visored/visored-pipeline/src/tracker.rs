@@ -1,18 +1,44 @@
 use crate::{
     executor::VdPipelineExecutor, input::VdPipelineInput, VdPipelineConfig, VdPipelineConfigData,
+    VdPipelineResult,
 };
 use all_llms::{transformation::AllLlmsStringTransformationRecord, AllLlmsClient};
 use eterned::db::EternerDb;
+use serde::{Deserialize, Serialize};
 use std::{path::Path, sync::Arc};
 
 pub struct VdPipelineTracker {
     pub input: Arc<VdPipelineInput>,
     pub config: Arc<VdPipelineConfig>,
-    pub raw_proof: String,
-    pub simplified_proof: (Vec<AllLlmsStringTransformationRecord>, String),
-    pub elaborated_proof: (Vec<AllLlmsStringTransformationRecord>, String),
-    pub regularized_proof: (Vec<AllLlmsStringTransformationRecord>, String),
-    pub lean4_code: String,
+    pub outcome: VdPipelineOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VdPipelineOutcome {
+    /// the builtin stages, used when the config declares none
+    Builtin {
+        raw_proof: String,
+        simplified_proof: (Vec<AllLlmsStringTransformationRecord>, String),
+        elaborated_proof: (Vec<AllLlmsStringTransformationRecord>, String),
+        regularized_proof: (Vec<AllLlmsStringTransformationRecord>, String),
+        lean4_code: String,
+    },
+    /// the stages declared in the config, up to the first one that's rejected
+    Staged { stages: Vec<VdPipelineStageRecord> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VdPipelineStageRecord {
+    pub name: String,
+    pub attempts: Vec<VdPipelineStageAttempt>,
+    /// the extracted output of the accepted attempt, `None` if all are rejected
+    pub output: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VdPipelineStageAttempt {
+    pub response: String,
+    pub rejection: Option<String>,
 }
 
 impl VdPipelineTracker {
@@ -24,20 +50,15 @@ impl VdPipelineTracker {
         lean4_dir: &Path,
         input: Arc<VdPipelineInput>,
         config: Arc<VdPipelineConfig>,
-    ) -> Self {
+    ) -> VdPipelineResult<Self> {
         let mut executor =
             VdPipelineExecutor::new(db, tokio_runtime, specs_dir, lean4_dir, &*input, &*config);
-        executor.execute_all();
-        let (raw_proof, simplified_proof, elaborated_proof, regularized_proof, lean4_code) =
-            executor.finish();
-        Self {
+        executor.execute_all()?;
+        let outcome = executor.finish();
+        Ok(Self {
             input,
             config,
-            raw_proof,
-            simplified_proof,
-            elaborated_proof,
-            regularized_proof,
-            lean4_code,
-        }
+            outcome,
+        })
     }
 }
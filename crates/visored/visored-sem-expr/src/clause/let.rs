@@ -3,13 +3,14 @@ pub mod placeholder;
 
 use self::{assigned::VdSemLetAssignedDispatch, placeholder::VdSemLetPlaceholderDispatch};
 use super::*;
-use visored_syn_expr::clause::r#let::VdSynLetClauseResolution;
+use visored_syn_expr::clause::r#let::{VdSynLetClauseError, VdSynLetClauseResolution};
 
 #[enum_class::from_variants]
 #[derive(Debug, PartialEq, Eq)]
 pub enum VdSemLetClauseDispatch {
     Assigned(VdSemLetAssignedDispatch),
     Placeholder(VdSemLetPlaceholderDispatch),
+    Err(VdSynLetClauseError),
 }
 
 impl<'a> VdSemExprBuilder<'a> {
@@ -35,6 +36,7 @@ impl ToVdSem<VdSemLetClauseDispatch> for &VdSynLetClauseResolution {
         match self {
             VdSynLetClauseResolution::Assigned(slf) => slf.to_vd_sem(builder).into(),
             VdSynLetClauseResolution::Placeholder(slf) => slf.to_vd_sem(builder).into(),
+            VdSynLetClauseResolution::Err(error) => VdSemLetClauseDispatch::Err(*error),
         }
     }
 }
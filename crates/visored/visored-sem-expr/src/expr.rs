@@ -1,3 +1,4 @@
+pub mod application;
 pub mod attach;
 pub mod binary;
 pub mod delimited;
@@ -15,7 +16,9 @@ pub mod uniadic_chain;
 pub mod variadic_array;
 pub mod variadic_chain;
 
-use self::{attach::*, binary::*, delimited::*, prefix::*, separated_list::*, suffix::*};
+use self::{
+    application::*, attach::*, binary::*, delimited::*, prefix::*, separated_list::*, suffix::*,
+};
 use crate::*;
 use either::*;
 use frac::VdSemFracDispatch;
@@ -77,7 +80,7 @@ pub enum VdSemExprData {
     Suffix {
         opd: VdSemExprIdx,
         opr: VdSemSuffixOpr,
-        dispatch: (),
+        dispatch: VdSemSuffixDispatch,
     },
     Attach {
         base: VdSemExprIdx,
@@ -124,6 +127,11 @@ pub enum VdSemExprData {
         radicand_arg: LxMathCompleteCommandArgument,
         dispatch: VdSemSqrtDispatch,
     },
+    Application {
+        function: VdSemApplicationFunction,
+        arguments: VdSemApplicationArguments,
+        dispatch: VdSemApplicationDispatch,
+    },
 }
 
 pub struct VdSemExprEntry {
@@ -224,7 +232,7 @@ impl<'a> VdSemExprBuilder<'a> {
             VdSynExprData::BaseOpr { opr } => todo!("opr = {:?}", opr),
             VdSynExprData::Binary { lopd, opr, ropd } => self.build_binary(lopd, opr, ropd),
             VdSynExprData::Prefix { opr, opd } => self.build_prefix(opr, opd),
-            VdSynExprData::Suffix { opd, opr } => todo!("opr = {:?}", opr),
+            VdSynExprData::Suffix { opd, opr } => self.build_suffix(opd, opr),
             VdSynExprData::SeparatedList {
                 separator_class,
                 items,
@@ -263,6 +271,12 @@ impl<'a> VdSemExprBuilder<'a> {
                 denominator_arg,
                 ..
             } => self.build_frac(command_token_idx, numerator, denominator, denominator_arg),
+            VdSynExprData::Binom {
+                command_token_idx,
+                upper,
+                lower,
+                lower_arg,
+            } => self.build_binom(command_token_idx, upper, lower, lower_arg),
             VdSynExprData::Sqrt {
                 command_token_idx,
                 radicand,
@@ -302,13 +316,24 @@ impl VdSemExprData {
                 VdSemBinaryOpr::Base(_, _) => vec![lopd, ropd],
                 VdSemBinaryOpr::Composite(opr, _) => vec![lopd, opr, ropd],
             },
-            VdSemExprData::Prefix { opr, opd, .. } => match opr {
-                VdSemPrefixOpr::Base(_, _) => vec![opd],
-                VdSemPrefixOpr::Composite(opr, _) => vec![opr, opd],
+            VdSemExprData::Prefix { opr, opd, dispatch } => match (opr, dispatch) {
+                (
+                    _,
+                    VdSemPrefixDispatch::Quantification { domain: bound, .. }
+                    | VdSemPrefixDispatch::Limit {
+                        approached: bound, ..
+                    },
+                ) => vec![bound, opd],
+                (VdSemPrefixOpr::Base(_, _), _) => vec![opd],
+                (VdSemPrefixOpr::Composite(opr, _), _) => vec![opr, opd],
             },
-            VdSemExprData::Suffix { opd, opr, .. } => match opr {
+            VdSemExprData::Suffix { opd, opr, dispatch } => match opr {
                 VdSemSuffixOpr::Base(_, _) => vec![opd],
                 VdSemSuffixOpr::Composite(opr, _) => vec![opd, opr],
+                VdSemSuffixOpr::Pmod { modulus, .. } => {
+                    let VdSemSuffixDispatch::Congruence { rhs, .. } = dispatch;
+                    vec![opd, rhs, modulus]
+                }
             },
             VdSemExprData::Attach {
                 base, ref scripts, ..
@@ -369,6 +394,7 @@ impl VdSemExprData {
                 ..
             } => vec![numerator, denominator],
             VdSemExprData::Sqrt { radicand, .. } => vec![radicand],
+            VdSemExprData::Application { ref arguments, .. } => arguments.to_vec(),
         }
     }
 }
@@ -421,6 +447,7 @@ impl<'db> VdSemExprBuilder<'db> {
                 radicand,
                 ..
             } => todo!(),
+            VdSemExprData::Application { .. } => todo!(),
         }
     }
 }
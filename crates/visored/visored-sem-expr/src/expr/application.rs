@@ -0,0 +1,164 @@
+use super::*;
+use smallvec::SmallVec;
+use visored_entity_path::path::function::VdFunctionPath;
+use visored_global_dispatch::dispatch::function::VdFunctionGlobalDispatch;
+use visored_signature::signature::function::{VdBaseFunctionSignature, VdFunctionParameterTys};
+use visored_syn_expr::expr::{VdSynLeftDelimiter, VdSynRightDelimiter};
+
+pub type VdSemApplicationArguments = SmallVec<[VdSemExprIdx; 2]>;
+
+/// the form in which a prelude function is applied in latex
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VdSemApplicationFunction {
+    /// `\log x`, `\log(x)` or `\gcd(a, b)`
+    ///
+    /// `rpar_token_idx_range` is the closing parenthesis around the arguments, if any
+    Prefix {
+        opr_token_idx_range: LxTokenIdxRange,
+        base_opr: VdBasePrefixOpr,
+        rpar_token_idx_range: Option<LxTokenIdxRange>,
+    },
+    /// `\lfloor x \rfloor` or `\lvert x \rvert`
+    Delimited {
+        left_delimiter_token_idx_range: LxTokenIdxRange,
+        left_delimiter: VdBaseLeftDelimiter,
+        right_delimiter_token_idx_range: LxTokenIdxRange,
+        right_delimiter: VdBaseRightDelimiter,
+    },
+    /// `\binom{n}{k}`
+    Binom {
+        command_token_idx: LxMathTokenIdx,
+        lower_arg: LxMathCompleteCommandArgument,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VdSemApplicationDispatch {
+    Base { signature: VdBaseFunctionSignature },
+}
+
+impl<'a> VdSemExprBuilder<'a> {
+    /// returns `None` if the prefix opr is not a function
+    pub(super) fn build_prefix_application(
+        &mut self,
+        opr_token_idx_range: LxTokenIdxRange,
+        base_opr: VdBasePrefixOpr,
+        syn_opd: VdSynExprIdx,
+    ) -> Option<(VdSemExprData, VdType)> {
+        let function_path = match base_opr {
+            VdBasePrefixOpr::Log => VdFunctionPath::LOG,
+            VdBasePrefixOpr::Exp => VdFunctionPath::EXP,
+            VdBasePrefixOpr::Gcd => VdFunctionPath::GCD,
+            VdBasePrefixOpr::Max => VdFunctionPath::MAX,
+            VdBasePrefixOpr::Min => VdFunctionPath::MIN,
+            _ => return None,
+        };
+        let (syn_arguments, rpar_token_idx_range) = match self.syn_expr_arena()[syn_opd] {
+            VdSynExprData::Delimited {
+                left_delimiter: VdSynLeftDelimiter::Base(_, VdBaseLeftDelimiter::Lpar),
+                item,
+                right_delimiter:
+                    VdSynRightDelimiter::Base(rpar_token_idx_range, VdBaseRightDelimiter::Rpar),
+            } => match self.syn_expr_arena()[item] {
+                VdSynExprData::SeparatedList {
+                    separator_class: VdSeparatorClass::Comma,
+                    items,
+                    ..
+                } => (items.into_iter().collect(), Some(rpar_token_idx_range)),
+                _ => (smallvec::smallvec![item], Some(rpar_token_idx_range)),
+            },
+            _ => (smallvec::smallvec![syn_opd], None),
+        };
+        let function = VdSemApplicationFunction::Prefix {
+            opr_token_idx_range,
+            base_opr,
+            rpar_token_idx_range,
+        };
+        Some(self.build_application(function, function_path, syn_arguments))
+    }
+
+    pub(super) fn build_delimited_application(
+        &mut self,
+        left_delimiter_token_idx_range: LxTokenIdxRange,
+        left_delimiter: VdBaseLeftDelimiter,
+        syn_item: VdSynExprIdx,
+        right_delimiter_token_idx_range: LxTokenIdxRange,
+        right_delimiter: VdBaseRightDelimiter,
+    ) -> (VdSemExprData, VdType) {
+        let function_path = match (left_delimiter, right_delimiter) {
+            (VdBaseLeftDelimiter::Lfloor, VdBaseRightDelimiter::Rfloor) => VdFunctionPath::FLOOR,
+            (VdBaseLeftDelimiter::Lvert, VdBaseRightDelimiter::Rvert) => VdFunctionPath::ABS,
+            _ => todo!("mismatched delimiters"),
+        };
+        let function = VdSemApplicationFunction::Delimited {
+            left_delimiter_token_idx_range,
+            left_delimiter,
+            right_delimiter_token_idx_range,
+            right_delimiter,
+        };
+        self.build_application(function, function_path, smallvec::smallvec![syn_item])
+    }
+
+    pub(super) fn build_binom(
+        &mut self,
+        command_token_idx: LxMathTokenIdx,
+        syn_upper: VdSynExprIdx,
+        syn_lower: VdSynExprIdx,
+        lower_arg: LxMathCompleteCommandArgument,
+    ) -> (VdSemExprData, VdType) {
+        let function = VdSemApplicationFunction::Binom {
+            command_token_idx,
+            lower_arg,
+        };
+        self.build_application(
+            function,
+            VdFunctionPath::BINOM,
+            smallvec::smallvec![syn_upper, syn_lower],
+        )
+    }
+
+    fn build_application(
+        &mut self,
+        function: VdSemApplicationFunction,
+        function_path: VdFunctionPath,
+        syn_arguments: SmallVec<[VdSynExprIdx; 2]>,
+    ) -> (VdSemExprData, VdType) {
+        let arguments: SmallVec<[VdSemExprEntry; 2]> = syn_arguments
+            .iter()
+            .map(|&syn_argument| self.build_expr_entry(syn_argument))
+            .collect();
+        let argument_tys = match *arguments {
+            [ref argument] => VdFunctionParameterTys::Unary(argument.ty()),
+            [ref largument, ref rargument] => {
+                VdFunctionParameterTys::Binary(largument.ty(), rargument.ty())
+            }
+            _ => todo!("arity = {}", arguments.len()),
+        };
+        let Some(VdFunctionGlobalDispatch::Base { signature }) = self
+            .default_global_dispatch_table()
+            .base_function_default_dispatch(function_path, argument_tys)
+        else {
+            todo!(
+                "no default dispatch for function_path = {:?}, argument_tys = {:?}",
+                function_path,
+                argument_tys
+            )
+        };
+        let arguments = syn_arguments
+            .into_iter()
+            .zip(arguments)
+            .enumerate()
+            .map(|(index, (syn_argument, argument))| {
+                self.alloc_expr(syn_argument, argument, Some(signature.parameter_ty(index)))
+            })
+            .collect();
+        (
+            VdSemExprData::Application {
+                function,
+                arguments,
+                dispatch: VdSemApplicationDispatch::Base { signature },
+            },
+            signature.expr_ty(),
+        )
+    }
+}
@@ -21,6 +21,22 @@ impl<'a> VdSemExprBuilder<'a> {
         syn_item: VdSynExprIdx,
         right_delimiter: VdSynRightDelimiter,
     ) -> (VdSemExprData, VdType) {
+        if let (
+            VdSynLeftDelimiter::Base(
+                left_range,
+                left_base @ (VdBaseLeftDelimiter::Lfloor | VdBaseLeftDelimiter::Lvert),
+            ),
+            VdSynRightDelimiter::Base(right_range, right_base),
+        ) = (left_delimiter, right_delimiter)
+        {
+            return self.build_delimited_application(
+                left_range,
+                left_base,
+                syn_item,
+                right_range,
+                right_base,
+            );
+        }
         let left_delimiter = match left_delimiter {
             VdSynLeftDelimiter::Base(range, VdBaseLeftDelimiter::Lpar) => {
                 VdSemLeftDelimiter::Base(range, VdBaseLeftDelimiter::Lpar)
@@ -1,6 +1,10 @@
 use super::*;
+use symbol::local_defn::VdSemSymbolLocalDefnIdx;
 use visored_global_dispatch::dispatch::prefix_opr::VdPrefixOprGlobalDispatch;
-use visored_syn_expr::expr::VdSynPrefixOpr;
+use visored_syn_expr::{
+    expr::{prefix::VdSynBinder, VdSynPrefixOpr},
+    symbol::resolution::{letter::VdSynLetterSymbolResolution, VdSynSymbolResolution},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VdSemPrefixOpr {
@@ -12,6 +16,16 @@ pub enum VdSemPrefixOpr {
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VdSemPrefixDispatch {
     Global(VdPrefixOprGlobalDispatch),
+    /// `\forall x \in S, P` or `\exists x \in S, P`, with `P` as the operand
+    Quantification {
+        variable: VdSemSymbolLocalDefnIdx,
+        domain: VdSemExprIdx,
+    },
+    /// `\lim_{x \to a} f(x)`, with `f(x)` as the operand
+    Limit {
+        variable: VdSemSymbolLocalDefnIdx,
+        approached: VdSemExprIdx,
+    },
 }
 
 impl<'a> VdSemExprBuilder<'a> {
@@ -20,20 +34,91 @@ impl<'a> VdSemExprBuilder<'a> {
         syn_opr: VdSynPrefixOpr,
         syn_opd: VdSynExprIdx,
     ) -> (VdSemExprData, VdType) {
+        if let Some((binder, syn_body)) = syn_opr.binding(syn_opd, self.syn_expr_arena()) {
+            return self.build_binding_prefix(syn_opr, binder, syn_body);
+        }
         match syn_opr {
             VdSynPrefixOpr::Base(opr_range, base_opr) => {
                 self.build_base_prefix(opr_range, base_opr, syn_opd)
             }
             VdSynPrefixOpr::Composite(arena_idx, opr) => todo!(),
+            VdSynPrefixOpr::Subscripted { .. } => {
+                unreachable!("only binding operators are subscripted")
+            }
         }
     }
 
+    /// the binder and the body are built directly, skipping the syn nodes grouping them
+    fn build_binding_prefix(
+        &mut self,
+        syn_opr: VdSynPrefixOpr,
+        binder: VdSynBinder,
+        syn_body: VdSynExprIdx,
+    ) -> (VdSemExprData, VdType) {
+        let (opr_range, base_opr) = match syn_opr {
+            VdSynPrefixOpr::Base(opr_range, base_opr)
+            | VdSynPrefixOpr::Subscripted {
+                token_idx_range: opr_range,
+                opr: base_opr,
+                ..
+            } => (opr_range, base_opr),
+            VdSynPrefixOpr::Composite(_, _) => unreachable!(),
+        };
+        let variable = self.build_binder_variable(binder);
+        let ty_menu = self.ty_menu();
+        let (dispatch, ty) = match base_opr {
+            VdBasePrefixOpr::Forall | VdBasePrefixOpr::Exists => {
+                let domain = (binder.bound, ty_menu.set).to_vd_sem(self);
+                let variable_ty = self.infer_expr_term(domain).to_ty();
+                self.set_local_defn_ty(variable, variable_ty);
+                (
+                    VdSemPrefixDispatch::Quantification { variable, domain },
+                    ty_menu.prop,
+                )
+            }
+            VdBasePrefixOpr::Lim => {
+                // TODO: limits of sequences and of complex functions
+                self.set_local_defn_ty(variable, ty_menu.real);
+                let approached = (binder.bound, ty_menu.real).to_vd_sem(self);
+                (
+                    VdSemPrefixDispatch::Limit {
+                        variable,
+                        approached,
+                    },
+                    ty_menu.real,
+                )
+            }
+            _ => unreachable!(),
+        };
+        let opd = (syn_body, ty).to_vd_sem(self);
+        (
+            VdSemExprData::Prefix {
+                opr: VdSemPrefixOpr::Base(opr_range, base_opr),
+                opd,
+                dispatch,
+            },
+            ty,
+        )
+    }
+
+    fn build_binder_variable(&mut self, binder: VdSynBinder) -> VdSemSymbolLocalDefnIdx {
+        let Ok(VdSynSymbolResolution::Letter(VdSynLetterSymbolResolution::Local(local_defn))) =
+            self.syn_symbol_resolution_table()[binder.letter_expr]
+        else {
+            unreachable!("the binder defines its letter")
+        };
+        local_defn.to_vd_sem(self)
+    }
+
     fn build_base_prefix(
         &mut self,
         opr_range: LxTokenIdxRange,
         base_opr: VdBasePrefixOpr,
         syn_opd: VdSynExprIdx,
     ) -> (VdSemExprData, VdType) {
+        if let Some(application) = self.build_prefix_application(opr_range, base_opr, syn_opd) {
+            return application;
+        }
         let opr = VdSemPrefixOpr::Base(opr_range, base_opr);
         let opd = self.build_expr_entry(syn_opd);
         if let Some(dispatch) = self
@@ -110,7 +110,7 @@ impl<'db> VdSemExprBuilder<'db> {
         let leader = self.alloc_expr(items.first().unwrap(), leader, Some(leader_expected_ty));
         let ty = followers.last().unwrap().dispatch.expr_ty();
         let data = match separator_class {
-            VdSeparatorClass::Relation => {
+            VdSeparatorClass::Relation | VdSeparatorClass::Logic => {
                 let joined_separator_and_signature =
                     self.infer_joined_separator_and_signature(&followers);
                 VdSemExprData::ChainingSeparatedList {
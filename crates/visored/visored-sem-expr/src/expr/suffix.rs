@@ -1,7 +1,95 @@
 use super::*;
+use visored_global_dispatch::dispatch::{
+    binary_opr::VdBinaryOprGlobalDispatch, separator::VdSeparatorGlobalDispatch,
+};
+use visored_signature::signature::binary_opr::base::VdBaseBinaryOprSignature;
+use visored_syn_expr::expr::VdSynSuffixOpr;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VdSemSuffixOpr {
     Base(LxTokenIdxRange, VdBaseSuffixOpr),
     Composite(VdSemExprIdx, VdCompositeSuffixOpr),
+    /// `\pmod{n}` in `a \equiv b \pmod{n}`
+    Pmod {
+        command_token_idx: LxMathTokenIdx,
+        modulus: VdSemExprIdx,
+        modulus_arg: LxMathCompleteCommandArgument,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VdSemSuffixDispatch {
+    /// `a \equiv b \pmod{n}` with `a` as the operand, meaning `n \mid a - b`
+    Congruence {
+        rhs: VdSemExprIdx,
+        sub_signature: VdBaseBinaryOprSignature,
+        dvd_signature: VdBaseSeparatorSignature,
+    },
+}
+
+impl<'a> VdSemExprBuilder<'a> {
+    pub(super) fn build_suffix(
+        &mut self,
+        syn_opd: VdSynExprIdx,
+        syn_opr: VdSynSuffixOpr,
+    ) -> (VdSemExprData, VdType) {
+        match syn_opr {
+            VdSynSuffixOpr::Base(_, opr) => match opr {},
+            VdSynSuffixOpr::Composite(_, opr) => match opr {},
+            VdSynSuffixOpr::Pmod {
+                command_token_idx,
+                modulus,
+                modulus_arg,
+            } => self.build_pmod(syn_opd, command_token_idx, modulus, modulus_arg),
+        }
+    }
+
+    /// the congruence `a \equiv b` is built directly, skipping the syn node grouping it
+    fn build_pmod(
+        &mut self,
+        syn_opd: VdSynExprIdx,
+        command_token_idx: LxMathTokenIdx,
+        syn_modulus: VdSynExprIdx,
+        modulus_arg: LxMathCompleteCommandArgument,
+    ) -> (VdSemExprData, VdType) {
+        let VdSynExprData::SeparatedList { items, .. } = self.syn_expr_arena()[syn_opd] else {
+            unreachable!("the parser has checked the congruence")
+        };
+        let ty_menu = self.ty_menu();
+        let int = ty_menu.int;
+        let table = self.default_global_dispatch_table();
+        let Some(VdBinaryOprGlobalDispatch::Normal {
+            signature: sub_signature,
+            ..
+        }) = table.base_binary_opr_default_dispatch(int, VdBaseBinaryOpr::Sub, int)
+        else {
+            unreachable!("integer subtraction is a default dispatch")
+        };
+        let Some(VdSeparatorGlobalDispatch::Chaining {
+            signature: dvd_signature,
+            ..
+        }) = table.base_separator_default_dispatch(int, VdBaseSeparator::Mid, int)
+        else {
+            unreachable!("integer divisibility is a default dispatch")
+        };
+        let lhs = (items.first().unwrap(), int).to_vd_sem(self);
+        let rhs = (items.last().unwrap(), int).to_vd_sem(self);
+        let modulus = (syn_modulus, int).to_vd_sem(self);
+        (
+            VdSemExprData::Suffix {
+                opd: lhs,
+                opr: VdSemSuffixOpr::Pmod {
+                    command_token_idx,
+                    modulus,
+                    modulus_arg,
+                },
+                dispatch: VdSemSuffixDispatch::Congruence {
+                    rhs,
+                    sub_signature,
+                    dvd_signature,
+                },
+            },
+            ty_menu.prop,
+        )
+    }
 }
@@ -151,6 +151,7 @@ impl<'a> VdSemExprLaTeXFormatter<'a> {
             VdSemExprData::Sqrt {
                 command_token_idx, ..
             } => todo!(),
+            VdSemExprData::Application { .. } => todo!(),
         }
     }
 
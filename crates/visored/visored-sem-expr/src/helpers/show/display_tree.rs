@@ -134,6 +134,7 @@ impl<'a> VdSemExprDisplayTreeBuilder<'a> {
                 ..
             } => format!("{:?} fraction", source),
             VdSemExprData::Sqrt { radicand, .. } => format!("{:?} sqrt", source),
+            VdSemExprData::Application { .. } => format!("{:?} application", source),
         };
         DisplayTree::new(
             value,
@@ -8,8 +8,10 @@ use crate::{
         VdSemDivisionMap,
     },
     expr::{
+        application::VdSemApplicationFunction,
         delimited::{VdSemLeftDelimiter, VdSemRightDelimiter},
         prefix::VdSemPrefixOpr,
+        suffix::VdSemSuffixOpr,
         VdSemExprArena, VdSemExprArenaRef, VdSemExprData, VdSemExprIdx, VdSemExprMap,
     },
     phrase::{VdSemPhraseArena, VdSemPhraseArenaRef, VdSemPhraseIdx, VdSemPhraseMap},
@@ -182,7 +184,19 @@ impl<'db> VdSemExprRangeCalculator<'db> {
                 };
                 opr_range.join(opd_range)
             }
-            VdSemExprData::Suffix { opd, opr, .. } => todo!(),
+            VdSemExprData::Suffix { opd, opr, .. } => {
+                let opd_range = self.get_expr(opd);
+                let opr_range = match opr {
+                    VdSemSuffixOpr::Base(_, opr) => match opr {},
+                    VdSemSuffixOpr::Composite(expr, _) => self.get_expr(expr),
+                    VdSemSuffixOpr::Pmod {
+                        command_token_idx,
+                        modulus_arg,
+                        ..
+                    } => self.calc_complete_command(command_token_idx, modulus_arg),
+                };
+                opd_range.join(opr_range)
+            }
             VdSemExprData::Attach {
                 base, ref scripts, ..
             } => {
@@ -253,6 +267,39 @@ impl<'db> VdSemExprRangeCalculator<'db> {
                 radicand_arg,
                 ..
             } => self.calc_complete_command(command_token_idx, radicand_arg),
+            VdSemExprData::Application {
+                function,
+                ref arguments,
+                ..
+            } => {
+                match function {
+                    VdSemApplicationFunction::Prefix {
+                        opr_token_idx_range,
+                        rpar_token_idx_range,
+                        ..
+                    } => {
+                        let opr_range = VdSemExprTokenIdxRange::Standard(opr_token_idx_range);
+                        match rpar_token_idx_range {
+                            Some(rpar_token_idx_range) => opr_range
+                                .join(VdSemExprTokenIdxRange::Standard(rpar_token_idx_range)),
+                            None => opr_range.join(self.get_expr(
+                                *arguments.last().expect("arguments are always non-empty"),
+                            )),
+                        }
+                    }
+                    VdSemApplicationFunction::Delimited {
+                        left_delimiter_token_idx_range,
+                        right_delimiter_token_idx_range,
+                        ..
+                    } => VdSemExprTokenIdxRange::Standard(
+                        left_delimiter_token_idx_range.join(right_delimiter_token_idx_range),
+                    ),
+                    VdSemApplicationFunction::Binom {
+                        command_token_idx,
+                        lower_arg,
+                    } => self.calc_complete_command(command_token_idx, lower_arg),
+                }
+            }
         }
     }
 
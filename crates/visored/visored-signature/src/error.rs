@@ -0,0 +1,12 @@
+use thiserror::Error;
+use visored_entity_path::path::trai_item::VdTraitItemPath;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum VdSignatureError {
+    #[error("`{0:?}` has no prefix operator form")]
+    NoPrefixOpr(VdTraitItemPath),
+    #[error("`{0:?}` has no binary operator form")]
+    NoBinaryOpr(VdTraitItemPath),
+}
+
+pub type VdSignatureResult<T> = Result<T, VdSignatureError>;
@@ -1,7 +1,8 @@
 use crate::signature::{
     attach::VdPowerSignature, binary_opr::base::VdBaseBinaryOprSignature,
-    frac::VdBaseFracSignature, prefix_opr::VdBasePrefixOprSignature,
-    separator::base::VdBaseSeparatorSignature, sqrt::VdBaseSqrtSignature,
+    frac::VdBaseFracSignature, function::VdBaseFunctionSignature,
+    prefix_opr::VdBasePrefixOprSignature, separator::base::VdBaseSeparatorSignature,
+    sqrt::VdBaseSqrtSignature,
 };
 use eterned::{db::EternerDb, memo};
 use lazy_static::lazy_static;
@@ -84,8 +85,40 @@ pub struct VdSignatureMenu {
     pub int_ge: VdBaseSeparatorSignature,
     pub rat_ge: VdBaseSeparatorSignature,
     pub real_ge: VdBaseSeparatorSignature,
+    /// ## dvd
+    pub nat_dvd: VdBaseSeparatorSignature,
+    pub int_dvd: VdBaseSeparatorSignature,
+    /// ## logic
+    pub prop_implies: VdBaseSeparatorSignature,
+    pub prop_iff: VdBaseSeparatorSignature,
     // # sqrt
     pub real_sqrt: VdBaseSqrtSignature,
+    /// # functions
+    /// ## log
+    pub real_log: VdBaseFunctionSignature,
+    /// ## exp
+    pub real_exp: VdBaseFunctionSignature,
+    /// ## gcd
+    pub nat_gcd: VdBaseFunctionSignature,
+    pub int_gcd: VdBaseFunctionSignature,
+    /// ## max
+    pub nat_max: VdBaseFunctionSignature,
+    pub int_max: VdBaseFunctionSignature,
+    pub rat_max: VdBaseFunctionSignature,
+    pub real_max: VdBaseFunctionSignature,
+    /// ## min
+    pub nat_min: VdBaseFunctionSignature,
+    pub int_min: VdBaseFunctionSignature,
+    pub rat_min: VdBaseFunctionSignature,
+    pub real_min: VdBaseFunctionSignature,
+    /// ## binom
+    pub nat_binom: VdBaseFunctionSignature,
+    /// ## floor
+    pub real_floor: VdBaseFunctionSignature,
+    /// ## abs
+    pub int_abs: VdBaseFunctionSignature,
+    pub rat_abs: VdBaseFunctionSignature,
+    pub real_abs: VdBaseFunctionSignature,
 }
 
 impl VdSignatureMenu {
@@ -156,12 +189,39 @@ impl VdSignatureMenu {
             int_ge,
             rat_ge,
             real_ge,
+            nat_dvd,
+            int_dvd,
+            prop_implies,
+            prop_iff,
             real_sqrt,
+            real_log,
+            real_exp,
+            nat_gcd,
+            int_gcd,
+            nat_max,
+            int_max,
+            rat_max,
+            real_max,
+            nat_min,
+            int_min,
+            rat_min,
+            real_min,
+            nat_binom,
+            real_floor,
+            int_abs,
+            rat_abs,
+            real_abs,
         } = *vd_instantiation_menu(db);
-        let pre = VdBasePrefixOprSignature::new;
-        let bin = VdBaseBinaryOprSignature::new;
+        let pre = |instantiation, opd_ty, expr_ty| {
+            VdBasePrefixOprSignature::new(instantiation, opd_ty, expr_ty).unwrap()
+        };
+        let bin = |instantiation, lopd_ty, ropd_ty, expr_ty| {
+            VdBaseBinaryOprSignature::new(instantiation, lopd_ty, ropd_ty, expr_ty).unwrap()
+        };
         let sep = VdBaseSeparatorSignature::new;
         let pow = VdPowerSignature::new;
+        let un = VdBaseFunctionSignature::new_unary;
+        let bi = VdBaseFunctionSignature::new_binary;
         Self {
             // # prefix operators
             // ## pos
@@ -232,9 +292,42 @@ impl VdSignatureMenu {
             int_ge: sep(int_ge, int, prop),
             rat_ge: sep(rat_ge, rat, prop),
             real_ge: sep(real_ge, real, prop),
+            // ## dvd
+            nat_dvd: sep(nat_dvd, nat, prop),
+            int_dvd: sep(int_dvd, int, prop),
+            // ## logic
+            prop_implies: sep(prop_implies, prop, prop),
+            prop_iff: sep(prop_iff, prop, prop),
             // # sqrt
             // TODO: use nnreal, i.e., non-negative real numbers
             real_sqrt: VdBaseSqrtSignature::new(real_sqrt, real, real),
+            // # functions
+            // ## log
+            // TODO: use posreal, i.e., positive real numbers
+            real_log: un(real_log, real, real),
+            // ## exp
+            real_exp: un(real_exp, real, real),
+            // ## gcd
+            nat_gcd: bi(nat_gcd, nat, nat, nat),
+            int_gcd: bi(int_gcd, int, int, nat),
+            // ## max
+            nat_max: bi(nat_max, nat, nat, nat),
+            int_max: bi(int_max, int, int, int),
+            rat_max: bi(rat_max, rat, rat, rat),
+            real_max: bi(real_max, real, real, real),
+            // ## min
+            nat_min: bi(nat_min, nat, nat, nat),
+            int_min: bi(int_min, int, int, int),
+            rat_min: bi(rat_min, rat, rat, rat),
+            real_min: bi(real_min, real, real, real),
+            // ## binom
+            nat_binom: bi(nat_binom, nat, nat, nat),
+            // ## floor
+            real_floor: un(real_floor, real, int),
+            // ## abs
+            int_abs: un(int_abs, int, int),
+            rat_abs: un(rat_abs, rat, rat),
+            real_abs: un(real_abs, real, real),
             // # attach
             // ## power
             nat_to_the_power_of_nat: pow(nat_to_the_power_of_nat, nat, nat, nat),
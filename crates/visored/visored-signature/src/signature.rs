@@ -16,7 +16,7 @@ use self::{
     separator::*,
     sqrt::*,
 };
-use crate::*;
+use crate::{error::VdSignatureResult, *};
 use eterned::db::EternerDb;
 use lisp_csv::expr::{LpCsvExpr, LpCsvExprData};
 use separator::base::VdBaseSeparatorSignature;
@@ -34,7 +34,7 @@ pub enum VdSignature {
 }
 
 impl VdSignature {
-    pub fn from_lp_csv_exprs(exprs: &[LpCsvExpr], db: &EternerDb) -> Self {
+    pub fn from_lp_csv_exprs(exprs: &[LpCsvExpr], db: &EternerDb) -> VdSignatureResult<Self> {
         assert_eq!(exprs.len(), 2);
         let instantiation = VdInstantiation::from_lp_csv_expr(&exprs[0], db);
         let (
@@ -47,14 +47,14 @@ impl VdSignature {
         else {
             unreachable!()
         };
-        match variant_ident.as_str() {
+        Ok(match variant_ident.as_str() {
             "base_prefix_opr" => {
                 assert_eq!(args.len(), 2);
                 VdBasePrefixOprSignature::new(
                     instantiation,
                     VdType::from_lp_csv_expr(&args[0], db),
                     VdType::from_lp_csv_expr(&args[1], db),
-                )
+                )?
                 .into()
             }
             "base_binary_opr" => {
@@ -69,7 +69,7 @@ impl VdSignature {
                     VdType::from_lp_csv_expr(&args[0], db),
                     VdType::from_lp_csv_expr(&args[1], db),
                     VdType::from_lp_csv_expr(&args[2], db),
-                )
+                )?
                 .into()
             }
             "base_folding" => {
@@ -99,6 +99,25 @@ impl VdSignature {
                 )
                 .into()
             }
+            "base_unary_function" => {
+                assert_eq!(args.len(), 2);
+                VdBaseFunctionSignature::new_unary(
+                    instantiation,
+                    VdType::from_lp_csv_expr(&args[0], db),
+                    VdType::from_lp_csv_expr(&args[1], db),
+                )
+                .into()
+            }
+            "base_binary_function" => {
+                assert_eq!(args.len(), 3);
+                VdBaseFunctionSignature::new_binary(
+                    instantiation,
+                    VdType::from_lp_csv_expr(&args[0], db),
+                    VdType::from_lp_csv_expr(&args[1], db),
+                    VdType::from_lp_csv_expr(&args[2], db),
+                )
+                .into()
+            }
             "power" => {
                 assert_eq!(args.len(), 3);
                 VdPowerSignature::new(
@@ -110,6 +129,36 @@ impl VdSignature {
                 .into()
             }
             s => todo!("s = {s:?} not handled"),
-        }
+        })
     }
 }
+
+#[test]
+fn vd_signature_from_lp_csv_exprs_rejects_missing_operator_forms() {
+    use crate::error::VdSignatureError;
+    use lisp_csv::{file::LpCsvFileData, parse_lp_csv_file, row::LpCsvRow};
+    use visored_entity_path::path::trai_item::VdTraitItemPath;
+
+    let db = &EternerDb::default();
+    let file = parse_lp_csv_file(
+        "nat_dvd = dvd nat, base_prefix_opr nat prop\nprop_iff = iff, base_binary_opr prop prop prop\n",
+    )
+    .unwrap();
+    let LpCsvFileData::Rows(rows) = file.data();
+    let errors: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            let LpCsvRow::SeparatedExprs(exprs) = row else {
+                unreachable!()
+            };
+            VdSignature::from_lp_csv_exprs(&exprs[1..], db).unwrap_err()
+        })
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            VdSignatureError::NoPrefixOpr(VdTraitItemPath::Dvd),
+            VdSignatureError::NoBinaryOpr(VdTraitItemPath::Iff),
+        ]
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::error::{VdSignatureError, VdSignatureResult};
 use visored_entity_path::path::{trai_item::VdTraitItemPath, VdItemPath};
 use visored_mir_opr::opr::binary::VdMirBaseBinaryOpr;
 
@@ -25,7 +26,7 @@ impl VdBaseBinaryOprSignature {
         lopd_ty: VdType,
         ropd_ty: VdType,
         expr_ty: VdType,
-    ) -> Self {
+    ) -> VdSignatureResult<Self> {
         let VdItemPath::TraitItem(path) = instantiation.path() else {
             unreachable!()
         };
@@ -48,14 +49,17 @@ impl VdBaseBinaryOprSignature {
             VdTraitItemPath::Ge => todo!(),
             VdTraitItemPath::FieldDiv => VdMirBaseBinaryOpr::COMM_FIELD_DIV,
             VdTraitItemPath::RealSqrt => todo!(),
+            VdTraitItemPath::Dvd | VdTraitItemPath::Implies | VdTraitItemPath::Iff => {
+                return Err(VdSignatureError::NoBinaryOpr(path));
+            }
         };
-        Self {
+        Ok(Self {
             instantiation,
             opr,
             lopd_ty,
             ropd_ty,
             expr_ty,
-        }
+        })
     }
 }
 
@@ -1,2 +1,86 @@
+use super::*;
+
+#[salsa::derive_debug_with_db]
+#[enum_class::from_variants]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum VdFunctionSignature {}
+pub enum VdFunctionSignature {
+    Base(VdBaseFunctionSignature),
+}
+
+/// signature of a prelude function like `\log`, `\gcd` or `\binom` applied to its arguments
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct VdBaseFunctionSignature {
+    instantiation: VdInstantiation,
+    parameter_tys: VdFunctionParameterTys,
+    expr_ty: VdType,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum VdFunctionParameterTys {
+    Unary(VdType),
+    Binary(VdType, VdType),
+}
+
+impl From<VdBaseFunctionSignature> for VdSignature {
+    fn from(signature: VdBaseFunctionSignature) -> Self {
+        VdSignature::Function(VdFunctionSignature::Base(signature))
+    }
+}
+
+impl VdBaseFunctionSignature {
+    pub fn new_unary(
+        instantiation: VdInstantiation,
+        parameter_ty: VdType,
+        expr_ty: VdType,
+    ) -> Self {
+        Self {
+            instantiation,
+            parameter_tys: VdFunctionParameterTys::Unary(parameter_ty),
+            expr_ty,
+        }
+    }
+
+    pub fn new_binary(
+        instantiation: VdInstantiation,
+        lparameter_ty: VdType,
+        rparameter_ty: VdType,
+        expr_ty: VdType,
+    ) -> Self {
+        Self {
+            instantiation,
+            parameter_tys: VdFunctionParameterTys::Binary(lparameter_ty, rparameter_ty),
+            expr_ty,
+        }
+    }
+}
+
+impl VdBaseFunctionSignature {
+    pub fn instantiation(self) -> VdInstantiation {
+        self.instantiation
+    }
+
+    pub fn parameter_tys(self) -> VdFunctionParameterTys {
+        self.parameter_tys
+    }
+
+    pub fn arity(self) -> usize {
+        match self.parameter_tys {
+            VdFunctionParameterTys::Unary(_) => 1,
+            VdFunctionParameterTys::Binary(_, _) => 2,
+        }
+    }
+
+    pub fn parameter_ty(self, index: usize) -> VdType {
+        match (self.parameter_tys, index) {
+            (VdFunctionParameterTys::Unary(ty), 0) => ty,
+            (VdFunctionParameterTys::Binary(ty, _), 0) => ty,
+            (VdFunctionParameterTys::Binary(_, ty), 1) => ty,
+            _ => unreachable!("index = {index} out of bounds"),
+        }
+    }
+
+    pub fn expr_ty(self) -> VdType {
+        self.expr_ty
+    }
+}
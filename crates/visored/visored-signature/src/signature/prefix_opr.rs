@@ -1,4 +1,5 @@
 use super::*;
+use crate::error::{VdSignatureError, VdSignatureResult};
 use visored_entity_path::path::{trai_item::VdTraitItemPath, VdItemPath};
 use visored_mir_opr::opr::prefix::VdMirBasePrefixOpr;
 
@@ -26,7 +27,11 @@ impl From<VdBasePrefixOprSignature> for VdSignature {
 }
 
 impl VdBasePrefixOprSignature {
-    pub fn new(instantiation: VdInstantiation, opd_ty: VdType, expr_ty: VdType) -> Self {
+    pub fn new(
+        instantiation: VdInstantiation,
+        opd_ty: VdType,
+        expr_ty: VdType,
+    ) -> VdSignatureResult<Self> {
         let VdItemPath::TraitItem(path) = instantiation.path() else {
             unreachable!()
         };
@@ -49,13 +54,16 @@ impl VdBasePrefixOprSignature {
             VdTraitItemPath::Ge => todo!(),
             VdTraitItemPath::FieldDiv => todo!(),
             VdTraitItemPath::RealSqrt => todo!(),
+            VdTraitItemPath::Dvd | VdTraitItemPath::Implies | VdTraitItemPath::Iff => {
+                return Err(VdSignatureError::NoPrefixOpr(path));
+            }
         };
-        Self {
+        Ok(Self {
             instantiation,
             opr,
             opd_ty,
             expr_ty,
-        }
+        })
     }
 }
 
@@ -42,6 +42,9 @@ impl VdBaseSeparatorSignature {
             VdTraitItemPath::Ge => VdMirBaseSeparator::GE,
             VdTraitItemPath::FieldDiv => todo!(),
             VdTraitItemPath::RealSqrt => todo!(),
+            VdTraitItemPath::Dvd => VdMirBaseSeparator::DVD,
+            VdTraitItemPath::Implies => VdMirBaseSeparator::IMPLIES,
+            VdTraitItemPath::Iff => VdMirBaseSeparator::IFF,
         };
         Self {
             instantiation,
@@ -64,7 +64,8 @@ impl VdSignatureTable {
                         LpCsvExprData::Ident(ref ident) => ident.to_string(),
                         _ => todo!(),
                     };
-                    let signature = VdSignature::from_lp_csv_exprs(&exprs[1..], db);
+                    let signature = VdSignature::from_lp_csv_exprs(&exprs[1..], db)
+                        .unwrap_or_else(|e| panic!("invalid signature for `{ident}`: {e}"));
                     (ident, signature)
                 }
             };
@@ -147,7 +148,28 @@ fn vd_signature_table_from_lp_csv_rows_works() {
         int_ge,
         rat_ge,
         real_ge,
+        nat_dvd,
+        int_dvd,
+        prop_implies,
+        prop_iff,
         real_sqrt,
+        real_log,
+        real_exp,
+        nat_gcd,
+        int_gcd,
+        nat_max,
+        int_max,
+        rat_max,
+        real_max,
+        nat_min,
+        int_min,
+        rat_min,
+        real_min,
+        nat_binom,
+        real_floor,
+        int_abs,
+        rat_abs,
+        real_abs,
     } = *vd_signature_menu(db);
     let entries: Vec<(&str, VdSignature)> = vec![
         ("int_pos", int_pos.into()),
@@ -209,7 +231,28 @@ fn vd_signature_table_from_lp_csv_rows_works() {
         ("int_ge", int_ge.into()),
         ("rat_ge", rat_ge.into()),
         ("real_ge", real_ge.into()),
+        ("nat_dvd", nat_dvd.into()),
+        ("int_dvd", int_dvd.into()),
+        ("prop_implies", prop_implies.into()),
+        ("prop_iff", prop_iff.into()),
         ("real_sqrt", real_sqrt.into()),
+        ("real_log", real_log.into()),
+        ("real_exp", real_exp.into()),
+        ("nat_gcd", nat_gcd.into()),
+        ("int_gcd", int_gcd.into()),
+        ("nat_max", nat_max.into()),
+        ("int_max", int_max.into()),
+        ("rat_max", rat_max.into()),
+        ("real_max", real_max.into()),
+        ("nat_min", nat_min.into()),
+        ("int_min", int_min.into()),
+        ("rat_min", rat_min.into()),
+        ("real_min", real_min.into()),
+        ("nat_binom", nat_binom.into()),
+        ("real_floor", real_floor.into()),
+        ("int_abs", int_abs.into()),
+        ("rat_abs", rat_abs.into()),
+        ("real_abs", real_abs.into()),
     ];
     for (key, signature) in entries {
         assert_eq!(
@@ -130,7 +130,8 @@ impl<'db> VdSynExprBuilder<'db> {
                     vibe,
                 )
             }
-            LxRoseAstData::NewParagraph(_) => {
+            // malformed tokens stay in the ast
+            LxRoseAstData::NewParagraph(_) | LxRoseAstData::Error(_, _) => {
                 asts.next();
                 self.build_stmt(asts, vibe)?
             }
@@ -174,6 +175,10 @@ impl<'db> VdSynExprBuilder<'db> {
                 }
                 LxRoseAstData::Environment { .. } => todo!(),
                 LxRoseAstData::NewParagraph(_) => break,
+                // malformed tokens stay in the ast
+                LxRoseAstData::Error(_, _) => {
+                    asts.next();
+                }
             }
         }
         VdSynBlockData::Paragraph(self.alloc_sentences(sentences))
@@ -134,6 +134,7 @@ impl<'db> VdSynExprBuilder<'db> {
                     } => todo!(),
                     LxRoseAstData::Environment { .. } => todo!(),
                     LxRoseAstData::NewParagraph(_) => todo!(),
+                    LxRoseAstData::Error(_, _) => todo!(),
                 }
             }
             "Assume" | "assume" | "Suppose" | "suppose" => {
@@ -170,6 +171,7 @@ impl<'db> VdSynExprBuilder<'db> {
                     } => todo!(),
                     LxRoseAstData::Environment { .. } => todo!(),
                     LxRoseAstData::NewParagraph(_) => todo!(),
+                    LxRoseAstData::Error(_, _) => todo!(),
                 }
             }
             "Then" | "then" => {
@@ -206,6 +208,7 @@ impl<'db> VdSynExprBuilder<'db> {
                     } => todo!(),
                     LxRoseAstData::Environment { .. } => todo!(),
                     LxRoseAstData::NewParagraph(_) => todo!(),
+                    LxRoseAstData::Error(_, _) => todo!(),
                 }
             }
             _ => todo!(), // VdSynClauseData::Todo(token_idx),
@@ -239,6 +242,14 @@ impl<'db> VdSynSymbolBuilder<'db> {
             VdSynLetClauseResolution::Placeholder(resolution) => {
                 self.build_symbols_in_let_placeholder_resolution(clause, resolution)
             }
+            // the formula still gets built so that its symbols are resolved
+            VdSynLetClauseResolution::Err(_) => {
+                let VdSynClauseData::Let { formula, .. } = *self.clause_arena()[clause].data()
+                else {
+                    unreachable!()
+                };
+                self.build_expr(formula)
+            }
         }
     }
 }
@@ -4,6 +4,8 @@ pub mod placeholder;
 use self::{assigned::VdSynLetAssignedResolution, placeholder::VdSynLetPlaceholderResolution};
 use super::*;
 use expr::{VdSynExprData, VdSynExprIdxRange, VdSynSeparator};
+use latex_token::idx::LxTokenIdxRange;
+use thiserror::Error;
 use visored_opr::separator::VdBaseSeparator;
 
 #[enum_class::from_variants]
@@ -11,6 +13,26 @@ use visored_opr::separator::VdBaseSeparator;
 pub enum VdSynLetClauseResolution {
     Assigned(VdSynLetAssignedResolution),
     Placeholder(VdSynLetPlaceholderResolution),
+    Err(VdSynLetClauseError),
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+pub enum VdSynLetClauseError {
+    #[error("separator `{separator:?}` doesn't introduce a symbol")]
+    NonDefiningSeparator {
+        token_idx_range: LxTokenIdxRange,
+        separator: VdBaseSeparator,
+    },
+}
+
+impl VdSynLetClauseError {
+    pub fn token_idx_range(&self) -> LxTokenIdxRange {
+        match *self {
+            VdSynLetClauseError::NonDefiningSeparator {
+                token_idx_range, ..
+            } => token_idx_range,
+        }
+    }
 }
 
 impl<'db> VdSynSymbolBuilder<'db> {
@@ -62,6 +84,12 @@ impl<'db> VdSynSymbolBuilder<'db> {
                 denominator,
                 ..
             } => todo!(),
+            VdSynExprData::Binom {
+                command_token_idx,
+                upper,
+                lower,
+                ..
+            } => todo!(),
             VdSynExprData::Sqrt {
                 command_token_idx,
                 radicand,
@@ -101,7 +129,7 @@ impl<'db> VdSynSymbolBuilder<'db> {
         separator: VdSynSeparator,
     ) -> VdSynLetClauseResolution {
         match separator {
-            VdSynSeparator::Base(_, base_separator) => match base_separator {
+            VdSynSeparator::Base(token_idx_range, base_separator) => match base_separator {
                 VdBaseSeparator::Space => todo!(),
                 VdBaseSeparator::Comma => todo!(),
                 VdBaseSeparator::Semicolon => todo!(),
@@ -129,6 +157,15 @@ impl<'db> VdSynSymbolBuilder<'db> {
                 VdBaseSeparator::Times => todo!(),
                 VdBaseSeparator::Otimes => todo!(),
                 VdBaseSeparator::Ne => todo!(),
+                VdBaseSeparator::Mid
+                | VdBaseSeparator::Equiv
+                | VdBaseSeparator::To
+                | VdBaseSeparator::Implies
+                | VdBaseSeparator::Iff => VdSynLetClauseError::NonDefiningSeparator {
+                    token_idx_range,
+                    separator: base_separator,
+                }
+                .into(),
             },
             VdSynSeparator::Composite(_, separator_class) => todo!(),
         }
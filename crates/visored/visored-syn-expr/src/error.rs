@@ -26,6 +26,17 @@ pub enum OriginalVdSynExprError {
     UnterminatedList { bra_token_idx: LxTokenIdx },
     #[error("no left operand for binary operator")]
     NoLeftOperandForBinaryOperator { opr: VdSynBinaryOpr },
+    /// like the use of a malformed macro
+    #[error("malformed latex token")]
+    MalformedToken(LxTokenIdxRange),
+    /// like `\forall x, P` without a domain or `\lim_{x} f(x)` without a limit point;
+    /// the range is that of the operator
+    #[error("malformed binder")]
+    MalformedBinder(LxTokenIdxRange),
+    /// like `a < b \pmod{n}`, where `\pmod` doesn't follow a congruence `a \equiv b`;
+    /// the range is that of `\pmod`
+    #[error("malformed congruence")]
+    MalformedCongruence(LxTokenIdxRange),
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -52,9 +63,19 @@ impl OriginalVdSynExprError {
             OriginalVdSynExprError::Empty(range) => range,
             OriginalVdSynExprError::Todo => todo!(),
             OriginalVdSynExprError::NoRightOperandForBinaryOperator { .. } => todo!(),
-            OriginalVdSynExprError::NoOperandForPrefixOperator { .. } => todo!(),
+            OriginalVdSynExprError::NoOperandForPrefixOperator { opr } => match opr {
+                VdSynPrefixOpr::Base(range, _)
+                | VdSynPrefixOpr::Subscripted {
+                    token_idx_range: range,
+                    ..
+                } => range,
+                VdSynPrefixOpr::Composite(_, _) => todo!(),
+            },
             OriginalVdSynExprError::UnterminatedList { bra_token_idx } => todo!(),
             OriginalVdSynExprError::NoLeftOperandForBinaryOperator { opr } => todo!(),
+            OriginalVdSynExprError::MalformedToken(range)
+            | OriginalVdSynExprError::MalformedBinder(range)
+            | OriginalVdSynExprError::MalformedCongruence(range) => range,
         }
     }
 }
@@ -35,6 +35,7 @@ use smallvec::{smallvec, SmallVec};
 use std::fmt::Formatter;
 use symbol::{
     builder::VdSynSymbolBuilder,
+    local_defn::{VdSynSymbolLocalDefnBody, VdSynSymbolLocalDefnHead, VdSynSymbolLocalDefnSrc},
     resolution::{
         error::VdSynSymbolResolutionResult, letter::VdSynLetterSymbolResolution,
         VdSynSymbolResolution, VdSynSymbolResolutions,
@@ -113,6 +114,12 @@ pub enum VdSynExprData {
         denominator: VdSynExprIdx,
         denominator_arg: LxMathCompleteCommandArgument,
     },
+    Binom {
+        command_token_idx: LxMathTokenIdx,
+        upper: VdSynExprIdx,
+        lower: VdSynExprIdx,
+        lower_arg: LxMathCompleteCommandArgument,
+    },
     Sqrt {
         // TODO: add field for the index or degree
         command_token_idx: LxMathTokenIdx,
@@ -130,27 +137,42 @@ pub enum VdSynExprData {
 pub enum VdSynPrefixOpr {
     Base(LxTokenIdxRange, VdBasePrefixOpr),
     Composite(VdSynExprIdx, VdCompositePrefixOpr),
+    /// `\lim_{x \to a}`
+    ///
+    /// `token_idx_range` is that of the command only
+    Subscripted {
+        token_idx_range: LxTokenIdxRange,
+        opr: VdBasePrefixOpr,
+        subscript: VdSynExprIdx,
+    },
 }
 
 impl VdSynPrefixOpr {
     pub fn expr(self) -> Option<VdSynExprIdx> {
         match self {
-            VdSynPrefixOpr::Base(..) => None,
+            VdSynPrefixOpr::Base(..) | VdSynPrefixOpr::Subscripted { .. } => None,
             VdSynPrefixOpr::Composite(expr, _) => Some(expr),
         }
     }
 
     pub(crate) fn precedence(self) -> VdPrecedence {
         match self {
-            VdSynPrefixOpr::Base(_, opr) => opr.precedence(),
+            VdSynPrefixOpr::Base(_, opr) | VdSynPrefixOpr::Subscripted { opr, .. } => {
+                opr.precedence()
+            }
             VdSynPrefixOpr::Composite(_, opr) => opr.precedence(),
         }
     }
 
-    pub(crate) fn show(self, arena: VdSynExprArenaRef) -> String {
+    pub(crate) fn show(self, arena: VdSynExprArenaRef, db: &EternerDb) -> String {
         match self {
             VdSynPrefixOpr::Base(_, opr) => opr.latex_code().to_string(),
             VdSynPrefixOpr::Composite(_, opr) => opr.latex_code().to_string(), // ad hoc
+            VdSynPrefixOpr::Subscripted { opr, subscript, .. } => format!(
+                "{}_{{{}}}",
+                opr.latex_code(),
+                arena[subscript].show(arena, db)
+            ),
         }
     }
 }
@@ -159,20 +181,46 @@ impl VdSynPrefixOpr {
 pub enum VdSynSuffixOpr {
     Base(LxTokenIdxRange, VdBaseSuffixOpr),
     Composite(VdSynExprIdx, VdCompositeSuffixOpr),
+    /// `\pmod{n}` in `a \equiv b \pmod{n}`
+    Pmod {
+        command_token_idx: LxMathTokenIdx,
+        modulus: VdSynExprIdx,
+        modulus_arg: LxMathCompleteCommandArgument,
+    },
 }
 
 impl VdSynSuffixOpr {
     pub fn expr(self) -> Option<VdSynExprIdx> {
         match self {
-            VdSynSuffixOpr::Base(..) => None,
+            VdSynSuffixOpr::Base(..) | VdSynSuffixOpr::Pmod { .. } => None,
             VdSynSuffixOpr::Composite(expr, _) => Some(expr),
         }
     }
 
-    pub(crate) fn show(&self, arena: VdSynExprArenaRef) -> String {
+    pub(crate) fn left_precedence_range(self) -> VdPrecedenceRange {
+        match self {
+            VdSynSuffixOpr::Base(_, opr) => opr.precedence_range(),
+            VdSynSuffixOpr::Composite(_, opr) => match opr {},
+            // `\pmod` qualifies the whole congruence
+            VdSynSuffixOpr::Pmod { .. } => VdPrecedenceRange::COMPARISON_LEFT,
+        }
+    }
+
+    pub(crate) fn precedence(self) -> VdPrecedence {
+        match self {
+            VdSynSuffixOpr::Base(_, opr) => match opr {},
+            VdSynSuffixOpr::Composite(_, opr) => match opr {},
+            VdSynSuffixOpr::Pmod { .. } => VdPrecedence::RELATION,
+        }
+    }
+
+    pub(crate) fn show(&self, arena: VdSynExprArenaRef, db: &EternerDb) -> String {
         match *self {
             VdSynSuffixOpr::Base(_, opr) => opr.latex_code().to_string(),
             VdSynSuffixOpr::Composite(_, opr) => opr.latex_code().to_string(), // ad hoc
+            VdSynSuffixOpr::Pmod { modulus, .. } => {
+                format!("\\pmod{{{}}}", arena[modulus].show(arena, db))
+            }
         }
     }
 }
@@ -296,10 +344,12 @@ impl VdSynExprData {
             VdSynExprData::Prefix { opr, opd } => match opr {
                 VdSynPrefixOpr::Base(_, _) => vec![opd],
                 VdSynPrefixOpr::Composite(opr, _) => vec![opr, opd],
+                VdSynPrefixOpr::Subscripted { subscript, .. } => vec![subscript, opd],
             },
             VdSynExprData::Suffix { opd, opr } => match opr {
                 VdSynSuffixOpr::Base(_, _) => vec![opd],
                 VdSynSuffixOpr::Composite(opr, _) => vec![opd, opr],
+                VdSynSuffixOpr::Pmod { modulus, .. } => vec![opd, modulus],
             },
             VdSynExprData::Attach { base, ref scripts } => [base]
                 .into_iter()
@@ -354,6 +404,7 @@ impl VdSynExprData {
                 denominator,
                 ..
             } => vec![numerator, denominator],
+            VdSynExprData::Binom { upper, lower, .. } => vec![upper, lower],
             VdSynExprData::Sqrt { radicand, .. } => vec![radicand],
         }
     }
@@ -365,6 +416,7 @@ impl VdSynExprData {
             | VdSynExprData::LxDelimited { .. }
             | VdSynExprData::Delimited { .. }
             | VdSynExprData::Fraction { .. }
+            | VdSynExprData::Binom { .. }
             | VdSynExprData::Sqrt { .. } => VdSynExprClass::Complete(VdPrecedence::ATOM),
             VdSynExprData::BaseOpr { opr } => match opr {
                 VdBaseOpr::Prefix(opr) => VdSynExprClass::PrefixOpr,
@@ -373,13 +425,14 @@ impl VdSynExprData {
             },
             VdSynExprData::Binary { opr, .. } => VdSynExprClass::Complete(opr.precedence()),
             VdSynExprData::Prefix { opr, .. } => VdSynExprClass::Complete(opr.precedence()),
-            VdSynExprData::Suffix { .. } => todo!(),
+            VdSynExprData::Suffix { opr, .. } => VdSynExprClass::Complete(opr.precedence()),
             VdSynExprData::Attach { .. } => VdSynExprClass::ATOM,
             VdSynExprData::UniadicChain => todo!(),
             VdSynExprData::VariadicChain => todo!(),
             VdSynExprData::UniadicArray => todo!(),
             VdSynExprData::VariadicArray => todo!(),
-            VdSynExprData::Err(..) => todo!(),
+            // errors stand in for whole operands
+            VdSynExprData::Err(..) => VdSynExprClass::ATOM,
             VdSynExprData::SeparatedList {
                 separator_class, ..
             } => VdSynExprClass::Complete(separator_class.precedence()),
@@ -445,8 +498,12 @@ impl VdSynExprData {
                     arena[ropd].show(arena, db)
                 )
             }
-            VdSynExprData::Prefix { opr, opd } => todo!(),
-            VdSynExprData::Suffix { opd, opr } => todo!(),
+            VdSynExprData::Prefix { opr, opd } => {
+                format!("{} {}", opr.show(arena, db), arena[opd].show(arena, db))
+            }
+            VdSynExprData::Suffix { opd, opr } => {
+                format!("{} {}", arena[opd].show(arena, db), opr.show(arena, db))
+            }
             VdSynExprData::SeparatedList {
                 items,
                 ref separators,
@@ -494,6 +551,11 @@ impl VdSynExprData {
                 arena[numerator].show(arena, db),
                 arena[denominator].show(arena, db)
             ),
+            VdSynExprData::Binom { upper, lower, .. } => format!(
+                "\\binom{{{}}}{{{}}}",
+                arena[upper].show(arena, db),
+                arena[lower].show(arena, db)
+            ),
             VdSynExprData::Sqrt { radicand, .. } => {
                 format!("\\sqrt{{{}}}", arena[radicand].show(arena, db))
             }
@@ -511,6 +573,21 @@ impl<'db> VdSynSymbolBuilder<'db> {
         &mut self,
         expr: VdSynExprIdx,
     ) -> VdSynSymbolResolutionResult<Option<VdSynSymbolResolutions>> {
+        // the bound variable has to be defined before the children are built
+        let binding = match self.expr_arena()[expr] {
+            VdSynExprData::Prefix { opr, opd } => opr.binding(opd, self.expr_arena()),
+            _ => None,
+        };
+        if let Some((binder, _)) = binding {
+            self.define_symbol(
+                VdSynSymbolLocalDefnHead::Letter {
+                    token_idx_range: binder.letter_token_idx_range,
+                    letter: binder.letter,
+                },
+                VdSynSymbolLocalDefnBody::Placeholder,
+                VdSynSymbolLocalDefnSrc::Expr(expr),
+            );
+        }
         for expr in self.expr_arena()[expr].children() {
             self.build_expr(expr);
         }
@@ -1 +1,91 @@
+use super::*;
 
+/// the variable bound by a quantifier or a limit,
+/// like `x \in S` in `\forall x \in S, P` or `x \to a` in `\lim_{x \to a} f(x)`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VdSynBinder {
+    pub letter_expr: VdSynExprIdx,
+    pub letter_token_idx_range: LxTokenIdxRange,
+    pub letter: LxMathLetter,
+    /// the domain for quantifiers and the approached value for limits
+    pub bound: VdSynExprIdx,
+}
+
+impl VdSynBinder {
+    /// returns `None` unless `data` is of the form `x <separator> bound` for a single letter `x`
+    pub(crate) fn from_data(
+        data: &VdSynExprData,
+        separator: VdBaseSeparator,
+        arena: VdSynExprArenaRef,
+    ) -> Option<Self> {
+        // look through the braces of a subscript like `_{x \to a}`
+        if let VdSynExprData::LxDelimited { item, .. } = *data {
+            return Self::from_data(&arena[item], separator, arena);
+        }
+        let VdSynExprData::SeparatedList {
+            items,
+            ref separators,
+            ..
+        } = *data
+        else {
+            return None;
+        };
+        let [VdSynSeparator::Base(_, separator1)] = **separators else {
+            return None;
+        };
+        if separator1 != separator || items.len() != 2 {
+            return None;
+        }
+        let letter_expr = items.first().unwrap();
+        let VdSynExprData::Letter {
+            token_idx_range: letter_token_idx_range,
+            letter,
+        } = arena[letter_expr]
+        else {
+            return None;
+        };
+        Some(Self {
+            letter_expr,
+            letter_token_idx_range,
+            letter,
+            bound: items.last().unwrap(),
+        })
+    }
+}
+
+impl VdSynPrefixOpr {
+    /// the binder and the body of a quantifier or a limit,
+    /// with the shapes already checked by the parser
+    pub fn binding(
+        self,
+        opd: VdSynExprIdx,
+        arena: VdSynExprArenaRef,
+    ) -> Option<(VdSynBinder, VdSynExprIdx)> {
+        match self {
+            VdSynPrefixOpr::Base(_, opr @ (VdBasePrefixOpr::Forall | VdBasePrefixOpr::Exists)) => {
+                let VdSynExprData::SeparatedList {
+                    separator_class: VdSeparatorClass::Comma,
+                    items,
+                    ..
+                } = arena[opd]
+                else {
+                    unreachable!("the parser puts the binder and the body in a comma list")
+                };
+                let binder = VdSynBinder::from_data(
+                    &arena[items.first().unwrap()],
+                    opr.binder_separator().unwrap(),
+                    arena,
+                )
+                .expect("the parser has checked the binder");
+                Some((binder, items.last().unwrap()))
+            }
+            VdSynPrefixOpr::Subscripted { opr, subscript, .. } => {
+                let binder =
+                    VdSynBinder::from_data(&arena[subscript], opr.binder_separator()?, arena)
+                        .expect("the parser has checked the subscript");
+                Some((binder, opd))
+            }
+            _ => None,
+        }
+    }
+}
@@ -1 +1,21 @@
+use super::*;
 
+impl VdSynExprData {
+    /// whether it's of the form `a \equiv b`, which is what `\pmod` applies to
+    pub(crate) fn is_congruence(&self) -> bool {
+        match *self {
+            VdSynExprData::SeparatedList {
+                items,
+                ref separators,
+                ..
+            } => {
+                items.len() == 2
+                    && matches!(
+                        **separators,
+                        [VdSynSeparator::Base(_, VdBaseSeparator::Equiv)]
+                    )
+            }
+            _ => false,
+        }
+    }
+}
@@ -594,3 +594,140 @@ fn styled_letter_vd_syn_expr_parsing_works() {
         "#]],
     );
 }
+
+#[test]
+fn user_defined_macros_vd_syn_expr_parsing_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        r"\newcommand{\R}{\mathbb{R}} x\in\R",
+        &[],
+        &[],
+        &expect![[r#"
+            "x\\in\\R" expr.separated_list
+            ├─ "x" expr.letter
+            └─ "\\R" expr.letter
+        "#]],
+    );
+    t(
+        models,
+        r"\newcommand{\sq}[1]{{#1}^2} \sq{x+1}",
+        &[],
+        &[],
+        &expect![[r#"
+            "\\sq{x+1}" expr.attach
+            ├─ "\\sq{x+1}" expr.latex_delimited
+            │ └─ "\\sq{x+1}" expr.separated_list
+            │   ├─ "\\sq{x+1}" expr.letter
+            │   └─ "\\sq{x+1}" expr.literal
+            └─ "\\sq{x+1}" expr.literal
+        "#]],
+    );
+    t(
+        models,
+        r"\newcommand{\f}[1]{#1} x+\f",
+        &[],
+        &[],
+        &expect![[r#"
+            "x+\\f" expr.separated_list
+            ├─ "x" expr.letter
+            └─ "\\f" expr.error
+        "#]],
+    );
+}
+
+#[test]
+fn binder_vd_syn_expr_parsing_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "\\forall x \\in \\mathbb{R}, x = x",
+        &[],
+        &[],
+        &expect![[r#"
+            "\\forall x \\in \\mathbb{R}, x = x" expr.prefix
+            └─ "x \\in \\mathbb{R}, x = x" expr.separated_list
+              ├─ "x \\in \\mathbb{R}" expr.separated_list
+              │ ├─ "x" expr.letter
+              │ └─ "\\mathbb{R}" expr.letter
+              └─ "x = x" expr.separated_list
+                ├─ "x" expr.letter
+                └─ "x" expr.letter
+        "#]],
+    );
+    t(
+        models,
+        "\\exists n \\in \\mathbb{N}, n > 1",
+        &[],
+        &[],
+        &expect![[r#"
+            "\\exists n \\in \\mathbb{N}, n > 1" expr.prefix
+            └─ "n \\in \\mathbb{N}, n > 1" expr.separated_list
+              ├─ "n \\in \\mathbb{N}" expr.separated_list
+              │ ├─ "n" expr.letter
+              │ └─ "\\mathbb{N}" expr.letter
+              └─ "n > 1" expr.separated_list
+                ├─ "n" expr.letter
+                └─ "1" expr.literal
+        "#]],
+    );
+    t(
+        models,
+        "\\lim_{x \\to 0} x",
+        &[],
+        &[],
+        &expect![[r#"
+            "\\lim_{x \\to 0} x" expr.prefix
+            ├─ "{x \\to 0}" expr.latex_delimited
+            │ └─ "x \\to 0" expr.separated_list
+            │   ├─ "x" expr.letter
+            │   └─ "0" expr.literal
+            └─ "x" expr.letter
+        "#]],
+    );
+    t(
+        models,
+        "a \\equiv b \\pmod{n}",
+        &[],
+        &[],
+        &expect![[r#"
+            "a \\equiv b \\pmod{n}" expr.suffix
+            ├─ "a \\equiv b" expr.separated_list
+            │ ├─ "a" expr.letter
+            │ └─ "b" expr.letter
+            └─ "n" expr.letter
+        "#]],
+    );
+}
+
+#[test]
+fn malformed_binder_vd_syn_expr_parsing_works() {
+    let models = &VdModels::new();
+    t(
+        models,
+        "\\forall x, x = x",
+        &[],
+        &[],
+        &expect![[r#"
+            "\\forall" expr.error
+        "#]],
+    );
+    t(
+        models,
+        "\\lim_{x} x",
+        &[],
+        &[],
+        &expect![[r#"
+            "\\lim" expr.error
+        "#]],
+    );
+    t(
+        models,
+        "a < b \\pmod{n}",
+        &[],
+        &[],
+        &expect![[r#"
+            "\\pmod" expr.error
+        "#]],
+    );
+}
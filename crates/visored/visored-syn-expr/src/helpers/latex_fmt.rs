@@ -149,6 +149,13 @@ impl<'a> VdSynExprLaTeXFormatter<'a> {
                 denominator,
                 ..
             } => todo!(),
+            VdSynExprData::Binom { upper, lower, .. } => {
+                self.result += "\\binom{";
+                self.fmt_expr(upper);
+                self.result += "}{";
+                self.fmt_expr(lower);
+                self.result += "}";
+            }
             VdSynExprData::Sqrt { radicand, .. } => todo!(),
         }
     }
@@ -146,6 +146,7 @@ impl<'a> VdSynExprDisplayTreeBuilder<'a> {
                 denominator,
                 ..
             } => format!("{:?} fraction", source),
+            VdSynExprData::Binom { upper, lower, .. } => format!("{:?} binom", source),
             VdSynExprData::Sqrt { radicand, .. } => format!("{:?} sqrt", source),
         };
         DisplayTree::new(value, self.render_exprs(self.expr_arena[expr].children()))
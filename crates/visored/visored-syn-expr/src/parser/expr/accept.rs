@@ -14,7 +14,7 @@ use visored_annotation::annotation::space::VdSpaceAnnotation;
 use visored_opr::{
     delimiter::{VdBaseLeftDelimiter, VdBaseRightDelimiter},
     opr::{binary::VdBaseBinaryOpr, prefix::VdBasePrefixOpr, suffix::VdBaseSuffixOpr, VdBaseOpr},
-    precedence::VdPrecedence,
+    precedence::{VdPrecedence, VdPrecedenceRange},
     separator::{VdBaseSeparator, VdSeparatorClass},
 };
 
@@ -38,6 +38,12 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
             DisambiguatedAst::Opr(opr) => {
                 self.accept_opr(preceding_space_annotation, token_idx_range, opr)
             }
+            DisambiguatedAst::PrefixOpr(opr) => {
+                self.accept_prefix_opr(preceding_space_annotation, opr)
+            }
+            DisambiguatedAst::SuffixOpr(opr) => {
+                self.accept_suffix_opr(preceding_space_annotation, opr)
+            }
             DisambiguatedAst::Separator(sep) => self.accept_separator(
                 preceding_space_annotation,
                 token_idx_range,
@@ -106,13 +112,28 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
         if let Some(annotation) = preceding_space_annotation {
             todo!()
         }
-        self.take_complete_and_push_to_top(|slf, top_expr| match top_expr {
-            Some(expr) => VdSynExprData::Suffix {
+        self.reduce(opr.left_precedence_range(), None);
+        self.take_complete_and_push_to_top(|slf, top_expr| match (opr, top_expr) {
+            (
+                VdSynSuffixOpr::Pmod {
+                    command_token_idx, ..
+                },
+                top_expr,
+            ) if !top_expr.as_ref().is_some_and(VdSynExprData::is_congruence) => {
+                VdSynExprData::Err(
+                    OriginalVdSynExprError::MalformedCongruence(LxTokenIdxRange::new_single(
+                        *command_token_idx,
+                    ))
+                    .into(),
+                )
+                .into()
+            }
+            (_, Some(expr)) => VdSynExprData::Suffix {
                 opd: slf.builder.alloc_expr(expr),
                 opr,
             }
             .into(),
-            None => todo!(),
+            (_, None) => todo!(),
         })
     }
 
@@ -125,6 +146,9 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
         if let Some(annotation) = preceding_space_annotation {
             todo!()
         }
+        if self.is_binder_comma(separator) {
+            return self.accept_binder_comma(separator);
+        }
         self.reduce(separator.left_precedence_range(), Some(separator.class()));
         if separator.class() == VdSeparatorClass::SPACE {
             todo!()
@@ -176,12 +200,54 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
                     IncompleteVdSynExprData::Delimited {
                         left_delimiter: bra,
                     } => todo!(),
+                    IncompleteVdSynExprData::Quantified { .. } => todo!(),
                 },
                 None => todo!(),
             },
         }
     }
 
+    /// whether the comma ends the binder of a quantifier, like the one in `\forall x \in S, P`
+    fn is_binder_comma(&self, separator: VdSynSeparator) -> bool {
+        if separator.class() != VdSeparatorClass::Comma {
+            return false;
+        }
+        for (incomplete_expr, _) in self.incomplete_exprs().iter().rev() {
+            match *incomplete_expr {
+                IncompleteVdSynExprData::Prefix {
+                    opr: VdSynPrefixOpr::Base(_, VdBasePrefixOpr::Forall | VdBasePrefixOpr::Exists),
+                } => return true,
+                IncompleteVdSynExprData::Delimited { .. }
+                | IncompleteVdSynExprData::Quantified { .. } => return false,
+                _ => (),
+            }
+        }
+        false
+    }
+
+    fn accept_binder_comma(&mut self, separator: VdSynSeparator) {
+        // everything after the quantifier binds tighter than it
+        self.reduce(VdPrecedenceRange::Greater(VdPrecedence::QUANTIFIER), None);
+        let Some(IncompleteVdSynExprData::Prefix { opr }) = self.take_last_incomplete_expr() else {
+            unreachable!()
+        };
+        let binder = self.take_complete_expr().unwrap_or_else(|| {
+            let VdSynPrefixOpr::Base(token_idx_range, _) = opr else {
+                unreachable!()
+            };
+            VdSynExprData::Err(OriginalVdSynExprError::MalformedBinder(token_idx_range).into())
+        });
+        self.push_top_expr(
+            None,
+            IncompleteVdSynExprData::Quantified {
+                opr,
+                binder,
+                separator,
+            }
+            .into(),
+        )
+    }
+
     fn accept_binary_opr(
         &mut self,
         preceding_space_annotation: Option<VdSpaceAnnotation>,
@@ -1,14 +1,16 @@
 use super::{
     builder::VdSynExprBuilder,
-    expr::{VdSynExprClass, VdSynExprData, VdSynExprIdx},
+    expr::{VdSynExprClass, VdSynExprData, VdSynExprIdx, VdSynPrefixOpr, VdSynSuffixOpr},
     ToVdSyn, VdSynExprParser, VdSynExprVibe,
 };
+use crate::error::OriginalVdSynExprError;
 use latex_ast::ast::math::{
     LxMathAstData, LxMathAstIdx, LxMathCommandArgumentAsts, LxMathCompleteCommandArgument,
 };
 use latex_command::path::LxCommandPath;
 use latex_math_letter::letter::LxMathLetter;
 use latex_math_punctuation::LxMathPunctuation;
+use latex_prelude::script::LxScriptKind;
 use latex_token::{
     idx::{LxMathTokenIdx, LxTokenIdxRange},
     token::math::digit::LxMathDigit,
@@ -20,7 +22,7 @@ use visored_global_resolution::resolution::{
 };
 use visored_opr::{
     delimiter::{VdBaseLeftDelimiter, VdBaseRightDelimiter},
-    opr::VdBaseOpr,
+    opr::{prefix::VdBasePrefixOpr, VdBaseOpr},
     precedence::VdPrecedence,
     separator::VdBaseSeparator,
 };
@@ -30,6 +32,10 @@ use visored_term::term::literal::{bigint::VdBigIntData, VdLiteral, VdLiteralData
 pub enum DisambiguatedAst {
     Expr(VdSynExprData, VdSynExprClass),
     Opr(VdBaseOpr),
+    /// operators carrying expressions, like `\lim_{x \to a}`
+    PrefixOpr(VdSynPrefixOpr),
+    /// operators carrying expressions, like `\pmod{n}`
+    SuffixOpr(VdSynSuffixOpr),
     Separator(VdBaseSeparator),
     LeftDelimiter(VdBaseLeftDelimiter),
     RightDelimiter(VdBaseRightDelimiter),
@@ -217,7 +223,17 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
                 DisambiguatedAst::Expr(expr_data, VdSynExprClass::ATOM)
             }
             LxMathAstData::TextEdit { ref buffer } => todo!(),
+            LxMathAstData::Error(token_idx, _) => DisambiguatedAst::Expr(
+                VdSynExprData::Err(
+                    OriginalVdSynExprError::MalformedToken(LxTokenIdxRange::new_single(*token_idx))
+                        .into(),
+                ),
+                VdSynExprClass::ATOM,
+            ),
             LxMathAstData::Attach { base, ref scripts } => {
+                if let Some(opr) = self.resolve_subscripted_prefix_opr(base, scripts, vibe) {
+                    return DisambiguatedAst::PrefixOpr(opr);
+                }
                 let base = base.to_vd_syn(self.builder, vibe);
                 let scripts = scripts
                     .iter()
@@ -324,6 +340,21 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
                     VdSynExprClass::ATOM,
                 )
             }
+            VdCompleteCommandGlobalResolution::Binom => {
+                debug_assert!(arguments.len() == 2);
+                let [upper_arg, lower_arg] = *arguments else {
+                    unreachable!()
+                };
+                DisambiguatedAst::Expr(
+                    VdSynExprData::Binom {
+                        command_token_idx,
+                        upper: upper_arg.to_vd_syn(self.builder, vibe),
+                        lower: lower_arg.to_vd_syn(self.builder, vibe),
+                        lower_arg,
+                    },
+                    VdSynExprClass::ATOM,
+                )
+            }
             VdCompleteCommandGlobalResolution::Sqrt => {
                 debug_assert!(arguments.len() == 1);
                 let [radicand_arg] = *arguments else {
@@ -339,6 +370,17 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
                     VdSynExprClass::ATOM,
                 )
             }
+            VdCompleteCommandGlobalResolution::Pmod => {
+                debug_assert!(arguments.len() == 1);
+                let [modulus_arg] = *arguments else {
+                    unreachable!()
+                };
+                DisambiguatedAst::SuffixOpr(VdSynSuffixOpr::Pmod {
+                    command_token_idx,
+                    modulus: modulus_arg.to_vd_syn(self.builder, vibe),
+                    modulus_arg,
+                })
+            }
             VdCompleteCommandGlobalResolution::Text => todo!(),
             VdCompleteCommandGlobalResolution::Opr(vd_base_opr) => {
                 DisambiguatedAst::Opr(vd_base_opr)
@@ -346,12 +388,57 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
             VdCompleteCommandGlobalResolution::Separator(vd_separator) => {
                 DisambiguatedAst::Separator(vd_separator)
             }
+            VdCompleteCommandGlobalResolution::LeftDelimiter(left_delimiter) => {
+                DisambiguatedAst::LeftDelimiter(left_delimiter)
+            }
+            VdCompleteCommandGlobalResolution::RightDelimiter(right_delimiter) => {
+                DisambiguatedAst::RightDelimiter(right_delimiter)
+            }
             VdCompleteCommandGlobalResolution::UsePackage => todo!(),
             VdCompleteCommandGlobalResolution::NewDivision(_) => todo!(),
             VdCompleteCommandGlobalResolution::DocumentClass => todo!(),
         }
     }
 
+    /// `\lim_{x \to a}`; returns `None` for other attached asts
+    fn resolve_subscripted_prefix_opr(
+        &mut self,
+        base: LxMathAstIdx,
+        scripts: &[(LxScriptKind, LxMathAstIdx)],
+        vibe: VdSynExprVibe,
+    ) -> Option<VdSynPrefixOpr> {
+        use crate::builder::ToVdSyn;
+
+        let LxMathAstData::CompleteCommand {
+            command_token_idx,
+            command_path,
+            ref arguments,
+        } = self.builder.ast_arena()[base]
+        else {
+            return None;
+        };
+        if !arguments.is_empty() {
+            return None;
+        }
+        let Some(VdCompleteCommandGlobalResolution::Opr(VdBaseOpr::Prefix(
+            opr @ VdBasePrefixOpr::Lim,
+        ))) = self
+            .builder
+            .default_resolution_table()
+            .resolve_complete_command(command_path)
+        else {
+            return None;
+        };
+        let [(LxScriptKind::Subscript, subscript)] = *scripts else {
+            return None;
+        };
+        Some(VdSynPrefixOpr::Subscripted {
+            token_idx_range: LxTokenIdxRange::new_single(*command_token_idx),
+            opr,
+            subscript: subscript.to_vd_syn(self.builder, vibe),
+        })
+    }
+
     fn resolve_lefted(
         &mut self,
         left_command_token_idx: LxMathTokenIdx,
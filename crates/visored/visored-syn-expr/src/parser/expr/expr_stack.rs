@@ -1,6 +1,9 @@
 use super::{
     error::{OriginalVdSynExprError, VdSynExprResult},
-    expr::{VdSynExprArenaRef, VdSynExprData, VdSynExprIdx, VdSynSeparator},
+    expr::{
+        prefix::VdSynBinder, VdSynExprArenaRef, VdSynExprData, VdSynExprIdx, VdSynPrefixOpr,
+        VdSynSeparator,
+    },
     incomplete_expr::IncompleteVdSynExprData,
     VdSynExprParser,
 };
@@ -12,6 +15,7 @@ use smallvec::{smallvec, SmallVec};
 use visored_annotation::annotation::space::VdSpaceAnnotation;
 use visored_opr::{
     delimiter::VdBaseLeftDelimiter,
+    opr::prefix::VdBasePrefixOpr,
     precedence::{VdPrecedence, VdPrecedenceRange},
     separator::{VdBaseSeparator, VdSeparatorClass},
 };
@@ -190,10 +194,20 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
                 IncompleteVdSynExprData::Prefix { opr } => {
                     let expr = self.take_complete_expr();
                     self.stack.complete_expr = Some(match expr {
-                        Some(opd) => VdSynExprData::Prefix {
-                            opr,
-                            opd: self.builder.alloc_expr(opd),
-                        },
+                        Some(opd) => self.reduce_prefix(opr, opd),
+                        None => VdSynExprData::Err(
+                            OriginalVdSynExprError::NoOperandForPrefixOperator { opr }.into(),
+                        ),
+                    })
+                }
+                IncompleteVdSynExprData::Quantified {
+                    opr,
+                    binder,
+                    separator,
+                } => {
+                    let expr = self.take_complete_expr();
+                    self.stack.complete_expr = Some(match expr {
+                        Some(body) => self.reduce_quantified(opr, binder, separator, body),
                         None => VdSynExprData::Err(
                             OriginalVdSynExprError::NoOperandForPrefixOperator { opr }.into(),
                         ),
@@ -224,6 +238,70 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
         }
     }
 
+    fn reduce_prefix(&mut self, opr: VdSynPrefixOpr, opd: VdSynExprData) -> VdSynExprData {
+        match opr {
+            // a quantifier that has met its comma is `Quantified` instead
+            VdSynPrefixOpr::Base(
+                token_idx_range,
+                VdBasePrefixOpr::Forall | VdBasePrefixOpr::Exists,
+            ) => {
+                return VdSynExprData::Err(
+                    OriginalVdSynExprError::MalformedBinder(token_idx_range).into(),
+                )
+            }
+            VdSynPrefixOpr::Subscripted {
+                token_idx_range,
+                opr,
+                subscript,
+            } => {
+                if let Some(separator) = opr.binder_separator() {
+                    let arena = self.builder.expr_arena().as_arena_ref();
+                    if VdSynBinder::from_data(&arena[subscript], separator, arena).is_none() {
+                        return VdSynExprData::Err(
+                            OriginalVdSynExprError::MalformedBinder(token_idx_range).into(),
+                        );
+                    }
+                }
+            }
+            _ => (),
+        }
+        VdSynExprData::Prefix {
+            opr,
+            opd: self.builder.alloc_expr(opd),
+        }
+    }
+
+    fn reduce_quantified(
+        &mut self,
+        opr: VdSynPrefixOpr,
+        binder: VdSynExprData,
+        separator: VdSynSeparator,
+        body: VdSynExprData,
+    ) -> VdSynExprData {
+        let VdSynPrefixOpr::Base(token_idx_range, base_opr) = opr else {
+            unreachable!()
+        };
+        let binder_separator = base_opr.binder_separator().unwrap();
+        if VdSynBinder::from_data(
+            &binder,
+            binder_separator,
+            self.builder.expr_arena().as_arena_ref(),
+        )
+        .is_none()
+        {
+            return VdSynExprData::Err(
+                OriginalVdSynExprError::MalformedBinder(token_idx_range).into(),
+            );
+        }
+        let items = self.builder.alloc_exprs([binder, body]);
+        let opd = self.builder.alloc_expr(VdSynExprData::SeparatedList {
+            separator_class: VdSeparatorClass::Comma,
+            items,
+            separators: smallvec![separator],
+        });
+        VdSynExprData::Prefix { opr, opd }
+    }
+
     // assumes that the precedence of the incoming separator is higher
     fn reduce_separated_list(
         &mut self,
@@ -40,6 +40,15 @@ pub(super) enum IncompleteVdSynExprData {
     Delimited {
         left_delimiter: VdSynLeftDelimiter,
     },
+    /// a quantifier whose binder has been closed by a comma, waiting for its body
+    /// ```latex
+    /// \forall x \in S,
+    /// ```
+    Quantified {
+        opr: VdSynPrefixOpr,
+        binder: VdSynExprData,
+        separator: VdSynSeparator,
+    },
 }
 
 impl IncompleteVdSynExprData {
@@ -51,6 +60,7 @@ impl IncompleteVdSynExprData {
                 separator_class, ..
             } => separator_class.precedence(),
             IncompleteVdSynExprData::Delimited { .. } => VdPrecedence::INCOMPLTE_DELIMITED,
+            IncompleteVdSynExprData::Quantified { opr, .. } => opr.precedence(),
         }
     }
 }
@@ -61,7 +71,7 @@ impl IncompleteVdSynExprData {
             IncompleteVdSynExprData::Binary { lopd, opr } => {
                 format!("{} {}", arena[lopd].show(arena, db), opr.show(arena, db))
             }
-            IncompleteVdSynExprData::Prefix { opr } => opr.show(arena),
+            IncompleteVdSynExprData::Prefix { opr } => opr.show(arena, db),
             IncompleteVdSynExprData::SeparatedList {
                 separator_class,
                 ref items,
@@ -87,6 +97,16 @@ impl IncompleteVdSynExprData {
             IncompleteVdSynExprData::Delimited { left_delimiter } => {
                 format!("{}", left_delimiter.show(arena, db))
             }
+            IncompleteVdSynExprData::Quantified {
+                opr,
+                ref binder,
+                separator,
+            } => format!(
+                "{} {} {}",
+                opr.show(arena, db),
+                binder.show(arena, db),
+                separator.show(arena, db)
+            ),
         }
     }
 }
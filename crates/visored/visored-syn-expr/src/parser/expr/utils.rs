@@ -54,6 +54,9 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
                 denominator,
                 ..
             } => *command_token_idx,
+            VdSynExprData::Binom {
+                command_token_idx, ..
+            } => *command_token_idx,
             VdSynExprData::Sqrt {
                 command_token_idx,
                 radicand,
@@ -83,12 +86,18 @@ impl<'a, 'db> VdSynExprParser<'a, 'db> {
             IncompleteVdSynExprData::Delimited { left_delimiter } => {
                 self.calc_left_delimiter_first_token_idx(left_delimiter)
             }
+            IncompleteVdSynExprData::Quantified { opr, .. } => {
+                self.calc_prefix_opr_first_token_idx(opr)
+            }
         }
     }
 
     fn calc_prefix_opr_first_token_idx(&self, opr: VdSynPrefixOpr) -> LxTokenIdx {
         match opr {
-            VdSynPrefixOpr::Base(token_idx_range, _) => token_idx_range.start(),
+            VdSynPrefixOpr::Base(token_idx_range, _)
+            | VdSynPrefixOpr::Subscripted {
+                token_idx_range, ..
+            } => token_idx_range.start(),
             VdSynPrefixOpr::Composite(expr, _) => self.calc_expr_first_token_idx(expr),
         }
     }
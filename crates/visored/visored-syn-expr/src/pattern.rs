@@ -60,6 +60,12 @@ impl<'db> VdSynSymbolBuilder<'db> {
                 denominator,
                 ..
             } => todo!(),
+            VdSynExprData::Binom {
+                command_token_idx,
+                upper,
+                lower,
+                ..
+            } => todo!(),
             VdSynExprData::Sqrt {
                 command_token_idx,
                 radicand,
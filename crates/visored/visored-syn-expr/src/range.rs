@@ -10,7 +10,7 @@ use crate::{
     },
     expr::{
         VdSynExprArena, VdSynExprArenaRef, VdSynExprData, VdSynExprIdx, VdSynExprMap,
-        VdSynLeftDelimiter, VdSynPrefixOpr, VdSynRightDelimiter, VdSynSeparator,
+        VdSynLeftDelimiter, VdSynPrefixOpr, VdSynRightDelimiter, VdSynSeparator, VdSynSuffixOpr,
     },
     phrase::{VdSynPhraseArena, VdSynPhraseArenaRef, VdSynPhraseIdx, VdSynPhraseMap},
     sentence::{
@@ -173,10 +173,28 @@ impl<'db> VdSynExprRangeCalculator<'db> {
                         VdSynExprTokenIdxRange::Standard(lx_token_idx_range)
                     }
                     VdSynPrefixOpr::Composite(expr, _) => self.get_expr(expr),
+                    VdSynPrefixOpr::Subscripted {
+                        token_idx_range,
+                        subscript,
+                        ..
+                    } => VdSynExprTokenIdxRange::Standard(token_idx_range)
+                        .join(self.get_expr(subscript)),
                 };
                 opr_range.join(opd_range)
             }
-            VdSynExprData::Suffix { opd, opr } => todo!(),
+            VdSynExprData::Suffix { opd, opr } => {
+                let opd_range = self.get_expr(opd);
+                let opr_range = match opr {
+                    VdSynSuffixOpr::Base(_, opr) => match opr {},
+                    VdSynSuffixOpr::Composite(expr, _) => self.get_expr(expr),
+                    VdSynSuffixOpr::Pmod {
+                        command_token_idx,
+                        modulus_arg,
+                        ..
+                    } => self.calc_complete_command(command_token_idx, modulus_arg),
+                };
+                opd_range.join(opr_range)
+            }
             VdSynExprData::Attach { base, ref scripts } => {
                 let mut range = self.get_expr(base);
                 for &(_, script) in scripts {
@@ -235,6 +253,11 @@ impl<'db> VdSynExprRangeCalculator<'db> {
                 denominator_arg,
                 ..
             } => self.calc_complete_command(command_token_idx, denominator_arg),
+            VdSynExprData::Binom {
+                command_token_idx,
+                lower_arg,
+                ..
+            } => self.calc_complete_command(command_token_idx, lower_arg),
             VdSynExprData::Sqrt {
                 command_token_idx,
                 radicand_arg,
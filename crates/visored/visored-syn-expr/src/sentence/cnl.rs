@@ -286,6 +286,8 @@ impl<'db> VdSynExprBuilder<'db> {
                 end_rcurl_token_idx,
             } => todo!(),
             LxRoseAstData::NewParagraph(lx_rose_token_idx) => todo!(),
+            // malformed tokens stay in the ast
+            LxRoseAstData::Error(_, _) => return self.next_cnl_token(asts),
         };
         Some(CnlToken { lx_ast: ast, data })
     }
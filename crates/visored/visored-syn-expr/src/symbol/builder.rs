@@ -234,6 +234,13 @@ impl<'a> VdSynSymbolBuilder<'a> {
             | VdSynSymbolLocalDefnSrc::LetPlaceholder(_) => {
                 self.calc_scope_from_module_path(module_path)
             }
+            VdSynSymbolLocalDefnSrc::Expr(expr) => {
+                let VdSynExprTokenIdxRange::Standard(token_idx_range) = self.expr_range_map[expr];
+                VdSynSymbolLocalDefnScope::Expr {
+                    module_path,
+                    token_idx_range,
+                }
+            }
         };
         self.symbol_local_defn_table.define_symbol(
             head,
@@ -31,6 +31,13 @@ pub enum VdSynSymbolLocalDefnScope {
     ///
     /// Then the modules will be the theorem environment and the proof environment.
     Modules(SmallVec<[VdModulePath; 2]>),
+    /// Applies to variables bound by an expression like `\forall x \in S, P` or `\lim_{x \to a} f(x)`.
+    ///
+    /// Then only the tokens of that expression can see the variable.
+    Expr {
+        module_path: VdModulePath,
+        token_idx_range: LxTokenIdxRange,
+    },
 }
 
 impl VdSynSymbolLocalDefnScope {
@@ -45,6 +52,13 @@ impl VdSynSymbolLocalDefnScope {
                 slf_module_path.contains(other_module_path, db)
             }
             VdSynSymbolLocalDefnScope::Modules(ref module_paths) => todo!(),
+            VdSynSymbolLocalDefnScope::Expr {
+                module_path: slf_module_path,
+                token_idx_range: slf_token_idx_range,
+            } => {
+                slf_module_path.contains(other_module_path, db)
+                    && slf_token_idx_range.contains(token_idx_range)
+            }
         }
     }
 }
@@ -67,7 +81,7 @@ pub enum VdSynSymbolLocalDefnBody {
 pub enum VdSynSymbolLocalDefnSrc {
     LetAssigned(VdSynClauseIdx),
     LetPlaceholder(VdSynClauseIdx),
-    // Expr(VdSynExprIdx),
+    Expr(VdSynExprIdx),
 }
 
 pub type VdSynSymbolLocalDefnArena = Arena<VdSynSymbolLocalDefnData>;
@@ -82,8 +82,40 @@ pub struct VdInstantiationMenu {
     pub int_ge: VdInstantiation,
     pub rat_ge: VdInstantiation,
     pub real_ge: VdInstantiation,
+    /// ## dvd
+    pub nat_dvd: VdInstantiation,
+    pub int_dvd: VdInstantiation,
+    /// ## logic
+    pub prop_implies: VdInstantiation,
+    pub prop_iff: VdInstantiation,
     /// # sqrt
     pub real_sqrt: VdInstantiation,
+    /// # functions
+    /// ## log
+    pub real_log: VdInstantiation,
+    /// ## exp
+    pub real_exp: VdInstantiation,
+    /// ## gcd
+    pub nat_gcd: VdInstantiation,
+    pub int_gcd: VdInstantiation,
+    /// ## max
+    pub nat_max: VdInstantiation,
+    pub int_max: VdInstantiation,
+    pub rat_max: VdInstantiation,
+    pub real_max: VdInstantiation,
+    /// ## min
+    pub nat_min: VdInstantiation,
+    pub int_min: VdInstantiation,
+    pub rat_min: VdInstantiation,
+    pub real_min: VdInstantiation,
+    /// ## binom
+    pub nat_binom: VdInstantiation,
+    /// ## floor
+    pub real_floor: VdInstantiation,
+    /// ## abs
+    pub int_abs: VdInstantiation,
+    pub rat_abs: VdInstantiation,
+    pub real_abs: VdInstantiation,
 }
 
 impl VdInstantiationMenu {
@@ -98,6 +130,14 @@ impl VdInstantiationMenu {
             complex,
             sin,
             cos,
+            log,
+            exp,
+            gcd,
+            max,
+            min,
+            binom,
+            floor,
+            abs,
             group,
             ring,
             group_mul,
@@ -118,6 +158,9 @@ impl VdInstantiationMenu {
             gt,
             le,
             ge,
+            dvd,
+            implies,
+            iff,
         } = *VD_ITEM_PATH_MENU;
         let VdTermMenu {
             zero,
@@ -200,8 +243,40 @@ impl VdInstantiationMenu {
         let int_ge = ins!(ge, int);
         let rat_ge = ins!(ge, rat);
         let real_ge = ins!(ge, real);
+        // ## dvd
+        let nat_dvd = ins!(dvd, nat);
+        let int_dvd = ins!(dvd, int);
+        // ## logic
+        let prop_implies = ins!(implies);
+        let prop_iff = ins!(iff);
         // # sqrt
         let real_sqrt = ins!(real_sqrt);
+        // # functions
+        // ## log
+        let real_log = ins!(log);
+        // ## exp
+        let real_exp = ins!(exp);
+        // ## gcd
+        let nat_gcd = ins!(gcd, nat);
+        let int_gcd = ins!(gcd, int);
+        // ## max
+        let nat_max = ins!(max, nat);
+        let int_max = ins!(max, int);
+        let rat_max = ins!(max, rat);
+        let real_max = ins!(max, real);
+        // ## min
+        let nat_min = ins!(min, nat);
+        let int_min = ins!(min, int);
+        let rat_min = ins!(min, rat);
+        let real_min = ins!(min, real);
+        // ## binom
+        let nat_binom = ins!(binom);
+        // ## floor
+        let real_floor = ins!(floor);
+        // ## abs
+        let int_abs = ins!(abs, int);
+        let rat_abs = ins!(abs, rat);
+        let real_abs = ins!(abs, real);
         // # attach
         // ## power
         let nat_to_the_power_of_nat = ins!(ring_power, nat);
@@ -281,8 +356,40 @@ impl VdInstantiationMenu {
             int_ge,
             rat_ge,
             real_ge,
+            // ## dvd
+            nat_dvd,
+            int_dvd,
+            // ## logic
+            prop_implies,
+            prop_iff,
             // # sqrt
             real_sqrt,
+            // # functions
+            // ## log
+            real_log,
+            // ## exp
+            real_exp,
+            // ## gcd
+            nat_gcd,
+            int_gcd,
+            // ## max
+            nat_max,
+            int_max,
+            rat_max,
+            real_max,
+            // ## min
+            nat_min,
+            int_min,
+            rat_min,
+            real_min,
+            // ## binom
+            nat_binom,
+            // ## floor
+            real_floor,
+            // ## abs
+            int_abs,
+            rat_abs,
+            real_abs,
         }
     }
 }
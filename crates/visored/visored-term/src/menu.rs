@@ -44,6 +44,14 @@ impl VdTypeMenu {
             complex,
             sin,
             cos,
+            log,
+            exp,
+            gcd,
+            max,
+            min,
+            binom,
+            floor,
+            abs,
             group,
             ring,
             group_mul,
@@ -64,6 +72,9 @@ impl VdTypeMenu {
             le,
             ge,
             real_sqrt,
+            dvd,
+            implies,
+            iff,
         } = *VD_ITEM_PATH_MENU;
 
         let nat = VdType::new_item_path(nat.into(), db);
@@ -29,6 +29,14 @@ impl VdTermMenu {
             complex,
             sin,
             cos,
+            log,
+            exp,
+            gcd,
+            max,
+            min,
+            binom,
+            floor,
+            abs,
             group,
             ring,
             group_mul,
@@ -49,6 +57,9 @@ impl VdTermMenu {
             le,
             ge,
             real_sqrt,
+            dvd,
+            implies,
+            iff,
         } = *VD_ITEM_PATH_MENU;
 
         let zero = VdLiteral::new(VdLiteralData::Int128(0), db);
@@ -32,6 +32,14 @@ impl VdItemPathZfcTypeTable {
             prop: prop_path,
             sin: sin_path,
             cos: cos_path,
+            log: log_path,
+            exp: exp_path,
+            gcd: gcd_path,
+            max: max_path,
+            min: min_path,
+            binom: binom_path,
+            floor: floor_path,
+            abs: abs_path,
             group: group_path,
             ring: ring_path,
             group_mul: group_mul_path,
@@ -52,6 +60,9 @@ impl VdItemPathZfcTypeTable {
             le: le_path,
             ge: ge_path,
             real_sqrt: real_sqrt_path,
+            dvd: dvd_path,
+            implies: implies_path,
+            iff: iff_path,
         } = *VD_ITEM_PATH_MENU;
         let VdTypeMenu {
             nat,
@@ -3,10 +3,12 @@ use crate::vm::{Vm, VmMode};
 use crate::*;
 use history::VmHistory;
 use husky_hir_eager_expr::variable::runtime::HirEagerRuntimeVariableIdx;
-use husky_linket_impl::linket_impl::LinketImplThawedValue;
+use husky_linket_impl::linket_impl::{LinketImplThawedValue, VmArgumentValue, VmArgumentValues};
 use husky_linktime::helpers::LinktimeThawedValue;
-use husky_value::IsThawedValue;
+use husky_value::{closure::VmClosure, vm_control_flow::VmControlFlow, IsThawedValue};
+use husky_vmir::expr::eval_closure_body;
 use husky_vmir::stmt::{VmirStmtIdx, VmirStmtIdxRange};
+use salsa::AsId;
 use snapshot::VmSnapshotKey;
 
 pub fn eval_linket_on_arguments<LinketImpl, VmRuntime: IsVmRuntime<LinketImpl>>(
@@ -79,6 +81,68 @@ where
         }
     }
 
+    fn eval_closure_call(
+        &mut self,
+        closure: &VmClosure<LinketImplThawedValue<LinketImpl>>,
+        arguments: Vec<LinketImplThawedValue<LinketImpl>>,
+    ) -> LinketImplVmControlFlowThawed<LinketImpl> {
+        let linket = Linket::from_id(salsa::Id::from_u32(closure.linket_raw()));
+        let vmir_region = self
+            .vmir_storage
+            .linket_vmir_region(linket, self.db, self.runtime.linktime())
+            .expect("closures are created by linkets defined in Husky");
+        // expressions inside the closure body might be evaluated more than once,
+        // so they are not recorded
+        let mut vm = Vm::new_fresh(
+            linket,
+            vec![],
+            VmMode::Quick,
+            &vmir_region,
+            self.db,
+            self.runtime,
+            self.vmir_storage,
+        );
+        eval_closure_body(closure, arguments, &mut vm)
+    }
+
+    fn eval_interpreted_linket(
+        &mut self,
+        linket: Linket,
+        arguments: VmArgumentValues<LinketImpl>,
+    ) -> LinketImplVmControlFlowThawed<LinketImpl> {
+        let vmir_region = self
+            .vmir_storage
+            .linket_vmir_region(linket, self.db, self.runtime.linktime())
+            .expect("interpreted linkets are defined in Husky");
+        // the expressions of the callee are not recorded
+        let mut vm = Vm::new_fresh(
+            linket,
+            vec![],
+            VmMode::Quick,
+            &vmir_region,
+            self.db,
+            self.runtime,
+            self.vmir_storage,
+        );
+        for (variable, argument) in
+            std::iter::zip(vmir_region.parameter_variables(self.db), arguments)
+        {
+            match argument {
+                VmArgumentValue::Simple(value) | VmArgumentValue::Keyed(Some(value)) => {
+                    vm.init_variable(variable, value)
+                }
+                // omitted keyed arguments and variadic arguments are never built for calls
+                // to interpreted linkets, see `VmirBuilder::build_arguments`
+                VmArgumentValue::Keyed(None) | VmArgumentValue::Variadic(_) => unreachable!(),
+                VmArgumentValue::RuntimeConstants(_) => unreachable!(),
+            }
+        }
+        match vmir_region.root_expr().eval(None, &mut vm) {
+            VmControlFlow::Return(value) => VmControlFlow::Continue(value),
+            cf => cf,
+        }
+    }
+
     fn eval_stmts(
         &mut self,
         stmts: VmirStmtIdxRange<LinketImpl>,
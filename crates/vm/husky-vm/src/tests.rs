@@ -1,23 +1,43 @@
 pub(crate) use husky_linket::test_helpers::TestLinket;
 pub(crate) use husky_vfs::test_helpers::*;
 
+use eterned::db::EternerDb;
 use husky_corgi_config::jar::CorgiConfigJar;
 use husky_corgi_config_ast::CorgiConfigAstJar;
 use husky_coword::jar::CowordJar;
 use husky_dec_signature::jar::DecSignatureJar;
+use husky_entity_path::path::major_item::form::MajorFormPath;
 use husky_entity_tree::jar::EntityTreeJar;
 use husky_eth_signature::jar::EthSignatureJar;
 use husky_eth_term::jar::EthTermJar;
 use husky_fly_term::jar::FlyTermJar;
+use husky_item_path_interface::ItemPathIdInterface;
+use husky_ki_repr_interface::{KiDomainReprInterface, KiReprInterface, KiRuntimeComptermInterface};
+use husky_linket::linket::{package_linkets, Linket, LinketData};
+use husky_linket_impl::{
+    dev_eval_context::{IsDevRuntimeInterface, IsDevRuntimeInterfaceDyn},
+    linket_impl::VmArgumentValue,
+};
+use husky_linktime::IsLinktime;
 use husky_manifest::jar::ManifestJar;
 use husky_manifest_ast::jar::ManifestAstJar;
 use husky_sem_expr::SemExprJar;
+use husky_standard_linket_impl::{
+    pedestal::StandardPedestal, StandardKiControlFlow, StandardLinketImpl,
+    StandardVmArgumentValues, StandardVmControlFlow,
+};
+use husky_standard_value::{thawed::ThawedValue, Value};
 use husky_syn_decl::jar::SynDeclJar;
 use husky_syn_defn::jar::SynDefnJar;
 use husky_syn_expr::jar::SynExprJar;
 use husky_term_prelude::jar::TermPreludeJar;
 use husky_token::TokenJar;
 use husky_toml_ast::TomlAstJar;
+use husky_value::vm_control_flow::VmControlFlow;
+use husky_vfs::path::{linktime_target_path::LinktimeTargetPath, package_path::PackagePath};
+use std::convert::Infallible;
+
+use crate::runtime::IsVmRuntime;
 
 #[salsa::db(
     CowordJar,
@@ -97,3 +117,224 @@ pub(crate) struct DB;
 //         &vmir_storage,
 //     );
 // }
+
+/// linkets defined in Husky are interpreted,
+/// and the few library linkets used by the closure examples are implemented below,
+/// so that no compiled library is needed
+struct InterpretedLinktime;
+
+impl IsLinktime for InterpretedLinktime {
+    type LinketImpl = StandardLinketImpl;
+
+    fn linket_impl(&self, linket: Linket, db: &::salsa::Db) -> StandardLinketImpl {
+        library_linket_impl(linket, db).expect("linkets defined in Husky are interpreted")
+    }
+
+    fn new(_target_path: LinktimeTargetPath, _db: &::salsa::Db) -> Self {
+        unreachable!()
+    }
+
+    fn init(&self, _runtime: &'static dyn IsDevRuntimeInterfaceDyn<StandardLinketImpl>) {}
+
+    fn is_linket_interpreted(&self, linket: Linket, db: &::salsa::Db) -> bool {
+        library_linket_impl(linket, db).is_none()
+    }
+}
+
+fn library_linket_impl(linket: Linket, db: &::salsa::Db) -> Option<StandardLinketImpl> {
+    let fn_vm_wrapper: fn(StandardVmArgumentValues) -> StandardVmControlFlow =
+        match *linket.data(db) {
+            LinketData::VecConstructor { .. } => vec_i32_constructor_vm_wrapper,
+            LinketData::MethodRitchie { path, .. }
+                if path.ident(db).is_some_and(|ident| ident.data() == "ilen") =>
+            {
+                vec_i32_ilen_vm_wrapper
+            }
+            _ => return None,
+        };
+    Some(StandardLinketImpl::RitchieFn {
+        fn_ki_wrapper: None,
+        fn_vm_wrapper,
+        fn_pointer: || unreachable!("the vm only calls the wrapper"),
+    })
+}
+
+fn vec_i32_constructor_vm_wrapper(arguments: StandardVmArgumentValues) -> StandardVmControlFlow {
+    let Some(VmArgumentValue::Variadic(elements)) = arguments.into_iter().next() else {
+        unreachable!()
+    };
+    let elements: Vec<i32> = elements
+        .into_iter()
+        .map(|element| match element {
+            ThawedValue::I32(element) => element,
+            _ => unreachable!(),
+        })
+        .collect();
+    VmControlFlow::Continue(ThawedValue::from_owned(elements))
+}
+
+fn vec_i32_ilen_vm_wrapper(arguments: StandardVmArgumentValues) -> StandardVmControlFlow {
+    let Some(VmArgumentValue::Simple(slf)) = arguments.into_iter().next() else {
+        unreachable!()
+    };
+    VmControlFlow::Continue(ThawedValue::I32(slf.into_ref::<Vec<i32>>(None).len() as i32))
+}
+
+/// runtime for linkets that don't touch vals, memos or the ki domain
+struct InterpretedRuntime(InterpretedLinktime);
+
+impl IsVmRuntime<StandardLinketImpl> for InterpretedRuntime {
+    type Linktime = InterpretedLinktime;
+
+    fn linktime(&self) -> &InterpretedLinktime {
+        &self.0
+    }
+
+    fn eval_val(&self, _major_form_path: MajorFormPath) -> StandardVmControlFlow {
+        unreachable!()
+    }
+}
+
+impl IsDevRuntimeInterface<StandardLinketImpl> for InterpretedRuntime {
+    type ThawedSelf = Self;
+
+    unsafe fn cast_to_thawed_self_static_ref(&self) -> &'static Self {
+        unreachable!()
+    }
+
+    fn interner_db(&self) -> &EternerDb {
+        unreachable!()
+    }
+
+    fn eval_eager_val_with(
+        &self,
+        _val_item_path_id_interface: ItemPathIdInterface,
+        _pedestal: StandardPedestal,
+        _f: fn() -> StandardKiControlFlow,
+    ) -> StandardKiControlFlow {
+        unreachable!()
+    }
+
+    fn eval_lazy_val(
+        &self,
+        _val_item_path_id_interface: ItemPathIdInterface,
+        _pedestal: StandardPedestal,
+    ) -> StandardKiControlFlow {
+        unreachable!()
+    }
+
+    fn eval_ki_repr_interface(&self, _ki_repr: KiReprInterface) -> StandardKiControlFlow {
+        unreachable!()
+    }
+
+    fn eval_ki_domain_repr_interface(
+        &self,
+        _ki_domain_repr: KiDomainReprInterface,
+    ) -> StandardKiControlFlow<(), Infallible> {
+        unreachable!()
+    }
+
+    fn eval_ki_repr_with(
+        &self,
+        _ki_repr: KiReprInterface,
+        _f: impl FnOnce(KiDomainReprInterface) -> StandardKiControlFlow,
+    ) -> StandardKiControlFlow {
+        unreachable!()
+    }
+
+    fn eval_memo_field_with(
+        &self,
+        _item_path_id_interface: ItemPathIdInterface,
+        _slf: &'static std::ffi::c_void,
+        _f: fn(&'static std::ffi::c_void) -> StandardKiControlFlow,
+    ) -> StandardKiControlFlow {
+        unreachable!()
+    }
+
+    fn eval_ki_runtime_compterm(&self, _ki_runtime_compterm: KiRuntimeComptermInterface) -> Value {
+        unreachable!()
+    }
+
+    fn eval_ki_pedestal(&self, _ki_repr_interface: KiReprInterface) -> StandardPedestal {
+        unreachable!()
+    }
+
+    fn eval_generic_gn_with<'a>(
+        &'a self,
+        _ki_repr_interface: KiReprInterface,
+        _pedestal: StandardPedestal,
+        _f: Box<dyn FnOnce() -> StandardKiControlFlow + 'a>,
+    ) -> StandardKiControlFlow {
+        unreachable!()
+    }
+}
+
+fn eval_closure_example(ident: &str) -> StandardVmControlFlow {
+    use crate::{eval::eval_linket_on_arguments, vm::VmMode};
+    use husky_path_utils::HuskyLangDevPaths;
+    use husky_vmir::storage::DevVmirStorage;
+
+    let db = &*DB::default();
+    let package_path = PackagePath::new_local_or_toolchain_package(
+        db,
+        db.dev_toolchain().unwrap(),
+        &HuskyLangDevPaths::new()
+            .examples_dir()
+            .join("basics/semantics-basics"),
+    )
+    .unwrap();
+    let linket = package_linkets(db, package_path)
+        .iter()
+        .copied()
+        .find(|linket| match *linket.data(db) {
+            LinketData::MajorRitchie { path, .. } => path.ident(db).data() == ident,
+            _ => false,
+        })
+        .unwrap();
+    let runtime = InterpretedRuntime(InterpretedLinktime);
+    let vmir_storage = DevVmirStorage::<StandardLinketImpl>::default();
+    let (cf, _) =
+        eval_linket_on_arguments(linket, vec![], VmMode::Quick, db, &runtime, &vmir_storage)
+            .unwrap();
+    cf
+}
+
+#[test]
+fn closure_captures_works() {
+    assert!(matches!(
+        eval_closure_example("capture_copyable"),
+        VmControlFlow::Continue(ThawedValue::I32(3))
+    ));
+}
+
+#[test]
+fn closure_as_argument_works() {
+    assert!(matches!(
+        eval_closure_example("closure_as_argument"),
+        VmControlFlow::Continue(ThawedValue::I32(6))
+    ));
+}
+
+#[test]
+fn closure_capturing_closure_works() {
+    assert!(matches!(
+        eval_closure_example("closure_capturing_closure"),
+        VmControlFlow::Continue(ThawedValue::I32(201))
+    ));
+}
+
+#[test]
+fn closure_capturing_non_copyable_works() {
+    assert!(matches!(
+        eval_closure_example("capture_non_copyable"),
+        VmControlFlow::Continue(ThawedValue::I32(4))
+    ));
+}
+
+#[test]
+fn closure_captures_non_copyable_by_reference() {
+    assert!(matches!(
+        eval_closure_example("capture_non_copyable_by_reference"),
+        VmControlFlow::Continue(ThawedValue::I32(12))
+    ));
+}
@@ -82,8 +82,9 @@ impl<
         let place_registry = linket
             .place_registry(db)
             .expect("has vmir_region implies that this is some");
+        // parameters are inherited from the decl region, so they don't have places in the registry
         let mut place_values = vec![];
-        for _ in place_values.len()..place_registry.len() {
+        for _ in 0..place_registry.len().max(vmir_region.variables_len(db)) {
             place_values.push(LinketImplThawedValue::<LinketImpl>::new_uninit())
         }
         Self {
@@ -114,6 +114,28 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
     pub(crate) fn linket_impl(&self, linket: Linket) -> Linktime::LinketImpl {
         self.linktime.linket_impl(linket, self.db)
     }
+
+    pub(crate) fn is_linket_interpreted(&self, linket: Linket) -> bool {
+        self.linktime.is_linket_interpreted(linket, self.db)
+    }
+
+    /// whether the linket has an eager body written in Husky, so that the vm can interpret it
+    pub(crate) fn is_linket_defined_in_husky(&self, linket: Linket) -> bool {
+        use husky_hir_defn::defn::HasHirDefn;
+
+        let db = self.db;
+        let Some((path, _)) = linket.path_and_instantiation_for_definition(db) else {
+            return false;
+        };
+        path.hir_defn(db)
+            .and_then(|hir_defn| hir_defn.hir_expr_body_and_region(db))
+            .is_some_and(|body_and_region| {
+                matches!(
+                    body_and_region,
+                    (HirExprIdx::Eager(_), HirExprRegion::Eager(_))
+                )
+            })
+    }
 }
 
 /// # actions
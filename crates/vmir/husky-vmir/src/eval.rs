@@ -5,12 +5,13 @@ use crate::{
 };
 use husky_entity_path::path::major_item::form::MajorFormPath;
 use husky_hir_eager_expr::variable::runtime::HirEagerRuntimeVariableIdx;
-use husky_linket::template_argument::qual::LinQual;
+use husky_linket::{linket::Linket, template_argument::qual::LinQual};
 use husky_linket_impl::{
-    linket_impl::{IsLinketImpl, LinketImplThawedValue},
+    linket_impl::{IsLinketImpl, LinketImplThawedValue, VmArgumentValues},
     LinketImplVmControlFlowThawed,
 };
 use husky_place::place::idx::PlaceIdx;
+use husky_value::closure::VmClosure;
 
 pub trait EvalVmir<'comptime, LinketImpl: IsLinketImpl> {
     fn db(&self) -> &'comptime ::salsa::Db;
@@ -39,6 +40,21 @@ pub trait EvalVmir<'comptime, LinketImpl: IsLinketImpl> {
         f: impl FnOnce(&mut Self) -> LinketImplVmControlFlowThawed<LinketImpl>,
     ) -> LinketImplVmControlFlowThawed<LinketImpl>;
 
+    /// evaluates a call to a closure on the VMIR region of the linket that created it,
+    /// with fresh variables so that the closure can be called repeatedly and from anywhere
+    fn eval_closure_call(
+        &mut self,
+        closure: &VmClosure<LinketImplThawedValue<LinketImpl>>,
+        arguments: Vec<LinketImplThawedValue<LinketImpl>>,
+    ) -> LinketImplVmControlFlowThawed<LinketImpl>;
+
+    /// evaluates a call to a linket that failed to transpile or compile on its own VMIR region
+    fn eval_interpreted_linket(
+        &mut self,
+        linket: Linket,
+        arguments: VmArgumentValues<LinketImpl>,
+    ) -> LinketImplVmControlFlowThawed<LinketImpl>;
+
     /// wrap the statements evaluation process
     fn eval_stmts(
         &mut self,
@@ -12,21 +12,26 @@ use husky_entity_path::path::{
 };
 use husky_hir_decl::decl::{HasHirDecl, TypeVariantHirDecl};
 use husky_hir_eager_expr::{
+    closure_parameter::HirEagerClosureParameterPattern,
     variable::runtime::{HirEagerRuntimeVariableIdx, HirEagerRuntimeVariableName},
-    HirEagerExprData, HirEagerExprIdx, HirEagerRitchieArgument,
+    HirEagerClosureCapture, HirEagerExprData, HirEagerExprIdx, HirEagerRitchieArgument,
 };
 use husky_hir_opr::{binary::HirBinaryOpr, prefix::HirPrefixOpr, suffix::HirSuffixOpr};
+use husky_hir_ty::HirType;
 use husky_ki::KiRuntimeCompterm;
 use husky_ki_repr::expansion::ki_runtime_compterms_from_hir_instantiation;
 use husky_lifetime_utils::capture::Captures;
 use husky_linket::{linket::Linket, template_argument::qual::LinQual};
-use husky_linket_impl::{linket_impl::VmArgumentValue, LinketImplVmControlFlowThawed};
+use husky_linket_impl::{
+    linket_impl::{LinketImplThawedValue, VmArgumentValue, VmArgumentValues},
+    LinketImplVmControlFlowThawed,
+};
 use husky_literal_value::LiteralValue;
 use husky_opr::{BinaryClosedOpr, BinaryComparisonOpr, BinaryShiftOpr};
 use husky_place::place::{idx::PlaceIdx, EthPlace};
-use husky_value::{vm_control_flow::VmControlFlow, IsThawedValue};
+use husky_value::{closure::VmClosure, vm_control_flow::VmControlFlow, IsThawedValue};
 use idx_arena::{map::ArenaMap, Arena, ArenaIdx, ArenaIdxRange};
-use salsa::DebugWithDb;
+use salsa::{AsId, DebugWithDb};
 use smallvec::{smallvec, SmallVec};
 
 #[salsa::derive_debug_with_db]
@@ -66,11 +71,25 @@ pub enum VmirExprData<LinketImpl: IsLinketImpl> {
         linket_impl: LinketImpl,
         arguments: VmirArguments<LinketImpl>,
     },
+    /// a ritchie call whose linket failed to transpile or compile,
+    /// interpreted from the VMIR region of the linket instead
+    InterpretedLinket {
+        linket: Linket,
+        arguments: VmirArguments<LinketImpl>,
+    },
     Block {
         stmts: VmirStmtIdxRange<LinketImpl>,
         destroyers: VmirDestroyerIdxRange,
     },
-    Closure,
+    Closure {
+        parameters: SmallVec<[VmirPattern<LinketImpl>; 4]>,
+        body: VmirExprIdx<LinketImpl>,
+        captures: SmallVec<[VmirClosureCapture; 4]>,
+    },
+    ClosureCall {
+        function: VmirExprIdx<LinketImpl>,
+        arguments: VmirArguments<LinketImpl>,
+    },
     Todo,
     Unreachable,
     As {
@@ -160,6 +179,26 @@ pub enum VmirArgument<LinketImpl: IsLinketImpl> {
 }
 pub type VmirArguments<LinketImpl> = SmallVec<[VmirArgument<LinketImpl>; 4]>;
 
+/// a variable captured by a closure
+///
+/// copyable values are copied into the closure when it is created and others are borrowed,
+/// just like closures in the transpiled Rust code.
+#[salsa::derive_debug_with_db]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VmirClosureCapture {
+    variable_idx: HirEagerRuntimeVariableIdx,
+    is_always_copyable: bool,
+}
+
+impl VmirClosureCapture {
+    fn from_hir_eager(capture: HirEagerClosureCapture) -> Self {
+        Self {
+            variable_idx: capture.variable_idx(),
+            is_always_copyable: capture.is_always_copyable(),
+        }
+    }
+}
+
 impl<LinketImpl: IsLinketImpl> ToVmir<LinketImpl> for HirEagerExprIdx {
     type Output = VmirExprIdx<LinketImpl>;
 
@@ -365,12 +404,9 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
                     self.lin_instantiation(),
                     self.db(),
                 );
-                let linket_impl = self.linket_impl(linket);
+                let takes_closures = takes_closures(arguments);
                 let arguments = self.build_arguments(arguments).collect();
-                VmirExprData::Linket {
-                    linket_impl,
-                    arguments,
-                }
+                self.build_ritchie_call(linket, arguments, takes_closures)
             }
             HirEagerExprData::AssocFunctionRitchieCall {
                 path,
@@ -383,12 +419,9 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
                     self.lin_instantiation(),
                     self.db(),
                 );
-                let linket_impl = self.linket_impl(linket);
+                let takes_closures = takes_closures(arguments);
                 let arguments = self.build_arguments(arguments).collect();
-                VmirExprData::Linket {
-                    linket_impl,
-                    arguments,
-                }
+                self.build_ritchie_call(linket, arguments, takes_closures)
             }
             HirEagerExprData::PropsStructField {
                 self_argument,
@@ -451,7 +484,6 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
             } => {
                 let linket =
                     Linket::new_method(path, instantiation, self.lin_instantiation(), self.db());
-                let linket_impl = self.linket_impl(linket);
                 let mut arguments = smallvec![VmirArgument::SelfValue {
                     expr: self_argument.to_vmir(self)
                 }];
@@ -460,10 +492,7 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
                     // ad hoc
                     // todo!()
                 }
-                VmirExprData::Linket {
-                    linket_impl,
-                    arguments,
-                }
+                self.build_ritchie_call(linket, arguments, takes_closures(hir_arguments))
             }
             HirEagerExprData::NewTuple { .. } => VmirExprData::Linket {
                 linket_impl: todo!(),
@@ -499,9 +528,31 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
             }
             HirEagerExprData::Closure {
                 ref parameters,
-                return_ty,
                 body,
-            } => VmirExprData::Closure,
+                ref captures,
+                ..
+            } => VmirExprData::Closure {
+                parameters: parameters
+                    .iter()
+                    .map(|parameter| match *parameter {
+                        HirEagerClosureParameterPattern::Simple { pattern_idx, .. } => {
+                            pattern_idx.to_vmir(self)
+                        }
+                    })
+                    .collect(),
+                body: body.to_vmir(self),
+                captures: captures
+                    .iter()
+                    .map(|&capture| VmirClosureCapture::from_hir_eager(capture))
+                    .collect(),
+            },
+            HirEagerExprData::ClosureCall {
+                function,
+                ref arguments,
+            } => VmirExprData::ClosureCall {
+                function: function.to_vmir(self),
+                arguments: self.build_arguments(arguments).collect(),
+            },
             HirEagerExprData::EmptyHtmlTag {
                 function_ident,
                 ref arguments,
@@ -517,6 +568,25 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
         }
     }
 
+    /// linkets defined in Husky that take closures are always interpreted,
+    /// because compiled code can't call closures created by the vm
+    fn build_ritchie_call(
+        &self,
+        linket: Linket,
+        arguments: VmirArguments<Linktime::LinketImpl>,
+        takes_closures: bool,
+    ) -> VmirExprData<Linktime::LinketImpl> {
+        match (takes_closures && self.is_linket_defined_in_husky(linket))
+            || self.is_linket_interpreted(linket)
+        {
+            true => VmirExprData::InterpretedLinket { linket, arguments },
+            false => VmirExprData::Linket {
+                linket_impl: self.linket_impl(linket),
+                arguments,
+            },
+        }
+    }
+
     fn build_arguments<'a>(
         &'a mut self,
         arguments: &'comptime [HirEagerRitchieArgument],
@@ -534,6 +604,15 @@ impl<'comptime, Linktime: IsLinktime> VmirBuilder<'comptime, Linktime> {
     }
 }
 
+fn takes_closures(arguments: &[HirEagerRitchieArgument]) -> bool {
+    arguments.iter().any(|argument| match *argument {
+        HirEagerRitchieArgument::Simple(ref parameter, _, _) => {
+            matches!(parameter.ty, HirType::Ritchie(_))
+        }
+        HirEagerRitchieArgument::Variadic | HirEagerRitchieArgument::Keyed => false,
+    })
+}
+
 impl<LinketImpl: IsLinketImpl> VmirExprIdx<LinketImpl> {
     pub fn eval<'comptime>(
         self,
@@ -651,34 +730,55 @@ impl<LinketImpl: IsLinketImpl> VmirExprIdx<LinketImpl> {
                 linket_impl,
                 ref arguments,
             } => {
-                let arguments = arguments
-                    .iter()
-                    .map(
-                        |arg| -> LinketImplVmControlFlowThawed<
-                            LinketImpl,
-                            VmArgumentValue<LinketImpl>,
-                        > {
-                            match arg {
-                                VmirArgument::SelfValue { expr } => VmControlFlow::Continue(
-                                    VmArgumentValue::Simple(expr.eval(None, ctx)?),
-                                ),
-                                VmirArgument::Simple { expr, coercion } => todo!(),
-                                VmirArgument::Variadic { exprs } => {
-                                    VmControlFlow::Continue(VmArgumentValue::Variadic(
-                                        exprs
-                                            .into_iter()
-                                            .map(|expr| expr.eval(None, ctx))
-                                            .collect::<VmControlFlow<_, _, _>>()?,
-                                    ))
-                                }
-                            }
-                        },
-                    )
-                    .collect::<VmControlFlow<_, _, _>>()?;
+                let arguments = eval_arguments(arguments, ctx)?;
                 ctx.eval_expr_itself(self, |ctx| linket_impl.eval_vm(arguments, ctx.db()))
             }
+            VmirExprData::InterpretedLinket {
+                linket,
+                ref arguments,
+            } => {
+                let arguments = eval_arguments(arguments, ctx)?;
+                ctx.eval_expr_itself(self, |ctx| ctx.eval_interpreted_linket(linket, arguments))
+            }
             VmirExprData::Block { stmts, destroyers } => stmts.eval(ctx),
-            VmirExprData::Closure => todo!(),
+            VmirExprData::Closure { ref captures, .. } => ctx.eval_expr_itself(self, |ctx| {
+                let captures = captures
+                    .iter()
+                    .map(|capture| {
+                        let value = match capture.is_always_copyable {
+                            true => ctx.access_variable(capture.variable_idx, LinQual::Transient),
+                            false => ctx.access_variable(capture.variable_idx, LinQual::Ref),
+                        };
+                        (capture.variable_idx.index(), value)
+                    })
+                    .collect();
+                Continue(LinketImplThawedValue::<LinketImpl>::from_vm_closure(
+                    VmClosure::new(
+                        ctx.vmir_region().linket().as_id().as_u32(),
+                        self.index(),
+                        captures,
+                    ),
+                ))
+            }),
+            VmirExprData::ClosureCall {
+                function,
+                ref arguments,
+            } => {
+                let function = function.eval(None, ctx)?;
+                let arguments = arguments
+                    .iter()
+                    .map(|arg| -> LinketImplVmControlFlowThawed<LinketImpl> {
+                        match *arg {
+                            VmirArgument::SelfValue { expr } => expr.eval(None, ctx),
+                            VmirArgument::Simple { expr, coercion } => expr.eval(coercion, ctx),
+                            VmirArgument::Variadic { .. } => todo!(),
+                        }
+                    })
+                    .collect::<VmControlFlow<Vec<_>, _, _>>()?;
+                ctx.eval_expr_itself(self, |ctx| {
+                    ctx.eval_closure_call(function.vm_closure(), arguments)
+                })
+            }
             VmirExprData::Todo => todo!(),
             VmirExprData::Unreachable => todo!(),
             VmirExprData::As { opd } => todo!(),
@@ -727,3 +827,69 @@ impl<LinketImpl: IsLinketImpl> VmirExprIdx<LinketImpl> {
         })
     }
 }
+
+/// evaluates a call to a closure on `ctx`,
+/// which should be fresh and on the vmir region of the linket that created the closure
+pub fn eval_closure_body<'comptime, LinketImpl: IsLinketImpl>(
+    closure: &VmClosure<LinketImplThawedValue<LinketImpl>>,
+    arguments: Vec<LinketImplThawedValue<LinketImpl>>,
+    ctx: &mut impl EvalVmir<'comptime, LinketImpl>,
+) -> LinketImplVmControlFlowThawed<LinketImpl> {
+    use VmControlFlow::*;
+
+    debug_assert_eq!(
+        ctx.vmir_region().linket().as_id().as_u32(),
+        closure.linket_raw()
+    );
+    // the closure expression comes from the same vmir region as `ctx`
+    let closure_expr: VmirExprIdx<LinketImpl> =
+        VmirExprIdx(unsafe { ArenaIdx::new_ext(closure.expr_raw()) });
+    let VmirExprData::Closure {
+        ref parameters,
+        body,
+        ref captures,
+    } = *closure_expr.entry(ctx.vmir_expr_arena())
+    else {
+        unreachable!()
+    };
+    for (capture, (_, value)) in std::iter::zip(captures, closure.captures()) {
+        // non-copyable captures are already references into the creating frame
+        ctx.init_variable(capture.variable_idx, value.transient_access())
+    }
+    for (&parameter, argument) in std::iter::zip(parameters, arguments) {
+        parameter.take_value(argument, ctx)
+    }
+    match body.eval(None, ctx) {
+        Return(value) => Continue(value),
+        cf => cf,
+    }
+}
+
+fn eval_arguments<'a, 'comptime, LinketImpl: IsLinketImpl>(
+    arguments: &VmirArguments<LinketImpl>,
+    ctx: &mut impl EvalVmir<'comptime, LinketImpl>,
+) -> LinketImplVmControlFlowThawed<LinketImpl, VmArgumentValues<'a, LinketImpl>> {
+    arguments
+        .iter()
+        .map(
+            |arg| -> LinketImplVmControlFlowThawed<LinketImpl, VmArgumentValue<'a, LinketImpl>> {
+                match *arg {
+                    VmirArgument::SelfValue { expr } => {
+                        VmControlFlow::Continue(VmArgumentValue::Simple(expr.eval(None, ctx)?))
+                    }
+                    VmirArgument::Simple { expr, coercion } => {
+                        VmControlFlow::Continue(VmArgumentValue::Simple(expr.eval(coercion, ctx)?))
+                    }
+                    VmirArgument::Variadic { exprs } => {
+                        VmControlFlow::Continue(VmArgumentValue::Variadic(
+                            exprs
+                                .into_iter()
+                                .map(|expr| expr.eval(None, ctx))
+                                .collect::<VmControlFlow<_, _, _>>()?,
+                        ))
+                    }
+                }
+            },
+        )
+        .collect()
+}
@@ -4,7 +4,13 @@ use crate::{
     *,
 };
 use destroyer::VmirDestroyerArena;
-use husky_hir_eager_expr::{HirEagerExprIdx, HirEagerExprMap, HirEagerStmtIdx, HirEagerStmtMap};
+use husky_hir_eager_expr::{
+    variable::runtime::{
+        HirEagerRuntimeVariableData, HirEagerRuntimeVariableIdx, HirEagerRuntimeVariableRegionData,
+    },
+    HirEagerExprIdx, HirEagerExprMap, HirEagerStmtIdx, HirEagerStmtMap,
+};
+use husky_hir_expr::HirExprRegion;
 use husky_linket::linket::Linket;
 use husky_virtual_linket_impl::VirtualLinketImpl;
 use husky_virtual_linktime::VirtualLinktime;
@@ -89,6 +95,47 @@ impl<LinketImpl: IsLinketImpl> VmirRegion<LinketImpl> {
     pub fn vmir_stmt_arena(&self) -> &VmirStmtArena<LinketImpl> {
         &self.vmir_stmt_arena
     }
+
+    /// the number of runtime variables, parameters included
+    pub fn variables_len(&self, db: &::salsa::Db) -> usize {
+        self.runtime_variable_region_data(db).arena().len()
+    }
+
+    /// the variables that the arguments are bound to when the region is called,
+    /// the self value first if any, then the parenate parameters in order
+    pub fn parameter_variables(&self, db: &::salsa::Db) -> Vec<HirEagerRuntimeVariableIdx> {
+        let runtime_variable_region_data = self.runtime_variable_region_data(db);
+        runtime_variable_region_data
+            .self_value_variable()
+            .into_iter()
+            .chain(
+                runtime_variable_region_data
+                    .arena()
+                    .indexed_iter()
+                    .filter(|(_, entry)| {
+                        *entry.data() == HirEagerRuntimeVariableData::ParenateParameter
+                    })
+                    .map(|(variable, _)| variable),
+            )
+            .collect()
+    }
+
+    fn runtime_variable_region_data<'db>(
+        &self,
+        db: &'db ::salsa::Db,
+    ) -> &'db HirEagerRuntimeVariableRegionData {
+        use husky_hir_defn::defn::HasHirDefn;
+
+        let Some((path, _)) = self.linket.path_and_instantiation_for_definition(db) else {
+            unreachable!("has vmir region implies that this is some")
+        };
+        let Some((_, HirExprRegion::Eager(hir_eager_expr_region))) =
+            path.hir_defn(db).unwrap().hir_expr_body_and_region(db)
+        else {
+            unreachable!("has vmir region implies that this is some")
+        };
+        hir_eager_expr_region.runtime_variable_region_data(db)
+    }
 }
 
 pub(crate) fn linket_vmir_region<'comptime, Linktime: IsLinktime>(